@@ -3,7 +3,7 @@
 //! Critical requirement: bind on 256 dims must be < 10µs (PHASE-1.md line 66).
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use volt_bus::{bind, unbind, superpose, permute, similarity};
+use volt_bus::{bind, unbind, superpose, permute, similarity, similarity_auto};
 use volt_bus::{bind_frames, unbind_frames, similarity_frames};
 use volt_core::{TensorFrame, SlotRole, SLOT_DIM};
 
@@ -73,6 +73,26 @@ fn bench_similarity(c: &mut Criterion) {
             similarity(black_box(&a), black_box(&b))
         });
     });
+    c.bench_function("similarity_auto (dense)", |bencher| {
+        bencher.iter(|| {
+            similarity_auto(black_box(&a), black_box(&b))
+        });
+    });
+
+    let mut one_hot_a = [0.0; SLOT_DIM];
+    one_hot_a[3] = 1.0;
+    let mut one_hot_b = [0.0; SLOT_DIM];
+    one_hot_b[200] = 1.0;
+    c.bench_function("similarity (one-hot)", |bencher| {
+        bencher.iter(|| {
+            similarity(black_box(&one_hot_a), black_box(&one_hot_b))
+        });
+    });
+    c.bench_function("similarity_auto (one-hot)", |bencher| {
+        bencher.iter(|| {
+            similarity_auto(black_box(&one_hot_a), black_box(&one_hot_b))
+        });
+    });
 }
 
 fn bench_bind_frames(c: &mut Criterion) {
@@ -2,10 +2,46 @@
 //!
 //! These operations apply single-vector operations (bind, unbind, similarity)
 //! to all corresponding slots/resolutions in TensorFrames.
+//!
+//! With the `parallel` feature the per-slot work runs on the rayon pool.
+//! Each slot is computed independently and results are assembled in slot
+//! order, so output is bitwise identical to the serial path.
 
-use volt_core::slot::SlotSource;
+use volt_core::slot::{SlotMeta, SlotSource};
 use volt_core::{SlotData, TensorFrame, VoltError, MAX_SLOTS, NUM_RESOLUTIONS};
 
+/// Whether the public batch operations use the rayon path.
+const PARALLEL: bool = cfg!(feature = "parallel");
+
+/// A computed output slot: its data and metadata.
+type SlotOutput = Option<(SlotData, SlotMeta)>;
+
+/// Evaluates `f` for every slot index, returning results in slot order.
+///
+/// Runs on the rayon pool when `parallel` is set and the `parallel`
+/// feature is enabled; serially otherwise.
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+fn map_slots<T: Send>(parallel: bool, f: impl Fn(usize) -> T + Sync + Send) -> Vec<T> {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        use rayon::prelude::*;
+        return (0..MAX_SLOTS).into_par_iter().map(f).collect();
+    }
+    (0..MAX_SLOTS).map(f).collect()
+}
+
+/// Writes per-slot outputs (in slot order) into a fresh frame.
+fn assemble(outputs: Vec<Result<SlotOutput, VoltError>>) -> Result<TensorFrame, VoltError> {
+    let mut result = TensorFrame::new();
+    for (slot_idx, output) in outputs.into_iter().enumerate() {
+        if let Some((data, meta)) = output? {
+            result.write_slot(slot_idx, data)?;
+            result.meta[slot_idx] = meta;
+        }
+    }
+    Ok(result)
+}
+
 /// Apply bind operation to all corresponding slots/resolutions in two frames.
 ///
 /// For each slot/resolution pair:
@@ -34,51 +70,57 @@ use volt_core::{SlotData, TensorFrame, VoltError, MAX_SLOTS, NUM_RESOLUTIONS};
 /// assert_eq!(bound.active_slot_count(), 1);
 /// ```
 pub fn bind_frames(frame_a: &TensorFrame, frame_b: &TensorFrame) -> Result<TensorFrame, VoltError> {
-    let mut result = TensorFrame::new();
+    bind_frames_with(PARALLEL, frame_a, frame_b)
+}
 
-    for slot_idx in 0..MAX_SLOTS {
-        let slot_a = &frame_a.slots[slot_idx];
-        let slot_b = &frame_b.slots[slot_idx];
+fn bind_frames_with(
+    parallel: bool,
+    frame_a: &TensorFrame,
+    frame_b: &TensorFrame,
+) -> Result<TensorFrame, VoltError> {
+    assemble(map_slots(parallel, |slot_idx| bind_slot(frame_a, frame_b, slot_idx)))
+}
 
-        match (slot_a, slot_b) {
-            (Some(data_a), Some(data_b)) => {
-                // Both slots exist: bind corresponding resolutions
-                let mut result_slot_data = SlotData::new(data_a.role);
-
-                for res_idx in 0..NUM_RESOLUTIONS {
-                    if let (Some(vec_a), Some(vec_b)) =
-                        (&data_a.resolutions[res_idx], &data_b.resolutions[res_idx])
-                    {
-                        let bound = crate::ops::bind(vec_a, vec_b)?;
-                        result_slot_data.write_resolution(res_idx, bound);
-                    }
+/// Computes one output slot of [`bind_frames`].
+fn bind_slot(
+    frame_a: &TensorFrame,
+    frame_b: &TensorFrame,
+    slot_idx: usize,
+) -> Result<SlotOutput, VoltError> {
+    let slot_a = &frame_a.slots[slot_idx];
+    let slot_b = &frame_b.slots[slot_idx];
+
+    match (slot_a, slot_b) {
+        (Some(data_a), Some(data_b)) => {
+            // Both slots exist: bind corresponding resolutions
+            let mut result_slot_data = SlotData::new(data_a.role);
+
+            for res_idx in 0..NUM_RESOLUTIONS {
+                if let (Some(vec_a), Some(vec_b)) =
+                    (&data_a.resolutions[res_idx], &data_b.resolutions[res_idx])
+                {
+                    let bound = crate::ops::bind(vec_a, vec_b)?;
+                    result_slot_data.write_resolution(res_idx, bound);
                 }
+            }
 
-                result.write_slot(slot_idx, result_slot_data)?;
-
-                // Metadata: min certainty (conservative propagation)
-                result.meta[slot_idx].certainty = frame_a.meta[slot_idx]
+            // Metadata: min certainty (conservative propagation)
+            let meta = SlotMeta {
+                certainty: frame_a.meta[slot_idx]
                     .certainty
-                    .min(frame_b.meta[slot_idx].certainty);
-                result.meta[slot_idx].source = SlotSource::HardCore;
-            }
-            (Some(data_a), None) => {
-                // Only frame_a has data: copy unchanged
-                result.write_slot(slot_idx, data_a.clone())?;
-                result.meta[slot_idx] = frame_a.meta[slot_idx].clone();
-            }
-            (None, Some(data_b)) => {
-                // Only frame_b has data: copy unchanged
-                result.write_slot(slot_idx, data_b.clone())?;
-                result.meta[slot_idx] = frame_b.meta[slot_idx].clone();
-            }
-            (None, None) => {
-                // Both empty: leave empty
-            }
+                    .min(frame_b.meta[slot_idx].certainty),
+                source: SlotSource::HardCore,
+                ..SlotMeta::default()
+            };
+            Ok(Some((result_slot_data, meta)))
         }
+        // Only frame_a has data: copy unchanged
+        (Some(data_a), None) => Ok(Some((data_a.clone(), frame_a.meta[slot_idx].clone()))),
+        // Only frame_b has data: copy unchanged
+        (None, Some(data_b)) => Ok(Some((data_b.clone(), frame_b.meta[slot_idx].clone()))),
+        // Both empty: leave empty
+        (None, None) => Ok(None),
     }
-
-    Ok(result)
 }
 
 /// Apply unbind operation to all corresponding slots/resolutions in two frames.
@@ -114,49 +156,57 @@ pub fn unbind_frames(
     frame_c: &TensorFrame,
     frame_a: &TensorFrame,
 ) -> Result<TensorFrame, VoltError> {
-    let mut result = TensorFrame::new();
+    unbind_frames_with(PARALLEL, frame_c, frame_a)
+}
 
-    for slot_idx in 0..MAX_SLOTS {
-        let slot_c = &frame_c.slots[slot_idx];
-        let slot_a = &frame_a.slots[slot_idx];
-
-        match (slot_c, slot_a) {
-            (Some(data_c), Some(data_a)) => {
-                // Both slots exist: unbind corresponding resolutions
-                let mut result_slot_data = SlotData::new(data_c.role);
-
-                for res_idx in 0..NUM_RESOLUTIONS {
-                    if let (Some(vec_c), Some(vec_a)) =
-                        (&data_c.resolutions[res_idx], &data_a.resolutions[res_idx])
-                    {
-                        let unbound = crate::ops::unbind(vec_c, vec_a)?;
-                        result_slot_data.write_resolution(res_idx, unbound);
-                    }
-                }
+fn unbind_frames_with(
+    parallel: bool,
+    frame_c: &TensorFrame,
+    frame_a: &TensorFrame,
+) -> Result<TensorFrame, VoltError> {
+    assemble(map_slots(parallel, |slot_idx| unbind_slot(frame_c, frame_a, slot_idx)))
+}
 
-                result.write_slot(slot_idx, result_slot_data)?;
+/// Computes one output slot of [`unbind_frames`].
+fn unbind_slot(
+    frame_c: &TensorFrame,
+    frame_a: &TensorFrame,
+    slot_idx: usize,
+) -> Result<SlotOutput, VoltError> {
+    let slot_c = &frame_c.slots[slot_idx];
+    let slot_a = &frame_a.slots[slot_idx];
+
+    match (slot_c, slot_a) {
+        (Some(data_c), Some(data_a)) => {
+            // Both slots exist: unbind corresponding resolutions
+            let mut result_slot_data = SlotData::new(data_c.role);
+
+            for res_idx in 0..NUM_RESOLUTIONS {
+                if let (Some(vec_c), Some(vec_a)) =
+                    (&data_c.resolutions[res_idx], &data_a.resolutions[res_idx])
+                {
+                    let unbound = crate::ops::unbind(vec_c, vec_a)?;
+                    result_slot_data.write_resolution(res_idx, unbound);
+                }
+            }
 
-                // Metadata: min certainty
-                result.meta[slot_idx].certainty = frame_c.meta[slot_idx]
+            // Metadata: min certainty
+            let meta = SlotMeta {
+                certainty: frame_c.meta[slot_idx]
                     .certainty
-                    .min(frame_a.meta[slot_idx].certainty);
-                result.meta[slot_idx].source = SlotSource::HardCore;
-            }
-            (Some(data_c), None) => {
-                // Only frame_c has data: copy unchanged
-                result.write_slot(slot_idx, data_c.clone())?;
-                result.meta[slot_idx] = frame_c.meta[slot_idx].clone();
-            }
-            (None, Some(_)) => {
-                // Only frame_a has data: cannot unbind, leave empty
-            }
-            (None, None) => {
-                // Both empty: leave empty
-            }
+                    .min(frame_a.meta[slot_idx].certainty),
+                source: SlotSource::HardCore,
+                ..SlotMeta::default()
+            };
+            Ok(Some((result_slot_data, meta)))
         }
+        // Only frame_c has data: copy unchanged
+        (Some(data_c), None) => Ok(Some((data_c.clone(), frame_c.meta[slot_idx].clone()))),
+        // Only frame_a has data: cannot unbind, leave empty
+        (None, Some(_)) => Ok(None),
+        // Both empty: leave empty
+        (None, None) => Ok(None),
     }
-
-    Ok(result)
 }
 
 /// Compute per-slot similarity between two frames at R0 (discourse level).
@@ -183,11 +233,17 @@ pub fn unbind_frames(
 /// assert!(similarities[1].is_none()); // Empty at slot 1
 /// ```
 pub fn similarity_frames(frame_a: &TensorFrame, frame_b: &TensorFrame) -> Vec<Option<f32>> {
-    let mut results = Vec::with_capacity(MAX_SLOTS);
+    similarity_frames_with(PARALLEL, frame_a, frame_b)
+}
 
-    for slot_idx in 0..MAX_SLOTS {
+fn similarity_frames_with(
+    parallel: bool,
+    frame_a: &TensorFrame,
+    frame_b: &TensorFrame,
+) -> Vec<Option<f32>> {
+    map_slots(parallel, |slot_idx| {
         // Compare at R0 (discourse level) for coarse similarity
-        let sim = match (&frame_a.slots[slot_idx], &frame_b.slots[slot_idx]) {
+        match (&frame_a.slots[slot_idx], &frame_b.slots[slot_idx]) {
             (Some(data_a), Some(data_b)) => {
                 match (&data_a.resolutions[0], &data_b.resolutions[0]) {
                     (Some(vec_a), Some(vec_b)) => Some(crate::ops::similarity(vec_a, vec_b)),
@@ -195,11 +251,8 @@ pub fn similarity_frames(frame_a: &TensorFrame, frame_b: &TensorFrame) -> Vec<Op
                 }
             }
             _ => None,
-        };
-        results.push(sim);
-    }
-
-    results
+        }
+    })
 }
 
 #[cfg(test)]
@@ -346,6 +399,42 @@ mod tests {
         assert!(similarities[1].is_none()); // Only frame_b has slot 1
     }
 
+    /// A frame with every slot filled at R0 and R1 and varied metadata.
+    fn full_frame(seed: u64) -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        for slot_idx in 0..MAX_SLOTS {
+            let mut slot = SlotData::new(SlotRole::Free(slot_idx as u8));
+            slot.write_resolution(0, test_vector(seed + slot_idx as u64));
+            slot.write_resolution(1, test_vector(seed + 100 + slot_idx as u64));
+            frame.write_slot(slot_idx, slot).unwrap();
+            frame.meta[slot_idx].certainty = (slot_idx as f32 + 1.0) / MAX_SLOTS as f32;
+        }
+        frame
+    }
+
+    #[test]
+    fn parallel_and_serial_paths_bitwise_identical() {
+        let frame_a = full_frame(1);
+        let mut frame_b = full_frame(500);
+        frame_b.slots[3] = None; // exercise the copy-through branch
+
+        let serial = bind_frames_with(false, &frame_a, &frame_b).unwrap();
+        let parallel = bind_frames_with(true, &frame_a, &frame_b).unwrap();
+        assert_eq!(serial.to_binary(), parallel.to_binary());
+
+        let serial = unbind_frames_with(false, &serial, &frame_a).unwrap();
+        let parallel = unbind_frames_with(true, &parallel, &frame_a).unwrap();
+        assert_eq!(serial.to_binary(), parallel.to_binary());
+
+        let bits = |sims: Vec<Option<f32>>| -> Vec<Option<u32>> {
+            sims.into_iter().map(|s| s.map(f32::to_bits)).collect()
+        };
+        assert_eq!(
+            bits(similarity_frames_with(false, &frame_a, &frame_b)),
+            bits(similarity_frames_with(true, &frame_a, &frame_b)),
+        );
+    }
+
     #[test]
     fn batch_operations_multi_resolution() {
         let mut frame_a = TensorFrame::new();
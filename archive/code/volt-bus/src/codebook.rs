@@ -15,6 +15,13 @@
 //! [entry_count * dim * 4 bytes: f32 LE data, row-major]
 //! ```
 //!
+//! Version 2 files append a decode-confidence [`Calibration`]:
+//! ```text
+//! [4 bytes: scale f32 LE]
+//! [4 bytes: bias f32 LE]
+//! ```
+//! Uncalibrated codebooks are still written as version 1.
+//!
 //! # Example
 //!
 //! ```
@@ -62,9 +69,136 @@ pub const CODEBOOK_CAPACITY: usize = 65536;
 /// Magic bytes identifying a codebook binary file.
 const MAGIC: [u8; 4] = *b"VXCB";
 
-/// Current binary format version.
+/// Binary format version for codebooks without calibration.
 const FORMAT_VERSION: u32 = 1;
 
+/// Binary format version for codebooks carrying a [`Calibration`].
+const FORMAT_VERSION_CALIBRATED: u32 = 2;
+
+/// Newton iterations used by [`Calibration::fit`].
+const CALIBRATION_FIT_ITERATIONS: usize = 100;
+
+/// Platt scaling that maps a raw nearest-neighbor cosine similarity to a
+/// calibrated decode confidence: `sigmoid(scale * similarity + bias)`.
+///
+/// `scale` is kept non-negative so confidence never decreases as
+/// similarity grows.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::codebook::Calibration;
+///
+/// let cal = Calibration { scale: 10.0, bias: -5.0 };
+/// assert!((cal.apply(0.5) - 0.5).abs() < 1e-6);
+/// assert!(cal.apply(0.9) > cal.apply(0.6));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calibration {
+    /// Slope applied to the similarity (non-negative).
+    pub scale: f32,
+    /// Offset added before the sigmoid.
+    pub bias: f32,
+}
+
+impl Calibration {
+    /// Map a raw similarity to a confidence in `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::Calibration;
+    ///
+    /// let cal = Calibration { scale: 4.0, bias: -2.0 };
+    /// let c = cal.apply(0.3);
+    /// assert!((0.0..=1.0).contains(&c));
+    /// ```
+    pub fn apply(&self, similarity: f32) -> f32 {
+        let z = self.scale.max(0.0) * similarity + self.bias;
+        (1.0 / (1.0 + (-z).exp())).clamp(0.0, 1.0)
+    }
+
+    /// Fit Platt scaling to held-out `(similarity, correct)` pairs.
+    ///
+    /// Runs Newton's method on the regularized logistic loss, using
+    /// Platt's smoothed targets so a perfectly separable sample still
+    /// yields finite parameters. A negative fitted slope is clamped to
+    /// zero, keeping the mapping monotonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::BusError`] if `samples` does not contain at
+    /// least one correct and one incorrect decode, or contains a
+    /// non-finite similarity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::Calibration;
+    ///
+    /// let samples = [(0.95, true), (0.9, true), (0.7, true), (0.6, false), (0.3, false)];
+    /// let cal = Calibration::fit(&samples).unwrap();
+    /// assert!(cal.apply(0.95) > cal.apply(0.3));
+    /// ```
+    pub fn fit(samples: &[(f32, bool)]) -> Result<Self, VoltError> {
+        if let Some((s, _)) = samples.iter().find(|(s, _)| !s.is_finite()) {
+            return Err(VoltError::BusError {
+                message: format!("calibration sample similarity is not finite: {s}"),
+            });
+        }
+        let positives = samples.iter().filter(|(_, correct)| *correct).count();
+        let negatives = samples.len() - positives;
+        if positives == 0 || negatives == 0 {
+            return Err(VoltError::BusError {
+                message: format!(
+                    "calibration needs correct and incorrect decodes, got {positives} correct and {negatives} incorrect"
+                ),
+            });
+        }
+
+        let hi_target = (positives as f64 + 1.0) / (positives as f64 + 2.0);
+        let lo_target = 1.0 / (negatives as f64 + 2.0);
+        let (mut a, mut b) = (0.0f64, 0.0f64);
+        for _ in 0..CALIBRATION_FIT_ITERATIONS {
+            // Gradient and Hessian of the cross-entropy in (a, b).
+            let (mut ga, mut gb) = (0.0f64, 0.0f64);
+            let (mut haa, mut hab, mut hbb) = (1e-6f64, 0.0f64, 1e-6f64);
+            for &(s, correct) in samples {
+                let x = s as f64;
+                let p = 1.0 / (1.0 + (-(a * x + b)).exp());
+                let t = if correct { hi_target } else { lo_target };
+                let w = (p * (1.0 - p)).max(1e-12);
+                ga += (p - t) * x;
+                gb += p - t;
+                haa += w * x * x;
+                hab += w * x;
+                hbb += w;
+            }
+            let det = haa * hbb - hab * hab;
+            if det.abs() < 1e-18 {
+                break;
+            }
+            let da = (hbb * ga - hab * gb) / det;
+            let db = (haa * gb - hab * ga) / det;
+            a -= da;
+            b -= db;
+            if da.abs() < 1e-9 && db.abs() < 1e-9 {
+                break;
+            }
+        }
+
+        if !a.is_finite() || !b.is_finite() {
+            return Err(VoltError::BusError {
+                message: "calibration fit diverged".into(),
+            });
+        }
+        Ok(Self {
+            scale: a.max(0.0) as f32,
+            bias: b as f32,
+        })
+    }
+}
+
 // HNSW tuning parameters.
 // M=24 balances recall vs build time for 256-dim vectors at 65K scale.
 const HNSW_MAX_NB_CONNECTION: usize = 24;
@@ -90,6 +224,8 @@ pub struct Codebook {
     entries: Vec<[f32; SLOT_DIM]>,
     /// HNSW index over entries for fast nearest-neighbor search.
     index: Hnsw<'static, f32, DistCosine>,
+    /// Decode-confidence calibration, if one has been fitted.
+    calibration: Option<Calibration>,
 }
 
 impl std::fmt::Debug for Codebook {
@@ -97,6 +233,7 @@ impl std::fmt::Debug for Codebook {
         f.debug_struct("Codebook")
             .field("len", &self.entries.len())
             .field("dim", &SLOT_DIM)
+            .field("calibration", &self.calibration)
             .finish()
     }
 }
@@ -176,7 +313,11 @@ impl Codebook {
             index.insert((&entry[..], i));
         }
 
-        Ok(Self { entries, index })
+        Ok(Self {
+            entries,
+            index,
+            calibration: None,
+        })
     }
 
     /// Look up a codebook entry by its ID.
@@ -270,6 +411,39 @@ impl Codebook {
         Ok((id, entry))
     }
 
+    /// Like [`quantize`](Self::quantize), but also returns the decode
+    /// confidence of the match: the [`calibrated_confidence`] of the
+    /// cosine similarity between `vector` and the chosen entry.
+    ///
+    /// [`calibrated_confidence`]: Self::calibrated_confidence
+    ///
+    /// # Errors
+    ///
+    /// Same as [`quantize`](Self::quantize).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::Codebook;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut v = [0.0f32; SLOT_DIM];
+    /// v[0] = 1.0;
+    /// let cb = Codebook::from_entries(vec![v]).unwrap();
+    ///
+    /// let (id, _, confidence) = cb.quantize_with_confidence(&v).unwrap();
+    /// assert_eq!(id, 0);
+    /// assert!((confidence - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn quantize_with_confidence(
+        &self,
+        vector: &[f32; SLOT_DIM],
+    ) -> Result<(u16, [f32; SLOT_DIM], f32), VoltError> {
+        let (id, entry) = self.quantize(vector)?;
+        let confidence = self.calibrated_confidence(crate::similarity(vector, &entry));
+        Ok((id, entry, confidence))
+    }
+
     /// Number of entries in the codebook.
     ///
     /// # Example
@@ -304,6 +478,70 @@ impl Codebook {
         self.entries.is_empty()
     }
 
+    /// The fitted decode-confidence calibration, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::Codebook;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut v = [0.0f32; SLOT_DIM];
+    /// v[0] = 1.0;
+    /// let cb = Codebook::from_entries(vec![v]).unwrap();
+    /// assert!(cb.calibration().is_none());
+    /// ```
+    pub fn calibration(&self) -> Option<Calibration> {
+        self.calibration
+    }
+
+    /// Attach (or with `None`, remove) a decode-confidence calibration.
+    ///
+    /// It is persisted by [`save`](Self::save).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::{Calibration, Codebook};
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut v = [0.0f32; SLOT_DIM];
+    /// v[0] = 1.0;
+    /// let mut cb = Codebook::from_entries(vec![v]).unwrap();
+    /// cb.set_calibration(Some(Calibration { scale: 8.0, bias: -4.0 }));
+    /// assert!(cb.calibration().is_some());
+    /// ```
+    pub fn set_calibration(&mut self, calibration: Option<Calibration>) {
+        self.calibration = calibration;
+    }
+
+    /// Convert a nearest-neighbor cosine similarity into a decode
+    /// confidence in `[0, 1]`.
+    ///
+    /// Uses the fitted [`Calibration`] when present. Without one it falls
+    /// back to the raw similarity, clamped to `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::{Calibration, Codebook};
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut v = [0.0f32; SLOT_DIM];
+    /// v[0] = 1.0;
+    /// let mut cb = Codebook::from_entries(vec![v]).unwrap();
+    /// assert_eq!(cb.calibrated_confidence(0.7), 0.7);
+    ///
+    /// cb.set_calibration(Some(Calibration { scale: 10.0, bias: -5.0 }));
+    /// assert!((cb.calibrated_confidence(0.5) - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn calibrated_confidence(&self, similarity: f32) -> f32 {
+        match self.calibration {
+            Some(calibration) => calibration.apply(similarity),
+            None => similarity.clamp(0.0, 1.0),
+        }
+    }
+
     /// Save the codebook to a binary file.
     ///
     /// The HNSW index is NOT serialized; it is rebuilt when loading via
@@ -331,9 +569,12 @@ impl Codebook {
 
         // Header
         writer.write_all(&MAGIC).map_err(io_err)?;
-        writer
-            .write_all(&FORMAT_VERSION.to_le_bytes())
-            .map_err(io_err)?;
+        let version = if self.calibration.is_some() {
+            FORMAT_VERSION_CALIBRATED
+        } else {
+            FORMAT_VERSION
+        };
+        writer.write_all(&version.to_le_bytes()).map_err(io_err)?;
         writer
             .write_all(&(self.entries.len() as u32).to_le_bytes())
             .map_err(io_err)?;
@@ -348,6 +589,16 @@ impl Codebook {
             }
         }
 
+        if let Some(calibration) = self.calibration {
+            writer
+                .write_all(&calibration.scale.to_le_bytes())
+                .map_err(io_err)?;
+            writer
+                .write_all(&calibration.bias.to_le_bytes())
+                .map_err(io_err)?;
+        }
+        writer.flush().map_err(io_err)?;
+
         Ok(())
     }
 
@@ -396,11 +647,11 @@ impl Codebook {
         let mut buf4 = [0u8; 4];
         reader.read_exact(&mut buf4).map_err(io_err)?;
         let version = u32::from_le_bytes(buf4);
-        if version != FORMAT_VERSION {
+        if version != FORMAT_VERSION && version != FORMAT_VERSION_CALIBRATED {
             return Err(VoltError::BusError {
                 message: format!(
-                    "unsupported codebook version: expected {}, got {}",
-                    FORMAT_VERSION, version
+                    "unsupported codebook version: expected {} or {}, got {}",
+                    FORMAT_VERSION, FORMAT_VERSION_CALIBRATED, version
                 ),
             });
         }
@@ -445,11 +696,94 @@ impl Codebook {
             entries.push(entry);
         }
 
+        let calibration = if version == FORMAT_VERSION_CALIBRATED {
+            reader.read_exact(&mut buf4).map_err(io_err)?;
+            let scale = f32::from_le_bytes(buf4);
+            reader.read_exact(&mut buf4).map_err(io_err)?;
+            let bias = f32::from_le_bytes(buf4);
+            Some(Calibration { scale, bias })
+        } else {
+            None
+        };
+
         // from_entries normalizes and builds the HNSW index
-        Self::from_entries(entries)
+        let mut codebook = Self::from_entries(entries)?;
+        codebook.calibration = calibration;
+        Ok(codebook)
     }
 }
 
+/// Associative cleanup: snaps a noisy vector to its nearest codeword.
+///
+/// Returns `(index, similarity)` for the codebook entry with the highest
+/// cosine similarity to `noisy`. Unlike [`Codebook::quantize`], this is an
+/// exact linear scan rather than an HNSW query, so the winner is always
+/// the true nearest entry (ties go to the lowest index). Use it to turn
+/// the noisy output of [`unbind`](crate::unbind) back into a clean symbol.
+///
+/// A zero `noisy` vector scores 0.0 against every entry and yields index
+/// 0; a `noisy` vector containing NaN yields a NaN score.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::codebook::{cleanup, Codebook};
+/// use volt_core::SLOT_DIM;
+///
+/// let mut entries = Vec::new();
+/// for i in 0..4 {
+///     let mut v = [0.0f32; SLOT_DIM];
+///     v[i] = 1.0;
+///     entries.push(v);
+/// }
+/// let cb = Codebook::from_entries(entries).unwrap();
+///
+/// let mut noisy = [0.05f32; SLOT_DIM];
+/// noisy[2] = 1.0;
+/// let (index, sim) = cleanup(&noisy, &cb);
+/// assert_eq!(index, 2);
+/// assert!(sim > 0.5);
+/// ```
+pub fn cleanup(noisy: &[f32; SLOT_DIM], codebook: &Codebook) -> (usize, f32) {
+    let mut best = (0, crate::similarity(noisy, &codebook.entries[0]));
+    for (i, entry) in codebook.entries.iter().enumerate().skip(1) {
+        let sim = crate::similarity(noisy, entry);
+        if sim > best.1 {
+            best = (i, sim);
+        }
+    }
+    best
+}
+
+/// Like [`cleanup`], but returns `None` when the best similarity is below
+/// `min_similarity` (or NaN), i.e. when `noisy` matches no codeword well
+/// enough to trust.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::codebook::{cleanup_thresholded, Codebook};
+/// use volt_core::SLOT_DIM;
+///
+/// let mut v = [0.0f32; SLOT_DIM];
+/// v[0] = 1.0;
+/// let cb = Codebook::from_entries(vec![v]).unwrap();
+///
+/// assert_eq!(cleanup_thresholded(&v, &cb, 0.9).map(|(i, _)| i), Some(0));
+///
+/// let mut unrelated = [0.0f32; SLOT_DIM];
+/// unrelated[1] = 1.0;
+/// assert!(cleanup_thresholded(&unrelated, &cb, 0.9).is_none());
+/// ```
+pub fn cleanup_thresholded(
+    noisy: &[f32; SLOT_DIM],
+    codebook: &Codebook,
+    min_similarity: f32,
+) -> Option<(usize, f32)> {
+    let (index, sim) = cleanup(noisy, codebook);
+    (sim >= min_similarity).then_some((index, sim))
+}
+
 /// Convert an I/O error to a VoltError.
 fn io_err(e: std::io::Error) -> VoltError {
     VoltError::BusError {
@@ -497,6 +831,49 @@ mod tests {
         Codebook::from_entries(entries).expect("failed to build test codebook")
     }
 
+    #[test]
+    fn cleanup_recovers_codeword_after_unbind() {
+        let cb = build_test_codebook(64);
+        let role = cb.entries[5];
+        let filler = cb.entries[42];
+
+        let bound = crate::bind(&role, &filler).unwrap();
+        let noisy = crate::unbind(&bound, &role).unwrap();
+
+        let (index, sim) = cleanup(&noisy, &cb);
+        assert_eq!(index, 42);
+        assert!(sim > 0.8, "cleanup similarity too low: {sim}");
+        assert_eq!(cleanup_thresholded(&noisy, &cb, 0.8), Some((index, sim)));
+    }
+
+    #[test]
+    fn cleanup_recovers_filler_from_superposed_bindings() {
+        let cb = build_test_codebook(64);
+        let (role_a, filler_a) = (cb.entries[1], cb.entries[10]);
+        let (role_b, filler_b) = (cb.entries[2], cb.entries[20]);
+
+        let pair_a = crate::bind(&role_a, &filler_a).unwrap();
+        let pair_b = crate::bind(&role_b, &filler_b).unwrap();
+        let record = crate::superpose(&[&pair_a, &pair_b]).unwrap();
+        let noisy = crate::unbind(&record, &role_a).unwrap();
+
+        assert!(cosine_sim(&noisy, &filler_a) < 0.99, "expected a noisy recovery");
+        assert_eq!(cleanup(&noisy, &cb).0, 10);
+    }
+
+    #[test]
+    fn cleanup_thresholded_rejects_weak_matches() {
+        let cb = build_test_codebook(16);
+        let unrelated = test_vector(999_999);
+        let (_, sim) = cleanup(&unrelated, &cb);
+        assert!(sim < 0.5);
+        assert!(cleanup_thresholded(&unrelated, &cb, 0.5).is_none());
+
+        let mut nan = [0.0f32; SLOT_DIM];
+        nan[0] = f32::NAN;
+        assert!(cleanup_thresholded(&nan, &cb, -1.0).is_none());
+    }
+
     #[test]
     fn from_entries_rejects_empty() {
         let result = Codebook::from_entries(vec![]);
@@ -653,6 +1030,93 @@ mod tests {
         let _ = std::fs::remove_dir(&dir);
     }
 
+    #[test]
+    fn calibrated_confidence_falls_back_to_raw_similarity() {
+        let cb = build_test_codebook(8);
+        assert!(cb.calibration().is_none());
+        for sim in [0.0, 0.25, 0.5, 0.99] {
+            assert_eq!(cb.calibrated_confidence(sim), sim);
+        }
+        assert_eq!(cb.calibrated_confidence(-0.4), 0.0);
+        assert_eq!(cb.calibrated_confidence(1.2), 1.0);
+    }
+
+    #[test]
+    fn calibration_fit_is_monotonic_and_bounded() {
+        // Decodes are mostly right above ~0.7 similarity, mostly wrong below.
+        let samples: Vec<(f32, bool)> = (0..200)
+            .map(|i| {
+                let sim = i as f32 / 200.0;
+                let correct = if i % 10 == 0 { sim < 0.7 } else { sim >= 0.7 };
+                (sim, correct)
+            })
+            .collect();
+        let cal = Calibration::fit(&samples).unwrap();
+        assert!(cal.scale > 0.0);
+
+        let mut cb = build_test_codebook(8);
+        cb.set_calibration(Some(cal));
+        let mut last = -1.0f32;
+        for i in -10..=10 {
+            let c = cb.calibrated_confidence(i as f32 / 10.0);
+            assert!((0.0..=1.0).contains(&c), "confidence {c} out of range");
+            assert!(c >= last, "confidence must not decrease: {c} < {last}");
+            last = c;
+        }
+        assert!(cb.calibrated_confidence(0.95) > 0.8);
+        assert!(cb.calibrated_confidence(0.2) < 0.2);
+    }
+
+    #[test]
+    fn quantize_with_confidence_applies_calibration() {
+        let mut cb = build_test_codebook(8);
+        let mut query = test_vector(1003);
+        for (q, n) in query.iter_mut().zip(test_vector(7).iter()) {
+            *q += 0.5 * n;
+        }
+
+        let (id, entry) = cb.quantize(&query).unwrap();
+        let sim = cosine_sim(&query, &entry);
+        let (raw_id, _, raw) = cb.quantize_with_confidence(&query).unwrap();
+        assert_eq!(raw_id, id);
+        assert!((raw - sim.clamp(0.0, 1.0)).abs() < 1e-5);
+
+        let cal = Calibration { scale: 10.0, bias: -5.0 };
+        cb.set_calibration(Some(cal));
+        let (_, _, calibrated) = cb.quantize_with_confidence(&query).unwrap();
+        assert!((calibrated - cal.apply(sim)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn calibration_fit_rejects_single_class() {
+        assert!(Calibration::fit(&[]).is_err());
+        assert!(Calibration::fit(&[(0.9, true), (0.8, true)]).is_err());
+        assert!(Calibration::fit(&[(0.9, false), (f32::NAN, true)]).is_err());
+    }
+
+    #[test]
+    fn save_load_roundtrip_keeps_calibration() {
+        let mut cb = build_test_codebook(16);
+        let cal = Calibration {
+            scale: 12.5,
+            bias: -7.25,
+        };
+        cb.set_calibration(Some(cal));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("calibrated.bin");
+
+        cb.save(&path).unwrap();
+        let loaded = Codebook::load(&path).unwrap();
+        assert_eq!(loaded.calibration(), Some(cal));
+        assert_eq!(loaded.len(), 16);
+
+        cb.set_calibration(None);
+        cb.save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[4..8], &FORMAT_VERSION.to_le_bytes());
+        assert!(Codebook::load(&path).unwrap().calibration().is_none());
+    }
+
     #[test]
     fn load_rejects_bad_magic() {
         let dir = std::env::temp_dir().join("volt_codebook_test_magic");
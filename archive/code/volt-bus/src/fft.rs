@@ -1,23 +1,51 @@
 //! FFT infrastructure for hyperdimensional computing operations.
 //!
 //! This module provides FFT-based circular convolution and correlation
-//! operations used by bind and unbind operations. Uses a thread-local
-//! FFT planner for performance (avoids repeated planner allocation).
+//! operations used by bind and unbind operations. The forward and inverse
+//! 256-point plans are built once per process and shared by all threads
+//! (rustfft plans are `Send + Sync`), so no call re-plans the transform.
+//! Working buffers live on the caller's stack, so concurrent calls share
+//! only the immutable plans.
 
 use rustfft::num_complex::Complex;
-use rustfft::FftPlanner;
-use std::cell::RefCell;
+use rustfft::{Fft, FftPlanner};
+use std::sync::{Arc, OnceLock};
 use volt_core::SLOT_DIM;
 
-thread_local! {
-    /// Thread-local FFT planner for 256-point transforms.
-    /// Reusing the planner avoids ~100µs allocation overhead per operation.
-    static FFT_PLANNER: RefCell<FftPlanner<f32>> = RefCell::new(FftPlanner::new());
+/// Cached forward and inverse FFT plans for `SLOT_DIM`-point transforms.
+struct FftPlans {
+    forward: Arc<dyn Fft<f32>>,
+    inverse: Arc<dyn Fft<f32>>,
+}
+
+impl FftPlans {
+    /// Plans both transforms with a fresh planner.
+    fn new() -> Self {
+        let mut planner = FftPlanner::new();
+        Self {
+            forward: planner.plan_fft_forward(SLOT_DIM),
+            inverse: planner.plan_fft_inverse(SLOT_DIM),
+        }
+    }
+}
+
+/// Process-wide plan cache, built on first use or by [`warm_up`].
+static FFT_PLANS: OnceLock<FftPlans> = OnceLock::new();
+
+/// Returns the cached plans, planning them on first call.
+fn plans() -> &'static FftPlans {
+    FFT_PLANS.get_or_init(FftPlans::new)
+}
+
+/// Builds the cached plans now so the first bind/unbind does not pay
+/// the planning cost. Idempotent.
+pub(crate) fn warm_up() {
+    plans();
 }
 
 /// Convert real f32 array to complex array (imaginary parts = 0).
-fn real_to_complex(input: &[f32; SLOT_DIM]) -> Vec<Complex<f32>> {
-    input.iter().map(|&x| Complex::new(x, 0.0)).collect()
+fn real_to_complex(input: &[f32; SLOT_DIM]) -> [Complex<f32>; SLOT_DIM] {
+    input.map(|x| Complex::new(x, 0.0))
 }
 
 /// Extract real parts from complex array, discarding imaginary parts.
@@ -42,37 +70,38 @@ fn complex_to_real(input: &[Complex<f32>]) -> [f32; SLOT_DIM] {
 /// # Performance
 /// ~15-20µs for 256-dimensional vectors (2 forward + 1 inverse FFT)
 pub(crate) fn circular_convolution(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> [f32; SLOT_DIM] {
-    FFT_PLANNER.with(|planner| {
-        let mut planner = planner.borrow_mut();
-        let fft = planner.plan_fft_forward(SLOT_DIM);
-        let ifft = planner.plan_fft_inverse(SLOT_DIM);
-
-        // Convert to complex
-        let mut freq_a = real_to_complex(a);
-        let mut freq_b = real_to_complex(b);
-
-        // Forward FFT
-        fft.process(&mut freq_a);
-        fft.process(&mut freq_b);
-
-        // Element-wise multiplication in frequency domain
-        let mut freq_result: Vec<Complex<f32>> = freq_a
-            .iter()
-            .zip(freq_b.iter())
-            .map(|(x, y)| x * y)
-            .collect();
+    convolve_with(plans(), a, b)
+}
 
-        // Inverse FFT
-        ifft.process(&mut freq_result);
+fn convolve_with(plans: &FftPlans, a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> [f32; SLOT_DIM] {
+    let FftPlans {
+        forward: fft,
+        inverse: ifft,
+    } = plans;
 
-        // Normalize by SLOT_DIM (FFT convention)
-        let scale = 1.0 / SLOT_DIM as f32;
-        for c in &mut freq_result {
-            *c *= scale;
-        }
+    // Convert to complex
+    let mut freq_a = real_to_complex(a);
+    let mut freq_b = real_to_complex(b);
+
+    // Forward FFT
+    fft.process(&mut freq_a);
+    fft.process(&mut freq_b);
 
-        complex_to_real(&freq_result)
-    })
+    // Element-wise multiplication in frequency domain (in place)
+    for (x, y) in freq_a.iter_mut().zip(freq_b.iter()) {
+        *x *= y;
+    }
+
+    // Inverse FFT
+    ifft.process(&mut freq_a);
+
+    // Normalize by SLOT_DIM (FFT convention)
+    let scale = 1.0 / SLOT_DIM as f32;
+    for c in &mut freq_a {
+        *c *= scale;
+    }
+
+    complex_to_real(&freq_a)
 }
 
 /// Compute circular correlation via FFT: a ⊙ b
@@ -90,47 +119,46 @@ pub(crate) fn circular_convolution(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) ->
 /// # HDC Property
 /// circular_correlation(circular_convolution(a, b), a) ≈ b
 pub(crate) fn circular_correlation(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> [f32; SLOT_DIM] {
-    FFT_PLANNER.with(|planner| {
-        let mut planner = planner.borrow_mut();
-        let fft = planner.plan_fft_forward(SLOT_DIM);
-        let ifft = planner.plan_fft_inverse(SLOT_DIM);
-
-        // Convert to complex
-        let mut freq_a = real_to_complex(a);
-        let mut freq_b = real_to_complex(b);
-
-        // Forward FFT
-        fft.process(&mut freq_a);
-        fft.process(&mut freq_b);
-
-        // Element-wise division in frequency domain (true inverse)
-        // unbind(c, a) = IFFT(FFT(c) / FFT(a))
-        let mut freq_result: Vec<Complex<f32>> = freq_a
-            .iter()
-            .zip(freq_b.iter())
-            .map(|(x, y)| {
-                // Division: x / y = x * conj(y) / |y|^2
-                let y_norm_sq = y.re * y.re + y.im * y.im;
-                if y_norm_sq < 1e-10 {
-                    // Avoid division by zero
-                    Complex::new(0.0, 0.0)
-                } else {
-                    (x * y.conj()) / y_norm_sq
-                }
-            })
-            .collect();
+    correlate_with(plans(), a, b)
+}
 
-        // Inverse FFT
-        ifft.process(&mut freq_result);
+fn correlate_with(plans: &FftPlans, a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> [f32; SLOT_DIM] {
+    let FftPlans {
+        forward: fft,
+        inverse: ifft,
+    } = plans;
+
+    // Convert to complex
+    let mut freq_a = real_to_complex(a);
+    let mut freq_b = real_to_complex(b);
+
+    // Forward FFT
+    fft.process(&mut freq_a);
+    fft.process(&mut freq_b);
+
+    // Element-wise division in frequency domain (true inverse)
+    // unbind(c, a) = IFFT(FFT(c) / FFT(a))
+    for (x, y) in freq_a.iter_mut().zip(freq_b.iter()) {
+        // Division: x / y = x * conj(y) / |y|^2
+        let y_norm_sq = y.re * y.re + y.im * y.im;
+        *x = if y_norm_sq < 1e-10 {
+            // Avoid division by zero
+            Complex::new(0.0, 0.0)
+        } else {
+            (*x * y.conj()) / y_norm_sq
+        };
+    }
 
-        // Normalize by SLOT_DIM
-        let scale = 1.0 / SLOT_DIM as f32;
-        for c in &mut freq_result {
-            *c *= scale;
-        }
+    // Inverse FFT
+    ifft.process(&mut freq_a);
 
-        complex_to_real(&freq_result)
-    })
+    // Normalize by SLOT_DIM
+    let scale = 1.0 / SLOT_DIM as f32;
+    for c in &mut freq_a {
+        *c *= scale;
+    }
+
+    complex_to_real(&freq_a)
 }
 
 #[cfg(test)]
@@ -170,28 +198,95 @@ mod tests {
     fn fft_roundtrip_preserves_vector() {
         let a = test_vector(42);
 
-        FFT_PLANNER.with(|planner| {
-            let mut planner = planner.borrow_mut();
-            let fft = planner.plan_fft_forward(SLOT_DIM);
-            let ifft = planner.plan_fft_inverse(SLOT_DIM);
+        let FftPlans {
+            forward: fft,
+            inverse: ifft,
+        } = plans();
 
-            let mut freq = real_to_complex(&a);
-            fft.process(&mut freq);
-            ifft.process(&mut freq);
+        let mut freq = real_to_complex(&a);
+        fft.process(&mut freq);
+        ifft.process(&mut freq);
 
-            // Normalize
-            let scale = 1.0 / SLOT_DIM as f32;
-            for c in &mut freq {
-                *c *= scale;
-            }
+        // Normalize
+        let scale = 1.0 / SLOT_DIM as f32;
+        for c in &mut freq {
+            *c *= scale;
+        }
+
+        let recovered = complex_to_real(&freq);
 
-            let recovered = complex_to_real(&freq);
+        // Check recovery is close to original
+        for i in 0..SLOT_DIM {
+            assert!((a[i] - recovered[i]).abs() < 1e-5);
+        }
+    }
 
-            // Check recovery is close to original
-            for i in 0..SLOT_DIM {
-                assert!((a[i] - recovered[i]).abs() < 1e-5);
+    #[test]
+    fn cached_plans_match_fresh_planner_bitwise() {
+        let a = test_vector(42);
+        let b = test_vector(99);
+        let fresh = FftPlans::new();
+
+        let cached = circular_convolution(&a, &b);
+        let uncached = convolve_with(&fresh, &a, &b);
+        assert_eq!(cached.map(f32::to_bits), uncached.map(f32::to_bits));
+
+        let cached = circular_correlation(&cached, &a);
+        let uncached = correlate_with(&fresh, &uncached, &a);
+        assert_eq!(cached.map(f32::to_bits), uncached.map(f32::to_bits));
+    }
+
+    #[test]
+    fn cached_call_not_slower_than_planning_call() {
+        use std::time::{Duration, Instant};
+
+        let a = test_vector(42);
+        let b = test_vector(99);
+        warm_up();
+
+        let time = |f: &dyn Fn()| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        };
+        // Interleave the two paths so scheduler noise hits both alike,
+        // then compare the best run of each.
+        let mut planning = Duration::MAX;
+        let mut cached = Duration::MAX;
+        for _ in 0..50 {
+            planning = planning.min(time(&|| {
+                std::hint::black_box(convolve_with(&FftPlans::new(), &a, &b));
+            }));
+            cached = cached.min(time(&|| {
+                std::hint::black_box(circular_convolution(&a, &b));
+            }));
+        }
+        assert!(
+            cached <= planning,
+            "cached {cached:?} slower than planning {planning:?}"
+        );
+    }
+
+    #[test]
+    fn cached_plans_deterministic_across_threads() {
+        let a = test_vector(42);
+        let b = test_vector(99);
+        let expected = circular_convolution(&a, &b).map(f32::to_bits);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    (0..100)
+                        .map(|_| circular_convolution(&a, &b).map(f32::to_bits))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            for bits in handle.join().unwrap() {
+                assert_eq!(bits, expected);
             }
-        });
+        }
     }
 
     #[test]
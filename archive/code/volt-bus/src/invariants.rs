@@ -0,0 +1,310 @@
+//! Invariant checks for the HDC algebra on a caller-supplied vector set.
+//!
+//! Downstream code relies on a few algebraic properties holding for the
+//! vectors it feeds through the bus:
+//! - **Unbind recall**: `unbind(bind(a, b), a) ≈ b`
+//! - **Permute invertibility**: `permute(permute(a, k), -k) = a`, with
+//!   shifted copies nearly orthogonal to the original
+//! - **Superposition capacity**: constituents of `superpose([..])` stay
+//!   detectable by similarity
+//!
+//! These hold for random unit vectors but can fail for structured ones
+//! (near-zero, constant, or highly correlated). [`check_invariants`]
+//! measures each property on a given set and flags the vectors that
+//! break the assumptions. It is fully deterministic.
+
+use volt_core::SLOT_DIM;
+
+use crate::ops::{bind, permute, similarity, superpose, unbind};
+
+/// Minimum acceptable `unbind(bind(a, b), a)` similarity to `b`
+/// (the Milestone 1.2 requirement).
+pub const UNBIND_RECALL_THRESHOLD: f32 = 0.85;
+
+/// Maximum acceptable `|sim(permute(a, 1), a)|`. Above this, a vector is
+/// close to shift-invariant and permutation cannot encode structure.
+pub const SHIFT_SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Minimum similarity a constituent must keep to its superposition to
+/// count as detectable (about 3σ of chance similarity at 256 dims).
+pub const SUPERPOSE_DETECTION_THRESHOLD: f32 = 0.2;
+
+/// Why a vector was flagged by [`check_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvariantViolation {
+    /// The vector contains NaN or Inf.
+    NonFinite,
+    /// The vector's L2 norm is zero or near-zero.
+    NearZero,
+    /// Used as the unbind key, it recovered its partner with this similarity,
+    /// below [`UNBIND_RECALL_THRESHOLD`].
+    PoorUnbindRecall(f32),
+    /// Its one-step shift has this similarity to itself, above
+    /// [`SHIFT_SIMILARITY_THRESHOLD`].
+    ShiftInvariant(f32),
+}
+
+/// A vector that breaks one of the algebra's assumptions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlaggedVector {
+    /// Index into the slice passed to [`check_invariants`].
+    pub index: usize,
+    /// The assumption it breaks.
+    pub violation: InvariantViolation,
+}
+
+/// Measured HDC properties for a vector set.
+///
+/// Near-zero and non-finite vectors are flagged and excluded from the
+/// measurements; if no usable vectors remain, all measurements are zero.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::check_invariants;
+/// use volt_core::SLOT_DIM;
+///
+/// let mut a = [0.0; SLOT_DIM];
+/// a[0] = 1.0;
+/// let report = check_invariants(&[a, [0.0; SLOT_DIM]]);
+/// assert_eq!(report.flagged.len(), 1);
+/// assert_eq!(report.flagged[0].index, 1);
+/// assert!(!report.is_healthy());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvariantReport {
+    /// Number of vectors that passed validation and were measured.
+    pub usable_count: usize,
+    /// Mean similarity of `unbind(bind(a, b), a)` to `b`, pairing each
+    /// usable vector with the next one (cyclically).
+    pub unbind_recall: f32,
+    /// Mean similarity of `permute(permute(a, 1), -1)` to `a`.
+    pub permute_recall: f32,
+    /// Mean `|sim(permute(a, 1), a)|`; near zero for well-behaved vectors.
+    pub shift_similarity: f32,
+    /// Largest `n` such that every one of the first `n` usable vectors
+    /// keeps similarity above [`SUPERPOSE_DETECTION_THRESHOLD`] to their
+    /// superposition.
+    pub superpose_capacity: usize,
+    /// Vectors that break an assumption, in index order.
+    pub flagged: Vec<FlaggedVector>,
+}
+
+impl InvariantReport {
+    /// `true` if no vector was flagged.
+    pub fn is_healthy(&self) -> bool {
+        self.flagged.is_empty()
+    }
+}
+
+/// Run the standard HDC property checks on `vectors` and report the results.
+///
+/// Deterministic: the same input always yields the same report.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::check_invariants;
+/// use volt_core::SLOT_DIM;
+///
+/// // One-hot vectors are exact under bind/unbind and shift-orthogonal.
+/// let vectors: Vec<[f32; SLOT_DIM]> = (0..4)
+///     .map(|i| {
+///         let mut v = [0.0; SLOT_DIM];
+///         v[i * 7] = 1.0;
+///         v
+///     })
+///     .collect();
+/// let report = check_invariants(&vectors);
+/// assert!(report.is_healthy());
+/// assert!(report.unbind_recall > 0.99);
+/// assert_eq!(report.superpose_capacity, 4);
+/// ```
+pub fn check_invariants(vectors: &[[f32; SLOT_DIM]]) -> InvariantReport {
+    let mut flagged = Vec::new();
+    let mut usable: Vec<(usize, &[f32; SLOT_DIM])> = Vec::new();
+
+    for (index, v) in vectors.iter().enumerate() {
+        if v.iter().any(|x| !x.is_finite()) {
+            flagged.push(FlaggedVector { index, violation: InvariantViolation::NonFinite });
+        } else if v.iter().map(|x| x * x).sum::<f32>() < 1e-10 {
+            flagged.push(FlaggedVector { index, violation: InvariantViolation::NearZero });
+        } else {
+            usable.push((index, v));
+        }
+    }
+
+    if usable.is_empty() {
+        return InvariantReport {
+            usable_count: 0,
+            unbind_recall: 0.0,
+            permute_recall: 0.0,
+            shift_similarity: 0.0,
+            superpose_capacity: 0,
+            flagged,
+        };
+    }
+
+    let n = usable.len();
+    let mut unbind_total = 0.0;
+    let mut permute_total = 0.0;
+    let mut shift_total = 0.0;
+
+    for (pos, &(index, key)) in usable.iter().enumerate() {
+        let partner = usable[(pos + 1) % n].1;
+
+        // Inputs are validated above, so bind/unbind cannot fail here;
+        // treat a failure as zero recall rather than panicking.
+        let recall = bind(key, partner)
+            .and_then(|bound| unbind(&bound, key))
+            .map(|recovered| similarity(&recovered, partner))
+            .unwrap_or(0.0);
+        unbind_total += recall;
+        if recall < UNBIND_RECALL_THRESHOLD {
+            flagged.push(FlaggedVector {
+                index,
+                violation: InvariantViolation::PoorUnbindRecall(recall),
+            });
+        }
+
+        let shifted = permute(key, 1);
+        permute_total += similarity(&permute(&shifted, -1), key);
+        let shift_sim = similarity(&shifted, key);
+        shift_total += shift_sim.abs();
+        if shift_sim.abs() > SHIFT_SIMILARITY_THRESHOLD {
+            flagged.push(FlaggedVector {
+                index,
+                violation: InvariantViolation::ShiftInvariant(shift_sim),
+            });
+        }
+    }
+
+    flagged.sort_by_key(|f| f.index);
+
+    InvariantReport {
+        usable_count: n,
+        unbind_recall: unbind_total / n as f32,
+        permute_recall: permute_total / n as f32,
+        shift_similarity: shift_total / n as f32,
+        superpose_capacity: superpose_capacity(&usable),
+        flagged,
+    }
+}
+
+/// Largest prefix of `usable` whose superposition keeps every constituent detectable.
+fn superpose_capacity(usable: &[(usize, &[f32; SLOT_DIM])]) -> usize {
+    let mut capacity = 0;
+    for n in 1..=usable.len() {
+        let constituents: Vec<&[f32; SLOT_DIM]> = usable[..n].iter().map(|&(_, v)| v).collect();
+        let detectable = superpose(&constituents).is_ok_and(|composite| {
+            constituents
+                .iter()
+                .all(|c| similarity(&composite, c) > SUPERPOSE_DETECTION_THRESHOLD)
+        });
+        if !detectable {
+            break;
+        }
+        capacity = n;
+    }
+    capacity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random unit vector (same mixing as the ops tests).
+    fn test_vector(seed: u64) -> [f32; SLOT_DIM] {
+        let mut v = [0.0; SLOT_DIM];
+        for (i, x) in v.iter_mut().enumerate() {
+            let mut h = seed.wrapping_mul(0xd2b74407b1ce6e93);
+            h = h.wrapping_add(i as u64);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xff51afd7ed558ccd);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+            h ^= h >> 33;
+            *x = ((h as f64 / u64::MAX as f64) * 2.0 - 1.0) as f32;
+        }
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        for x in &mut v {
+            *x /= norm;
+        }
+        v
+    }
+
+    #[test]
+    fn random_vectors_are_healthy() {
+        let vectors: Vec<_> = (0..16).map(test_vector).collect();
+        let report = check_invariants(&vectors);
+
+        assert!(report.is_healthy(), "flagged: {:?}", report.flagged);
+        assert_eq!(report.usable_count, 16);
+        assert!(report.unbind_recall > UNBIND_RECALL_THRESHOLD);
+        assert!((report.permute_recall - 1.0).abs() < 1e-5);
+        assert!(report.shift_similarity < 0.2);
+        assert!(report.superpose_capacity >= 8, "capacity {}", report.superpose_capacity);
+    }
+
+    #[test]
+    fn degenerate_vectors_are_flagged() {
+        let mut nan = test_vector(3);
+        nan[10] = f32::NAN;
+        let vectors = vec![
+            test_vector(1),
+            [0.0; SLOT_DIM],
+            [1.0; SLOT_DIM],
+            nan,
+            test_vector(2),
+        ];
+        let report = check_invariants(&vectors);
+
+        assert!(!report.is_healthy());
+        assert_eq!(report.usable_count, 3);
+        let violation_at = |index: usize| {
+            report
+                .flagged
+                .iter()
+                .filter(|f| f.index == index)
+                .map(|f| f.violation)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(violation_at(1), vec![InvariantViolation::NearZero]);
+        assert_eq!(violation_at(3), vec![InvariantViolation::NonFinite]);
+        assert!(
+            violation_at(2)
+                .iter()
+                .any(|v| matches!(v, InvariantViolation::ShiftInvariant(s) if *s > 0.99)),
+            "constant vector should be shift-invariant: {:?}",
+            violation_at(2)
+        );
+        assert!(report.flagged.windows(2).all(|w| w[0].index <= w[1].index));
+    }
+
+    #[test]
+    fn all_unusable_reports_zero() {
+        let report = check_invariants(&[[0.0; SLOT_DIM], [f32::INFINITY; SLOT_DIM]]);
+        assert_eq!(report.usable_count, 0);
+        assert_eq!(report.superpose_capacity, 0);
+        assert_eq!(report.unbind_recall, 0.0);
+        assert_eq!(report.flagged.len(), 2);
+
+        assert_eq!(check_invariants(&[]).usable_count, 0);
+    }
+
+    #[test]
+    fn superpose_capacity_is_bounded_for_random_vectors() {
+        // Constituent similarity decays like 1/sqrt(n), so a long enough
+        // random set must exceed the detection threshold's capacity.
+        let vectors: Vec<_> = (0..64).map(test_vector).collect();
+        let report = check_invariants(&vectors);
+        assert!(report.superpose_capacity > 0);
+        assert!(report.superpose_capacity < 64, "capacity {}", report.superpose_capacity);
+    }
+
+    #[test]
+    fn report_is_deterministic() {
+        let vectors: Vec<_> = (0..8).map(test_vector).collect();
+        assert_eq!(check_invariants(&vectors), check_invariants(&vectors));
+    }
+}
@@ -7,7 +7,8 @@
 //! - **Unbind** (⊗⁻¹): Inverse binding for content retrieval
 //! - **Superpose** (+): Additive superposition of multiple bindings
 //! - **Permute** (ρ): Role permutation for structural manipulation
-//! - **Similarity**: Cosine similarity between slot embeddings
+//! - **Similarity**: Cosine similarity between slot embeddings (with raw
+//!   dot product and Euclidean distance variants)
 //!
 //! ## HDC Background
 //!
@@ -26,6 +27,12 @@
 //! - `permute`: ~0.5-1µs (array rotation)
 //! - `similarity`: ~0.5-1µs (dot product)
 //!
+//! ## Features
+//!
+//! - `parallel`: run the per-slot loops of [`bind_frames`],
+//!   [`unbind_frames`], and [`similarity_frames`] on the rayon thread
+//!   pool. Results are bitwise identical to the default serial build.
+//!
 //! ## Example
 //!
 //! ```
@@ -62,10 +69,20 @@ pub use volt_core;
 mod fft;
 mod ops;
 mod batch;
+mod invariants;
 pub mod codebook;
 
 // Public API: Single-vector operations
-pub use ops::{bind, unbind, superpose, permute, similarity};
+pub use ops::{
+    bind, unbind, superpose, weighted_superpose, permute, similarity, similarity_auto, dot,
+    euclidean_distance, warm_up, SPARSE_MAX_NONZERO,
+};
 
 // Public API: Batch operations on TensorFrames
 pub use batch::{bind_frames, unbind_frames, similarity_frames};
+
+// Public API: Algebra invariant checks on a vector set
+pub use invariants::{
+    check_invariants, FlaggedVector, InvariantReport, InvariantViolation,
+    SHIFT_SIMILARITY_THRESHOLD, SUPERPOSE_DETECTION_THRESHOLD, UNBIND_RECALL_THRESHOLD,
+};
@@ -2,8 +2,11 @@
 //!
 //! This module implements the five fundamental operations for HDC:
 //! - **similarity**: Cosine similarity between vectors
+//!   (plus raw **dot** product and **euclidean_distance**, and
+//!   **similarity_auto**, which takes a sparse path for near-sparse inputs)
 //! - **permute**: Cyclic shift for role rotation
 //! - **superpose**: Additive superposition with normalization
+//!   (and **weighted_superpose** with per-vector weights)
 //! - **bind**: FFT-based circular convolution for role-filler binding
 //! - **unbind**: Approximate inverse of bind via circular correlation
 
@@ -45,6 +48,127 @@ pub fn similarity(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> f32 {
     dot / (norm_a * norm_b)
 }
 
+/// Most non-zero dimensions a vector may have for [`similarity_auto`] to
+/// treat it as sparse (1/8 of [`SLOT_DIM`]).
+pub const SPARSE_MAX_NONZERO: usize = SLOT_DIM / 8;
+
+/// Indices of the non-zero dimensions of `v`, or `None` once more than
+/// [`SPARSE_MAX_NONZERO`] are found. Dense vectors bail out early.
+fn sparse_support(v: &[f32; SLOT_DIM]) -> Option<([u16; SPARSE_MAX_NONZERO], usize)> {
+    let mut support = [0u16; SPARSE_MAX_NONZERO];
+    let mut len = 0;
+    for (i, &x) in v.iter().enumerate() {
+        if x != 0.0 {
+            if len == SPARSE_MAX_NONZERO {
+                return None;
+            }
+            support[len] = i as u16;
+            len += 1;
+        }
+    }
+    Some((support, len))
+}
+
+/// Sum of `f(i)` over the given dimensions, in ascending order.
+fn sum_over(support: &[u16], f: impl Fn(usize) -> f32) -> f32 {
+    support.iter().map(|&i| f(i as usize)).sum()
+}
+
+/// Cosine similarity like [`similarity`], with a sparse fast path.
+///
+/// When either vector has at most [`SPARSE_MAX_NONZERO`] non-zero
+/// dimensions (one-hot and few-hot codes), the dot product and that
+/// vector's norm only visit its non-zero dimensions. Otherwise this falls
+/// back to [`similarity`]; the sparsity check stops at the first
+/// `SPARSE_MAX_NONZERO + 1` non-zeros, so dense inputs pay only a short
+/// scan.
+///
+/// Results agree with [`similarity`] within float tolerance: skipped
+/// dimensions contribute exact zeros. The one difference is that a NaN or
+/// infinity in the other vector at a dimension where the sparse vector is
+/// zero is not propagated.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::{similarity, similarity_auto};
+/// use volt_core::SLOT_DIM;
+///
+/// let mut a = [0.0; SLOT_DIM];
+/// a[3] = 1.0;
+/// let b = [0.5; SLOT_DIM];
+/// assert!((similarity_auto(&a, &b) - similarity(&a, &b)).abs() < 1e-6);
+/// ```
+pub fn similarity_auto(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> f32 {
+    let (sparse, other, support) = match sparse_support(a) {
+        Some(support) => (a, b, support),
+        None => match sparse_support(b) {
+            Some(support) => (b, a, support),
+            None => return similarity(a, b),
+        },
+    };
+    let support = &support.0[..support.1];
+
+    let dot = sum_over(support, |i| sparse[i] * other[i]);
+    let norm_sparse = sum_over(support, |i| sparse[i] * sparse[i]).sqrt();
+    let norm_other = match sparse_support(other) {
+        Some((other_support, len)) => {
+            sum_over(&other_support[..len], |i| other[i] * other[i]).sqrt()
+        }
+        None => other.iter().map(|x| x * x).sum::<f32>().sqrt(),
+    };
+
+    if norm_sparse < 1e-10 || norm_other < 1e-10 {
+        return 0.0;
+    }
+
+    dot / (norm_sparse * norm_other)
+}
+
+/// Raw dot product between two 256-dimensional vectors.
+///
+/// Unlike [`similarity`] this is not normalized, so it also reflects
+/// vector magnitude. For unit-length inputs it equals cosine similarity.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::dot;
+/// use volt_core::SLOT_DIM;
+///
+/// let a = [1.0; SLOT_DIM];
+/// let b = [0.5; SLOT_DIM];
+/// assert!((dot(&a, &b) - 0.5 * SLOT_DIM as f32).abs() < 1e-3);
+/// ```
+pub fn dot(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Euclidean (L2) distance between two 256-dimensional vectors.
+///
+/// Returns 0.0 for identical vectors and grows without bound as they
+/// diverge.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::euclidean_distance;
+/// use volt_core::SLOT_DIM;
+///
+/// let a = [0.0; SLOT_DIM];
+/// let mut b = [0.0; SLOT_DIM];
+/// b[0] = 3.0;
+/// b[1] = 4.0;
+/// assert!((euclidean_distance(&a, &b) - 5.0).abs() < 1e-6);
+/// ```
+pub fn euclidean_distance(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> f32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f32>()
+        .sqrt()
+}
+
 /// Permute (cyclically shift) a vector by k positions.
 ///
 /// Positive k shifts right, negative k shifts left.
@@ -134,6 +258,73 @@ pub fn superpose(vectors: &[&[f32; SLOT_DIM]]) -> Result<[f32; SLOT_DIM], VoltEr
     Ok(result)
 }
 
+/// Weighted superposition: `Σ wᵢ·vᵢ`, then normalize to unit length.
+///
+/// Like [`superpose`], but each vector is scaled by its weight first, so
+/// heavier constituents (e.g. more certain source frames) dominate the
+/// result. Equal weights give the same direction as [`superpose`].
+///
+/// # Errors
+///
+/// Returns [`VoltError::BusError`] if:
+/// - Input slice is empty
+/// - Any weight is NaN or Inf
+/// - The weighted sum is zero or near-zero (e.g. all weights are 0)
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::{weighted_superpose, similarity};
+/// use volt_core::SLOT_DIM;
+///
+/// let mut a = [0.0; SLOT_DIM];
+/// a[0] = 1.0;
+/// let mut b = [0.0; SLOT_DIM];
+/// b[1] = 1.0;
+/// let composite = weighted_superpose(&[(a, 3.0), (b, 1.0)]).unwrap();
+///
+/// // The heavier constituent dominates
+/// assert!(similarity(&composite, &a) > similarity(&composite, &b));
+/// ```
+pub fn weighted_superpose(
+    vectors: &[([f32; SLOT_DIM], f32)],
+) -> Result<[f32; SLOT_DIM], VoltError> {
+    if vectors.is_empty() {
+        return Err(VoltError::BusError {
+            message: "weighted_superpose requires at least one vector".to_string(),
+        });
+    }
+    if let Some((i, (_, w))) = vectors.iter().enumerate().find(|(_, (_, w))| !w.is_finite()) {
+        return Err(VoltError::BusError {
+            message: format!("weighted_superpose: weight {i} is not finite ({w})"),
+        });
+    }
+
+    // Weighted element-wise sum
+    let mut result = [0.0; SLOT_DIM];
+    for (vec, weight) in vectors {
+        for (r, &v) in result.iter_mut().zip(vec.iter()) {
+            *r += weight * v;
+        }
+    }
+
+    // Check for zero result
+    let norm_sq: f32 = result.iter().map(|x| x * x).sum();
+    if norm_sq < 1e-10 {
+        return Err(VoltError::BusError {
+            message: "weighted superposition resulted in zero or near-zero vector".to_string(),
+        });
+    }
+
+    // L2 normalize to unit length
+    let norm = norm_sq.sqrt();
+    for x in &mut result {
+        *x /= norm;
+    }
+
+    Ok(result)
+}
+
 /// Bind two vectors via circular convolution (HDC role-filler binding).
 ///
 /// In HDC, bind(ROLE, FILLER) creates a compound representation where
@@ -217,6 +408,28 @@ pub fn unbind(c: &[f32; SLOT_DIM], a: &[f32; SLOT_DIM]) -> Result<[f32; SLOT_DIM
     Ok(result)
 }
 
+/// Pre-plans the FFTs behind [`bind`] and [`unbind`].
+///
+/// Plans are built lazily on the first bind/unbind and then shared by all
+/// threads; calling this at startup moves that one-off planning cost out
+/// of the first request. Calling it again is a no-op, and results are
+/// identical whether or not it was called.
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::{bind, warm_up};
+/// use volt_core::SLOT_DIM;
+///
+/// warm_up();
+/// let mut a = [0.0; SLOT_DIM];
+/// a[0] = 1.0;
+/// assert!(bind(&a, &a).is_ok());
+/// ```
+pub fn warm_up() {
+    crate::fft::warm_up();
+}
+
 /// Validate vector for algebra operations.
 ///
 /// Checks for:
@@ -294,6 +507,22 @@ mod tests {
         assert_eq!(similarity(&zero, &a), 0.0);
     }
 
+    #[test]
+    fn dot_equals_similarity_for_normalized_vectors() {
+        for seed in 0..10 {
+            let a = test_vector(seed);
+            let b = test_vector(seed + 100);
+            assert!((dot(&a, &b) - similarity(&a, &b)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn euclidean_distance_to_self_is_zero() {
+        let a = test_vector(42);
+        assert_eq!(euclidean_distance(&a, &a), 0.0);
+        assert!(euclidean_distance(&a, &test_vector(43)) > 0.0);
+    }
+
     #[test]
     fn permute_roundtrip() {
         let a = test_vector(42);
@@ -368,6 +597,38 @@ mod tests {
         assert!(result.is_err(), "Superpose of opposite vectors should error");
     }
 
+    #[test]
+    fn weighted_superpose_doubling_weight_shifts_toward_vector() {
+        let a = test_vector(42);
+        let b = test_vector(99);
+
+        let even = weighted_superpose(&[(a, 1.0), (b, 1.0)]).unwrap();
+        let skewed = weighted_superpose(&[(a, 2.0), (b, 1.0)]).unwrap();
+
+        let gain = similarity(&skewed, &a) - similarity(&even, &a);
+        assert!(gain > 0.1, "doubling a's weight should pull toward a, gain = {gain}");
+        assert!(similarity(&skewed, &b) < similarity(&even, &b));
+    }
+
+    #[test]
+    fn weighted_superpose_equal_weights_match_superpose() {
+        let a = test_vector(42);
+        let b = test_vector(99);
+
+        let weighted = weighted_superpose(&[(a, 0.7), (b, 0.7)]).unwrap();
+        let plain = superpose(&[&a, &b]).unwrap();
+        assert!((similarity(&weighted, &plain) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn weighted_superpose_rejects_empty_non_finite_and_zero() {
+        let a = test_vector(42);
+        assert!(weighted_superpose(&[]).is_err());
+        assert!(weighted_superpose(&[(a, f32::NAN)]).is_err());
+        assert!(weighted_superpose(&[(a, 1.0), (a, f32::INFINITY)]).is_err());
+        assert!(weighted_superpose(&[(a, 0.0)]).is_err());
+    }
+
     #[test]
     fn bind_unbind_recovers_original() {
         let a = test_vector(42);
@@ -419,6 +680,50 @@ mod tests {
         assert!(bind(&a, &zero).is_err());
     }
 
+    /// Unit vector with `hot` evenly spread non-zero dimensions.
+    fn few_hot(hot: usize, offset: usize) -> [f32; SLOT_DIM] {
+        let mut v = [0.0; SLOT_DIM];
+        for k in 0..hot {
+            v[(offset + k * (SLOT_DIM / hot)) % SLOT_DIM] = 1.0 + k as f32 * 0.1;
+        }
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        v.map(|x| x / norm)
+    }
+
+    #[test]
+    fn similarity_auto_matches_dense_on_mixed_inputs() {
+        let inputs = [
+            few_hot(1, 0),
+            few_hot(1, 7),
+            few_hot(4, 2),
+            few_hot(SPARSE_MAX_NONZERO, 0),
+            few_hot(SPARSE_MAX_NONZERO + 1, 1),
+            few_hot(128, 0),
+            test_vector(1),
+            test_vector(2),
+            [0.0; SLOT_DIM],
+        ];
+        for a in &inputs {
+            for b in &inputs {
+                let dense = similarity(a, b);
+                let auto = similarity_auto(a, b);
+                assert!(
+                    (dense - auto).abs() < 1e-6,
+                    "sparse path diverged: dense={dense}, auto={auto}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sparse_support_threshold() {
+        assert_eq!(sparse_support(&few_hot(3, 5)).map(|(_, len)| len), Some(3));
+        assert!(sparse_support(&few_hot(SPARSE_MAX_NONZERO, 0)).is_some());
+        assert!(sparse_support(&few_hot(SPARSE_MAX_NONZERO + 1, 0)).is_none());
+        assert!(sparse_support(&test_vector(3)).is_none());
+        assert_eq!(sparse_support(&[0.0; SLOT_DIM]).map(|(_, len)| len), Some(0));
+    }
+
     #[test]
     fn unbind_zero_vector_errors() {
         let a = test_vector(42);
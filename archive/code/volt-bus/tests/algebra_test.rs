@@ -255,3 +255,22 @@ fn bind_with_permuted_vectors() {
     let sim = similarity(&recovered, &perm_b);
     assert!(sim > 0.85, "Should recover permuted vector, got {}", sim);
 }
+
+#[test]
+fn ten_thousand_binds_within_wall_clock_bound() {
+    // Bind targets < 10µs (0.1s per 10k in release). Unoptimized debug
+    // builds run ~15x slower, so 10s leaves headroom for parallel load.
+    let a = normalized_test_vec(1);
+    let b = normalized_test_vec(2);
+    volt_bus::warm_up();
+
+    let start = std::time::Instant::now();
+    for _ in 0..10_000 {
+        std::hint::black_box(bind(std::hint::black_box(&a), &b).unwrap());
+    }
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < std::time::Duration::from_secs(10),
+        "10k binds took {elapsed:?}"
+    );
+}
@@ -0,0 +1,544 @@
+//! Compact binary encoding for [`TensorFrame`].
+//!
+//! [`TensorFrame::to_binary`] writes a little-endian layout that stores
+//! only the resolutions actually present, so a sparse frame costs a few
+//! hundred bytes instead of the tens of kilobytes its JSON form takes.
+//! The header starts with [`FRAME_BINARY_MAGIC`] and
+//! [`FRAME_BINARY_VERSION`], which lets readers tell binary frames apart
+//! from older JSON payloads.
+//!
+//! ## Layout (version 3)
+//!
+//! ```text
+//! magic:"VTF" | version:u8 | schema_version:u16
+//! frame_id:u64 | strand_id:u64 | created_at:u64 | global_certainty:f32
+//! discourse_type:u8 | verified:u8 | rar_iterations:u32 | proof_length:u32
+//! derived_count:u32 | derived_from:[u64; derived_count]
+//! has_ttl:u8 [| ttl_micros:u64]
+//! For each of the 16 slots:
+//!   certainty:f32 | source:u8 | updated_at:u64 | needs_verify:u8
+//! slot_presence:u16
+//! For each present slot:
+//!   role_tag:u8 | role_data:u8 | has_codebook:u8 [| codebook_id:u16]
+//!   resolution_presence:u8 | [f32; 256] per present resolution
+//! ```
+//!
+//! Version 2 is the same layout without the TTL fields, and version 1
+//! additionally lacks `schema_version`; such frames decode with no TTL
+//! (and schema version 1 for version 1) and are upgraded by
+//! [`TensorFrame::migrate`].
+
+use crate::error::VoltError;
+use crate::frame::TensorFrame;
+use crate::meta::DiscourseType;
+use crate::slot::{SlotData, SlotRole, SlotSource};
+use crate::{NUM_RESOLUTIONS, SLOT_DIM};
+
+/// Leading bytes of every binary-encoded frame.
+pub const FRAME_BINARY_MAGIC: [u8; 3] = *b"VTF";
+
+/// Current binary frame format version, written after the magic.
+pub const FRAME_BINARY_VERSION: u8 = 3;
+
+impl TensorFrame {
+    /// Encodes this frame in the compact binary format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// let mut slot = SlotData::new(SlotRole::Agent);
+    /// slot.write_resolution(0, [0.5; SLOT_DIM]);
+    /// frame.write_slot(0, slot).unwrap();
+    ///
+    /// let bytes = frame.to_binary();
+    /// let decoded = TensorFrame::from_binary(&bytes).unwrap();
+    /// assert_eq!(decoded.to_binary(), bytes);
+    /// ```
+    pub fn to_binary(&self) -> Vec<u8> {
+        let active: usize = self
+            .slots
+            .iter()
+            .flatten()
+            .map(|s| s.resolutions.iter().flatten().count())
+            .sum();
+        let mut buf = Vec::with_capacity(320 + active * SLOT_DIM * 4);
+
+        buf.extend_from_slice(&FRAME_BINARY_MAGIC);
+        buf.push(FRAME_BINARY_VERSION);
+
+        let fm = &self.frame_meta;
+        buf.extend_from_slice(&fm.schema_version.to_le_bytes());
+        buf.extend_from_slice(&fm.frame_id.to_le_bytes());
+        buf.extend_from_slice(&fm.strand_id.to_le_bytes());
+        buf.extend_from_slice(&fm.created_at.to_le_bytes());
+        buf.extend_from_slice(&fm.global_certainty.to_le_bytes());
+        buf.push(discourse_type_to_byte(fm.discourse_type));
+        buf.push(fm.verified as u8);
+        buf.extend_from_slice(&fm.rar_iterations.to_le_bytes());
+        buf.extend_from_slice(&fm.proof_length.to_le_bytes());
+        buf.extend_from_slice(&(fm.derived_from.len() as u32).to_le_bytes());
+        for id in &fm.derived_from {
+            buf.extend_from_slice(&id.to_le_bytes());
+        }
+        match fm.ttl_micros {
+            Some(ttl) => {
+                buf.push(1);
+                buf.extend_from_slice(&ttl.to_le_bytes());
+            }
+            None => buf.push(0),
+        }
+
+        // Slot metadata is kept for every slot: empty slots may still
+        // carry a certainty or source.
+        for meta in &self.meta {
+            buf.extend_from_slice(&meta.certainty.to_le_bytes());
+            buf.push(slot_source_to_byte(meta.source));
+            buf.extend_from_slice(&meta.updated_at.to_le_bytes());
+            buf.push(meta.needs_verify as u8);
+        }
+
+        let mut presence: u16 = 0;
+        for (i, slot) in self.slots.iter().enumerate() {
+            if slot.is_some() {
+                presence |= 1 << i;
+            }
+        }
+        buf.extend_from_slice(&presence.to_le_bytes());
+
+        for slot in self.slots.iter().flatten() {
+            let (tag, data) = slot_role_to_bytes(slot.role);
+            buf.push(tag);
+            buf.push(data);
+            if let Some(cb) = slot.codebook_id {
+                buf.push(1);
+                buf.extend_from_slice(&cb.to_le_bytes());
+            } else {
+                buf.push(0);
+            }
+
+            let mut res_presence: u8 = 0;
+            for (r, res) in slot.resolutions.iter().enumerate() {
+                if res.is_some() {
+                    res_presence |= 1 << r;
+                }
+            }
+            buf.push(res_presence);
+            for res in slot.resolutions.iter().flatten() {
+                for &v in res {
+                    buf.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
+
+        buf
+    }
+
+    /// Decodes a frame written by [`to_binary`](Self::to_binary).
+    ///
+    /// Frames in the version 1 layout decode with a schema version of 1;
+    /// this does not migrate them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::FrameError`] if the magic or version is not
+    /// recognized, the data is truncated, an enum tag is invalid, or
+    /// bytes remain after the frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::TensorFrame;
+    ///
+    /// assert!(TensorFrame::from_binary(b"{\"slots\":[]}").is_err());
+    /// ```
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, VoltError> {
+        let mut r = Reader { data: bytes, pos: 0 };
+
+        if r.take(3, "magic")? != FRAME_BINARY_MAGIC {
+            return Err(decode_error("bad magic"));
+        }
+        let version = r.u8("version")?;
+        if version == 0 || version > FRAME_BINARY_VERSION {
+            return Err(decode_error(&format!("unsupported version {version}")));
+        }
+
+        let mut frame = TensorFrame::new();
+        let fm = &mut frame.frame_meta;
+        fm.schema_version = if version >= 2 { r.u16("schema_version")? } else { 1 };
+        fm.frame_id = r.u64("frame_id")?;
+        fm.strand_id = r.u64("strand_id")?;
+        fm.created_at = r.u64("created_at")?;
+        fm.global_certainty = r.f32("global_certainty")?;
+        fm.discourse_type = discourse_type_from_byte(r.u8("discourse_type")?)
+            .ok_or_else(|| decode_error("discourse_type"))?;
+        fm.verified = r.u8("verified")? != 0;
+        fm.rar_iterations = r.u32("rar_iterations")?;
+        fm.proof_length = r.u32("proof_length")?;
+        let derived_count = r.u32("derived_count")? as usize;
+        if derived_count > r.remaining() / 8 {
+            return Err(decode_error("derived_from truncated"));
+        }
+        fm.derived_from = (0..derived_count)
+            .map(|_| r.u64("derived_from"))
+            .collect::<Result<_, _>>()?;
+        if version >= 3 && r.u8("ttl flag")? != 0 {
+            fm.ttl_micros = Some(r.u64("ttl_micros")?);
+        }
+
+        for meta in frame.meta.iter_mut() {
+            meta.certainty = r.f32("slot certainty")?;
+            meta.source = slot_source_from_byte(r.u8("slot source")?)
+                .ok_or_else(|| decode_error("slot source"))?;
+            meta.updated_at = r.u64("slot updated_at")?;
+            meta.needs_verify = r.u8("slot needs_verify")? != 0;
+        }
+
+        let presence = r.u16("slot presence")?;
+        for (i, slot_opt) in frame.slots.iter_mut().enumerate() {
+            if presence & (1 << i) == 0 {
+                continue;
+            }
+            let tag = r.u8("slot role")?;
+            let data = r.u8("slot role")?;
+            let role = slot_role_from_bytes(tag, data).ok_or_else(|| decode_error("slot role"))?;
+            let mut slot = SlotData::new(role);
+            if r.u8("codebook flag")? != 0 {
+                slot.codebook_id = Some(r.u16("codebook_id")?);
+            }
+            let res_presence = r.u8("resolution presence")?;
+            if res_presence >> NUM_RESOLUTIONS != 0 {
+                return Err(decode_error("resolution presence"));
+            }
+            for (res_idx, res) in slot.resolutions.iter_mut().enumerate() {
+                if res_presence & (1 << res_idx) != 0 {
+                    *res = Some(r.f32_array()?);
+                }
+            }
+            *slot_opt = Some(slot);
+        }
+
+        if r.remaining() != 0 {
+            return Err(decode_error("trailing bytes"));
+        }
+        Ok(frame)
+    }
+}
+
+fn decode_error(msg: &str) -> VoltError {
+    VoltError::FrameError {
+        message: format!("binary frame decode: {msg}"),
+    }
+}
+
+/// Cursor over an encoded frame.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn take(&mut self, n: usize, what: &str) -> Result<&'a [u8], VoltError> {
+        if self.remaining() < n {
+            return Err(decode_error(&format!("{what} truncated")));
+        }
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self, what: &str) -> Result<[u8; N], VoltError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N, what)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self, what: &str) -> Result<u8, VoltError> {
+        Ok(self.take(1, what)?[0])
+    }
+
+    fn u16(&mut self, what: &str) -> Result<u16, VoltError> {
+        self.array(what).map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self, what: &str) -> Result<u32, VoltError> {
+        self.array(what).map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self, what: &str) -> Result<u64, VoltError> {
+        self.array(what).map(u64::from_le_bytes)
+    }
+
+    fn f32(&mut self, what: &str) -> Result<f32, VoltError> {
+        self.array(what).map(f32::from_le_bytes)
+    }
+
+    fn f32_array(&mut self) -> Result<[f32; SLOT_DIM], VoltError> {
+        let bytes = self.take(SLOT_DIM * 4, "resolution")?;
+        let mut arr = [0.0f32; SLOT_DIM];
+        for (v, chunk) in arr.iter_mut().zip(bytes.chunks_exact(4)) {
+            *v = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        Ok(arr)
+    }
+}
+
+fn slot_role_to_bytes(role: SlotRole) -> (u8, u8) {
+    match role {
+        SlotRole::Agent => (0, 0),
+        SlotRole::Predicate => (1, 0),
+        SlotRole::Patient => (2, 0),
+        SlotRole::Location => (3, 0),
+        SlotRole::Time => (4, 0),
+        SlotRole::Manner => (5, 0),
+        SlotRole::Instrument => (6, 0),
+        SlotRole::Cause => (7, 0),
+        SlotRole::Result => (8, 0),
+        SlotRole::Free(n) => (9, n),
+    }
+}
+
+fn slot_role_from_bytes(tag: u8, data: u8) -> Option<SlotRole> {
+    match tag {
+        0 => Some(SlotRole::Agent),
+        1 => Some(SlotRole::Predicate),
+        2 => Some(SlotRole::Patient),
+        3 => Some(SlotRole::Location),
+        4 => Some(SlotRole::Time),
+        5 => Some(SlotRole::Manner),
+        6 => Some(SlotRole::Instrument),
+        7 => Some(SlotRole::Cause),
+        8 => Some(SlotRole::Result),
+        9 => Some(SlotRole::Free(data)),
+        _ => None,
+    }
+}
+
+fn discourse_type_to_byte(dt: DiscourseType) -> u8 {
+    match dt {
+        DiscourseType::Query => 0,
+        DiscourseType::Statement => 1,
+        DiscourseType::Command => 2,
+        DiscourseType::Response => 3,
+        DiscourseType::Creative => 4,
+        DiscourseType::Unknown => 5,
+    }
+}
+
+fn discourse_type_from_byte(b: u8) -> Option<DiscourseType> {
+    match b {
+        0 => Some(DiscourseType::Query),
+        1 => Some(DiscourseType::Statement),
+        2 => Some(DiscourseType::Command),
+        3 => Some(DiscourseType::Response),
+        4 => Some(DiscourseType::Creative),
+        5 => Some(DiscourseType::Unknown),
+        _ => None,
+    }
+}
+
+fn slot_source_to_byte(source: SlotSource) -> u8 {
+    match source {
+        SlotSource::Empty => 0,
+        SlotSource::Translator => 1,
+        SlotSource::SoftCore => 2,
+        SlotSource::HardCore => 3,
+        SlotSource::Memory => 4,
+        SlotSource::Personal => 5,
+        SlotSource::Redacted => 6,
+    }
+}
+
+fn slot_source_from_byte(b: u8) -> Option<SlotSource> {
+    match b {
+        0 => Some(SlotSource::Empty),
+        1 => Some(SlotSource::Translator),
+        2 => Some(SlotSource::SoftCore),
+        3 => Some(SlotSource::HardCore),
+        4 => Some(SlotSource::Memory),
+        5 => Some(SlotSource::Personal),
+        6 => Some(SlotSource::Redacted),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_SLOTS;
+
+    /// Frame with slot `i` holding the resolutions whose bits are set in
+    /// `masks[i]` (0 leaves the slot empty).
+    fn frame_with_occupancy(masks: &[u8; MAX_SLOTS]) -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        frame.frame_meta.frame_id = 77;
+        frame.frame_meta.strand_id = 3;
+        frame.frame_meta.created_at = 1_700_000_000_000_000;
+        frame.frame_meta.global_certainty = 0.625;
+        frame.frame_meta.discourse_type = DiscourseType::Creative;
+        frame.frame_meta.rar_iterations = 12;
+        frame.frame_meta.proof_length = 4;
+        frame.frame_meta.derived_from = vec![5, 9, u64::MAX];
+        for (i, &mask) in masks.iter().enumerate() {
+            if mask == 0 {
+                continue;
+            }
+            let mut slot = SlotData::new(if i % 2 == 0 {
+                SlotRole::Agent
+            } else {
+                SlotRole::Free(i as u8)
+            });
+            if i % 3 == 0 {
+                slot.codebook_id = Some(1000 + i as u16);
+            }
+            for r in 0..NUM_RESOLUTIONS {
+                if mask & (1 << r) != 0 {
+                    let mut v = [0.0f32; SLOT_DIM];
+                    for (d, x) in v.iter_mut().enumerate() {
+                        *x = (i * 1000 + r * 100 + d) as f32 * 0.001 - 1.0;
+                    }
+                    slot.resolutions[r] = Some(v);
+                }
+            }
+            frame.slots[i] = Some(slot);
+            frame.meta[i].certainty = 0.1 * i as f32;
+            frame.meta[i].source = SlotSource::SoftCore;
+            frame.meta[i].updated_at = i as u64;
+            frame.meta[i].needs_verify = i % 2 == 1;
+        }
+        frame
+    }
+
+    fn assert_frames_equal(a: &TensorFrame, b: &TensorFrame) {
+        assert_eq!(a.frame_meta.frame_id, b.frame_meta.frame_id);
+        assert_eq!(a.frame_meta.strand_id, b.frame_meta.strand_id);
+        assert_eq!(a.frame_meta.created_at, b.frame_meta.created_at);
+        assert_eq!(
+            a.frame_meta.global_certainty.to_bits(),
+            b.frame_meta.global_certainty.to_bits()
+        );
+        assert_eq!(a.frame_meta.discourse_type, b.frame_meta.discourse_type);
+        assert_eq!(a.frame_meta.verified, b.frame_meta.verified);
+        assert_eq!(a.frame_meta.rar_iterations, b.frame_meta.rar_iterations);
+        assert_eq!(a.frame_meta.proof_length, b.frame_meta.proof_length);
+        assert_eq!(a.frame_meta.derived_from, b.frame_meta.derived_from);
+        assert_eq!(a.frame_meta.ttl_micros, b.frame_meta.ttl_micros);
+        assert_eq!(a.frame_meta.schema_version, b.frame_meta.schema_version);
+        for i in 0..MAX_SLOTS {
+            assert_eq!(a.meta[i].certainty.to_bits(), b.meta[i].certainty.to_bits());
+            assert_eq!(a.meta[i].source, b.meta[i].source);
+            assert_eq!(a.meta[i].updated_at, b.meta[i].updated_at);
+            assert_eq!(a.meta[i].needs_verify, b.meta[i].needs_verify);
+            match (&a.slots[i], &b.slots[i]) {
+                (None, None) => {}
+                (Some(x), Some(y)) => {
+                    assert_eq!(x.role, y.role);
+                    assert_eq!(x.codebook_id, y.codebook_id);
+                    for r in 0..NUM_RESOLUTIONS {
+                        let bits = |res: &Option<[f32; SLOT_DIM]>| {
+                            res.map(|v| v.map(f32::to_bits))
+                        };
+                        assert_eq!(bits(&x.resolutions[r]), bits(&y.resolutions[r]));
+                    }
+                }
+                _ => panic!("slot {i} presence differs"),
+            }
+        }
+    }
+
+    #[test]
+    fn roundtrip_varying_occupancy() {
+        let cases: [[u8; MAX_SLOTS]; 4] = [
+            [0; MAX_SLOTS],
+            [0b0001, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0b1111; MAX_SLOTS],
+            [
+                0b0101, 0, 0b1000, 0b0011, 0, 0b1111, 0b0000, 0b0110, 0, 0, 0b0001, 0, 0b1010,
+                0, 0, 0b1000,
+            ],
+        ];
+        for masks in &cases {
+            let frame = frame_with_occupancy(masks);
+            let bytes = frame.to_binary();
+            let decoded = TensorFrame::from_binary(&bytes).unwrap();
+            assert_frames_equal(&frame, &decoded);
+            assert_eq!(decoded.to_binary(), bytes);
+        }
+    }
+
+    #[test]
+    fn size_scales_with_active_resolutions() {
+        let empty = TensorFrame::new().to_binary().len();
+        let mut masks = [0u8; MAX_SLOTS];
+        masks[4] = 0b0011;
+        let two = frame_with_occupancy(&masks).to_binary().len();
+        assert!(two - empty >= 2 * SLOT_DIM * 4);
+        assert!(two - empty < 2 * SLOT_DIM * 4 + 64);
+    }
+
+    #[test]
+    fn header_carries_magic_and_version() {
+        let bytes = TensorFrame::new().to_binary();
+        assert_eq!(&bytes[..3], &FRAME_BINARY_MAGIC);
+        assert_eq!(bytes[3], FRAME_BINARY_VERSION);
+
+        let mut future = bytes.clone();
+        future[3] = FRAME_BINARY_VERSION + 1;
+        assert!(TensorFrame::from_binary(&future).is_err());
+    }
+
+    #[test]
+    fn decodes_version_1_layout_as_schema_1() {
+        let mut masks = [0u8; MAX_SLOTS];
+        masks[0] = 0b0001;
+        let frame = frame_with_occupancy(&masks);
+        let bytes = frame.to_binary();
+        // Version 1 is the version 3 layout without the schema field
+        // and the TTL flag that follows the three `derived_from` IDs.
+        let ttl_flag = 6 + 3 * 8 + 4 + 2 + 3 * 4 + 3 * 8;
+        assert_eq!(bytes[ttl_flag], 0);
+        let mut v1 = bytes[..4].to_vec();
+        v1[3] = 1;
+        v1.extend_from_slice(&bytes[6..ttl_flag]);
+        v1.extend_from_slice(&bytes[ttl_flag + 1..]);
+
+        let mut decoded = TensorFrame::from_binary(&v1).unwrap();
+        assert_eq!(decoded.frame_meta.schema_version, 1);
+        assert!(decoded.migrate());
+        assert_frames_equal(&frame, &decoded);
+    }
+
+    #[test]
+    fn ttl_roundtrips_and_version_2_decodes_without_ttl() {
+        let mut frame = frame_with_occupancy(&[0b0001; MAX_SLOTS]);
+        frame.frame_meta.ttl_micros = Some(30_000_000);
+        let bytes = frame.to_binary();
+        assert_frames_equal(&frame, &TensorFrame::from_binary(&bytes).unwrap());
+
+        // Version 2 has no TTL flag; drop the flag and its value.
+        let ttl_flag = 6 + 3 * 8 + 4 + 2 + 3 * 4 + 3 * 8;
+        assert_eq!(bytes[ttl_flag], 1);
+        let mut v2 = bytes[..ttl_flag].to_vec();
+        v2[3] = 2;
+        v2.extend_from_slice(&bytes[ttl_flag + 9..]);
+        let decoded = TensorFrame::from_binary(&v2).unwrap();
+        assert_eq!(decoded.frame_meta.ttl_micros, None);
+    }
+
+    #[test]
+    fn rejects_truncated_and_trailing_bytes() {
+        let bytes = frame_with_occupancy(&[0b0001; MAX_SLOTS]).to_binary();
+        for len in [0, 3, 20, bytes.len() / 2, bytes.len() - 1] {
+            assert!(TensorFrame::from_binary(&bytes[..len]).is_err(), "len {len}");
+        }
+        let mut extra = bytes;
+        extra.push(0);
+        assert!(TensorFrame::from_binary(&extra).is_err());
+    }
+}
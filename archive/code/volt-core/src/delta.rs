@@ -0,0 +1,410 @@
+//! Frame deltas: the changes that turn one [`TensorFrame`] into another.
+//!
+//! Consecutive frames in a conversation strand usually share most of
+//! their slots. [`TensorFrame::diff`] records only what differs — per
+//! resolution vectors, slot roles and codebook IDs, slot metadata, and
+//! frame metadata — and [`TensorFrame::apply_delta`] replays it onto
+//! the base frame.
+
+use crate::error::VoltError;
+use crate::frame::TensorFrame;
+use crate::meta::FrameMeta;
+use crate::slot::{SlotData, SlotMeta, SlotRole};
+use crate::{MAX_SLOTS, NUM_RESOLUTIONS, SLOT_DIM};
+
+/// The changes from a base frame to a target frame.
+///
+/// Produced by [`TensorFrame::diff`]. Applying it to the base with
+/// [`TensorFrame::apply_delta`] yields the target exactly.
+///
+/// # Example
+///
+/// ```
+/// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+///
+/// let base = TensorFrame::new();
+/// let mut target = TensorFrame::new();
+/// let mut slot = SlotData::new(SlotRole::Agent);
+/// slot.write_resolution(0, [0.5; SLOT_DIM]);
+/// target.write_slot(0, slot).unwrap();
+///
+/// let delta = base.diff(&target);
+/// assert_eq!(delta.resolutions.len(), 1);
+///
+/// let mut rebuilt = base.clone();
+/// rebuilt.apply_delta(&delta).unwrap();
+/// assert_eq!(rebuilt.to_binary(), target.to_binary());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FrameDelta {
+    /// Slots that are empty in the target.
+    pub cleared_slots: Vec<usize>,
+    /// `(slot, role, codebook_id)` for slots that are new in the target
+    /// or whose role or codebook ID changed.
+    pub slot_headers: Vec<(usize, SlotRole, Option<u16>)>,
+    /// `(slot, resolution, vector)` for resolutions that are new or changed.
+    pub resolutions: Vec<(usize, usize, [f32; SLOT_DIM])>,
+    /// `(slot, resolution)` for resolutions that are empty in the target.
+    pub cleared_resolutions: Vec<(usize, usize)>,
+    /// `(slot, meta)` for slot metadata that changed.
+    pub meta: Vec<(usize, SlotMeta)>,
+    /// The target's frame metadata, if it differs from the base's.
+    pub frame_meta: Option<FrameMeta>,
+}
+
+impl FrameDelta {
+    /// Returns `true` if the delta records no changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::TensorFrame;
+    ///
+    /// let frame = TensorFrame::new();
+    /// assert!(frame.diff(&frame).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.cleared_slots.is_empty()
+            && self.slot_headers.is_empty()
+            && self.resolutions.is_empty()
+            && self.cleared_resolutions.is_empty()
+            && self.meta.is_empty()
+            && self.frame_meta.is_none()
+    }
+
+    /// Checks every index in the delta against `MAX_SLOTS` and `NUM_RESOLUTIONS`.
+    fn validate(&self) -> Result<(), VoltError> {
+        let slots = self
+            .cleared_slots
+            .iter()
+            .copied()
+            .chain(self.slot_headers.iter().map(|&(s, _, _)| s))
+            .chain(self.resolutions.iter().map(|&(s, _, _)| s))
+            .chain(self.cleared_resolutions.iter().map(|&(s, _)| s))
+            .chain(self.meta.iter().map(|&(s, _)| s));
+        for index in slots {
+            if index >= MAX_SLOTS {
+                return Err(VoltError::SlotOutOfRange {
+                    index,
+                    max: MAX_SLOTS,
+                });
+            }
+        }
+        let resolutions = self
+            .resolutions
+            .iter()
+            .map(|&(_, r, _)| r)
+            .chain(self.cleared_resolutions.iter().map(|&(_, r)| r));
+        for index in resolutions {
+            if index >= NUM_RESOLUTIONS {
+                return Err(VoltError::ResolutionOutOfRange {
+                    index,
+                    max: NUM_RESOLUTIONS,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TensorFrame {
+    /// Computes the changes that turn `self` into `other`.
+    ///
+    /// Vectors and certainties are compared bit for bit, so the delta
+    /// reproduces `other` exactly, including NaN payloads and signed zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    ///
+    /// let mut base = TensorFrame::new();
+    /// base.write_slot(0, SlotData::new(SlotRole::Agent)).unwrap();
+    /// let mut target = base.clone();
+    /// target.clear_slot(0).unwrap();
+    ///
+    /// let delta = base.diff(&target);
+    /// assert_eq!(delta.cleared_slots, vec![0]);
+    /// ```
+    pub fn diff(&self, other: &TensorFrame) -> FrameDelta {
+        let mut delta = FrameDelta::default();
+
+        for i in 0..MAX_SLOTS {
+            match (&self.slots[i], &other.slots[i]) {
+                (Some(_), None) => delta.cleared_slots.push(i),
+                (None, Some(to)) => {
+                    delta.slot_headers.push((i, to.role, to.codebook_id));
+                    for (r, res) in to.resolutions.iter().enumerate() {
+                        if let Some(v) = res {
+                            delta.resolutions.push((i, r, *v));
+                        }
+                    }
+                }
+                (Some(from), Some(to)) => {
+                    if from.role != to.role || from.codebook_id != to.codebook_id {
+                        delta.slot_headers.push((i, to.role, to.codebook_id));
+                    }
+                    for r in 0..NUM_RESOLUTIONS {
+                        match (&from.resolutions[r], &to.resolutions[r]) {
+                            (Some(_), None) => delta.cleared_resolutions.push((i, r)),
+                            (None, Some(v)) => delta.resolutions.push((i, r, *v)),
+                            (Some(a), Some(b)) if !vectors_identical(a, b) => {
+                                delta.resolutions.push((i, r, *b));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                (None, None) => {}
+            }
+
+            if !slot_meta_identical(&self.meta[i], &other.meta[i]) {
+                delta.meta.push((i, other.meta[i].clone()));
+            }
+        }
+
+        if !frame_meta_identical(&self.frame_meta, &other.frame_meta) {
+            delta.frame_meta = Some(other.frame_meta.clone());
+        }
+
+        delta
+    }
+
+    /// Applies a delta produced by [`diff`](Self::diff).
+    ///
+    /// Applied to the frame the delta was computed from, this yields the
+    /// diff target exactly. On error the frame is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SlotOutOfRange`] or
+    /// [`VoltError::ResolutionOutOfRange`] if the delta holds an invalid
+    /// index, and [`VoltError::EmptySlot`] if it writes a resolution of a
+    /// slot that is empty and not added by the delta.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole};
+    ///
+    /// let base = TensorFrame::new();
+    /// let mut target = TensorFrame::new();
+    /// target.write_slot(2, SlotData::new(SlotRole::Time)).unwrap();
+    ///
+    /// let mut frame = base.clone();
+    /// frame.apply_delta(&base.diff(&target)).unwrap();
+    /// assert_eq!(frame.read_slot(2).unwrap().role, SlotRole::Time);
+    /// ```
+    pub fn apply_delta(&mut self, delta: &FrameDelta) -> Result<(), VoltError> {
+        delta.validate()?;
+        let mut present: [bool; MAX_SLOTS] = std::array::from_fn(|i| self.slots[i].is_some());
+        for &i in &delta.cleared_slots {
+            present[i] = false;
+        }
+        for &(i, _, _) in &delta.slot_headers {
+            present[i] = true;
+        }
+        if let Some(&(index, _, _)) = delta.resolutions.iter().find(|&&(i, _, _)| !present[i]) {
+            return Err(VoltError::EmptySlot { index });
+        }
+
+        for &i in &delta.cleared_slots {
+            self.slots[i] = None;
+        }
+        for &(i, role, codebook_id) in &delta.slot_headers {
+            let slot = self.slots[i].get_or_insert_with(|| SlotData::new(role));
+            slot.role = role;
+            slot.codebook_id = codebook_id;
+        }
+        for &(i, r) in &delta.cleared_resolutions {
+            if let Some(slot) = self.slots[i].as_mut() {
+                slot.resolutions[r] = None;
+            }
+        }
+        for (i, r, v) in &delta.resolutions {
+            if let Some(slot) = self.slots[*i].as_mut() {
+                slot.resolutions[*r] = Some(*v);
+            }
+        }
+        for (i, meta) in &delta.meta {
+            self.meta[*i] = meta.clone();
+        }
+        if let Some(frame_meta) = &delta.frame_meta {
+            self.frame_meta = frame_meta.clone();
+        }
+        Ok(())
+    }
+}
+
+fn vectors_identical(a: &[f32; SLOT_DIM], b: &[f32; SLOT_DIM]) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| x.to_bits() == y.to_bits())
+}
+
+fn slot_meta_identical(a: &SlotMeta, b: &SlotMeta) -> bool {
+    a.certainty.to_bits() == b.certainty.to_bits()
+        && a.source == b.source
+        && a.updated_at == b.updated_at
+        && a.needs_verify == b.needs_verify
+}
+
+fn frame_meta_identical(a: &FrameMeta, b: &FrameMeta) -> bool {
+    a.frame_id == b.frame_id
+        && a.strand_id == b.strand_id
+        && a.global_certainty.to_bits() == b.global_certainty.to_bits()
+        && a.discourse_type == b.discourse_type
+        && a.created_at == b.created_at
+        && a.rar_iterations == b.rar_iterations
+        && a.verified == b.verified
+        && a.proof_length == b.proof_length
+        && a.derived_from == b.derived_from
+        && a.ttl_micros == b.ttl_micros
+        && a.schema_version == b.schema_version
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::DiscourseType;
+    use crate::slot::SlotSource;
+
+    fn vector(seed: usize) -> [f32; SLOT_DIM] {
+        std::array::from_fn(|d| ((seed * 31 + d) as f32 * 0.37).sin())
+    }
+
+    fn base_frame() -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        for i in 0..4 {
+            let mut slot = SlotData::new(SlotRole::Free(i as u8));
+            slot.write_resolution(0, vector(i));
+            slot.write_resolution(1, vector(i + 100));
+            frame.write_slot(i, slot).unwrap();
+            frame.meta[i].certainty = 0.5;
+            frame.meta[i].source = SlotSource::Translator;
+        }
+        frame.frame_meta.frame_id = 10;
+        frame.frame_meta.strand_id = 2;
+        frame
+    }
+
+    fn assert_roundtrip(base: &TensorFrame, target: &TensorFrame) -> FrameDelta {
+        let delta = base.diff(target);
+        let mut rebuilt = base.clone();
+        rebuilt.apply_delta(&delta).unwrap();
+        assert_eq!(rebuilt.to_binary(), target.to_binary());
+        delta
+    }
+
+    #[test]
+    fn diff_of_identical_frames_is_empty() {
+        let frame = base_frame();
+        assert!(frame.diff(&frame.clone()).is_empty());
+        assert!(TensorFrame::new().diff(&TensorFrame::new()).is_empty());
+    }
+
+    #[test]
+    fn slot_added() {
+        let base = base_frame();
+        let mut target = base.clone();
+        let mut slot = SlotData::new(SlotRole::Location);
+        slot.write_resolution(2, vector(7));
+        slot.codebook_id = Some(12);
+        target.write_slot(9, slot).unwrap();
+
+        let delta = assert_roundtrip(&base, &target);
+        assert_eq!(delta.slot_headers, vec![(9, SlotRole::Location, Some(12))]);
+        assert_eq!(delta.resolutions.len(), 1);
+        assert!(delta.frame_meta.is_none());
+    }
+
+    #[test]
+    fn slot_cleared() {
+        let base = base_frame();
+        let mut target = base.clone();
+        target.clear_slot(1).unwrap();
+
+        let delta = assert_roundtrip(&base, &target);
+        assert_eq!(delta.cleared_slots, vec![1]);
+        assert!(delta.resolutions.is_empty());
+    }
+
+    #[test]
+    fn role_changed_and_resolutions_edited() {
+        let base = base_frame();
+        let mut target = base.clone();
+        let slot = target.slots[2].as_mut().unwrap();
+        slot.role = SlotRole::Cause;
+        slot.resolutions[1] = None;
+        slot.resolutions[3] = Some(vector(42));
+        target.slots[3].as_mut().unwrap().resolutions[0].as_mut().unwrap()[5] += 1.0;
+
+        let delta = assert_roundtrip(&base, &target);
+        assert_eq!(delta.slot_headers, vec![(2, SlotRole::Cause, None)]);
+        assert_eq!(delta.cleared_resolutions, vec![(2, 1)]);
+        assert_eq!(delta.resolutions.len(), 2);
+    }
+
+    #[test]
+    fn meta_only_change() {
+        let base = base_frame();
+        let mut target = base.clone();
+        target.meta[0].certainty = 0.9;
+        target.meta[12].needs_verify = true;
+        target.frame_meta.discourse_type = DiscourseType::Response;
+        target.frame_meta.derived_from = vec![3, 4];
+
+        let delta = assert_roundtrip(&base, &target);
+        assert!(delta.slot_headers.is_empty());
+        assert!(delta.resolutions.is_empty());
+        assert_eq!(delta.meta.len(), 2);
+        assert!(delta.frame_meta.is_some());
+    }
+
+    #[test]
+    fn ttl_only_change_is_kept() {
+        let base = base_frame();
+        let mut target = base.clone();
+        target.frame_meta.ttl_micros = Some(5_000_000);
+
+        let delta = assert_roundtrip(&base, &target);
+        assert!(!delta.is_empty());
+        assert_eq!(delta.frame_meta.unwrap().ttl_micros, Some(5_000_000));
+    }
+
+    #[test]
+    fn schema_version_only_change_is_kept() {
+        let mut base = base_frame();
+        base.frame_meta.schema_version = 1;
+        let mut target = base.clone();
+        target.frame_meta.schema_version = crate::meta::FRAME_SCHEMA_VERSION;
+
+        let delta = assert_roundtrip(&base, &target);
+        assert!(!delta.is_empty());
+        assert_eq!(
+            delta.frame_meta.unwrap().schema_version,
+            crate::meta::FRAME_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn invalid_delta_leaves_frame_unchanged() {
+        let mut frame = base_frame();
+        let before = frame.to_binary();
+
+        let mut delta = FrameDelta::default();
+        delta.cleared_slots.push(0);
+        delta.resolutions.push((MAX_SLOTS, 0, vector(1)));
+        assert!(matches!(
+            frame.apply_delta(&delta),
+            Err(VoltError::SlotOutOfRange { .. })
+        ));
+
+        let mut delta = FrameDelta::default();
+        delta.cleared_slots.push(0);
+        delta.resolutions.push((0, 1, vector(1)));
+        assert!(matches!(
+            frame.apply_delta(&delta),
+            Err(VoltError::EmptySlot { index: 0 })
+        ));
+        assert_eq!(frame.to_binary(), before);
+    }
+}
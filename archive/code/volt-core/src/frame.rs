@@ -5,8 +5,8 @@
 //! inspectable, composable, multi-resolution representations of thoughts.
 
 use crate::error::VoltError;
-use crate::meta::FrameMeta;
-use crate::slot::{SlotData, SlotMeta};
+use crate::meta::{DiscourseType, FrameMeta, FRAME_SCHEMA_VERSION};
+use crate::slot::{SlotData, SlotMeta, SlotRole, SlotSource};
 use crate::{MAX_SLOTS, NUM_RESOLUTIONS, SLOT_DIM};
 
 /// The fundamental unit of thought in Volt X.
@@ -71,6 +71,44 @@ impl TensorFrame {
         Self::default()
     }
 
+    /// Upgrades a frame persisted under an older schema to
+    /// [`FRAME_SCHEMA_VERSION`](crate::FRAME_SCHEMA_VERSION).
+    ///
+    /// Each step fills the fields introduced by the next version with
+    /// their defaults. Returns `true` if the frame was upgraded; frames
+    /// already at (or, from a newer build, past) the current version are
+    /// left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, FRAME_SCHEMA_VERSION};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// assert!(!frame.migrate());
+    ///
+    /// frame.frame_meta.schema_version = 1;
+    /// assert!(frame.migrate());
+    /// assert_eq!(frame.frame_meta.schema_version, FRAME_SCHEMA_VERSION);
+    /// ```
+    pub fn migrate(&mut self) -> bool {
+        let from = self.frame_meta.schema_version;
+        if from >= FRAME_SCHEMA_VERSION {
+            return false;
+        }
+        if from < 2 {
+            // v2 adds `schema_version` itself; the other fields a v1
+            // frame can lack (`derived_from`) were defaulted on decode.
+            self.frame_meta.schema_version = 2;
+        }
+        if self.frame_meta.schema_version < 3 {
+            // v3 adds `ttl_micros`; older frames never expire.
+            self.frame_meta.ttl_micros = None;
+            self.frame_meta.schema_version = 3;
+        }
+        true
+    }
+
     /// Returns `true` if all slots are empty.
     ///
     /// # Example
@@ -223,6 +261,141 @@ impl TensorFrame {
         }
     }
 
+    /// Derives a frame-level certainty from active slot gammas under `rule`.
+    ///
+    /// A frame with no active slots has certainty 0.0 under every rule:
+    /// nothing has been asserted, so nothing is certain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{CertaintyRule, SlotData, SlotRole, TensorFrame};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_slot(0, SlotData::new(SlotRole::Agent)).unwrap();
+    /// frame.meta[0].certainty = 0.5;
+    /// frame.write_slot(1, SlotData::new(SlotRole::Predicate)).unwrap();
+    /// frame.meta[1].certainty = 0.8;
+    ///
+    /// assert_eq!(frame.derived_certainty(CertaintyRule::Min), 0.5);
+    /// assert!((frame.derived_certainty(CertaintyRule::Product) - 0.4).abs() < 1e-6);
+    /// assert_eq!(TensorFrame::new().derived_certainty(CertaintyRule::Min), 0.0);
+    /// ```
+    pub fn derived_certainty(&self, rule: CertaintyRule) -> f32 {
+        let mut min = f32::MAX;
+        let mut product = 1.0f32;
+        let mut weighted_sum = 0.0f32;
+        let mut weight_total = 0.0f32;
+        let mut active = 0usize;
+
+        for (slot, meta) in self.slots.iter().zip(self.meta.iter()) {
+            let Some(slot) = slot else { continue };
+            let gamma = meta.certainty;
+            active += 1;
+            min = min.min(gamma);
+            product *= gamma;
+            let weight = slot.resolutions.iter().filter(|r| r.is_some()).count().max(1) as f32;
+            weighted_sum += weight * gamma;
+            weight_total += weight;
+        }
+
+        if active == 0 {
+            return 0.0;
+        }
+        match rule {
+            CertaintyRule::Min => min,
+            CertaintyRule::Product => product,
+            CertaintyRule::WeightedMean => weighted_sum / weight_total,
+        }
+    }
+
+    /// Recomputes `frame_meta.global_certainty` from active slot gammas.
+    ///
+    /// Call this after any mutation that adds, removes, or re-scores
+    /// slots so the frame-level γ does not go stale. Returns the new
+    /// value; see [`derived_certainty`](Self::derived_certainty) for how
+    /// each rule combines slots and for the empty-frame value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{CertaintyRule, SlotData, SlotRole, TensorFrame};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_slot(0, SlotData::new(SlotRole::Agent)).unwrap();
+    /// frame.meta[0].certainty = 0.7;
+    ///
+    /// let gamma = frame.recompute_global_certainty(CertaintyRule::Min);
+    /// assert_eq!(gamma, 0.7);
+    /// assert_eq!(frame.frame_meta.global_certainty, 0.7);
+    /// ```
+    pub fn recompute_global_certainty(&mut self, rule: CertaintyRule) -> f32 {
+        let gamma = self.derived_certainty(rule);
+        self.frame_meta.global_certainty = gamma;
+        gamma
+    }
+
+    /// Checks the frame's integrity before it enters memory or the pipeline.
+    ///
+    /// A frame is valid when every resolution vector is finite, every
+    /// `meta[i].certainty` and the frame's `global_certainty` lie in
+    /// `[0.0, 1.0]`, and every slot holding resolution data has a source
+    /// other than [`SlotSource::Empty`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::FrameError`] describing the first problem found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_core::slot::SlotSource;
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// let mut slot = SlotData::new(SlotRole::Agent);
+    /// slot.write_resolution(0, [0.5; SLOT_DIM]);
+    /// frame.write_slot(0, slot).unwrap();
+    /// assert!(frame.validate().is_err()); // no source recorded yet
+    ///
+    /// frame.meta[0].source = SlotSource::Translator;
+    /// assert!(frame.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), VoltError> {
+        let invalid = |message: String| Err(VoltError::FrameError { message });
+
+        let gamma = self.frame_meta.global_certainty;
+        if !(0.0..=1.0).contains(&gamma) {
+            return invalid(format!("global certainty {gamma} outside [0, 1]"));
+        }
+        for (i, meta) in self.meta.iter().enumerate() {
+            if !(0.0..=1.0).contains(&meta.certainty) {
+                return invalid(format!(
+                    "slot {i} certainty {} outside [0, 1]",
+                    meta.certainty
+                ));
+            }
+        }
+        for (i, slot) in self.slots.iter().enumerate() {
+            let Some(slot) = slot else { continue };
+            let mut has_data = false;
+            for (r, res) in slot.resolutions.iter().enumerate() {
+                let Some(vector) = res else { continue };
+                has_data = true;
+                if let Some(d) = vector.iter().position(|v| !v.is_finite()) {
+                    return invalid(format!(
+                        "slot {i} resolution {r} has non-finite value {} at dim {d}",
+                        vector[d]
+                    ));
+                }
+            }
+            if has_data && self.meta[i].source == SlotSource::Empty {
+                return invalid(format!("slot {i} has resolution data but no source"));
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the approximate memory size in bytes of the populated data.
     ///
     /// # Example
@@ -253,6 +426,48 @@ impl TensorFrame {
             .sum()
     }
 
+    /// Clones the frame keeping only the listed resolutions.
+    ///
+    /// Every occupied slot is kept with its role, codebook ID, and
+    /// metadata, but resolutions not in `resolutions` are `None`. Use this
+    /// on indexing and storage paths that only read R₀, instead of copying
+    /// the full 64KB frame. Indices `>= NUM_RESOLUTIONS` are ignored, and
+    /// passing all four is equivalent to [`clone`](Clone::clone).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotRole, SLOT_DIM};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_at(0, 0, SlotRole::Agent, [0.1; SLOT_DIM]).unwrap();
+    /// frame.write_at(0, 3, SlotRole::Agent, [0.2; SLOT_DIM]).unwrap();
+    ///
+    /// let gist = frame.clone_resolutions(&[0]);
+    /// let slot = gist.read_slot(0).unwrap();
+    /// assert!(slot.resolutions[0].is_some());
+    /// assert!(slot.resolutions[3].is_none());
+    /// assert_eq!(gist.data_size_bytes(), frame.data_size_bytes() / 2);
+    /// ```
+    pub fn clone_resolutions(&self, resolutions: &[usize]) -> TensorFrame {
+        let mut keep = [false; NUM_RESOLUTIONS];
+        for &r in resolutions.iter().filter(|&&r| r < NUM_RESOLUTIONS) {
+            keep[r] = true;
+        }
+
+        TensorFrame {
+            slots: std::array::from_fn(|i| {
+                self.slots[i].as_ref().map(|slot| SlotData {
+                    resolutions: std::array::from_fn(|r| slot.resolutions[r].filter(|_| keep[r])),
+                    role: slot.role,
+                    codebook_id: slot.codebook_id,
+                })
+            }),
+            meta: self.meta.clone(),
+            frame_meta: self.frame_meta.clone(),
+        }
+    }
+
     /// Writes a raw embedding at a specific slot and resolution.
     ///
     /// Creates the slot with the given role if it doesn't exist.
@@ -349,11 +564,71 @@ impl TensorFrame {
 
         // Merge frame metadata
         merged.frame_meta = Self::merge_frame_meta(&self.frame_meta, &other.frame_meta);
-        merged.frame_meta.global_certainty = merged.min_certainty().unwrap_or(0.0);
+        merged.recompute_global_certainty(CertaintyRule::Min);
 
         merged
     }
 
+    /// Returns a new frame with slots moved according to a permutation.
+    ///
+    /// The slot at index `i` (together with its [`SlotMeta`]) is moved to
+    /// index `mapping[i]`. Empty slots are moved too, so `mapping` must be a
+    /// bijection over all `MAX_SLOTS` indices. Slot roles are not changed —
+    /// only their positions — which makes this useful for role-permutation
+    /// data augmentation and for testing role-invariance of downstream
+    /// components. Frame-level metadata is copied unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SlotOutOfRange`] if any target index is `>= MAX_SLOTS`.
+    /// Returns [`VoltError::FrameError`] if two source slots map to the same target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, MAX_SLOTS};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_slot(0, SlotData::new(SlotRole::Agent)).unwrap();
+    ///
+    /// // Swap slots 0 and 1
+    /// let mut mapping: [usize; MAX_SLOTS] = std::array::from_fn(|i| i);
+    /// mapping.swap(0, 1);
+    ///
+    /// let rotated = frame.rotate_slots(&mapping).unwrap();
+    /// assert!(rotated.read_slot(0).is_err());
+    /// assert_eq!(rotated.read_slot(1).unwrap().role, SlotRole::Agent);
+    /// ```
+    pub fn rotate_slots(&self, mapping: &[usize; MAX_SLOTS]) -> Result<TensorFrame, VoltError> {
+        let mut seen = [false; MAX_SLOTS];
+        for (source, &target) in mapping.iter().enumerate() {
+            if target >= MAX_SLOTS {
+                return Err(VoltError::SlotOutOfRange {
+                    index: target,
+                    max: MAX_SLOTS,
+                });
+            }
+            if seen[target] {
+                return Err(VoltError::FrameError {
+                    message: format!(
+                        "slot mapping is not a permutation: target {} is reused (source slot {})",
+                        target, source
+                    ),
+                });
+            }
+            seen[target] = true;
+        }
+
+        let mut rotated = TensorFrame::new();
+        for (source, &target) in mapping.iter().enumerate() {
+            rotated.slots[target] = self.slots[source].clone();
+            rotated.meta[target] = self.meta[source].clone();
+        }
+        rotated.frame_meta = self.frame_meta.clone();
+
+        Ok(rotated)
+    }
+
     /// Merges frame metadata from two frames.
     fn merge_frame_meta(left: &FrameMeta, right: &FrameMeta) -> FrameMeta {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -372,6 +647,9 @@ impl TensorFrame {
             rar_iterations: left.rar_iterations + right.rar_iterations,
             verified: false, // Merged frames need re-verification
             proof_length: left.proof_length.max(right.proof_length),
+            derived_from: Vec::new(),
+            ttl_micros: None,
+            schema_version: FRAME_SCHEMA_VERSION,
         }
     }
 
@@ -457,8 +735,9 @@ impl TensorFrame {
 
     /// Normalizes all populated resolutions in all active slots.
     ///
-    /// Skips empty slots and empty resolutions. If any normalization fails,
-    /// returns early with the error (partial normalization may occur).
+    /// Skips empty slots, redacted slots and empty resolutions. If any
+    /// normalization fails, returns early with the error (partial
+    /// normalization may occur).
     ///
     /// # Example
     ///
@@ -476,7 +755,7 @@ impl TensorFrame {
     /// ```
     pub fn normalize_all(&mut self) -> Result<(), VoltError> {
         for slot_idx in 0..MAX_SLOTS {
-            if self.slots[slot_idx].is_some() {
+            if self.slots[slot_idx].is_some() && !self.is_redacted(slot_idx) {
                 for res_idx in 0..NUM_RESOLUTIONS {
                     let has_resolution = self.slots[slot_idx]
                         .as_ref()
@@ -489,12 +768,267 @@ impl TensorFrame {
         }
         Ok(())
     }
+
+    /// Lists every populated resolution whose L2 norm differs from 1.0 by
+    /// more than [`NORMALIZATION_EPSILON`], as `(slot, resolution, norm)`.
+    ///
+    /// RAR and the HDC operations assume unit vectors, so a non-empty
+    /// report means the frame should be normalized (see
+    /// [`normalize_all`](Self::normalize_all)) before inference. Redacted
+    /// slots are skipped. A fully normalized frame yields an empty `Vec`,
+    /// which does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotRole, SLOT_DIM};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_at(0, 0, SlotRole::Agent, [2.0; SLOT_DIM]).unwrap();
+    /// let report = frame.normalization_report();
+    /// assert_eq!(report.len(), 1);
+    /// assert_eq!((report[0].0, report[0].1), (0, 0));
+    ///
+    /// frame.normalize_all().unwrap();
+    /// assert!(frame.normalization_report().is_empty());
+    /// ```
+    pub fn normalization_report(&self) -> Vec<(usize, usize, f32)> {
+        let mut report = Vec::new();
+        for (slot_idx, slot) in self.slots.iter().enumerate() {
+            let Some(slot) = slot else { continue };
+            if self.is_redacted(slot_idx) {
+                continue;
+            }
+            for (res_idx, res) in slot.resolutions.iter().enumerate() {
+                let Some(vec) = res else { continue };
+                let norm = vec.iter().map(|x| x * x).sum::<f32>().sqrt();
+                // NaN norms fail the comparison and are reported too.
+                let normalized = (norm - 1.0).abs() <= NORMALIZATION_EPSILON;
+                if !normalized {
+                    report.push((slot_idx, res_idx, norm));
+                }
+            }
+        }
+        report
+    }
+
+    /// Returns a deterministic 64-bit hash of the frame's content.
+    ///
+    /// Covers each active slot's index, role, codebook ID and resolution
+    /// vectors, plus the frame's discourse type. Volatile fields
+    /// (`frame_id`, `strand_id`, timestamps, certainties and other
+    /// per-slot or pipeline metadata) are ignored, so the same thought
+    /// stored twice hashes identically.
+    ///
+    /// Floats are hashed by bit pattern, so `0.0` and `-0.0` differ and
+    /// NaN payloads are significant. The hash is FNV-1a over
+    /// little-endian bytes and is stable across runs and platforms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotRole, SLOT_DIM};
+    ///
+    /// let mut a = TensorFrame::new();
+    /// a.write_at(0, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+    /// let mut b = a.clone();
+    /// b.frame_meta.frame_id = 42;
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    ///
+    /// b.write_at(1, 0, SlotRole::Predicate, [0.5; SLOT_DIM]).unwrap();
+    /// assert_ne!(a.content_hash(), b.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+        for (index, slot) in self.slots.iter().enumerate() {
+            let Some(slot) = slot else { continue };
+            hasher.write(&[index as u8]);
+            hasher.write(&role_tag(slot.role));
+            match slot.codebook_id {
+                Some(id) => {
+                    hasher.write(&[1]);
+                    hasher.write(&id.to_le_bytes());
+                }
+                None => hasher.write(&[0]),
+            }
+            for (res, data) in slot.resolutions.iter().enumerate() {
+                let Some(data) = data else { continue };
+                hasher.write(&[res as u8]);
+                for x in data {
+                    hasher.write(&x.to_bits().to_le_bytes());
+                }
+            }
+        }
+        hasher.write(&[discourse_tag(self.frame_meta.discourse_type)]);
+        hasher.finish()
+    }
+
+    /// Irreversibly wipes the content of the given slots.
+    ///
+    /// Every populated resolution of each listed slot is overwritten with
+    /// zeros in place and its codebook ID is cleared, so the original
+    /// vector cannot be recovered from the frame. The slot keeps its role
+    /// and certainty and is marked [`SlotSource::Redacted`], which
+    /// translators decode as [`REDACTED_PLACEHOLDER`]. Empty slots are
+    /// left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SlotOutOfRange`] if any index is
+    /// `>= MAX_SLOTS`; in that case no slot is modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotRole, SLOT_DIM};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_at(0, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+    /// frame.redact_slots(&[0]).unwrap();
+    ///
+    /// assert!(frame.is_redacted(0));
+    /// assert_eq!(frame.read_slot(0).unwrap().resolutions[0], Some([0.0; SLOT_DIM]));
+    /// ```
+    pub fn redact_slots(&mut self, slots: &[usize]) -> Result<(), VoltError> {
+        if let Some(&index) = slots.iter().find(|&&i| i >= MAX_SLOTS) {
+            return Err(VoltError::SlotOutOfRange {
+                index,
+                max: MAX_SLOTS,
+            });
+        }
+        for &index in slots {
+            let Some(slot) = self.slots[index].as_mut() else {
+                continue;
+            };
+            for data in slot.resolutions.iter_mut().flatten() {
+                data.fill(0.0);
+            }
+            slot.codebook_id = None;
+            self.meta[index].source = SlotSource::Redacted;
+            self.meta[index].needs_verify = false;
+        }
+        Ok(())
+    }
+
+    /// Redacts every active slot with the given role.
+    ///
+    /// Returns the number of slots redacted. See [`redact_slots`](Self::redact_slots).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotRole, SLOT_DIM};
+    ///
+    /// let mut frame = TensorFrame::new();
+    /// frame.write_at(0, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+    /// frame.write_at(1, 0, SlotRole::Predicate, [0.5; SLOT_DIM]).unwrap();
+    ///
+    /// assert_eq!(frame.redact_by_role(SlotRole::Agent), 1);
+    /// assert!(frame.is_redacted(0));
+    /// assert!(!frame.is_redacted(1));
+    /// ```
+    pub fn redact_by_role(&mut self, role: SlotRole) -> usize {
+        let matching: Vec<usize> = (0..MAX_SLOTS)
+            .filter(|&i| self.slots[i].as_ref().is_some_and(|slot| slot.role == role))
+            .collect();
+        // Indices come from 0..MAX_SLOTS, so this cannot fail.
+        let _ = self.redact_slots(&matching);
+        matching.len()
+    }
+
+    /// Returns true if the slot at `index` has been redacted.
+    ///
+    /// Out-of-range indices return false.
+    pub fn is_redacted(&self, index: usize) -> bool {
+        index < MAX_SLOTS
+            && self.slots[index].is_some()
+            && self.meta[index].source == SlotSource::Redacted
+    }
+}
+
+/// Text that translators emit for a redacted slot.
+pub const REDACTED_PLACEHOLDER: &str = "[redacted]";
+
+/// Tolerance on `|‖v‖₂ - 1|` below which
+/// [`TensorFrame::normalization_report`] treats a vector as normalized.
+pub const NORMALIZATION_EPSILON: f32 = 1e-4;
+
+/// How active slot gammas combine into a frame's global certainty.
+///
+/// Mirrors the non-override options of the hard core's certainty engine,
+/// so a frame recomputed here agrees with one propagated there.
+///
+/// # Example
+///
+/// ```
+/// use volt_core::CertaintyRule;
+///
+/// assert_eq!(CertaintyRule::default(), CertaintyRule::Min);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CertaintyRule {
+    /// The least certain active slot bounds the frame.
+    #[default]
+    Min,
+    /// Mean of active slot gammas, each weighted by the number of
+    /// resolutions the slot has filled (at least 1).
+    WeightedMean,
+    /// Product of active slot gammas.
+    Product,
+}
+
+/// Minimal FNV-1a hasher; unlike `DefaultHasher`, its output is fixed
+/// across Rust versions.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= u64::from(b);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Stable byte encoding of a slot role for hashing.
+fn role_tag(role: SlotRole) -> [u8; 2] {
+    match role {
+        SlotRole::Agent => [0, 0],
+        SlotRole::Predicate => [1, 0],
+        SlotRole::Patient => [2, 0],
+        SlotRole::Location => [3, 0],
+        SlotRole::Time => [4, 0],
+        SlotRole::Manner => [5, 0],
+        SlotRole::Instrument => [6, 0],
+        SlotRole::Cause => [7, 0],
+        SlotRole::Result => [8, 0],
+        SlotRole::Free(n) => [9, n],
+    }
+}
+
+/// Stable byte encoding of a discourse type for hashing.
+fn discourse_tag(discourse: DiscourseType) -> u8 {
+    match discourse {
+        DiscourseType::Query => 0,
+        DiscourseType::Statement => 1,
+        DiscourseType::Command => 2,
+        DiscourseType::Response => 3,
+        DiscourseType::Creative => 4,
+        DiscourseType::Unknown => 5,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::slot::SlotRole;
 
     #[test]
     fn new_frame_is_empty() {
@@ -677,6 +1211,119 @@ mod tests {
         assert_eq!(merged.frame_meta.global_certainty, 0.78);
     }
 
+    #[test]
+    fn recompute_global_certainty_under_each_rule() {
+        let mut frame = TensorFrame::new();
+        let mut agent = SlotData::new(SlotRole::Agent);
+        agent.write_resolution(0, [0.1; SLOT_DIM]);
+        agent.write_resolution(1, [0.1; SLOT_DIM]);
+        agent.write_resolution(2, [0.1; SLOT_DIM]);
+        frame.write_slot(0, agent).unwrap();
+        frame.meta[0].certainty = 0.8;
+        frame.write_slot(1, SlotData::new(SlotRole::Predicate)).unwrap();
+        frame.meta[1].certainty = 0.4;
+
+        assert_eq!(frame.recompute_global_certainty(CertaintyRule::Min), 0.4);
+        assert_eq!(frame.frame_meta.global_certainty, 0.4);
+
+        // Weights: 3 resolutions for slot 0, none (floored to 1) for slot 1.
+        let mean = frame.recompute_global_certainty(CertaintyRule::WeightedMean);
+        assert!((mean - (3.0 * 0.8 + 0.4) / 4.0).abs() < 1e-6);
+        assert_eq!(frame.frame_meta.global_certainty, mean);
+
+        let product = frame.recompute_global_certainty(CertaintyRule::Product);
+        assert!((product - 0.32).abs() < 1e-6);
+        assert_eq!(frame.frame_meta.global_certainty, product);
+    }
+
+    #[test]
+    fn recompute_global_certainty_of_empty_frame_is_zero() {
+        for rule in [CertaintyRule::Min, CertaintyRule::WeightedMean, CertaintyRule::Product] {
+            let mut frame = TensorFrame::new();
+            frame.frame_meta.global_certainty = 0.9;
+            assert_eq!(frame.recompute_global_certainty(rule), 0.0);
+            assert_eq!(frame.frame_meta.global_certainty, 0.0);
+        }
+    }
+
+    fn identity_mapping() -> [usize; MAX_SLOTS] {
+        std::array::from_fn(|i| i)
+    }
+
+    #[test]
+    fn rotate_slots_identity_preserves_frame() {
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, [1.0; SLOT_DIM]).unwrap();
+        frame.write_at(2, 1, SlotRole::Patient, [2.0; SLOT_DIM]).unwrap();
+        frame.meta[0].certainty = 0.9;
+        frame.meta[2].certainty = 0.6;
+        frame.frame_meta.strand_id = 7;
+
+        let rotated = frame.rotate_slots(&identity_mapping()).unwrap();
+
+        assert_eq!(rotated.active_slot_count(), 2);
+        assert_eq!(rotated.read_slot(0).unwrap().role, SlotRole::Agent);
+        assert_eq!(rotated.read_slot(2).unwrap().resolutions[1].unwrap()[0], 2.0);
+        assert_eq!(rotated.meta[0].certainty, 0.9);
+        assert_eq!(rotated.meta[2].certainty, 0.6);
+        assert_eq!(rotated.frame_meta.strand_id, 7);
+    }
+
+    #[test]
+    fn rotate_slots_swap_moves_data_and_meta() {
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, [1.0; SLOT_DIM]).unwrap();
+        frame.write_at(1, 0, SlotRole::Predicate, [2.0; SLOT_DIM]).unwrap();
+        frame.meta[0].certainty = 0.9;
+        frame.meta[1].certainty = 0.4;
+
+        let mut mapping = identity_mapping();
+        mapping.swap(0, 1);
+        let rotated = frame.rotate_slots(&mapping).unwrap();
+
+        let slot0 = rotated.read_slot(0).unwrap();
+        let slot1 = rotated.read_slot(1).unwrap();
+        assert_eq!(slot0.role, SlotRole::Predicate);
+        assert_eq!(slot0.resolutions[0].unwrap()[0], 2.0);
+        assert_eq!(slot1.role, SlotRole::Agent);
+        assert_eq!(slot1.resolutions[0].unwrap()[0], 1.0);
+        assert_eq!(rotated.meta[0].certainty, 0.4);
+        assert_eq!(rotated.meta[1].certainty, 0.9);
+    }
+
+    #[test]
+    fn rotate_slots_moves_into_empty_slot() {
+        let mut frame = TensorFrame::new();
+        frame.write_slot(0, SlotData::new(SlotRole::Agent)).unwrap();
+
+        let mut mapping = identity_mapping();
+        mapping.swap(0, MAX_SLOTS - 1);
+        let rotated = frame.rotate_slots(&mapping).unwrap();
+
+        assert!(rotated.read_slot(0).is_err());
+        assert_eq!(rotated.read_slot(MAX_SLOTS - 1).unwrap().role, SlotRole::Agent);
+    }
+
+    #[test]
+    fn rotate_slots_rejects_non_bijective_mapping() {
+        let frame = TensorFrame::new();
+        let mut mapping = identity_mapping();
+        mapping[1] = 0; // slots 0 and 1 both map to 0, slot 1 is never filled
+
+        let result = frame.rotate_slots(&mapping);
+        assert!(matches!(result, Err(VoltError::FrameError { .. })));
+    }
+
+    #[test]
+    fn rotate_slots_rejects_out_of_range_target() {
+        let frame = TensorFrame::new();
+        let mut mapping = identity_mapping();
+        mapping[3] = MAX_SLOTS;
+
+        let result = frame.rotate_slots(&mapping);
+        assert!(matches!(result, Err(VoltError::SlotOutOfRange { .. })));
+    }
+
     #[test]
     fn normalize_slot_produces_unit_vector() {
         let mut frame = TensorFrame::new();
@@ -791,4 +1438,278 @@ mod tests {
             assert!(val.abs() < 1e-6);
         }
     }
+
+    #[test]
+    fn content_hash_ignores_volatile_fields() {
+        let mut a = TensorFrame::new();
+        a.write_at(0, 0, SlotRole::Agent, [0.25; SLOT_DIM]).unwrap();
+        a.write_at(1, 1, SlotRole::Predicate, [-0.5; SLOT_DIM]).unwrap();
+
+        let mut b = a.clone();
+        b.frame_meta.frame_id = 99;
+        b.frame_meta.strand_id = 7;
+        b.frame_meta.created_at = 123_456;
+        b.frame_meta.global_certainty = 0.9;
+        b.meta[0].certainty = 0.3;
+        b.meta[0].updated_at = 42;
+
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn content_hash_distinguishes_content() {
+        let mut base = TensorFrame::new();
+        base.write_at(0, 0, SlotRole::Agent, [0.25; SLOT_DIM]).unwrap();
+        let h = base.content_hash();
+
+        let mut value = base.clone();
+        value.slots[0].as_mut().unwrap().resolutions[0].as_mut().unwrap()[3] = 0.3;
+        assert_ne!(h, value.content_hash());
+
+        let mut role = base.clone();
+        role.slots[0].as_mut().unwrap().role = SlotRole::Patient;
+        assert_ne!(h, role.content_hash());
+
+        let mut moved = TensorFrame::new();
+        moved.write_at(1, 0, SlotRole::Agent, [0.25; SLOT_DIM]).unwrap();
+        assert_ne!(h, moved.content_hash());
+
+        let mut resolution = TensorFrame::new();
+        resolution.write_at(0, 1, SlotRole::Agent, [0.25; SLOT_DIM]).unwrap();
+        assert_ne!(h, resolution.content_hash());
+
+        let mut discourse = base.clone();
+        discourse.frame_meta.discourse_type = DiscourseType::Query;
+        assert_ne!(h, discourse.content_hash());
+
+        // Hashed by bit pattern
+        let mut pos = TensorFrame::new();
+        pos.write_at(0, 0, SlotRole::Agent, [0.0; SLOT_DIM]).unwrap();
+        let mut neg = TensorFrame::new();
+        neg.write_at(0, 0, SlotRole::Agent, [-0.0; SLOT_DIM]).unwrap();
+        assert_ne!(pos.content_hash(), neg.content_hash());
+    }
+
+    #[test]
+    fn content_hash_is_stable() {
+        // Pinned value: changing it breaks caches keyed by content hash.
+        assert_eq!(TensorFrame::new().content_hash(), 0xaf63b84c8601af60);
+    }
+
+    #[test]
+    fn redact_slots_zeroes_and_marks() {
+        let mut frame = TensorFrame::new();
+        let mut slot = SlotData::new(SlotRole::Agent);
+        slot.write_resolution(0, [0.5; SLOT_DIM]);
+        slot.write_resolution(2, [0.25; SLOT_DIM]);
+        slot.codebook_id = Some(7);
+        frame.write_slot(0, slot).unwrap();
+        frame.meta[0].certainty = 0.9;
+        frame.write_at(1, 0, SlotRole::Predicate, [0.5; SLOT_DIM]).unwrap();
+        let before = frame.content_hash();
+
+        frame.redact_slots(&[0, 5]).unwrap();
+
+        let redacted = frame.read_slot(0).unwrap();
+        assert_eq!(redacted.role, SlotRole::Agent);
+        assert_eq!(redacted.resolutions[0], Some([0.0; SLOT_DIM]));
+        assert_eq!(redacted.resolutions[2], Some([0.0; SLOT_DIM]));
+        assert!(redacted.resolutions[1].is_none());
+        assert_eq!(redacted.codebook_id, None);
+        assert!(frame.is_redacted(0));
+        assert_eq!(frame.meta[0].source, SlotSource::Redacted);
+        assert_eq!(frame.meta[0].certainty, 0.9);
+        assert!(!frame.is_redacted(1));
+        assert!(!frame.is_redacted(5), "empty slots stay empty");
+        assert_ne!(frame.content_hash(), before);
+
+        // Still structurally valid: normalization skips the wiped slot.
+        frame.normalize_all().unwrap();
+    }
+
+    #[test]
+    fn redact_slots_out_of_range_changes_nothing() {
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+        assert!(frame.redact_slots(&[0, MAX_SLOTS]).is_err());
+        assert!(!frame.is_redacted(0));
+        assert_eq!(frame.read_slot(0).unwrap().resolutions[0], Some([0.5; SLOT_DIM]));
+    }
+
+    #[test]
+    fn redact_by_role_counts_matches() {
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+        frame.write_at(3, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+        frame.write_at(1, 0, SlotRole::Predicate, [0.5; SLOT_DIM]).unwrap();
+        assert_eq!(frame.redact_by_role(SlotRole::Agent), 2);
+        assert!(frame.is_redacted(0) && frame.is_redacted(3));
+        assert_eq!(frame.redact_by_role(SlotRole::Location), 0);
+    }
+
+    #[test]
+    fn normalization_report_flags_unnormalized_slot() {
+        let mut unit = [0.0; SLOT_DIM];
+        unit[0] = 1.0;
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, unit).unwrap();
+        frame.write_at(1, 0, SlotRole::Predicate, unit).unwrap();
+        frame.write_at(1, 2, SlotRole::Predicate, [0.5; SLOT_DIM]).unwrap();
+
+        let report = frame.normalization_report();
+        assert_eq!(report.len(), 1);
+        let (slot, resolution, norm) = report[0];
+        assert_eq!((slot, resolution), (1, 2));
+        assert!((norm - 8.0).abs() < 1e-4); // sqrt(256 * 0.25)
+
+        frame.normalize_slot(1, 2).unwrap();
+        assert!(frame.normalization_report().is_empty());
+    }
+
+    #[test]
+    fn normalization_report_flags_non_finite_and_skips_redacted() {
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, [f32::NAN; SLOT_DIM]).unwrap();
+        frame.write_at(1, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+        frame.redact_slots(&[1]).unwrap();
+
+        let report = frame.normalization_report();
+        assert_eq!(report.len(), 1);
+        assert_eq!((report[0].0, report[0].1), (0, 0));
+        assert!(report[0].2.is_nan());
+    }
+
+    fn valid_frame() -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        frame.write_at(0, 0, SlotRole::Agent, [0.5; SLOT_DIM]).unwrap();
+        frame.meta[0].source = SlotSource::Translator;
+        frame.meta[0].certainty = 0.8;
+        frame.frame_meta.global_certainty = 0.8;
+        frame
+    }
+
+    fn validation_message(frame: &TensorFrame) -> String {
+        match frame.validate() {
+            Err(VoltError::FrameError { message }) => message,
+            other => panic!("expected FrameError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_accepts_valid_and_empty_frames() {
+        assert!(valid_frame().validate().is_ok());
+        assert!(TensorFrame::new().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_non_finite_resolution() {
+        let mut frame = valid_frame();
+        let mut bad = [0.5; SLOT_DIM];
+        bad[7] = f32::NAN;
+        frame.write_at(0, 1, SlotRole::Agent, bad).unwrap();
+        let message = validation_message(&frame);
+        assert!(message.contains("slot 0 resolution 1"), "{message}");
+        assert!(message.contains("dim 7"), "{message}");
+
+        let mut frame = valid_frame();
+        frame.write_at(0, 0, SlotRole::Agent, [f32::INFINITY; SLOT_DIM]).unwrap();
+        assert!(validation_message(&frame).contains("non-finite"));
+    }
+
+    #[test]
+    fn validate_rejects_slot_certainty_out_of_range() {
+        let mut frame = valid_frame();
+        frame.meta[3].certainty = 1.2;
+        assert!(validation_message(&frame).contains("slot 3 certainty"));
+
+        frame.meta[3].certainty = -0.1;
+        assert!(validation_message(&frame).contains("slot 3 certainty"));
+
+        frame.meta[3].certainty = f32::NAN;
+        assert!(validation_message(&frame).contains("slot 3 certainty"));
+    }
+
+    #[test]
+    fn validate_rejects_global_certainty_out_of_range() {
+        let mut frame = valid_frame();
+        frame.frame_meta.global_certainty = 1.01;
+        assert!(validation_message(&frame).contains("global certainty"));
+    }
+
+    #[test]
+    fn validate_rejects_data_without_source() {
+        let mut frame = valid_frame();
+        frame.write_at(2, 0, SlotRole::Patient, [0.1; SLOT_DIM]).unwrap();
+        assert!(validation_message(&frame).contains("slot 2 has resolution data but no source"));
+
+        // A slot allocated without any resolution data needs no source.
+        let mut frame = valid_frame();
+        frame.write_slot(2, SlotData::new(SlotRole::Patient)).unwrap();
+        assert!(frame.validate().is_ok());
+    }
+
+    fn multi_resolution_frame() -> TensorFrame {
+        let mut frame = valid_frame();
+        for r in 1..NUM_RESOLUTIONS {
+            frame.write_at(0, r, SlotRole::Agent, [r as f32; SLOT_DIM]).unwrap();
+        }
+        for r in 0..NUM_RESOLUTIONS {
+            frame.write_at(1, r, SlotRole::Predicate, [0.25; SLOT_DIM]).unwrap();
+        }
+        frame.meta[1].source = SlotSource::Translator;
+        frame.slots[1].as_mut().unwrap().codebook_id = Some(7);
+        frame
+    }
+
+    fn active_resolutions(frame: &TensorFrame) -> usize {
+        frame
+            .slots
+            .iter()
+            .flatten()
+            .map(SlotData::active_resolution_count)
+            .sum()
+    }
+
+    #[test]
+    fn clone_resolutions_r0_only_keeps_mask_and_is_smaller() {
+        let frame = multi_resolution_frame();
+        let gist = frame.clone_resolutions(&[0]);
+
+        for i in [0, 1] {
+            let mask: Vec<bool> = gist.slots[i]
+                .as_ref()
+                .unwrap()
+                .resolutions
+                .iter()
+                .map(Option::is_some)
+                .collect();
+            assert_eq!(mask, [true, false, false, false]);
+        }
+        assert_eq!(gist.read_slot(1).unwrap().codebook_id, Some(7));
+        assert_eq!(gist.active_slot_count(), frame.active_slot_count());
+        assert_eq!(active_resolutions(&gist), 2);
+        assert!(active_resolutions(&gist) < active_resolutions(&frame));
+        assert!(gist.validate().is_ok());
+    }
+
+    #[test]
+    fn clone_resolutions_all_equals_full_clone() {
+        let frame = multi_resolution_frame();
+        let full = frame.clone();
+        let partial = frame.clone_resolutions(&[0, 1, 2, 3]);
+
+        assert_eq!(partial.content_hash(), full.content_hash());
+        for i in 0..MAX_SLOTS {
+            assert_eq!(
+                partial.slots[i].as_ref().map(|s| s.resolutions),
+                full.slots[i].as_ref().map(|s| s.resolutions)
+            );
+            assert_eq!(partial.meta[i].certainty, full.meta[i].certainty);
+        }
+        assert_eq!(partial.frame_meta.global_certainty, full.frame_meta.global_certainty);
+
+        // Out-of-range indices are ignored.
+        let ignored = frame.clone_resolutions(&[NUM_RESOLUTIONS, 2]);
+        assert_eq!(active_resolutions(&ignored), 2); // R2 of slots 0 and 1
+    }
 }
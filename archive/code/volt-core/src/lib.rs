@@ -17,16 +17,20 @@
 //! - No `async` code in this crate — pure synchronous logic.
 //! - No `unwrap()` in library code — use `Result<T, VoltError>` everywhere.
 
+pub mod codec;
+pub mod delta;
 pub mod error;
 pub mod frame;
 pub mod meta;
 pub mod module_info;
 pub mod slot;
 
+pub use codec::{FRAME_BINARY_MAGIC, FRAME_BINARY_VERSION};
+pub use delta::FrameDelta;
 pub use error::VoltError;
-pub use frame::TensorFrame;
-pub use meta::FrameMeta;
-pub use module_info::{ModuleInfo, ModuleType};
+pub use frame::{CertaintyRule, TensorFrame, NORMALIZATION_EPSILON, REDACTED_PLACEHOLDER};
+pub use meta::{FrameMeta, FRAME_SCHEMA_VERSION};
+pub use module_info::{ModuleInfo, ModuleType, CORE_VERSION};
 pub use slot::{SlotData, SlotMeta, SlotRole};
 
 /// Maximum number of slots in a TensorFrame.
@@ -3,6 +3,25 @@
 //! [`FrameMeta`] holds information that applies to the entire frame,
 //! such as which strand it belongs to, the discourse type, and
 //! the global certainty score.
+//!
+//! [`FrameMeta::schema_version`] records the frame layout a frame was
+//! created under; [`TensorFrame::migrate`](crate::TensorFrame::migrate)
+//! upgrades frames persisted under an older layout.
+
+/// Current frame schema version, stamped on every new [`FrameMeta`].
+///
+/// | Version | Change |
+/// |---------|--------|
+/// | 1 | Frames persisted before `schema_version` existed |
+/// | 2 | `schema_version` recorded in the frame |
+/// | 3 | Optional `ttl_micros` for ephemeral frames |
+pub const FRAME_SCHEMA_VERSION: u16 = 3;
+
+/// Schema version assumed for persisted frames that carry none.
+#[cfg(feature = "serde")]
+fn legacy_schema_version() -> u16 {
+    1
+}
 
 /// Frame-level metadata.
 ///
@@ -13,6 +32,7 @@
 ///
 /// let meta = FrameMeta::default();
 /// assert_eq!(meta.strand_id, 0);
+/// assert_eq!(meta.schema_version, volt_core::FRAME_SCHEMA_VERSION);
 /// assert_eq!(meta.global_certainty, 0.0);
 /// ```
 #[derive(Debug, Clone)]
@@ -42,6 +62,22 @@ pub struct FrameMeta {
 
     /// Proof chain length (number of reasoning steps).
     pub proof_length: u32,
+
+    /// Frame IDs this frame was distilled from (e.g. the cluster members
+    /// behind a consolidated wisdom frame). Empty for ordinary frames.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub derived_from: Vec<u64>,
+
+    /// Lifetime in microseconds after `created_at`, for ephemeral frames
+    /// a store expires. `None` (the default) never expires.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub ttl_micros: Option<u64>,
+
+    /// Frame layout version this frame was created under. New frames get
+    /// [`FRAME_SCHEMA_VERSION`]; frames persisted before the field existed
+    /// read back as 1.
+    #[cfg_attr(feature = "serde", serde(default = "legacy_schema_version"))]
+    pub schema_version: u16,
 }
 
 impl Default for FrameMeta {
@@ -55,10 +91,34 @@ impl Default for FrameMeta {
             rar_iterations: 0,
             verified: false,
             proof_length: 0,
+            derived_from: Vec::new(),
+            ttl_micros: None,
+            schema_version: FRAME_SCHEMA_VERSION,
         }
     }
 }
 
+impl FrameMeta {
+    /// The timestamp (microseconds since epoch) at which this frame
+    /// expires: `created_at + ttl_micros`, saturating. `None` if the
+    /// frame has no TTL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::meta::FrameMeta;
+    ///
+    /// let mut meta = FrameMeta { created_at: 1_000, ..FrameMeta::default() };
+    /// assert_eq!(meta.expires_at(), None);
+    /// meta.ttl_micros = Some(500);
+    /// assert_eq!(meta.expires_at(), Some(1_500));
+    /// ```
+    pub fn expires_at(&self) -> Option<u64> {
+        self.ttl_micros
+            .map(|ttl| self.created_at.saturating_add(ttl))
+    }
+}
+
 /// The discourse type classifies the frame's communicative purpose.
 ///
 /// # Example
@@ -7,6 +7,12 @@
 //! These types live in `volt-core` so all crates can use them without
 //! circular dependencies.
 
+use crate::VoltError;
+
+/// The `volt-core` version that [`ModuleInfo::min_core_version`] is
+/// checked against.
+pub const CORE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// The category of module (which trait it implements).
 ///
 /// # Example
@@ -55,6 +61,9 @@ impl core::fmt::Display for ModuleType {
 ///     author: "Volt X Team".to_string(),
 ///     description: "Provides mock weather data for demonstration.".to_string(),
 ///     module_type: ModuleType::HardStrand,
+///     min_core_version: "0.1.0".to_string(),
+///     requires: vec![],
+///     deterministic: true,
 /// };
 /// assert_eq!(info.module_type, ModuleType::HardStrand);
 /// ```
@@ -72,6 +81,84 @@ pub struct ModuleInfo {
     pub description: String,
     /// Which trait this module implements.
     pub module_type: ModuleType,
+    /// Oldest compatible `volt-core` version, e.g. `"0.1.0"`.
+    /// Empty means no requirement.
+    pub min_core_version: String,
+    /// IDs of modules that must be installed for this one to activate.
+    pub requires: Vec<String>,
+    /// Whether the module's output is a pure function of its input.
+    ///
+    /// `false` for modules that consult external state (network, clock),
+    /// such as async I/O strands; their results cannot carry the Hard
+    /// Core's provable guarantee.
+    pub deterministic: bool,
+}
+
+impl ModuleInfo {
+    /// Check that `core_version` meets this module's `min_core_version`.
+    ///
+    /// Versions are compared numerically as `major.minor.patch`; missing
+    /// components count as 0 and pre-release/build suffixes are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::ModuleError`] if either version is malformed
+    /// or `core_version` is older than required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::module_info::{ModuleInfo, ModuleType};
+    ///
+    /// let info = ModuleInfo {
+    ///     id: "future-strand".to_string(),
+    ///     display_name: "Future".to_string(),
+    ///     version: "1.0.0".to_string(),
+    ///     author: "Someone".to_string(),
+    ///     description: "Needs a newer core.".to_string(),
+    ///     module_type: ModuleType::HardStrand,
+    ///     min_core_version: "0.3".to_string(),
+    ///     requires: vec![],
+    ///     deterministic: true,
+    /// };
+    /// assert!(info.check_core_version("0.3.1").is_ok());
+    /// assert!(info.check_core_version("0.2.9").is_err());
+    /// ```
+    pub fn check_core_version(&self, core_version: &str) -> Result<(), VoltError> {
+        if self.min_core_version.trim().is_empty() {
+            return Ok(());
+        }
+        let malformed = |v: &str| VoltError::ModuleError {
+            name: self.id.clone(),
+            message: format!("malformed version {v:?}"),
+        };
+        let required =
+            parse_version(&self.min_core_version).ok_or_else(|| malformed(&self.min_core_version))?;
+        let actual = parse_version(core_version).ok_or_else(|| malformed(core_version))?;
+        if actual < required {
+            return Err(VoltError::ModuleError {
+                name: self.id.clone(),
+                message: format!(
+                    "requires volt-core >= {}, but core is {core_version}",
+                    self.min_core_version
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Parse `major[.minor[.patch]]`, ignoring any `-pre` or `+build` suffix.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.trim().split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|p| p.parse::<u64>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().transpose().ok()?.unwrap_or(0);
+    let patch = parts.next().transpose().ok()?.unwrap_or(0);
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
 }
 
 #[cfg(test)]
@@ -87,6 +174,9 @@ mod tests {
             author: "Author".to_string(),
             description: "A test module.".to_string(),
             module_type: ModuleType::HardStrand,
+            min_core_version: "0.1.0".to_string(),
+            requires: vec![],
+            deterministic: true,
         };
         assert_eq!(info.id, "test-module");
         assert_eq!(info.module_type, ModuleType::HardStrand);
@@ -114,9 +204,39 @@ mod tests {
             author: "Author".to_string(),
             description: "Test cloning.".to_string(),
             module_type: ModuleType::ActionCore,
+            min_core_version: String::new(),
+            requires: vec!["math_engine".to_string()],
+            deterministic: true,
         };
         let cloned = info.clone();
         assert_eq!(cloned.id, info.id);
         assert_eq!(cloned.module_type, info.module_type);
+        assert_eq!(cloned.requires, info.requires);
+    }
+
+    #[test]
+    fn core_version_check() {
+        let mut info = ModuleInfo {
+            id: "versioned".to_string(),
+            display_name: "Versioned".to_string(),
+            version: "0.1.0".to_string(),
+            author: "Author".to_string(),
+            description: "Version checks.".to_string(),
+            module_type: ModuleType::HardStrand,
+            min_core_version: String::new(),
+            requires: vec![],
+            deterministic: true,
+        };
+        assert!(info.check_core_version(CORE_VERSION).is_ok());
+
+        info.min_core_version = "0.2.0".to_string();
+        assert!(info.check_core_version("0.2.0").is_ok());
+        assert!(info.check_core_version("0.10.0").is_ok());
+        assert!(info.check_core_version("1.0.0-alpha").is_ok());
+        let err = info.check_core_version("0.1.9").unwrap_err().to_string();
+        assert!(err.contains("requires volt-core >= 0.2.0"), "{err}");
+
+        info.min_core_version = "0.x".to_string();
+        assert!(info.check_core_version("0.1.0").is_err());
     }
 }
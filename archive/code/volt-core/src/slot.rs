@@ -189,6 +189,86 @@ pub enum SlotRole {
     Free(u8),
 }
 
+/// Affinity between a [`SlotRole::Free`] role and any other role,
+/// including a different `Free` index.
+pub const FREE_ROLE_AFFINITY: f32 = 0.25;
+
+/// Coarse grouping of roles used by [`SlotRole::affinity`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RoleClass {
+    /// The predicate itself.
+    Predicate,
+    /// Core arguments of the predicate (Agent, Patient).
+    Argument,
+    /// Circumstantial adjuncts (Location, Time, Manner, Instrument).
+    Adjunct,
+    /// Causal links (Cause, Result).
+    Causal,
+    /// Domain-specific extensions.
+    Free,
+}
+
+impl SlotRole {
+    /// Fixed co-occurrence affinity between two roles, in `[0, 1]`.
+    ///
+    /// Intended as a routing or attention hint: roles that usually form
+    /// one proposition score high, circumstantial roles lower. The score
+    /// is symmetric, and every role has affinity 1.0 with itself.
+    ///
+    /// | Pair                                       | Affinity |
+    /// |--------------------------------------------|----------|
+    /// | Same role                                  | 1.0      |
+    /// | Predicate ↔ Agent/Patient, Agent ↔ Patient | 0.9      |
+    /// | Cause ↔ Result                             | 0.7      |
+    /// | Predicate ↔ adjunct or causal role         | 0.6      |
+    /// | Agent/Patient ↔ Cause/Result               | 0.5      |
+    /// | Adjunct ↔ adjunct                          | 0.5      |
+    /// | Agent/Patient ↔ adjunct                    | 0.4      |
+    /// | Adjunct ↔ Cause/Result                     | 0.4      |
+    /// | `Free(n)` ↔ any other role                 | [`FREE_ROLE_AFFINITY`] |
+    ///
+    /// Adjuncts are Location, Time, Manner, and Instrument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::slot::SlotRole;
+    ///
+    /// let agent = SlotRole::Agent;
+    /// assert!(agent.affinity(&SlotRole::Predicate) > agent.affinity(&SlotRole::Time));
+    /// assert_eq!(agent.affinity(&SlotRole::Time), SlotRole::Time.affinity(&agent));
+    /// ```
+    pub fn affinity(&self, other: &SlotRole) -> f32 {
+        use RoleClass::*;
+
+        if self == other {
+            return 1.0;
+        }
+        let (a, b) = (self.class(), other.class());
+        match (a, b) {
+            (Free, _) | (_, Free) => FREE_ROLE_AFFINITY,
+            (Predicate | Argument, Predicate | Argument) => 0.9,
+            (Causal, Causal) => 0.7,
+            (Predicate, _) | (_, Predicate) => 0.6,
+            (Argument, Causal) | (Causal, Argument) | (Adjunct, Adjunct) => 0.5,
+            (Argument, Adjunct) | (Adjunct, Argument) | (Adjunct, Causal) | (Causal, Adjunct) => 0.4,
+        }
+    }
+
+    /// The coarse class this role belongs to.
+    fn class(&self) -> RoleClass {
+        match self {
+            SlotRole::Predicate => RoleClass::Predicate,
+            SlotRole::Agent | SlotRole::Patient => RoleClass::Argument,
+            SlotRole::Location | SlotRole::Time | SlotRole::Manner | SlotRole::Instrument => {
+                RoleClass::Adjunct
+            }
+            SlotRole::Cause | SlotRole::Result => RoleClass::Causal,
+            SlotRole::Free(_) => RoleClass::Free,
+        }
+    }
+}
+
 /// Per-slot metadata tracking certainty, source, and timestamps.
 ///
 /// # Example
@@ -254,4 +334,56 @@ pub enum SlotSource {
     Memory,
     /// Data came from a personal/user strand.
     Personal,
+    /// Data was removed by [`TensorFrame::redact_slots`](crate::TensorFrame::redact_slots);
+    /// the slot's vectors are zeroed.
+    Redacted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_ROLES: [SlotRole; 11] = [
+        SlotRole::Agent,
+        SlotRole::Predicate,
+        SlotRole::Patient,
+        SlotRole::Location,
+        SlotRole::Time,
+        SlotRole::Manner,
+        SlotRole::Instrument,
+        SlotRole::Cause,
+        SlotRole::Result,
+        SlotRole::Free(0),
+        SlotRole::Free(3),
+    ];
+
+    #[test]
+    fn affinity_is_symmetric_and_bounded() {
+        for a in &ALL_ROLES {
+            assert_eq!(a.affinity(a), 1.0);
+            for b in &ALL_ROLES {
+                let ab = a.affinity(b);
+                assert_eq!(ab, b.affinity(a), "{a:?} / {b:?}");
+                assert!((0.0..=1.0).contains(&ab));
+            }
+        }
+    }
+
+    #[test]
+    fn core_roles_have_higher_affinity_than_adjuncts() {
+        let agent = SlotRole::Agent;
+        assert!(agent.affinity(&SlotRole::Predicate) > agent.affinity(&SlotRole::Time));
+        assert!(agent.affinity(&SlotRole::Patient) > agent.affinity(&SlotRole::Location));
+        assert!(
+            SlotRole::Predicate.affinity(&SlotRole::Manner)
+                > SlotRole::Agent.affinity(&SlotRole::Manner)
+        );
+    }
+
+    #[test]
+    fn free_roles_use_default_affinity() {
+        assert_eq!(SlotRole::Free(0).affinity(&SlotRole::Agent), FREE_ROLE_AFFINITY);
+        assert_eq!(SlotRole::Free(0).affinity(&SlotRole::Free(1)), FREE_ROLE_AFFINITY);
+        assert_eq!(SlotRole::Free(2).affinity(&SlotRole::Free(2)), 1.0);
+    }
 }
@@ -11,7 +11,7 @@
 use serde::{Deserialize, Serialize};
 use volt_core::meta::DiscourseType;
 use volt_core::slot::SlotRole;
-use volt_core::{TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
+use volt_core::{TensorFrame, VoltError, FRAME_BINARY_MAGIC, MAX_SLOTS, SLOT_DIM};
 
 /// The decay level of a frame in the GC pipeline.
 ///
@@ -142,6 +142,8 @@ pub struct CompressedFrame {
     pub verified: bool,
     /// Compressed slots (R₀ + R₁ only). Most are None.
     pub slots: [Option<CompressedSlot>; MAX_SLOTS],
+    /// Time-to-live carried over from the full frame, in microseconds.
+    pub ttl_micros: Option<u64>,
 }
 
 /// A gist-level frame retaining only R₀ per slot.
@@ -177,6 +179,8 @@ pub struct GistFrame {
     pub slot_gists: [Option<[f32; SLOT_DIM]>; MAX_SLOTS],
     /// The superposed R₀ gist vector (same as FrameGist.vector).
     pub gist_vector: [f32; SLOT_DIM],
+    /// Time-to-live carried over from the full frame, in microseconds.
+    pub ttl_micros: Option<u64>,
 }
 
 /// A tombstone marking a frame as deleted by GC.
@@ -286,11 +290,24 @@ impl FrameEntry {
         }
     }
 
+    /// Returns when this entry's TTL lapses (microseconds), if it has one.
+    ///
+    /// Tombstones never expire; they are already deleted.
+    pub fn expires_at(&self) -> Option<u64> {
+        let (created_at, ttl) = match self {
+            Self::Full(f) => return f.frame_meta.expires_at(),
+            Self::Compressed(c) => (c.created_at, c.ttl_micros?),
+            Self::Gist(g) => (g.created_at, g.ttl_micros?),
+            Self::Tombstone(_) => return None,
+        };
+        Some(created_at.saturating_add(ttl))
+    }
+
     /// Serializes this entry to bytes.
     ///
     /// Format: `[decay_level: u8][payload_bytes]`
     ///
-    /// - Full frames use serde_json (TensorFrame has Serialize).
+    /// - Full frames use [`TensorFrame::to_binary`].
     /// - Compressed/Gist frames use custom binary (efficient for `[f32; 256]` arrays).
     /// - Tombstones use serde_json (small, no arrays).
     ///
@@ -302,12 +319,7 @@ impl FrameEntry {
         buf.push(self.decay_level().tag());
         match self {
             Self::Full(f) => {
-                let json = serde_json::to_vec(f.as_ref()).map_err(|e| {
-                    VoltError::StorageError {
-                        message: format!("failed to serialize full frame: {e}"),
-                    }
-                })?;
-                buf.extend_from_slice(&json);
+                buf.extend_from_slice(&f.to_binary());
             }
             Self::Compressed(c) => {
                 compressed_frame_to_binary(c, &mut buf);
@@ -327,6 +339,11 @@ impl FrameEntry {
 
     /// Deserializes a `FrameEntry` from bytes produced by [`to_bytes`](Self::to_bytes).
     ///
+    /// Full-frame payloads without the binary frame magic are read as
+    /// JSON, the format older WAL entries and T2 runs were written in.
+    /// Full frames written under an older schema are upgraded with
+    /// [`TensorFrame::migrate`].
+    ///
     /// # Errors
     ///
     /// Returns [`VoltError::StorageError`] if the format is invalid.
@@ -343,10 +360,16 @@ impl FrameEntry {
         })?;
         match level {
             DecayLevel::Full => {
-                let frame: TensorFrame =
+                let mut frame = if payload.starts_with(&FRAME_BINARY_MAGIC) {
+                    TensorFrame::from_binary(payload).map_err(|e| VoltError::StorageError {
+                        message: format!("failed to decode full frame: {e}"),
+                    })?
+                } else {
                     serde_json::from_slice(payload).map_err(|e| VoltError::StorageError {
                         message: format!("failed to deserialize full frame: {e}"),
-                    })?;
+                    })?
+                };
+                frame.migrate();
                 Ok(Self::Full(Box::new(frame)))
             }
             DecayLevel::Compressed => {
@@ -415,6 +438,7 @@ pub fn compress(frame: &TensorFrame) -> CompressedFrame {
         discourse_type: frame.frame_meta.discourse_type,
         verified: frame.frame_meta.verified,
         slots,
+        ttl_micros: frame.frame_meta.ttl_micros,
     }
 }
 
@@ -457,6 +481,7 @@ pub fn to_gist_frame(
         global_certainty: compressed.global_certainty,
         slot_gists,
         gist_vector,
+        ttl_micros: compressed.ttl_micros,
     }
 }
 
@@ -584,7 +609,11 @@ fn read_f32_array(data: &[u8], offset: usize) -> Option<([f32; SLOT_DIM], usize)
 ///   r0_present:u8 [| r0:[f32;256]]
 ///   r1_present:u8 [| r1:[f32;256]]
 ///   has_codebook:u8 [| codebook_id:u16]
+/// [has_ttl:u8 [| ttl_micros:u64]]
 /// ```
+///
+/// The trailing TTL is optional on read so entries written before it
+/// existed still decode.
 fn compressed_frame_to_binary(frame: &CompressedFrame, buf: &mut Vec<u8>) {
     buf.extend_from_slice(&frame.frame_id.to_le_bytes());
     buf.extend_from_slice(&frame.strand_id.to_le_bytes());
@@ -633,6 +662,8 @@ fn compressed_frame_to_binary(frame: &CompressedFrame, buf: &mut Vec<u8>) {
             buf.push(0);
         }
     }
+
+    write_ttl(buf, frame.ttl_micros);
 }
 
 /// Deserializes a [`CompressedFrame`] from binary bytes.
@@ -734,6 +765,8 @@ fn compressed_frame_from_binary(data: &[u8]) -> Result<CompressedFrame, VoltErro
         });
     }
 
+    let ttl_micros = read_ttl(data, pos).ok_or_else(|| err("ttl"))?;
+
     Ok(CompressedFrame {
         frame_id,
         strand_id,
@@ -742,6 +775,7 @@ fn compressed_frame_from_binary(data: &[u8]) -> Result<CompressedFrame, VoltErro
         discourse_type,
         verified,
         slots,
+        ttl_micros,
     })
 }
 
@@ -754,6 +788,7 @@ fn compressed_frame_from_binary(data: &[u8]) -> Result<CompressedFrame, VoltErro
 /// For each present slot_gist:
 ///   vector:[f32;256]
 /// gist_vector:[f32;256]
+/// [has_ttl:u8 [| ttl_micros:u64]]
 /// ```
 fn gist_frame_to_binary(gist: &GistFrame, buf: &mut Vec<u8>) {
     buf.extend_from_slice(&gist.frame_id.to_le_bytes());
@@ -777,6 +812,8 @@ fn gist_frame_to_binary(gist: &GistFrame, buf: &mut Vec<u8>) {
 
     // Global gist vector
     write_f32_array(buf, &gist.gist_vector);
+
+    write_ttl(buf, gist.ttl_micros);
 }
 
 /// Deserializes a [`GistFrame`] from binary bytes.
@@ -813,8 +850,10 @@ fn gist_frame_from_binary(data: &[u8]) -> Result<GistFrame, VoltError> {
     }
 
     // Global gist vector
-    let (gist_vector, _consumed) =
+    let (gist_vector, consumed) =
         read_f32_array(data, pos).ok_or_else(|| err("gist_vector data"))?;
+    pos += consumed;
+    let ttl_micros = read_ttl(data, pos).ok_or_else(|| err("ttl"))?;
 
     Ok(GistFrame {
         frame_id,
@@ -823,9 +862,34 @@ fn gist_frame_from_binary(data: &[u8]) -> Result<GistFrame, VoltError> {
         global_certainty,
         slot_gists,
         gist_vector,
+        ttl_micros,
     })
 }
 
+/// Appends an optional TTL trailer: `has_ttl:u8 [| ttl_micros:u64]`.
+fn write_ttl(buf: &mut Vec<u8>, ttl_micros: Option<u64>) {
+    if let Some(ttl) = ttl_micros {
+        buf.push(1);
+        buf.extend_from_slice(&ttl.to_le_bytes());
+    } else {
+        buf.push(0);
+    }
+}
+
+/// Reads the optional TTL trailer at `pos`.
+///
+/// A missing trailer (entries written before TTLs were kept in T2)
+/// reads as no TTL; `None` means the trailer is truncated.
+fn read_ttl(data: &[u8], pos: usize) -> Option<Option<u64>> {
+    match data.get(pos) {
+        None | Some(0) => Some(None),
+        Some(_) => {
+            let bytes = data.get(pos + 1..pos + 9)?;
+            Some(Some(u64::from_le_bytes(bytes.try_into().ok()?)))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -988,6 +1052,96 @@ mod tests {
         assert_eq!(entry.global_certainty(), 0.7);
     }
 
+    #[test]
+    fn frame_entry_bytes_roundtrip_full_binary() {
+        // serde_json on a full TensorFrame needs more than the default test stack
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let mut frame = TensorFrame::new();
+                frame.frame_meta.frame_id = 42;
+                for i in 0..6 {
+                    let mut slot = SlotData::new(SlotRole::Free(i as u8));
+                    for r in 0..4 {
+                        let v = std::array::from_fn(|d| ((d * 7 + r * 3 + i) as f32 * 0.618).sin());
+                        slot.write_resolution(r, v);
+                    }
+                    frame.write_slot(i, slot).unwrap();
+                }
+                let bytes = FrameEntry::Full(Box::new(frame.clone())).to_bytes().unwrap();
+                assert_eq!(&bytes[1..4], &FRAME_BINARY_MAGIC);
+
+                let restored = FrameEntry::from_bytes(&bytes).unwrap();
+                let FrameEntry::Full(restored) = restored else {
+                    panic!("expected full frame");
+                };
+                assert_eq!(restored.to_binary(), frame.to_binary());
+
+                // JSON spends roughly 10-12 bytes per float against 4 here
+                let json = serde_json::to_vec(&frame).unwrap();
+                assert!(bytes.len() * 5 < json.len() * 2, "{} vs {}", bytes.len(), json.len());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn frame_entry_reads_legacy_json_full_frame() {
+        // serde_json on a full TensorFrame needs more than the default test stack
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let frame = make_full_frame();
+                let mut bytes = vec![DecayLevel::Full.tag()];
+                bytes.extend_from_slice(&serde_json::to_vec(&frame).unwrap());
+
+                let restored = FrameEntry::from_bytes(&bytes).unwrap();
+                let FrameEntry::Full(restored) = restored else {
+                    panic!("expected full frame");
+                };
+                assert_eq!(restored.to_binary(), frame.to_binary());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn frame_entry_migrates_old_schema_frame() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                // A frame persisted before `schema_version` and
+                // `derived_from` were part of FrameMeta.
+                let frame = make_full_frame();
+                let mut json = serde_json::to_value(&frame).unwrap();
+                let meta = json["frame_meta"].as_object_mut().unwrap();
+                meta.remove("schema_version");
+                meta.remove("derived_from");
+                let mut bytes = vec![DecayLevel::Full.tag()];
+                bytes.extend_from_slice(&serde_json::to_vec(&json).unwrap());
+
+                let FrameEntry::Full(restored) = FrameEntry::from_bytes(&bytes).unwrap() else {
+                    panic!("expected full frame");
+                };
+                assert_eq!(restored.frame_meta.schema_version, volt_core::FRAME_SCHEMA_VERSION);
+                assert!(restored.frame_meta.derived_from.is_empty());
+                assert_eq!(restored.frame_meta.frame_id, frame.frame_meta.frame_id);
+                assert_eq!(restored.to_binary(), frame.to_binary());
+
+                // A current frame is unchanged by the round trip.
+                let current = FrameEntry::Full(Box::new(frame.clone())).to_bytes().unwrap();
+                let FrameEntry::Full(same) = FrameEntry::from_bytes(&current).unwrap() else {
+                    panic!("expected full frame");
+                };
+                assert_eq!(same.to_binary(), frame.to_binary());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn frame_entry_bytes_roundtrip_compressed() {
         let frame = make_full_frame();
@@ -1013,6 +1167,24 @@ mod tests {
         assert_eq!(restored.frame_id(), 42);
     }
 
+    #[test]
+    fn ttl_survives_compression_and_gisting() {
+        let mut frame = make_full_frame();
+        frame.frame_meta.ttl_micros = Some(500);
+        let c = compress(&frame);
+        let g = to_gist_frame(&c, [0.5; SLOT_DIM]);
+
+        for entry in [FrameEntry::Compressed(c), FrameEntry::Gist(g)] {
+            let bytes = entry.to_bytes().unwrap();
+            let restored = FrameEntry::from_bytes(&bytes).unwrap();
+            assert_eq!(restored.expires_at(), Some(1_000_500));
+
+            // Entries written before the TTL trailer existed decode as no TTL.
+            let legacy = FrameEntry::from_bytes(&bytes[..bytes.len() - 9]).unwrap();
+            assert_eq!(legacy.expires_at(), None);
+        }
+    }
+
     #[test]
     fn frame_entry_bytes_roundtrip_tombstone() {
         let ts = to_tombstone(42, 1, 2_000_000, Some(99));
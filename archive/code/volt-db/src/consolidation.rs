@@ -9,10 +9,12 @@
 //! 1. For each frame gist in a strand, query HNSW for similar neighbors
 //! 2. Group frames into clusters using greedy union-find
 //! 3. For each cluster above min size, average R₀ vectors into a wisdom frame
-//! 4. Mark source frames as superseded by the wisdom frame
+//! 4. Mark source frames as superseded by the wisdom frame (and, with
+//!    [`ConsolidationConfig::supersede_sources`], tombstone them)
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use volt_core::meta::DiscourseType;
 use volt_core::slot::{SlotData, SlotRole, SlotSource};
 use volt_core::{TensorFrame, SLOT_DIM};
@@ -40,6 +42,10 @@ pub struct ConsolidationConfig {
     pub wisdom_gamma: f32,
     /// Number of HNSW neighbors to query per frame. Default: 20.
     pub query_k: usize,
+    /// Whether to tombstone source frames once their wisdom frame is
+    /// stored, recording the wisdom frame ID as `superseded_by`. Pinned
+    /// frames are never superseded. Default: false.
+    pub supersede_sources: bool,
 }
 
 impl Default for ConsolidationConfig {
@@ -49,6 +55,7 @@ impl Default for ConsolidationConfig {
             similarity_threshold: 0.85,
             wisdom_gamma: 0.95,
             query_k: 20,
+            supersede_sources: false,
         }
     }
 }
@@ -91,16 +98,57 @@ pub struct FrameCluster {
 ///     superseded_frame_ids: Vec::new(),
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsolidationResult {
     /// Number of clusters detected.
     pub clusters_found: usize,
     /// The wisdom frames created (one per cluster).
     pub wisdom_frames: Vec<TensorFrame>,
-    /// Source frame IDs that were superseded by wisdom frames.
+    /// Source frame IDs that were superseded by wisdom frames. With
+    /// [`ConsolidationConfig::supersede_sources`] set, this lists only the
+    /// frames actually tombstoned (pinned sources are excluded).
     pub superseded_frame_ids: Vec<u64>,
 }
 
+/// Result of one resumable consolidation step, see
+/// [`VoltStore::consolidate_strand_from`](crate::VoltStore::consolidate_strand_from).
+///
+/// # Example
+///
+/// ```
+/// use volt_db::consolidation::{ConsolidationProgress, ConsolidationResult};
+///
+/// let progress = ConsolidationProgress {
+///     result: ConsolidationResult {
+///         clusters_found: 0,
+///         wisdom_frames: Vec::new(),
+///         superseded_frame_ids: Vec::new(),
+///     },
+///     cursor: 42,
+///     frames_examined: 10,
+///     frames_remaining: 0,
+/// };
+/// assert!(progress.is_complete());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConsolidationProgress {
+    /// Clusters and wisdom frames produced by this step.
+    pub result: ConsolidationResult,
+    /// Highest frame ID examined so far; pass it to the next step.
+    pub cursor: u64,
+    /// Frames examined by this step.
+    pub frames_examined: usize,
+    /// Frames past the cursor still waiting for a later step.
+    pub frames_remaining: usize,
+}
+
+impl ConsolidationProgress {
+    /// Returns true if every pending frame has been examined.
+    pub fn is_complete(&self) -> bool {
+        self.frames_remaining == 0
+    }
+}
+
 /// Engine for detecting clusters and creating wisdom frames.
 ///
 /// # Example
@@ -128,8 +176,9 @@ impl ConsolidationEngine {
 
     /// Finds clusters of similar frames in a strand using HNSW.
     ///
-    /// Uses greedy union-find: for each gist, query HNSW for neighbors
-    /// above the similarity threshold, then merge groups.
+    /// Uses greedy union-find: query HNSW for every gist's neighbors
+    /// (one [`HnswIndex::query_strand_batch`] call), then merge groups
+    /// whose similarity is above the threshold.
     ///
     /// # Example
     ///
@@ -162,9 +211,10 @@ impl ConsolidationEngine {
             .collect();
         let mut parent: Vec<usize> = (0..gists.len()).collect();
 
-        // Query HNSW for each gist and union similar frames
-        for gist in gists {
-            let results = hnsw.query_strand(strand_id, &gist.vector, self.config.query_k);
+        // Query HNSW for every gist in one batch and union similar frames
+        let queries: Vec<[f32; SLOT_DIM]> = gists.iter().map(|g| g.vector).collect();
+        let neighbors = hnsw.query_strand_batch(strand_id, &queries, self.config.query_k);
+        for (gist, results) in gists.iter().zip(neighbors) {
             for result in results {
                 if result.frame_id == gist.frame_id {
                     continue;
@@ -181,10 +231,64 @@ impl ConsolidationEngine {
             }
         }
 
+        self.collect_clusters(gists, &parent)
+    }
+
+    /// Finds clusters among `gists` by comparing every pair directly.
+    ///
+    /// Unlike [`find_clusters`](Self::find_clusters), neighbors outside
+    /// `gists` can never crowd out members of the set, which makes it
+    /// suitable for clustering a bounded batch of frames. Cost is
+    /// quadratic in `gists.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::consolidation::{ConsolidationConfig, ConsolidationEngine};
+    /// use volt_db::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let engine = ConsolidationEngine::new(ConsolidationConfig {
+    ///     min_cluster_size: 2,
+    ///     ..ConsolidationConfig::default()
+    /// });
+    /// let gists: Vec<FrameGist> = (1..=3)
+    ///     .map(|id| FrameGist {
+    ///         vector: [1.0 / (SLOT_DIM as f32).sqrt(); SLOT_DIM],
+    ///         frame_id: id,
+    ///         strand_id: 0,
+    ///         created_at: id,
+    ///     })
+    ///     .collect();
+    /// let clusters = engine.find_clusters_exact(&gists);
+    /// assert_eq!(clusters.len(), 1);
+    /// assert_eq!(clusters[0].member_frame_ids.len(), 3);
+    /// ```
+    pub fn find_clusters_exact(&self, gists: &[FrameGist]) -> Vec<FrameCluster> {
+        let mut parent: Vec<usize> = (0..gists.len()).collect();
+        for (a, gist_a) in gists.iter().enumerate() {
+            for (b, gist_b) in gists.iter().enumerate().skip(a + 1) {
+                let similarity: f32 = gist_a
+                    .vector
+                    .iter()
+                    .zip(gist_b.vector.iter())
+                    .map(|(x, y)| x * y)
+                    .sum();
+                if similarity >= self.config.similarity_threshold {
+                    union(&mut parent, a, b);
+                }
+            }
+        }
+        self.collect_clusters(gists, &parent)
+    }
+
+    /// Groups `gists` by union-find root and keeps groups of at least
+    /// `min_cluster_size`.
+    fn collect_clusters(&self, gists: &[FrameGist], parent: &[usize]) -> Vec<FrameCluster> {
         // Group by root
         let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
         for i in 0..gists.len() {
-            let root = find(&parent, i);
+            let root = find(parent, i);
             groups.entry(root).or_default().push(i);
         }
 
@@ -232,6 +336,7 @@ impl ConsolidationEngine {
     /// - High gamma (configurable, default 0.95)
     /// - DiscourseType::Response
     /// - verified = true
+    /// - `derived_from` listing the source frame IDs
     ///
     /// # Example
     ///
@@ -297,6 +402,10 @@ impl ConsolidationEngine {
         wisdom.frame_meta.global_certainty = self.config.wisdom_gamma;
         wisdom.frame_meta.discourse_type = DiscourseType::Response;
         wisdom.frame_meta.verified = true;
+        wisdom.frame_meta.derived_from = source_frames
+            .iter()
+            .map(|f| f.frame_meta.frame_id)
+            .collect();
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
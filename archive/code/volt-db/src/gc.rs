@@ -17,8 +17,14 @@
 //! - Full → Compressed: score < 0.7
 //! - Compressed → Gist: score < 0.4
 //! - Gist → Tombstoned: score < 0.1
+//!
+//! A custom scorer installed with [`GcEngine::set_scorer`] replaces the
+//! formula above; pinned frames stay immortal either way.
 
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
 
 use crate::compressed::DecayLevel;
 
@@ -53,6 +59,9 @@ pub struct GcConfig {
     pub threshold_compressed_to_gist: f64,
     /// Threshold: Gist → Tombstoned. Default: 0.1.
     pub threshold_gist_to_tombstone: f64,
+    /// Half-life of tracked access counts in days; see
+    /// [`AccessTracker::decay_to`]. Default: 7.0.
+    pub access_half_life_days: f64,
 }
 
 impl Default for GcConfig {
@@ -66,6 +75,7 @@ impl Default for GcConfig {
             threshold_full_to_compressed: 0.7,
             threshold_compressed_to_gist: 0.4,
             threshold_gist_to_tombstone: 0.1,
+            access_half_life_days: 7.0,
         }
     }
 }
@@ -120,7 +130,7 @@ pub struct FrameGcMeta {
 /// let result = GcResult::default();
 /// assert_eq!(result.frames_compressed, 0);
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GcResult {
     /// Number of frames demoted Full → Compressed.
     pub frames_compressed: usize,
@@ -132,6 +142,87 @@ pub struct GcResult {
     pub frames_preserved: usize,
 }
 
+/// Per-frame retrieval counts that fade over time.
+///
+/// Each [`record`](Self::record) adds 1 to a frame's count; each
+/// [`decay_to`](Self::decay_to) halves every count once per elapsed
+/// half-life, so frames that were popular long ago lose their boost.
+/// Recording takes `&self` so read paths can count hits.
+///
+/// # Example
+///
+/// ```
+/// use volt_db::gc::AccessTracker;
+///
+/// let mut tracker = AccessTracker::default();
+/// for _ in 0..8 {
+///     tracker.record(42);
+/// }
+/// assert_eq!(tracker.count(42), 8);
+///
+/// let day = 86_400_000_000u64;
+/// tracker.decay_to(0, 7.0);
+/// tracker.decay_to(14 * day, 7.0); // two half-lives
+/// assert_eq!(tracker.count(42), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct AccessTracker {
+    counts: Mutex<HashMap<u64, f64>>,
+    /// Timestamp (microseconds) of the last decay; `None` before the first.
+    last_decay: Option<u64>,
+}
+
+impl AccessTracker {
+    /// Records one access of `frame_id`.
+    pub fn record(&self, frame_id: u64) {
+        *self.lock().entry(frame_id).or_insert(0.0) += 1.0;
+    }
+
+    /// Returns the whole number of (decayed) accesses of `frame_id`.
+    pub fn count(&self, frame_id: u64) -> u32 {
+        self.lock().get(&frame_id).map_or(0, |&c| c as u32)
+    }
+
+    /// Drops the count of `frame_id`, e.g. when the frame is deleted.
+    pub fn forget(&self, frame_id: u64) {
+        self.lock().remove(&frame_id);
+    }
+
+    /// Decays all counts from the previous decay to `now` (microseconds).
+    ///
+    /// The first call only sets the reference time. Counts that fall
+    /// below one access are dropped. Calls with `now` before the previous
+    /// one, or with a non-positive half-life, leave the counts unchanged.
+    pub fn decay_to(&mut self, now: u64, half_life_days: f64) {
+        let Some(last) = self.last_decay else {
+            self.last_decay = Some(now);
+            return;
+        };
+        if now <= last || half_life_days <= 0.0 {
+            return;
+        }
+        self.last_decay = Some(now);
+        let elapsed_days = (now - last) as f64 / MICROS_PER_DAY;
+        let factor = 0.5f64.powf(elapsed_days / half_life_days);
+        let counts = self.counts.get_mut().unwrap_or_else(|e| e.into_inner());
+        counts.retain(|_, c| {
+            *c *= factor;
+            *c >= 1.0
+        });
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u64, f64>> {
+        self.counts.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A caller-supplied retention score: `(meta, now) -> score`, where
+/// `now` is in microseconds and the score is compared against the
+/// [`GcConfig`] decay thresholds.
+pub type GcScorer = Box<ScoreFn>;
+
+type ScoreFn = dyn Fn(&FrameGcMeta, u64) -> f32 + Send + Sync;
+
 /// The GC engine evaluates retention scores and decides decay levels.
 ///
 /// # Example
@@ -158,13 +249,27 @@ pub struct GcResult {
 /// let score = engine.retention_score(&meta, now);
 /// assert!(score < 0.7);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct GcEngine {
     config: GcConfig,
     /// Set of pinned frame IDs.
     pinned: HashSet<u64>,
     /// Reference counts per frame.
     ref_counts: HashMap<u64, u32>,
+    /// Custom scorer used by [`evaluate`](Self::evaluate) instead of
+    /// [`retention_score`](Self::retention_score).
+    scorer: Option<Arc<ScoreFn>>,
+}
+
+impl std::fmt::Debug for GcEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcEngine")
+            .field("config", &self.config)
+            .field("pinned", &self.pinned)
+            .field("ref_counts", &self.ref_counts)
+            .field("scorer", &self.scorer.as_ref().map(|_| "custom"))
+            .finish()
+    }
 }
 
 impl GcEngine {
@@ -174,6 +279,7 @@ impl GcEngine {
             config,
             pinned: HashSet::new(),
             ref_counts: HashMap::new(),
+            scorer: None,
         }
     }
 
@@ -340,9 +446,45 @@ impl GcEngine {
         }
     }
 
+    /// Installs a custom retention scorer used by [`evaluate`](Self::evaluate)
+    /// in place of [`retention_score`](Self::retention_score).
+    ///
+    /// Pinned frames still score 1.0 without consulting the scorer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::gc::{GcEngine, FrameGcMeta};
+    /// use volt_db::compressed::DecayLevel;
+    ///
+    /// let mut engine = GcEngine::with_defaults();
+    /// // Keep everything in strand 7 at full fidelity.
+    /// engine.set_scorer(Box::new(|meta: &FrameGcMeta, _now| {
+    ///     if meta.strand_id == 7 { 1.0 } else { 0.0 }
+    /// }));
+    ///
+    /// let frame = FrameGcMeta {
+    ///     frame_id: 1, strand_id: 7, created_at: 0,
+    ///     global_certainty: 0.0, current_level: DecayLevel::Full,
+    ///     reference_count: 0, is_pinned: false, is_wisdom: false,
+    /// };
+    /// assert!(engine.evaluate(&[frame], 100 * 86_400_000_000).is_empty());
+    /// ```
+    pub fn set_scorer(&mut self, scorer: GcScorer) {
+        self.scorer = Some(Arc::from(scorer));
+    }
+
+    /// Removes any custom scorer, restoring the built-in formula.
+    pub fn clear_scorer(&mut self) {
+        self.scorer = None;
+    }
+
     /// Evaluates a batch of frames and returns their target decay levels.
     ///
-    /// Only returns entries where the target level differs from the current level.
+    /// Scores come from the custom scorer if one is set (see
+    /// [`set_scorer`](Self::set_scorer)), otherwise from
+    /// [`retention_score`](Self::retention_score). Only returns entries
+    /// where the target level differs from the current level.
     ///
     /// # Example
     ///
@@ -367,7 +509,11 @@ impl GcEngine {
     pub fn evaluate(&self, frames: &[FrameGcMeta], now: u64) -> Vec<(u64, DecayLevel)> {
         let mut demotions = Vec::new();
         for meta in frames {
-            let score = self.retention_score(meta, now);
+            let score = match &self.scorer {
+                Some(_) if meta.is_pinned || self.pinned.contains(&meta.frame_id) => 1.0,
+                Some(scorer) => scorer(meta, now) as f64,
+                None => self.retention_score(meta, now),
+            };
             let target = self.target_level(score, meta.current_level);
             if target != meta.current_level {
                 demotions.push((meta.frame_id, target));
@@ -569,6 +715,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn high_custom_scorer_prevents_demotion() {
+        let mut engine = GcEngine::with_defaults();
+        engine.set_scorer(Box::new(|_, _| 1.0));
+        let old = FrameGcMeta {
+            created_at: 0,
+            ..fresh_frame(1, 0.0)
+        };
+
+        // The built-in formula would tombstone this frame.
+        assert!(engine.retention_score(&old, days(200)) < 0.1);
+        assert!(engine.evaluate(&[old, fresh_frame(2, 0.1)], days(200)).is_empty());
+    }
+
+    #[test]
+    fn low_custom_scorer_demotes_and_respects_pins() {
+        let mut engine = GcEngine::with_defaults();
+        engine.set_scorer(Box::new(|_, _| 0.0));
+        engine.pin_frame(3);
+        let frames = [fresh_frame(1, 0.9), fresh_frame(2, 0.99), fresh_frame(3, 0.0)];
+
+        let demotions = engine.evaluate(&frames, days(100));
+        assert_eq!(
+            demotions,
+            vec![(1, DecayLevel::Tombstoned), (2, DecayLevel::Tombstoned)]
+        );
+
+        engine.clear_scorer();
+        assert!(engine.evaluate(&frames, days(100)).is_empty());
+    }
+
+    #[test]
+    fn access_tracker_decays_by_half_life() {
+        let mut tracker = AccessTracker::default();
+        for _ in 0..4 {
+            tracker.record(1);
+        }
+        tracker.record(2);
+
+        // First decay only anchors the clock
+        tracker.decay_to(days(10), 7.0);
+        assert_eq!(tracker.count(1), 4);
+
+        // Going backwards in time is a no-op
+        tracker.decay_to(days(3), 7.0);
+        assert_eq!(tracker.count(1), 4);
+
+        // One half-life: 4 → 2, and frame 2 (1 → 0.5) is dropped
+        tracker.decay_to(days(17), 7.0);
+        assert_eq!(tracker.count(1), 2);
+        assert_eq!(tracker.count(2), 0);
+
+        tracker.forget(1);
+        assert_eq!(tracker.count(1), 0);
+    }
+
     #[test]
     fn pin_unpin() {
         let mut engine = GcEngine::with_defaults();
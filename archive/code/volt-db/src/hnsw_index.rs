@@ -4,22 +4,203 @@
 //! queries over frame R₀ gists. Each strand has its own HNSW index,
 //! providing natural strand isolation.
 //!
-//! The HNSW index is rebuilt from stored gists on load (not serialized),
-//! matching the pattern from [`volt_bus::codebook::Codebook`].
+//! [`HnswIndex::save`] writes each strand's graph with `hnsw_rs`'s own
+//! dump format, next to a versioned manifest holding the params, the
+//! soft-deleted set, each strand's frame IDs and the dump files'
+//! checksums. [`HnswIndex::load`] reads the graphs back as saved, so no
+//! gist is re-inserted and queries return the same neighbours.
+//!
+//! ## Distance metric
+//!
+//! Indices rank by cosine distance unless built with
+//! [`DistanceMetric::Euclidean`] in [`HnswParams::metric`], in which case
+//! the graph is built and searched by L2 distance.
+//!
+//! ## Quantization
+//!
+//! With [`HnswParams::quantize`] set, each gist is stored as `i8` codes
+//! plus one `f32` scale (`max |x| / 127`) instead of `[f32; 256]`,
+//! cutting the index's gist storage roughly 4x (260 vs 1024 bytes).
+//! The graph is built and searched over the codes; because the scale
+//! cancels in cosine distance, no dequantization is needed there.
+//! Candidates are then re-ranked by the exact distance between the f32
+//! query and the dequantized gist, which is the distance reported in
+//! [`SimilarityResult`]. On 1000 clustered unit vectors, top-10 recall is
+//! within 1% of the unquantized index (see the
+//! `quantized_recall_within_one_percent` test).
+//!
+//! ## Batched queries
+//!
+//! [`HnswIndex::query_batch`] and [`HnswIndex::query_strand_batch`] run
+//! many queries against the same index in one call, returning results in
+//! query order. With the `parallel` feature the queries are spread over
+//! the rayon pool; each query's results are identical to the single-query
+//! methods either way.
 
 use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
 
 use hnsw_rs::prelude::*;
 use volt_core::{VoltError, SLOT_DIM};
 
 use crate::gist::FrameGist;
 
-// HNSW tuning constants — same as volt-bus codebook for consistency.
+// Default HNSW tuning — same as volt-bus codebook for consistency.
 const HNSW_M: usize = 24;
 const HNSW_MAX_LAYER: usize = 16;
 const HNSW_EF_CONSTRUCTION: usize = 200;
 const HNSW_EF_SEARCH: usize = 32;
 
+/// Candidates fetched per requested result before re-ranking a
+/// quantized search by exact distance.
+const QUANTIZED_OVERSAMPLE: usize = 4;
+
+/// Manifest file inside a saved index directory; the per-strand graph
+/// dumps sit beside it.
+const INDEX_MANIFEST: &str = "index.bin";
+
+/// Magic bytes at the start of a saved index manifest.
+const INDEX_MAGIC: &[u8; 4] = b"VHNS";
+
+/// Format version of a saved [`HnswIndex`]. [`HnswIndex::load`] rejects
+/// indices written with any other version.
+pub const HNSW_INDEX_VERSION: u32 = 3;
+
+/// Extensions `hnsw_rs` gives the two files of a graph dump.
+const DUMP_EXTENSIONS: [&str; 2] = ["hnsw.graph", "hnsw.data"];
+
+/// Distance an HNSW index is built and searched with.
+///
+/// # Example
+///
+/// ```
+/// use volt_db::hnsw_index::DistanceMetric;
+///
+/// assert_eq!(DistanceMetric::default(), DistanceMetric::Cosine);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// Cosine distance (0.0 = identical direction, 2.0 = opposite).
+    #[default]
+    Cosine,
+    /// Euclidean (L2) distance, sensitive to gist magnitude.
+    Euclidean,
+}
+
+/// Construction options for [`StrandHnsw`] and [`HnswIndex`].
+///
+/// # Example
+///
+/// ```
+/// use volt_db::hnsw_index::{DistanceMetric, HnswIndex, HnswParams};
+///
+/// assert!(!HnswParams::default().quantize);
+/// assert_eq!(HnswParams::default().metric, DistanceMetric::Cosine);
+/// assert_eq!(HnswParams::default().ef_search, 32);
+/// let params = HnswParams { quantize: true, ..HnswParams::default() };
+/// let index = HnswIndex::with_params(params);
+/// assert!(index.params().quantize);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HnswParams {
+    /// Store gists as `i8` codes with a per-gist scale (~4x smaller)
+    /// instead of full `f32` vectors. Off by default.
+    ///
+    /// Only applies to [`DistanceMetric::Cosine`]: the scale does not
+    /// cancel in L2 distance, so Euclidean indices always store full gists.
+    pub quantize: bool,
+    /// Distance used to build and search the graph. Default: cosine.
+    pub metric: DistanceMetric,
+    /// Maximum links per node in the graph. Higher values improve recall
+    /// at the cost of memory and build time. Default: 24.
+    pub m: usize,
+    /// Candidate list size while inserting. Higher values build a better
+    /// graph more slowly. Default: 200.
+    pub ef_construction: usize,
+    /// Candidate list size while searching, unless a query overrides it
+    /// (see [`HnswIndex::query_all_ef`]). Higher values trade speed for
+    /// recall. Default: 32.
+    pub ef_search: usize,
+}
+
+impl Default for HnswParams {
+    fn default() -> Self {
+        Self {
+            quantize: false,
+            metric: DistanceMetric::Cosine,
+            m: HNSW_M,
+            ef_construction: HNSW_EF_CONSTRUCTION,
+            ef_search: HNSW_EF_SEARCH,
+        }
+    }
+}
+
+/// A gist scalar-quantized to `i8` with a shared scale.
+#[derive(Debug, Clone, Copy)]
+struct QuantizedGist {
+    codes: [i8; SLOT_DIM],
+    scale: f32,
+}
+
+impl QuantizedGist {
+    fn quantize(vector: &[f32; SLOT_DIM]) -> Self {
+        let max_abs = vector.iter().fold(0.0f32, |m, x| m.max(x.abs()));
+        let scale = if max_abs > 0.0 { max_abs / 127.0 } else { 1.0 };
+        let mut codes = [0i8; SLOT_DIM];
+        for (code, x) in codes.iter_mut().zip(vector) {
+            *code = (x / scale).round().clamp(-127.0, 127.0) as i8;
+        }
+        Self { codes, scale }
+    }
+
+    fn dequantize(&self) -> [f32; SLOT_DIM] {
+        let mut vector = [0.0f32; SLOT_DIM];
+        for (x, &code) in vector.iter_mut().zip(&self.codes) {
+            *x = code as f32 * self.scale;
+        }
+        vector
+    }
+}
+
+/// Cosine distance over `i8` codes. The per-gist scale cancels out,
+/// so this equals the cosine distance between the dequantized vectors.
+#[derive(Debug, Default, Clone, Copy)]
+struct DistQuantizedCosine;
+
+impl Distance<i8> for DistQuantizedCosine {
+    fn eval(&self, va: &[i8], vb: &[i8]) -> f32 {
+        let (mut dot, mut norm_a, mut norm_b) = (0i64, 0i64, 0i64);
+        for (&a, &b) in va.iter().zip(vb) {
+            let (a, b) = (a as i64, b as i64);
+            dot += a * b;
+            norm_a += a * a;
+            norm_b += b * b;
+        }
+        if norm_a == 0 || norm_b == 0 {
+            return 0.0;
+        }
+        (1.0 - dot as f64 / ((norm_a as f64) * (norm_b as f64)).sqrt()).max(0.0) as f32
+    }
+}
+
+/// The HNSW graph together with the gists it indexes.
+enum Graph {
+    Full {
+        index: Hnsw<'static, f32, DistCosine>,
+        gists: Vec<[f32; SLOT_DIM]>,
+    },
+    Quantized {
+        index: Hnsw<'static, i8, DistQuantizedCosine>,
+        gists: Vec<QuantizedGist>,
+    },
+    Euclidean {
+        index: Hnsw<'static, f32, DistL2>,
+        gists: Vec<[f32; SLOT_DIM]>,
+    },
+}
+
 /// A single search result from the HNSW index.
 ///
 /// # Example
@@ -42,7 +223,8 @@ pub struct SimilarityResult {
     pub frame_id: u64,
     /// The strand this frame belongs to.
     pub strand_id: u64,
-    /// Cosine distance from the query (0.0 = identical, 2.0 = opposite).
+    /// Distance from the query under the index's [`DistanceMetric`]
+    /// (for cosine: 0.0 = identical, 2.0 = opposite).
     pub distance: f32,
     /// The R₀ gist vector of the matched frame.
     pub gist: [f32; SLOT_DIM],
@@ -51,25 +233,32 @@ pub struct SimilarityResult {
 /// HNSW index for a single strand.
 ///
 /// Stores gist vectors and maintains an HNSW graph for fast ANN queries.
-/// The internal HNSW uses `DistCosine` for cosine distance.
+/// The internal HNSW uses `DistCosine` for cosine distance, or `DistL2`
+/// when built with [`DistanceMetric::Euclidean`].
 pub struct StrandHnsw {
-    /// HNSW index over this strand's gists.
-    index: Hnsw<'static, f32, DistCosine>,
+    /// HNSW index over this strand's gists, and the stored gists
+    /// (parallel to id_map).
+    graph: Graph,
     /// Maps internal HNSW ID (usize) → frame_id (u64).
     id_map: Vec<u64>,
-    /// Stored gist vectors (parallel to id_map).
-    gists: Vec<[f32; SLOT_DIM]>,
     /// The strand this index covers.
     strand_id: u64,
+    /// Default search candidate list size, from [`HnswParams::ef_search`].
+    ef_search: usize,
+    /// Loader a graph reloaded by [`StrandHnsw::load_from`] borrows from.
+    /// Declared after `graph` so it is dropped after the graph.
+    #[allow(dead_code)]
+    loader: Option<Arc<HnswIo>>,
 }
 
 impl std::fmt::Debug for StrandHnsw {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "StrandHnsw(strand={}, entries={})",
+            "StrandHnsw(strand={}, entries={}, quantized={})",
             self.strand_id,
-            self.gists.len()
+            self.len(),
+            self.is_quantized()
         )
     }
 }
@@ -92,19 +281,63 @@ impl StrandHnsw {
     /// assert_eq!(idx.len(), 0);
     /// ```
     pub fn new(strand_id: u64, initial_capacity: usize) -> Self {
+        Self::with_params(strand_id, initial_capacity, HnswParams::default())
+    }
+
+    /// Creates a new empty HNSW index for a strand with explicit params.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::{HnswParams, StrandHnsw};
+    ///
+    /// let params = HnswParams { quantize: true, ..HnswParams::default() };
+    /// let idx = StrandHnsw::with_params(0, 100, params);
+    /// assert!(idx.is_quantized());
+    /// assert!(idx.is_empty());
+    /// ```
+    pub fn with_params(strand_id: u64, initial_capacity: usize, params: HnswParams) -> Self {
         let capacity = initial_capacity.max(16);
-        let index = Hnsw::<f32, DistCosine>::new(
-            HNSW_M,
-            capacity,
-            HNSW_MAX_LAYER,
-            HNSW_EF_CONSTRUCTION,
-            DistCosine,
-        );
+        let graph = if params.metric == DistanceMetric::Euclidean {
+            Graph::Euclidean {
+                index: Hnsw::new(
+                    params.m,
+                    capacity,
+                    HNSW_MAX_LAYER,
+                    params.ef_construction,
+                    DistL2,
+                ),
+                gists: Vec::with_capacity(capacity),
+            }
+        } else if params.quantize {
+            Graph::Quantized {
+                index: Hnsw::new(
+                    params.m,
+                    capacity,
+                    HNSW_MAX_LAYER,
+                    params.ef_construction,
+                    DistQuantizedCosine,
+                ),
+                gists: Vec::with_capacity(capacity),
+            }
+        } else {
+            Graph::Full {
+                index: Hnsw::new(
+                    params.m,
+                    capacity,
+                    HNSW_MAX_LAYER,
+                    params.ef_construction,
+                    DistCosine,
+                ),
+                gists: Vec::with_capacity(capacity),
+            }
+        };
         Self {
-            index,
+            graph,
             id_map: Vec::with_capacity(capacity),
-            gists: Vec::with_capacity(capacity),
             strand_id,
+            ef_search: params.ef_search,
+            loader: None,
         }
     }
 
@@ -141,19 +374,150 @@ impl StrandHnsw {
             });
         }
 
-        let internal_id = self.gists.len();
-        self.gists.push(gist.vector);
+        let internal_id = self.len();
+        match &mut self.graph {
+            Graph::Full { index, gists } => {
+                gists.push(gist.vector);
+                index.insert((&gists[internal_id][..], internal_id));
+            }
+            Graph::Euclidean { index, gists } => {
+                gists.push(gist.vector);
+                index.insert((&gists[internal_id][..], internal_id));
+            }
+            Graph::Quantized { index, gists } => {
+                gists.push(QuantizedGist::quantize(&gist.vector));
+                index.insert((&gists[internal_id].codes[..], internal_id));
+            }
+        }
         self.id_map.push(gist.frame_id);
-        self.index.insert((&self.gists[internal_id][..], internal_id));
 
         Ok(())
     }
 
+    /// Dumps this strand's graph into `dir` and appends its partition
+    /// (frame IDs, quantization scales, dump name and checksums) to the
+    /// manifest buffer.
+    fn save_to(&self, dir: &Path, buf: &mut Vec<u8>) -> Result<(), VoltError> {
+        let basename = format!("strand_{}", self.strand_id);
+        let dumped = match &self.graph {
+            Graph::Full { index, .. } => index.file_dump(dir, &basename),
+            Graph::Euclidean { index, .. } => index.file_dump(dir, &basename),
+            Graph::Quantized { index, .. } => index.file_dump(dir, &basename),
+        }
+        .map_err(|e| VoltError::StorageError {
+            message: format!("failed to dump HNSW graph for strand {}: {e}", self.strand_id),
+        })?;
+
+        buf.extend_from_slice(&self.strand_id.to_le_bytes());
+        buf.extend_from_slice(&(self.len() as u64).to_le_bytes());
+        for frame_id in &self.id_map {
+            buf.extend_from_slice(&frame_id.to_le_bytes());
+        }
+        if let Graph::Quantized { gists, .. } = &self.graph {
+            for gist in gists {
+                buf.extend_from_slice(&gist.scale.to_le_bytes());
+            }
+        }
+        buf.extend_from_slice(&(dumped.len() as u64).to_le_bytes());
+        buf.extend_from_slice(dumped.as_bytes());
+        for ext in DUMP_EXTENSIONS {
+            let path = dir.join(format!("{dumped}.{ext}"));
+            let data = std::fs::read(&path).map_err(|e| VoltError::StorageError {
+                message: format!("failed to read HNSW dump {}: {e}", path.display()),
+            })?;
+            buf.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            buf.extend_from_slice(&crc32fast::hash(&data).to_le_bytes());
+        }
+        Ok(())
+    }
+
+    /// Reads one strand partition written by [`StrandHnsw::save_to`] and
+    /// loads its graph dump from `dir`.
+    fn load_from(
+        reader: &mut IndexReader<'_>,
+        dir: &Path,
+        params: HnswParams,
+    ) -> Result<Self, VoltError> {
+        let strand_id = reader.u64()?;
+        let len = reader.u64()? as usize;
+        let mut id_map = Vec::with_capacity(len.min(reader.data.len() / 8));
+        for _ in 0..len {
+            id_map.push(reader.u64()?);
+        }
+        let quantized = params.quantize && params.metric == DistanceMetric::Cosine;
+        let mut scales = Vec::new();
+        if quantized {
+            for _ in 0..len {
+                scales.push(reader.f32()?);
+            }
+        }
+        let name_len = reader.u64()? as usize;
+        let basename = std::str::from_utf8(reader.take(name_len)?)
+            .map_err(|_| reader.error("dump name is not UTF-8"))?
+            .to_owned();
+        for ext in DUMP_EXTENSIONS {
+            let size = reader.u64()?;
+            let crc = reader.u32()?;
+            let path = dir.join(format!("{basename}.{ext}"));
+            let data = std::fs::read(&path).map_err(|e| VoltError::StorageError {
+                message: format!("failed to read HNSW dump {}: {e}", path.display()),
+            })?;
+            if data.len() as u64 != size || crc32fast::hash(&data) != crc {
+                return Err(reader.error(&format!("checksum mismatch in {}", path.display())));
+            }
+        }
+
+        // hnsw_rs ties a reloaded graph's lifetime to its loader, so the
+        // loader is kept next to the graph for as long as the graph lives.
+        let loader = Arc::new(HnswIo::new(dir, &basename));
+        // SAFETY: `loader` is moved into the returned StrandHnsw, which
+        // never mutates or replaces it and drops it only after `graph`
+        // (field order), so the graph never outlives what it borrows.
+        // The Arc's heap allocation does not move with the struct.
+        let io: &'static HnswIo = unsafe { &*Arc::as_ptr(&loader) };
+        let dump_error = |e: String| VoltError::StorageError {
+            message: format!("failed to load HNSW graph for strand {strand_id}: {e}"),
+        };
+        let corrupt = || reader.error(&format!("graph for strand {strand_id} does not match its frame IDs"));
+        let graph = if params.metric == DistanceMetric::Euclidean {
+            let index: Hnsw<'static, f32, DistL2> =
+                io.load_hnsw_with_dist(DistL2).map_err(|e| dump_error(e.to_string()))?;
+            let gists = dumped_vectors(&index, len).ok_or_else(corrupt)?;
+            Graph::Euclidean { index, gists }
+        } else if quantized {
+            let index: Hnsw<'static, i8, DistQuantizedCosine> =
+                io.load_hnsw_with_dist(DistQuantizedCosine).map_err(|e| dump_error(e.to_string()))?;
+            let gists = dumped_vectors(&index, len)
+                .ok_or_else(corrupt)?
+                .into_iter()
+                .zip(scales)
+                .map(|(codes, scale)| QuantizedGist { codes, scale })
+                .collect();
+            Graph::Quantized { index, gists }
+        } else {
+            let index: Hnsw<'static, f32, DistCosine> =
+                io.load_hnsw_with_dist(DistCosine).map_err(|e| dump_error(e.to_string()))?;
+            let gists = dumped_vectors(&index, len).ok_or_else(corrupt)?;
+            Graph::Full { index, gists }
+        };
+
+        Ok(Self {
+            graph,
+            id_map,
+            strand_id,
+            ef_search: params.ef_search,
+            loader: Some(loader),
+        })
+    }
+
     /// Queries the top-k most similar gists to the given query vector.
     ///
     /// Returns results sorted by ascending distance (closest first).
     /// Returns an empty vec if the index is empty or k is 0.
     ///
+    /// For a quantized index the returned `gist` is the dequantized
+    /// vector and `distance` is measured against it.
+    ///
     /// # Example
     ///
     /// ```
@@ -175,31 +539,115 @@ impl StrandHnsw {
     /// assert_eq!(results[0].frame_id, 1);
     /// ```
     pub fn query(&self, query: &[f32; SLOT_DIM], k: usize) -> Vec<SimilarityResult> {
-        if self.gists.is_empty() || k == 0 {
+        self.query_ef(query, k, self.ef_search)
+    }
+
+    /// Like [`StrandHnsw::query`], searching with candidate list size
+    /// `ef` instead of the index's [`HnswParams::ef_search`].
+    pub fn query_ef(
+        &self,
+        query: &[f32; SLOT_DIM],
+        k: usize,
+        ef: usize,
+    ) -> Vec<SimilarityResult> {
+        if self.is_empty() || k == 0 {
             return Vec::new();
         }
 
-        let neighbours = self.index.search(query.as_slice(), k, HNSW_EF_SEARCH);
-
-        neighbours
-            .into_iter()
-            .map(|n| SimilarityResult {
-                frame_id: self.id_map[n.d_id],
-                strand_id: self.strand_id,
-                distance: n.distance,
-                gist: self.gists[n.d_id],
-            })
-            .collect()
+        match &self.graph {
+            Graph::Full { index, gists } => index
+                .search(query.as_slice(), k, ef)
+                .into_iter()
+                .map(|n| SimilarityResult {
+                    frame_id: self.id_map[n.d_id],
+                    strand_id: self.strand_id,
+                    distance: n.distance,
+                    gist: gists[n.d_id],
+                })
+                .collect(),
+            Graph::Euclidean { index, gists } => index
+                .search(query.as_slice(), k, ef)
+                .into_iter()
+                .map(|n| SimilarityResult {
+                    frame_id: self.id_map[n.d_id],
+                    strand_id: self.strand_id,
+                    distance: n.distance,
+                    gist: gists[n.d_id],
+                })
+                .collect(),
+            Graph::Quantized { index, gists } => {
+                let codes = QuantizedGist::quantize(query).codes;
+                let fetch_k = k.saturating_mul(QUANTIZED_OVERSAMPLE);
+                let ef = ef.max(fetch_k);
+                let mut results: Vec<SimilarityResult> = index
+                    .search(&codes[..], fetch_k, ef)
+                    .into_iter()
+                    .map(|n| {
+                        let gist = gists[n.d_id].dequantize();
+                        SimilarityResult {
+                            frame_id: self.id_map[n.d_id],
+                            strand_id: self.strand_id,
+                            distance: DistCosine.eval(query.as_slice(), gist.as_slice()),
+                            gist,
+                        }
+                    })
+                    .collect();
+                results.sort_by(|a, b| {
+                    a.distance
+                        .partial_cmp(&b.distance)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                results.truncate(k);
+                results
+            }
+        }
     }
 
     /// Returns the number of entries in this strand's index.
     pub fn len(&self) -> usize {
-        self.gists.len()
+        self.id_map.len()
     }
 
     /// Returns true if the index is empty.
     pub fn is_empty(&self) -> bool {
-        self.gists.is_empty()
+        self.id_map.is_empty()
+    }
+
+    /// Returns the indexed frame IDs in insertion order.
+    pub fn frame_ids(&self) -> &[u64] {
+        &self.id_map
+    }
+
+    /// Returns true if gists are stored scalar-quantized.
+    pub fn is_quantized(&self) -> bool {
+        matches!(self.graph, Graph::Quantized { .. })
+    }
+
+    /// Approximate bytes used by the stored gist vectors (excluding the
+    /// graph links).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::{HnswParams, StrandHnsw};
+    /// use volt_db::gist::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let gist = FrameGist { vector: [0.1; SLOT_DIM], frame_id: 1, strand_id: 0, created_at: 0 };
+    /// let mut full = StrandHnsw::new(0, 16);
+    /// let params = HnswParams { quantize: true, ..HnswParams::default() };
+    /// let mut quantized = StrandHnsw::with_params(0, 16, params);
+    /// full.insert(&gist).unwrap();
+    /// quantized.insert(&gist).unwrap();
+    /// assert!(quantized.gist_bytes() * 3 < full.gist_bytes());
+    /// ```
+    pub fn gist_bytes(&self) -> usize {
+        match &self.graph {
+            Graph::Full { gists, .. } | Graph::Euclidean { gists, .. } => {
+                gists.len() * std::mem::size_of::<[f32; SLOT_DIM]>()
+            }
+            Graph::Quantized { gists, .. } => gists.len() * std::mem::size_of::<QuantizedGist>(),
+        }
     }
 
     /// Returns the strand ID this index covers.
@@ -232,6 +680,8 @@ impl StrandHnsw {
 /// ```
 pub struct HnswIndex {
     strands: HashMap<u64, StrandHnsw>,
+    /// Params applied to every strand index created by this collection.
+    params: HnswParams,
     /// Frame IDs that have been soft-deleted (tombstoned by GC).
     /// Query results filter these out. Cleared on index rebuild (load).
     deleted: HashSet<u64>,
@@ -266,12 +716,40 @@ impl HnswIndex {
     /// assert_eq!(index.total_entries(), 0);
     /// ```
     pub fn new() -> Self {
+        Self::with_params(HnswParams::default())
+    }
+
+    /// Creates a new empty collection whose strand indices use `params`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::{HnswIndex, HnswParams};
+    /// use volt_db::gist::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let params = HnswParams { quantize: true, ..HnswParams::default() };
+    /// let mut index = HnswIndex::with_params(params);
+    /// let gist = FrameGist { vector: [0.1; SLOT_DIM], frame_id: 1, strand_id: 0, created_at: 0 };
+    /// index.insert(&gist).unwrap();
+    ///
+    /// let results = index.query_strand(0, &[0.1; SLOT_DIM], 1);
+    /// assert_eq!(results[0].frame_id, 1);
+    /// assert!(results[0].distance < 1e-3);
+    /// ```
+    pub fn with_params(params: HnswParams) -> Self {
         Self {
             strands: HashMap::new(),
+            params,
             deleted: HashSet::new(),
         }
     }
 
+    /// Returns the params used for new strand indices.
+    pub fn params(&self) -> HnswParams {
+        self.params
+    }
+
     /// Inserts a gist into the appropriate strand's HNSW index.
     ///
     /// Creates the strand index automatically if it doesn't exist.
@@ -301,7 +779,7 @@ impl HnswIndex {
         let strand_index = self
             .strands
             .entry(gist.strand_id)
-            .or_insert_with(|| StrandHnsw::new(gist.strand_id, 64));
+            .or_insert_with(|| StrandHnsw::with_params(gist.strand_id, 64, self.params));
         strand_index.insert(gist)
     }
 
@@ -388,6 +866,39 @@ impl HnswIndex {
     /// assert_eq!(results.len(), 2);
     /// ```
     pub fn query_all(&self, query: &[f32; SLOT_DIM], k: usize) -> Vec<SimilarityResult> {
+        self.query_all_ef(query, k, self.params.ef_search)
+    }
+
+    /// Like [`HnswIndex::query_all`], searching every strand with
+    /// candidate list size `ef` instead of [`HnswParams::ef_search`].
+    ///
+    /// Raise `ef` for better recall on a large index, or lower it for
+    /// faster queries. Values below `k` are searched with `k`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::HnswIndex;
+    /// use volt_db::gist::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut index = HnswIndex::new();
+    /// index.insert(&FrameGist {
+    ///     vector: [0.1; SLOT_DIM],
+    ///     frame_id: 1,
+    ///     strand_id: 0,
+    ///     created_at: 0,
+    /// }).unwrap();
+    ///
+    /// let results = index.query_all_ef(&[0.1; SLOT_DIM], 5, 400);
+    /// assert_eq!(results[0].frame_id, 1);
+    /// ```
+    pub fn query_all_ef(
+        &self,
+        query: &[f32; SLOT_DIM],
+        k: usize,
+        ef: usize,
+    ) -> Vec<SimilarityResult> {
         if k == 0 {
             return Vec::new();
         }
@@ -401,7 +912,7 @@ impl HnswIndex {
         let mut all_results: Vec<SimilarityResult> = self
             .strands
             .values()
-            .flat_map(|strand_index| strand_index.query(query, fetch_k))
+            .flat_map(|strand_index| strand_index.query_ef(query, fetch_k, ef.max(fetch_k)))
             .filter(|r| !self.deleted.contains(&r.frame_id))
             .collect();
 
@@ -411,6 +922,63 @@ impl HnswIndex {
         all_results
     }
 
+    /// Runs [`query_all`](Self::query_all) for every query in `queries`.
+    ///
+    /// Returns one result list per query, in query order; each list is
+    /// exactly what `query_all(query, k)` returns. Runs on the rayon pool
+    /// when the `parallel` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::HnswIndex;
+    /// use volt_db::gist::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut index = HnswIndex::new();
+    /// index.insert(&FrameGist {
+    ///     vector: [0.1; SLOT_DIM],
+    ///     frame_id: 1,
+    ///     strand_id: 0,
+    ///     created_at: 0,
+    /// }).unwrap();
+    ///
+    /// let results = index.query_batch(&[[0.1; SLOT_DIM], [-0.1; SLOT_DIM]], 5);
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[0][0].frame_id, 1);
+    /// ```
+    pub fn query_batch(
+        &self,
+        queries: &[[f32; SLOT_DIM]],
+        k: usize,
+    ) -> Vec<Vec<SimilarityResult>> {
+        map_queries(queries, |query| self.query_all(query, k))
+    }
+
+    /// Runs [`query_strand`](Self::query_strand) on `strand_id` for every
+    /// query in `queries`, returning one result list per query in query
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::HnswIndex;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let index = HnswIndex::new();
+    /// let results = index.query_strand_batch(0, &[[0.1; SLOT_DIM]], 5);
+    /// assert_eq!(results.len(), 1);
+    /// assert!(results[0].is_empty());
+    /// ```
+    pub fn query_strand_batch(
+        &self,
+        strand_id: u64,
+        queries: &[[f32; SLOT_DIM]],
+        k: usize,
+    ) -> Vec<Vec<SimilarityResult>> {
+        map_queries(queries, |query| self.query_strand(strand_id, query, k))
+    }
+
     /// Returns a list of strand IDs that have HNSW indices.
     pub fn indexed_strands(&self) -> Vec<u64> {
         self.strands.keys().copied().collect()
@@ -423,11 +991,40 @@ impl HnswIndex {
         raw.saturating_sub(self.deleted.len())
     }
 
+    /// Returns the frame IDs of every live (not soft-deleted) entry
+    /// across all strands, sorted ascending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::HnswIndex;
+    /// use volt_db::gist::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut index = HnswIndex::new();
+    /// for (frame_id, strand_id) in [(3, 0), (1, 7), (2, 0)] {
+    ///     let gist = FrameGist { vector: [0.1; SLOT_DIM], frame_id, strand_id, created_at: 0 };
+    ///     index.insert(&gist).unwrap();
+    /// }
+    /// index.mark_deleted(2);
+    /// assert_eq!(index.frame_ids(), vec![1, 3]);
+    /// ```
+    pub fn frame_ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .strands
+            .values()
+            .flat_map(|s| s.frame_ids().iter().copied())
+            .filter(|id| !self.deleted.contains(id))
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
     /// Marks a frame as soft-deleted in the HNSW index.
     ///
     /// Deleted frames are filtered out of query results. The actual HNSW
-    /// graph entries remain but are invisible to callers. On index rebuild
-    /// (during load), deleted entries are simply not re-inserted.
+    /// graph entries remain but are invisible to callers. A rebuild from
+    /// stored frames leaves them out; [`HnswIndex::save`] keeps the set.
     ///
     /// # Example
     ///
@@ -460,6 +1057,251 @@ impl HnswIndex {
     pub fn is_deleted(&self, frame_id: u64) -> bool {
         self.deleted.contains(&frame_id)
     }
+
+    /// Returns `(frame_id, strand_id)` for every live entry, sorted.
+    pub(crate) fn live_entries(&self) -> Vec<(u64, u64)> {
+        let mut entries: Vec<(u64, u64)> = self
+            .strands
+            .values()
+            .flat_map(|s| s.frame_ids().iter().map(move |&id| (id, s.strand_id())))
+            .filter(|(id, _)| !self.deleted.contains(id))
+            .collect();
+        entries.sort_unstable();
+        entries
+    }
+
+    /// Saves the index to the directory `dir`, replacing any existing one.
+    ///
+    /// Each strand's graph is dumped as `strand_<id>.hnsw.graph` and
+    /// `strand_<id>.hnsw.data`; a manifest records the params, the
+    /// soft-deleted set, each strand's frame IDs and the dumps'
+    /// checksums. Everything is written to a staging directory next to
+    /// `dir` and swapped in at the end, so a crash mid-save leaves either
+    /// the previous index or none, never a partial one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the index cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::HnswIndex;
+    /// use volt_db::gist::FrameGist;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let mut index = HnswIndex::new();
+    /// let gist = FrameGist { vector: [0.1; SLOT_DIM], frame_id: 1, strand_id: 0, created_at: 0 };
+    /// index.insert(&gist).unwrap();
+    ///
+    /// let dir = std::env::temp_dir().join("volt_doc_hnsw_save");
+    /// index.save(&dir).unwrap();
+    /// let loaded = HnswIndex::load(&dir).unwrap();
+    /// assert_eq!(loaded.frame_ids(), vec![1]);
+    /// ```
+    pub fn save(&self, dir: &Path) -> Result<(), VoltError> {
+        let io_error = |action: &str, path: &Path, e: std::io::Error| VoltError::StorageError {
+            message: format!("failed to {action} HNSW index {}: {e}", path.display()),
+        };
+        let staging = dir.with_extension("tmp");
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging).map_err(|e| io_error("clear", &staging, e))?;
+        }
+        std::fs::create_dir_all(&staging).map_err(|e| io_error("create", &staging, e))?;
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(INDEX_MAGIC);
+        buf.extend_from_slice(&HNSW_INDEX_VERSION.to_le_bytes());
+        buf.push(self.params.quantize as u8);
+        buf.push(match self.params.metric {
+            DistanceMetric::Cosine => 0,
+            DistanceMetric::Euclidean => 1,
+        });
+        for value in [self.params.m, self.params.ef_construction, self.params.ef_search] {
+            buf.extend_from_slice(&(value as u64).to_le_bytes());
+        }
+
+        let mut deleted: Vec<u64> = self.deleted.iter().copied().collect();
+        deleted.sort_unstable();
+        buf.extend_from_slice(&(deleted.len() as u64).to_le_bytes());
+        for frame_id in deleted {
+            buf.extend_from_slice(&frame_id.to_le_bytes());
+        }
+
+        // A strand whose only insert failed has no graph to dump
+        let mut strand_ids: Vec<u64> = self
+            .strands
+            .iter()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(&id, _)| id)
+            .collect();
+        strand_ids.sort_unstable();
+        buf.extend_from_slice(&(strand_ids.len() as u64).to_le_bytes());
+        for strand_id in strand_ids {
+            self.strands[&strand_id].save_to(&staging, &mut buf)?;
+        }
+
+        let manifest = staging.join(INDEX_MANIFEST);
+        let write = || -> std::io::Result<()> {
+            let mut file = std::fs::File::create(&manifest)?;
+            file.write_all(&buf)?;
+            file.sync_all()
+        };
+        write().map_err(|e| io_error("write", &manifest, e))?;
+
+        if dir.exists() {
+            std::fs::remove_dir_all(dir).map_err(|e| io_error("replace", dir, e))?;
+        }
+        std::fs::rename(&staging, dir).map_err(|e| io_error("rename", &staging, e))
+    }
+
+    /// Loads an index saved by [`HnswIndex::save`].
+    ///
+    /// The graphs are read back as dumped rather than rebuilt, so queries
+    /// return the same neighbour IDs and distances as the saved index,
+    /// with the same params and soft-deleted set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if a file is missing, truncated
+    /// or fails its checksum, or the index was written with a version
+    /// other than [`HNSW_INDEX_VERSION`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::hnsw_index::HnswIndex;
+    ///
+    /// let dir = std::env::temp_dir().join("volt_doc_hnsw_load_bad");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("index.bin"), b"not an index").unwrap();
+    /// assert!(HnswIndex::load(&dir).is_err());
+    /// ```
+    pub fn load(dir: &Path) -> Result<Self, VoltError> {
+        let path = dir.join(INDEX_MANIFEST);
+        let data = std::fs::read(&path).map_err(|e| VoltError::StorageError {
+            message: format!("failed to read HNSW index {}: {e}", path.display()),
+        })?;
+        let mut reader = IndexReader { data: &data, path: &path };
+
+        if reader.take(INDEX_MAGIC.len())? != INDEX_MAGIC {
+            return Err(reader.error("not an HNSW index manifest"));
+        }
+        let version = reader.u32()?;
+        if version != HNSW_INDEX_VERSION {
+            return Err(reader.error(&format!(
+                "unsupported version {version} (expected {HNSW_INDEX_VERSION})"
+            )));
+        }
+        let quantize = reader.u8()? != 0;
+        let metric = match reader.u8()? {
+            0 => DistanceMetric::Cosine,
+            1 => DistanceMetric::Euclidean,
+            tag => return Err(reader.error(&format!("unknown distance metric tag {tag}"))),
+        };
+        let m = reader.u64()? as usize;
+        let ef_construction = reader.u64()? as usize;
+        let ef_search = reader.u64()? as usize;
+        let mut index = Self::with_params(HnswParams {
+            quantize,
+            metric,
+            m,
+            ef_construction,
+            ef_search,
+        });
+
+        let deleted_count = reader.u64()?;
+        for _ in 0..deleted_count {
+            index.deleted.insert(reader.u64()?);
+        }
+        let strand_count = reader.u64()?;
+        for _ in 0..strand_count {
+            let strand = StrandHnsw::load_from(&mut reader, dir, index.params)?;
+            index.strands.insert(strand.strand_id(), strand);
+        }
+        if !reader.data.is_empty() {
+            return Err(reader.error("trailing bytes after last strand"));
+        }
+        Ok(index)
+    }
+}
+
+/// Collects the vectors of a reloaded graph by internal ID.
+///
+/// Returns `None` unless the graph holds exactly one point for every ID
+/// in `0..len`.
+fn dumped_vectors<T, D>(index: &Hnsw<'static, T, D>, len: usize) -> Option<Vec<[T; SLOT_DIM]>>
+where
+    T: Copy + Send + Sync,
+    D: Distance<T> + Send + Sync,
+{
+    if index.get_nb_point() != len {
+        return None;
+    }
+    let mut vectors: Vec<Option<[T; SLOT_DIM]>> = vec![None; len];
+    for point in index.get_point_indexation() {
+        let slot = vectors.get_mut(point.get_origin_id())?;
+        *slot = Some(point.get_v().try_into().ok()?);
+    }
+    vectors.into_iter().collect()
+}
+
+/// Cursor over the bytes of a saved [`HnswIndex`] manifest.
+struct IndexReader<'a> {
+    data: &'a [u8],
+    path: &'a Path,
+}
+
+impl<'a> IndexReader<'a> {
+    fn error(&self, reason: &str) -> VoltError {
+        VoltError::StorageError {
+            message: format!("invalid HNSW index {}: {reason}", self.path.display()),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], VoltError> {
+        if self.data.len() < len {
+            return Err(self.error("manifest is truncated"));
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, VoltError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, VoltError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().expect("4 bytes")))
+    }
+
+    fn u64(&mut self) -> Result<u64, VoltError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().expect("8 bytes")))
+    }
+
+    fn f32(&mut self) -> Result<f32, VoltError> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().expect("4 bytes")))
+    }
+}
+
+/// Evaluates `f` for every query, returning results in query order.
+///
+/// Runs on the rayon pool when the `parallel` feature is enabled;
+/// serially otherwise.
+fn map_queries<F>(queries: &[[f32; SLOT_DIM]], f: F) -> Vec<Vec<SimilarityResult>>
+where
+    F: Fn(&[f32; SLOT_DIM]) -> Vec<SimilarityResult> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        queries.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        queries.iter().map(f).collect()
+    }
 }
 
 #[cfg(test)]
@@ -467,6 +1309,14 @@ mod tests {
     use super::*;
     use crate::gist::FrameGist;
 
+    const QUANTIZED: HnswParams = HnswParams {
+        quantize: true,
+        metric: DistanceMetric::Cosine,
+        m: HNSW_M,
+        ef_construction: HNSW_EF_CONSTRUCTION,
+        ef_search: HNSW_EF_SEARCH,
+    };
+
     fn make_gist(frame_id: u64, strand_id: u64, value: f32) -> FrameGist {
         FrameGist {
             vector: [value; SLOT_DIM],
@@ -487,8 +1337,143 @@ mod tests {
         }
     }
 
+    fn saved_index_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir()
+            .join("volt_hnsw_save_test")
+            .join(format!("{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn save_load_roundtrip_preserves_query_results() {
+        let euclidean = HnswParams { metric: DistanceMetric::Euclidean, ..HnswParams::default() };
+        for (name, params) in [("full", HnswParams::default()), ("quantized", QUANTIZED), ("l2", euclidean)] {
+            let mut index = HnswIndex::with_params(params);
+            for i in 0..300u64 {
+                let mut gist = make_gist(i + 1, i % 4, 0.0);
+                gist.vector = random_unit(i);
+                index.insert(&gist).unwrap();
+            }
+            index.mark_deleted(11);
+
+            let dir = saved_index_dir(name);
+            index.save(&dir).unwrap();
+            let mut loaded = HnswIndex::load(&dir).unwrap();
+
+            assert_eq!(loaded.params(), params);
+            assert!(loaded.is_deleted(11));
+            assert_eq!(loaded.live_entries(), index.live_entries());
+            let summarize = |results: Vec<SimilarityResult>| -> Vec<(u64, u64, u32)> {
+                results
+                    .iter()
+                    .map(|r| (r.frame_id, r.strand_id, r.distance.to_bits()))
+                    .collect()
+            };
+            for q in 0..50 {
+                let query = random_unit(20_000 + q);
+                assert_eq!(
+                    summarize(loaded.query_all(&query, 10)),
+                    summarize(index.query_all(&query, 10))
+                );
+            }
+
+            // A loaded graph keeps accepting inserts
+            let mut gist = make_gist(1000, 0, 0.0);
+            gist.vector = random_unit(1000);
+            loaded.insert(&gist).unwrap();
+            assert_eq!(loaded.query_strand(0, &gist.vector, 1)[0].frame_id, 1000);
+        }
+    }
+
+    #[test]
+    fn load_rejects_other_versions_and_corrupt_dumps() {
+        let mut index = HnswIndex::new();
+        index.insert(&make_gist(1, 0, 0.1)).unwrap();
+        let dir = saved_index_dir("versioned");
+        index.save(&dir).unwrap();
+        let manifest = dir.join(INDEX_MANIFEST);
+        let bytes = std::fs::read(&manifest).unwrap();
+
+        let mut bumped = bytes.clone();
+        bumped[4..8].copy_from_slice(&(HNSW_INDEX_VERSION + 1).to_le_bytes());
+        std::fs::write(&manifest, &bumped).unwrap();
+        assert!(HnswIndex::load(&dir).is_err());
+
+        std::fs::write(&manifest, &bytes[..bytes.len() - 3]).unwrap();
+        assert!(HnswIndex::load(&dir).is_err());
+
+        std::fs::write(&manifest, &bytes).unwrap();
+        assert!(HnswIndex::load(&dir).is_ok());
+        std::fs::write(dir.join("strand_0.hnsw.graph"), b"garbage").unwrap();
+        assert!(HnswIndex::load(&dir).is_err());
+    }
+
+    #[test]
+    fn higher_ef_search_recall_at_least_as_good() {
+        let low = HnswParams { ef_search: 10, ..HnswParams::default() };
+        let high = HnswParams { ef_search: 200, ..HnswParams::default() };
+        let mut low_index = HnswIndex::with_params(low);
+        let mut high_index = HnswIndex::with_params(high);
+        let vectors: Vec<[f32; SLOT_DIM]> = (0..1000).map(random_unit).collect();
+        for (i, vector) in vectors.iter().enumerate() {
+            let gist = FrameGist { vector: *vector, frame_id: i as u64, strand_id: 0, created_at: 0 };
+            low_index.insert(&gist).unwrap();
+            high_index.insert(&gist).unwrap();
+        }
+
+        let k = 10;
+        let (mut low_hits, mut high_hits, mut override_hits) = (0, 0, 0);
+        for q in 0..50 {
+            let query = random_unit(50_000 + q);
+            let mut exact: Vec<(f32, u64)> = vectors
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (DistCosine.eval(&query, v), i as u64))
+                .collect();
+            exact.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let truth: HashSet<u64> = exact[..k].iter().map(|&(_, id)| id).collect();
+            let recall = |results: Vec<SimilarityResult>| {
+                results.iter().filter(|r| truth.contains(&r.frame_id)).count()
+            };
+            low_hits += recall(low_index.query_all(&query, k));
+            high_hits += recall(high_index.query_all(&query, k));
+            override_hits += recall(low_index.query_all_ef(&query, k, 200));
+        }
+        assert!(high_hits >= low_hits, "high ef recall {high_hits} < low ef {low_hits}");
+        assert!(override_hits >= low_hits);
+    }
+
     // --- StrandHnsw tests ---
 
+    #[test]
+    fn query_batch_matches_sequential_queries() {
+        let mut index = HnswIndex::new();
+        for i in 0..300u64 {
+            let mut gist = make_gist(i + 1, i % 3, 0.0);
+            gist.vector = random_unit(i);
+            index.insert(&gist).unwrap();
+        }
+        index.mark_deleted(7);
+        let queries: Vec<[f32; SLOT_DIM]> =
+            (0..100).map(|q| random_unit(10_000 + q)).collect();
+
+        let summarize = |results: &[SimilarityResult]| -> Vec<(u64, u32)> {
+            results.iter().map(|r| (r.frame_id, r.distance.to_bits())).collect()
+        };
+        let batched = index.query_batch(&queries, 10);
+        assert_eq!(batched.len(), queries.len());
+        for (query, results) in queries.iter().zip(&batched) {
+            assert_eq!(summarize(results), summarize(&index.query_all(query, 10)));
+            assert!(results.windows(2).all(|w| w[0].distance <= w[1].distance));
+        }
+
+        let strand_batched = index.query_strand_batch(1, &queries, 5);
+        for (query, results) in queries.iter().zip(&strand_batched) {
+            assert_eq!(summarize(results), summarize(&index.query_strand(1, query, 5)));
+        }
+    }
+
     #[test]
     fn strand_hnsw_insert_and_query() {
         let mut idx = StrandHnsw::new(0, 100);
@@ -633,6 +1618,171 @@ mod tests {
         assert_eq!(index.total_entries(), 8);
     }
 
+    // --- Quantization tests ---
+
+    /// Deterministic pseudo-random unit vector.
+    fn random_unit(seed: u64) -> [f32; SLOT_DIM] {
+        let mut v = [0.0f32; SLOT_DIM];
+        for (i, x) in v.iter_mut().enumerate() {
+            let mut h = seed.wrapping_mul(0x9e3779b97f4a7c15).wrapping_add(i as u64);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xff51afd7ed558ccd);
+            h ^= h >> 33;
+            *x = (h as f64 / u64::MAX as f64 * 2.0 - 1.0) as f32;
+        }
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        v.map(|x| x / norm)
+    }
+
+    #[test]
+    fn quantize_roundtrip_preserves_direction() {
+        let v = random_unit(7);
+        let q = QuantizedGist::quantize(&v);
+        let restored = q.dequantize();
+        assert!(DistCosine.eval(&v[..], &restored[..]) < 1e-3);
+        // Code-space distance equals distance between dequantized vectors.
+        let w = random_unit(8);
+        let qw = QuantizedGist::quantize(&w);
+        let code_dist = DistQuantizedCosine.eval(&q.codes[..], &qw.codes[..]);
+        let deq_dist = DistCosine.eval(&restored[..], &qw.dequantize()[..]);
+        assert!((code_dist - deq_dist).abs() < 1e-4);
+        // All-zero vectors quantize without dividing by zero.
+        assert!(QuantizedGist::quantize(&[0.0; SLOT_DIM]).dequantize().iter().all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn quantized_recall_within_one_percent() {
+        // 50 clusters of 20 gists each, so top-10 neighbours sit inside a
+        // cluster and their order depends on fine distance differences.
+        const CLUSTERS: u64 = 50;
+        const PER_CLUSTER: u64 = 20;
+        const QUERIES: u64 = 200;
+        const K: usize = 10;
+        const TRIALS: usize = 3;
+
+        let around = |center: &[f32; SLOT_DIM], seed: u64| {
+            let noise = random_unit(seed);
+            let mut v = [0.0f32; SLOT_DIM];
+            for ((x, c), n) in v.iter_mut().zip(center).zip(&noise) {
+                *x = c + 0.5 * n;
+            }
+            let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+            v.map(|x| x / norm)
+        };
+        let centers: Vec<[f32; SLOT_DIM]> = (0..CLUSTERS).map(|c| random_unit(1_000_000 + c)).collect();
+        let vectors: Vec<[f32; SLOT_DIM]> = (0..CLUSTERS * PER_CLUSTER)
+            .map(|i| around(&centers[(i / PER_CLUSTER) as usize], i))
+            .collect();
+        assert_eq!(vectors.len(), 1000);
+
+        let queries: Vec<[f32; SLOT_DIM]> = (0..QUERIES)
+            .map(|q| around(&centers[(q % CLUSTERS) as usize], 2_000_000 + q))
+            .collect();
+        let truths: Vec<HashSet<u64>> = queries
+            .iter()
+            .map(|query| {
+                let mut exact: Vec<(u64, f32)> = vectors
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (i as u64, DistCosine.eval(&query[..], &v[..])))
+                    .collect();
+                exact.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                exact.iter().take(K).map(|(id, _)| *id).collect()
+            })
+            .collect();
+
+        // HNSW layer assignment is randomly seeded, so a single build can
+        // be unlucky either way; average over a few builds of each.
+        let (mut full_hits, mut quantized_hits) = (0usize, 0usize);
+        for _ in 0..TRIALS {
+            let mut full = StrandHnsw::new(0, vectors.len());
+            let mut quantized = StrandHnsw::with_params(0, vectors.len(), QUANTIZED);
+            for (i, v) in vectors.iter().enumerate() {
+                let gist = FrameGist {
+                    vector: *v,
+                    frame_id: i as u64,
+                    strand_id: 0,
+                    created_at: 0,
+                };
+                full.insert(&gist).unwrap();
+                quantized.insert(&gist).unwrap();
+            }
+            assert!(quantized.gist_bytes() * 3 < full.gist_bytes());
+
+            for (query, truth) in queries.iter().zip(&truths) {
+                let hits = |results: Vec<SimilarityResult>| {
+                    results.iter().filter(|r| truth.contains(&r.frame_id)).count()
+                };
+                full_hits += hits(full.query(query, K));
+                quantized_hits += hits(quantized.query(query, K));
+            }
+        }
+
+        let total = (TRIALS * QUERIES as usize * K) as f64;
+        let full_recall = full_hits as f64 / total;
+        let quantized_recall = quantized_hits as f64 / total;
+        assert!(
+            full_recall - quantized_recall < 0.01,
+            "quantized recall {quantized_recall:.3} vs full {full_recall:.3}"
+        );
+    }
+
+    #[test]
+    fn quantized_query_reports_dequantized_distance() {
+        let mut idx = StrandHnsw::with_params(0, 16, QUANTIZED);
+        for i in 0..20 {
+            idx.insert(&make_directional_gist(i + 1, 0, i as usize)).unwrap();
+        }
+        let mut query = [0.0f32; SLOT_DIM];
+        query[3] = 1.0;
+        let results = idx.query(&query, 5);
+        assert_eq!(results.len(), 5);
+        assert_eq!(results[0].frame_id, 4);
+        assert!(results[0].distance < 1e-6);
+        assert_eq!(results[0].gist, query);
+        assert!(results.windows(2).all(|w| w[0].distance <= w[1].distance));
+    }
+
+    #[test]
+    fn euclidean_metric_ranks_by_l2_distance() {
+        // Same direction as the query but far away vs. a nearby vector
+        // pointing slightly elsewhere: cosine and L2 disagree.
+        let query = [0.1f32; SLOT_DIM];
+        let mut near = [0.1f32; SLOT_DIM];
+        near[0] = 0.3;
+        let far = [2.0f32; SLOT_DIM];
+
+        let euclidean = HnswParams {
+            metric: DistanceMetric::Euclidean,
+            ..HnswParams::default()
+        };
+        let mut l2_index = HnswIndex::with_params(euclidean);
+        let mut cos_index = HnswIndex::new();
+        for (frame_id, vector) in [(1, near), (2, far)] {
+            let gist = FrameGist { vector, frame_id, strand_id: 0, created_at: 0 };
+            l2_index.insert(&gist).unwrap();
+            cos_index.insert(&gist).unwrap();
+        }
+
+        let l2 = l2_index.query_all(&query, 2);
+        assert_eq!(l2[0].frame_id, 1);
+        assert!((l2[0].distance - volt_bus::euclidean_distance(&query, &near)).abs() < 1e-5);
+        assert_eq!(cos_index.query_all(&query, 2)[0].frame_id, 2);
+    }
+
+    #[test]
+    fn euclidean_metric_ignores_quantize() {
+        let params = HnswParams {
+            quantize: true,
+            metric: DistanceMetric::Euclidean,
+            ..HnswParams::default()
+        };
+        let mut idx = StrandHnsw::with_params(0, 16, params);
+        assert!(!idx.is_quantized());
+        idx.insert(&make_gist(1, 0, 0.1)).unwrap();
+        assert!(idx.query(&[0.1; SLOT_DIM], 1)[0].distance < 1e-6);
+    }
+
     #[test]
     fn hnsw_index_query_k_zero() {
         let mut index = HnswIndex::new();
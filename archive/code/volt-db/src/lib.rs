@@ -61,10 +61,12 @@ pub mod gc;
 pub mod consolidation;
 mod store;
 
-pub use store::{VoltStore, VoltStoreConfig, ConcurrentVoltStore};
+pub use store::{
+    ConsistencyReport, HNSW_INDEX_DIR, OpenPolicy, RecoveryReport, SnapshotManifest, StoreMetrics, SNAPSHOT_VERSION, T1_FILE, VoltStore, VoltStoreConfig, ConcurrentVoltStore, CONVERSATION_SEARCH_OVERSAMPLE, FRAME_RAM_BYTES,
+};
 pub use gist::{FrameGist, extract_gist};
-pub use hnsw_index::{HnswIndex, SimilarityResult, StrandHnsw};
-pub use temporal::TemporalIndex;
+pub use hnsw_index::{DistanceMetric, HnswIndex, HnswParams, SimilarityResult, StrandHnsw, HNSW_INDEX_VERSION};
+pub use temporal::{TemporalIndex, MAX_HISTOGRAM_BUCKETS};
 pub use ghost::{GhostBuffer, GhostEntry, BleedEngine, GHOST_BUFFER_CAPACITY};
 pub use compressed::{
     CompressedFrame, CompressedSlot, GistFrame, Tombstone,
@@ -72,9 +74,10 @@ pub use compressed::{
 };
 pub use bloom::BloomFilter;
 pub use wal::{WalManager, WalEntry, WalOp};
-pub use tier2::{Tier2Store, T2Config};
-pub use gc::{GcEngine, GcConfig, GcResult, FrameGcMeta};
+pub use tier2::{QuarantinedFile, Tier2Store, T2Config};
+pub use gc::{AccessTracker, GcEngine, GcConfig, GcResult, GcScorer, FrameGcMeta};
 pub use consolidation::{
-    ConsolidationEngine, ConsolidationConfig, ConsolidationResult, FrameCluster,
+    ConsolidationEngine, ConsolidationConfig, ConsolidationProgress, ConsolidationResult,
+    FrameCluster,
 };
 pub use volt_core;
@@ -7,22 +7,110 @@
 //! temporal indexing, Ghost Bleed Engine, WAL crash recovery, garbage collection,
 //! and frame consolidation.
 
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
+use serde::{Deserialize, Serialize};
+use volt_core::meta::DiscourseType;
 use volt_core::{TensorFrame, VoltError, SLOT_DIM};
 
-use crate::compressed::{compress, to_gist_frame, to_tombstone, DecayLevel, FrameEntry};
-use crate::consolidation::{ConsolidationConfig, ConsolidationEngine, ConsolidationResult};
-use crate::gc::{FrameGcMeta, GcConfig, GcEngine, GcResult};
+use crate::compressed::{compress, to_gist_frame, to_tombstone, DecayLevel, FrameEntry, Tombstone};
+use crate::consolidation::{
+    ConsolidationConfig, ConsolidationEngine, ConsolidationProgress, ConsolidationResult,
+    FrameCluster,
+};
+use crate::gc::{AccessTracker, FrameGcMeta, GcConfig, GcEngine, GcResult, GcScorer};
 use crate::ghost::{BleedEngine, GhostBuffer};
 use crate::gist::{extract_gist, FrameGist};
-use crate::hnsw_index::{HnswIndex, SimilarityResult};
+use crate::hnsw_index::{HnswIndex, HnswParams, SimilarityResult};
 use crate::temporal::TemporalIndex;
 use crate::tier0::WorkingMemory;
-use crate::tier1::StrandStore;
-use crate::tier2::{T2Config, Tier2Store};
-use crate::wal::{WalEntry, WalManager, WalOp};
+use crate::tier1::{StrandStore, T1Compression};
+use crate::tier2::{quarantine_file, QuarantinedFile, T2Config, Tier2Store};
+use crate::wal::{batch_payload, merge_by_timestamp, split_batch_payload, WalEntry, WalManager, WalOp};
+
+/// Estimated RAM held by one full frame in T0 or T1.
+///
+/// Slot arrays are stored inline, so every frame costs the same
+/// regardless of how many slots are populated.
+pub const FRAME_RAM_BYTES: usize = std::mem::size_of::<TensorFrame>();
+
+/// Frames fetched per requested conversation by
+/// [`VoltStore::search_conversations`], so `k` distinct strands can
+/// still be found when some strands contribute several hits.
+pub const CONVERSATION_SEARCH_OVERSAMPLE: usize = 8;
+
+/// Directory in a disk-backed store's data directory holding the HNSW
+/// index saved by [`VoltStore::save_index`].
+pub const HNSW_INDEX_DIR: &str = "hnsw_index";
+
+/// File in a disk-backed store's data directory holding T1, read by
+/// [`VoltStore::open`]. Saving here with [`VoltStore::save`] checkpoints
+/// the WAL.
+pub const T1_FILE: &str = "t1_strands.json";
+
+/// Format version written to a snapshot's `manifest.json`.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Contents of a snapshot's `manifest.json`, written by
+/// [`VoltStore::snapshot`].
+///
+/// A snapshot directory holds this manifest, `t0.bin` and `t1.bin`
+/// (strand-store files) and a `t2/` directory of sorted runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// Snapshot format version, [`SNAPSHOT_VERSION`] when written.
+    pub version: u32,
+    /// When the snapshot was taken, in microseconds since the Unix epoch.
+    pub created_at: u64,
+    /// The ID the store would have assigned to its next frame.
+    pub next_id: u64,
+    /// The strand that was active.
+    pub active_strand: u64,
+    /// Frames captured from T0.
+    pub t0_frames: usize,
+    /// Frames captured from T1.
+    pub t1_frames: usize,
+    /// Entries captured from T2.
+    pub t2_entries: usize,
+}
+
+/// How [`VoltStore::open`] reacts to unreadable on-disk data.
+///
+/// # Example
+///
+/// ```
+/// use volt_db::{OpenPolicy, VoltStoreConfig};
+///
+/// assert_eq!(VoltStoreConfig::default().recovery_mode, OpenPolicy::Strict);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OpenPolicy {
+    /// Fail on the first unreadable file.
+    #[default]
+    Strict,
+    /// Quarantine unreadable T2 runs and T1 snapshots (renamed to
+    /// `*.corrupt` and logged) and open with everything else.
+    BestEffort,
+}
+
+/// What a [`OpenPolicy::BestEffort`] open set aside, reported by
+/// [`VoltStore::recovery_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecoveryReport {
+    /// Files that could not be loaded and were renamed to `*.corrupt`.
+    pub quarantined: Vec<QuarantinedFile>,
+}
+
+impl RecoveryReport {
+    /// Returns true if nothing was skipped.
+    pub fn is_clean(&self) -> bool {
+        self.quarantined.is_empty()
+    }
+}
 
 /// Configuration for opening a disk-backed VoltStore.
 ///
@@ -49,6 +137,22 @@ pub struct VoltStoreConfig {
     pub gc_config: GcConfig,
     /// Consolidation configuration.
     pub consolidation_config: ConsolidationConfig,
+    /// Minimum `global_certainty` a frame needs to be stored.
+    ///
+    /// Frames below the floor are rejected by [`VoltStore::store`] before
+    /// they receive an ID or a WAL entry. `None` stores everything.
+    /// Default: `None`.
+    pub min_store_certainty: Option<f32>,
+    /// Compression for T1 snapshots written by [`VoltStore::save`].
+    /// Snapshots are read regardless of this setting.
+    /// Default: [`T1Compression::None`].
+    pub t1_compression: T1Compression,
+    /// How unreadable T1/T2 files are handled on open.
+    /// Default: [`OpenPolicy::Strict`].
+    pub recovery_mode: OpenPolicy,
+    /// Graph and search parameters for the HNSW index.
+    /// Default: [`HnswParams::default`].
+    pub hnsw_params: HnswParams,
 }
 
 impl Default for VoltStoreConfig {
@@ -63,6 +167,68 @@ impl Default for VoltStoreConfig {
             t1_overflow_threshold: 1024,
             gc_config: GcConfig::default(),
             consolidation_config: ConsolidationConfig::default(),
+            min_store_certainty: None,
+            t1_compression: T1Compression::None,
+            recovery_mode: OpenPolicy::Strict,
+            hnsw_params: HnswParams::default(),
+        }
+    }
+}
+
+/// Result of [`VoltStore::verify_consistency`].
+///
+/// Both lists hold frame IDs sorted ascending.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsistencyReport {
+    /// Stored full frames with an R₀ gist but no live HNSW entry.
+    pub unindexed_frames: Vec<u64>,
+    /// Live HNSW entries whose frame is not a full frame in T0 or T1.
+    pub orphaned_entries: Vec<u64>,
+}
+
+impl ConsistencyReport {
+    /// Returns true if the index and the stored frames agree.
+    pub fn is_consistent(&self) -> bool {
+        self.unindexed_frames.is_empty() && self.orphaned_entries.is_empty()
+    }
+}
+
+/// Tier movement counters reported by [`VoltStore::store_metrics`].
+///
+/// Counters are monotonic for the lifetime of a store and only go back
+/// to zero through [`VoltStore::reset_store_metrics`]. They are not
+/// persisted: a reopened or loaded store starts from zero.
+///
+/// # Example
+///
+/// ```
+/// use volt_db::StoreMetrics;
+///
+/// let metrics = StoreMetrics::default();
+/// assert_eq!(metrics.t0_evictions, 0);
+/// assert_eq!(metrics.eviction_rate(), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StoreMetrics {
+    /// Frames evicted from T0 working memory into T1.
+    pub t0_evictions: u64,
+    /// Frames moved from T1 into the compressed T2 archive.
+    pub t1_to_t2_overflows: u64,
+    /// Frames accepted by [`VoltStore::store`]. Frames dropped by the
+    /// minimum certainty floor are not counted.
+    pub store_operations: u64,
+}
+
+impl StoreMetrics {
+    /// Fraction of store operations that evicted a frame from T0.
+    ///
+    /// A rate near 1.0 after warm-up means T0 is saturated and every new
+    /// frame pushes an older one out. Returns 0.0 before any store.
+    pub fn eviction_rate(&self) -> f64 {
+        if self.store_operations == 0 {
+            0.0
+        } else {
+            self.t0_evictions as f64 / self.store_operations as f64
         }
     }
 }
@@ -86,6 +252,8 @@ impl Default for VoltStoreConfig {
 /// # Memory-Only vs Disk-Backed
 ///
 /// - [`VoltStore::new()`] creates a memory-only store (no T2, no WAL)
+/// - [`VoltStore::new_bounded()`] creates a memory-only store that spills
+///   to T2 to stay under a RAM budget (no WAL)
 /// - [`VoltStore::open()`] creates a disk-backed store with T2 and WAL
 ///
 /// # Example
@@ -108,14 +276,26 @@ pub struct VoltStore {
     t2: Option<Tier2Store>,
     wal: Option<WalManager>,
     gc: GcEngine,
+    /// Retrieval counts fed into GC as `reference_count`.
+    access: AccessTracker,
+    /// Supersede tombstones for memory-only stores, which have no T2 to
+    /// keep the `superseded_by` link in.
+    superseded: HashMap<u64, Tombstone>,
     consolidation: ConsolidationEngine,
     active_strand: u64,
-    next_id: u64,
+    /// Next frame ID to hand out. Atomic so IDs can be reserved through
+    /// `&self` (see [`VoltStore::reserve_ids`]).
+    next_id: AtomicU64,
     hnsw: HnswIndex,
     temporal: TemporalIndex,
     bleed: BleedEngine,
     data_dir: Option<PathBuf>,
     t1_overflow_threshold: usize,
+    min_store_certainty: Option<f32>,
+    max_ram_bytes: Option<usize>,
+    t1_compression: T1Compression,
+    metrics: StoreMetrics,
+    recovery: RecoveryReport,
 }
 
 impl std::fmt::Debug for VoltStore {
@@ -130,6 +310,7 @@ impl std::fmt::Debug for VoltStore {
             .field("temporal_entries", &self.temporal.len())
             .field("ghost_count", &self.bleed.buffer().len())
             .field("disk_backed", &self.data_dir.is_some())
+            .field("max_ram_bytes", &self.max_ram_bytes)
             .finish()
     }
 }
@@ -163,22 +344,95 @@ impl VoltStore {
             t2: None,
             wal: None,
             gc: GcEngine::with_defaults(),
+            access: AccessTracker::default(),
+            superseded: HashMap::new(),
             consolidation: ConsolidationEngine::with_defaults(),
             active_strand: 0,
-            next_id: 1,
+            next_id: AtomicU64::new(1),
             hnsw: HnswIndex::new(),
             temporal: TemporalIndex::new(),
             bleed: BleedEngine::new(),
             data_dir: None,
             t1_overflow_threshold: 1024,
+            min_store_certainty: None,
+            max_ram_bytes: None,
+            t1_compression: T1Compression::None,
+            metrics: StoreMetrics::default(),
+            recovery: RecoveryReport::default(),
         }
     }
 
+    /// Creates a memory-only VoltStore that stays under a RAM budget by
+    /// spilling the oldest T1 frames to a T2 archive in `spill_dir`.
+    ///
+    /// Sits between [`VoltStore::new`] (unbounded RAM) and
+    /// [`VoltStore::open`] (full durability): there is no WAL and T1 is
+    /// never persisted, so a crash loses everything still in T0/T1.
+    /// Spilled frames are compressed and remain retrievable through
+    /// [`VoltStore::get_entry_by_id`].
+    ///
+    /// The budget is checked against [`VoltStore::ram_usage_bytes`], which
+    /// counts full frames in T0 and T1. T0 is never spilled, so a budget
+    /// below `T0_CAPACITY * FRAME_RAM_BYTES` only keeps T1 empty.
+    /// Frame IDs continue after any entries already in `spill_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if `spill_dir` cannot be
+    /// created or the T2 archive fails to open.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use volt_db::{VoltStore, FRAME_RAM_BYTES};
+    ///
+    /// let store = VoltStore::new_bounded(128 * FRAME_RAM_BYTES, "/tmp/volt_spill").unwrap();
+    /// assert_eq!(store.max_ram_bytes(), Some(128 * FRAME_RAM_BYTES));
+    /// assert!(!store.is_disk_backed());
+    /// ```
+    pub fn new_bounded(
+        max_ram_bytes: usize,
+        spill_dir: impl AsRef<Path>,
+    ) -> Result<Self, VoltError> {
+        let spill_dir = spill_dir.as_ref();
+        std::fs::create_dir_all(spill_dir).map_err(|e| VoltError::StorageError {
+            message: format!(
+                "failed to create spill directory {}: {e}",
+                spill_dir.display()
+            ),
+        })?;
+
+        let t2 = Tier2Store::open(T2Config {
+            data_dir: spill_dir.to_path_buf(),
+            ..T2Config::default()
+        })?;
+        let mut max_t2 = 0;
+        t2.for_each(|e| max_t2 = max_t2.max(e.frame_id()));
+
+        let mut store = Self::new();
+        store.t2 = Some(t2);
+        store.next_id = AtomicU64::new(max_t2 + 1);
+        store.max_ram_bytes = Some(max_ram_bytes);
+        Ok(store)
+    }
+
     /// Opens a disk-backed VoltStore with T2 archive and WAL.
     ///
     /// Creates the data directory structure, opens T2 and WAL,
     /// replays any WAL entries for crash recovery, and rebuilds
-    /// HNSW and temporal indices from T1 data.
+    /// HNSW and temporal indices from T1 data. Indices are built after
+    /// replay, so every recovered frame with an R₀ gist is indexed.
+    ///
+    /// If [`HNSW_INDEX_DIR`] exists (see [`VoltStore::save_index`]), was
+    /// saved with the configured [`HnswParams`] and covers exactly the
+    /// frames that would be indexed, its graph is loaded instead of
+    /// rebuilt. A missing, unreadable, version-mismatched or stale index
+    /// falls back to a rebuild.
+    ///
+    /// With [`OpenPolicy::BestEffort`], an unreadable T2 sorted run or T1
+    /// snapshot is quarantined instead of failing the open; see
+    /// [`VoltStore::recovery_report`]. Frames from a quarantined T1
+    /// snapshot that are still in the WAL are recovered.
     ///
     /// # Errors
     ///
@@ -208,14 +462,22 @@ impl VoltStore {
         // Open T2
         let mut t2_config = config.t2_config.clone();
         t2_config.data_dir = config.data_dir.join("t2");
-        let t2 = Tier2Store::open(t2_config)?;
+        let mut recovery = RecoveryReport::default();
+        let mut t2 = match config.recovery_mode {
+            OpenPolicy::Strict => Tier2Store::open(t2_config)?,
+            OpenPolicy::BestEffort => {
+                let (t2, quarantined) = Tier2Store::open_best_effort(t2_config)?;
+                recovery.quarantined.extend(quarantined);
+                t2
+            }
+        };
 
         // Open WAL
         let wal_dir = config.data_dir.join("wal");
         let wal = WalManager::open(&wal_dir)?;
 
         // Load T1 if it exists
-        let t1_path = config.data_dir.join("t1_strands.json");
+        let t1_path = config.data_dir.join(T1_FILE);
         let mut t1 = if t1_path.exists() {
             // Spawn a thread with a larger stack for serde on Windows
             let t1_path_clone = t1_path.clone();
@@ -225,9 +487,19 @@ impl VoltStore {
                 .map_err(|e| VoltError::StorageError {
                     message: format!("failed to spawn T1 load thread: {e}"),
                 })?;
-            handle.join().map_err(|_| VoltError::StorageError {
+            let loaded = handle.join().map_err(|_| VoltError::StorageError {
                 message: "T1 load thread panicked".to_string(),
-            })??
+            })?;
+            match loaded {
+                Ok(t1) => t1,
+                Err(e) if config.recovery_mode == OpenPolicy::BestEffort => {
+                    recovery
+                        .quarantined
+                        .push(quarantine_file(&t1_path, &e.to_string())?);
+                    StrandStore::new()
+                }
+                Err(e) => return Err(e),
+            }
         } else {
             let mut t1 = StrandStore::new();
             t1.create_strand(0);
@@ -241,60 +513,68 @@ impl VoltStore {
 
         // Find max frame ID across T1 and T2
         let max_t1 = Self::find_max_frame_id(&t1);
-        let max_t2 = t2
-            .scan_all()
-            .iter()
-            .map(|e| e.frame_id())
-            .max()
-            .unwrap_or(0);
+        let mut max_t2 = 0;
+        t2.for_each(|e| max_t2 = max_t2.max(e.frame_id()));
         let max_id = max_t1.max(max_t2);
 
-        // Rebuild HNSW and temporal indices from T1
-        let mut hnsw = HnswIndex::new();
-        let mut temporal = TemporalIndex::new();
-        for strand_id in t1.list_strands() {
-            for frame in t1.get_by_strand(strand_id) {
-                if let Some(gist) = extract_gist(frame)? {
-                    hnsw.insert(&gist)?;
-                    temporal.insert(gist.created_at, gist.frame_id);
-                }
-            }
-        }
-
-        // Replay WAL for crash recovery
-        let wal_entries = wal.replay_all()?;
+        // Replay WAL for crash recovery, all strands merged in log order
+        // so entries that move frames between strands apply in sequence
         let mut recovered_count = 0u64;
-        for entries in wal_entries.values() {
-            for entry in entries {
-                if entry.op == WalOp::Store && !entry.payload.is_empty() {
-                    // Try to parse as a FrameEntry and recover to T1
-                    if let Ok(FrameEntry::Full(frame)) =
-                        FrameEntry::from_bytes(&entry.payload)
-                    {
-                        let fid = frame.frame_meta.frame_id;
-                        // Only recover if not already in T1
-                        if t1.get_by_id(fid).is_none() {
-                            if !t1.has_strand(frame.frame_meta.strand_id) {
-                                t1.create_strand(frame.frame_meta.strand_id);
-                            }
-                            if let Some(gist) = extract_gist(&frame)? {
-                                hnsw.insert(&gist)?;
-                                temporal.insert(gist.created_at, gist.frame_id);
-                            }
-                            t1.store(*frame)?;
-                            recovered_count += 1;
-                        }
-                    }
+        let mut max_reserved = 0u64;
+        let mut reassigned_to = BTreeSet::new();
+        for entry in &wal.replay_ordered()? {
+            if entry.op == WalOp::Delete || entry.op == WalOp::Tombstone {
+                // A later delete wins over the store it follows; its
+                // ID stays reserved so it is never handed out again
+                t1.remove_frame(entry.frame_id);
+                max_reserved = max_reserved.max(entry.frame_id);
+                // The T2 tombstone may not have been flushed yet
+                if !matches!(t2.get(entry.frame_id), Some(FrameEntry::Tombstone(_))) {
+                    t2.update(FrameEntry::Tombstone(to_tombstone(
+                        entry.frame_id,
+                        entry.strand_id,
+                        entry.timestamp,
+                        superseded_by_payload(entry),
+                    )))?;
                 }
+            } else if entry.op == WalOp::Store && !entry.payload.is_empty() {
+                recovered_count += Self::recover_frame(&mut t1, &entry.payload)?;
+            } else if entry.op == WalOp::StoreBatch {
+                for part in split_batch_payload(&entry.payload).unwrap_or_default() {
+                    recovered_count += Self::recover_frame(&mut t1, part)?;
+                }
+            } else if entry.op == WalOp::Checkpoint {
+                // IDs handed out before the checkpoint stay reserved
+                max_reserved = max_reserved.max(entry.frame_id.saturating_sub(1));
+            } else if entry.op == WalOp::Reassign {
+                // The T2 retag may not have been flushed yet
+                if !Self::replay_reassign(&mut t1, entry)?
+                    && let Some(mut archived) = t2.get(entry.frame_id)
+                    && archived.strand_id() != entry.strand_id
+                {
+                    set_entry_strand(&mut archived, entry.strand_id);
+                    t2.update(archived)?;
+                }
+                reassigned_to.insert(entry.strand_id);
             }
         }
+        for strand_id in reassigned_to {
+            t1.sort_strand_chronologically(strand_id);
+        }
+
+        // Build indices only once T1 holds every recovered frame, so a
+        // frame recovered from the WAL is indexed exactly like a persisted one
+        let saved_index = config.data_dir.join(HNSW_INDEX_DIR);
+        let (hnsw, temporal) =
+            Self::build_indices_reusing(t1_frames(&t1), &saved_index, config.hnsw_params)?;
 
         // Update max_id with recovered frames
         let final_max = if recovered_count > 0 {
             Self::find_max_frame_id(&t1).max(max_id)
         } else {
             max_id
-        };
+        }
+        .max(max_reserved);
 
         Ok(Self {
             t0: WorkingMemory::new(),
@@ -302,17 +582,135 @@ impl VoltStore {
             t2: Some(t2),
             wal: Some(wal),
             gc: GcEngine::new(config.gc_config),
+            access: AccessTracker::default(),
+            superseded: HashMap::new(),
             consolidation: ConsolidationEngine::new(config.consolidation_config),
             active_strand: 0,
-            next_id: final_max + 1,
+            next_id: AtomicU64::new(final_max + 1),
             hnsw,
             temporal,
             bleed: BleedEngine::new(),
             data_dir: Some(config.data_dir),
             t1_overflow_threshold: config.t1_overflow_threshold,
+            min_store_certainty: config.min_store_certainty,
+            max_ram_bytes: None,
+            t1_compression: config.t1_compression,
+            metrics: StoreMetrics::default(),
+            recovery,
         })
     }
 
+    /// Logs a [`WalOp::Store`] entry for `frame` if disk-backed.
+    fn log_store(&mut self, frame: &TensorFrame) -> Result<(), VoltError> {
+        // Build payload directly to avoid cloning 64KB TensorFrame on the stack
+        // (same layout as `FrameEntry::Full(..).to_bytes()`).
+        if let Some(ref mut wal) = self.wal {
+            let mut payload = vec![DecayLevel::Full.tag()];
+            payload.extend_from_slice(&frame.to_binary());
+            wal.log_entry(WalEntry {
+                frame_id: frame.frame_meta.frame_id,
+                strand_id: frame.frame_meta.strand_id,
+                op: WalOp::Store,
+                payload,
+                timestamp: 0,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Recovers one full frame from a WAL payload into T1, unless T1
+    /// already holds it. Returns the number of frames recovered.
+    fn recover_frame(t1: &mut StrandStore, payload: &[u8]) -> Result<u64, VoltError> {
+        let Ok(FrameEntry::Full(frame)) = FrameEntry::from_bytes(payload) else {
+            return Ok(0);
+        };
+        if t1.get_by_id(frame.frame_meta.frame_id).is_some() {
+            return Ok(0);
+        }
+        if !t1.has_strand(frame.frame_meta.strand_id) {
+            t1.create_strand(frame.frame_meta.strand_id);
+        }
+        t1.store(*frame)?;
+        Ok(1)
+    }
+
+    /// Replays a [`WalOp::Reassign`] entry against T1: moves the frame
+    /// into the entry's strand and drops the strand it left once empty,
+    /// as [`VoltStore::merge_strands`] does. Returns `false` if T1 does not
+    /// hold the frame.
+    fn replay_reassign(t1: &mut StrandStore, entry: &WalEntry) -> Result<bool, VoltError> {
+        let Some(mut frame) = t1.remove_frame(entry.frame_id) else {
+            return Ok(false);
+        };
+        frame.frame_meta.strand_id = entry.strand_id;
+        t1.store(*frame)?;
+        if let Some(previous) = reassigned_from_payload(entry)
+            && previous != 0
+        {
+            t1.remove_empty_strand(previous);
+        }
+        Ok(true)
+    }
+
+    /// Logs a [`WalOp::Reassign`] entry moving `frame_id` from strand
+    /// `from` to strand `to`, if disk-backed.
+    fn log_reassign(&mut self, frame_id: u64, from: u64, to: u64) -> Result<(), VoltError> {
+        if let Some(ref mut wal) = self.wal {
+            wal.log_entry(WalEntry {
+                frame_id,
+                strand_id: to,
+                op: WalOp::Reassign,
+                payload: from.to_le_bytes().to_vec(),
+                timestamp: 0,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Hands out the next frame ID.
+    fn allocate_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Reserves `count` consecutive frame IDs without storing anything.
+    ///
+    /// Reserved IDs are never assigned by [`VoltStore::store`] or any
+    /// other call, and each reservation starts above every ID handed out
+    /// before it, even when reservations race on a shared store (see
+    /// [`ConcurrentVoltStore::reserve_ids`]). IDs a caller never uses are
+    /// simply skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let reserved = store.reserve_ids(3);
+    /// assert_eq!(reserved, 1..4);
+    /// assert_eq!(store.store(TensorFrame::new()).unwrap(), 4);
+    /// ```
+    pub fn reserve_ids(&self, count: u64) -> Range<u64> {
+        let start = self.next_id.fetch_add(count, Ordering::SeqCst);
+        start..start + count
+    }
+
+    /// Files a [`OpenPolicy::BestEffort`] open set aside. Always clean
+    /// for stores that were not opened from disk, or opened strictly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    ///
+    /// let store = VoltStore::new();
+    /// assert!(store.recovery_report().is_clean());
+    /// ```
+    pub fn recovery_report(&self) -> &RecoveryReport {
+        &self.recovery
+    }
+
     /// Stores a frame, assigning it a unique frame ID and the active strand ID.
     ///
     /// The frame is placed in T0. If T0 is full, the oldest frame is
@@ -323,7 +721,15 @@ impl VoltStore {
     /// In disk-backed mode, the frame is also WAL-logged and T1 overflow
     /// to T2 is checked.
     ///
-    /// Returns the assigned frame ID.
+    /// Returns the assigned frame ID. If a minimum store certainty is
+    /// configured and the frame's `global_certainty` is below it, the
+    /// frame is dropped without being assigned an ID, WAL-logged, or
+    /// indexed, and `0` (never a valid frame ID) is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::FrameError`] if the frame fails
+    /// [`TensorFrame::validate`]; nothing is stored or logged.
     ///
     /// # Example
     ///
@@ -337,34 +743,27 @@ impl VoltStore {
     /// assert!(store.get_by_id(id).is_some());
     /// ```
     pub fn store(&mut self, mut frame: TensorFrame) -> Result<u64, VoltError> {
-        let frame_id = self.next_id;
-        self.next_id += 1;
+        frame.validate()?;
+        if let Some(floor) = self.min_store_certainty
+            && below_floor(frame.frame_meta.global_certainty, floor)
+        {
+            return Ok(0);
+        }
+
+        let frame_id = self.allocate_id();
 
         frame.frame_meta.frame_id = frame_id;
         frame.frame_meta.strand_id = self.active_strand;
 
-        // WAL log if disk-backed
-        // Build payload directly to avoid cloning 64KB TensorFrame on the stack
-        // (Windows default thread stack is 1MB; serde + clone can overflow).
-        if let Some(ref mut wal) = self.wal {
-            let mut payload = vec![DecayLevel::Full.tag()];
-            let json = serde_json::to_vec(&frame).map_err(|e| VoltError::StorageError {
-                message: format!("failed to serialize frame for WAL: {e}"),
-            })?;
-            payload.extend_from_slice(&json);
-            wal.log_entry(WalEntry {
-                frame_id,
-                strand_id: self.active_strand,
-                op: WalOp::Store,
-                payload,
-            })?;
-        }
+        self.log_store(&frame)?;
 
         // Extract gist before storing (we need the frame reference)
         let gist = extract_gist(&frame)?;
 
+        self.metrics.store_operations += 1;
         if let Some(evicted) = self.t0.store(frame) {
             self.t1.store(evicted)?;
+            self.metrics.t0_evictions += 1;
         }
 
         // Update indices with gist
@@ -376,7 +775,8 @@ impl VoltStore {
 
         // T1 → T2 overflow check
         if self.t2.is_some()
-            && self.t1.total_frame_count() > self.t1_overflow_threshold
+            && (self.t1.total_frame_count() > self.t1_overflow_threshold
+                || self.ram_overflow_count() > 0)
         {
             self.maybe_overflow_t1_to_t2()?;
         }
@@ -389,10 +789,141 @@ impl VoltStore {
         Ok(frame_id)
     }
 
+    /// Stores several frames in the active strand in one pass.
+    ///
+    /// Equivalent to calling [`VoltStore::store`] for each frame, but
+    /// cheaper: disk-backed stores write one grouped
+    /// [`WalOp::StoreBatch`] record, and the ghost buffer is refreshed
+    /// once, against the last indexed frame, instead of per frame.
+    ///
+    /// Returns the assigned IDs in input order, with `0` for frames
+    /// dropped by the minimum store certainty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::FrameError`] if any frame fails
+    /// [`TensorFrame::validate`]; nothing is stored or logged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let ids = store
+    ///     .store_batch(vec![TensorFrame::new(), TensorFrame::new()])
+    ///     .unwrap();
+    /// assert_eq!(ids, vec![1, 2]);
+    /// assert!(store.get_by_id(2).is_some());
+    /// ```
+    pub fn store_batch(&mut self, frames: Vec<TensorFrame>) -> Result<Vec<u64>, VoltError> {
+        for frame in &frames {
+            frame.validate()?;
+        }
+
+        let mut ids = Vec::with_capacity(frames.len());
+        let mut accepted = Vec::with_capacity(frames.len());
+        for mut frame in frames {
+            if let Some(floor) = self.min_store_certainty
+                && below_floor(frame.frame_meta.global_certainty, floor)
+            {
+                ids.push(0);
+                continue;
+            }
+            let frame_id = self.allocate_id();
+            frame.frame_meta.frame_id = frame_id;
+            frame.frame_meta.strand_id = self.active_strand;
+            ids.push(frame_id);
+            accepted.push(frame);
+        }
+        let Some(first) = accepted.first() else {
+            return Ok(ids);
+        };
+
+        if let Some(ref mut wal) = self.wal {
+            let parts: Vec<Vec<u8>> = accepted
+                .iter()
+                .map(|frame| {
+                    let mut part = vec![DecayLevel::Full.tag()];
+                    part.extend_from_slice(&frame.to_binary());
+                    part
+                })
+                .collect();
+            wal.log_entry(WalEntry {
+                frame_id: first.frame_meta.frame_id,
+                strand_id: self.active_strand,
+                op: WalOp::StoreBatch,
+                payload: batch_payload(&parts),
+                timestamp: 0,
+            })?;
+        }
+
+        let mut last_gist = None;
+        for frame in accepted {
+            let gist = extract_gist(&frame)?;
+            self.metrics.store_operations += 1;
+            if let Some(evicted) = self.t0.store(frame) {
+                self.t1.store(evicted)?;
+                self.metrics.t0_evictions += 1;
+            }
+            if let Some(g) = gist {
+                self.hnsw.insert(&g)?;
+                self.temporal.insert(g.created_at, g.frame_id);
+                last_gist = Some(g);
+            }
+        }
+        if let Some(ref g) = last_gist {
+            self.bleed.on_new_frame(g, &self.hnsw)?;
+        }
+
+        if self.t2.is_some()
+            && (self.t1.total_frame_count() > self.t1_overflow_threshold
+                || self.ram_overflow_count() > 0)
+        {
+            self.maybe_overflow_t1_to_t2()?;
+        }
+        if let Some(ref mut t2) = self.t2 {
+            t2.maybe_flush_and_compact()?;
+        }
+
+        Ok(ids)
+    }
+
+    /// Stores a frame that expires `ttl_micros` after its `created_at`.
+    ///
+    /// The TTL is kept in the frame's metadata, so it survives save/load
+    /// and WAL replay. Expired frames are removed by [`VoltStore::expire`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`VoltStore::store`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let id = store.store_with_ttl(TensorFrame::new(), 1_000).unwrap();
+    /// let frame = store.get_by_id(id).unwrap();
+    /// assert_eq!(frame.frame_meta.ttl_micros, Some(1_000));
+    /// ```
+    pub fn store_with_ttl(
+        &mut self,
+        mut frame: TensorFrame,
+        ttl_micros: u64,
+    ) -> Result<u64, VoltError> {
+        frame.frame_meta.ttl_micros = Some(ttl_micros);
+        self.store(frame)
+    }
+
     /// Retrieves a frame by its `frame_id`, searching T0 first, then T1.
     ///
     /// Does **not** search T2 (compressed frames). Use [`get_entry_by_id`]
-    /// to search all tiers including compressed/gist forms.
+    /// to search all tiers including compressed/gist forms. A hit counts
+    /// as an access for GC retention (see [`VoltStore::access_count`]).
     ///
     /// # Example
     ///
@@ -406,16 +937,49 @@ impl VoltStore {
     /// assert!(store.get_by_id(9999).is_none());
     /// ```
     pub fn get_by_id(&self, frame_id: u64) -> Option<&TensorFrame> {
+        let frame = self.find_frame(frame_id);
+        if frame.is_some() {
+            self.access.record(frame_id);
+        }
+        frame
+    }
+
+    /// Looks up a full frame in T0, then T1, without counting an access.
+    fn find_frame(&self, frame_id: u64) -> Option<&TensorFrame> {
         self.t0
             .get_by_id(frame_id)
             .or_else(|| self.t1.get_by_id(frame_id))
     }
 
+    /// Returns how often `frame_id` has been retrieved through
+    /// [`get_by_id`](Self::get_by_id), [`get_entry_by_id`](Self::get_entry_by_id),
+    /// or [`query_similar`](Self::query_similar), decayed with
+    /// [`GcConfig::access_half_life_days`] at each GC run.
+    ///
+    /// GC uses this as the frame's reference count, so frequently
+    /// retrieved frames are demoted later. Counts are not persisted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let id = store.store(TensorFrame::new()).unwrap();
+    /// store.get_by_id(id);
+    /// store.get_by_id(id);
+    /// assert_eq!(store.access_count(id), 2);
+    /// ```
+    pub fn access_count(&self, frame_id: u64) -> u32 {
+        self.access.count(frame_id)
+    }
+
     /// Retrieves a frame entry at any decay level from T0, T1, or T2.
     ///
     /// Search order: T0 → T1 → T2 (memtable → sorted runs).
     /// Returns `Full` for T0/T1 frames, or `Compressed`/`Gist`/`Tombstone`
-    /// for T2 frames.
+    /// for T2 frames. A hit counts as an access for GC retention.
     ///
     /// # Example
     ///
@@ -430,6 +994,25 @@ impl VoltStore {
     /// assert_eq!(entry.decay_level(), DecayLevel::Full);
     /// ```
     pub fn get_entry_by_id(&self, frame_id: u64) -> Option<FrameEntry> {
+        let entry = self.find_entry(frame_id);
+        if entry.is_some() {
+            self.access.record(frame_id);
+        }
+        entry
+    }
+
+    /// The frame that replaced `frame_id`, if it was superseded (see
+    /// [`supersede_frame`](Self::supersede_frame)). Does not count as an
+    /// access.
+    pub fn superseded_by(&self, frame_id: u64) -> Option<u64> {
+        match self.find_entry(frame_id)? {
+            FrameEntry::Tombstone(ts) => ts.superseded_by,
+            _ => None,
+        }
+    }
+
+    /// [`get_entry_by_id`](Self::get_entry_by_id) without counting an access.
+    fn find_entry(&self, frame_id: u64) -> Option<FrameEntry> {
         // Check T0
         if let Some(frame) = self.t0.get_by_id(frame_id) {
             return Some(FrameEntry::Full(Box::new(frame.clone())));
@@ -442,7 +1025,9 @@ impl VoltStore {
         if let Some(ref t2) = self.t2 {
             return t2.get(frame_id);
         }
-        None
+        self.superseded
+            .get(&frame_id)
+            .map(|ts| FrameEntry::Tombstone(*ts))
     }
 
     /// Returns all frames belonging to a strand, from both T0 and T1.
@@ -472,7 +1057,12 @@ impl VoltStore {
         frames
     }
 
-    /// Returns the most recent `n` frames from T0, newest-first.
+    /// Returns all frames belonging to a strand in chronological order.
+    ///
+    /// Spans T1 and T0 like [`get_by_strand`](Self::get_by_strand), but
+    /// sorts by `created_at` with ties broken by `frame_id`, so the order
+    /// is deterministic regardless of which tier holds a frame. Does not
+    /// include T2 compressed frames.
     ///
     /// # Example
     ///
@@ -481,18 +1071,97 @@ impl VoltStore {
     /// use volt_core::TensorFrame;
     ///
     /// let mut store = VoltStore::new();
-    /// for _ in 0..5 {
-    ///     store.store(TensorFrame::new()).unwrap();
+    /// for created_at in [30, 10, 20] {
+    ///     let mut frame = TensorFrame::new();
+    ///     frame.frame_meta.created_at = created_at;
+    ///     store.store(frame).unwrap();
     /// }
     ///
-    /// let recent = store.recent(3);
-    /// assert_eq!(recent.len(), 3);
+    /// let times: Vec<u64> = store
+    ///     .iter_strand_chronological(0)
+    ///     .iter()
+    ///     .map(|f| f.frame_meta.created_at)
+    ///     .collect();
+    /// assert_eq!(times, vec![10, 20, 30]);
     /// ```
-    pub fn recent(&self, n: usize) -> Vec<&TensorFrame> {
-        self.t0.recent(n)
+    pub fn iter_strand_chronological(&self, strand_id: u64) -> Vec<&TensorFrame> {
+        let mut frames = self.get_by_strand(strand_id);
+        frames.sort_by_key(|f| (f.frame_meta.created_at, f.frame_meta.frame_id));
+        frames
     }
 
-    /// Creates a new strand.
+    /// Exports a conversation strand as consecutive (input, response) frame pairs.
+    ///
+    /// Frames from T1 and T0 are walked in `frame_id` order. A frame with
+    /// [`DiscourseType::Response`] is an assistant turn; any other
+    /// discourse type is a user turn. Each user turn immediately followed
+    /// by an assistant turn yields one pair. User turns without a reply
+    /// (including a trailing one) and replies without a preceding user
+    /// turn are dropped. The result feeds Flow Matching `FramePair`s or
+    /// RLVF datasets.
+    ///
+    /// Returns an empty vector for an unknown strand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    /// use volt_core::meta::DiscourseType;
+    ///
+    /// let mut store = VoltStore::new();
+    /// for discourse_type in [DiscourseType::Query, DiscourseType::Response, DiscourseType::Query] {
+    ///     let mut frame = TensorFrame::new();
+    ///     frame.frame_meta.discourse_type = discourse_type;
+    ///     store.store(frame).unwrap();
+    /// }
+    ///
+    /// // The trailing query has no response yet, so only one pair.
+    /// let pairs = store.export_strand_as_pairs(0);
+    /// assert_eq!(pairs.len(), 1);
+    /// assert_eq!(pairs[0].0.frame_meta.frame_id, 1);
+    /// assert_eq!(pairs[0].1.frame_meta.frame_id, 2);
+    /// ```
+    pub fn export_strand_as_pairs(&self, strand_id: u64) -> Vec<(TensorFrame, TensorFrame)> {
+        let mut frames = self.get_by_strand(strand_id);
+        frames.sort_by_key(|f| f.frame_meta.frame_id);
+
+        let mut pairs = Vec::new();
+        let mut pending_input: Option<&TensorFrame> = None;
+        for frame in frames {
+            if frame.frame_meta.discourse_type == DiscourseType::Response {
+                if let Some(input) = pending_input.take() {
+                    pairs.push((input.clone(), frame.clone()));
+                }
+            } else {
+                // A newer user turn supersedes an unanswered one.
+                pending_input = Some(frame);
+            }
+        }
+        pairs
+    }
+
+    /// Returns the most recent `n` frames from T0, newest-first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// for _ in 0..5 {
+    ///     store.store(TensorFrame::new()).unwrap();
+    /// }
+    ///
+    /// let recent = store.recent(3);
+    /// assert_eq!(recent.len(), 3);
+    /// ```
+    pub fn recent(&self, n: usize) -> Vec<&TensorFrame> {
+        self.t0.recent(n)
+    }
+
+    /// Creates a new strand.
     ///
     /// Returns an error if the strand already exists.
     ///
@@ -605,25 +1274,33 @@ impl VoltStore {
     /// Queries ALL strands for the top-k most similar frames by R₀ gist.
     ///
     /// Returns results sorted by ascending cosine distance (closest first).
+    /// Each returned frame counts as an access for GC retention.
     ///
     /// # Example
     ///
     /// ```
     /// use volt_db::VoltStore;
     /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_core::slot::SlotSource;
     ///
     /// let mut store = VoltStore::new();
     /// let mut frame = TensorFrame::new();
     /// let mut slot = SlotData::new(SlotRole::Agent);
     /// slot.write_resolution(0, [0.1; SLOT_DIM]);
     /// frame.write_slot(0, slot).unwrap();
+    /// frame.meta[0].source = SlotSource::Translator;
     /// store.store(frame).unwrap();
     ///
     /// let results = store.query_similar(&[0.1; SLOT_DIM], 10);
     /// assert_eq!(results.len(), 1);
+    /// assert_eq!(store.access_count(results[0].frame_id), 1);
     /// ```
     pub fn query_similar(&self, query: &[f32; SLOT_DIM], k: usize) -> Vec<SimilarityResult> {
-        self.hnsw.query_all(query, k)
+        let results = self.hnsw.query_all(query, k);
+        for result in &results {
+            self.access.record(result.frame_id);
+        }
+        results
     }
 
     /// Queries a single strand for the top-k most similar frames by R₀ gist.
@@ -635,12 +1312,14 @@ impl VoltStore {
     /// ```
     /// use volt_db::VoltStore;
     /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_core::slot::SlotSource;
     ///
     /// let mut store = VoltStore::new();
     /// let mut frame = TensorFrame::new();
     /// let mut slot = SlotData::new(SlotRole::Agent);
     /// slot.write_resolution(0, [0.1; SLOT_DIM]);
     /// frame.write_slot(0, slot).unwrap();
+    /// frame.meta[0].source = SlotSource::Translator;
     /// store.store(frame).unwrap();
     ///
     /// let results = store.query_similar_in_strand(0, &[0.1; SLOT_DIM], 10);
@@ -655,6 +1334,64 @@ impl VoltStore {
         self.hnsw.query_strand(strand_id, query, k)
     }
 
+    /// Finds the conversations (strands) most related to `query`.
+    ///
+    /// Runs the global HNSW query for up to
+    /// `k * CONVERSATION_SEARCH_OVERSAMPLE` frames, groups the hits by
+    /// strand, and returns at most `k` rows of
+    /// `(strand_id, best_distance, frame_count)`, closest strand first.
+    /// `frame_count` is the number of that strand's frames among the
+    /// hits, not the strand's total size.
+    ///
+    /// Returns an empty vec if nothing is indexed or `k == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_core::slot::SlotSource;
+    ///
+    /// let mut store = VoltStore::new();
+    /// for _ in 0..2 {
+    ///     let mut frame = TensorFrame::new();
+    ///     let mut slot = SlotData::new(SlotRole::Agent);
+    ///     slot.write_resolution(0, [0.1; SLOT_DIM]);
+    ///     frame.write_slot(0, slot).unwrap();
+    ///     frame.meta[0].source = SlotSource::Translator;
+    ///     store.store(frame).unwrap();
+    /// }
+    ///
+    /// let results = store.search_conversations(&[0.1; SLOT_DIM], 5);
+    /// assert_eq!(results.len(), 1);
+    /// assert_eq!(results[0].0, 0);
+    /// assert_eq!(results[0].2, 2);
+    /// ```
+    pub fn search_conversations(
+        &self,
+        query: &[f32; SLOT_DIM],
+        k: usize,
+    ) -> Vec<(u64, f32, usize)> {
+        let hits = self
+            .hnsw
+            .query_all(query, k.saturating_mul(CONVERSATION_SEARCH_OVERSAMPLE));
+
+        // Hits are sorted closest first, so a strand's first hit is its best.
+        let mut rows: Vec<(u64, f32, usize)> = Vec::new();
+        let mut row_of: HashMap<u64, usize> = HashMap::new();
+        for hit in hits {
+            match row_of.get(&hit.strand_id) {
+                Some(&i) => rows[i].2 += 1,
+                None => {
+                    row_of.insert(hit.strand_id, rows.len());
+                    rows.push((hit.strand_id, hit.distance, 1));
+                }
+            }
+        }
+        rows.truncate(k);
+        rows
+    }
+
     /// Returns all frame IDs created within the time range `[start, end]` inclusive.
     ///
     /// Timestamps are in microseconds.
@@ -673,6 +1410,32 @@ impl VoltStore {
         self.temporal.query_range(start, end)
     }
 
+    /// Like [`VoltStore::query_time_range`], newest first.
+    pub fn query_time_range_rev(&self, start: u64, end: u64) -> Vec<u64> {
+        self.temporal.query_range_rev(start, end)
+    }
+
+    /// Returns the `k` frame IDs created closest to `timestamp`
+    /// (microseconds), across all strands, closest first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    ///
+    /// let store = VoltStore::new();
+    /// assert!(store.nearest_in_time(1_000_000, 5).is_empty());
+    /// ```
+    pub fn nearest_in_time(&self, timestamp: u64, k: usize) -> Vec<u64> {
+        self.temporal.nearest(timestamp, k)
+    }
+
+    /// Returns the `n` most recently created frame IDs across all
+    /// strands, newest first.
+    pub fn most_recent_frames(&self, n: usize) -> Vec<u64> {
+        self.temporal.most_recent(n)
+    }
+
     /// Returns a reference to the Ghost Bleed Buffer.
     ///
     /// The buffer contains R₀ gists from historical frames that are
@@ -716,6 +1479,12 @@ impl VoltStore {
         self.hnsw.total_entries()
     }
 
+    /// Returns the parameters the HNSW index was built with, from
+    /// [`VoltStoreConfig::hnsw_params`] for a disk-backed store.
+    pub fn hnsw_params(&self) -> HnswParams {
+        self.hnsw.params()
+    }
+
     /// Returns the total number of entries in the temporal index.
     pub fn temporal_entries(&self) -> usize {
         self.temporal.len()
@@ -760,6 +1529,7 @@ impl VoltStore {
     /// Returns [`VoltError::StorageError`] if T2 operations fail.
     pub fn run_gc_at(&mut self, now: u64) -> Result<GcResult, VoltError> {
         let mut result = GcResult::default();
+        self.access.decay_to(now, self.gc.config().access_half_life_days);
 
         // Collect metadata from T1 frames
         let mut gc_metas: Vec<FrameGcMeta> = Vec::new();
@@ -771,16 +1541,17 @@ impl VoltStore {
                     created_at: frame.frame_meta.created_at,
                     global_certainty: frame.frame_meta.global_certainty,
                     current_level: DecayLevel::Full,
-                    reference_count: 0,
+                    reference_count: self.access.count(frame.frame_meta.frame_id),
                     is_pinned: self.gc.is_pinned(frame.frame_meta.frame_id),
                     is_wisdom: false,
                 });
             }
         }
 
-        // Collect metadata from T2 entries
+        // Collect metadata from T2 entries, streamed so the archive is
+        // never held in memory at once
         if let Some(ref t2) = self.t2 {
-            for entry in t2.scan_all() {
+            t2.for_each(|entry| {
                 let fid = entry.frame_id();
                 gc_metas.push(FrameGcMeta {
                     frame_id: fid,
@@ -788,11 +1559,11 @@ impl VoltStore {
                     created_at: entry.created_at(),
                     global_certainty: entry.global_certainty(),
                     current_level: entry.decay_level(),
-                    reference_count: 0,
+                    reference_count: self.access.count(fid),
                     is_pinned: self.gc.is_pinned(fid),
                     is_wisdom: false,
                 });
-            }
+            });
         }
 
         // Evaluate
@@ -822,6 +1593,7 @@ impl VoltStore {
                                 strand_id: frame.frame_meta.strand_id,
                                 op: WalOp::Compress,
                                 payload,
+                                timestamp: 0,
                             })?;
                         }
 
@@ -931,6 +1703,12 @@ impl VoltStore {
 
     /// Consolidates a strand by finding clusters and creating wisdom frames.
     ///
+    /// Each wisdom frame records its cluster in `frame_meta.derived_from`.
+    /// When [`ConsolidationConfig::supersede_sources`] is set, unpinned
+    /// source frames are then removed from T1 and the indices, and a
+    /// tombstone with `superseded_by` pointing at the wisdom frame is
+    /// written to T2 (memory-only stores have no T2 and simply drop them).
+    ///
     /// # Errors
     ///
     /// Returns [`VoltError::StorageError`] if wisdom frame storage fails.
@@ -958,7 +1736,77 @@ impl VoltStore {
 
         // Find clusters
         let clusters = self.consolidation.find_clusters(strand_id, &self.hnsw, &gists);
+        self.store_wisdom_frames(strand_id, &clusters)
+    }
+
+    /// Consolidates the next batch of a strand's frames, resuming after
+    /// `cursor`.
+    ///
+    /// Examines up to `max_frames` T1 frames with a frame ID above
+    /// `cursor`, oldest first, clusters them among themselves with
+    /// [`ConsolidationEngine::find_clusters_exact`], and stores a wisdom
+    /// frame per cluster as [`VoltStore::consolidate_strand`] does.
+    /// Wisdom frames (non-empty `derived_from`) are never examined, and
+    /// frames at or below `cursor` are never clustered again, so repeated
+    /// calls with the returned [`ConsolidationProgress::cursor`] never
+    /// produce duplicate wisdom frames. Frames stored after a call are
+    /// picked up by the next one once they reach T1. A frame that did not
+    /// fit a cluster in its batch is not revisited.
+    ///
+    /// Start with a cursor of 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if wisdom frame storage fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let progress = store.consolidate_strand_from(0, 0, 100).unwrap();
+    /// assert_eq!(progress.frames_examined, 0);
+    /// assert!(progress.is_complete());
+    /// ```
+    pub fn consolidate_strand_from(
+        &mut self,
+        strand_id: u64,
+        cursor: u64,
+        max_frames: usize,
+    ) -> Result<ConsolidationProgress, VoltError> {
+        let mut pending: Vec<FrameGist> = Vec::new();
+        for frame in self.t1.get_by_strand(strand_id) {
+            if frame.frame_meta.frame_id > cursor
+                && frame.frame_meta.derived_from.is_empty()
+                && let Some(gist) = extract_gist(frame)?
+            {
+                pending.push(gist);
+            }
+        }
+        pending.sort_by_key(|gist| gist.frame_id);
+
+        let examined = max_frames.min(pending.len());
+        let batch = &pending[..examined];
+        let next_cursor = batch.last().map_or(cursor, |gist| gist.frame_id);
+        let clusters = self.consolidation.find_clusters_exact(batch);
+        let result = self.store_wisdom_frames(strand_id, &clusters)?;
+
+        Ok(ConsolidationProgress {
+            result,
+            cursor: next_cursor,
+            frames_examined: examined,
+            frames_remaining: pending.len() - examined,
+        })
+    }
 
+    /// Stores one wisdom frame per cluster of `strand_id`, superseding
+    /// the sources if configured.
+    fn store_wisdom_frames(
+        &mut self,
+        strand_id: u64,
+        clusters: &[FrameCluster],
+    ) -> Result<ConsolidationResult, VoltError> {
         if clusters.is_empty() {
             return Ok(ConsolidationResult {
                 clusters_found: 0,
@@ -970,12 +1818,12 @@ impl VoltStore {
         let mut wisdom_frames = Vec::new();
         let mut superseded_ids = Vec::new();
 
-        for cluster in &clusters {
+        for cluster in clusters {
             // Gather source frames for this cluster (clone to release borrow on self)
             let source_frames: Vec<TensorFrame> = cluster
                 .member_frame_ids
                 .iter()
-                .filter_map(|&id| self.get_by_id(id).cloned())
+                .filter_map(|&id| self.find_frame(id).cloned())
                 .collect();
 
             if source_frames.len() < self.consolidation.config().min_cluster_size {
@@ -983,8 +1831,7 @@ impl VoltStore {
             }
 
             // Assign an ID to the wisdom frame
-            let wisdom_id = self.next_id;
-            self.next_id += 1;
+            let wisdom_id = self.allocate_id();
 
             let source_refs: Vec<&TensorFrame> = source_frames.iter().collect();
             let wisdom = self.consolidation.create_wisdom_frame(
@@ -994,10 +1841,13 @@ impl VoltStore {
                 wisdom_id,
             );
 
-            // Store the wisdom frame
+            // Store the wisdom frame, logged ahead of the source
+            // tombstones that point at it
+            self.log_store(&wisdom)?;
             let gist = extract_gist(&wisdom)?;
             if let Some(evicted) = self.t0.store(wisdom.clone()) {
                 self.t1.store(evicted)?;
+                self.metrics.t0_evictions += 1;
             }
 
             if let Some(ref g) = gist {
@@ -1005,7 +1855,16 @@ impl VoltStore {
                 self.temporal.insert(g.created_at, wisdom_id);
             }
 
-            superseded_ids.extend_from_slice(&cluster.member_frame_ids);
+            if self.consolidation.config().supersede_sources {
+                for frame in &source_frames {
+                    let id = frame.frame_meta.frame_id;
+                    if !self.gc.is_pinned(id) && self.supersede_frame(id, wisdom_id)? {
+                        superseded_ids.push(id);
+                    }
+                }
+            } else {
+                superseded_ids.extend_from_slice(&cluster.member_frame_ids);
+            }
             wisdom_frames.push(wisdom);
         }
 
@@ -1016,6 +1875,190 @@ impl VoltStore {
         })
     }
 
+    /// Deletes a frame, e.g. to honour a "forget this message" request.
+    ///
+    /// The frame is removed from T0/T1, its T2 entry (if any) becomes a
+    /// tombstone, and it leaves the HNSW and temporal indices. When
+    /// disk-backed, a [`WalOp::Delete`] entry is logged first so WAL
+    /// replay does not resurrect the frame.
+    ///
+    /// Returns `true` if a live frame was deleted, `false` if no frame
+    /// with that ID exists or it is already a tombstone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the WAL entry or the T2
+    /// tombstone cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let id = store.store(TensorFrame::new()).unwrap();
+    /// assert!(store.delete(id).unwrap());
+    /// assert!(store.get_by_id(id).is_none());
+    /// assert!(!store.delete(id).unwrap());
+    /// ```
+    pub fn delete(&mut self, frame_id: u64) -> Result<bool, VoltError> {
+        let t2_entry = self.t2.as_ref().and_then(|t2| t2.get(frame_id));
+        let strand_id = match self.find_frame(frame_id) {
+            Some(frame) => frame.frame_meta.strand_id,
+            None => match t2_entry {
+                Some(FrameEntry::Tombstone(_)) | None => return Ok(false),
+                Some(ref entry) => entry.strand_id(),
+            },
+        };
+
+        if let Some(ref mut wal) = self.wal {
+            wal.log_entry(WalEntry {
+                frame_id,
+                strand_id,
+                op: WalOp::Delete,
+                payload: Vec::new(),
+                timestamp: 0,
+            })?;
+        }
+
+        if self.t0.remove(frame_id).is_none() {
+            self.t1.remove_frame(frame_id);
+        }
+        if let Some(ref mut t2) = self.t2 {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_micros() as u64)
+                .unwrap_or(0);
+            t2.update(FrameEntry::Tombstone(to_tombstone(frame_id, strand_id, now, None)))?;
+        }
+        self.hnsw.mark_deleted(frame_id);
+        self.temporal.remove(frame_id);
+        self.access.forget(frame_id);
+        Ok(true)
+    }
+
+    /// Replaces a T0/T1 frame with a tombstone linking to `superseded_by`,
+    /// e.g. a consolidation source to its wisdom frame or a response to
+    /// its regenerated replacement.
+    ///
+    /// When disk-backed, a [`WalOp::Tombstone`] entry carrying the link is
+    /// logged first so WAL replay neither resurrects the frame nor loses
+    /// the link. Memory-only stores keep the tombstone in RAM.
+    ///
+    /// Returns `false` if no live T0/T1 frame has that ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the WAL entry or the T2
+    /// tombstone cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let old = store.store(TensorFrame::new()).unwrap();
+    /// let new = store.store(TensorFrame::new()).unwrap();
+    /// assert!(store.supersede_frame(old, new).unwrap());
+    /// assert!(store.get_by_id(old).is_none());
+    /// assert_eq!(store.superseded_by(old), Some(new));
+    /// ```
+    pub fn supersede_frame(&mut self, frame_id: u64, superseded_by: u64) -> Result<bool, VoltError> {
+        let Some(strand_id) = self.find_frame(frame_id).map(|f| f.frame_meta.strand_id) else {
+            return Ok(false);
+        };
+        if let Some(ref mut wal) = self.wal {
+            wal.log_entry(WalEntry {
+                frame_id,
+                strand_id,
+                op: WalOp::Tombstone,
+                payload: superseded_by.to_le_bytes().to_vec(),
+                timestamp: 0,
+            })?;
+        }
+        if self.t0.remove(frame_id).is_none() {
+            self.t1.remove_frame(frame_id);
+        }
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+        let ts = to_tombstone(frame_id, strand_id, now, Some(superseded_by));
+        match self.t2 {
+            Some(ref mut t2) => t2.update(FrameEntry::Tombstone(ts))?,
+            None => {
+                self.superseded.insert(frame_id, ts);
+            }
+        }
+        self.hnsw.mark_deleted(frame_id);
+        self.temporal.remove(frame_id);
+        self.access.forget(frame_id);
+        Ok(true)
+    }
+
+    /// Deletes every unpinned frame whose TTL has run out by `now`
+    /// (microseconds since the Unix epoch).
+    ///
+    /// T0, T1, and T2 are all scanned; compressed and gist entries keep
+    /// the TTL of the frame they came from. Each expired frame goes
+    /// through [`delete`](Self::delete), so the removal is WAL-logged and
+    /// survives a restart. Frames without a TTL and pinned frames are
+    /// never expired. Returns the number of frames removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if a WAL entry or T2
+    /// tombstone cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let id = store.store_with_ttl(TensorFrame::new(), 10).unwrap();
+    /// let created = store.get_by_id(id).unwrap().frame_meta.created_at;
+    ///
+    /// assert_eq!(store.expire(created).unwrap(), 0);
+    /// assert_eq!(store.expire(created + 10).unwrap(), 1);
+    /// assert!(store.get_by_id(id).is_none());
+    /// ```
+    pub fn expire(&mut self, now: u64) -> Result<usize, VoltError> {
+        let is_due = |deadline: Option<u64>| deadline.is_some_and(|d| d <= now);
+        let mut expired_ids: BTreeSet<u64> = self
+            .t0
+            .iter()
+            .chain(
+                self.t1
+                    .list_strands()
+                    .into_iter()
+                    .flat_map(|strand| self.t1.get_by_strand(strand)),
+            )
+            .filter(|f| is_due(f.frame_meta.expires_at()))
+            .map(|f| f.frame_meta.frame_id)
+            .collect();
+        if let Some(ref t2) = self.t2 {
+            t2.for_each(|entry| {
+                if is_due(entry.expires_at()) {
+                    expired_ids.insert(entry.frame_id());
+                }
+            });
+        }
+
+        let mut expired = 0;
+        for frame_id in expired_ids {
+            if !self.gc.is_pinned(frame_id) && self.delete(frame_id)? {
+                expired += 1;
+            }
+        }
+        Ok(expired)
+    }
+
     /// Pins a frame so it is never garbage collected.
     ///
     /// # Example
@@ -1056,25 +2099,24 @@ impl VoltStore {
         self.gc.is_pinned(frame_id)
     }
 
-    /// Returns whether the store is disk-backed (has T2 and WAL).
-    pub fn is_disk_backed(&self) -> bool {
-        self.data_dir.is_some()
-    }
-
-    /// Reassigns a frame from its current strand to a new strand.
+    /// Replaces the GC retention formula with `scorer` for future GC runs.
     ///
-    /// Removes the frame from T1, updates its `strand_id`, and stores
-    /// it back. Also updates the HNSW index so the gist moves to the
-    /// new strand's partition. The target strand is created if it does
-    /// not exist.
+    /// See [`GcEngine::set_scorer`]; pinned frames are never demoted.
     ///
-    /// Only operates on T1 frames. T0 frames are ephemeral and should
-    /// not be reassigned.
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use volt_db::VoltStore;
     ///
-    /// Returns [`VoltError::StorageError`] if the frame is not found in T1,
-    /// or if re-insertion fails.
+    /// let mut store = VoltStore::new();
+    /// store.set_gc_scorer(Box::new(|meta, _now| if meta.reference_count > 0 { 1.0 } else { 0.5 }));
+    /// ```
+    pub fn set_gc_scorer(&mut self, scorer: GcScorer) {
+        self.gc.set_scorer(scorer);
+    }
+
+    /// Sets the minimum `global_certainty` required for [`VoltStore::store`]
+    /// to accept a frame. `None` disables the floor.
     ///
     /// # Example
     ///
@@ -1083,45 +2125,189 @@ impl VoltStore {
     /// use volt_core::TensorFrame;
     ///
     /// let mut store = VoltStore::new();
-    /// store.create_strand(1).unwrap();
-    /// store.switch_strand(1).unwrap();
-    ///
-    /// // Fill T0 so frames overflow to T1
-    /// let mut target_id = 0;
-    /// for _ in 0..65 {
-    ///     target_id = store.store(TensorFrame::new()).unwrap();
-    /// }
+    /// store.set_min_store_certainty(Some(0.5));
+    /// assert_eq!(store.min_store_certainty(), Some(0.5));
     ///
-    /// store.create_strand(2).unwrap();
-    /// // The first frame should now be in T1
-    /// let first_t1 = store.get_by_strand(1).first()
-    ///     .map(|f| f.frame_meta.frame_id).unwrap();
-    /// store.reassign_frame_strand(first_t1, 2).unwrap();
+    /// // Default frames have global_certainty 0.0 and are rejected
+    /// assert_eq!(store.store(TensorFrame::new()).unwrap(), 0);
+    /// assert_eq!(store.total_frame_count(), 0);
     /// ```
-    pub fn reassign_frame_strand(
-        &mut self,
-        frame_id: u64,
-        new_strand_id: u64,
-    ) -> Result<(), VoltError> {
-        // Remove from T1
-        let mut frame = self.t1.remove_frame(frame_id).ok_or_else(|| {
-            VoltError::StorageError {
-                message: format!(
-                    "reassign_frame_strand: frame {frame_id} not found in T1"
-                ),
-            }
-        })?;
-
-        // Update strand_id on the frame
-        frame.frame_meta.strand_id = new_strand_id;
+    pub fn set_min_store_certainty(&mut self, floor: Option<f32>) {
+        self.min_store_certainty = floor;
+    }
 
-        // Ensure target strand exists
-        if !self.t1.has_strand(new_strand_id) {
-            self.t1.create_strand(new_strand_id);
-        }
+    /// Returns the configured minimum store certainty, if any.
+    pub fn min_store_certainty(&self) -> Option<f32> {
+        self.min_store_certainty
+    }
 
-        // Remove old gist from HNSW and re-insert with new strand
-        self.hnsw.mark_deleted(frame_id);
+    /// Sets the compression used for T1 snapshots written by
+    /// [`VoltStore::save`]. Loading detects compression on its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_db::tier1::T1Compression;
+    ///
+    /// let mut store = VoltStore::new();
+    /// assert_eq!(store.t1_compression(), T1Compression::None);
+    /// store.set_t1_compression(T1Compression::Zstd(3));
+    /// assert_eq!(store.t1_compression(), T1Compression::Zstd(3));
+    /// ```
+    pub fn set_t1_compression(&mut self, compression: T1Compression) {
+        self.t1_compression = compression;
+    }
+
+    /// Returns the compression used for T1 snapshots.
+    pub fn t1_compression(&self) -> T1Compression {
+        self.t1_compression
+    }
+
+    /// Returns the RAM budget set by [`VoltStore::new_bounded`], if any.
+    pub fn max_ram_bytes(&self) -> Option<usize> {
+        self.max_ram_bytes
+    }
+
+    /// Estimated RAM held by full frames in T0 and T1, in bytes.
+    ///
+    /// Each frame counts as [`FRAME_RAM_BYTES`]. Indices and the T2
+    /// memtable are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::{VoltStore, FRAME_RAM_BYTES};
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// store.store(TensorFrame::new()).unwrap();
+    /// assert_eq!(store.ram_usage_bytes(), FRAME_RAM_BYTES);
+    /// ```
+    pub fn ram_usage_bytes(&self) -> usize {
+        self.total_frame_count() * FRAME_RAM_BYTES
+    }
+
+    /// Returns the tier movement counters accumulated since creation or
+    /// the last [`VoltStore::reset_store_metrics`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// store.store(TensorFrame::new()).unwrap();
+    /// assert_eq!(store.store_metrics().store_operations, 1);
+    /// assert_eq!(store.store_metrics().t0_evictions, 0);
+    /// ```
+    pub fn store_metrics(&self) -> StoreMetrics {
+        self.metrics
+    }
+
+    /// Resets every counter in [`VoltStore::store_metrics`] to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// store.store(TensorFrame::new()).unwrap();
+    /// store.reset_store_metrics();
+    /// assert_eq!(store.store_metrics().store_operations, 0);
+    /// ```
+    pub fn reset_store_metrics(&mut self) {
+        self.metrics = StoreMetrics::default();
+    }
+
+    /// Returns whether the store is disk-backed (has T2 and WAL).
+    pub fn is_disk_backed(&self) -> bool {
+        self.data_dir.is_some()
+    }
+
+    /// Reassigns a frame from its current strand to a new strand.
+    ///
+    /// Removes the frame from T1, updates its `strand_id`, and stores
+    /// it back. Also updates the HNSW index so the gist moves to the
+    /// new strand's partition. The target strand is created if it does
+    /// not exist.
+    ///
+    /// Only operates on T1 frames. T0 frames are ephemeral and should
+    /// not be reassigned.
+    ///
+    /// When disk-backed, a [`WalOp::Reassign`] entry is logged first so
+    /// WAL replay repeats the move.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the frame is not found in T1,
+    /// or if the WAL entry or re-insertion fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// store.create_strand(1).unwrap();
+    /// store.switch_strand(1).unwrap();
+    ///
+    /// // Fill T0 so frames overflow to T1
+    /// let mut target_id = 0;
+    /// for _ in 0..65 {
+    ///     target_id = store.store(TensorFrame::new()).unwrap();
+    /// }
+    ///
+    /// store.create_strand(2).unwrap();
+    /// // The first frame should now be in T1
+    /// let first_t1 = store.get_by_strand(1).first()
+    ///     .map(|f| f.frame_meta.frame_id).unwrap();
+    /// store.reassign_frame_strand(first_t1, 2).unwrap();
+    /// ```
+    pub fn reassign_frame_strand(
+        &mut self,
+        frame_id: u64,
+        new_strand_id: u64,
+    ) -> Result<(), VoltError> {
+        let old_strand_id = self
+            .t1
+            .get_by_id(frame_id)
+            .map(|f| f.frame_meta.strand_id)
+            .ok_or_else(|| VoltError::StorageError {
+                message: format!(
+                    "reassign_frame_strand: frame {frame_id} not found in T1"
+                ),
+            })?;
+        self.log_reassign(frame_id, old_strand_id, new_strand_id)?;
+        self.move_t1_frame(frame_id, new_strand_id)
+    }
+
+    /// Moves a T1 frame and its HNSW entry to `new_strand_id` without
+    /// logging the move.
+    fn move_t1_frame(&mut self, frame_id: u64, new_strand_id: u64) -> Result<(), VoltError> {
+        // Remove from T1
+        let mut frame = self.t1.remove_frame(frame_id).ok_or_else(|| {
+            VoltError::StorageError {
+                message: format!(
+                    "reassign_frame_strand: frame {frame_id} not found in T1"
+                ),
+            }
+        })?;
+
+        // Update strand_id on the frame
+        frame.frame_meta.strand_id = new_strand_id;
+
+        // Ensure target strand exists
+        if !self.t1.has_strand(new_strand_id) {
+            self.t1.create_strand(new_strand_id);
+        }
+
+        // Remove old gist from HNSW and re-insert with new strand
+        self.hnsw.mark_deleted(frame_id);
         if let Some(gist) = extract_gist(&frame)? {
             self.hnsw.insert(&gist)?;
         }
@@ -1132,12 +2318,153 @@ impl VoltStore {
         Ok(())
     }
 
+    /// Merges strand `source` into strand `target` and deletes `source`.
+    ///
+    /// Every frame of `source` is moved: T1 frames as by
+    /// [`VoltStore::reassign_frame_strand`] (which moves their HNSW
+    /// entries to the target partition), T0 frames in place, and T2
+    /// entries rewritten with the new strand ID. Frame IDs and
+    /// timestamps are untouched, and the target's T1 frames are re-sorted
+    /// by `(created_at, frame_id)` so the merged history reads in order.
+    /// If `source` was the active strand, `target` becomes active.
+    /// `target` is created if it does not exist. When disk-backed, a
+    /// [`WalOp::Reassign`] entry for every moved frame is logged before
+    /// anything moves, so WAL replay repeats the merge.
+    ///
+    /// Returns the number of frames moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StrandError`] if `source == target`, if
+    /// `source` is the default strand 0 (which may be a target but is
+    /// never deleted), or if `source` does not exist. Returns
+    /// [`VoltError::StorageError`] if moving a frame fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut store = VoltStore::new();
+    /// store.switch_strand(1).unwrap();
+    /// store.store(TensorFrame::new()).unwrap();
+    /// store.switch_strand(2).unwrap();
+    /// store.store(TensorFrame::new()).unwrap();
+    ///
+    /// assert_eq!(store.merge_strands(1, 2).unwrap(), 1);
+    /// assert_eq!(store.get_by_strand(2).len(), 2);
+    /// assert!(!store.list_strands().contains(&1));
+    /// assert!(store.merge_strands(0, 2).is_err());
+    /// ```
+    pub fn merge_strands(&mut self, source: u64, target: u64) -> Result<usize, VoltError> {
+        if source == target {
+            return Err(VoltError::StrandError {
+                strand_id: source,
+                message: "cannot merge a strand into itself".to_string(),
+            });
+        }
+        if source == 0 {
+            return Err(VoltError::StrandError {
+                strand_id: source,
+                message: "the default strand cannot be merged away".to_string(),
+            });
+        }
+        if !self.t1.has_strand(source) {
+            return Err(VoltError::StrandError {
+                strand_id: source,
+                message: "strand not found".to_string(),
+            });
+        }
+        if !self.t1.has_strand(target) {
+            self.t1.create_strand(target);
+        }
+
+        let t1_ids: Vec<u64> = self
+            .t1
+            .get_by_strand(source)
+            .iter()
+            .map(|f| f.frame_meta.frame_id)
+            .collect();
+        let t0_ids: Vec<u64> = self
+            .t0
+            .get_by_strand(source)
+            .iter()
+            .map(|f| f.frame_meta.frame_id)
+            .collect();
+        // Runs can hold stale versions, so only the current version of
+        // each archived ID is moved.
+        let mut t2_entries = Vec::new();
+        if let Some(ref t2) = self.t2 {
+            let mut t2_ids: Vec<u64> =
+                t2.scan_strand(source).iter().map(|e| e.frame_id()).collect();
+            t2_ids.sort_unstable();
+            t2_ids.dedup();
+            t2_entries = t2_ids
+                .into_iter()
+                .filter_map(|frame_id| t2.get(frame_id))
+                .filter(|entry| entry.strand_id() == source)
+                .collect();
+        }
+        let moved_ids = t1_ids
+            .iter()
+            .chain(&t0_ids)
+            .copied()
+            .chain(t2_entries.iter().map(FrameEntry::frame_id));
+        for frame_id in moved_ids.collect::<Vec<_>>() {
+            self.log_reassign(frame_id, source, target)?;
+        }
+
+        // T1: the per-frame move (HNSW partitions included)
+        for &frame_id in &t1_ids {
+            self.move_t1_frame(frame_id, target)?;
+        }
+        self.t1.sort_strand_chronologically(target);
+
+        // T0: retag in place, then move gists to the target partition
+        let t0_ids = self.t0.reassign_strand(source, target);
+        for &frame_id in &t0_ids {
+            self.hnsw.mark_deleted(frame_id);
+            if let Some(frame) = self.t0.get_by_id(frame_id)
+                && let Some(gist) = extract_gist(frame)?
+            {
+                self.hnsw.insert(&gist)?;
+            }
+        }
+
+        // T2: rewrite archived entries with the new strand ID
+        let t2_moved = t2_entries.len();
+        if let Some(ref mut t2) = self.t2 {
+            for mut entry in t2_entries {
+                set_entry_strand(&mut entry, target);
+                t2.update(entry)?;
+            }
+        }
+
+        self.t1.remove_empty_strand(source);
+        if self.active_strand == source {
+            self.active_strand = target;
+        }
+
+        Ok(t1_ids.len() + t0_ids.len() + t2_moved)
+    }
+
     // --- Persistence ---
 
     /// Saves T1 strand storage to disk for persistence across restarts.
     ///
     /// T0 is intentionally not saved — it's ephemeral working memory.
     /// HNSW and temporal indices are rebuilt on load from T1 data.
+    /// The file is compressed per [`VoltStore::t1_compression`].
+    ///
+    /// When a disk-backed store saves to [`T1_FILE`] in its own data
+    /// directory, the WAL is then checkpointed so the next
+    /// [`VoltStore::open`] only replays entries logged after this save.
+    /// Frames still in T0 are carried into the checkpointed log, in the
+    /// same atomic rewrite and with their original WAL timestamps, since
+    /// the saved file doesn't hold them. The T2 memtable is flushed to a
+    /// sorted run first, so compressed frames and tombstones it holds
+    /// survive the checkpoint. Saving anywhere else leaves the WAL alone.
     ///
     /// # Errors
     ///
@@ -1149,11 +2476,78 @@ impl VoltStore {
     /// use volt_db::VoltStore;
     /// use std::path::Path;
     ///
-    /// let store = VoltStore::new();
+    /// let mut store = VoltStore::new();
     /// store.save(Path::new("voltdb_t1.json")).unwrap();
     /// ```
-    pub fn save(&self, path: &Path) -> Result<(), VoltError> {
-        self.t1.save(path)
+    pub fn save(&mut self, path: &Path) -> Result<(), VoltError> {
+        self.t1.save_with(path, self.t1_compression)?;
+
+        let is_own_t1 = self
+            .data_dir
+            .as_ref()
+            .is_some_and(|dir| dir.join(T1_FILE) == path);
+        if !is_own_t1 || self.wal.is_none() {
+            return Ok(());
+        }
+        // GC demotions and T2 tombstones are only durable through the WAL
+        // until the memtable reaches a sorted run, so flush before
+        // discarding their entries.
+        if let Some(ref mut t2) = self.t2 {
+            t2.flush_memtable()?;
+        }
+        let Some(wal) = self.wal.as_mut() else {
+            return Ok(());
+        };
+        let logged_at = wal_store_timestamps(wal)?;
+        let mut t0_frames: Vec<&TensorFrame> = self.t0.iter().collect();
+        t0_frames.sort_by_key(|f| f.frame_meta.frame_id);
+        let carried: Vec<WalEntry> = t0_frames
+            .into_iter()
+            .map(|frame| {
+                let frame_id = frame.frame_meta.frame_id;
+                let mut payload = vec![DecayLevel::Full.tag()];
+                payload.extend_from_slice(&frame.to_binary());
+                WalEntry {
+                    frame_id,
+                    strand_id: frame.frame_meta.strand_id,
+                    op: WalOp::Store,
+                    payload,
+                    timestamp: logged_at
+                        .get(&frame_id)
+                        .copied()
+                        .unwrap_or(frame.frame_meta.created_at),
+                }
+            })
+            .collect();
+        wal.checkpoint(self.next_id.load(Ordering::SeqCst), &carried)
+    }
+
+    /// Saves the HNSW index, graph links included, to [`HNSW_INDEX_DIR`]
+    /// in the data directory, so the next [`VoltStore::open`] can load it
+    /// instead of rebuilding.
+    ///
+    /// Call it after [`VoltStore::save`] when shutting down: `open` only
+    /// reuses the file if it matches the frames in T1 after WAL replay.
+    /// Memory-only stores have no data directory; for them this is a
+    /// no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the index cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    ///
+    /// let store = VoltStore::new();
+    /// store.save_index().unwrap(); // memory-only: nothing written
+    /// ```
+    pub fn save_index(&self) -> Result<(), VoltError> {
+        match &self.data_dir {
+            Some(dir) => self.hnsw.save(&dir.join(HNSW_INDEX_DIR)),
+            None => Ok(()),
+        }
     }
 
     /// Loads T1 strand storage from disk, creating a fresh T0.
@@ -1175,7 +2569,70 @@ impl VoltStore {
     /// let store = VoltStore::load(Path::new("voltdb_t1.json")).unwrap();
     /// ```
     pub fn load(path: &Path) -> Result<Self, VoltError> {
-        let mut t1 = StrandStore::load(path)?;
+        Self::from_strand_store(StrandStore::load(path)?)
+    }
+
+    /// Rebuilds the store as it was at `timestamp` (microseconds since
+    /// the Unix epoch) by replaying the WAL in `data_dir` up to that time.
+    ///
+    /// The WAL files are only read, never opened for writing, and the
+    /// result is a memory-only store: writes to it never reach
+    /// `data_dir`. Replayed frames land in T1; deletes and tombstones
+    /// remove them again and reassignments move them between strands,
+    /// while compression and gisting are tier moves and leave them in
+    /// place. Entries logged before WAL timestamps
+    /// existed count as the oldest. A timestamp before every entry yields
+    /// an empty store. Only entries since the last WAL checkpoint (see
+    /// [`VoltStore::save`]) are still in the log, so earlier history
+    /// cannot be recovered this way.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if a WAL file cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    ///
+    /// let dir = std::env::temp_dir().join("volt_doc_recover_to_missing");
+    /// let store = VoltStore::recover_to(&dir, 0).unwrap();
+    /// assert_eq!(store.total_frame_count(), 0);
+    /// ```
+    pub fn recover_to(data_dir: &Path, timestamp: u64) -> Result<Self, VoltError> {
+        let mut entries = merge_by_timestamp(WalManager::read_dir(&data_dir.join("wal"))?);
+        entries.retain(|entry| entry.timestamp <= timestamp);
+
+        let mut t1 = StrandStore::new();
+        let mut reassigned_to = BTreeSet::new();
+        for entry in &entries {
+            match entry.op {
+                WalOp::Store => {
+                    Self::recover_frame(&mut t1, &entry.payload)?;
+                }
+                WalOp::StoreBatch => {
+                    for part in split_batch_payload(&entry.payload).unwrap_or_default() {
+                        Self::recover_frame(&mut t1, part)?;
+                    }
+                }
+                WalOp::Delete | WalOp::Tombstone => {
+                    t1.remove_frame(entry.frame_id);
+                }
+                WalOp::Reassign => {
+                    Self::replay_reassign(&mut t1, entry)?;
+                    reassigned_to.insert(entry.strand_id);
+                }
+                WalOp::Compress | WalOp::Gist | WalOp::Checkpoint => {}
+            }
+        }
+        for strand_id in reassigned_to {
+            t1.sort_strand_chronologically(strand_id);
+        }
+        Self::from_strand_store(t1)
+    }
+
+    /// Builds a memory-only store around `t1`, indexing its frames.
+    fn from_strand_store(mut t1: StrandStore) -> Result<Self, VoltError> {
         if !t1.has_strand(0) {
             t1.create_strand(0);
         }
@@ -1184,16 +2641,7 @@ impl VoltStore {
         let max_id = Self::find_max_frame_id(&t1);
 
         // Rebuild HNSW and temporal indices from T1 frames
-        let mut hnsw = HnswIndex::new();
-        let mut temporal = TemporalIndex::new();
-        for strand_id in t1.list_strands() {
-            for frame in t1.get_by_strand(strand_id) {
-                if let Some(gist) = extract_gist(frame)? {
-                    hnsw.insert(&gist)?;
-                    temporal.insert(gist.created_at, gist.frame_id);
-                }
-            }
-        }
+        let (hnsw, temporal, _) = Self::build_indices(t1_frames(&t1), HnswParams::default())?;
 
         Ok(Self {
             t0: WorkingMemory::new(),
@@ -1201,41 +2649,445 @@ impl VoltStore {
             t2: None,
             wal: None,
             gc: GcEngine::with_defaults(),
+            access: AccessTracker::default(),
+            superseded: HashMap::new(),
             consolidation: ConsolidationEngine::with_defaults(),
             active_strand: 0,
-            next_id: max_id + 1,
+            next_id: AtomicU64::new(max_id + 1),
             hnsw,
             temporal,
             bleed: BleedEngine::new(),
             data_dir: None,
             t1_overflow_threshold: 1024,
+            min_store_certainty: None,
+            max_ram_bytes: None,
+            t1_compression: T1Compression::None,
+            metrics: StoreMetrics::default(),
+            recovery: RecoveryReport::default(),
         })
     }
 
-    /// Scans T1 to find the highest frame_id for ID generation continuity.
-    fn find_max_frame_id(t1: &StrandStore) -> u64 {
-        let mut max = 0u64;
-        for strand_id in t1.list_strands() {
-            for frame in t1.get_by_strand(strand_id) {
-                if frame.frame_meta.frame_id > max {
-                    max = frame.frame_meta.frame_id;
-                }
-            }
-        }
-        max
-    }
-
-    /// Overflows the oldest T1 frames to T2 (compressed).
-    fn maybe_overflow_t1_to_t2(&mut self) -> Result<(), VoltError> {
-        let overflow_count = self
-            .t1
-            .total_frame_count()
-            .saturating_sub(self.t1_overflow_threshold);
-
-        if overflow_count == 0 {
-            return Ok(());
-        }
-
+    /// Captures T0, T1 and T2 into a new `snapshot_<micros>` directory
+    /// under `dir`, returning its path.
+    ///
+    /// The T2 memtable is flushed first so every T2 entry is in a sorted
+    /// run, and the runs are copied. T0 and T1 are written in full, so the
+    /// snapshot does not depend on the WAL; the manifest records the next
+    /// frame ID instead. Files are written to a hidden staging directory
+    /// that is renamed into place only once complete. Snapshot a shared
+    /// store through [`ConcurrentVoltStore::snapshot`] so no write lands
+    /// mid-snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if flushing T2 or any file
+    /// operation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use volt_db::VoltStore;
+    /// use volt_core::TensorFrame;
+    /// use std::path::Path;
+    ///
+    /// let mut store = VoltStore::new();
+    /// store.store(TensorFrame::new()).unwrap();
+    /// let snapshot = store.snapshot(Path::new("backups")).unwrap();
+    /// assert!(snapshot.join("manifest.json").exists());
+    /// ```
+    pub fn snapshot(&mut self, dir: &Path) -> Result<PathBuf, VoltError> {
+        let created_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+        let target = dir.join(format!("snapshot_{created_at}"));
+        let staging = dir.join(format!(".snapshot_{created_at}.tmp"));
+        let t2_dir = staging.join("t2");
+        std::fs::create_dir_all(&t2_dir).map_err(|e| snapshot_io_error("create", &t2_dir, e))?;
+
+        let mut t2_entries = 0;
+        if let Some(ref mut t2) = self.t2 {
+            t2.flush_memtable()?;
+            t2_entries = t2.total_entries();
+            for run in t2.run_paths() {
+                if let Some(name) = run.file_name() {
+                    std::fs::copy(&run, t2_dir.join(name))
+                        .map_err(|e| snapshot_io_error("copy", &run, e))?;
+                }
+            }
+        }
+
+        let mut t0 = StrandStore::new();
+        for frame in self.t0.iter() {
+            t0.store(frame.clone())?;
+        }
+        t0.save_with(&staging.join("t0.bin"), self.t1_compression)?;
+        self.t1.save_with(&staging.join("t1.bin"), self.t1_compression)?;
+
+        let manifest = SnapshotManifest {
+            version: SNAPSHOT_VERSION,
+            created_at,
+            next_id: self.next_id.load(Ordering::SeqCst),
+            active_strand: self.active_strand,
+            t0_frames: self.t0.len(),
+            t1_frames: self.t1.total_frame_count(),
+            t2_entries,
+        };
+        let json = serde_json::to_vec_pretty(&manifest).map_err(|e| VoltError::StorageError {
+            message: format!("failed to serialize snapshot manifest: {e}"),
+        })?;
+        let manifest_path = staging.join("manifest.json");
+        std::fs::write(&manifest_path, json)
+            .map_err(|e| snapshot_io_error("write", &manifest_path, e))?;
+
+        std::fs::rename(&staging, &target).map_err(|e| snapshot_io_error("finalize", &target, e))?;
+        Ok(target)
+    }
+
+    /// Reads the manifest of the snapshot directory `snapshot`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the manifest is missing,
+    /// malformed, or from a newer snapshot version.
+    pub fn read_snapshot_manifest(snapshot: &Path) -> Result<SnapshotManifest, VoltError> {
+        let path = snapshot.join("manifest.json");
+        let bytes = std::fs::read(&path).map_err(|e| snapshot_io_error("read", &path, e))?;
+        let manifest: SnapshotManifest =
+            serde_json::from_slice(&bytes).map_err(|e| VoltError::StorageError {
+                message: format!("malformed snapshot manifest {}: {e}", path.display()),
+            })?;
+        if manifest.version > SNAPSHOT_VERSION {
+            return Err(VoltError::StorageError {
+                message: format!(
+                    "snapshot version {} is newer than supported version {SNAPSHOT_VERSION}",
+                    manifest.version
+                ),
+            });
+        }
+        Ok(manifest)
+    }
+
+    /// Rebuilds a disk-backed store in `config.data_dir` from a snapshot
+    /// taken by [`VoltStore::snapshot`].
+    ///
+    /// The snapshot's T1 and T2 files are copied into the data directory,
+    /// which must be empty or missing, and the store is opened from them.
+    /// T0 frames go back into working memory and are WAL-logged, and the
+    /// active strand and next frame ID come from the manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the data directory is not
+    /// empty, the snapshot is incomplete or unreadable, or opening the
+    /// restored store fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use volt_db::{VoltStore, VoltStoreConfig};
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let config = VoltStoreConfig {
+    ///     data_dir: PathBuf::from("/tmp/voltdb_restored"),
+    ///     ..VoltStoreConfig::default()
+    /// };
+    /// let snapshot = Path::new("backups/snapshot_1700000000000000");
+    /// let store = VoltStore::restore(snapshot, config).unwrap();
+    /// ```
+    pub fn restore(snapshot: &Path, config: VoltStoreConfig) -> Result<Self, VoltError> {
+        let manifest = Self::read_snapshot_manifest(snapshot)?;
+
+        let data_dir = &config.data_dir;
+        if let Ok(mut entries) = std::fs::read_dir(data_dir)
+            && entries.next().is_some()
+        {
+            return Err(VoltError::StorageError {
+                message: format!(
+                    "refusing to restore into non-empty directory {}",
+                    data_dir.display()
+                ),
+            });
+        }
+
+        let t2_dir = data_dir.join("t2");
+        std::fs::create_dir_all(&t2_dir).map_err(|e| snapshot_io_error("create", &t2_dir, e))?;
+        let snapshot_t2 = snapshot.join("t2");
+        let runs = std::fs::read_dir(&snapshot_t2)
+            .map_err(|e| snapshot_io_error("read", &snapshot_t2, e))?;
+        for run in runs {
+            let run = run.map_err(|e| snapshot_io_error("read", &snapshot_t2, e))?.path();
+            if let Some(name) = run.file_name() {
+                std::fs::copy(&run, t2_dir.join(name))
+                    .map_err(|e| snapshot_io_error("copy", &run, e))?;
+            }
+        }
+        let t1_src = snapshot.join("t1.bin");
+        std::fs::copy(&t1_src, data_dir.join(T1_FILE))
+            .map_err(|e| snapshot_io_error("copy", &t1_src, e))?;
+        let t0 = StrandStore::load(&snapshot.join("t0.bin"))?;
+
+        let mut store = Self::open(config)?;
+        let mut t0_frames: Vec<&TensorFrame> = t1_frames(&t0).collect();
+        t0_frames.sort_by_key(|f| f.frame_meta.frame_id);
+        for frame in t0_frames {
+            if let Some(ref mut wal) = store.wal {
+                let mut payload = vec![DecayLevel::Full.tag()];
+                payload.extend_from_slice(&frame.to_binary());
+                wal.log_entry(WalEntry {
+                    frame_id: frame.frame_meta.frame_id,
+                    strand_id: frame.frame_meta.strand_id,
+                    op: WalOp::Store,
+                    payload,
+                    timestamp: 0,
+                })?;
+            }
+            if !store.t1.has_strand(frame.frame_meta.strand_id) {
+                store.t1.create_strand(frame.frame_meta.strand_id);
+            }
+            if let Some(evicted) = store.t0.store(frame.clone()) {
+                store.t1.store(evicted)?;
+            }
+        }
+        store.reindex()?;
+
+        if !store.t1.has_strand(manifest.active_strand) {
+            store.t1.create_strand(manifest.active_strand);
+        }
+        store.active_strand = manifest.active_strand;
+        store.next_id.fetch_max(manifest.next_id, Ordering::SeqCst);
+        Ok(store)
+    }
+
+    /// Rebuilds the HNSW and temporal indices in place from T0 and T1.
+    ///
+    /// This is a live repair operation for when the indices have drifted
+    /// from the stored frames (e.g. after a bug or a bulk import that
+    /// bypassed indexing). Both indices are discarded and every full frame
+    /// with an R₀ gist is re-inserted, producing the same index contents as
+    /// a fresh [`VoltStore::load`] plus the frames still in T0. Compressed
+    /// frames in T2 are not indexed, matching normal operation.
+    ///
+    /// Requires `&mut self`; on a [`ConcurrentVoltStore`] call it under
+    /// the write lock so readers never observe a half-built index. The
+    /// number of entries indexed is logged; read it back with
+    /// [`VoltStore::hnsw_entries`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError`] if gist extraction or HNSW insertion fails.
+    /// On error the existing indices are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_core::slot::SlotSource;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let mut frame = TensorFrame::new();
+    /// let mut slot = SlotData::new(SlotRole::Agent);
+    /// slot.write_resolution(0, [0.1; SLOT_DIM]);
+    /// frame.write_slot(0, slot).unwrap();
+    /// frame.meta[0].source = SlotSource::Translator;
+    /// store.store(frame).unwrap();
+    ///
+    /// store.reindex().unwrap();
+    /// assert_eq!(store.hnsw_entries(), 1);
+    /// ```
+    pub fn reindex(&mut self) -> Result<(), VoltError> {
+        let frames = t1_frames(&self.t1).chain(self.t0.iter());
+        let (hnsw, temporal, indexed) = Self::build_indices(frames, self.hnsw.params())?;
+        self.hnsw = hnsw;
+        self.temporal = temporal;
+        tracing::info!(indexed, "rebuilt HNSW and temporal indices");
+        Ok(())
+    }
+
+    /// Checks that the HNSW index agrees with the stored frames.
+    ///
+    /// Every full frame in T0 or T1 with an R₀ gist should have a live
+    /// HNSW entry, and every live HNSW entry should belong to such a
+    /// frame. Frames missing from the index are reported as unindexed;
+    /// index entries with no matching frame are reported as orphaned.
+    /// Run [`VoltStore::reindex`] to repair a failing check.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError`] if gist extraction fails for a stored frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::VoltStore;
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_core::slot::SlotSource;
+    ///
+    /// let mut store = VoltStore::new();
+    /// let mut frame = TensorFrame::new();
+    /// let mut slot = SlotData::new(SlotRole::Agent);
+    /// slot.write_resolution(0, [0.1; SLOT_DIM]);
+    /// frame.write_slot(0, slot).unwrap();
+    /// frame.meta[0].source = SlotSource::Translator;
+    /// store.store(frame).unwrap();
+    ///
+    /// assert!(store.verify_consistency().unwrap().is_consistent());
+    /// ```
+    pub fn verify_consistency(&self) -> Result<ConsistencyReport, VoltError> {
+        let mut expected = Vec::new();
+        for frame in t1_frames(&self.t1).chain(self.t0.iter()) {
+            if extract_gist(frame)?.is_some() {
+                expected.push(frame.frame_meta.frame_id);
+            }
+        }
+        expected.sort_unstable();
+        let indexed = self.hnsw.frame_ids();
+
+        let unindexed_frames = expected
+            .iter()
+            .copied()
+            .filter(|id| indexed.binary_search(id).is_err())
+            .collect();
+        let orphaned_entries = indexed
+            .iter()
+            .copied()
+            .filter(|id| expected.binary_search(id).is_err())
+            .collect();
+        Ok(ConsistencyReport {
+            unindexed_frames,
+            orphaned_entries,
+        })
+    }
+
+    /// Builds fresh HNSW and temporal indices over the given frames,
+    /// with the HNSW index using `params`.
+    ///
+    /// Frames without an R₀ gist are skipped. Returns the indices and
+    /// the number of frames indexed.
+    fn build_indices<'a>(
+        frames: impl Iterator<Item = &'a TensorFrame>,
+        params: HnswParams,
+    ) -> Result<(HnswIndex, TemporalIndex, usize), VoltError> {
+        let mut hnsw = HnswIndex::with_params(params);
+        let mut temporal = TemporalIndex::new();
+        let mut indexed = 0;
+        for frame in frames {
+            if let Some(gist) = extract_gist(frame)? {
+                hnsw.insert(&gist)?;
+                temporal.insert(gist.created_at, gist.frame_id);
+                indexed += 1;
+            }
+        }
+        Ok((hnsw, temporal, indexed))
+    }
+
+    /// Like [`VoltStore::build_indices`], but takes the HNSW index from
+    /// the directory at `saved` when it was built with `params` and holds
+    /// exactly the live `(frame_id, strand_id)` entries the frames would
+    /// produce.
+    ///
+    /// Only the temporal index and the gist extraction run over the
+    /// frames then; the HNSW graph is not rebuilt.
+    fn build_indices_reusing<'a>(
+        frames: impl Iterator<Item = &'a TensorFrame>,
+        saved: &Path,
+        params: HnswParams,
+    ) -> Result<(HnswIndex, TemporalIndex), VoltError> {
+        let mut gists = Vec::new();
+        for frame in frames {
+            if let Some(gist) = extract_gist(frame)? {
+                gists.push(gist);
+            }
+        }
+        let mut temporal = TemporalIndex::new();
+        for gist in &gists {
+            temporal.insert(gist.created_at, gist.frame_id);
+        }
+
+        if saved.exists() {
+            match HnswIndex::load(saved) {
+                Ok(index) if index.params() == params => {
+                    let mut expected: Vec<(u64, u64)> =
+                        gists.iter().map(|g| (g.frame_id, g.strand_id)).collect();
+                    expected.sort_unstable();
+                    if index.live_entries() == expected {
+                        return Ok((index, temporal));
+                    }
+                    tracing::warn!(
+                        path = %saved.display(),
+                        "saved HNSW index is stale, rebuilding"
+                    );
+                }
+                Ok(_) => tracing::warn!(
+                    path = %saved.display(),
+                    "saved HNSW index uses different params, rebuilding"
+                ),
+                Err(e) => tracing::warn!(error = %e, "ignoring saved HNSW index, rebuilding"),
+            }
+        }
+
+        let mut hnsw = HnswIndex::with_params(params);
+        for gist in &gists {
+            hnsw.insert(gist)?;
+        }
+        Ok((hnsw, temporal))
+    }
+
+    /// Drops both indices without touching stored frames (test-only
+    /// corruption hook for exercising [`VoltStore::reindex`]).
+    #[cfg(test)]
+    fn clear_indices_for_test(&mut self) {
+        self.hnsw = HnswIndex::new();
+        self.temporal = TemporalIndex::new();
+    }
+
+    /// Inserts a gist into HNSW without storing a frame (test-only
+    /// corruption hook for exercising [`VoltStore::verify_consistency`]).
+    #[cfg(test)]
+    fn insert_orphan_gist_for_test(&mut self, gist: &FrameGist) -> Result<(), VoltError> {
+        self.hnsw.insert(gist)
+    }
+
+    /// Scans T1 to find the highest frame_id for ID generation continuity.
+    fn find_max_frame_id(t1: &StrandStore) -> u64 {
+        let mut max = 0u64;
+        for strand_id in t1.list_strands() {
+            for frame in t1.get_by_strand(strand_id) {
+                if frame.frame_meta.frame_id > max {
+                    max = frame.frame_meta.frame_id;
+                }
+            }
+        }
+        max
+    }
+
+    /// Number of frames that must leave RAM to get back under the budget.
+    ///
+    /// Zero when unbounded or within budget.
+    fn ram_overflow_count(&self) -> usize {
+        match self.max_ram_bytes {
+            Some(max) => self
+                .ram_usage_bytes()
+                .saturating_sub(max)
+                .div_ceil(FRAME_RAM_BYTES),
+            None => 0,
+        }
+    }
+
+    /// Overflows the oldest T1 frames to T2 (compressed), enough to satisfy
+    /// both the T1 frame threshold and the RAM budget (if any).
+    fn maybe_overflow_t1_to_t2(&mut self) -> Result<(), VoltError> {
+        let overflow_count = self
+            .t1
+            .total_frame_count()
+            .saturating_sub(self.t1_overflow_threshold)
+            .max(self.ram_overflow_count());
+
+        if overflow_count == 0 {
+            return Ok(());
+        }
+
         // Get oldest frame IDs
         let oldest_ids = self.t1.oldest_frame_ids(overflow_count);
 
@@ -1252,12 +3104,14 @@ impl VoltStore {
                         strand_id: frame.frame_meta.strand_id,
                         op: WalOp::Compress,
                         payload,
+                        timestamp: 0,
                     })?;
                 }
 
                 if let Some(ref mut t2) = self.t2 {
                     t2.insert(FrameEntry::Compressed(compressed))?;
                 }
+                self.metrics.t1_to_t2_overflows += 1;
 
                 // Mark deleted in HNSW (frame is no longer in Full form)
                 self.hnsw.mark_deleted(frame_id);
@@ -1269,6 +3123,29 @@ impl VoltStore {
     }
 }
 
+/// Whether a frame with certainty `gamma` falls below the store floor.
+///
+/// Written as `!(gamma >= floor)` so a NaN on either side rejects the
+/// frame instead of slipping past a `<` comparison.
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+fn below_floor(gamma: f32, floor: f32) -> bool {
+    !(gamma >= floor)
+}
+
+/// Iterates over every frame in T1, strand by strand.
+fn t1_frames(t1: &StrandStore) -> impl Iterator<Item = &TensorFrame> {
+    t1.list_strands()
+        .into_iter()
+        .flat_map(move |strand_id| t1.get_by_strand(strand_id))
+}
+
+/// Wraps an I/O error from snapshot or restore with the path involved.
+fn snapshot_io_error(action: &str, path: &Path, e: std::io::Error) -> VoltError {
+    VoltError::StorageError {
+        message: format!("failed to {action} {}: {e}", path.display()),
+    }
+}
+
 /// Extracts a gist vector from a CompressedFrame by averaging R₀ slots.
 fn extract_gist_vector_from_compressed(
     compressed: &crate::compressed::CompressedFrame,
@@ -1302,6 +3179,55 @@ fn extract_gist_vector_from_compressed(
     sum
 }
 
+/// The superseding frame ID carried by a [`WalOp::Tombstone`] entry.
+fn superseded_by_payload(entry: &WalEntry) -> Option<u64> {
+    if entry.op != WalOp::Tombstone {
+        return None;
+    }
+    let bytes = entry.payload.get(..8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// The strand a frame left, carried by a [`WalOp::Reassign`] entry.
+fn reassigned_from_payload(entry: &WalEntry) -> Option<u64> {
+    let bytes = entry.payload.get(..8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Retags a T2 entry with a new strand ID.
+fn set_entry_strand(entry: &mut FrameEntry, strand_id: u64) {
+    match entry {
+        FrameEntry::Full(f) => f.frame_meta.strand_id = strand_id,
+        FrameEntry::Compressed(c) => c.strand_id = strand_id,
+        FrameEntry::Gist(g) => g.strand_id = strand_id,
+        FrameEntry::Tombstone(t) => t.strand_id = strand_id,
+    }
+}
+
+/// Maps each frame stored through the WAL (singly or in a batch) to the
+/// timestamp of its `Store` entry.
+fn wal_store_timestamps(wal: &WalManager) -> Result<HashMap<u64, u64>, VoltError> {
+    let mut logged_at = HashMap::new();
+    for entries in wal.replay_all()?.values() {
+        for entry in entries {
+            match entry.op {
+                WalOp::Store => {
+                    logged_at.insert(entry.frame_id, entry.timestamp);
+                }
+                WalOp::StoreBatch => {
+                    for part in split_batch_payload(&entry.payload).unwrap_or_default() {
+                        if let Some(frame) = part.get(1..).and_then(|b| TensorFrame::from_binary(b).ok()) {
+                            logged_at.insert(frame.frame_meta.frame_id, entry.timestamp);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(logged_at)
+}
+
 /// Thread-safe concurrent wrapper around VoltStore.
 ///
 /// Wraps `VoltStore` in `Arc<RwLock<_>>` for concurrent access.
@@ -1372,6 +3298,39 @@ impl ConcurrentVoltStore {
         })
     }
 
+    /// Takes a [`VoltStore::snapshot`] under the write lock, so no write
+    /// lands while the snapshot is taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the lock is poisoned or the
+    /// snapshot fails.
+    pub fn snapshot(&self, dir: &Path) -> Result<PathBuf, VoltError> {
+        self.write()?.snapshot(dir)
+    }
+
+    /// Reserves `count` consecutive frame IDs under the read lock only,
+    /// so reservations do not wait for each other or block readers.
+    ///
+    /// See [`VoltStore::reserve_ids`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the lock is poisoned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::{VoltStore, ConcurrentVoltStore};
+    ///
+    /// let concurrent = ConcurrentVoltStore::new(VoltStore::new());
+    /// assert_eq!(concurrent.reserve_ids(2).unwrap(), 1..3);
+    /// assert_eq!(concurrent.reserve_ids(1).unwrap(), 3..4);
+    /// ```
+    pub fn reserve_ids(&self, count: u64) -> Result<Range<u64>, VoltError> {
+        Ok(self.read()?.reserve_ids(count))
+    }
+
     /// Returns a clone of the inner `Arc<RwLock<VoltStore>>`.
     ///
     /// Useful for passing to components that require raw Arc access,
@@ -1396,6 +3355,7 @@ impl ConcurrentVoltStore {
 mod tests {
     use super::*;
     use crate::tier0::T0_CAPACITY;
+    use volt_core::slot::SlotSource;
     use volt_core::{SlotData, SlotRole, SLOT_DIM};
 
     fn make_frame_with_content() -> TensorFrame {
@@ -1403,6 +3363,7 @@ mod tests {
         let mut slot = SlotData::new(SlotRole::Agent);
         slot.write_resolution(0, [0.5; SLOT_DIM]);
         frame.write_slot(0, slot).unwrap();
+        frame.meta[0].source = SlotSource::Translator;
         frame
     }
 
@@ -1470,6 +3431,34 @@ mod tests {
         assert_eq!(store.t1_len(), 1);
     }
 
+    #[test]
+    fn store_metrics_count_t0_evictions() {
+        let mut store = VoltStore::new();
+        for _ in 0..T0_CAPACITY {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        let metrics = store.store_metrics();
+        assert_eq!(metrics.store_operations, T0_CAPACITY as u64);
+        assert_eq!(metrics.t0_evictions, 0);
+
+        for _ in 0..3 {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        let metrics = store.store_metrics();
+        assert_eq!(metrics.t0_evictions, 3);
+        assert_eq!(metrics.store_operations, T0_CAPACITY as u64 + 3);
+        assert_eq!(metrics.t1_to_t2_overflows, 0);
+
+        // Frames dropped by the certainty floor are not store operations.
+        store.set_min_store_certainty(Some(2.0));
+        store.store(make_frame_with_content()).unwrap();
+        assert_eq!(store.store_metrics(), metrics);
+
+        store.reset_store_metrics();
+        assert_eq!(store.store_metrics(), StoreMetrics::default());
+        assert_eq!(store.t1_len(), 3);
+    }
+
     #[test]
     fn switch_strand_creates_if_needed() {
         let mut store = VoltStore::new();
@@ -1500,6 +3489,35 @@ mod tests {
         assert_eq!(frames.len(), T0_CAPACITY + 5);
     }
 
+    #[test]
+    fn iter_strand_chronological_spans_tiers() {
+        let mut store = VoltStore::new();
+        store.switch_strand(1).unwrap();
+
+        // Oldest-stored frames get the newest timestamps and end up in T1;
+        // after the first, frames share timestamps in pairs.
+        let count = T0_CAPACITY + 5;
+        for i in 0..count {
+            let mut frame = make_frame_with_content();
+            frame.frame_meta.created_at = ((count - i - i % 2) * 1000) as u64;
+            store.store(frame).unwrap();
+        }
+        store.switch_strand(2).unwrap();
+        store.store(make_frame_with_content()).unwrap();
+        assert!(store.t1.total_frame_count() > 0);
+
+        let frames = store.iter_strand_chronological(1);
+        assert_eq!(frames.len(), count);
+        let keys: Vec<(u64, u64)> = frames
+            .iter()
+            .map(|f| (f.frame_meta.created_at, f.frame_meta.frame_id))
+            .collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]), "{keys:?}");
+        // The newest timestamp belongs to the first-stored frame, now in T1.
+        assert_eq!(frames.last().unwrap().frame_meta.frame_id, 1);
+        assert!(store.iter_strand_chronological(99).is_empty());
+    }
+
     #[test]
     fn recent_returns_from_t0() {
         let mut store = VoltStore::new();
@@ -1580,15 +3598,201 @@ mod tests {
     }
 
     #[test]
-    fn load_continues_id_generation() {
+    fn expire_removes_frames_past_ttl() {
         let mut store = VoltStore::new();
-        for _ in 0..(T0_CAPACITY + 5) {
-            store.store(make_frame_with_content()).unwrap();
-        }
+        let kept = store.store(make_frame_with_content()).unwrap();
+        let ephemeral = store.store_with_ttl(make_frame_with_content(), 1).unwrap();
+        let entries_before = store.hnsw_entries();
+        let created = store.get_by_id(ephemeral).unwrap().frame_meta.created_at;
+
+        assert_eq!(store.expire(created + 1_000_000).unwrap(), 1);
+        assert!(store.get_by_id(ephemeral).is_none());
+        assert!(store.get_by_id(kept).is_some());
+        assert_eq!(store.hnsw_entries(), entries_before - 1);
+        assert_eq!(store.temporal_entries(), 1);
+    }
 
-        let dir = std::env::temp_dir().join("volt_db_test_ids_42");
-        std::fs::create_dir_all(&dir).unwrap();
-        let path = dir.join("test_ids.json");
+    #[test]
+    fn expire_skips_pinned_frames() {
+        let mut store = VoltStore::new();
+        let id = store.store_with_ttl(make_frame_with_content(), 1).unwrap();
+        store.pin_frame(id);
+        assert_eq!(store.expire(u64::MAX).unwrap(), 0);
+        assert!(store.get_by_id(id).is_some());
+    }
+
+    #[test]
+    fn wal_replay_does_not_resurrect_expired_frame() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_expire_wal_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+
+                let (expired, kept) = {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    let expired = store.store_with_ttl(make_frame_with_content(), 1).unwrap();
+                    let kept = store.store(make_frame_with_content()).unwrap();
+                    assert_eq!(store.expire(u64::MAX).unwrap(), 1);
+                    (expired, kept)
+                };
+
+                // Reopen without a clean shutdown: only the WAL remembers
+                let store = VoltStore::open(config).unwrap();
+                assert!(store.get_by_id(expired).is_none());
+                assert!(store.get_by_id(kept).is_some());
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn expire_reaches_frames_decayed_into_t2() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_expire_t2_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let mut store =
+                    VoltStore::new_bounded(T0_CAPACITY * FRAME_RAM_BYTES, &dir).unwrap();
+                let ephemeral = store.store_with_ttl(make_frame_with_content(), 1).unwrap();
+                for _ in 0..(T0_CAPACITY + 4) {
+                    store.store(make_frame_with_content()).unwrap();
+                }
+                assert!(matches!(
+                    store.get_entry_by_id(ephemeral),
+                    Some(FrameEntry::Compressed(_))
+                ));
+
+                assert_eq!(store.expire(u64::MAX).unwrap(), 1);
+                assert!(matches!(
+                    store.get_entry_by_id(ephemeral),
+                    Some(FrameEntry::Tombstone(_))
+                ));
+                assert_eq!(store.expire(u64::MAX).unwrap(), 0);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn ttl_survives_save_and_load() {
+        let mut store = VoltStore::new();
+        let ephemeral = store.store_with_ttl(make_frame_with_content(), 1).unwrap();
+        for _ in 0..T0_CAPACITY {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        assert_eq!(store.t1_len(), 1);
+
+        let dir = std::env::temp_dir().join("volt_db_test_store_ttl");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("t1_{}.bin", std::process::id()));
+        store.save(&path).unwrap();
+
+        let mut loaded = VoltStore::load(&path).unwrap();
+        assert_eq!(
+            loaded.get_by_id(ephemeral).unwrap().frame_meta.ttl_micros,
+            Some(1)
+        );
+        assert_eq!(loaded.expire(u64::MAX).unwrap(), 1);
+        assert!(loaded.get_by_id(ephemeral).is_none());
+        assert_eq!(loaded.hnsw_entries(), 0);
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn compressed_save_roundtrip_preserves_t1() {
+        let mut store = VoltStore::new();
+        store.set_t1_compression(T1Compression::Zstd(3));
+        for _ in 0..(T0_CAPACITY + 5) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+
+        let dir = std::env::temp_dir().join("volt_db_test_store_zstd");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("t1_{}.bin", std::process::id()));
+        store.save(&path).unwrap();
+
+        let loaded = VoltStore::load(&path).unwrap();
+        assert_eq!(loaded.t1_len(), store.t1_len());
+        for id in 1..=(store.t1_len() as u64) {
+            assert_eq!(
+                loaded.get_by_id(id).unwrap().to_binary(),
+                store.get_by_id(id).unwrap().to_binary()
+            );
+        }
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[test]
+    fn compressed_store_opens_uncompressed_snapshot() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_t1_backcompat")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                std::fs::create_dir_all(&dir).unwrap();
+
+                let mut old = VoltStore::new();
+                for _ in 0..(T0_CAPACITY + 5) {
+                    old.store(make_frame_with_content()).unwrap();
+                }
+                old.save(&dir.join("t1_strands.json")).unwrap();
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    t2_config: T2Config {
+                        data_dir: dir.join("t2"),
+                        ..T2Config::default()
+                    },
+                    t1_compression: T1Compression::Zstd(3),
+                    ..VoltStoreConfig::default()
+                };
+                let store = VoltStore::open(config).unwrap();
+                assert_eq!(store.t1_compression(), T1Compression::Zstd(3));
+                assert_eq!(store.t1_len(), old.t1_len());
+                assert_eq!(store.hnsw_entries(), old.t1_len());
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn load_continues_id_generation() {
+        let mut store = VoltStore::new();
+        for _ in 0..(T0_CAPACITY + 5) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+
+        let dir = std::env::temp_dir().join("volt_db_test_ids_42");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test_ids.json");
 
         store.save(&path).unwrap();
         let mut loaded = VoltStore::load(&path).unwrap();
@@ -1670,6 +3874,50 @@ mod tests {
         assert_eq!(results[0].strand_id, 0);
     }
 
+    #[test]
+    fn search_conversations_ranks_strands_by_closest_hit() {
+        fn frame_at(value: [f32; SLOT_DIM]) -> TensorFrame {
+            let mut frame = TensorFrame::new();
+            let mut slot = SlotData::new(SlotRole::Agent);
+            slot.write_resolution(0, value);
+            frame.write_slot(0, slot).unwrap();
+            frame.meta[0].source = SlotSource::Translator;
+            frame
+        }
+        let mut query = [0.0; SLOT_DIM];
+        query[0] = 1.0;
+        let mut near = query;
+        near[1] = 0.1;
+        let mut mid = query;
+        mid[1] = 1.0;
+        let mut far = [0.0; SLOT_DIM];
+        far[2] = 1.0;
+
+        let mut store = VoltStore::new();
+        assert!(store.search_conversations(&query, 5).is_empty());
+
+        // Strand 1: two mid-distance frames. Strand 2: one close frame
+        // and one far one. Strand 3: far only.
+        store.switch_strand(1).unwrap();
+        store.store(frame_at(mid)).unwrap();
+        store.store(frame_at(mid)).unwrap();
+        store.switch_strand(2).unwrap();
+        store.store(frame_at(far)).unwrap();
+        store.store(frame_at(near)).unwrap();
+        store.switch_strand(3).unwrap();
+        store.store(frame_at(far)).unwrap();
+
+        let results = store.search_conversations(&query, 5);
+        let strands: Vec<u64> = results.iter().map(|r| r.0).collect();
+        assert_eq!(strands, vec![2, 1, 3]);
+        assert_eq!(results[0].2, 2);
+        assert_eq!(results[1].2, 2);
+        assert!(results[0].1 < results[1].1);
+
+        assert_eq!(store.search_conversations(&query, 1).len(), 1);
+        assert!(store.search_conversations(&query, 0).is_empty());
+    }
+
     #[test]
     fn ghost_buffer_populates_on_store() {
         let mut store = VoltStore::new();
@@ -1738,6 +3986,38 @@ mod tests {
         assert_eq!(result.frames_tombstoned, 0);
     }
 
+    #[test]
+    fn frequently_accessed_frame_resists_gc() {
+        let mut store = VoltStore::new();
+        let created_at = 1_000_000;
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let mut frame = make_frame_with_content();
+            frame.frame_meta.created_at = created_at;
+            ids.push(store.store(frame).unwrap());
+        }
+        let (hot, cold) = (ids[0], ids[1]);
+        for _ in 0..10 {
+            assert!(store.get_by_id(hot).is_some());
+        }
+        assert_eq!(store.access_count(hot), 10);
+        assert_eq!(store.access_count(cold), 0);
+
+        // Push both frames out of T0 so GC evaluates them
+        for _ in 0..T0_CAPACITY {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        assert!(store.t1().get_by_id(hot).is_some());
+        assert!(store.t1().get_by_id(cold).is_some());
+
+        // Equal age and gamma: 0.40 + 0.15 * ln(1 + refs) only clears the
+        // 0.7 Full threshold with the hot frame's accesses
+        let result = store.run_gc_at(created_at).unwrap();
+        assert_eq!(result.frames_compressed, 1);
+        assert!(store.t1().get_by_id(hot).is_some(), "hot frame was demoted");
+        assert!(store.t1().get_by_id(cold).is_none(), "cold frame stayed Full");
+    }
+
     #[test]
     fn consolidate_empty_strand() {
         let mut store = VoltStore::new();
@@ -1746,6 +4026,88 @@ mod tests {
         assert!(result.wisdom_frames.is_empty());
     }
 
+    #[test]
+    fn memory_only_store_keeps_superseded_by_link() {
+        let mut store = VoltStore::new();
+        store.consolidation = ConsolidationEngine::new(ConsolidationConfig {
+            supersede_sources: true,
+            ..ConsolidationConfig::default()
+        });
+        for _ in 0..(T0_CAPACITY + 12) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+
+        let result = store.consolidate_strand(0).unwrap();
+        assert!(!result.superseded_frame_ids.is_empty());
+        let wisdom_id = result.wisdom_frames[0].frame_meta.frame_id;
+        let source = result.wisdom_frames[0].frame_meta.derived_from[0];
+        assert!(store.get_by_id(source).is_none());
+        match store.get_entry_by_id(source) {
+            Some(FrameEntry::Tombstone(ts)) => assert_eq!(ts.superseded_by, Some(wisdom_id)),
+            other => panic!("source {source} not tombstoned: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn consolidate_strand_from_resumes_without_duplicates() {
+        let mut store = VoltStore::new();
+        for _ in 0..(T0_CAPACITY + 12) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+
+        let first = store.consolidate_strand_from(0, 0, 6).unwrap();
+        assert_eq!(first.frames_examined, 6);
+        assert_eq!(first.frames_remaining, 6);
+        assert_eq!(first.cursor, 6);
+        assert_eq!(first.result.wisdom_frames.len(), 1);
+
+        // Storing a wisdom frame can evict another frame into T1, so drain
+        // the strand in budgeted steps until nothing is pending.
+        let mut steps = vec![first];
+        let drain = |store: &mut VoltStore, steps: &mut Vec<ConsolidationProgress>| loop {
+            let cursor = steps.last().unwrap().cursor;
+            let progress = store.consolidate_strand_from(0, cursor, 6).unwrap();
+            let done = progress.is_complete();
+            steps.push(progress);
+            if done {
+                break;
+            }
+        };
+        drain(&mut store, &mut steps);
+
+        let cursor = steps.last().unwrap().cursor;
+        let idle = store.consolidate_strand_from(0, cursor, 6).unwrap();
+        assert_eq!(idle.frames_examined, 0);
+        assert_eq!(idle.cursor, cursor);
+        assert!(idle.result.wisdom_frames.is_empty());
+
+        // Frames evicted after the last step are picked up; wisdom frames
+        // are never examined.
+        for _ in 0..8 {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        let before = steps.len();
+        drain(&mut store, &mut steps);
+        assert!(steps[before..].iter().map(|p| p.frames_examined).sum::<usize>() >= 8);
+        assert!(steps.windows(2).all(|w| w[1].cursor >= w[0].cursor));
+
+        let wisdom: Vec<&TensorFrame> = steps
+            .iter()
+            .flat_map(|p| p.result.wisdom_frames.iter())
+            .collect();
+        assert!(wisdom.len() >= 3);
+        let mut sources: Vec<u64> = wisdom
+            .iter()
+            .flat_map(|w| w.frame_meta.derived_from.iter().copied())
+            .collect();
+        let total = sources.len();
+        sources.sort_unstable();
+        sources.dedup();
+        assert_eq!(sources.len(), total, "a frame fed two wisdom frames");
+        let wisdom_ids: Vec<u64> = wisdom.iter().map(|w| w.frame_meta.frame_id).collect();
+        assert!(sources.iter().all(|id| !wisdom_ids.contains(id)));
+    }
+
     #[test]
     fn concurrent_store_read_write() {
         let store = VoltStore::new();
@@ -1765,6 +4127,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn concurrent_reserve_ids_unique_and_monotonic() {
+        let concurrent = ConcurrentVoltStore::new(VoltStore::new());
+        let (reserved, stored) = std::thread::scope(|scope| {
+            let reservers: Vec<_> = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..200)
+                            .flat_map(|i| concurrent.reserve_ids(1 + i % 3).unwrap())
+                            .collect::<Vec<u64>>()
+                    })
+                })
+                .collect();
+            let writer = scope.spawn(|| {
+                (0..50)
+                    .map(|_| concurrent.write().unwrap().store(make_frame_with_content()).unwrap())
+                    .collect::<Vec<u64>>()
+            });
+            let reserved: Vec<Vec<u64>> =
+                reservers.into_iter().map(|h| h.join().unwrap()).collect();
+            (reserved, writer.join().unwrap())
+        });
+
+        for ids in reserved.iter().chain(std::iter::once(&stored)) {
+            assert!(ids.windows(2).all(|w| w[0] < w[1]), "IDs must increase per thread");
+        }
+        let mut all: Vec<u64> = reserved.into_iter().flatten().chain(stored).collect();
+        let total = all.len();
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), total, "no ID is handed out twice");
+
+        let next = concurrent.write().unwrap().store(make_frame_with_content()).unwrap();
+        assert_eq!(next, *all.last().unwrap() + 1);
+    }
+
     #[test]
     fn concurrent_multi_reader() {
         let store = VoltStore::new();
@@ -1829,6 +4227,1052 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn save_checkpoints_wal_so_reopen_replays_only_later_entries() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_wal_checkpoint_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+
+                {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    // 70 frames: 1..=6 are evicted to T1, the rest stay in T0
+                    for _ in 0..70 {
+                        store.store(make_frame_with_content()).unwrap();
+                    }
+                    assert!(store.delete(70).unwrap());
+
+                    // Saving elsewhere leaves the WAL alone
+                    store.save(&dir.join("backup.json")).unwrap();
+                    let wal = WalManager::read_dir(&dir.join("wal")).unwrap();
+                    assert_eq!(wal[&0].len(), 71);
+                    let logged_at: HashMap<u64, u64> = wal[&0]
+                        .iter()
+                        .filter(|e| e.op == WalOp::Store)
+                        .map(|e| (e.frame_id, e.timestamp))
+                        .collect();
+
+                    store.save(&dir.join(T1_FILE)).unwrap();
+                    // Carried T0 frames keep their original timestamps
+                    let wal = WalManager::read_dir(&dir.join("wal")).unwrap();
+                    for entry in wal[&0].iter().filter(|e| e.op == WalOp::Store) {
+                        assert_eq!(entry.timestamp, logged_at[&entry.frame_id]);
+                    }
+
+                    for _ in 0..3 {
+                        store.store(make_frame_with_content()).unwrap();
+                    }
+                }
+
+                let wal = WalManager::read_dir(&dir.join("wal")).unwrap();
+                let entries = &wal[&0];
+                assert_eq!(entries[0].op, WalOp::Checkpoint);
+                let stored: Vec<u64> = entries[1..]
+                    .iter()
+                    .filter(|e| e.op == WalOp::Store)
+                    .map(|e| e.frame_id)
+                    .collect();
+                // Only the T0 frames re-logged at the checkpoint and the
+                // frames stored after it; T1 frames are not replayed
+                let expected: Vec<u64> = (7..=69).chain(71..=73).collect();
+                assert_eq!(stored, expected);
+
+                let mut store = VoltStore::open(config).unwrap();
+                assert_eq!(store.total_frame_count(), 72);
+                for id in (1..=69).chain(71..=73) {
+                    assert!(store.get_by_id(id).is_some(), "frame {id} lost");
+                }
+                assert!(store.get_by_id(70).is_none());
+                // The deleted frame's ID stays reserved across the checkpoint
+                assert_eq!(store.store(make_frame_with_content()).unwrap(), 74);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn save_flushes_t2_memtable_before_checkpointing_wal() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_save_flush_t2_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+
+                {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    let now = 1_000_000;
+                    // 1..=6 are evicted to T1; a day later gamma 0.5
+                    // scores them into the Compressed band
+                    for _ in 0..70 {
+                        let mut frame = make_frame_with_content();
+                        frame.frame_meta.created_at = now;
+                        frame.frame_meta.global_certainty = 0.5;
+                        store.store(frame).unwrap();
+                    }
+                    let day = 24 * 60 * 60 * 1_000_000;
+                    let result = store.run_gc_at(now + day).unwrap();
+                    assert_eq!(result.frames_compressed, 6);
+
+                    // Frame 1 reaches a sorted run; its delete is only a
+                    // WAL entry plus a memtable tombstone
+                    store.t2.as_mut().unwrap().flush_memtable().unwrap();
+                    assert!(store.delete(1).unwrap());
+
+                    store.save(&dir.join(T1_FILE)).unwrap();
+                }
+
+                let store = VoltStore::open(config).unwrap();
+                assert!(matches!(
+                    store.get_entry_by_id(1),
+                    Some(FrameEntry::Tombstone(_)) | None
+                ));
+                for id in 2..=6 {
+                    assert!(
+                        matches!(store.get_entry_by_id(id), Some(FrameEntry::Compressed(_))),
+                        "compressed frame {id} lost"
+                    );
+                }
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn min_store_certainty_rejects_low_certainty_frames() {
+        let mut store = VoltStore::new();
+        store.set_min_store_certainty(Some(0.5));
+
+        let mut weak = make_frame_with_content();
+        weak.frame_meta.global_certainty = 0.2;
+        assert_eq!(store.store(weak).unwrap(), 0);
+        assert_eq!(store.total_frame_count(), 0);
+        assert_eq!(store.hnsw_entries(), 0);
+        assert_eq!(store.temporal_entries(), 0);
+
+        let mut strong = make_frame_with_content();
+        strong.frame_meta.global_certainty = 0.8;
+        let id = store.store(strong).unwrap();
+        // Rejected frame did not consume an ID
+        assert_eq!(id, 1);
+        assert_eq!(store.hnsw_entries(), 1);
+    }
+
+    #[test]
+    fn min_store_certainty_nan_floor_rejects_frames() {
+        let mut store = VoltStore::new();
+        store.set_min_store_certainty(Some(f32::NAN));
+
+        let mut frame = make_frame_with_content();
+        frame.frame_meta.global_certainty = 0.9;
+        assert_eq!(store.store(frame.clone()).unwrap(), 0);
+        assert_eq!(store.store_batch(vec![frame]).unwrap(), vec![0]);
+        assert_eq!(store.total_frame_count(), 0);
+    }
+
+    #[test]
+    fn store_rejects_invalid_frame() {
+        let mut store = VoltStore::new();
+        let mut frame = make_frame_with_content();
+        frame.meta[0].certainty = 1.5;
+        assert!(matches!(
+            store.store(frame),
+            Err(VoltError::FrameError { .. })
+        ));
+        assert_eq!(store.total_frame_count(), 0);
+        assert_eq!(store.hnsw_entries(), 0);
+
+        // Rejected frame did not consume an ID
+        assert_eq!(store.store(make_frame_with_content()).unwrap(), 1);
+    }
+
+    #[test]
+    fn min_store_certainty_none_stores_everything() {
+        let mut store = VoltStore::new();
+        assert!(store.min_store_certainty().is_none());
+        let id = store.store(make_frame_with_content()).unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(store.hnsw_entries(), 1);
+    }
+
+    #[test]
+    fn min_store_certainty_skips_wal_for_rejected_frames() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_min_certainty_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    min_store_certainty: Some(0.5),
+                    ..VoltStoreConfig::default()
+                };
+
+                {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    let mut weak = make_frame_with_content();
+                    weak.frame_meta.global_certainty = 0.1;
+                    assert_eq!(store.store(weak).unwrap(), 0);
+                }
+
+                // Nothing should be recovered from the WAL on reopen
+                let store = VoltStore::open(config).unwrap();
+                assert_eq!(store.total_frame_count(), 0);
+                assert_eq!(store.hnsw_entries(), 0);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn delete_removes_frame_from_every_tier() {
+        let mut store = VoltStore::new();
+        let id = store.store(make_frame_with_content()).unwrap();
+        for _ in 0..T0_CAPACITY {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        let in_t0 = store.store(make_frame_with_content()).unwrap();
+        let entries = store.hnsw_entries();
+
+        assert!(store.delete(id).unwrap());
+        assert!(store.delete(in_t0).unwrap());
+        assert!(store.get_by_id(id).is_none());
+        assert!(store.get_by_id(in_t0).is_none());
+        assert_eq!(store.hnsw_entries(), entries - 2);
+        assert_eq!(store.temporal_entries(), entries - 2);
+        assert!(!store.delete(id).unwrap());
+        assert!(!store.delete(9999).unwrap());
+    }
+
+    #[test]
+    fn wal_replay_recovers_wisdom_frame_and_superseded_sources() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_wisdom_wal_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    consolidation_config: ConsolidationConfig {
+                        supersede_sources: true,
+                        ..ConsolidationConfig::default()
+                    },
+                    ..VoltStoreConfig::default()
+                };
+
+                let result = {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    for _ in 0..(T0_CAPACITY + 12) {
+                        store.store(make_frame_with_content()).unwrap();
+                    }
+                    // Exact clustering, so the sources do not depend on HNSW
+                    store.consolidate_strand_from(0, 0, usize::MAX).unwrap().result
+                };
+                assert!(!result.superseded_frame_ids.is_empty());
+
+                // Reopen without a clean shutdown: only the WAL remembers
+                let store = VoltStore::open(config).unwrap();
+                for wisdom in &result.wisdom_frames {
+                    let wisdom_id = wisdom.frame_meta.frame_id;
+                    let recovered = store
+                        .get_by_id(wisdom_id)
+                        .unwrap_or_else(|| panic!("wisdom frame {wisdom_id} lost"));
+                    assert_eq!(recovered.frame_meta.derived_from, wisdom.frame_meta.derived_from);
+                    for &source in &wisdom.frame_meta.derived_from {
+                        assert!(store.get_by_id(source).is_none());
+                        assert_eq!(store.superseded_by(source), Some(wisdom_id));
+                    }
+                }
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn wal_replay_does_not_resurrect_deleted_frame() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_delete_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+
+                let (deleted, kept) = {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    let deleted = store.store(make_frame_with_content()).unwrap();
+                    let kept = store.store(make_frame_with_content()).unwrap();
+                    assert!(store.delete(deleted).unwrap());
+                    assert!(matches!(
+                        store.get_entry_by_id(deleted),
+                        Some(FrameEntry::Tombstone(_))
+                    ));
+                    (deleted, kept)
+                };
+
+                // Reopen without a clean shutdown: only the WAL remembers
+                let mut store = VoltStore::open(config).unwrap();
+                assert!(store.get_by_id(deleted).is_none());
+                assert!(store.get_by_id(kept).is_some());
+                assert_eq!(store.hnsw_entries(), 1);
+                assert!(store.store(make_frame_with_content()).unwrap() > kept);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn best_effort_open_quarantines_corrupt_run() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_best_effort_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                // Two sorted runs, one entry each
+                {
+                    let mut t2 = Tier2Store::open(T2Config {
+                        data_dir: dir.join("t2"),
+                        ..T2Config::default()
+                    })
+                    .unwrap();
+                    for id in [1, 2] {
+                        let mut frame = make_frame_with_content();
+                        frame.frame_meta.frame_id = id;
+                        t2.insert(FrameEntry::Compressed(compress(&frame))).unwrap();
+                        t2.flush_memtable().unwrap();
+                    }
+                }
+                let mut runs: Vec<PathBuf> = std::fs::read_dir(dir.join("t2"))
+                    .unwrap()
+                    .map(|e| e.unwrap().path())
+                    .collect();
+                runs.sort();
+                assert_eq!(runs.len(), 2);
+                std::fs::write(&runs[0], b"not a sorted run").unwrap();
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+                assert!(VoltStore::open(config.clone()).is_err());
+                assert!(runs[0].exists(), "strict open must not touch files");
+
+                let store = VoltStore::open(VoltStoreConfig {
+                    recovery_mode: OpenPolicy::BestEffort,
+                    ..config
+                })
+                .unwrap();
+                let report = store.recovery_report();
+                assert_eq!(report.quarantined.len(), 1);
+                assert_eq!(report.quarantined[0].original, runs[0]);
+                assert!(report.quarantined[0].quarantined.exists());
+                assert!(!runs[0].exists());
+                assert!(store.get_entry_by_id(2).is_some());
+                assert!(store.get_entry_by_id(1).is_none());
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn store_batch_assigns_contiguous_ids_and_indexes_once() {
+        let mut store = VoltStore::new();
+        store.store(make_frame_with_content()).unwrap();
+        let frames: Vec<TensorFrame> = (0..100)
+            .map(|i| {
+                if i % 10 == 0 {
+                    TensorFrame::new() // no R₀ gist
+                } else {
+                    make_frame_with_content()
+                }
+            })
+            .collect();
+
+        let ids = store.store_batch(frames).unwrap();
+        assert_eq!(ids, (2..=101).collect::<Vec<u64>>());
+        for &id in &ids {
+            assert!(store.get_by_id(id).is_some(), "frame {id} missing");
+        }
+        assert_eq!(store.total_frame_count(), 101);
+        assert_eq!(store.hnsw_entries(), 1 + 90);
+        assert_eq!(store.temporal_entries(), 1 + 90);
+        assert!(!store.ghost_buffer().is_empty());
+        assert_eq!(store.store(make_frame_with_content()).unwrap(), 102);
+    }
+
+    #[test]
+    fn store_batch_rejects_whole_batch_on_invalid_frame() {
+        let mut store = VoltStore::new();
+        let mut bad = make_frame_with_content();
+        bad.meta[0].source = SlotSource::Empty;
+        let err = store.store_batch(vec![make_frame_with_content(), bad]);
+        assert!(err.is_err());
+        assert_eq!(store.total_frame_count(), 0);
+        assert_eq!(store.store(make_frame_with_content()).unwrap(), 1);
+    }
+
+    #[test]
+    fn store_batch_is_recovered_from_wal() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_batch_wal_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+
+                let ids = {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    let frames = (0..5).map(|_| make_frame_with_content()).collect();
+                    store.store_batch(frames).unwrap()
+                };
+
+                let store = VoltStore::open(config).unwrap();
+                for id in ids {
+                    assert!(store.get_by_id(id).is_some(), "frame {id} not recovered");
+                }
+                assert_eq!(store.hnsw_entries(), 5);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn snapshot_restore_roundtrip_after_mutation() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let root = std::env::temp_dir()
+                    .join("volt_store_snapshot_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&root);
+                let config = VoltStoreConfig {
+                    data_dir: root.join("live"),
+                    t1_overflow_threshold: 10,
+                    ..VoltStoreConfig::default()
+                };
+
+                let concurrent = ConcurrentVoltStore::new(VoltStore::open(config).unwrap());
+                {
+                    let mut store = concurrent.write().unwrap();
+                    store.switch_strand(3).unwrap();
+                    for i in 0..(T0_CAPACITY + 30) {
+                        let mut frame = make_frame_with_content();
+                        frame.frame_meta.global_certainty = i as f32 / 100.0;
+                        store.store(frame).unwrap();
+                    }
+                }
+                let snapshot = concurrent.snapshot(&root.join("backups")).unwrap();
+                let (counts, samples) = {
+                    let store = concurrent.read().unwrap();
+                    let counts = (store.t0_len(), store.t1_len(), store.t2_len());
+                    let samples: Vec<Vec<u8>> = [25, 70, 90]
+                        .iter()
+                        .map(|&id| store.get_by_id(id).unwrap().to_binary())
+                        .collect();
+                    (counts, samples)
+                };
+                assert!(counts.0 > 0 && counts.1 > 0 && counts.2 > 0, "{counts:?}");
+
+                // Mutate the original after the snapshot
+                {
+                    let mut store = concurrent.write().unwrap();
+                    assert!(store.delete(90).unwrap());
+                    store.store(make_frame_with_content()).unwrap();
+                }
+
+                let manifest = VoltStore::read_snapshot_manifest(&snapshot).unwrap();
+                assert_eq!(manifest.t0_frames, counts.0);
+                let restored_config = VoltStoreConfig {
+                    data_dir: root.join("restored"),
+                    ..VoltStoreConfig::default()
+                };
+                let mut restored = VoltStore::restore(&snapshot, restored_config.clone()).unwrap();
+                assert_eq!(
+                    (restored.t0_len(), restored.t1_len(), restored.t2_len()),
+                    counts
+                );
+                for (id, bytes) in [25, 70, 90].into_iter().zip(&samples) {
+                    assert_eq!(&restored.get_by_id(id).unwrap().to_binary(), bytes);
+                }
+                assert_eq!(restored.active_strand(), 3);
+                assert_eq!(restored.hnsw_entries(), counts.0 + counts.1);
+                assert_eq!(restored.store(make_frame_with_content()).unwrap(), manifest.next_id);
+
+                // A restore never overwrites an existing store
+                assert!(VoltStore::restore(&snapshot, restored_config).is_err());
+
+                let _ = std::fs::remove_dir_all(&root);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn open_applies_configured_hnsw_params() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_hnsw_params_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                let params = HnswParams {
+                    m: 8,
+                    ef_construction: 50,
+                    ef_search: 128,
+                    ..HnswParams::default()
+                };
+                let config = |params| VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    hnsw_params: params,
+                    ..VoltStoreConfig::default()
+                };
+
+                {
+                    let mut store = VoltStore::open(config(params)).unwrap();
+                    assert_eq!(store.hnsw_params(), params);
+                    store.store(make_frame_with_content()).unwrap();
+                    store.reindex().unwrap();
+                    assert_eq!(store.hnsw_params(), params);
+                    store.save_index().unwrap();
+                }
+
+                // A saved index with other params is rebuilt with the configured ones
+                let store = VoltStore::open(config(HnswParams::default())).unwrap();
+                assert_eq!(store.hnsw_params(), HnswParams::default());
+                assert_eq!(store.hnsw_entries(), 1);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn open_reuses_saved_hnsw_index_and_rebuilds_when_stale() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_saved_index_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                let config = || VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+                let frame_at = |i: usize| {
+                    let mut vector = [0.1; SLOT_DIM];
+                    vector[i % SLOT_DIM] = 1.0;
+                    let mut frame = TensorFrame::new();
+                    let mut slot = SlotData::new(SlotRole::Agent);
+                    slot.write_resolution(0, vector);
+                    frame.write_slot(0, slot).unwrap();
+                    frame.meta[0].source = SlotSource::Translator;
+                    frame
+                };
+                let mut query = [0.1; SLOT_DIM];
+                query[3] = 1.0;
+                let neighbours = |store: &VoltStore| -> Vec<(u64, u32)> {
+                    store
+                        .query_similar(&query, 5)
+                        .iter()
+                        .map(|r| (r.frame_id, r.distance.to_bits()))
+                        .collect()
+                };
+
+                let expected = {
+                    let mut store = VoltStore::open(config()).unwrap();
+                    for i in 0..20 {
+                        store.store(frame_at(i)).unwrap();
+                    }
+                    store.save_index().unwrap();
+                    neighbours(&store)
+                };
+                assert!(dir.join(HNSW_INDEX_DIR).exists());
+
+                {
+                    let mut store = VoltStore::open(config()).unwrap();
+                    assert_eq!(store.hnsw_entries(), 20);
+                    assert_eq!(neighbours(&store), expected);
+                    assert!(store.verify_consistency().unwrap().is_consistent());
+                    // Stored after the index was saved, so the saved index is stale
+                    store.store(frame_at(20)).unwrap();
+                }
+
+                let store = VoltStore::open(config()).unwrap();
+                assert_eq!(store.hnsw_entries(), 21);
+                assert!(store.verify_consistency().unwrap().is_consistent());
+                drop(store);
+
+                std::fs::write(dir.join(HNSW_INDEX_DIR).join("index.bin"), b"garbage").unwrap();
+                let store = VoltStore::open(config()).unwrap();
+                assert_eq!(store.hnsw_entries(), 21);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn recover_to_replays_wal_up_to_timestamp() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_recover_to_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let log_frame = |wal: &mut WalManager, id: u64, op: WalOp, timestamp: u64| {
+                    let mut frame = make_frame_with_content();
+                    frame.frame_meta.frame_id = id;
+                    let mut payload = vec![DecayLevel::Full.tag()];
+                    payload.extend_from_slice(&frame.to_binary());
+                    if op == WalOp::Delete {
+                        payload.clear();
+                    }
+                    wal.log_entry(WalEntry {
+                        frame_id: id,
+                        strand_id: 0,
+                        op,
+                        payload,
+                        timestamp,
+                    })
+                    .unwrap();
+                };
+                {
+                    let mut wal = WalManager::open(&dir.join("wal")).unwrap();
+                    log_frame(&mut wal, 1, WalOp::Store, 1_000);
+                    log_frame(&mut wal, 2, WalOp::Store, 2_000);
+                    log_frame(&mut wal, 1, WalOp::Delete, 3_000);
+                    log_frame(&mut wal, 3, WalOp::Store, 4_000);
+                }
+                let wal_bytes = std::fs::read(dir.join("wal").join("strand_0.wal")).unwrap();
+
+                let before = VoltStore::recover_to(&dir, 999).unwrap();
+                assert_eq!(before.total_frame_count(), 0);
+
+                let midpoint = VoltStore::recover_to(&dir, 2_500).unwrap();
+                assert!(midpoint.get_by_id(1).is_some());
+                assert!(midpoint.get_by_id(2).is_some());
+                assert!(midpoint.get_by_id(3).is_none());
+                assert_eq!(midpoint.hnsw_entries(), 2);
+
+                let latest = VoltStore::recover_to(&dir, u64::MAX).unwrap();
+                assert!(latest.get_by_id(1).is_none());
+                assert_eq!(latest.total_frame_count(), 2);
+
+                // The live WAL is untouched
+                let after = std::fs::read(dir.join("wal").join("strand_0.wal")).unwrap();
+                assert_eq!(after, wal_bytes);
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn merge_strands_moves_all_frames_in_order() {
+        let mut store = VoltStore::new();
+        // Interleave two strands so both have frames in T1 and T0
+        for _ in 0..(T0_CAPACITY / 2 + 10) {
+            store.switch_strand(1).unwrap();
+            store.store(make_frame_with_content()).unwrap();
+            store.switch_strand(2).unwrap();
+            store.store(make_frame_with_content()).unwrap();
+        }
+        let mut expected: Vec<u64> = store
+            .get_by_strand(1)
+            .iter()
+            .chain(store.get_by_strand(2).iter())
+            .map(|f| f.frame_meta.frame_id)
+            .collect();
+        expected.sort_unstable();
+        let source_t1 = store.t1().strand_frame_count(1);
+        assert!(source_t1 > 0 && !store.t0().get_by_strand(1).is_empty());
+
+        store.switch_strand(1).unwrap();
+        let moved = store.merge_strands(1, 2).unwrap();
+
+        assert_eq!(moved, T0_CAPACITY / 2 + 10);
+        assert!(!store.list_strands().contains(&1));
+        assert!(store.get_by_strand(1).is_empty());
+        assert_eq!(store.active_strand(), 2);
+
+        let merged: Vec<u64> = store
+            .get_by_strand(2)
+            .iter()
+            .map(|f| f.frame_meta.frame_id)
+            .collect();
+        // T1 then T0, each chronological, so the whole history is in order
+        assert_eq!(merged, expected);
+        assert!(merged.iter().all(|&id| store.get_by_id(id).unwrap().frame_meta.strand_id == 2));
+
+        // HNSW entries followed the frames to the target partition
+        let hits = store.query_similar_in_strand(2, &[0.5; SLOT_DIM], 10);
+        assert!(!hits.is_empty());
+        assert!(hits.iter().all(|h| h.strand_id == 2));
+        assert!(store.query_similar_in_strand(1, &[0.5; SLOT_DIM], 5).is_empty());
+    }
+
+    #[test]
+    fn merge_strands_rejects_invalid_pairs() {
+        let mut store = VoltStore::new();
+        store.switch_strand(1).unwrap();
+        store.store(make_frame_with_content()).unwrap();
+
+        assert!(store.merge_strands(1, 1).is_err());
+        assert!(store.merge_strands(0, 1).is_err());
+        assert!(store.merge_strands(9, 1).is_err());
+        assert!(store.list_strands().contains(&0));
+
+        // The default strand is a valid target
+        assert_eq!(store.merge_strands(1, 0).unwrap(), 1);
+        assert_eq!(store.get_by_strand(0).len(), 1);
+    }
+
+    #[test]
+    fn merge_strands_rewrites_t2_entries() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_merge_t2_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let mut store =
+                    VoltStore::new_bounded(T0_CAPACITY * FRAME_RAM_BYTES, &dir).unwrap();
+                store.switch_strand(1).unwrap();
+                for _ in 0..(T0_CAPACITY + 5) {
+                    store.store(make_frame_with_content()).unwrap();
+                }
+                assert_eq!(store.t2_len(), 5);
+
+                assert_eq!(store.merge_strands(1, 3).unwrap(), T0_CAPACITY + 5);
+                for id in 1..=5 {
+                    assert_eq!(store.get_entry_by_id(id).unwrap().strand_id(), 3);
+                }
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn merge_strands_survives_reopen() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_merge_reopen_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let config = VoltStoreConfig {
+                    data_dir: dir.clone(),
+                    ..VoltStoreConfig::default()
+                };
+
+                let merged = {
+                    let mut store = VoltStore::open(config.clone()).unwrap();
+                    store.switch_strand(1).unwrap();
+                    for _ in 0..(T0_CAPACITY + 5) {
+                        store.store(make_frame_with_content()).unwrap();
+                    }
+                    // T1 reaches disk under strand 1; T0 stays in the WAL
+                    store.save(&dir.join(T1_FILE)).unwrap();
+                    store.switch_strand(2).unwrap();
+                    for _ in 0..2 {
+                        store.store(make_frame_with_content()).unwrap();
+                    }
+                    assert_eq!(store.merge_strands(1, 2).unwrap(), T0_CAPACITY + 5);
+                    let mut ids: Vec<u64> = store
+                        .get_by_strand(2)
+                        .iter()
+                        .map(|f| f.frame_meta.frame_id)
+                        .collect();
+                    ids.sort_unstable();
+                    ids
+                };
+
+                // Reopen without a clean shutdown: only the WAL remembers
+                let store = VoltStore::open(config).unwrap();
+                assert!(!store.list_strands().contains(&1));
+                assert!(store.get_by_strand(1).is_empty());
+                let frames = store.get_by_strand(2);
+                let mut ids: Vec<u64> = frames.iter().map(|f| f.frame_meta.frame_id).collect();
+                ids.sort_unstable();
+                assert_eq!(ids, merged);
+                assert!(frames.iter().all(|f| f.frame_meta.strand_id == 2));
+                assert!(frames.windows(2).all(|w| {
+                    (w[0].frame_meta.created_at, w[0].frame_meta.frame_id)
+                        <= (w[1].frame_meta.created_at, w[1].frame_meta.frame_id)
+                }));
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn bounded_store_spills_past_ram_budget() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let dir = std::env::temp_dir()
+                    .join("volt_store_bounded_test")
+                    .join(format!("{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+
+                let budget = (T0_CAPACITY + 10) * FRAME_RAM_BYTES;
+                let mut store = VoltStore::new_bounded(budget, &dir).unwrap();
+                assert_eq!(store.max_ram_bytes(), Some(budget));
+                assert!(!store.is_disk_backed());
+
+                let total = T0_CAPACITY + 40;
+                let ids: Vec<u64> = (0..total)
+                    .map(|_| store.store(make_frame_with_content()).unwrap())
+                    .collect();
+
+                assert!(store.ram_usage_bytes() <= budget);
+                assert_eq!(store.t1_len(), 10);
+                assert_eq!(store.t2_len(), 30);
+                let metrics = store.store_metrics();
+                assert_eq!(metrics.t0_evictions, 40);
+                assert_eq!(metrics.t1_to_t2_overflows, 30);
+                for id in ids {
+                    assert!(
+                        store.get_entry_by_id(id).is_some(),
+                        "frame {id} should be retrievable after spill"
+                    );
+                }
+                // The oldest frame was spilled in compressed form
+                assert_eq!(
+                    store.get_entry_by_id(1).unwrap().decay_level(),
+                    DecayLevel::Compressed
+                );
+                // No WAL is written in bounded mode
+                assert!(!dir.join("wal").exists());
+
+                let _ = std::fs::remove_dir_all(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn unbounded_store_has_no_ram_budget() {
+        let mut store = VoltStore::new();
+        assert!(store.max_ram_bytes().is_none());
+        for _ in 0..(T0_CAPACITY + 5) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        assert_eq!(store.ram_usage_bytes(), (T0_CAPACITY + 5) * FRAME_RAM_BYTES);
+        assert_eq!(store.t2_len(), 0);
+    }
+
+    #[test]
+    fn reindex_recovers_corrupted_indices() {
+        let mut store = VoltStore::new();
+        store.switch_strand(3).unwrap();
+        for _ in 0..(T0_CAPACITY + 5) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        let expected = store.query_similar(&[0.5; SLOT_DIM], 5).len();
+        let total = store.total_frame_count();
+
+        store.clear_indices_for_test();
+        assert_eq!(store.hnsw_entries(), 0);
+        assert!(store.query_similar(&[0.5; SLOT_DIM], 5).is_empty());
+        assert!(store.query_time_range(0, u64::MAX).is_empty());
+
+        store.reindex().unwrap();
+        assert_eq!(store.hnsw_entries(), total);
+        assert_eq!(store.temporal_entries(), total);
+        assert_eq!(store.query_similar(&[0.5; SLOT_DIM], 5).len(), expected);
+        assert_eq!(store.query_similar_in_strand(3, &[0.5; SLOT_DIM], 5).len(), expected);
+    }
+
+    #[test]
+    fn reindex_matches_fresh_load() {
+        let mut store = VoltStore::new();
+        for _ in 0..(T0_CAPACITY + 5) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+
+        let dir = std::env::temp_dir().join(format!("volt_db_test_reindex_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("t1.json");
+        store.save(&path).unwrap();
+        let loaded = VoltStore::load(&path).unwrap();
+
+        // Reindexed T1 entries should line up with the freshly loaded store
+        store.clear_indices_for_test();
+        store.reindex().unwrap();
+        let t1_ids: Vec<u64> = loaded.query_time_range(0, u64::MAX);
+        let reindexed_ids = store.query_time_range(0, u64::MAX);
+        for id in &t1_ids {
+            assert!(reindexed_ids.contains(id), "frame {id} missing after reindex");
+        }
+        assert_eq!(reindexed_ids.len(), t1_ids.len() + store.t0_len());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn verify_consistency_detects_unindexed_frames() {
+        let mut store = VoltStore::new();
+        for _ in 0..(T0_CAPACITY + 5) {
+            store.store(make_frame_with_content()).unwrap();
+        }
+        assert!(store.verify_consistency().unwrap().is_consistent());
+
+        store.clear_indices_for_test();
+        let report = store.verify_consistency().unwrap();
+        assert_eq!(report.unindexed_frames.len(), store.total_frame_count());
+        assert!(report.orphaned_entries.is_empty());
+        assert!(!report.is_consistent());
+
+        store.reindex().unwrap();
+        assert!(store.verify_consistency().unwrap().is_consistent());
+    }
+
+    #[test]
+    fn verify_consistency_detects_orphaned_entries() {
+        let mut store = VoltStore::new();
+        let id = store.store(make_frame_with_content()).unwrap();
+        let orphan = FrameGist {
+            vector: [0.5; SLOT_DIM],
+            frame_id: id + 100,
+            strand_id: 0,
+            created_at: 0,
+        };
+        store.insert_orphan_gist_for_test(&orphan).unwrap();
+
+        let report = store.verify_consistency().unwrap();
+        assert_eq!(report.orphaned_entries, vec![id + 100]);
+        assert!(report.unindexed_frames.is_empty());
+
+        store.reindex().unwrap();
+        assert!(store.verify_consistency().unwrap().is_consistent());
+    }
+
+    fn make_turn(discourse_type: DiscourseType) -> TensorFrame {
+        let mut frame = make_frame_with_content();
+        frame.frame_meta.discourse_type = discourse_type;
+        frame
+    }
+
+    #[test]
+    fn export_strand_as_pairs_follows_turn_order() {
+        let mut store = VoltStore::new();
+        store.switch_strand(7).unwrap();
+
+        // Enough alternating turns to push the early ones into T1.
+        let mut expected = Vec::new();
+        for _ in 0..T0_CAPACITY {
+            let input = store.store(make_turn(DiscourseType::Query)).unwrap();
+            let response = store.store(make_turn(DiscourseType::Response)).unwrap();
+            expected.push((input, response));
+        }
+        // An unanswered turn superseded by a new one, a stray response,
+        // then a trailing unanswered turn.
+        store.store(make_turn(DiscourseType::Statement)).unwrap();
+        let input = store.store(make_turn(DiscourseType::Command)).unwrap();
+        let response = store.store(make_turn(DiscourseType::Response)).unwrap();
+        expected.push((input, response));
+        store.store(make_turn(DiscourseType::Response)).unwrap();
+        store.store(make_turn(DiscourseType::Query)).unwrap();
+
+        // Other strands don't leak in.
+        store.switch_strand(8).unwrap();
+        store.store(make_turn(DiscourseType::Query)).unwrap();
+        store.store(make_turn(DiscourseType::Response)).unwrap();
+
+        assert!(store.t1_len() > 0, "early turns should be in T1");
+        let pairs = store.export_strand_as_pairs(7);
+        let ids: Vec<(u64, u64)> = pairs
+            .iter()
+            .map(|(i, r)| (i.frame_meta.frame_id, r.frame_meta.frame_id))
+            .collect();
+        assert_eq!(ids, expected);
+        assert!(pairs.iter().all(|(i, r)| {
+            i.frame_meta.discourse_type != DiscourseType::Response
+                && r.frame_meta.discourse_type == DiscourseType::Response
+        }));
+    }
+
+    #[test]
+    fn export_strand_as_pairs_unknown_strand_is_empty() {
+        let store = VoltStore::new();
+        assert!(store.export_strand_as_pairs(99).is_empty());
+    }
+
     #[test]
     fn total_entry_count_includes_t0_t1() {
         let mut store = VoltStore::new();
@@ -4,6 +4,13 @@
 //! efficient range queries like "all frames from last week".
 
 use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use volt_core::VoltError;
+
+/// Maximum number of buckets a single [`TemporalIndex::histogram`] call
+/// may produce.
+pub const MAX_HISTOGRAM_BUCKETS: usize = 100_000;
 
 /// Temporal index mapping `created_at` timestamps to frame IDs.
 ///
@@ -95,6 +102,152 @@ impl TemporalIndex {
             .collect()
     }
 
+    /// Returns all frame IDs in the time range `[start, end]` inclusive,
+    /// newest first.
+    ///
+    /// Exactly the reverse of [`TemporalIndex::query_range`]: descending
+    /// timestamp, then reverse insertion order within a timestamp.
+    /// Returns an empty vector if `start > end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::temporal::TemporalIndex;
+    ///
+    /// let mut idx = TemporalIndex::new();
+    /// idx.insert(1000, 1);
+    /// idx.insert(2000, 2);
+    /// idx.insert(3000, 3);
+    ///
+    /// assert_eq!(idx.query_range_rev(1000, 2500), vec![2, 1]);
+    /// ```
+    pub fn query_range_rev(&self, start: u64, end: u64) -> Vec<u64> {
+        if start > end {
+            return Vec::new();
+        }
+        self.index
+            .range(start..=end)
+            .rev()
+            .flat_map(|(_, ids)| ids.iter().rev().copied())
+            .collect()
+    }
+
+    /// Returns the `k` frame IDs created closest to `timestamp`, closest
+    /// first.
+    ///
+    /// Walks outward from `timestamp` in both directions, so cost is
+    /// proportional to `k` plus the tree depth. On equal distance the
+    /// earlier frame comes first; frames sharing a timestamp keep their
+    /// insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::temporal::TemporalIndex;
+    ///
+    /// let mut idx = TemporalIndex::new();
+    /// idx.insert(1000, 1);
+    /// idx.insert(2000, 2);
+    /// idx.insert(3000, 3);
+    /// idx.insert(9000, 4);
+    ///
+    /// assert_eq!(idx.nearest(2900, 2), vec![3, 2]);
+    /// assert_eq!(idx.nearest(8000, 1), vec![4]);
+    /// ```
+    pub fn nearest(&self, timestamp: u64, k: usize) -> Vec<u64> {
+        let mut before = self.index.range(..=timestamp).rev().peekable();
+        let mut after = self
+            .index
+            .range((Bound::Excluded(timestamp), Bound::Unbounded))
+            .peekable();
+
+        let mut result = Vec::with_capacity(k.min(self.count));
+        while result.len() < k {
+            let take_before = match (before.peek(), after.peek()) {
+                (Some((b, _)), Some((a, _))) => timestamp - **b <= **a - timestamp,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let (_, ids) = if take_before {
+                before.next()
+            } else {
+                after.next()
+            }
+            .expect("peeked entry exists");
+            result.extend(ids.iter().copied().take(k - result.len()));
+        }
+        result
+    }
+
+    /// Returns per-bucket frame counts over the range `[start, end]` inclusive.
+    ///
+    /// Buckets are `bucket_micros` wide and aligned to `start`: each entry
+    /// is `(bucket_start, count)` covering `[bucket_start, bucket_start +
+    /// bucket_micros)`, clipped to `end`. Empty buckets are included with a
+    /// count of 0, so the result is a continuous timeline. Returns an empty
+    /// vector if `start > end`.
+    ///
+    /// Walks only the B-tree range, so cost is proportional to the
+    /// matching entries plus the number of buckets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if `bucket_micros` is 0 or the
+    /// range would need more than [`MAX_HISTOGRAM_BUCKETS`] buckets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::temporal::TemporalIndex;
+    ///
+    /// let mut idx = TemporalIndex::new();
+    /// idx.insert(1000, 1);
+    /// idx.insert(1500, 2);
+    /// idx.insert(3200, 3);
+    ///
+    /// let hist = idx.histogram(1000, 3999, 1000).unwrap();
+    /// assert_eq!(hist, vec![(1000, 2), (2000, 0), (3000, 1)]);
+    /// assert!(idx.histogram(0, 10, 0).is_err());
+    /// ```
+    pub fn histogram(
+        &self,
+        start: u64,
+        end: u64,
+        bucket_micros: u64,
+    ) -> Result<Vec<(u64, u32)>, VoltError> {
+        if bucket_micros == 0 {
+            return Err(VoltError::StorageError {
+                message: "histogram bucket size must be non-zero".to_string(),
+            });
+        }
+        if start > end {
+            return Ok(Vec::new());
+        }
+
+        let last_bucket = (end - start) / bucket_micros;
+        if last_bucket >= MAX_HISTOGRAM_BUCKETS as u64 {
+            return Err(VoltError::StorageError {
+                message: format!(
+                    "histogram would need more than {MAX_HISTOGRAM_BUCKETS} buckets"
+                ),
+            });
+        }
+        let bucket_count = last_bucket + 1;
+
+        let mut counts = vec![0u32; bucket_count as usize];
+        for (&ts, ids) in self.index.range(start..=end) {
+            let bucket = ((ts - start) / bucket_micros) as usize;
+            counts[bucket] = counts[bucket].saturating_add(ids.len() as u32);
+        }
+
+        Ok(counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (start + i as u64 * bucket_micros, count))
+            .collect())
+    }
+
     /// Returns the N most recent frame IDs, newest first.
     ///
     /// # Example
@@ -253,4 +406,77 @@ mod tests {
         let all = idx.query_range(0, u64::MAX);
         assert_eq!(all.len(), 100);
     }
+
+    #[test]
+    fn query_range_rev_reverses_query_range() {
+        let mut idx = TemporalIndex::new();
+        for (ts, id) in [(3000, 1), (1000, 2), (2000, 3), (2000, 4), (5000, 5)] {
+            idx.insert(ts, id);
+        }
+        for (start, end) in [(0, u64::MAX), (1000, 2000), (2000, 2000), (4000, 4500)] {
+            let mut forward = idx.query_range(start, end);
+            forward.reverse();
+            assert_eq!(idx.query_range_rev(start, end), forward);
+        }
+        assert!(idx.query_range_rev(10, 5).is_empty());
+    }
+
+    #[test]
+    fn nearest_returns_temporally_closest() {
+        let mut idx = TemporalIndex::new();
+        idx.insert(1000, 1);
+        idx.insert(2000, 2);
+        idx.insert(2000, 3);
+        idx.insert(2600, 4);
+        idx.insert(10_000, 5);
+
+        assert_eq!(idx.nearest(2400, 3), vec![4, 2, 3]);
+        // 1500 is equidistant from 1000 and 2000: the earlier wins
+        assert_eq!(idx.nearest(1500, 2), vec![1, 2]);
+        assert_eq!(idx.nearest(0, 1), vec![1]);
+        assert_eq!(idx.nearest(u64::MAX, 1), vec![5]);
+        assert_eq!(idx.nearest(2000, 10).len(), 5);
+        assert!(idx.nearest(2000, 0).is_empty());
+        assert!(TemporalIndex::new().nearest(2000, 3).is_empty());
+    }
+
+    #[test]
+    fn histogram_counts_per_bucket() {
+        let mut idx = TemporalIndex::new();
+        // Bucket [0, 1000): 3 frames, two sharing a timestamp
+        idx.insert(0, 1);
+        idx.insert(999, 2);
+        idx.insert(999, 3);
+        // Bucket [1000, 2000): boundary timestamp belongs here
+        idx.insert(1000, 4);
+        // Bucket [2000, 3000): empty
+        // Bucket [3000, 3500]: clipped final bucket
+        idx.insert(3500, 5);
+        // Outside the range
+        idx.insert(3501, 6);
+
+        let hist = idx.histogram(0, 3500, 1000).unwrap();
+        assert_eq!(hist, vec![(0, 3), (1000, 1), (2000, 0), (3000, 1)]);
+        let total: u32 = hist.iter().map(|&(_, c)| c).sum();
+        assert_eq!(total as usize, idx.query_range(0, 3500).len());
+    }
+
+    #[test]
+    fn histogram_aligns_to_start() {
+        let mut idx = TemporalIndex::new();
+        idx.insert(250, 1);
+        idx.insert(750, 2);
+
+        let hist = idx.histogram(500, 1499, 500).unwrap();
+        assert_eq!(hist, vec![(500, 1), (1000, 0)]);
+    }
+
+    #[test]
+    fn histogram_rejects_bad_arguments() {
+        let idx = TemporalIndex::new();
+        assert!(idx.histogram(0, 1000, 0).is_err());
+        assert!(idx.histogram(0, u64::MAX, 1).is_err());
+        assert!(idx.histogram(10, 5, 1).unwrap().is_empty());
+        assert_eq!(idx.histogram(0, 0, 1).unwrap(), vec![(0, 0)]);
+    }
 }
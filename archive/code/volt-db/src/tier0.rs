@@ -169,6 +169,62 @@ impl WorkingMemory {
             .collect()
     }
 
+    /// Moves every frame of strand `from` to strand `to` in place.
+    ///
+    /// Buffer order (and so eviction order) is unchanged. Returns the
+    /// IDs of the frames that were moved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::tier0::WorkingMemory;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut wm = WorkingMemory::new();
+    /// let mut f = TensorFrame::new();
+    /// f.frame_meta.frame_id = 7;
+    /// f.frame_meta.strand_id = 1;
+    /// wm.store(f);
+    ///
+    /// assert_eq!(wm.reassign_strand(1, 2), vec![7]);
+    /// assert_eq!(wm.get_by_strand(2).len(), 1);
+    /// ```
+    pub fn reassign_strand(&mut self, from: u64, to: u64) -> Vec<u64> {
+        self.buffer
+            .iter_mut()
+            .filter(|f| f.frame_meta.strand_id == from)
+            .map(|f| {
+                f.frame_meta.strand_id = to;
+                f.frame_meta.frame_id
+            })
+            .collect()
+    }
+
+    /// Removes the frame with the given `frame_id`, returning it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::tier0::WorkingMemory;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let mut wm = WorkingMemory::new();
+    /// let mut f = TensorFrame::new();
+    /// f.frame_meta.frame_id = 7;
+    /// wm.store(f);
+    ///
+    /// assert!(wm.remove(7).is_some());
+    /// assert!(wm.remove(7).is_none());
+    /// assert!(wm.is_empty());
+    /// ```
+    pub fn remove(&mut self, frame_id: u64) -> Option<TensorFrame> {
+        let pos = self
+            .buffer
+            .iter()
+            .position(|f| f.frame_meta.frame_id == frame_id)?;
+        self.buffer.remove(pos)
+    }
+
     /// Returns the number of frames currently stored.
     pub fn len(&self) -> usize {
         self.buffer.len()
@@ -6,13 +6,50 @@
 //!
 //! Frames are stored as `Box<TensorFrame>` to avoid stack overflow
 //! during serialization — each TensorFrame is ~64KB.
+//!
+//! On disk, [`StrandStore::save`] writes a versioned binary file whose
+//! frames use [`TensorFrame::to_binary`]. [`StrandStore::save_with`] can
+//! additionally zstd-compress the file. [`StrandStore::load`] detects
+//! compression from the zstd magic header and also accepts the older
+//! JSON files. Writing or reading compressed files requires the `zstd`
+//! feature.
 
 use std::collections::HashMap;
+use std::io::{BufRead, Read, Write};
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 use volt_core::{TensorFrame, VoltError};
 
+/// Leading bytes of a binary T1 file written by [`StrandStore::save`].
+pub const T1_FILE_MAGIC: [u8; 4] = *b"VT1S";
+
+/// Current T1 file format version, written after [`T1_FILE_MAGIC`].
+pub const T1_FILE_VERSION: u8 = 1;
+
+/// Leading bytes of every zstd frame, used to detect compressed T1 files.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Compression applied to a T1 file by [`StrandStore::save_with`].
+///
+/// Compression is lossless; [`StrandStore::load`] reads every variant.
+///
+/// # Example
+///
+/// ```
+/// use volt_db::tier1::T1Compression;
+///
+/// assert_eq!(T1Compression::default(), T1Compression::None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum T1Compression {
+    /// Plain binary format.
+    #[default]
+    None,
+    /// zstd-compressed binary format at the given level (1–22).
+    Zstd(i32),
+}
+
 /// T1 Strand Store — frames organized by strand ID in RAM.
 ///
 /// Each strand is a `Vec<Box<TensorFrame>>` ordered by insertion time.
@@ -230,6 +267,40 @@ impl StrandStore {
         self.strands.get(&strand_id).map(|v| v.len()).unwrap_or(0)
     }
 
+    /// Removes an empty strand entry.
+    ///
+    /// Returns `false` (and leaves the strand in place) if the strand
+    /// does not exist or still holds frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::tier1::StrandStore;
+    ///
+    /// let mut store = StrandStore::new();
+    /// store.create_strand(1);
+    /// assert!(store.remove_empty_strand(1));
+    /// assert!(!store.has_strand(1));
+    /// ```
+    pub fn remove_empty_strand(&mut self, strand_id: u64) -> bool {
+        if self.strands.get(&strand_id).is_some_and(|frames| frames.is_empty()) {
+            self.strands.remove(&strand_id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Re-sorts a strand's frames by `(created_at, frame_id)`.
+    ///
+    /// Restores chronological order after frames from another strand
+    /// have been appended to it.
+    pub fn sort_strand_chronologically(&mut self, strand_id: u64) {
+        if let Some(frames) = self.strands.get_mut(&strand_id) {
+            frames.sort_by_key(|f| (f.frame_meta.created_at, f.frame_meta.frame_id));
+        }
+    }
+
     /// Removes and returns a frame by its `frame_id`.
     ///
     /// Used by the GC pipeline to demote full frames from T1 to compressed
@@ -301,7 +372,15 @@ impl StrandStore {
         all.into_iter().take(n).map(|(_, id)| id).collect()
     }
 
-    /// Serializes the strand store to a JSON file on disk.
+    /// Serializes the strand store to a binary file on disk.
+    ///
+    /// Format:
+    /// ```text
+    /// magic:"VT1S" | version:u8 | strand_count:u32
+    /// For each strand:
+    ///   strand_id:u64 | frame_count:u32
+    ///   For each frame: len:u32 | TensorFrame::to_binary bytes
+    /// ```
     ///
     /// # Errors
     ///
@@ -317,6 +396,27 @@ impl StrandStore {
     /// store.save(Path::new("voltdb_t1.json")).unwrap();
     /// ```
     pub fn save(&self, path: &Path) -> Result<(), VoltError> {
+        self.save_with(path, T1Compression::None)
+    }
+
+    /// Serializes the strand store like [`save`](Self::save), optionally
+    /// wrapping the whole file in a zstd stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if serialization, compression,
+    /// or file I/O fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use volt_db::tier1::{StrandStore, T1Compression};
+    /// use std::path::Path;
+    ///
+    /// let store = StrandStore::new();
+    /// store.save_with(Path::new("voltdb_t1.json"), T1Compression::Zstd(3)).unwrap();
+    /// ```
+    pub fn save_with(&self, path: &Path, compression: T1Compression) -> Result<(), VoltError> {
         // Run on a dedicated thread with 8MB stack — TensorFrame is ~64KB
         // and serde's recursive descent can overflow Windows' 1MB default stack.
         let data = self.clone();
@@ -329,10 +429,26 @@ impl StrandStore {
                     std::fs::File::create(&path).map_err(|e| VoltError::StorageError {
                         message: format!("failed to create T1 file {}: {e}", path.display()),
                     })?;
-                let writer = std::io::BufWriter::new(file);
-                serde_json::to_writer(writer, &data).map_err(|e| VoltError::StorageError {
-                    message: format!("failed to serialize T1 strand store: {e}"),
-                })
+                let mut writer = std::io::BufWriter::new(file);
+                let written = match compression {
+                    T1Compression::None => data.write_binary(&mut writer),
+                    #[cfg(feature = "zstd")]
+                    T1Compression::Zstd(level) => {
+                        zstd::Encoder::new(&mut writer, level).and_then(|mut encoder| {
+                            data.write_binary(&mut encoder)?;
+                            encoder.finish().map(|_| ())
+                        })
+                    }
+                    #[cfg(not(feature = "zstd"))]
+                    T1Compression::Zstd(_) => Err(std::io::Error::other(
+                        "compressed T1 files require the `zstd` feature",
+                    )),
+                };
+                written
+                    .and_then(|()| writer.flush())
+                    .map_err(|e| VoltError::StorageError {
+                        message: format!("failed to write T1 file {}: {e}", path.display()),
+                    })
             })
             .map_err(|e| VoltError::StorageError {
                 message: format!("failed to spawn save thread: {e}"),
@@ -343,9 +459,12 @@ impl StrandStore {
             })?
     }
 
-    /// Loads a strand store from a JSON file on disk.
+    /// Loads a strand store from a file written by [`save`](Self::save).
     ///
-    /// Deserialization runs on a dedicated thread with 8MB stack —
+    /// zstd-compressed files (see [`save_with`](Self::save_with)) are
+    /// detected by their magic header and decompressed transparently.
+    /// Files that do not start with [`T1_FILE_MAGIC`] are read as the
+    /// JSON format used before the binary one. Deserialization runs on a dedicated thread with 8MB stack —
     /// TensorFrame is ~64KB and serde's recursive descent can overflow
     /// Windows' default 1MB stack.
     ///
@@ -371,10 +490,23 @@ impl StrandStore {
                     std::fs::File::open(&path).map_err(|e| VoltError::StorageError {
                         message: format!("failed to open T1 file {}: {e}", path.display()),
                     })?;
-                let reader = std::io::BufReader::new(file);
-                serde_json::from_reader(reader).map_err(|e| VoltError::StorageError {
-                    message: format!("failed to deserialize T1 strand store: {e}"),
-                })
+                let mut reader = std::io::BufReader::new(file);
+                let read_err = |e: std::io::Error| VoltError::StorageError {
+                    message: format!("failed to read T1 file {}: {e}", path.display()),
+                };
+                let is_zstd = reader.fill_buf().map_err(read_err)?.starts_with(&ZSTD_MAGIC);
+                if !is_zstd {
+                    return Self::read_uncompressed(reader);
+                }
+                #[cfg(feature = "zstd")]
+                {
+                    let decoder = zstd::Decoder::with_buffer(reader).map_err(read_err)?;
+                    Self::read_uncompressed(std::io::BufReader::new(decoder))
+                }
+                #[cfg(not(feature = "zstd"))]
+                Err(read_err(std::io::Error::other(
+                    "compressed T1 files require the `zstd` feature",
+                )))
             })
             .map_err(|e| VoltError::StorageError {
                 message: format!("failed to spawn load thread: {e}"),
@@ -384,6 +516,78 @@ impl StrandStore {
                 message: "load thread panicked".to_string(),
             })?
     }
+
+    /// Reads an uncompressed T1 file: binary if it starts with
+    /// [`T1_FILE_MAGIC`], legacy JSON otherwise. Frames written under an
+    /// older schema are migrated.
+    fn read_uncompressed(mut reader: impl BufRead) -> Result<Self, VoltError> {
+        let is_binary = reader
+            .fill_buf()
+            .map_err(|e| VoltError::StorageError {
+                message: format!("failed to read T1 data: {e}"),
+            })?
+            .starts_with(&T1_FILE_MAGIC);
+        let mut store: Self = if is_binary {
+            Self::read_binary(&mut reader)?
+        } else {
+            serde_json::from_reader(reader).map_err(|e| VoltError::StorageError {
+                message: format!("failed to deserialize T1 strand store: {e}"),
+            })?
+        };
+        for frame in store.strands.values_mut().flatten() {
+            frame.migrate();
+        }
+        Ok(store)
+    }
+
+    /// Writes the binary T1 format described on [`save`](Self::save).
+    fn write_binary(&self, w: &mut impl Write) -> std::io::Result<()> {
+        w.write_all(&T1_FILE_MAGIC)?;
+        w.write_all(&[T1_FILE_VERSION])?;
+        w.write_all(&(self.strands.len() as u32).to_le_bytes())?;
+        for (&strand_id, frames) in &self.strands {
+            w.write_all(&strand_id.to_le_bytes())?;
+            w.write_all(&(frames.len() as u32).to_le_bytes())?;
+            for frame in frames {
+                let bytes = frame.to_binary();
+                w.write_all(&(bytes.len() as u32).to_le_bytes())?;
+                w.write_all(&bytes)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the binary T1 format written by [`write_binary`](Self::write_binary).
+    fn read_binary(r: &mut impl Read) -> Result<Self, VoltError> {
+        let err = |msg: String| VoltError::StorageError {
+            message: format!("T1 file decode: {msg}"),
+        };
+        let mut header = [0u8; 9];
+        r.read_exact(&mut header).map_err(|e| err(format!("header: {e}")))?;
+        let version = header[4];
+        if version != T1_FILE_VERSION {
+            return Err(err(format!("unsupported version {version}")));
+        }
+        let strand_count = u32::from_le_bytes([header[5], header[6], header[7], header[8]]);
+
+        let mut store = Self::new();
+        let mut word = [0u8; 8];
+        for _ in 0..strand_count {
+            r.read_exact(&mut word).map_err(|e| err(format!("strand id: {e}")))?;
+            let strand_id = u64::from_le_bytes(word);
+            r.read_exact(&mut word[..4]).map_err(|e| err(format!("frame count: {e}")))?;
+            let frame_count = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+            let frames = store.strands.entry(strand_id).or_default();
+            for _ in 0..frame_count {
+                r.read_exact(&mut word[..4]).map_err(|e| err(format!("frame length: {e}")))?;
+                let len = u32::from_le_bytes([word[0], word[1], word[2], word[3]]) as usize;
+                let mut bytes = vec![0u8; len];
+                r.read_exact(&mut bytes).map_err(|e| err(format!("frame data: {e}")))?;
+                frames.push(Box::new(TensorFrame::from_binary(&bytes)?));
+            }
+        }
+        Ok(store)
+    }
 }
 
 #[cfg(test)]
@@ -511,6 +715,74 @@ mod tests {
         let _ = std::fs::remove_dir(&dir);
     }
 
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_save_roundtrip_is_lossless() {
+        let mut store = StrandStore::new();
+        for id in 1..=20 {
+            store.store(make_frame(id, id % 3)).unwrap();
+        }
+
+        let dir = std::env::temp_dir().join("volt_db_test_t1_zstd");
+        std::fs::create_dir_all(&dir).unwrap();
+        let plain_path = dir.join(format!("plain_{}.bin", std::process::id()));
+        let zstd_path = dir.join(format!("zstd_{}.bin", std::process::id()));
+        store.save(&plain_path).unwrap();
+        store.save_with(&zstd_path, T1Compression::Zstd(3)).unwrap();
+
+        let compressed = std::fs::read(&zstd_path).unwrap();
+        assert!(compressed.starts_with(&ZSTD_MAGIC));
+        assert!(compressed.len() * 5 < std::fs::metadata(&plain_path).unwrap().len() as usize);
+
+        let loaded = StrandStore::load(&zstd_path).unwrap();
+        assert_eq!(loaded.total_frame_count(), 20);
+        for id in 1..=20 {
+            assert_eq!(
+                loaded.get_by_id(id).unwrap().to_binary(),
+                store.get_by_id(id).unwrap().to_binary()
+            );
+        }
+
+        let _ = std::fs::remove_file(&plain_path);
+        let _ = std::fs::remove_file(&zstd_path);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[test]
+    fn load_reads_legacy_json_file() {
+        // serde_json on a full TensorFrame needs more than the default test stack
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let mut store = StrandStore::new();
+                store.store(make_frame(1, 10)).unwrap();
+                store.store(make_frame(2, 20)).unwrap();
+
+                let dir = std::env::temp_dir().join("volt_db_test_t1_legacy");
+                std::fs::create_dir_all(&dir).unwrap();
+                let path = dir.join(format!("legacy_{}.json", std::process::id()));
+                std::fs::write(&path, serde_json::to_vec(&store).unwrap()).unwrap();
+
+                let loaded = StrandStore::load(&path).unwrap();
+                assert_eq!(loaded.total_frame_count(), 2);
+                assert_eq!(
+                    loaded.get_by_id(2).unwrap().to_binary(),
+                    store.get_by_id(2).unwrap().to_binary()
+                );
+
+                // Saving rewrites it in the binary format
+                loaded.save(&path).unwrap();
+                assert!(std::fs::read(&path).unwrap().starts_with(&T1_FILE_MAGIC));
+                assert_eq!(StrandStore::load(&path).unwrap().total_frame_count(), 2);
+
+                let _ = std::fs::remove_file(&path);
+                let _ = std::fs::remove_dir(&dir);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn default_is_same_as_new() {
         let store = StrandStore::default();
@@ -12,11 +12,33 @@
 //!
 //! ```text
 //! [magic: 4B "VXSR"][version: u32][entry_count: u32][bloom_bytes_len: u32]
+//! [flags: u32][min_created_at: u64][max_created_at: u64]
 //! [bloom_data: N bytes]
 //! [index: entry_count × (frame_id: u64, offset: u32, length: u32, decay_level: u8)]
 //! [frame_data: concatenated serialized entries]
 //! ```
+//!
+//! With [`T2Config::compression`] set, each entry in `frame_data` is its
+//! own zstd frame and the `RUN_FLAG_ZSTD` flag bit is set; index offsets
+//! and lengths refer to the compressed entries, which are decoded one at
+//! a time as they are read, so a run never holds more than one
+//! decompressed entry in memory. The bloom filter and index are never
+//! compressed. Version 1 runs have no
+//! `flags` field and are read as uncompressed. Writing or opening
+//! compressed runs requires the `zstd` feature.
+//!
+//! `min_created_at`/`max_created_at` bound the entries' `created_at`
+//! timestamps, letting [`Tier2Store::scan_strand_range`] skip whole runs
+//! without decoding them. Runs from before version 3 lack the bounds and
+//! are always scanned.
+//!
+//! ## Recovery
+//!
+//! [`Tier2Store::open`] fails on the first unreadable run.
+//! [`Tier2Store::open_best_effort`] instead renames each unreadable run
+//! to `*.vxr.corrupt`, logs it, and opens with the remaining runs.
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fs::{self, File};
 use std::io::Write as IoWrite;
@@ -32,10 +54,26 @@ use crate::compressed::{DecayLevel, FrameEntry};
 const SORTED_RUN_MAGIC: [u8; 4] = *b"VXSR";
 
 /// Current file format version.
-const SORTED_RUN_VERSION: u32 = 1;
+const SORTED_RUN_VERSION: u32 = 3;
+
+/// Version of runs written before the header carried flags.
+const SORTED_RUN_VERSION_V1: u32 = 1;
+
+/// Version of runs written before the header carried time bounds.
+const SORTED_RUN_VERSION_V2: u32 = 2;
+
+/// Size of the fixed header: magic(4) + version(4) + entry_count(4) +
+/// bloom_len(4) + flags(4) + min_created_at(8) + max_created_at(8).
+const HEADER_SIZE: usize = 36;
+
+/// Header size of a version 1 run, which has no flags field.
+const HEADER_SIZE_V1: usize = 16;
+
+/// Header size of a version 2 run, which has no time bounds.
+const HEADER_SIZE_V2: usize = 20;
 
-/// Size of the fixed header: magic(4) + version(4) + entry_count(4) + bloom_len(4).
-const HEADER_SIZE: usize = 16;
+/// Header flag: each frame data entry is zstd-compressed.
+const RUN_FLAG_ZSTD: u32 = 1;
 
 /// Size of one index entry: frame_id(8) + offset(4) + length(4) + decay_level(1).
 const INDEX_ENTRY_SIZE: usize = 17;
@@ -53,6 +91,7 @@ const INDEX_ENTRY_SIZE: usize = 17;
 ///     memtable_flush_threshold: 4 * 1024 * 1024,
 ///     max_runs_per_level: 4,
 ///     max_levels: 4,
+///     compression: Some(3),
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -65,6 +104,10 @@ pub struct T2Config {
     pub max_runs_per_level: usize,
     /// Maximum levels in the LSM tree (default 4).
     pub max_levels: usize,
+    /// zstd level (1–22) for the frame data entries of newly written
+    /// sorted runs; `None` writes it uncompressed (default). Existing
+    /// runs are read either way.
+    pub compression: Option<i32>,
 }
 
 impl Default for T2Config {
@@ -74,6 +117,7 @@ impl Default for T2Config {
             memtable_flush_threshold: 4 * 1024 * 1024,
             max_runs_per_level: 4,
             max_levels: 4,
+            compression: None,
         }
     }
 }
@@ -93,12 +137,16 @@ struct SortedRun {
     run_id: u64,
     bloom: BloomFilter,
     /// Memory-mapped file.
-    #[allow(dead_code)]
     mmap: Mmap,
     /// In-memory index: sorted by frame_id for binary search.
     index: Vec<IndexEntry>,
     /// Start offset of frame data within the mmap.
     data_offset: usize,
+    /// Whether each frame data entry is zstd-compressed.
+    compressed: bool,
+    /// Inclusive `(min, max)` of the entries' `created_at`; `None` for
+    /// runs written before the header recorded it.
+    time_range: Option<(u64, u64)>,
     entry_count: usize,
     path: PathBuf,
 }
@@ -109,20 +157,50 @@ impl std::fmt::Debug for SortedRun {
             .field("level", &self.level)
             .field("run_id", &self.run_id)
             .field("entry_count", &self.entry_count)
+            .field("compressed", &self.compressed)
+            .field("time_range", &self.time_range)
             .field("path", &self.path)
             .finish()
     }
 }
 
+/// zstd-compresses one serialized frame entry of a sorted run.
+#[cfg(feature = "zstd")]
+fn compress_entry(data: &[u8], level: i32) -> Result<Vec<u8>, VoltError> {
+    zstd::encode_all(data, level).map_err(|e| VoltError::StorageError {
+        message: format!("failed to compress frame data: {e}"),
+    })
+}
+
+#[cfg(not(feature = "zstd"))]
+fn compress_entry(_data: &[u8], _level: i32) -> Result<Vec<u8>, VoltError> {
+    Err(VoltError::StorageError {
+        message: "sorted run compression requires the `zstd` feature".to_string(),
+    })
+}
+
+/// Decompresses one frame entry of a sorted run; `None` if it is damaged.
+#[cfg(feature = "zstd")]
+fn decompress_entry(data: &[u8]) -> Option<Vec<u8>> {
+    zstd::decode_all(data).ok()
+}
+
+#[cfg(not(feature = "zstd"))]
+fn decompress_entry(_data: &[u8]) -> Option<Vec<u8>> {
+    None
+}
+
 impl SortedRun {
     /// Creates a new sorted run from a set of frame entries.
     ///
     /// Writes the file, builds the bloom filter and index, then mmaps it.
+    /// With a `compression` level each frame data entry is zstd-compressed.
     fn create(
         path: &Path,
         entries: &[(u64, Vec<u8>)], // (frame_id, serialized_frame_entry)
         level: usize,
         run_id: u64,
+        compression: Option<i32>,
     ) -> Result<Self, VoltError> {
         let entry_count = entries.len();
 
@@ -133,6 +211,39 @@ impl SortedRun {
         }
         let bloom_bytes = bloom.to_bytes();
 
+        // Bound the entries' timestamps; an empty run gets an empty range
+        let (min_created_at, max_created_at) = entries
+            .iter()
+            .filter_map(|(_, bytes)| FrameEntry::from_bytes(bytes).ok())
+            .fold((u64::MAX, 0), |(min, max), entry| {
+                (min.min(entry.created_at()), max.max(entry.created_at()))
+            });
+
+        // Build index entries and frame data, compressing before the
+        // file is created so a failure leaves nothing behind
+        let mut index = Vec::with_capacity(entry_count);
+        let mut data_buf = Vec::new();
+
+        for &(frame_id, ref frame_bytes) in entries {
+            let decay_level = if frame_bytes.is_empty() {
+                DecayLevel::Tombstoned
+            } else {
+                DecayLevel::from_tag(frame_bytes[0]).unwrap_or(DecayLevel::Tombstoned)
+            };
+            let compressed_bytes = compression
+                .map(|level| compress_entry(frame_bytes, level))
+                .transpose()?;
+            let stored = compressed_bytes.as_deref().unwrap_or(frame_bytes);
+
+            index.push(IndexEntry {
+                frame_id,
+                offset: data_buf.len() as u32,
+                length: stored.len() as u32,
+                decay_level,
+            });
+            data_buf.extend_from_slice(stored);
+        }
+
         // Calculate offsets for frame data
         let index_start = HEADER_SIZE + bloom_bytes.len();
         let data_start = index_start + entry_count * INDEX_ENTRY_SIZE;
@@ -158,6 +269,16 @@ impl SortedRun {
             .map_err(|e| VoltError::StorageError {
                 message: format!("failed to write sorted run bloom size: {e}"),
             })?;
+        let flags = if compression.is_some() { RUN_FLAG_ZSTD } else { 0 };
+        file.write_all(&flags.to_le_bytes())
+            .map_err(|e| VoltError::StorageError {
+                message: format!("failed to write sorted run flags: {e}"),
+            })?;
+        file.write_all(&min_created_at.to_le_bytes())
+            .and_then(|()| file.write_all(&max_created_at.to_le_bytes()))
+            .map_err(|e| VoltError::StorageError {
+                message: format!("failed to write sorted run time range: {e}"),
+            })?;
 
         // Write bloom filter
         file.write_all(&bloom_bytes)
@@ -165,28 +286,6 @@ impl SortedRun {
                 message: format!("failed to write bloom filter: {e}"),
             })?;
 
-        // Build index entries and write frame data
-        let mut index = Vec::with_capacity(entry_count);
-        let mut data_buf = Vec::new();
-
-        for &(frame_id, ref frame_bytes) in entries {
-            let offset = data_buf.len() as u32;
-            let length = frame_bytes.len() as u32;
-            let decay_level = if frame_bytes.is_empty() {
-                DecayLevel::Tombstoned
-            } else {
-                DecayLevel::from_tag(frame_bytes[0]).unwrap_or(DecayLevel::Tombstoned)
-            };
-
-            index.push(IndexEntry {
-                frame_id,
-                offset,
-                length,
-                decay_level,
-            });
-            data_buf.extend_from_slice(frame_bytes);
-        }
-
         // Write index
         for idx in &index {
             file.write_all(&idx.frame_id.to_le_bytes())
@@ -236,6 +335,8 @@ impl SortedRun {
             mmap,
             index,
             data_offset: data_start,
+            compressed: compression.is_some(),
+            time_range: Some((min_created_at, max_created_at)),
             entry_count,
             path: path.to_path_buf(),
         })
@@ -254,7 +355,7 @@ impl SortedRun {
             })?
         };
 
-        if mmap.len() < HEADER_SIZE {
+        if mmap.len() < HEADER_SIZE_V1 {
             return Err(VoltError::StorageError {
                 message: format!(
                     "sorted run {} too small: {} bytes",
@@ -271,19 +372,40 @@ impl SortedRun {
             });
         }
         let version = u32::from_le_bytes(mmap[4..8].try_into().unwrap());
-        if version != SORTED_RUN_VERSION {
+        let header_size = match version {
+            SORTED_RUN_VERSION => HEADER_SIZE,
+            SORTED_RUN_VERSION_V2 => HEADER_SIZE_V2,
+            SORTED_RUN_VERSION_V1 => HEADER_SIZE_V1,
+            _ => {
+                return Err(VoltError::StorageError {
+                    message: format!(
+                        "sorted run {} has unsupported version {version}",
+                        path.display()
+                    ),
+                });
+            }
+        };
+        if mmap.len() < header_size {
             return Err(VoltError::StorageError {
-                message: format!(
-                    "sorted run {} has unsupported version {version}",
-                    path.display()
-                ),
+                message: format!("sorted run {} header truncated", path.display()),
             });
         }
         let entry_count = u32::from_le_bytes(mmap[8..12].try_into().unwrap()) as usize;
         let bloom_len = u32::from_le_bytes(mmap[12..16].try_into().unwrap()) as usize;
+        let flags = if version == SORTED_RUN_VERSION_V1 {
+            0
+        } else {
+            u32::from_le_bytes(mmap[16..20].try_into().unwrap())
+        };
+        let time_range = (version == SORTED_RUN_VERSION).then(|| {
+            (
+                u64::from_le_bytes(mmap[20..28].try_into().unwrap()),
+                u64::from_le_bytes(mmap[28..36].try_into().unwrap()),
+            )
+        });
 
         // Parse bloom filter
-        let bloom_start = HEADER_SIZE;
+        let bloom_start = header_size;
         let bloom_end = bloom_start + bloom_len;
         if mmap.len() < bloom_end {
             return Err(VoltError::StorageError {
@@ -320,6 +442,18 @@ impl SortedRun {
             });
         }
 
+        // A truncated data block fails the open instead of reading as a
+        // run with missing entries later.
+        let data_len = mmap.len() - index_end;
+        if index
+            .iter()
+            .any(|idx| idx.offset as usize + idx.length as usize > data_len)
+        {
+            return Err(VoltError::StorageError {
+                message: format!("sorted run {} frame data truncated", path.display()),
+            });
+        }
+
         Ok(Self {
             level,
             run_id,
@@ -327,11 +461,25 @@ impl SortedRun {
             mmap,
             index,
             data_offset: index_end,
+            compressed: flags & RUN_FLAG_ZSTD != 0,
+            time_range,
             entry_count,
             path: path.to_path_buf(),
         })
     }
 
+    /// The serialized entry an index entry points to, decompressed if
+    /// the run is compressed; `None` if it is out of bounds or damaged.
+    fn entry_bytes(&self, idx: &IndexEntry) -> Option<Cow<'_, [u8]>> {
+        let start = self.data_offset + idx.offset as usize;
+        let bytes = self.mmap.get(start..start + idx.length as usize)?;
+        if self.compressed {
+            decompress_entry(bytes).map(Cow::Owned)
+        } else {
+            Some(Cow::Borrowed(bytes))
+        }
+    }
+
     /// Looks up a frame by ID using bloom filter + binary search.
     fn get(&self, frame_id: u64) -> Option<FrameEntry> {
         // Bloom filter fast rejection
@@ -345,27 +493,17 @@ impl SortedRun {
             .binary_search_by_key(&frame_id, |e| e.frame_id)
             .ok()?;
 
-        let idx = &self.index[pos];
-        let start = self.data_offset + idx.offset as usize;
-        let end = start + idx.length as usize;
-
-        if end > self.mmap.len() {
-            return None;
-        }
-
-        FrameEntry::from_bytes(&self.mmap[start..end]).ok()
+        FrameEntry::from_bytes(&self.entry_bytes(&self.index[pos])?).ok()
     }
 
     /// Returns all entries in this run for a given strand.
     fn scan_strand(&self, strand_id: u64) -> Vec<FrameEntry> {
         let mut entries = Vec::new();
         for idx in &self.index {
-            let start = self.data_offset + idx.offset as usize;
-            let end = start + idx.length as usize;
-            if end > self.mmap.len() {
+            let Some(bytes) = self.entry_bytes(idx) else {
                 continue;
-            }
-            if let Ok(entry) = FrameEntry::from_bytes(&self.mmap[start..end])
+            };
+            if let Ok(entry) = FrameEntry::from_bytes(&bytes)
                 && entry.strand_id() == strand_id
             {
                 entries.push(entry);
@@ -374,16 +512,48 @@ impl SortedRun {
         entries
     }
 
+    /// Returns this run's entries for a strand created within
+    /// `start..=end`, without decoding anything if the run's time bounds
+    /// miss the range.
+    fn scan_strand_range(&self, strand_id: u64, start: u64, end: u64) -> Vec<FrameEntry> {
+        if !self.overlaps(start, end) {
+            return Vec::new();
+        }
+        let mut entries = self.scan_strand(strand_id);
+        entries.retain(|entry| (start..=end).contains(&entry.created_at()));
+        entries
+    }
+
+    /// Whether entries created within `start..=end` may be in this run.
+    ///
+    /// Always true for runs without recorded time bounds.
+    fn overlaps(&self, start: u64, end: u64) -> bool {
+        self.time_range
+            .is_none_or(|(min, max)| min <= end && max >= start)
+    }
+
+    /// Decodes entries one at a time in index order, calling `f` on each.
+    ///
+    /// Visits the same entries as [`Self::scan_all`] without collecting.
+    fn for_each(&self, f: &mut impl FnMut(FrameEntry)) {
+        for idx in &self.index {
+            let Some(bytes) = self.entry_bytes(idx) else {
+                continue;
+            };
+            if let Ok(entry) = FrameEntry::from_bytes(&bytes) {
+                f(entry);
+            }
+        }
+    }
+
     /// Returns all entries in this run.
     fn scan_all(&self) -> Vec<(u64, FrameEntry)> {
         let mut entries = Vec::new();
         for idx in &self.index {
-            let start = self.data_offset + idx.offset as usize;
-            let end = start + idx.length as usize;
-            if end > self.mmap.len() {
+            let Some(bytes) = self.entry_bytes(idx) else {
                 continue;
-            }
-            if let Ok(entry) = FrameEntry::from_bytes(&self.mmap[start..end]) {
+            };
+            if let Ok(entry) = FrameEntry::from_bytes(&bytes) {
                 entries.push((idx.frame_id, entry));
             }
         }
@@ -444,6 +614,47 @@ impl Tier2Store {
     /// Returns [`VoltError::StorageError`] if directory creation or
     /// file loading fails.
     pub fn open(config: T2Config) -> Result<Self, VoltError> {
+        Self::open_inner(config, false).map(|(store, _)| store)
+    }
+
+    /// Opens a T2 store like [`Tier2Store::open`], but quarantines sorted
+    /// runs that fail to load instead of failing.
+    ///
+    /// Each unreadable run is renamed to `<name>.corrupt` so it is kept
+    /// for inspection but no longer loaded. Returns the store and the
+    /// quarantined runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the directory cannot be
+    /// created or read, or a corrupt run cannot be renamed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::tier2::{Tier2Store, T2Config};
+    ///
+    /// let dir = std::env::temp_dir().join("volt_t2_doc_best_effort");
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("run_0001_L0.vxr"), b"garbage").unwrap();
+    ///
+    /// let config = T2Config { data_dir: dir.clone(), ..T2Config::default() };
+    /// let (store, quarantined) = Tier2Store::open_best_effort(config).unwrap();
+    /// assert_eq!(store.total_entries(), 0);
+    /// assert_eq!(quarantined.len(), 1);
+    /// assert!(dir.join("run_0001_L0.vxr.corrupt").exists());
+    /// ```
+    pub fn open_best_effort(
+        config: T2Config,
+    ) -> Result<(Self, Vec<QuarantinedFile>), VoltError> {
+        Self::open_inner(config, true)
+    }
+
+    fn open_inner(
+        config: T2Config,
+        best_effort: bool,
+    ) -> Result<(Self, Vec<QuarantinedFile>), VoltError> {
         fs::create_dir_all(&config.data_dir).map_err(|e| VoltError::StorageError {
             message: format!(
                 "failed to create T2 data directory {}: {e}",
@@ -455,6 +666,7 @@ impl Tier2Store {
             .map(|_| Vec::new())
             .collect();
         let mut max_run_id = 0u64;
+        let mut quarantined = Vec::new();
 
         // Discover existing run files: run_{id}_L{level}.vxr
         let entries = fs::read_dir(&config.data_dir).map_err(|e| VoltError::StorageError {
@@ -474,9 +686,15 @@ impl Tier2Store {
             if let Some((run_id, level)) = parse_run_filename(&name)
                 && level < config.max_levels
             {
-                let run = SortedRun::open(&entry.path(), level, run_id)?;
-                sorted_runs[level].push(run);
+                // A quarantined run still reserves its ID
                 max_run_id = max_run_id.max(run_id);
+                match SortedRun::open(&entry.path(), level, run_id) {
+                    Ok(run) => sorted_runs[level].push(run),
+                    Err(e) if best_effort => {
+                        quarantined.push(quarantine_file(&entry.path(), &e.to_string())?);
+                    }
+                    Err(e) => return Err(e),
+                }
             }
         }
 
@@ -485,13 +703,16 @@ impl Tier2Store {
             level_runs.sort_by(|a, b| b.run_id.cmp(&a.run_id));
         }
 
-        Ok(Self {
-            config,
-            memtable: BTreeMap::new(),
-            memtable_size: 0,
-            sorted_runs,
-            next_run_id: max_run_id + 1,
-        })
+        Ok((
+            Self {
+                config,
+                memtable: BTreeMap::new(),
+                memtable_size: 0,
+                sorted_runs,
+                next_run_id: max_run_id + 1,
+            },
+            quarantined,
+        ))
     }
 
     /// Inserts a frame entry into the memtable.
@@ -560,7 +781,65 @@ impl Tier2Store {
         entries
     }
 
+    /// Returns the entries for a strand created within `start..=end`
+    /// (inclusive, `created_at` microseconds) across memtable and runs.
+    ///
+    /// Runs whose recorded time bounds miss the range are skipped without
+    /// decoding. Returns nothing if `start > end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::compressed::{compress, FrameEntry};
+    /// use volt_db::tier2::{Tier2Store, T2Config};
+    /// use volt_core::TensorFrame;
+    ///
+    /// let dir = std::env::temp_dir().join("volt_t2_doc_strand_range");
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// let config = T2Config { data_dir: dir.clone(), ..T2Config::default() };
+    /// let mut store = Tier2Store::open(config).unwrap();
+    ///
+    /// for (id, t) in [(1, 1_000), (2, 2_000), (3, 3_000)] {
+    ///     let mut frame = TensorFrame::new();
+    ///     frame.frame_meta.frame_id = id;
+    ///     frame.frame_meta.strand_id = 7;
+    ///     frame.frame_meta.created_at = t;
+    ///     store.insert(FrameEntry::Compressed(compress(&frame))).unwrap();
+    /// }
+    /// store.flush_memtable().unwrap();
+    ///
+    /// let hits = store.scan_strand_range(7, 1_500, 3_000);
+    /// assert_eq!(hits.len(), 2);
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// ```
+    pub fn scan_strand_range(&self, strand_id: u64, start: u64, end: u64) -> Vec<FrameEntry> {
+        let mut entries = Vec::new();
+        if start > end {
+            return entries;
+        }
+
+        for bytes in self.memtable.values() {
+            if let Ok(entry) = FrameEntry::from_bytes(bytes)
+                && entry.strand_id() == strand_id
+                && (start..=end).contains(&entry.created_at())
+            {
+                entries.push(entry);
+            }
+        }
+
+        for level_runs in &self.sorted_runs {
+            for run in level_runs {
+                entries.extend(run.scan_strand_range(strand_id, start, end));
+            }
+        }
+
+        entries
+    }
+
     /// Returns all entries across memtable and runs.
+    ///
+    /// Materializes the whole archive; prefer [`Self::for_each`] for
+    /// large stores.
     pub fn scan_all(&self) -> Vec<FrameEntry> {
         let mut entries = Vec::new();
 
@@ -581,6 +860,27 @@ impl Tier2Store {
         entries
     }
 
+    /// Calls `f` on every entry across memtable and runs, decoding one
+    /// entry at a time instead of collecting them.
+    ///
+    /// Visits exactly the entries [`Self::scan_all`] returns, in the same
+    /// order: the memtable first, then runs newest level first. Like
+    /// `scan_all`, shadowed versions of a frame in older runs are still
+    /// visited.
+    pub fn for_each(&self, mut f: impl FnMut(FrameEntry)) {
+        for bytes in self.memtable.values() {
+            if let Ok(entry) = FrameEntry::from_bytes(bytes) {
+                f(entry);
+            }
+        }
+
+        for level_runs in &self.sorted_runs {
+            for run in level_runs {
+                run.for_each(&mut f);
+            }
+        }
+    }
+
     /// Flushes the memtable to a new level-0 sorted run on disk.
     ///
     /// # Errors
@@ -606,7 +906,7 @@ impl Tier2Store {
             .map(|(&id, bytes)| (id, bytes.clone()))
             .collect();
 
-        let run = SortedRun::create(&path, &entries, 0, run_id)?;
+        let run = SortedRun::create(&path, &entries, 0, run_id, self.config.compression)?;
 
         // Ensure level 0 exists
         while self.sorted_runs.is_empty() {
@@ -664,7 +964,8 @@ impl Tier2Store {
             .join(format!("run_{run_id:04}_L{next_level}.vxr"));
 
         let entries: Vec<(u64, Vec<u8>)> = all_entries.into_iter().collect();
-        let merged_run = SortedRun::create(&path, &entries, next_level, run_id)?;
+        let merged_run =
+            SortedRun::create(&path, &entries, next_level, run_id, self.config.compression)?;
 
         // Delete old runs at this level
         let old_paths: Vec<PathBuf> = self.sorted_runs[level]
@@ -736,6 +1037,14 @@ impl Tier2Store {
         self.memtable.len()
     }
 
+    /// Returns the paths of all sorted run files, newest level first.
+    pub fn run_paths(&self) -> Vec<PathBuf> {
+        self.sorted_runs
+            .iter()
+            .flat_map(|level| level.iter().map(|run| run.path.clone()))
+            .collect()
+    }
+
     /// Returns the number of sorted runs at each level.
     pub fn runs_per_level(&self) -> Vec<usize> {
         self.sorted_runs.iter().map(|r| r.len()).collect()
@@ -767,6 +1076,38 @@ impl Tier2Store {
     }
 }
 
+/// A file set aside during a best-effort open because it could not be
+/// loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuarantinedFile {
+    /// Where the file was found.
+    pub original: PathBuf,
+    /// Where the file was moved to.
+    pub quarantined: PathBuf,
+    /// Why the file could not be loaded.
+    pub reason: String,
+}
+
+/// Renames `path` to `<path>.corrupt` and logs the quarantine.
+pub(crate) fn quarantine_file(path: &Path, reason: &str) -> Result<QuarantinedFile, VoltError> {
+    let mut target = path.as_os_str().to_owned();
+    target.push(".corrupt");
+    let target = PathBuf::from(target);
+    fs::rename(path, &target).map_err(|e| VoltError::StorageError {
+        message: format!("failed to quarantine {}: {e}", path.display()),
+    })?;
+    tracing::warn!(
+        "quarantined unreadable file {} as {}: {reason}",
+        path.display(),
+        target.display()
+    );
+    Ok(QuarantinedFile {
+        original: path.to_path_buf(),
+        quarantined: target,
+        reason: reason.to_string(),
+    })
+}
+
 /// Parses a sorted run filename into (run_id, level).
 ///
 /// Expected format: `run_NNNN_LM.vxr`
@@ -936,6 +1277,53 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn for_each_visits_same_entries_as_scan_all() {
+        let dir = temp_dir("for_each");
+        let config = T2Config {
+            data_dir: dir.clone(),
+            memtable_flush_threshold: 100 * 1024 * 1024,
+            max_runs_per_level: 8,
+            ..T2Config::default()
+        };
+        let mut store = Tier2Store::open(config).unwrap();
+
+        // Three runs plus a memtable; frames 3 and 12 are rewritten at a
+        // later decay level, shadowing their older copies.
+        for batch in 0..3u64 {
+            for i in 1..=4u64 {
+                let frame = make_test_frame(batch * 10 + i, batch);
+                store
+                    .insert(FrameEntry::Compressed(compress(&frame)))
+                    .unwrap();
+            }
+            if batch == 1 {
+                let gist = to_gist_frame(&compress(&make_test_frame(3, 0)), [0.5; SLOT_DIM]);
+                store.insert(FrameEntry::Gist(gist)).unwrap();
+            }
+            store.flush_memtable().unwrap();
+        }
+        store
+            .insert(FrameEntry::Tombstone(to_tombstone(12, 1, 9000, None)))
+            .unwrap();
+        store
+            .insert(FrameEntry::Compressed(compress(&make_test_frame(40, 2))))
+            .unwrap();
+
+        let scanned: Vec<Vec<u8>> = store
+            .scan_all()
+            .iter()
+            .map(|e| e.to_bytes().unwrap())
+            .collect();
+        let mut streamed: Vec<Vec<u8>> = Vec::new();
+        store.for_each(|e| streamed.push(e.to_bytes().unwrap()));
+
+        assert_eq!(scanned.len(), 3 * 4 + 1 + 2);
+        assert_eq!(streamed, scanned);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn compaction_merges() {
         let dir = temp_dir("compaction");
@@ -944,6 +1332,7 @@ mod tests {
             memtable_flush_threshold: 100 * 1024 * 1024,
             max_runs_per_level: 2, // Compact after 3 runs at level 0
             max_levels: 4,
+            compression: None,
         };
         let mut store = Tier2Store::open(config).unwrap();
 
@@ -1131,4 +1520,179 @@ mod tests {
 
         let _ = fs::remove_dir_all(&dir);
     }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn compressed_runs_reopen_and_are_smaller() {
+        let write = |name: &str, compression: Option<i32>| {
+            let dir = temp_dir(name);
+            let config = T2Config {
+                data_dir: dir.clone(),
+                memtable_flush_threshold: 100 * 1024 * 1024,
+                compression,
+                ..T2Config::default()
+            };
+            let mut store = Tier2Store::open(config.clone()).unwrap();
+            for i in 1..=50u64 {
+                store
+                    .insert(FrameEntry::Compressed(compress(&make_test_frame(i, i % 3))))
+                    .unwrap();
+            }
+            store.flush_memtable().unwrap();
+            (dir, config, store.disk_size_bytes())
+        };
+
+        let (plain_dir, _, plain_size) = write("plain_run", None);
+        let (zstd_dir, config, zstd_size) = write("zstd_run", Some(3));
+        assert!(
+            zstd_size < plain_size,
+            "compressed run ({zstd_size} B) not smaller than plain ({plain_size} B)"
+        );
+
+        let store = Tier2Store::open(config).unwrap();
+        for i in 1..=50u64 {
+            let entry = store.get(i).unwrap_or_else(|| panic!("frame {i} not found"));
+            assert_eq!(entry.frame_id(), i);
+            assert_eq!(entry.decay_level(), DecayLevel::Compressed);
+        }
+        assert_eq!(store.scan_all().len(), 50);
+        assert_eq!(store.scan_strand(1).len(), 17);
+
+        let _ = fs::remove_dir_all(&plain_dir);
+        let _ = fs::remove_dir_all(&zstd_dir);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn open_fails_on_truncated_compressed_run() {
+        let dir = temp_dir("bad_zstd_run");
+        let config = T2Config {
+            data_dir: dir.clone(),
+            memtable_flush_threshold: 100 * 1024 * 1024,
+            compression: Some(3),
+            ..T2Config::default()
+        };
+        {
+            let mut store = Tier2Store::open(config.clone()).unwrap();
+            for i in 1..=5u64 {
+                store
+                    .insert(FrameEntry::Compressed(compress(&make_test_frame(i, 0))))
+                    .unwrap();
+            }
+            store.flush_memtable().unwrap();
+        }
+
+        // Cut the tail off the last compressed entry
+        let run = dir.join("run_0001_L0.vxr");
+        let bytes = fs::read(&run).unwrap();
+        fs::write(&run, &bytes[..bytes.len() - 32]).unwrap();
+
+        let err = Tier2Store::open(config).unwrap_err();
+        assert!(matches!(err, VoltError::StorageError { .. }), "{err}");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    #[test]
+    fn compression_requires_zstd_feature() {
+        let dir = temp_dir("no_zstd_run");
+        let config = T2Config {
+            data_dir: dir.clone(),
+            compression: Some(3),
+            ..T2Config::default()
+        };
+        let mut store = Tier2Store::open(config).unwrap();
+        store
+            .insert(FrameEntry::Compressed(compress(&make_test_frame(1, 0))))
+            .unwrap();
+        assert!(store.flush_memtable().is_err());
+        assert!(!dir.join("run_0001_L0.vxr").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn version_1_run_without_flags_still_opens() {
+        let dir = temp_dir("v1_run");
+        let config = T2Config {
+            data_dir: dir.clone(),
+            memtable_flush_threshold: 100 * 1024 * 1024,
+            ..T2Config::default()
+        };
+        let path = {
+            let mut store = Tier2Store::open(config.clone()).unwrap();
+            for i in 1..=5u64 {
+                store
+                    .insert(FrameEntry::Compressed(compress(&make_test_frame(i, 0))))
+                    .unwrap();
+            }
+            store.flush_memtable().unwrap();
+            store.run_paths().remove(0)
+        };
+
+        // Rewrite the run in the v1 layout: same header minus the flags word
+        let bytes = fs::read(&path).unwrap();
+        let mut v1 = bytes[..HEADER_SIZE_V1].to_vec();
+        v1[4..8].copy_from_slice(&SORTED_RUN_VERSION_V1.to_le_bytes());
+        v1.extend_from_slice(&bytes[HEADER_SIZE..]);
+        fs::write(&path, v1).unwrap();
+
+        let store = Tier2Store::open(config).unwrap();
+        for i in 1..=5u64 {
+            assert_eq!(store.get(i).unwrap().frame_id(), i);
+        }
+        // Without recorded bounds the run is never pruned
+        assert_eq!(store.scan_strand_range(0, 2_000, 3_000).len(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn scan_strand_range_filters_and_prunes_runs() {
+        let dir = temp_dir("strand_range");
+        let config = T2Config {
+            data_dir: dir.clone(),
+            memtable_flush_threshold: 100 * 1024 * 1024,
+            ..T2Config::default()
+        };
+        let mut store = Tier2Store::open(config).unwrap();
+
+        // Three runs covering created_at 1000..=10000, 11000..=20000, 21000..=30000,
+        // with strands 0 and 1 interleaved, plus a memtable tail.
+        for batch in 0..3u64 {
+            for i in 1..=10u64 {
+                let id = batch * 10 + i;
+                store
+                    .insert(FrameEntry::Compressed(compress(&make_test_frame(id, id % 2))))
+                    .unwrap();
+            }
+            store.flush_memtable().unwrap();
+        }
+        store
+            .insert(FrameEntry::Compressed(compress(&make_test_frame(31, 1))))
+            .unwrap();
+
+        let hits = store.scan_strand_range(1, 12_000, 19_000);
+        let mut ids: Vec<u64> = hits.iter().map(|e| e.frame_id()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![13, 15, 17, 19]);
+        assert!(hits.iter().all(|e| e.strand_id() == 1));
+
+        let overlapping = store.sorted_runs[0]
+            .iter()
+            .filter(|run| run.overlaps(12_000, 19_000))
+            .count();
+        assert_eq!(overlapping, 1, "runs outside the range were not pruned");
+
+        let tail = store.scan_strand_range(1, 29_000, u64::MAX);
+        let mut ids: Vec<u64> = tail.iter().map(|e| e.frame_id()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![29, 31]);
+
+        assert!(store.scan_strand_range(1, 19_000, 12_000).is_empty());
+        assert!(store.scan_strand_range(1, 40_000, 50_000).is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -7,11 +7,26 @@
 //!
 //! ```text
 //! [entry_len: u32][frame_id: u64][strand_id: u64][op: u8]
-//! [payload_len: u32][payload: bytes][crc32: u32]
+//! [payload_len: u32][payload: bytes][timestamp: u64][crc32: u32]
 //! ```
 //!
-//! The CRC32 covers everything from `entry_len` through `payload`.
+//! The CRC32 covers everything from `entry_len` through `timestamp`.
 //! Corrupt or truncated entries at the tail are skipped on replay.
+//! Entries written before timestamps existed end at `payload` and are
+//! read with a timestamp of 0.
+//!
+//! Entries that touch several strands (such as [`WalOp::Reassign`]) make
+//! the order across logs matter, so [`WalManager::replay_ordered`] merges
+//! all logs by timestamp. [`WalManager::log_entry`] stamps entries with
+//! strictly increasing timestamps to keep that order unambiguous.
+//!
+//! ## Checkpoints
+//!
+//! [`WalManager::checkpoint`] is called once T1 is safely on disk. It
+//! replaces each strand's log with a single [`WalOp::Checkpoint`] marker,
+//! so the WAL only grows between checkpoints. Replay starts at the last
+//! marker in a file, so entries before it are never replayed even if a
+//! log was not rewritten.
 
 use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
@@ -39,8 +54,20 @@ pub enum WalOp {
     Compress = 1,
     /// A frame was gisted (GC demotion).
     Gist = 2,
-    /// A frame was tombstoned (GC demotion).
+    /// A frame was tombstoned (GC demotion or consolidation). The
+    /// payload holds the superseding frame ID as a little-endian `u64`,
+    /// or is empty.
     Tombstone = 3,
+    /// A frame was explicitly deleted.
+    Delete = 4,
+    /// Several frames were stored together; see [`batch_payload`].
+    StoreBatch = 5,
+    /// Everything logged before this entry is durable elsewhere. The
+    /// entry's `frame_id` is the next frame ID at checkpoint time.
+    Checkpoint = 6,
+    /// A frame moved to the entry's strand. The payload holds the
+    /// strand it left as a little-endian `u64`.
+    Reassign = 7,
 }
 
 impl WalOp {
@@ -51,6 +78,10 @@ impl WalOp {
             1 => Some(Self::Compress),
             2 => Some(Self::Gist),
             3 => Some(Self::Tombstone),
+            4 => Some(Self::Delete),
+            5 => Some(Self::StoreBatch),
+            6 => Some(Self::Checkpoint),
+            7 => Some(Self::Reassign),
             _ => None,
         }
     }
@@ -73,6 +104,7 @@ impl WalOp {
 ///     strand_id: 1,
 ///     op: WalOp::Store,
 ///     payload: vec![1, 2, 3],
+///     timestamp: 0,
 /// };
 /// assert_eq!(entry.frame_id, 42);
 /// ```
@@ -86,14 +118,19 @@ pub struct WalEntry {
     pub op: WalOp,
     /// Serialized frame data (e.g. from `FrameEntry::to_bytes()`).
     pub payload: Vec<u8>,
+    /// When the entry was logged, in microseconds since the Unix epoch.
+    /// [`WalManager::log_entry`] stamps entries left at 0 with the
+    /// current time.
+    pub timestamp: u64,
 }
 
 impl WalEntry {
     /// Serializes this entry to bytes including CRC32.
     fn to_bytes(&self) -> Vec<u8> {
         let payload_len = self.payload.len() as u32;
-        // entry_len covers: frame_id(8) + strand_id(8) + op(1) + payload_len(4) + payload
-        let entry_len: u32 = 8 + 8 + 1 + 4 + payload_len;
+        // entry_len covers: frame_id(8) + strand_id(8) + op(1) + payload_len(4)
+        // + payload + timestamp(8)
+        let entry_len: u32 = 8 + 8 + 1 + 4 + payload_len + 8;
 
         let mut buf = Vec::with_capacity(4 + entry_len as usize + 4);
 
@@ -104,6 +141,7 @@ impl WalEntry {
         buf.push(self.op.tag());
         buf.extend_from_slice(&payload_len.to_le_bytes());
         buf.extend_from_slice(&self.payload);
+        buf.extend_from_slice(&self.timestamp.to_le_bytes());
 
         // CRC32 over everything before this point
         let mut hasher = Hasher::new();
@@ -168,6 +206,12 @@ impl WalEntry {
             return None;
         }
         let payload = data[pos..pos + payload_len].to_vec();
+        pos += payload_len;
+        let timestamp = match entry_end - pos {
+            0 => 0, // written before entries carried timestamps
+            8 => u64::from_le_bytes(data[pos..pos + 8].try_into().ok()?),
+            _ => return None,
+        };
 
         Some((
             Self {
@@ -175,12 +219,114 @@ impl WalEntry {
                 strand_id,
                 op,
                 payload,
+                timestamp,
             },
             total,
         ))
     }
 }
 
+/// Joins per-frame payloads into one [`WalOp::StoreBatch`] payload.
+///
+/// Each part is written as `[len: u32][bytes]`, so the whole batch is
+/// covered by a single CRC and replays all-or-nothing.
+///
+/// # Example
+///
+/// ```
+/// use volt_db::wal::{batch_payload, split_batch_payload};
+///
+/// let payload = batch_payload(&[vec![1, 2], vec![3]]);
+/// let parts = split_batch_payload(&payload).unwrap();
+/// assert_eq!(parts, vec![&[1, 2][..], &[3][..]]);
+/// ```
+pub fn batch_payload(parts: &[Vec<u8>]) -> Vec<u8> {
+    let total: usize = parts.iter().map(|p| 4 + p.len()).sum();
+    let mut payload = Vec::with_capacity(total);
+    for part in parts {
+        payload.extend_from_slice(&(part.len() as u32).to_le_bytes());
+        payload.extend_from_slice(part);
+    }
+    payload
+}
+
+/// Splits a [`WalOp::StoreBatch`] payload back into its parts.
+///
+/// Returns `None` if the payload is truncated.
+pub fn split_batch_payload(payload: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut parts = Vec::new();
+    let mut pos = 0;
+    while pos < payload.len() {
+        let len_end = pos.checked_add(4).filter(|&end| end <= payload.len())?;
+        let len = u32::from_le_bytes(payload[pos..len_end].try_into().ok()?) as usize;
+        let end = len_end.checked_add(len).filter(|&end| end <= payload.len())?;
+        parts.push(&payload[len_end..end]);
+        pos = end;
+    }
+    Some(parts)
+}
+
+/// Reads all valid entries from the WAL file at `path`, stopping at the
+/// first corrupt or truncated entry. Entries before the last
+/// [`WalOp::Checkpoint`] marker are dropped; the marker itself is kept.
+fn read_entries(path: &Path, strand_id: u64) -> Result<Vec<WalEntry>, VoltError> {
+    let data = fs::read(path).map_err(|e| VoltError::StorageError {
+        message: format!("failed to read WAL for strand {strand_id}: {e}"),
+    })?;
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        match WalEntry::from_bytes_at(&data, offset) {
+            Some((entry, consumed)) => {
+                entries.push(entry);
+                offset += consumed;
+            }
+            None => {
+                // Corrupt or truncated entry at tail — stop replay
+                break;
+            }
+        }
+    }
+
+    if let Some(last) = entries.iter().rposition(|e| e.op == WalOp::Checkpoint) {
+        entries.drain(..last);
+    }
+    Ok(entries)
+}
+
+/// Merges per-strand entries into one list ordered by timestamp; ties
+/// keep log order, lower strand IDs first.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use volt_db::wal::{merge_by_timestamp, WalEntry, WalOp};
+///
+/// let entry = |strand_id, timestamp| WalEntry {
+///     frame_id: 1,
+///     strand_id,
+///     op: WalOp::Store,
+///     payload: vec![],
+///     timestamp,
+/// };
+/// let merged = merge_by_timestamp(HashMap::from([
+///     (1, vec![entry(1, 10), entry(1, 30)]),
+///     (2, vec![entry(2, 20)]),
+/// ]));
+/// let order: Vec<u64> = merged.iter().map(|e| e.timestamp).collect();
+/// assert_eq!(order, vec![10, 20, 30]);
+/// ```
+pub fn merge_by_timestamp(by_strand: HashMap<u64, Vec<WalEntry>>) -> Vec<WalEntry> {
+    let mut strands: Vec<(u64, Vec<WalEntry>)> = by_strand.into_iter().collect();
+    strands.sort_unstable_by_key(|&(strand_id, _)| strand_id);
+    let mut entries: Vec<WalEntry> = strands.into_iter().flat_map(|(_, e)| e).collect();
+    entries.sort_by_key(|entry| entry.timestamp);
+    entries
+}
+
 /// Per-strand WAL file.
 ///
 /// Each strand gets its own `.wal` file in the WAL directory.
@@ -265,33 +411,44 @@ impl StrandWal {
     ///
     /// Skips corrupt or truncated entries at the tail.
     fn replay(&self) -> Result<Vec<WalEntry>, VoltError> {
-        let data = fs::read(&self.path).map_err(|e| VoltError::StorageError {
+        read_entries(&self.path, self.strand_id)
+    }
+
+    /// Replaces the WAL file with `marker` followed by `carried`.
+    ///
+    /// Everything is written to a temporary file that is renamed over the
+    /// log, so a crash leaves either the old log or the complete new one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if writing or renaming fails.
+    fn reset_to(&mut self, marker: &WalEntry, carried: &[WalEntry]) -> Result<(), VoltError> {
+        let io_err = |e: std::io::Error| VoltError::StorageError {
             message: format!(
-                "failed to read WAL for strand {}: {e}",
+                "failed to checkpoint WAL for strand {}: {e}",
                 self.strand_id
             ),
-        })?;
-
-        let mut entries = Vec::new();
-        let mut offset = 0;
-
-        while offset < data.len() {
-            match WalEntry::from_bytes_at(&data, offset) {
-                Some((entry, consumed)) => {
-                    entries.push(entry);
-                    offset += consumed;
-                }
-                None => {
-                    // Corrupt or truncated entry at tail — stop replay
-                    break;
-                }
+        };
+        let tmp = self.path.with_extension("wal.tmp");
+        {
+            let mut file = File::create(&tmp).map_err(io_err)?;
+            file.write_all(&marker.to_bytes()).map_err(io_err)?;
+            for entry in carried {
+                file.write_all(&entry.to_bytes()).map_err(io_err)?;
             }
+            file.sync_all().map_err(io_err)?;
         }
-
-        Ok(entries)
+        fs::rename(&tmp, &self.path).map_err(io_err)?;
+        self.file = OpenOptions::new()
+            .append(true)
+            .read(true)
+            .open(&self.path)
+            .map_err(io_err)?;
+        self.entry_count = 1 + carried.len();
+        Ok(())
     }
 
-    /// Truncates (clears) the WAL file after a successful checkpoint.
+    /// Truncates (clears) the WAL file.
     ///
     /// # Errors
     ///
@@ -340,6 +497,7 @@ impl StrandWal {
 ///     strand_id: 0,
 ///     op: WalOp::Store,
 ///     payload: vec![],
+///     timestamp: 0,
 /// }).unwrap();
 /// wal.sync_all().unwrap();
 /// ```
@@ -347,6 +505,8 @@ impl StrandWal {
 pub struct WalManager {
     dir: PathBuf,
     wals: HashMap<u64, StrandWal>,
+    /// Timestamp of the last entry stamped by [`WalManager::log_entry`].
+    last_timestamp: u64,
 }
 
 impl WalManager {
@@ -386,15 +546,75 @@ impl WalManager {
         Ok(Self {
             dir: dir.to_path_buf(),
             wals,
+            last_timestamp: 0,
         })
     }
 
+    /// Reads every WAL file in `dir` without opening any for writing,
+    /// returning entries grouped by strand. A missing directory has no
+    /// entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the directory or a WAL file
+    /// cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::wal::WalManager;
+    ///
+    /// let dir = std::env::temp_dir().join("volt_wal_doc_read_dir_missing");
+    /// assert!(WalManager::read_dir(&dir).unwrap().is_empty());
+    /// ```
+    pub fn read_dir(dir: &Path) -> Result<HashMap<u64, Vec<WalEntry>>, VoltError> {
+        let mut result = HashMap::new();
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(result),
+            Err(e) => {
+                return Err(VoltError::StorageError {
+                    message: format!("failed to read WAL directory {}: {e}", dir.display()),
+                });
+            }
+        };
+        for entry in entries {
+            let entry = entry.map_err(|e| VoltError::StorageError {
+                message: format!("failed to read WAL directory entry: {e}"),
+            })?;
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy();
+            if let Some(id_str) = name
+                .strip_prefix("strand_")
+                .and_then(|s| s.strip_suffix(".wal"))
+                && let Ok(strand_id) = id_str.parse::<u64>()
+            {
+                let wal_entries = read_entries(&entry.path(), strand_id)?;
+                if !wal_entries.is_empty() {
+                    result.insert(strand_id, wal_entries);
+                }
+            }
+        }
+        Ok(result)
+    }
+
     /// Logs a WAL entry, creating the strand's WAL file if needed.
     ///
+    /// An entry with a `timestamp` of 0 is stamped with the current time,
+    /// bumped past the previous stamp if needed so stamps never repeat.
+    ///
     /// # Errors
     ///
     /// Returns [`VoltError::StorageError`] if the write fails.
-    pub fn log_entry(&mut self, entry: WalEntry) -> Result<(), VoltError> {
+    pub fn log_entry(&mut self, mut entry: WalEntry) -> Result<(), VoltError> {
+        if entry.timestamp == 0 {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_micros() as u64)
+                .unwrap_or(0);
+            entry.timestamp = now.max(self.last_timestamp + 1);
+            self.last_timestamp = entry.timestamp;
+        }
         let strand_id = entry.strand_id;
         let wal = self.get_or_create_wal(strand_id)?;
         wal.append(&entry)
@@ -430,27 +650,112 @@ impl WalManager {
         Ok(result)
     }
 
-    /// Truncates the WAL for a strand after a successful T2 flush.
+    /// Replays all WAL files merged into one list ordered by timestamp.
+    ///
+    /// Entries with equal timestamps keep their log order, lower strand
+    /// IDs first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if any file read fails.
+    pub fn replay_ordered(&self) -> Result<Vec<WalEntry>, VoltError> {
+        Ok(merge_by_timestamp(self.replay_all()?))
+    }
+
+    /// Records a checkpoint in every strand's WAL and discards the
+    /// entries logged before it.
+    ///
+    /// Call it only once everything logged so far, except `carried`, is
+    /// durable elsewhere (T1 saved to disk); the rest is gone afterwards.
+    /// Each log is left holding a [`WalOp::Checkpoint`] marker whose
+    /// `frame_id` is `next_frame_id`, so IDs handed out before the
+    /// checkpoint stay reserved on replay even if their frames were
+    /// deleted, followed by that strand's `carried` entries (state that is
+    /// not on disk yet, such as T0 frames). Marker and carried entries go
+    /// into the log in one atomic rename, so a crash never leaves a log
+    /// that has dropped the old entries without the carried ones. Carried
+    /// entries keep their timestamps; a `timestamp` of 0 is stamped with
+    /// the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if a log cannot be rewritten.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_db::wal::{WalEntry, WalManager, WalOp};
+    ///
+    /// let dir = std::env::temp_dir().join("volt_wal_doc_checkpoint");
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// let mut wal = WalManager::open(&dir).unwrap();
+    /// wal.log_entry(WalEntry {
+    ///     frame_id: 1,
+    ///     strand_id: 0,
+    ///     op: WalOp::Store,
+    ///     payload: vec![],
+    ///     timestamp: 0,
+    /// }).unwrap();
+    ///
+    /// wal.checkpoint(2, &[]).unwrap();
+    /// let replayed = &wal.replay_all().unwrap()[&0];
+    /// assert_eq!(replayed.len(), 1);
+    /// assert_eq!(replayed[0].op, WalOp::Checkpoint);
+    /// assert_eq!(replayed[0].frame_id, 2);
+    /// let _ = std::fs::remove_dir_all(&dir);
+    /// ```
+    pub fn checkpoint(&mut self, next_frame_id: u64, carried: &[WalEntry]) -> Result<(), VoltError> {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+        let mut by_strand: HashMap<u64, Vec<WalEntry>> = HashMap::new();
+        for entry in carried {
+            let mut entry = entry.clone();
+            if entry.timestamp == 0 {
+                entry.timestamp = timestamp;
+            }
+            by_strand.entry(entry.strand_id).or_default().push(entry);
+        }
+        for &strand_id in by_strand.keys() {
+            self.get_or_create_wal(strand_id)?;
+        }
+        for (&strand_id, wal) in &mut self.wals {
+            let marker = WalEntry {
+                frame_id: next_frame_id,
+                strand_id,
+                op: WalOp::Checkpoint,
+                payload: Vec::new(),
+                timestamp,
+            };
+            let entries = by_strand.get(&strand_id).map_or(&[][..], Vec::as_slice);
+            wal.reset_to(&marker, entries)?;
+        }
+        Ok(())
+    }
+
+    /// Truncates the WAL for a strand, without leaving a checkpoint
+    /// marker.
     ///
     /// # Errors
     ///
     /// Returns [`VoltError::StorageError`] if truncation fails.
-    pub fn checkpoint(&mut self, strand_id: u64) -> Result<(), VoltError> {
+    pub fn truncate_strand(&mut self, strand_id: u64) -> Result<(), VoltError> {
         if let Some(wal) = self.wals.get_mut(&strand_id) {
             wal.truncate()?;
         }
         Ok(())
     }
 
-    /// Truncates all WAL files.
+    /// Truncates all WAL files, without leaving checkpoint markers.
     ///
     /// # Errors
     ///
     /// Returns [`VoltError::StorageError`] if any truncation fails.
-    pub fn checkpoint_all(&mut self) -> Result<(), VoltError> {
+    pub fn truncate_all(&mut self) -> Result<(), VoltError> {
         let strand_ids: Vec<u64> = self.wals.keys().copied().collect();
         for strand_id in strand_ids {
-            self.checkpoint(strand_id)?;
+            self.truncate_strand(strand_id)?;
         }
         Ok(())
     }
@@ -485,7 +790,13 @@ mod tests {
 
     #[test]
     fn wal_op_roundtrip() {
-        for op in [WalOp::Store, WalOp::Compress, WalOp::Gist, WalOp::Tombstone] {
+        for op in [
+            WalOp::Store,
+            WalOp::Compress,
+            WalOp::Gist,
+            WalOp::Tombstone,
+            WalOp::Reassign,
+        ] {
             assert_eq!(WalOp::from_tag(op.tag()), Some(op));
         }
         assert_eq!(WalOp::from_tag(99), None);
@@ -498,6 +809,7 @@ mod tests {
             strand_id: 1,
             op: WalOp::Store,
             payload: vec![10, 20, 30, 40, 50],
+            timestamp: 1_700_000_000_000_000,
         };
         let bytes = entry.to_bytes();
         let (restored, consumed) = WalEntry::from_bytes_at(&bytes, 0).unwrap();
@@ -507,6 +819,29 @@ mod tests {
         assert_eq!(restored.strand_id, 1);
         assert_eq!(restored.op, WalOp::Store);
         assert_eq!(restored.payload, vec![10, 20, 30, 40, 50]);
+        assert_eq!(restored.timestamp, 1_700_000_000_000_000);
+    }
+
+    #[test]
+    fn entry_without_timestamp_decodes_as_zero() {
+        // Pre-timestamp layout: header, payload, then the CRC directly
+        let payload = [7u8, 8, 9];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(21u32 + payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&5u64.to_le_bytes());
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.push(WalOp::Store.tag());
+        bytes.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        let mut hasher = Hasher::new();
+        hasher.update(&bytes);
+        bytes.extend_from_slice(&hasher.finalize().to_le_bytes());
+
+        let (restored, consumed) = WalEntry::from_bytes_at(&bytes, 0).unwrap();
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(restored.frame_id, 5);
+        assert_eq!(restored.payload, payload);
+        assert_eq!(restored.timestamp, 0);
     }
 
     #[test]
@@ -520,6 +855,7 @@ mod tests {
                 strand_id: 0,
                 op: WalOp::Store,
                 payload: vec![i as u8],
+                timestamp: 0,
             })
             .unwrap();
         }
@@ -550,6 +886,7 @@ mod tests {
                 strand_id: 0,
                 op: WalOp::Store,
                 payload: vec![i as u8; 10],
+                timestamp: 0,
             })
             .unwrap();
         }
@@ -580,6 +917,7 @@ mod tests {
             strand_id: 0,
             op: WalOp::Store,
             payload: vec![0; 20],
+            timestamp: 0,
         })
         .unwrap();
         wal.log_entry(WalEntry {
@@ -587,6 +925,7 @@ mod tests {
             strand_id: 0,
             op: WalOp::Store,
             payload: vec![1; 20],
+            timestamp: 0,
         })
         .unwrap();
         wal.sync_all().unwrap();
@@ -611,8 +950,8 @@ mod tests {
     }
 
     #[test]
-    fn checkpoint_truncates() {
-        let dir = temp_dir("checkpoint");
+    fn truncate_strand_clears_log() {
+        let dir = temp_dir("truncate");
         let mut wal = WalManager::open(&dir).unwrap();
 
         for i in 0..10u64 {
@@ -621,12 +960,13 @@ mod tests {
                 strand_id: 0,
                 op: WalOp::Store,
                 payload: vec![],
+                timestamp: 0,
             })
             .unwrap();
         }
         wal.sync_all().unwrap();
 
-        wal.checkpoint(0).unwrap();
+        wal.truncate_strand(0).unwrap();
 
         // Reopen — should have 0 entries
         let wal2 = WalManager::open(&dir).unwrap();
@@ -636,6 +976,105 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn checkpoint_drops_earlier_entries_across_strands() {
+        let dir = temp_dir("checkpoint");
+        let mut wal = WalManager::open(&dir).unwrap();
+        let log = |wal: &mut WalManager, frame_id: u64, strand_id: u64| {
+            wal.log_entry(WalEntry {
+                frame_id,
+                strand_id,
+                op: WalOp::Store,
+                payload: vec![],
+                timestamp: 0,
+            })
+            .unwrap();
+        };
+
+        for i in 0..6u64 {
+            log(&mut wal, i, i % 2);
+        }
+        let size_before = fs::metadata(dir.join("strand_0.wal")).unwrap().len();
+        wal.checkpoint(6, &[]).unwrap();
+        assert!(fs::metadata(dir.join("strand_0.wal")).unwrap().len() < size_before);
+        log(&mut wal, 6, 1);
+        wal.sync_all().unwrap();
+
+        let replayed = WalManager::open(&dir).unwrap().replay_all().unwrap();
+        let ops = |strand: u64| -> Vec<(WalOp, u64)> {
+            replayed[&strand].iter().map(|e| (e.op, e.frame_id)).collect()
+        };
+        assert_eq!(ops(0), vec![(WalOp::Checkpoint, 6)]);
+        assert_eq!(ops(1), vec![(WalOp::Checkpoint, 6), (WalOp::Store, 6)]);
+        assert!(!dir.join("strand_0.wal.tmp").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn checkpoint_writes_carried_entries_after_marker() {
+        let dir = temp_dir("checkpoint_carried");
+        let mut wal = WalManager::open(&dir).unwrap();
+        let entry = |frame_id: u64, strand_id: u64, timestamp: u64| WalEntry {
+            frame_id,
+            strand_id,
+            op: WalOp::Store,
+            payload: vec![frame_id as u8],
+            timestamp,
+        };
+        wal.log_entry(entry(1, 0, 0)).unwrap();
+
+        // Strand 2 has no log yet; the checkpoint creates one
+        wal.checkpoint(4, &[entry(2, 0, 111), entry(3, 2, 222)]).unwrap();
+
+        let replayed = WalManager::open(&dir).unwrap().replay_all().unwrap();
+        let summary = |strand: u64| -> Vec<(WalOp, u64, u64)> {
+            replayed[&strand]
+                .iter()
+                .map(|e| (e.op, e.frame_id, e.timestamp))
+                .collect()
+        };
+        let marker_ts = replayed[&0][0].timestamp;
+        assert_eq!(
+            summary(0),
+            vec![(WalOp::Checkpoint, 4, marker_ts), (WalOp::Store, 2, 111)]
+        );
+        assert_eq!(
+            summary(2),
+            vec![(WalOp::Checkpoint, 4, marker_ts), (WalOp::Store, 3, 222)]
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn replay_starts_at_last_marker() {
+        let dir = temp_dir("marker_without_rewrite");
+        let mut wal = WalManager::open(&dir).unwrap();
+        for (frame_id, op) in [
+            (1, WalOp::Store),
+            (2, WalOp::Checkpoint),
+            (2, WalOp::Store),
+            (3, WalOp::Checkpoint),
+            (3, WalOp::Store),
+        ] {
+            wal.log_entry(WalEntry {
+                frame_id,
+                strand_id: 0,
+                op,
+                payload: vec![],
+                timestamp: 0,
+            })
+            .unwrap();
+        }
+
+        let replayed = &wal.replay_all().unwrap()[&0];
+        let ops: Vec<(WalOp, u64)> = replayed.iter().map(|e| (e.op, e.frame_id)).collect();
+        assert_eq!(ops, vec![(WalOp::Checkpoint, 3), (WalOp::Store, 3)]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn multiple_strands() {
         let dir = temp_dir("multi_strand");
@@ -647,6 +1086,7 @@ mod tests {
                 strand_id: 0,
                 op: WalOp::Store,
                 payload: vec![],
+                timestamp: 0,
             })
             .unwrap();
         }
@@ -656,6 +1096,7 @@ mod tests {
                 strand_id: 1,
                 op: WalOp::Store,
                 payload: vec![],
+                timestamp: 0,
             })
             .unwrap();
         }
@@ -671,6 +1112,31 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn replay_ordered_interleaves_strands_in_log_order() {
+        let dir = temp_dir("replay_ordered");
+        let mut wal = WalManager::open(&dir).unwrap();
+        // Logged faster than the clock ticks, alternating strands
+        for i in 0..20u64 {
+            wal.log_entry(WalEntry {
+                frame_id: i,
+                strand_id: i % 3,
+                op: WalOp::Store,
+                payload: vec![],
+                timestamp: 0,
+            })
+            .unwrap();
+        }
+        wal.sync_all().unwrap();
+
+        let entries = WalManager::open(&dir).unwrap().replay_ordered().unwrap();
+        let ids: Vec<u64> = entries.iter().map(|e| e.frame_id).collect();
+        assert_eq!(ids, (0..20).collect::<Vec<_>>());
+        assert!(entries.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn empty_wal_replays_empty() {
         let dir = temp_dir("empty_replay");
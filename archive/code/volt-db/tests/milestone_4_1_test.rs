@@ -7,6 +7,7 @@
 //! - Restart server → T1 persists (serialize/deserialize)
 //! - Retrieval by ID performance (< 0.1ms)
 
+use volt_core::slot::SlotSource;
 use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
 use volt_db::tier0::T0_CAPACITY;
 use volt_db::VoltStore;
@@ -17,6 +18,7 @@ fn make_test_frame() -> TensorFrame {
     let mut slot = SlotData::new(SlotRole::Agent);
     slot.write_resolution(0, [0.42; SLOT_DIM]);
     frame.write_slot(0, slot).unwrap();
+    frame.meta[0].source = SlotSource::Translator;
     frame
 }
 
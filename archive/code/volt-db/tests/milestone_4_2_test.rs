@@ -8,6 +8,7 @@
 //! 4. Temporal range queries work correctly
 //! 5. HNSW query performance at scale
 
+use volt_core::slot::SlotSource;
 use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
 use volt_db::VoltStore;
 
@@ -24,6 +25,7 @@ fn make_directional_frame(direction_dim: usize) -> TensorFrame {
 
     slot.write_resolution(0, vector);
     frame.write_slot(0, slot).unwrap();
+    frame.meta[0].source = SlotSource::Translator;
     frame
 }
 
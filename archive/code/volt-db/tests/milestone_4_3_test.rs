@@ -14,8 +14,10 @@
 
 use std::path::PathBuf;
 
+use volt_core::slot::SlotSource;
 use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
 use volt_db::compressed::{compress, to_gist_frame, to_tombstone, DecayLevel, FrameEntry};
+use volt_db::consolidation::ConsolidationConfig;
 use volt_db::gc::{GcConfig, GcEngine};
 use volt_db::wal::{WalEntry, WalManager, WalOp};
 use volt_db::{
@@ -29,6 +31,7 @@ fn make_frame(gamma: f32, value: f32) -> TensorFrame {
     let mut slot = SlotData::new(SlotRole::Agent);
     slot.write_resolution(0, [value; SLOT_DIM]);
     frame.write_slot(0, slot).unwrap();
+    frame.meta[0].source = SlotSource::Translator;
     frame.frame_meta.global_certainty = gamma;
     frame
 }
@@ -192,6 +195,7 @@ fn wal_crash_recovery() {
                         strand_id: 0,
                         op: WalOp::Store,
                         payload: entry_data,
+                        timestamp: 0,
                     })
                     .unwrap();
                 }
@@ -219,6 +223,11 @@ fn wal_crash_recovery() {
                 );
             }
 
+            // Every recovered frame must also be indexed
+            assert_eq!(store.hnsw_entries(), 5);
+            let report = store.verify_consistency().unwrap();
+            assert!(report.is_consistent(), "{report:?}");
+
             let _ = std::fs::remove_dir_all(&dir);
         })
         .unwrap()
@@ -253,6 +262,7 @@ fn wal_corrupt_tail_recovery() {
                         strand_id: 0,
                         op: WalOp::Store,
                         payload: entry_data,
+                        timestamp: 0,
                     })
                     .unwrap();
                 }
@@ -442,6 +452,87 @@ fn consolidation_creates_wisdom_frames() {
     }
 }
 
+#[test]
+fn consolidation_supersedes_unpinned_sources() {
+    std::thread::Builder::new()
+        .name("consolidation_supersede".into())
+        .stack_size(8 * 1024 * 1024)
+        .spawn(|| {
+            let dir = temp_dir("consolidation_supersede");
+            let config = VoltStoreConfig {
+                data_dir: dir.clone(),
+                t1_overflow_threshold: 2000,
+                t2_config: T2Config {
+                    data_dir: dir.join("t2"),
+                    ..T2Config::default()
+                },
+                consolidation_config: ConsolidationConfig {
+                    supersede_sources: true,
+                    ..ConsolidationConfig::default()
+                },
+                ..VoltStoreConfig::default()
+            };
+            let mut store = VoltStore::open(config.clone()).unwrap();
+
+            // The first frames overflow from T0 into T1, where consolidation looks
+            let ids: Vec<u64> = (0..80)
+                .map(|_| store.store(make_frame(0.5, 0.5)).unwrap())
+                .collect();
+            let pinned = ids[0];
+            store.pin_frame(pinned);
+
+            let result = store.consolidate_strand(0).unwrap();
+            assert!(!result.wisdom_frames.is_empty());
+            assert!(!result.superseded_frame_ids.is_empty());
+            assert!(!result.superseded_frame_ids.contains(&pinned));
+
+            for wisdom in &result.wisdom_frames {
+                let wisdom_id = wisdom.frame_meta.frame_id;
+                assert!(!wisdom.frame_meta.derived_from.is_empty());
+                for &source in &wisdom.frame_meta.derived_from {
+                    if source == pinned {
+                        continue;
+                    }
+                    match store.get_entry_by_id(source) {
+                        Some(FrameEntry::Tombstone(ts)) => {
+                            assert_eq!(ts.superseded_by, Some(wisdom_id));
+                        }
+                        other => panic!("source {source} not tombstoned: {other:?}"),
+                    }
+                }
+            }
+
+            // The pinned source contributed but stays a full frame
+            assert!(
+                result
+                    .wisdom_frames
+                    .iter()
+                    .any(|w| w.frame_meta.derived_from.contains(&pinned))
+            );
+            assert_eq!(
+                store.get_entry_by_id(pinned).unwrap().decay_level(),
+                DecayLevel::Full
+            );
+
+            // Reopen without a clean shutdown: the WAL keeps sources
+            // superseded and their links intact
+            drop(store);
+            let store = VoltStore::open(config).unwrap();
+            for &source in &result.superseded_frame_ids {
+                assert!(store.get_by_id(source).is_none());
+                match store.get_entry_by_id(source) {
+                    Some(FrameEntry::Tombstone(ts)) => assert!(ts.superseded_by.is_some()),
+                    other => panic!("source {source} resurrected: {other:?}"),
+                }
+            }
+
+            let _ = std::fs::remove_dir_all(&dir);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
 // ---------------------------------------------------------------------------
 // Test 8: Bloom filter effectiveness
 // ---------------------------------------------------------------------------
@@ -0,0 +1,377 @@
+//! Async Hard Strands — I/O-bound tools driven from the sync pipeline.
+//!
+//! CPU strands implement [`HardStrand`] and stay pure. Strands that need
+//! external data (network, clocks) implement [`AsyncHardStrand`] instead
+//! and are wrapped in an [`AsyncStrandAdapter`], which drives their
+//! future on a multi-thread tokio runtime through a [`Handle`]. The adapter is itself a
+//! [`HardStrand`], so the [`IntentRouter`](crate::router::IntentRouter)
+//! holds both kinds side by side and synchronous pipeline callers need no
+//! changes.
+//!
+//! Async strands are not deterministic: the adapter always reports
+//! `deterministic: false` in its [`ModuleInfo`], so their results never
+//! claim the Hard Core's provable guarantee.
+//!
+//! ## Feature Gate
+//!
+//! This module is behind `#[cfg(feature = "async")]`, which pulls in tokio.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::runtime::{Handle, RuntimeFlavor};
+use volt_core::module_info::{ModuleInfo, ModuleType};
+use volt_core::{TensorFrame, VoltError, SLOT_DIM};
+
+use crate::strand::{HardStrand, StrandResult};
+
+/// The boxed future returned by [`AsyncHardStrand::execute`].
+pub type StrandFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<StrandResult, VoltError>> + Send + 'a>>;
+
+/// An I/O-bound Hard Strand whose processing is asynchronous.
+///
+/// Mirrors [`HardStrand`] except that [`execute`](Self::execute) returns a
+/// future. Implement it with `Box::pin(async move { ... })`.
+///
+/// # Example
+///
+/// ```
+/// use volt_hard::async_strand::{AsyncHardStrand, StrandFuture};
+/// use volt_hard::strand::StrandResult;
+/// use volt_core::{TensorFrame, SLOT_DIM};
+///
+/// struct EchoStrand;
+///
+/// impl AsyncHardStrand for EchoStrand {
+///     fn name(&self) -> &str { "echo" }
+///     fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+///         static V: [f32; SLOT_DIM] = [0.0; SLOT_DIM];
+///         &V
+///     }
+///     fn threshold(&self) -> f32 { 0.5 }
+///     fn execute<'a>(&'a self, frame: &'a TensorFrame) -> StrandFuture<'a> {
+///         Box::pin(async move {
+///             Ok(StrandResult {
+///                 frame: frame.clone(),
+///                 activated: false,
+///                 operation: None,
+///                 description: "echo: no-op".to_string(),
+///             })
+///         })
+///     }
+/// }
+/// ```
+pub trait AsyncHardStrand: Send + Sync {
+    /// Human-readable name for this strand (e.g., "weather_api").
+    fn name(&self) -> &str;
+
+    /// The 256-dimensional capability vector used for routing.
+    fn capability_vector(&self) -> &[f32; SLOT_DIM];
+
+    /// Minimum cosine similarity required for this strand to activate.
+    fn threshold(&self) -> f32;
+
+    /// Process a frame asynchronously and return the result.
+    fn execute<'a>(&'a self, frame: &'a TensorFrame) -> StrandFuture<'a>;
+
+    /// Optional metadata about this module.
+    ///
+    /// The `deterministic` flag is overridden to `false` by
+    /// [`AsyncStrandAdapter`].
+    fn info(&self) -> Option<ModuleInfo> {
+        None
+    }
+}
+
+/// Runs an [`AsyncHardStrand`] as a synchronous [`HardStrand`].
+///
+/// Each `process` call blocks on the strand's future using the given
+/// runtime handle. When called from inside a tokio runtime (where
+/// blocking the current thread would panic), the future is driven from a
+/// short-lived helper thread instead.
+///
+/// The handle must belong to a multi-thread runtime. A current-thread
+/// runtime only makes progress inside its own `block_on`, so the helper
+/// thread would wait on it forever; `process` returns an error instead.
+///
+/// # Example
+///
+/// ```
+/// use volt_hard::async_strand::{AsyncHardStrand, AsyncStrandAdapter, StrandFuture};
+/// use volt_hard::strand::{HardStrand, StrandResult};
+/// use volt_core::{TensorFrame, SLOT_DIM};
+///
+/// struct EchoStrand;
+///
+/// impl AsyncHardStrand for EchoStrand {
+///     fn name(&self) -> &str { "echo" }
+///     fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+///         static V: [f32; SLOT_DIM] = [0.0; SLOT_DIM];
+///         &V
+///     }
+///     fn threshold(&self) -> f32 { 0.5 }
+///     fn execute<'a>(&'a self, frame: &'a TensorFrame) -> StrandFuture<'a> {
+///         Box::pin(async move {
+///             Ok(StrandResult {
+///                 frame: frame.clone(),
+///                 activated: false,
+///                 operation: None,
+///                 description: "echo: no-op".to_string(),
+///             })
+///         })
+///     }
+/// }
+///
+/// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+///     let runtime = tokio::runtime::Runtime::new().unwrap();
+///     let strand = AsyncStrandAdapter::new(Box::new(EchoStrand), runtime.handle().clone());
+///     let result = strand.process(&TensorFrame::new()).unwrap();
+///     assert!(!result.activated);
+///     assert!(!strand.info().unwrap().deterministic);
+/// }).unwrap().join().unwrap();
+/// ```
+pub struct AsyncStrandAdapter {
+    strand: Box<dyn AsyncHardStrand>,
+    handle: Handle,
+}
+
+impl std::fmt::Debug for AsyncStrandAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncStrandAdapter")
+            .field("strand", &self.strand.name())
+            .finish()
+    }
+}
+
+impl AsyncStrandAdapter {
+    /// Wraps `strand`, driving its futures on the runtime behind `handle`.
+    pub fn new(strand: Box<dyn AsyncHardStrand>, handle: Handle) -> Self {
+        Self { strand, handle }
+    }
+}
+
+impl HardStrand for AsyncStrandAdapter {
+    fn name(&self) -> &str {
+        self.strand.name()
+    }
+
+    fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+        self.strand.capability_vector()
+    }
+
+    fn threshold(&self) -> f32 {
+        self.strand.threshold()
+    }
+
+    fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+        if self.handle.runtime_flavor() == RuntimeFlavor::CurrentThread {
+            return Err(VoltError::FrameError {
+                message: format!(
+                    "async strand '{}' needs a multi-thread tokio runtime",
+                    self.strand.name()
+                ),
+            });
+        }
+
+        let future = self.strand.execute(frame);
+        if Handle::try_current().is_err() {
+            return self.handle.block_on(future);
+        }
+
+        // Blocking a runtime thread panics, so block on a helper thread.
+        std::thread::scope(|scope| {
+            std::thread::Builder::new()
+                .stack_size(4 * 1024 * 1024)
+                .spawn_scoped(scope, || self.handle.block_on(future))
+                .map_err(|e| VoltError::FrameError {
+                    message: format!("failed to spawn async strand thread: {e}"),
+                })?
+                .join()
+                .map_err(|_| VoltError::FrameError {
+                    message: format!("async strand '{}' panicked", self.strand.name()),
+                })?
+        })
+    }
+
+    fn info(&self) -> Option<ModuleInfo> {
+        let name = self.strand.name();
+        let mut info = self.strand.info().unwrap_or_else(|| ModuleInfo {
+            id: name.to_string(),
+            display_name: name.to_string(),
+            version: String::new(),
+            author: String::new(),
+            description: format!("async strand {name}"),
+            module_type: ModuleType::HardStrand,
+            min_core_version: String::new(),
+            requires: vec![],
+            deterministic: false,
+        });
+        info.deterministic = false;
+        Some(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::HardCorePipeline;
+    use crate::router::IntentRouter;
+    use volt_core::{SlotData, SlotRole};
+
+    const RESULT_SLOT: usize = 8;
+
+    /// Async strand that yields once, then writes a canned reading.
+    struct MockFetchStrand {
+        capability: [f32; SLOT_DIM],
+        claims_deterministic: bool,
+    }
+
+    impl MockFetchStrand {
+        fn new(claims_deterministic: bool) -> Self {
+            let mut capability = [0.0; SLOT_DIM];
+            capability[7] = 1.0;
+            Self {
+                capability,
+                claims_deterministic,
+            }
+        }
+    }
+
+    impl AsyncHardStrand for MockFetchStrand {
+        fn name(&self) -> &str {
+            "mock_fetch"
+        }
+
+        fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+            &self.capability
+        }
+
+        fn threshold(&self) -> f32 {
+            0.5
+        }
+
+        fn execute<'a>(&'a self, frame: &'a TensorFrame) -> StrandFuture<'a> {
+            Box::pin(async move {
+                tokio::task::yield_now().await;
+                let mut result = frame.clone();
+                let mut data = [0.0_f32; SLOT_DIM];
+                data[0] = 21.5;
+                let mut slot = SlotData::new(SlotRole::Result);
+                slot.write_resolution(0, data);
+                result.write_slot(RESULT_SLOT, slot)?;
+                result.meta[RESULT_SLOT].certainty = 0.9;
+                Ok(StrandResult {
+                    frame: result,
+                    activated: true,
+                    operation: None,
+                    description: "mock_fetch: 21.5".to_string(),
+                })
+            })
+        }
+
+        fn info(&self) -> Option<ModuleInfo> {
+            self.claims_deterministic.then(|| ModuleInfo {
+                id: "mock-fetch".to_string(),
+                display_name: "Mock Fetch".to_string(),
+                version: "0.1.0".to_string(),
+                author: "Test".to_string(),
+                description: "Canned async result.".to_string(),
+                module_type: ModuleType::HardStrand,
+                min_core_version: String::new(),
+                requires: vec![],
+                deterministic: true,
+            })
+        }
+    }
+
+    fn query_frame(capability: [f32; SLOT_DIM]) -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        let mut pred = SlotData::new(SlotRole::Predicate);
+        pred.write_resolution(0, capability);
+        frame.write_slot(1, pred).unwrap();
+        frame.meta[1].certainty = 0.8;
+        frame
+    }
+
+    fn result_value(frame: &TensorFrame) -> f32 {
+        frame.read_slot(RESULT_SLOT).unwrap().resolutions[0].unwrap()[0]
+    }
+
+    #[test]
+    fn sync_pipeline_drives_async_strand() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                let strand = MockFetchStrand::new(false);
+                let frame = query_frame(strand.capability);
+
+                let mut router = crate::default_router();
+                router.register_async(Box::new(strand), runtime.handle().clone());
+                assert!(router.strand_names().contains(&"mock_fetch"));
+
+                let result = HardCorePipeline::new(router).process(&frame).unwrap();
+                assert!((result_value(&result.frame) - 21.5).abs() < 1e-6);
+                assert!(!result.proof.is_empty());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn adapter_works_inside_runtime_context() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                let strand = MockFetchStrand::new(false);
+                let frame = query_frame(strand.capability);
+                let mut router = IntentRouter::new();
+                router.register_async(Box::new(strand), runtime.handle().clone());
+
+                let _guard = runtime.enter();
+                let result = router.route(&frame).unwrap();
+                assert!((result_value(&result.frame) - 21.5).abs() < 1e-6);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn adapter_rejects_current_thread_runtime() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .build()
+                    .unwrap();
+                let strand = MockFetchStrand::new(false);
+                let frame = query_frame(strand.capability);
+                let adapter = AsyncStrandAdapter::new(Box::new(strand), runtime.handle().clone());
+
+                assert!(adapter.process(&frame).is_err());
+                runtime.block_on(async { assert!(adapter.process(&frame).is_err()) });
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn adapter_flags_module_as_nondeterministic() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        for claims in [false, true] {
+            let adapter = AsyncStrandAdapter::new(
+                Box::new(MockFetchStrand::new(claims)),
+                runtime.handle().clone(),
+            );
+            let info = adapter.info().unwrap();
+            assert!(!info.deterministic);
+            let expected_id = if claims { "mock-fetch" } else { "mock_fetch" };
+            assert_eq!(info.id, expected_id);
+        }
+    }
+}
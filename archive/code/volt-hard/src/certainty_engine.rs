@@ -6,7 +6,9 @@
 //!
 //! The CertaintyEngine is **not** a [`HardStrand`](crate::strand::HardStrand).
 //! It is pipeline infrastructure that runs on every frame after strand
-//! execution, unconditionally.
+//! execution, unconditionally. Strands may ask for a different
+//! [`PropagationRule`] via
+//! [`HardStrand::propagation_rule`](crate::strand::HardStrand::propagation_rule).
 //!
 //! # Example
 //!
@@ -32,7 +34,64 @@
 //! assert_eq!(frame.frame_meta.global_certainty, 0.6);
 //! ```
 
-use volt_core::{TensorFrame, MAX_SLOTS};
+use volt_core::{CertaintyRule, TensorFrame, MAX_SLOTS};
+
+/// How slot gammas are combined into the frame's global certainty.
+///
+/// # Example
+///
+/// ```
+/// use volt_hard::certainty_engine::PropagationRule;
+///
+/// assert_eq!(PropagationRule::default(), PropagationRule::Min);
+/// assert_eq!(PropagationRule::Override(1.0).op_name(), "override");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PropagationRule {
+    /// Global gamma is the minimum active slot gamma.
+    #[default]
+    Min,
+    /// Global gamma is the product of all active slot gammas.
+    Product,
+    /// Global gamma is this fixed value (clamped to `[0, 1]`),
+    /// regardless of slot gammas. Used by exact computations.
+    Override(f32),
+    /// Global gamma is the mean of active slot gammas, each weighted by
+    /// the number of resolutions the slot has filled.
+    WeightedMean,
+}
+
+impl PropagationRule {
+    /// Short snake-case name, used in proof op codes
+    /// (`"certainty_engine.<name>"`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::certainty_engine::PropagationRule;
+    ///
+    /// assert_eq!(PropagationRule::Min.op_name(), "min_rule");
+    /// assert_eq!(PropagationRule::WeightedMean.op_name(), "weighted_mean");
+    /// ```
+    pub fn op_name(&self) -> &'static str {
+        match self {
+            PropagationRule::Min => "min_rule",
+            PropagationRule::Product => "product",
+            PropagationRule::Override(_) => "override",
+            PropagationRule::WeightedMean => "weighted_mean",
+        }
+    }
+}
+
+impl From<CertaintyRule> for PropagationRule {
+    fn from(rule: CertaintyRule) -> Self {
+        match rule {
+            CertaintyRule::Min => PropagationRule::Min,
+            CertaintyRule::WeightedMean => PropagationRule::WeightedMean,
+            CertaintyRule::Product => PropagationRule::Product,
+        }
+    }
+}
 
 /// The result of certainty propagation across a frame.
 ///
@@ -158,6 +217,63 @@ impl CertaintyEngine {
     /// assert!((frame.frame_meta.global_certainty - 0.0).abs() < 0.01);
     /// ```
     pub fn compute(&self, frame: &TensorFrame) -> CertaintyResult {
+        self.compute_with(frame, PropagationRule::Min)
+    }
+
+    /// Propagate certainty across a frame using `rule`.
+    ///
+    /// Like [`propagate`](Self::propagate), but combines slot gammas with
+    /// the given [`PropagationRule`] instead of the min-rule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_hard::certainty_engine::{CertaintyEngine, PropagationRule};
+    ///
+    /// let engine = CertaintyEngine::new();
+    /// let mut frame = TensorFrame::new();
+    ///
+    /// let mut slot = SlotData::new(SlotRole::Agent);
+    /// slot.write_resolution(0, [0.5; SLOT_DIM]);
+    /// frame.write_slot(0, slot).unwrap();
+    /// frame.meta[0].certainty = 0.4;
+    ///
+    /// let result = engine.propagate_with(&mut frame, PropagationRule::Override(1.0));
+    /// assert_eq!(result.global_certainty, 1.0);
+    /// assert_eq!(frame.frame_meta.global_certainty, 1.0);
+    /// ```
+    pub fn propagate_with(&self, frame: &mut TensorFrame, rule: PropagationRule) -> CertaintyResult {
+        let result = self.compute_with(frame, rule);
+        frame.frame_meta.global_certainty = result.global_certainty;
+        result
+    }
+
+    /// Compute certainty under `rule` without modifying the frame.
+    ///
+    /// `weakest_slot` and `slot_gammas` are the same for every rule; only
+    /// `global_certainty` changes. A frame with no active slots has
+    /// global certainty 0.0 unless the rule is an override.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+    /// use volt_hard::certainty_engine::{CertaintyEngine, PropagationRule};
+    ///
+    /// let engine = CertaintyEngine::new();
+    /// let mut frame = TensorFrame::new();
+    /// for (i, gamma) in [(0, 0.5), (1, 0.8)] {
+    ///     let mut slot = SlotData::new(SlotRole::Agent);
+    ///     slot.write_resolution(0, [0.5; SLOT_DIM]);
+    ///     frame.write_slot(i, slot).unwrap();
+    ///     frame.meta[i].certainty = gamma;
+    /// }
+    ///
+    /// let result = engine.compute_with(&frame, PropagationRule::Product);
+    /// assert!((result.global_certainty - 0.4).abs() < 1e-6);
+    /// ```
+    pub fn compute_with(&self, frame: &TensorFrame, rule: PropagationRule) -> CertaintyResult {
         let mut min_gamma = f32::MAX;
         let mut weakest_slot = None;
         let mut slot_gammas = Vec::new();
@@ -173,10 +289,11 @@ impl CertaintyEngine {
             }
         }
 
-        let global = if min_gamma < f32::MAX {
-            min_gamma
-        } else {
-            0.0
+        let global = match rule {
+            PropagationRule::Override(gamma) => gamma.clamp(0.0, 1.0),
+            PropagationRule::Min => frame.derived_certainty(CertaintyRule::Min),
+            PropagationRule::Product => frame.derived_certainty(CertaintyRule::Product),
+            PropagationRule::WeightedMean => frame.derived_certainty(CertaintyRule::WeightedMean),
         };
 
         CertaintyResult {
@@ -325,6 +442,74 @@ mod tests {
         );
     }
 
+    fn frame_with_gammas(gammas: &[f32]) -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        for (i, &gamma) in gammas.iter().enumerate() {
+            let mut slot = SlotData::new(SlotRole::Agent);
+            slot.write_resolution(0, [0.5; SLOT_DIM]);
+            frame.write_slot(i, slot).unwrap();
+            frame.meta[i].certainty = gamma;
+        }
+        frame
+    }
+
+    #[test]
+    fn propagation_rule_product_multiplies() {
+        let engine = CertaintyEngine::new();
+        let mut frame = frame_with_gammas(&[0.9, 0.8, 0.5]);
+
+        let result = engine.propagate_with(&mut frame, PropagationRule::Product);
+
+        assert!((result.global_certainty - 0.36).abs() < 1e-6);
+        assert!((frame.frame_meta.global_certainty - 0.36).abs() < 1e-6);
+        assert_eq!(result.weakest_slot, Some(2));
+    }
+
+    #[test]
+    fn propagation_rule_override_ignores_slots() {
+        let engine = CertaintyEngine::new();
+        let frame = frame_with_gammas(&[0.2, 0.4]);
+
+        let result = engine.compute_with(&frame, PropagationRule::Override(1.0));
+        assert_eq!(result.global_certainty, 1.0);
+
+        let clamped = engine.compute_with(&frame, PropagationRule::Override(3.0));
+        assert_eq!(clamped.global_certainty, 1.0);
+        let empty = engine.compute_with(&TensorFrame::new(), PropagationRule::Override(0.7));
+        assert!((empty.global_certainty - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn propagation_rule_weighted_mean_weights_by_resolutions() {
+        let engine = CertaintyEngine::new();
+        let mut frame = frame_with_gammas(&[1.0, 0.4]);
+        // Slot 1 fills three resolutions, slot 0 only one.
+        let mut slot = frame.slots[1].take().unwrap();
+        slot.write_resolution(1, [0.5; SLOT_DIM]);
+        slot.write_resolution(2, [0.5; SLOT_DIM]);
+        frame.slots[1] = Some(slot);
+
+        let result = engine.compute_with(&frame, PropagationRule::WeightedMean);
+
+        // (1 * 1.0 + 3 * 0.4) / 4
+        assert!((result.global_certainty - 0.55).abs() < 1e-6);
+        assert_eq!(
+            engine.compute_with(&TensorFrame::new(), PropagationRule::WeightedMean).global_certainty,
+            0.0
+        );
+    }
+
+    #[test]
+    fn propagation_rule_min_matches_compute() {
+        let engine = CertaintyEngine::new();
+        let frame = frame_with_gammas(&[0.9, 0.3, 0.6]);
+
+        let default = engine.compute(&frame);
+        let min = engine.compute_with(&frame, PropagationRule::Min);
+        assert_eq!(default.global_certainty, min.global_certainty);
+        assert_eq!(default.weakest_slot, min.weakest_slot);
+    }
+
     #[test]
     fn certainty_engine_default_trait() {
         let engine = CertaintyEngine::default();
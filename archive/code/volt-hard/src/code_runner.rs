@@ -1,10 +1,27 @@
 //! CodeRunner Hard Strand — sandboxed code execution via `wasmtime`.
 //!
 //! Executes WebAssembly modules in a secure sandbox with:
-//! - **No WASI**: No filesystem, network, or clock access
+//! - **No filesystem or network**: all other WASI imports fail instantiation
+//! - **Deterministic host functions**: the nondeterministic WASI imports
+//!   are stubbed to fixed values (see below)
 //! - **Fuel limit**: Maximum 1 million instructions
 //! - **Memory limit**: Maximum 16 pages (1MB)
 //!
+//! ## Determinism
+//!
+//! The same module always produces the same output. The only host
+//! functions linked are these `wasi_snapshot_preview1` stubs:
+//!
+//! | Import | Behaviour |
+//! |--------|-----------|
+//! | `clock_time_get` | Always writes [`DETERMINISTIC_CLOCK_NANOS`] |
+//! | `clock_res_get` | Always writes a resolution of 1ns |
+//! | `random_get` | Fills the buffer from a SplitMix64 stream seeded with [`DETERMINISTIC_RANDOM_SEED`], restarted for every execution |
+//! | `environ_sizes_get` / `environ_get` | Report an empty environment |
+//!
+//! Stubs that need guest memory return WASI `EFAULT` (21) if the module
+//! exports no `memory` or the pointer is out of bounds.
+//!
 //! ## Slot Convention
 //!
 //! | Slot | Resolution | Meaning |
@@ -34,8 +51,9 @@
 use volt_core::{
     slot::SlotSource, SlotData, SlotMeta, SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM,
 };
-use wasmtime::{Config, Engine, Linker, Module, Store};
+use wasmtime::{Caller, Config, Engine, Extern, Linker, Module, Store};
 
+use crate::proof_constructor::ProofOperation;
 use crate::strand::{HardStrand, StrandResult};
 
 /// Operation code for code execution.
@@ -49,6 +67,45 @@ const RESULT_SLOT: usize = 8;
 /// Maximum fuel (instruction count) for sandboxed execution.
 const MAX_FUEL: u64 = 1_000_000;
 
+/// Timestamp (nanoseconds) reported by the stubbed `clock_time_get`.
+pub const DETERMINISTIC_CLOCK_NANOS: u64 = 0;
+
+/// Seed of the byte stream returned by the stubbed `random_get`.
+pub const DETERMINISTIC_RANDOM_SEED: u64 = 0x564F_4C54_5241_4E44; // "VOLTRAND"
+
+/// Bytes of guest memory `random_get` fills per chunk.
+const RANDOM_CHUNK_BYTES: usize = 4096;
+
+/// WASI module name for the stubbed host functions.
+const WASI_MODULE: &str = "wasi_snapshot_preview1";
+
+/// WASI errno: success.
+const ERRNO_SUCCESS: i32 = 0;
+/// WASI errno: bad address.
+const ERRNO_FAULT: i32 = 21;
+
+/// Per-execution sandbox state.
+struct SandboxState {
+    /// SplitMix64 state for `random_get`.
+    rng: u64,
+}
+
+impl SandboxState {
+    fn new() -> Self {
+        Self {
+            rng: DETERMINISTIC_RANDOM_SEED,
+        }
+    }
+
+    fn next_random(&mut self) -> u64 {
+        self.rng = self.rng.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
 /// The CodeRunner Hard Strand — sandboxed WASM execution.
 ///
 /// Activates when the frame contains a code execution request (op code 10.0)
@@ -176,7 +233,7 @@ impl CodeRunner {
             message: format!("code_runner: failed to compile module: {e}"),
         })?;
 
-        let mut store = Store::new(&self.engine, ());
+        let mut store = Store::new(&self.engine, SandboxState::new());
         store
             .set_fuel(MAX_FUEL)
             .map_err(|e| VoltError::StrandError {
@@ -184,8 +241,8 @@ impl CodeRunner {
                 message: format!("code_runner: failed to set fuel: {e}"),
             })?;
 
-        // No WASI — no filesystem, no network, no clock, no environ
-        let linker = Linker::new(&self.engine);
+        // Only deterministic WASI stubs — no filesystem, no network
+        let linker = self.deterministic_linker()?;
 
         let instance =
             linker
@@ -227,6 +284,62 @@ impl CodeRunner {
         Ok((result, stdout))
     }
 
+    /// Build a linker exposing only the deterministic WASI stubs.
+    fn deterministic_linker(&self) -> Result<Linker<SandboxState>, VoltError> {
+        let mut linker = Linker::new(&self.engine);
+        let link_err = |e: wasmtime::Error| VoltError::StrandError {
+            strand_id: 0,
+            message: format!("code_runner: failed to link deterministic stub: {e}"),
+        };
+
+        linker
+            .func_wrap(
+                WASI_MODULE,
+                "clock_time_get",
+                |mut caller: Caller<'_, SandboxState>, _id: i32, _precision: i64, ptr: i32| {
+                    write_guest(&mut caller, ptr, &DETERMINISTIC_CLOCK_NANOS.to_le_bytes())
+                },
+            )
+            .map_err(link_err)?;
+        linker
+            .func_wrap(
+                WASI_MODULE,
+                "clock_res_get",
+                |mut caller: Caller<'_, SandboxState>, _id: i32, ptr: i32| {
+                    write_guest(&mut caller, ptr, &1u64.to_le_bytes())
+                },
+            )
+            .map_err(link_err)?;
+        linker
+            .func_wrap(
+                WASI_MODULE,
+                "random_get",
+                |mut caller: Caller<'_, SandboxState>, ptr: i32, len: i32| {
+                    fill_guest_random(&mut caller, ptr, len)
+                },
+            )
+            .map_err(link_err)?;
+        linker
+            .func_wrap(
+                WASI_MODULE,
+                "environ_sizes_get",
+                |mut caller: Caller<'_, SandboxState>, count_ptr: i32, size_ptr: i32| {
+                    match write_guest(&mut caller, count_ptr, &0u32.to_le_bytes()) {
+                        ERRNO_SUCCESS => write_guest(&mut caller, size_ptr, &0u32.to_le_bytes()),
+                        errno => errno,
+                    }
+                },
+            )
+            .map_err(link_err)?;
+        linker
+            .func_wrap(WASI_MODULE, "environ_get", |_environ: i32, _buf: i32| {
+                ERRNO_SUCCESS
+            })
+            .map_err(link_err)?;
+
+        Ok(linker)
+    }
+
     /// Recompute global certainty as min of all active slot gammas.
     fn recompute_global_certainty(frame: &mut TensorFrame) {
         let mut min_gamma = f32::MAX;
@@ -265,6 +378,7 @@ impl HardStrand for CodeRunner {
                 return Ok(StrandResult {
                     frame: frame.clone(),
                     activated: false,
+                    operation: None,
                     description: "code_runner: no instrument slot data".to_string(),
                 });
             }
@@ -276,6 +390,7 @@ impl HardStrand for CodeRunner {
                 return Ok(StrandResult {
                     frame: frame.clone(),
                     activated: false,
+                    operation: None,
                     description: "code_runner: no R0 data in instrument slot".to_string(),
                 });
             }
@@ -286,6 +401,7 @@ impl HardStrand for CodeRunner {
             return Ok(StrandResult {
                 frame: frame.clone(),
                 activated: false,
+                operation: None,
                 description: format!(
                     "code_runner: not a code execution request (op={})",
                     r0_data[0]
@@ -333,13 +449,59 @@ impl HardStrand for CodeRunner {
             frame: result_frame,
             activated: true,
             description: format!(
-                "code_runner: executed WASM, exit={exit_code}, stdout={} bytes",
+                "code_runner: executed WASM in deterministic sandbox, exit={exit_code}, stdout={} bytes",
                 stdout.len()
             ),
+            operation: Some(ProofOperation::new(
+                "code_runner.exec_wasm",
+                vec![INSTRUMENT_SLOT],
+                vec![RESULT_SLOT],
+            )),
         })
     }
 }
 
+/// Write `bytes` into the guest's exported memory at `ptr`.
+///
+/// Returns a WASI errno: success, or `EFAULT` if there is no exported
+/// memory or the range is out of bounds.
+fn write_guest(caller: &mut Caller<'_, SandboxState>, ptr: i32, bytes: &[u8]) -> i32 {
+    let Some(Extern::Memory(memory)) = caller.get_export("memory") else {
+        return ERRNO_FAULT;
+    };
+    match memory.write(caller, ptr as u32 as usize, bytes) {
+        Ok(()) => ERRNO_SUCCESS,
+        Err(_) => ERRNO_FAULT,
+    }
+}
+
+/// Fill `len` bytes of guest memory at `ptr` from the sandbox's random
+/// stream, in place.
+///
+/// The range is bounds-checked before any byte is generated, so a guest
+/// cannot make the host allocate or loop over more than its own memory.
+/// Returns a WASI errno: success, or `EFAULT` if there is no exported
+/// memory or the range is out of bounds.
+fn fill_guest_random(caller: &mut Caller<'_, SandboxState>, ptr: i32, len: i32) -> i32 {
+    let Some(Extern::Memory(memory)) = caller.get_export("memory") else {
+        return ERRNO_FAULT;
+    };
+    let (data, state) = memory.data_and_store_mut(caller);
+    let start = ptr as u32 as usize;
+    let Some(end) = start
+        .checked_add(len as u32 as usize)
+        .filter(|&end| end <= data.len())
+    else {
+        return ERRNO_FAULT;
+    };
+    for chunk in data[start..end].chunks_mut(RANDOM_CHUNK_BYTES) {
+        for byte in chunk {
+            *byte = state.next_random() as u8;
+        }
+    }
+    ERRNO_SUCCESS
+}
+
 // CodeRunner holds a wasmtime Engine which is !Send on some platforms
 // but wasmtime::Engine is actually Send + Sync.
 // The HardStrand trait requires Send + Sync.
@@ -374,6 +536,33 @@ mod tests {
         )
     )"#;
 
+    /// WAT module that reads 4 random bytes and the clock, returning the
+    /// random word and reporting the timestamp's low byte as stdout.
+    const WAT_NONDETERMINISTIC: &str = r#"(module
+        (import "wasi_snapshot_preview1" "random_get"
+            (func $rand (param i32 i32) (result i32)))
+        (import "wasi_snapshot_preview1" "clock_time_get"
+            (func $clock (param i32 i64 i32) (result i32)))
+        (memory (export "memory") 1)
+        (func (export "run") (result i32)
+            (drop (call $clock (i32.const 0) (i64.const 1) (i32.const 16)))
+            (i32.store (i32.const 0) (i32.const 1))
+            (i32.store8 (i32.const 4) (i32.load8_u (i32.const 16)))
+            (drop (call $rand (i32.const 8) (i32.const 4)))
+            (i32.load (i32.const 8))
+        )
+    )"#;
+
+    /// WAT module importing a nondeterministic WASI function that is not
+    /// stubbed (should fail).
+    const WAT_POLL_ONEOFF: &str = r#"(module
+        (import "wasi_snapshot_preview1" "poll_oneoff"
+            (func $poll (param i32 i32 i32 i32) (result i32)))
+        (func (export "run") (result i32)
+            (i32.const 0)
+        )
+    )"#;
+
     /// WAT module with an infinite loop (should exhaust fuel).
     const WAT_INFINITE_LOOP: &str = r#"(module
         (func (export "run") (result i32)
@@ -546,4 +735,58 @@ mod tests {
         assert!(result.frame.frame_meta.verified);
         assert!(result.frame.frame_meta.proof_length >= 1);
     }
+
+    #[test]
+    fn code_runner_stubs_random_and_clock_deterministically() {
+        let runner = CodeRunner::new().unwrap();
+        let frame = make_code_frame(WAT_NONDETERMINISTIC);
+
+        let first = runner.process(&frame).unwrap();
+        let second = runner.process(&frame).unwrap();
+        assert!(first.activated);
+        assert!(first.description.contains("deterministic sandbox"));
+
+        let a = first.frame.read_slot(RESULT_SLOT).unwrap();
+        let b = second.frame.read_slot(RESULT_SLOT).unwrap();
+        assert_eq!(a.resolutions[0].unwrap(), b.resolutions[0].unwrap());
+        assert_eq!(a.resolutions[2].unwrap(), b.resolutions[2].unwrap());
+
+        // The random word matches the documented seeded stream
+        let mut state = SandboxState::new();
+        let expected =
+            i32::from_le_bytes(std::array::from_fn(|_| state.next_random() as u8));
+        assert_eq!(a.resolutions[0].unwrap()[0], expected as f32);
+
+        // The clock reads DETERMINISTIC_CLOCK_NANOS
+        assert_eq!(a.resolutions[2].unwrap()[0], DETERMINISTIC_CLOCK_NANOS as u8 as f32);
+    }
+
+    #[test]
+    fn code_runner_random_get_out_of_bounds_faults() {
+        // len = i32::MAX far exceeds the one-page memory
+        const WAT_HUGE_RANDOM: &str = r#"(module
+            (import "wasi_snapshot_preview1" "random_get"
+                (func $rand (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "run") (result i32)
+                (call $rand (i32.const 8) (i32.const 2147483647))
+            )
+        )"#;
+        let runner = CodeRunner::new().unwrap();
+        let result = runner.process(&make_code_frame(WAT_HUGE_RANDOM)).unwrap();
+        let slot = result.frame.read_slot(RESULT_SLOT).unwrap();
+        assert_eq!(slot.resolutions[0].unwrap()[0], ERRNO_FAULT as f32);
+    }
+
+    #[test]
+    fn code_runner_rejects_unstubbed_nondeterministic_import() {
+        let runner = CodeRunner::new().unwrap();
+        let frame = make_code_frame(WAT_POLL_ONEOFF);
+
+        let err = runner.process(&frame).unwrap_err().to_string();
+        assert!(
+            err.contains("instantiation failed"),
+            "poll_oneoff should be rejected, got: {err}"
+        );
+    }
 }
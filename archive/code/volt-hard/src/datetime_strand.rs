@@ -0,0 +1,624 @@
+//! DateTimeStrand Hard Strand — exact calendar arithmetic.
+//!
+//! Handles day offsets, weekday lookup, and the number of days between two
+//! dates on the proleptic Gregorian calendar. Like the
+//! [`MathEngine`](crate::math_engine::MathEngine), results are exact and
+//! written with gamma = 1.0.
+//!
+//! Dates are passed as three separate floats (year, month, day) because a
+//! packed `YYYYMMDD` value exceeds the integer precision of `f32`.
+//! Years are limited to −9999..=9999 and day counts to ±10,000,000;
+//! operands or results outside those ranges are rejected with an error.
+//!
+//! ## Slot Convention
+//!
+//! | Slot | Resolution | Dim | Meaning |
+//! |------|-----------|-----|---------|
+//! | S6 (Instrument) | R0 | dim\[0\] | Operation code (30.0–33.0) |
+//! | S6 (Instrument) | R0 | dim\[1..4\] | Date A (year, month, day) |
+//! | S6 (Instrument) | R0 | dim\[4\] | Day count (add/sub days) |
+//! | S6 (Instrument) | R0 | dim\[4..7\] | Date B (days between) |
+//! | S8 (Result) | R0 | dim\[0..3\] | Result date (add/sub days) |
+//! | S8 (Result) | R0 | dim\[0\] | Weekday, 0 = Monday (weekday) |
+//! | S8 (Result) | R0 | dim\[0\] | B − A in days (days between) |
+//! | S8 (Result) | R0 | dim\[3\] | 1.0 = valid result |
+//!
+//! ## Feature Gate
+//!
+//! This module is behind `#[cfg(feature = "datetime")]`.
+
+use volt_core::{
+    module_info::{ModuleInfo, ModuleType},
+    slot::{SlotMeta, SlotSource},
+    SlotData, SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM,
+};
+
+use crate::proof_constructor::ProofOperation;
+use crate::strand::{HardStrand, StrandResult};
+
+/// Operation codes for the DateTimeStrand protocol.
+const OP_ADD_DAYS: f32 = 30.0;
+const OP_SUB_DAYS: f32 = 31.0;
+const OP_WEEKDAY: f32 = 32.0;
+const OP_DAYS_BETWEEN: f32 = 33.0;
+
+/// Slot index for operation input (Instrument = S6).
+const INSTRUMENT_SLOT: usize = 6;
+/// Slot index for result output (Result = S8).
+const RESULT_SLOT: usize = 8;
+/// Dimension of the valid flag in the Result slot.
+const VALID_DIM: usize = 3;
+
+/// Supported years. Keeps day numbers well inside `i64` and every day
+/// difference exactly representable in `f32`.
+const YEAR_RANGE: std::ops::RangeInclusive<i64> = -9_999..=9_999;
+/// Largest accepted day count for add/sub days (covers the full year range).
+const MAX_DAY_COUNT: i64 = 10_000_000;
+
+/// A calendar date on the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Read and validate a date from three consecutive floats.
+    fn from_dims(dims: &[f32]) -> Result<Self, VoltError> {
+        let year = whole(dims[0], "year")?;
+        if !YEAR_RANGE.contains(&year) {
+            return Err(out_of_range_year(year));
+        }
+        let month = whole(dims[1], "month")?;
+        let day = whole(dims[2], "day")?;
+        if !(1..=12).contains(&month) {
+            return Err(strand_error(format!("invalid month {month}")));
+        }
+        let (month, day) = (month as u32, day as u32);
+        if day < 1 || day > days_in_month(year, month) {
+            return Err(strand_error(format!(
+                "invalid day {day} for {year}-{month:02}"
+            )));
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Days since 1970-01-01 (negative before the epoch).
+    ///
+    /// Uses Howard Hinnant's `days_from_civil` algorithm.
+    fn to_days(self) -> i64 {
+        let y = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let m = i64::from(self.month);
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Inverse of [`to_days`](Self::to_days).
+    fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self { year, month, day }
+    }
+
+    /// ISO weekday index: 0 = Monday … 6 = Sunday.
+    fn weekday(self) -> u32 {
+        // 1970-01-01 was a Thursday (index 3).
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Convert a float operand to an integer, rejecting fractions and non-finite values.
+///
+/// Values beyond `i64` saturate; callers bound the result before doing
+/// calendar arithmetic with it.
+fn whole(value: f32, what: &str) -> Result<i64, VoltError> {
+    if !value.is_finite() || value.fract() != 0.0 {
+        return Err(strand_error(format!(
+            "{what} must be a whole number, got {value}"
+        )));
+    }
+    Ok(value as i64)
+}
+
+fn out_of_range_year(year: i64) -> VoltError {
+    strand_error(format!(
+        "year {year} outside supported range {}..={}",
+        YEAR_RANGE.start(),
+        YEAR_RANGE.end()
+    ))
+}
+
+fn strand_error(message: String) -> VoltError {
+    VoltError::StrandError {
+        strand_id: 0,
+        message: format!("datetime: {message}"),
+    }
+}
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// The DateTimeStrand Hard Strand — exact calendar arithmetic.
+///
+/// Activates when the Instrument slot (S6) carries one of its operation
+/// codes; any other code passes through unactivated.
+///
+/// # Supported Operations
+///
+/// | Code | Operation    | Description                  |
+/// |------|--------------|------------------------------|
+/// | 30.0 | add_days     | A + n days                   |
+/// | 31.0 | sub_days     | A − n days                   |
+/// | 32.0 | weekday      | weekday of A (0 = Monday)    |
+/// | 33.0 | days_between | B − A in days                |
+///
+/// # Example
+///
+/// ```
+/// use volt_hard::datetime_strand::DateTimeStrand;
+/// use volt_hard::strand::HardStrand;
+/// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+///
+/// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+///     let strand = DateTimeStrand::new();
+///     let mut frame = TensorFrame::new();
+///     let mut inst = SlotData::new(SlotRole::Instrument);
+///     let mut data = [0.0_f32; SLOT_DIM];
+///     data[0] = 33.0; // OP_DAYS_BETWEEN
+///     data[1..4].copy_from_slice(&[2024.0, 1.0, 1.0]);
+///     data[4..7].copy_from_slice(&[2025.0, 1.0, 1.0]);
+///     inst.write_resolution(0, data);
+///     frame.write_slot(6, inst).unwrap();
+///     frame.meta[6].certainty = 0.9;
+///
+///     let result = strand.process(&frame).unwrap();
+///     assert!(result.activated);
+///     let r0 = result.frame.read_slot(8).unwrap().resolutions[0].unwrap();
+///     assert_eq!(r0[0], 366.0); // 2024 is a leap year
+/// }).unwrap().join().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateTimeStrand {
+    /// Pre-computed capability vector for routing.
+    capability: [f32; SLOT_DIM],
+}
+
+impl DateTimeStrand {
+    /// Create a new `DateTimeStrand` with a deterministic capability vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::datetime_strand::DateTimeStrand;
+    /// use volt_hard::strand::HardStrand;
+    ///
+    /// let strand = DateTimeStrand::new();
+    /// assert_eq!(strand.name(), "datetime");
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            capability: Self::build_capability_vector(),
+        }
+    }
+
+    /// Build the deterministic capability vector for date/time queries.
+    ///
+    /// Same splitmix64 pattern as the MathEngine with its own seed, so the
+    /// two vectors are near-orthogonal.
+    fn build_capability_vector() -> [f32; SLOT_DIM] {
+        const DATETIME_SEED: u64 = 0x4441_5445_5449_4d31; // "DATETIM1"
+        let mut v = [0.0_f32; SLOT_DIM];
+        for (i, val) in v.iter_mut().enumerate() {
+            let mut h = DATETIME_SEED.wrapping_mul(0xd2b7_4407_b1ce_6e93);
+            h = h.wrapping_add(i as u64);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+            h ^= h >> 33;
+            *val = ((h as f64 / u64::MAX as f64) * 2.0 - 1.0) as f32;
+        }
+        // L2 normalize
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 1e-10 {
+            for x in &mut v {
+                *x /= norm;
+            }
+        }
+        v
+    }
+
+    /// Stable proof op code for a DateTimeStrand operation code.
+    fn op_name(op_code: f32) -> Option<&'static str> {
+        [
+            (OP_ADD_DAYS, "datetime.add_days"),
+            (OP_SUB_DAYS, "datetime.sub_days"),
+            (OP_WEEKDAY, "datetime.weekday"),
+            (OP_DAYS_BETWEEN, "datetime.days_between"),
+        ]
+        .into_iter()
+        .find(|(code, _)| (op_code - code).abs() < 0.1)
+        .map(|(_, name)| name)
+    }
+
+    /// Execute the operation encoded in the Instrument slot.
+    ///
+    /// Returns the Result slot values (without the valid flag) and a
+    /// human-readable description.
+    fn execute_operation(data: &[f32; SLOT_DIM]) -> Result<([f32; 3], String), VoltError> {
+        let op_code = data[0];
+        let a = Date::from_dims(&data[1..4])?;
+
+        if (op_code - OP_WEEKDAY).abs() < 0.1 {
+            let weekday = a.weekday();
+            let name = WEEKDAY_NAMES[weekday as usize];
+            return Ok(([weekday as f32, 0.0, 0.0], format!("weekday({a}) = {name}")));
+        }
+
+        if (op_code - OP_DAYS_BETWEEN).abs() < 0.1 {
+            let b = Date::from_dims(&data[4..7])?;
+            let days = b.to_days() - a.to_days();
+            return Ok(([days as f32, 0.0, 0.0], format!("days({a} → {b}) = {days}")));
+        }
+
+        let n = whole(data[4], "day count")?;
+        if n.abs() > MAX_DAY_COUNT {
+            return Err(strand_error(format!(
+                "day count {n} exceeds ±{MAX_DAY_COUNT}"
+            )));
+        }
+        let (signed, symbol) = if (op_code - OP_ADD_DAYS).abs() < 0.1 {
+            (n, '+')
+        } else {
+            (-n, '-')
+        };
+        let r = Date::from_days(a.to_days() + signed);
+        if !YEAR_RANGE.contains(&r.year) {
+            return Err(out_of_range_year(r.year));
+        }
+        Ok((
+            [r.year as f32, r.month as f32, r.day as f32],
+            format!("{a} {symbol} {n} days = {r}"),
+        ))
+    }
+}
+
+impl Default for DateTimeStrand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HardStrand for DateTimeStrand {
+    fn name(&self) -> &str {
+        "datetime"
+    }
+
+    fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+        &self.capability
+    }
+
+    fn threshold(&self) -> f32 {
+        0.3
+    }
+
+    fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+        let inst_data = frame.slots[INSTRUMENT_SLOT]
+            .as_ref()
+            .and_then(|slot| slot.resolutions[0].as_ref());
+
+        let Some(r0) = inst_data else {
+            return Ok(StrandResult {
+                frame: frame.clone(),
+                activated: false,
+                operation: None,
+                description: "datetime: no instrument data".to_string(),
+            });
+        };
+
+        let op_code = r0[0];
+        let Some(op_name) = Self::op_name(op_code) else {
+            return Ok(StrandResult {
+                frame: frame.clone(),
+                activated: false,
+                operation: None,
+                description: format!("datetime: unrecognized op code {op_code}"),
+            });
+        };
+
+        let (values, description) = Self::execute_operation(r0)?;
+
+        let mut result = frame.clone();
+
+        let mut result_data = [0.0_f32; SLOT_DIM];
+        result_data[..3].copy_from_slice(&values);
+        result_data[VALID_DIM] = 1.0;
+
+        let mut result_slot = SlotData::new(SlotRole::Result);
+        result_slot.write_resolution(0, result_data);
+        result.write_slot(RESULT_SLOT, result_slot)?;
+
+        // Calendar arithmetic is exact: gamma = 1.0
+        result.meta[RESULT_SLOT] = SlotMeta {
+            certainty: 1.0,
+            source: SlotSource::HardCore,
+            updated_at: 0,
+            needs_verify: false,
+        };
+
+        result.frame_meta.verified = true;
+        result.frame_meta.proof_length += 1;
+
+        // Update global certainty (min-rule over active slots)
+        let min_gamma = (0..MAX_SLOTS)
+            .filter(|&i| result.slots[i].is_some())
+            .map(|i| result.meta[i].certainty)
+            .fold(f32::INFINITY, f32::min);
+        if min_gamma.is_finite() {
+            result.frame_meta.global_certainty = min_gamma;
+        }
+
+        Ok(StrandResult {
+            frame: result,
+            activated: true,
+            description: format!("datetime: {description}"),
+            operation: Some(ProofOperation::new(
+                op_name,
+                vec![INSTRUMENT_SLOT],
+                vec![RESULT_SLOT],
+            )),
+        })
+    }
+
+    fn info(&self) -> Option<ModuleInfo> {
+        Some(ModuleInfo {
+            id: "volt-strand-datetime".to_string(),
+            display_name: "DateTime Strand".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            author: "Volt X Team".to_string(),
+            description: "Exact Gregorian calendar arithmetic.".to_string(),
+            module_type: ModuleType::HardStrand,
+            min_core_version: "0.1.0".to_string(),
+            requires: vec![],
+            deterministic: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_STACK: usize = 4 * 1024 * 1024;
+
+    fn make_datetime_frame(op: f32, a: [f32; 3], rest: [f32; 3]) -> TensorFrame {
+        let strand = DateTimeStrand::new();
+        let mut frame = TensorFrame::new();
+
+        // Predicate tagged with the datetime capability for routing
+        let mut pred = SlotData::new(SlotRole::Predicate);
+        pred.write_resolution(0, *strand.capability_vector());
+        frame.write_slot(1, pred).unwrap();
+        frame.meta[1].certainty = 0.8;
+
+        let mut inst = SlotData::new(SlotRole::Instrument);
+        let mut data = [0.0_f32; SLOT_DIM];
+        data[0] = op;
+        data[1..4].copy_from_slice(&a);
+        data[4..7].copy_from_slice(&rest);
+        inst.write_resolution(0, data);
+        frame.write_slot(INSTRUMENT_SLOT, inst).unwrap();
+        frame.meta[INSTRUMENT_SLOT].certainty = 0.9;
+        frame
+    }
+
+    fn run<F: FnOnce() + Send + 'static>(f: F) {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(f)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    fn result_r0(frame: &TensorFrame) -> [f32; SLOT_DIM] {
+        frame.read_slot(RESULT_SLOT).unwrap().resolutions[0].unwrap()
+    }
+
+    #[test]
+    fn date_day_conversion_roundtrips() {
+        let epoch = Date {
+            year: 1970,
+            month: 1,
+            day: 1,
+        };
+        assert_eq!(epoch.to_days(), 0);
+        for days in [-800_000, -1, 0, 59, 60, 11_016, 19_782, 2_932_896] {
+            assert_eq!(Date::from_days(days).to_days(), days);
+        }
+        let leap = Date {
+            year: 2000,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!(Date::from_days(leap.to_days()), leap);
+    }
+
+    #[test]
+    fn capability_vector_is_normalized_and_distinct_from_math() {
+        let strand = DateTimeStrand::new();
+        let math = crate::math_engine::MathEngine::new();
+        let cap = strand.capability_vector();
+        let norm: f32 = cap.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+        let sim: f32 = cap
+            .iter()
+            .zip(math.capability_vector())
+            .map(|(a, b)| a * b)
+            .sum();
+        assert!(
+            sim.abs() < strand.threshold(),
+            "similarity to math was {sim}"
+        );
+    }
+
+    #[test]
+    fn add_and_sub_days_cross_month_and_year() {
+        run(|| {
+            let strand = DateTimeStrand::new();
+            let frame = make_datetime_frame(OP_ADD_DAYS, [2024.0, 2.0, 28.0], [2.0, 0.0, 0.0]);
+            let r0 = result_r0(&strand.process(&frame).unwrap().frame);
+            assert_eq!(&r0[..4], &[2024.0, 3.0, 1.0, 1.0]);
+
+            let frame = make_datetime_frame(OP_SUB_DAYS, [2025.0, 1.0, 1.0], [1.0, 0.0, 0.0]);
+            let r0 = result_r0(&strand.process(&frame).unwrap().frame);
+            assert_eq!(&r0[..4], &[2024.0, 12.0, 31.0, 1.0]);
+        });
+    }
+
+    #[test]
+    fn weekday_of_known_dates() {
+        run(|| {
+            let strand = DateTimeStrand::new();
+            // 2000-01-01 was a Saturday, 2026-10-17 is a Saturday, 1969-07-20 a Sunday.
+            for (date, expected) in [
+                ([2000.0, 1.0, 1.0], 5.0),
+                ([2026.0, 10.0, 17.0], 5.0),
+                ([1969.0, 7.0, 20.0], 6.0),
+            ] {
+                let frame = make_datetime_frame(OP_WEEKDAY, date, [0.0; 3]);
+                let result = strand.process(&frame).unwrap();
+                assert_eq!(result_r0(&result.frame)[0], expected, "{date:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn days_between_is_signed() {
+        run(|| {
+            let strand = DateTimeStrand::new();
+            let frame =
+                make_datetime_frame(OP_DAYS_BETWEEN, [2025.0, 3.0, 1.0], [2025.0, 2.0, 1.0]);
+            let result = strand.process(&frame).unwrap();
+            assert_eq!(result_r0(&result.frame)[0], -28.0);
+            assert_eq!(result.frame.meta[RESULT_SLOT].certainty, 1.0);
+            assert!(result.frame.frame_meta.verified);
+        });
+    }
+
+    #[test]
+    fn invalid_dates_error() {
+        run(|| {
+            let strand = DateTimeStrand::new();
+            for date in [[2023.0, 2.0, 29.0], [2024.0, 13.0, 1.0], [2024.0, 1.5, 1.0]] {
+                let frame = make_datetime_frame(OP_WEEKDAY, date, [0.0; 3]);
+                assert!(
+                    strand.process(&frame).is_err(),
+                    "{date:?} should be rejected"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn out_of_range_operands_error() {
+        run(|| {
+            let strand = DateTimeStrand::new();
+            let cases = [
+                (OP_WEEKDAY, [1e30, 1.0, 1.0], [0.0; 3]),
+                (OP_DAYS_BETWEEN, [2024.0, 1.0, 1.0], [-1e30, 1.0, 1.0]),
+                (OP_ADD_DAYS, [2024.0, 1.0, 1.0], [1e30, 0.0, 0.0]),
+                (OP_SUB_DAYS, [2024.0, 1.0, 1.0], [1e30, 0.0, 0.0]),
+                (OP_ADD_DAYS, [9_999.0, 12.0, 31.0], [1.0, 0.0, 0.0]),
+                (OP_SUB_DAYS, [-9_999.0, 1.0, 1.0], [1.0, 0.0, 0.0]),
+            ];
+            for (op, a, b) in cases {
+                let frame = make_datetime_frame(op, a, b);
+                assert!(
+                    strand.process(&frame).is_err(),
+                    "op {op} on {a:?}, {b:?} should be rejected"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn non_datetime_op_passes_through() {
+        run(|| {
+            let strand = DateTimeStrand::new();
+            let frame = make_datetime_frame(3.0, [6.0, 7.0, 0.0], [0.0; 3]);
+            let result = strand.process(&frame).unwrap();
+            assert!(!result.activated);
+            assert!(strand
+                .process(&TensorFrame::new())
+                .unwrap()
+                .operation
+                .is_none());
+        });
+    }
+
+    #[test]
+    fn days_between_routes_through_default_pipeline() {
+        run(|| {
+            let pipeline = crate::default_pipeline();
+            let frame =
+                make_datetime_frame(OP_DAYS_BETWEEN, [2024.0, 1.0, 1.0], [2026.0, 10.0, 17.0]);
+            let result = pipeline.process(&frame).unwrap();
+
+            let r0 = result_r0(&result.frame);
+            assert_eq!(r0[0], 1020.0);
+            assert_eq!(r0[VALID_DIM], 1.0);
+            assert!(
+                result
+                    .proof
+                    .steps
+                    .iter()
+                    .any(|step| step.strand_name == "datetime"
+                        && step.op_code == "datetime.days_between"),
+                "proof chain should record the datetime step: {:?}",
+                result.proof
+            );
+        });
+    }
+}
@@ -71,6 +71,7 @@ use volt_core::{
     slot::SlotSource, SlotData, SlotMeta, SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM,
 };
 
+use crate::proof_constructor::ProofOperation;
 use crate::strand::{HardStrand, StrandResult};
 
 /// Operation codes for HDC algebra operations.
@@ -210,6 +211,7 @@ impl HardStrand for HDCAlgebra {
                 return Ok(StrandResult {
                     frame: frame.clone(),
                     activated: false,
+                    operation: None,
                     description: "hdc_algebra: no instrument slot data".to_string(),
                 });
             }
@@ -221,6 +223,7 @@ impl HardStrand for HDCAlgebra {
                 return Ok(StrandResult {
                     frame: frame.clone(),
                     activated: false,
+                    operation: None,
                     description: "hdc_algebra: no R0 data in instrument slot".to_string(),
                 });
             }
@@ -231,16 +234,24 @@ impl HardStrand for HDCAlgebra {
         let slot_b_idx = r0_data[2] as usize;
         let permute_k = r0_data[3] as isize;
 
-        let (result_vec, description) = if (op_code - OP_HDC_BIND).abs() < 0.5 {
+        let (result_vec, description, op_name) = if (op_code - OP_HDC_BIND).abs() < 0.5 {
             let vec_a = Self::read_slot_vector(frame, slot_a_idx)?;
             let vec_b = Self::read_slot_vector(frame, slot_b_idx)?;
             let bound = bind(&vec_a, &vec_b)?;
-            (bound, format!("bind(S{slot_a_idx}, S{slot_b_idx})"))
+            (
+                bound,
+                format!("bind(S{slot_a_idx}, S{slot_b_idx})"),
+                "hdc_algebra.bind",
+            )
         } else if (op_code - OP_HDC_UNBIND).abs() < 0.5 {
             let vec_a = Self::read_slot_vector(frame, slot_a_idx)?;
             let vec_b = Self::read_slot_vector(frame, slot_b_idx)?;
             let unbound = unbind(&vec_a, &vec_b)?;
-            (unbound, format!("unbind(S{slot_a_idx}, S{slot_b_idx})"))
+            (
+                unbound,
+                format!("unbind(S{slot_a_idx}, S{slot_b_idx})"),
+                "hdc_algebra.unbind",
+            )
         } else if (op_code - OP_HDC_SUPERPOSE).abs() < 0.5 {
             let vec_a = Self::read_slot_vector(frame, slot_a_idx)?;
             let vec_b = Self::read_slot_vector(frame, slot_b_idx)?;
@@ -248,11 +259,16 @@ impl HardStrand for HDCAlgebra {
             (
                 superposed,
                 format!("superpose(S{slot_a_idx}, S{slot_b_idx})"),
+                "hdc_algebra.superpose",
             )
         } else if (op_code - OP_HDC_PERMUTE).abs() < 0.5 {
             let vec_a = Self::read_slot_vector(frame, slot_a_idx)?;
             let permuted = permute(&vec_a, permute_k);
-            (permuted, format!("permute(S{slot_a_idx}, k={permute_k})"))
+            (
+                permuted,
+                format!("permute(S{slot_a_idx}, k={permute_k})"),
+                "hdc_algebra.permute",
+            )
         } else if (op_code - OP_HDC_SIMILARITY).abs() < 0.5 {
             let vec_a = Self::read_slot_vector(frame, slot_a_idx)?;
             let vec_b = Self::read_slot_vector(frame, slot_b_idx)?;
@@ -263,11 +279,13 @@ impl HardStrand for HDCAlgebra {
             (
                 result,
                 format!("similarity(S{slot_a_idx}, S{slot_b_idx}) = {sim:.4}"),
+                "hdc_algebra.similarity",
             )
         } else {
             return Ok(StrandResult {
                 frame: frame.clone(),
                 activated: false,
+                operation: None,
                 description: format!("hdc_algebra: unknown op code {op_code}"),
             });
         };
@@ -290,10 +308,18 @@ impl HardStrand for HDCAlgebra {
 
         Self::recompute_global_certainty(&mut result_frame);
 
+        // Permute is the only unary op; the others read both source slots.
+        let inputs = if op_name == "hdc_algebra.permute" {
+            vec![INSTRUMENT_SLOT, slot_a_idx]
+        } else {
+            vec![INSTRUMENT_SLOT, slot_a_idx, slot_b_idx]
+        };
+
         Ok(StrandResult {
             frame: result_frame,
             activated: true,
             description: format!("hdc_algebra: {description}"),
+            operation: Some(ProofOperation::new(op_name, inputs, vec![RESULT_SLOT])),
         })
     }
 }
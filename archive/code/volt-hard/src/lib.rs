@@ -4,6 +4,8 @@
 //!
 //! Implements deterministic, exact computation in pure Rust:
 //! - **[`strand::HardStrand`]**: Pluggable trait for CPU-side tools
+//! - **`async_strand::AsyncHardStrand`**: I/O-bound strands run on tokio
+//!   (behind the `async` feature)
 //! - **[`router::IntentRouter`]**: Routes frame slots to Hard Strands by cosine similarity
 //! - **[`math_engine::MathEngine`]**: Exact arithmetic, algebra, basic calculus
 //! - **[`hdc_algebra::HDCAlgebra`]**: Compositional reasoning via HDC operations
@@ -15,6 +17,8 @@
 //!
 //! - Pure CPU, no GPU code.
 //! - No network code (network goes in `volt-ledger` or `volt-server`).
+//!   I/O-bound strands implement `AsyncHardStrand` and are flagged
+//!   non-deterministic in their `ModuleInfo`.
 //! - Depends on `volt-core` and `volt-bus`.
 //! - Hard Strands are hot-pluggable via `impl HardStrand` trait.
 //!
@@ -70,15 +74,21 @@
 
 pub use volt_core;
 
+#[cfg(feature = "async")]
+pub mod async_strand;
 pub mod certainty_engine;
 #[cfg(feature = "sandbox")]
 pub mod code_runner;
+#[cfg(feature = "datetime")]
+pub mod datetime_strand;
 pub mod hdc_algebra;
 pub mod math_engine;
 pub mod pipeline;
 pub mod proof_constructor;
 pub mod router;
 pub mod strand;
+#[cfg(feature = "units")]
+pub mod unit_strand;
 #[cfg(feature = "weather")]
 pub mod weather_strand;
 
@@ -130,7 +140,8 @@ pub fn verify_stub(frame: &TensorFrame) -> Result<TensorFrame, VoltError> {
 /// Create a default Intent Router with all standard Hard Strands.
 ///
 /// Registers MathEngine, HDCAlgebra, and (if the `sandbox` feature is
-/// enabled) CodeRunner.
+/// enabled) CodeRunner. The `weather`, `datetime`, and `units` features
+/// add WeatherStrand, DateTimeStrand, and UnitConvertStrand.
 ///
 /// # Example
 ///
@@ -153,6 +164,12 @@ pub fn default_router() -> router::IntentRouter {
     #[cfg(feature = "weather")]
     router.register(Box::new(weather_strand::WeatherStrand::new()));
 
+    #[cfg(feature = "datetime")]
+    router.register(Box::new(datetime_strand::DateTimeStrand::new()));
+
+    #[cfg(feature = "units")]
+    router.register(Box::new(unit_strand::UnitConvertStrand::new()));
+
     router
 }
 
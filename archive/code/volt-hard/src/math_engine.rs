@@ -16,9 +16,12 @@
 //!
 //! For Milestone 3.1, the MathEngine operates on **structured numeric data**
 //! encoded directly in frame slots using a simple protocol:
-//! - S6 (Instrument) R0 dim[0]: operation code (1.0=add, 2.0=sub, 3.0=mul, 4.0=div, 5.0=pow)
+//! - S6 (Instrument) R0 dim[0]: operation code (1.0=add, 2.0=sub, 3.0=mul, 4.0=div, 5.0=pow,
+//!   see [`MathEngine`] for the full table)
 //! - S6 (Instrument) R0 dim[1]: left operand (f32)
-//! - S6 (Instrument) R0 dim[2]: right operand (f32)
+//! - S6 (Instrument) R0 dim[2]: right operand (f32, ignored by unary operations)
+//!
+//! Trigonometric operations take their operand in radians.
 //! - S8 (Result) R0 dim[0]: the exact result (f32)
 //! - S8 (Result) R0 dim[1]: 1.0 if result is valid, 0.0 otherwise
 
@@ -26,6 +29,8 @@ use volt_core::{
     slot::SlotSource, SlotData, SlotMeta, SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM,
 };
 
+use crate::certainty_engine::PropagationRule;
+use crate::proof_constructor::ProofOperation;
 use crate::strand::{HardStrand, StrandResult};
 
 /// Operation codes for the MathEngine protocol.
@@ -37,6 +42,13 @@ const OP_POW: f32 = 5.0;
 const OP_SQRT: f32 = 6.0;
 const OP_ABS: f32 = 7.0;
 const OP_NEG: f32 = 8.0;
+// Transcendental ops sit at 50+ so they don't overlap the code runner
+// (10.0) or HDC algebra (11.0-15.0) codes.
+const OP_SIN: f32 = 50.0;
+const OP_COS: f32 = 51.0;
+const OP_TAN: f32 = 52.0;
+const OP_LN: f32 = 53.0;
+const OP_EXP: f32 = 54.0;
 
 /// Slot index for operation input (Instrument = S6).
 const INSTRUMENT_SLOT: usize = 6;
@@ -61,6 +73,11 @@ const RESULT_SLOT: usize = 8;
 /// | 6.0  | sqrt      | sqrt(a)     |
 /// | 7.0  | abs       | |a|         |
 /// | 8.0  | neg       | -a          |
+/// | 50.0 | sin       | sin(a), radians |
+/// | 51.0 | cos       | cos(a), radians |
+/// | 52.0 | tan       | tan(a), radians |
+/// | 53.0 | ln        | ln(a), a > 0 |
+/// | 54.0 | exp       | e^a         |
 ///
 /// # Example
 ///
@@ -149,6 +166,64 @@ impl MathEngine {
         v
     }
 
+    /// Stable proof op code for a MathEngine operation code.
+    ///
+    /// Only called after [`execute_operation`](Self::execute_operation)
+    /// has accepted `op_code`.
+    fn op_name(op_code: f32) -> &'static str {
+        [
+            (OP_ADD, "math_engine.add"),
+            (OP_SUB, "math_engine.sub"),
+            (OP_MUL, "math_engine.mul"),
+            (OP_DIV, "math_engine.div"),
+            (OP_POW, "math_engine.pow"),
+            (OP_SQRT, "math_engine.sqrt"),
+            (OP_ABS, "math_engine.abs"),
+            (OP_NEG, "math_engine.neg"),
+            (OP_SIN, "math_engine.sin"),
+            (OP_COS, "math_engine.cos"),
+            (OP_TAN, "math_engine.tan"),
+            (OP_LN, "math_engine.ln"),
+            (OP_EXP, "math_engine.exp"),
+        ]
+        .into_iter()
+        .find(|(code, _)| (op_code - code).abs() < 0.1)
+        .map_or("math_engine.process", |(_, name)| name)
+    }
+
+    /// Parse and evaluate an infix arithmetic expression.
+    ///
+    /// Supports `+ - * / ^`, parentheses, and unary minus, with the usual
+    /// precedence (`^` is right-associative and binds tighter than unary
+    /// minus). Lets the translator pass raw math text straight through;
+    /// the pipeline still uses the slot-opcode protocol.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StrandError`] for malformed input (unknown
+    /// characters, unbalanced parentheses, missing operands or
+    /// operators), division by zero, or a non-finite result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::math_engine::MathEngine;
+    ///
+    /// let engine = MathEngine::new();
+    /// assert_eq!(engine.eval_expression("2 + 3 * 4").unwrap(), 14.0);
+    /// assert_eq!(engine.eval_expression("-(2 + 3) ^ 2").unwrap(), -25.0);
+    /// assert!(engine.eval_expression("1/0").is_err());
+    /// ```
+    pub fn eval_expression(&self, expr: &str) -> Result<f64, VoltError> {
+        let result = eval_rpn(&to_rpn(tokenize(expr)?)?)?;
+        if !result.is_finite() {
+            return Err(expression_error(format!(
+                "result of '{expr}' is not finite"
+            )));
+        }
+        Ok(result)
+    }
+
     /// Execute the math operation encoded in the Instrument slot.
     ///
     /// Returns `Ok((result_value, description))` on success, or
@@ -191,6 +266,22 @@ impl MathEngine {
             (left.abs(), format!("|{left}| = {}", left.abs()))
         } else if (op_code - OP_NEG).abs() < 0.1 {
             (-left, format!("-{left} = {}", -left))
+        } else if (op_code - OP_SIN).abs() < 0.1 {
+            (left.sin(), format!("sin({left}) = {}", left.sin()))
+        } else if (op_code - OP_COS).abs() < 0.1 {
+            (left.cos(), format!("cos({left}) = {}", left.cos()))
+        } else if (op_code - OP_TAN).abs() < 0.1 {
+            (left.tan(), format!("tan({left}) = {}", left.tan()))
+        } else if (op_code - OP_LN).abs() < 0.1 {
+            if left <= 0.0 {
+                return Err(VoltError::StrandError {
+                    strand_id: 0,
+                    message: format!("math_engine: ln of non-positive number {left}"),
+                });
+            }
+            (left.ln(), format!("ln({left}) = {}", left.ln()))
+        } else if (op_code - OP_EXP).abs() < 0.1 {
+            (left.exp(), format!("exp({left}) = {}", left.exp()))
         } else {
             return Err(VoltError::StrandError {
                 strand_id: 0,
@@ -209,6 +300,172 @@ impl MathEngine {
     }
 }
 
+/// A lexical token of an infix math expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Num(f64),
+    Op(Operator),
+    LParen,
+    RParen,
+}
+
+/// Operators understood by [`MathEngine::eval_expression`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Neg,
+}
+
+impl Operator {
+    /// Binding strength; unary minus binds tighter than `* /` but looser
+    /// than `^`, so `-2^2 = -4`.
+    fn precedence(self) -> u8 {
+        match self {
+            Operator::Add | Operator::Sub => 1,
+            Operator::Mul | Operator::Div => 2,
+            Operator::Neg => 3,
+            Operator::Pow => 4,
+        }
+    }
+
+    fn right_associative(self) -> bool {
+        matches!(self, Operator::Pow | Operator::Neg)
+    }
+}
+
+fn expression_error(message: String) -> VoltError {
+    VoltError::StrandError {
+        strand_id: 0,
+        message: format!("math_engine: {message}"),
+    }
+}
+
+/// Split an expression into tokens, telling unary from binary minus.
+fn tokenize(expr: &str) -> Result<Vec<Token>, VoltError> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c.is_ascii_digit() || c == '.' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                if !(d.is_ascii_digit() || d == '.') {
+                    break;
+                }
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            let literal = &expr[start..end];
+            let value = literal
+                .parse::<f64>()
+                .map_err(|_| expression_error(format!("invalid number '{literal}'")))?;
+            tokens.push(Token::Num(value));
+            continue;
+        }
+        // A minus is unary at the start, after an operator, or after '('.
+        let unary = matches!(tokens.last(), None | Some(Token::Op(_)) | Some(Token::LParen));
+        let token = match c {
+            '+' => Token::Op(Operator::Add),
+            '-' if unary => Token::Op(Operator::Neg),
+            '-' => Token::Op(Operator::Sub),
+            '*' => Token::Op(Operator::Mul),
+            '/' => Token::Op(Operator::Div),
+            '^' => Token::Op(Operator::Pow),
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            other => {
+                return Err(expression_error(format!(
+                    "unexpected character '{other}' at position {start}"
+                )))
+            }
+        };
+        tokens.push(token);
+        chars.next();
+    }
+    Ok(tokens)
+}
+
+/// Shunting-yard: reorder infix tokens into reverse Polish notation.
+fn to_rpn(tokens: Vec<Token>) -> Result<Vec<Token>, VoltError> {
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut stack: Vec<Token> = Vec::new();
+    for token in tokens {
+        match token {
+            Token::Num(_) => output.push(token),
+            // Prefix operators have no left operand to pop for.
+            Token::Op(Operator::Neg) => stack.push(token),
+            Token::Op(op) => {
+                while let Some(&Token::Op(top)) = stack.last() {
+                    let pops = top.precedence() > op.precedence()
+                        || (top.precedence() == op.precedence() && !op.right_associative());
+                    if !pops {
+                        break;
+                    }
+                    output.push(Token::Op(top));
+                    stack.pop();
+                }
+                stack.push(token);
+            }
+            Token::LParen => stack.push(token),
+            Token::RParen => loop {
+                match stack.pop() {
+                    Some(Token::LParen) => break,
+                    Some(op) => output.push(op),
+                    None => return Err(expression_error("unmatched ')'".to_string())),
+                }
+            },
+        }
+    }
+    while let Some(token) = stack.pop() {
+        if token == Token::LParen {
+            return Err(expression_error("unmatched '('".to_string()));
+        }
+        output.push(token);
+    }
+    Ok(output)
+}
+
+/// Evaluate a reverse-Polish token stream.
+fn eval_rpn(rpn: &[Token]) -> Result<f64, VoltError> {
+    let missing = || expression_error("operator is missing an operand".to_string());
+    let mut stack: Vec<f64> = Vec::new();
+    for token in rpn {
+        let value = match *token {
+            Token::Num(n) => n,
+            Token::Op(Operator::Neg) => -stack.pop().ok_or_else(missing)?,
+            Token::Op(op) => {
+                let right = stack.pop().ok_or_else(missing)?;
+                let left = stack.pop().ok_or_else(missing)?;
+                match op {
+                    Operator::Add => left + right,
+                    Operator::Sub => left - right,
+                    Operator::Mul => left * right,
+                    Operator::Div if right == 0.0 => {
+                        return Err(expression_error("division by zero".to_string()))
+                    }
+                    Operator::Div => left / right,
+                    Operator::Pow => left.powf(right),
+                    Operator::Neg => unreachable!("handled above"),
+                }
+            }
+            Token::LParen | Token::RParen => unreachable!("removed by to_rpn"),
+        };
+        stack.push(value);
+    }
+    match stack.as_slice() {
+        [result] => Ok(*result),
+        [] => Err(expression_error("empty expression".to_string())),
+        _ => Err(expression_error("missing operator between operands".to_string())),
+    }
+}
+
 impl Default for MathEngine {
     fn default() -> Self {
         Self::new()
@@ -236,6 +493,7 @@ impl HardStrand for MathEngine {
                 return Ok(StrandResult {
                     frame: frame.clone(),
                     activated: false,
+                    operation: None,
                     description: "math_engine: no instrument slot data".to_string(),
                 });
             }
@@ -247,6 +505,7 @@ impl HardStrand for MathEngine {
                 return Ok(StrandResult {
                     frame: frame.clone(),
                     activated: false,
+                    operation: None,
                     description: "math_engine: no R0 data in instrument slot".to_string(),
                 });
             }
@@ -302,8 +561,19 @@ impl HardStrand for MathEngine {
             frame: result_frame,
             activated: true,
             description: format!("math_engine: {description}"),
+            operation: Some(ProofOperation::new(
+                Self::op_name(op_code),
+                vec![INSTRUMENT_SLOT],
+                vec![RESULT_SLOT],
+            )),
         })
     }
+
+    /// Arithmetic results are exact, so they are not capped by the
+    /// certainty of the slots that carried the question.
+    fn propagation_rule(&self) -> PropagationRule {
+        PropagationRule::Override(1.0)
+    }
 }
 
 #[cfg(test)]
@@ -323,6 +593,40 @@ mod tests {
         frame
     }
 
+    #[test]
+    fn eval_expression_respects_precedence_and_parentheses() {
+        let engine = MathEngine::new();
+        assert_eq!(engine.eval_expression("2 + 3 * 4").unwrap(), 14.0);
+        assert_eq!(engine.eval_expression("(2+3)*4").unwrap(), 20.0);
+        assert_eq!(engine.eval_expression("10 - 4 - 3").unwrap(), 3.0);
+        assert_eq!(engine.eval_expression("2 ^ 3 ^ 2").unwrap(), 512.0);
+        assert_eq!(engine.eval_expression("7.5 / 2.5").unwrap(), 3.0);
+    }
+
+    #[test]
+    fn eval_expression_unary_minus() {
+        let engine = MathEngine::new();
+        assert_eq!(engine.eval_expression("-2 ^ 2").unwrap(), -4.0);
+        assert_eq!(engine.eval_expression("2 ^ -1").unwrap(), 0.5);
+        assert_eq!(engine.eval_expression("3 - -2").unwrap(), 5.0);
+        assert_eq!(engine.eval_expression("-(1 + 2) * 2").unwrap(), -6.0);
+    }
+
+    #[test]
+    fn eval_expression_errors_are_descriptive() {
+        let engine = MathEngine::new();
+        let message = |expr: &str| engine.eval_expression(expr).unwrap_err().to_string();
+        assert!(message("1/0").contains("division by zero"));
+        assert!(message("(1 + 2").contains("unmatched '('"));
+        assert!(message("1 + 2)").contains("unmatched ')'"));
+        assert!(message("2 +").contains("missing an operand"));
+        assert!(message("2 3").contains("missing operator"));
+        assert!(message("2 $ 3").contains("unexpected character '$'"));
+        assert!(message("1.2.3").contains("invalid number"));
+        assert!(message("").contains("empty"));
+        assert!(message("10 ^ 400").contains("not finite"));
+    }
+
     #[test]
     fn math_engine_name() {
         let engine = MathEngine::new();
@@ -430,6 +734,62 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn math_engine_sqrt_two() {
+        let engine = MathEngine::new();
+        let frame = make_math_frame(OP_SQRT, 2.0, 0.0);
+        let result = engine.process(&frame).unwrap();
+
+        let r = result.frame.read_slot(RESULT_SLOT).unwrap();
+        assert!((r.resolutions[0].unwrap()[0] - std::f32::consts::SQRT_2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn math_engine_trigonometry_uses_radians() {
+        let engine = MathEngine::new();
+        for (op, x, expected) in [
+            (OP_SIN, std::f32::consts::FRAC_PI_2, 1.0),
+            (OP_COS, std::f32::consts::PI, -1.0),
+            (OP_TAN, std::f32::consts::FRAC_PI_4, 1.0),
+        ] {
+            let result = engine.process(&make_math_frame(op, x, 0.0)).unwrap();
+            assert!(result.activated);
+            let r = result.frame.read_slot(RESULT_SLOT).unwrap();
+            assert!(
+                (r.resolutions[0].unwrap()[0] - expected).abs() < 1e-5,
+                "op {op} on {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn math_engine_ln_and_exp() {
+        let engine = MathEngine::new();
+        let result = engine
+            .process(&make_math_frame(OP_LN, std::f32::consts::E, 0.0))
+            .unwrap();
+        let r = result.frame.read_slot(RESULT_SLOT).unwrap();
+        assert!((r.resolutions[0].unwrap()[0] - 1.0).abs() < 1e-5);
+        assert_eq!(result.operation.unwrap().op_code, "math_engine.ln");
+
+        let result = engine.process(&make_math_frame(OP_EXP, 1.0, 0.0)).unwrap();
+        let r = result.frame.read_slot(RESULT_SLOT).unwrap();
+        assert!((r.resolutions[0].unwrap()[0] - std::f32::consts::E).abs() < 1e-5);
+    }
+
+    #[test]
+    fn math_engine_ln_non_positive_errors() {
+        let engine = MathEngine::new();
+        assert!(engine.process(&make_math_frame(OP_LN, -1.0, 0.0)).is_err());
+        assert!(engine.process(&make_math_frame(OP_LN, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn math_engine_exp_overflow_errors() {
+        let engine = MathEngine::new();
+        assert!(engine.process(&make_math_frame(OP_EXP, 1000.0, 0.0)).is_err());
+    }
+
     #[test]
     fn math_engine_abs() {
         let engine = MathEngine::new();
@@ -460,6 +820,15 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn math_engine_rejects_code_runner_and_hdc_op_codes() {
+        let engine = MathEngine::new();
+        for op in 9..=15 {
+            let frame = make_math_frame(op as f32, 1.0, 2.0);
+            assert!(engine.process(&frame).is_err(), "op {op} was evaluated");
+        }
+    }
+
     #[test]
     fn math_engine_no_instrument_slot_passthrough() {
         let engine = MathEngine::new();
@@ -48,7 +48,7 @@ use volt_core::{TensorFrame, VoltError};
 
 use crate::certainty_engine::CertaintyEngine;
 use crate::proof_constructor::{ProofChain, ProofConstructor};
-use crate::router::IntentRouter;
+use crate::router::{IntentRouter, RouterResult};
 
 /// The result of the full Hard Core pipeline.
 ///
@@ -100,6 +100,9 @@ pub struct PipelineResult {
 pub struct HardCorePipeline {
     router: IntentRouter,
     certainty_engine: CertaintyEngine,
+    /// Retry with the runner-up strand when the top strand's output
+    /// gamma is below this value. `None` routes once (the default).
+    runner_up_below: Option<f32>,
 }
 
 impl HardCorePipeline {
@@ -124,9 +127,37 @@ impl HardCorePipeline {
         Self {
             router,
             certainty_engine: CertaintyEngine::new(),
+            runner_up_below: None,
         }
     }
 
+    /// Enables runner-up retry: when the activated strand's output has a
+    /// global gamma below `min_gamma`, the frame is routed again with that
+    /// strand excluded, and the runner-up's output is kept if it activates
+    /// with a higher gamma. If the runner-up fails, the first output stands.
+    ///
+    /// When the runner-up is kept, the superseded attempt is still recorded
+    /// in the proof chain, marked as not activated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::pipeline::HardCorePipeline;
+    /// use volt_hard::router::IntentRouter;
+    ///
+    /// let pipeline = HardCorePipeline::new(IntentRouter::new()).with_runner_up(0.5);
+    /// assert_eq!(pipeline.runner_up_threshold(), Some(0.5));
+    /// ```
+    pub fn with_runner_up(mut self, min_gamma: f32) -> Self {
+        self.runner_up_below = Some(min_gamma);
+        self
+    }
+
+    /// Returns the runner-up retry threshold, if enabled.
+    pub fn runner_up_threshold(&self) -> Option<f32> {
+        self.runner_up_below
+    }
+
     /// Process a frame through the full Hard Core pipeline.
     ///
     /// 1. Route frame to best-matching strand via [`IntentRouter`]
@@ -156,11 +187,117 @@ impl HardCorePipeline {
     /// }).unwrap().join().unwrap();
     /// ```
     pub fn process(&self, frame: &TensorFrame) -> Result<PipelineResult, VoltError> {
+        self.process_excluding(frame, &[])
+    }
+
+    /// Process a frame as [`process`](Self::process) does, with the named
+    /// strands left out of routing for this call only.
+    ///
+    /// See [`IntentRouter::route_excluding`] for how `excluded` is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(VoltError)` if strand execution fails.
+    pub fn process_excluding(
+        &self,
+        frame: &TensorFrame,
+        excluded: &[String],
+    ) -> Result<PipelineResult, VoltError> {
         let mut proof = ProofConstructor::new();
 
         // Step 1 & 2: Route and execute strand
-        let router_result = self.router.route(frame)?;
+        let mut router_result = self.router.route_excluding(frame, excluded)?;
+
+        // Optional: retry with the runner-up strand on low output gamma
+        if let Some(min_gamma) = self.runner_up_below {
+            self.retry_runner_up(frame, excluded, min_gamma, &mut router_result, &mut proof);
+        }
+
+        Ok(self.finish(router_result, proof))
+    }
+
+    /// Replace `router_result` with the runner-up strand's output when the
+    /// top strand's gamma is below `min_gamma` and the runner-up beats it.
+    ///
+    /// Kept out of line so the retry's [`RouterResult`], which holds a
+    /// full [`TensorFrame`], only occupies stack while a retry runs
+    /// rather than in every [`process_excluding`](Self::process_excluding)
+    /// call.
+    #[inline(never)]
+    fn retry_runner_up(
+        &self,
+        frame: &TensorFrame,
+        excluded: &[String],
+        min_gamma: f32,
+        router_result: &mut RouterResult,
+        proof: &mut ProofConstructor,
+    ) {
+        let gamma = self.certainty_engine.compute(&router_result.frame).global_certainty;
+        let top = router_result.decisions.iter().find(|d| d.activated);
+        let Some(top) = top.filter(|_| gamma < min_gamma) else {
+            return;
+        };
+        let mut retry_excluded = excluded.to_vec();
+        retry_excluded.push(top.strand_name.clone());
+        let retry = self
+            .router
+            .route_excluding(frame, &retry_excluded)
+            .ok()
+            .filter(|r| r.decisions.iter().any(|d| d.activated))
+            .filter(|r| self.certainty_engine.compute(&r.frame).global_certainty > gamma);
+
+        if let Some(retry) = retry {
+            let mut superseded = top.clone();
+            superseded.activated = false;
+            proof.record_from_decision(
+                &superseded,
+                &format!(
+                    "{} superseded: output gamma {:.4} < {:.4}, retried with runner-up",
+                    superseded.strand_name, gamma, min_gamma
+                ),
+                gamma,
+            );
+            *router_result = retry;
+        }
+    }
+
+    /// Process a frame with every independently routed strand running
+    /// concurrently.
+    ///
+    /// Opt-in alternative to [`process`](Self::process): instead of
+    /// activating only the single best strand, each active slot routes on
+    /// its own and every strand that wins a slot runs on its own thread
+    /// (see [`IntentRouter::route_parallel_excluding`]). Outputs are merged
+    /// and proof steps recorded in slot order, so the result is
+    /// reproducible and matches running the same strands one after
+    /// another. Certainty uses the rule of the first activated strand in
+    /// slot order. Runner-up retry is not applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(VoltError)` if any strand's execution fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::pipeline::HardCorePipeline;
+    /// use volt_hard::router::IntentRouter;
+    /// use volt_core::TensorFrame;
+    ///
+    /// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+    ///     let pipeline = HardCorePipeline::new(IntentRouter::new());
+    ///     let result = pipeline.process_parallel(&TensorFrame::new()).unwrap();
+    ///     assert_eq!(result.proof.len(), 1); // certainty propagation only
+    /// }).unwrap().join().unwrap();
+    /// ```
+    pub fn process_parallel(&self, frame: &TensorFrame) -> Result<PipelineResult, VoltError> {
+        let router_result = self.router.route_parallel_excluding(frame, &[])?;
+        Ok(self.finish(router_result, ProofConstructor::new()))
+    }
 
+    /// Record routing decisions, propagate certainty, and build the proof
+    /// chain for a routed frame.
+    fn finish(&self, router_result: RouterResult, mut proof: ProofConstructor) -> PipelineResult {
         // Step 3: Record routing decisions in proof
         for decision in &router_result.decisions {
             let description = if decision.activated {
@@ -182,12 +319,19 @@ impl HardCorePipeline {
             );
         }
 
-        // Step 4: Propagate certainty via min-rule
+        // Step 4: Propagate certainty with the acting strand's rule
+        // (min-rule when no strand activated)
+        let rule = router_result
+            .decisions
+            .iter()
+            .find(|d| d.activated)
+            .and_then(|d| self.router.propagation_rule(&d.strand_name))
+            .unwrap_or_default();
         let mut result_frame = router_result.frame;
-        let certainty_result = self.certainty_engine.propagate(&mut result_frame);
+        let certainty_result = self.certainty_engine.propagate_with(&mut result_frame, rule);
 
         // Step 5: Record certainty propagation in proof
-        proof.record_certainty_propagation(certainty_result.global_certainty);
+        proof.record_certainty_rule(certainty_result.global_certainty, rule);
 
         // Update proof_length to count activated steps
         let activated_count = proof
@@ -199,10 +343,10 @@ impl HardCorePipeline {
 
         let chain = proof.build(certainty_result.global_certainty);
 
-        Ok(PipelineResult {
+        PipelineResult {
             frame: result_frame,
             proof: chain,
-        })
+        }
     }
 
     /// Returns a reference to the inner router.
@@ -259,9 +403,11 @@ impl HardCorePipeline {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::certainty_engine::PropagationRule;
     use crate::math_engine::MathEngine;
-    use crate::strand::HardStrand;
-    use volt_core::{SlotData, SlotRole, SLOT_DIM};
+    use crate::strand::{HardStrand, StrandResult};
+    use crate::proof_constructor::ProofOperation;
+    use volt_core::{SlotData, SlotRole, MAX_SLOTS, SLOT_DIM};
 
     /// Stack size for tests that allocate TensorFrames (each ~65KB).
     const TEST_STACK: usize = 4 * 1024 * 1024;
@@ -355,6 +501,29 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn pipeline_proof_steps_carry_op_codes() {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(|| {
+                let pipeline = make_pipeline();
+                let frame = make_math_frame(3.0, 6.0, 7.0); // MUL
+
+                let result = pipeline.process(&frame).unwrap();
+
+                let step = &result.proof.steps[0];
+                assert_eq!(step.op_code, "math_engine.mul");
+                assert_eq!(step.inputs, vec![6]);
+                assert_eq!(step.outputs, vec![8]);
+
+                let last = result.proof.steps.last().unwrap();
+                assert_eq!(last.op_code, "certainty_engine.override");
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn pipeline_certainty_matches_engine() {
         std::thread::Builder::new()
@@ -365,15 +534,15 @@ mod tests {
 
                 let result = pipeline.process(&frame).unwrap();
 
-                // Global certainty should be min(0.8, 0.9, 1.0) = 0.8
+                // MathEngine overrides min(0.8, 0.9, 1.0) with its exact 1.0
                 assert!(
-                    (result.frame.frame_meta.global_certainty - 0.8).abs() < 0.01,
-                    "pipeline certainty should be 0.8, got {}",
+                    (result.frame.frame_meta.global_certainty - 1.0).abs() < 0.01,
+                    "pipeline certainty should be 1.0, got {}",
                     result.frame.frame_meta.global_certainty
                 );
                 assert!(
-                    (result.proof.final_gamma - 0.8).abs() < 0.01,
-                    "proof final_gamma should be 0.8, got {}",
+                    (result.proof.final_gamma - 1.0).abs() < 0.01,
+                    "proof final_gamma should be 1.0, got {}",
                     result.proof.final_gamma
                 );
             })
@@ -388,6 +557,293 @@ mod tests {
         assert_eq!(pipeline.strand_count(), 1);
     }
 
+    /// Strand that always activates and writes a Result slot with a fixed gamma.
+    struct FixedGammaStrand {
+        name: &'static str,
+        capability: [f32; SLOT_DIM],
+        gamma: f32,
+        rule: PropagationRule,
+    }
+
+    impl HardStrand for FixedGammaStrand {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+            &self.capability
+        }
+
+        fn threshold(&self) -> f32 {
+            0.3
+        }
+
+        fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+            let mut result = frame.clone();
+            let mut slot = SlotData::new(SlotRole::Result);
+            slot.write_resolution(0, [self.gamma; SLOT_DIM]);
+            result.write_slot(8, slot)?;
+            result.meta[8].certainty = self.gamma;
+            Ok(StrandResult {
+                frame: result,
+                activated: true,
+                operation: None,
+                description: format!("{}: gamma {}", self.name, self.gamma),
+            })
+        }
+
+        fn propagation_rule(&self) -> PropagationRule {
+            self.rule
+        }
+    }
+
+    /// Router whose top match (`unsure`, sim≈0.89) yields gamma 0.2 and
+    /// whose runner-up (`sure`, sim≈0.45) yields gamma 0.95.
+    fn ambiguous_setup() -> (HardCorePipeline, TensorFrame) {
+        let mut unsure = [0.0_f32; SLOT_DIM];
+        unsure[0] = 1.0;
+        let mut sure = [0.0_f32; SLOT_DIM];
+        sure[1] = 1.0;
+        let mut router = IntentRouter::new();
+        router.register(Box::new(FixedGammaStrand {
+            name: "sure",
+            capability: sure,
+            gamma: 0.95,
+            rule: PropagationRule::Min,
+        }));
+        router.register(Box::new(FixedGammaStrand {
+            name: "unsure",
+            capability: unsure,
+            gamma: 0.2,
+            rule: PropagationRule::Min,
+        }));
+
+        let mut query = [0.0_f32; SLOT_DIM];
+        query[0] = 0.89;
+        query[1] = 0.45;
+        let mut frame = TensorFrame::new();
+        let mut pred = SlotData::new(SlotRole::Predicate);
+        pred.write_resolution(0, query);
+        frame.write_slot(1, pred).unwrap();
+        frame.meta[1].certainty = 0.8;
+        (HardCorePipeline::new(router), frame)
+    }
+
+    #[test]
+    fn pipeline_default_keeps_top_strand_output() {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(|| {
+                let (pipeline, frame) = ambiguous_setup();
+                assert_eq!(pipeline.runner_up_threshold(), None);
+                let result = pipeline.process(&frame).unwrap();
+                assert_eq!(result.frame.meta[8].certainty, 0.2);
+                assert_eq!(result.proof.steps[0].strand_name, "unsure");
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn pipeline_retries_runner_up_on_low_gamma() {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(|| {
+                let (pipeline, frame) = ambiguous_setup();
+                let pipeline = pipeline.with_runner_up(0.5);
+                let result = pipeline.process(&frame).unwrap();
+
+                assert_eq!(result.frame.meta[8].certainty, 0.95);
+                assert!((result.frame.frame_meta.global_certainty - 0.8).abs() < 1e-6);
+                let steps: Vec<(&str, bool)> = result
+                    .proof
+                    .steps
+                    .iter()
+                    .map(|s| (s.strand_name.as_str(), s.activated))
+                    .collect();
+                assert_eq!(steps[0], ("unsure", false));
+                assert_eq!(steps[1], ("sure", true));
+
+                // A threshold the top strand already meets routes once.
+                let (pipeline, frame) = ambiguous_setup();
+                let result = pipeline.with_runner_up(0.1).process(&frame).unwrap();
+                assert_eq!(result.frame.meta[8].certainty, 0.2);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    /// Pipeline with one `FixedGammaStrand` (gamma 0.5) using `rule`, and
+    /// a frame routed to it whose query slot has gamma 0.8.
+    fn rule_setup(rule: PropagationRule) -> (HardCorePipeline, TensorFrame) {
+        let mut capability = [0.0_f32; SLOT_DIM];
+        capability[0] = 1.0;
+        let mut router = IntentRouter::new();
+        router.register(Box::new(FixedGammaStrand {
+            name: "fixed",
+            capability,
+            gamma: 0.5,
+            rule,
+        }));
+
+        let mut frame = TensorFrame::new();
+        let mut pred = SlotData::new(SlotRole::Predicate);
+        pred.write_resolution(0, capability);
+        frame.write_slot(1, pred).unwrap();
+        frame.meta[1].certainty = 0.8;
+        (HardCorePipeline::new(router), frame)
+    }
+
+    #[test]
+    fn pipeline_applies_strand_propagation_rule() {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(|| {
+                let cases = [
+                    (PropagationRule::Min, 0.5, "certainty_engine.min_rule"),
+                    (PropagationRule::Product, 0.4, "certainty_engine.product"),
+                    (PropagationRule::Override(1.0), 1.0, "certainty_engine.override"),
+                ];
+                for (rule, expected, op_code) in cases {
+                    let (pipeline, frame) = rule_setup(rule);
+                    let result = pipeline.process(&frame).unwrap();
+                    assert!(
+                        (result.frame.frame_meta.global_certainty - expected).abs() < 1e-6,
+                        "{rule:?}: expected {expected}, got {}",
+                        result.frame.frame_meta.global_certainty
+                    );
+                    assert!((result.proof.final_gamma - expected).abs() < 1e-6);
+                    assert_eq!(result.proof.steps.last().unwrap().op_code, op_code);
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    /// Strand that writes `value` into its own `out_slot`, leaving every
+    /// other slot untouched.
+    struct SlotWriterStrand {
+        name: &'static str,
+        capability: [f32; SLOT_DIM],
+        out_slot: usize,
+        value: f32,
+    }
+
+    impl HardStrand for SlotWriterStrand {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+            &self.capability
+        }
+
+        fn threshold(&self) -> f32 {
+            0.5
+        }
+
+        fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+            let mut result = frame.clone();
+            let mut data = [0.0_f32; SLOT_DIM];
+            data[100] = self.value;
+            let mut slot = SlotData::new(SlotRole::Result);
+            slot.write_resolution(0, data);
+            result.write_slot(self.out_slot, slot)?;
+            result.meta[self.out_slot].certainty = 0.9;
+            Ok(StrandResult {
+                frame: result,
+                activated: true,
+                operation: Some(ProofOperation::new(
+                    format!("{}.write", self.name),
+                    vec![],
+                    vec![self.out_slot],
+                )),
+                description: format!("{} wrote S{}", self.name, self.out_slot),
+            })
+        }
+    }
+
+    #[test]
+    fn pipeline_parallel_matches_serial_path() {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(|| {
+                let mut cap_a = [0.0_f32; SLOT_DIM];
+                cap_a[0] = 1.0;
+                let mut cap_b = [0.0_f32; SLOT_DIM];
+                cap_b[1] = 1.0;
+                let mut router = IntentRouter::new();
+                router.register(Box::new(SlotWriterStrand {
+                    name: "writer_b",
+                    capability: cap_b,
+                    out_slot: 9,
+                    value: 2.0,
+                }));
+                router.register(Box::new(SlotWriterStrand {
+                    name: "writer_a",
+                    capability: cap_a,
+                    out_slot: 8,
+                    value: 1.0,
+                }));
+                let pipeline = HardCorePipeline::new(router);
+
+                let mut frame = TensorFrame::new();
+                for (slot, cap) in [(1, cap_a), (2, cap_b)] {
+                    let mut pred = SlotData::new(SlotRole::Predicate);
+                    pred.write_resolution(0, cap);
+                    frame.write_slot(slot, pred).unwrap();
+                    frame.meta[slot].certainty = 0.8;
+                }
+
+                let parallel = pipeline.process_parallel(&frame).unwrap();
+
+                // Serial path: run each strand in turn with the other excluded.
+                let first = pipeline
+                    .process_excluding(&frame, &["writer_b".to_string()])
+                    .unwrap();
+                let serial = pipeline
+                    .process_excluding(&first.frame, &["writer_a".to_string()])
+                    .unwrap();
+
+                for i in 0..MAX_SLOTS {
+                    let p = parallel.frame.slots[i].as_ref().map(|s| s.resolutions[0]);
+                    let s = serial.frame.slots[i].as_ref().map(|s| s.resolutions[0]);
+                    assert_eq!(p, s, "slot {i} differs from serial path");
+                    assert_eq!(parallel.frame.meta[i].certainty, serial.frame.meta[i].certainty);
+                }
+                assert_eq!(
+                    parallel.frame.frame_meta.global_certainty,
+                    serial.frame.frame_meta.global_certainty
+                );
+
+                // Strand steps in slot order, then one certainty step.
+                let strand_steps = |chains: &[&ProofChain]| -> Vec<(String, String, bool)> {
+                    chains
+                        .iter()
+                        .flat_map(|c| c.steps.iter())
+                        .filter(|s| s.strand_name != "certainty_engine")
+                        .map(|s| (s.strand_name.clone(), s.op_code.clone(), s.activated))
+                        .collect()
+                };
+                let expected = strand_steps(&[&first.proof, &serial.proof]);
+                assert_eq!(strand_steps(&[&parallel.proof]), expected);
+                assert_eq!(expected[0].0, "writer_a");
+                assert_eq!(expected[1].0, "writer_b");
+                assert_eq!(parallel.proof.len(), 3);
+                assert_eq!(parallel.proof.activated_count, 3);
+
+                // Reproducible across runs.
+                let again = pipeline.process_parallel(&frame).unwrap();
+                assert_eq!(strand_steps(&[&again.proof]), expected);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn pipeline_router_accessors() {
         let mut pipeline = make_pipeline();
@@ -4,6 +4,8 @@
 //! It is pipeline infrastructure that accumulates a proof chain as strands
 //! process a frame. Each step records which strand executed, what it did,
 //! the routing similarity score, and the certainty (gamma) after that step.
+//! Alongside the human-readable description, each step carries a stable
+//! machine-readable `op_code` and the slot indices it read and wrote.
 //!
 //! # Example
 //!
@@ -21,6 +23,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::certainty_engine::PropagationRule;
 use crate::router::RoutingDecision;
 
 /// A single step in a proof chain.
@@ -39,6 +42,9 @@ use crate::router::RoutingDecision;
 ///     similarity: 0.92,
 ///     gamma_after: 1.0,
 ///     activated: true,
+///     op_code: "math_engine.add".to_string(),
+///     inputs: vec![6],
+///     outputs: vec![8],
 /// };
 /// assert!(step.activated);
 /// ```
@@ -58,6 +64,60 @@ pub struct ProofStep {
 
     /// Whether the strand actually activated and performed computation.
     pub activated: bool,
+
+    /// Stable machine-readable operation code, `"<strand>.<operation>"`
+    /// (e.g. `"math_engine.mul"`). Steps without a specific operation use
+    /// `"<strand>.process"` when activated and `"<strand>.skip"` otherwise.
+    #[serde(default)]
+    pub op_code: String,
+
+    /// Slot indices the operation read from.
+    #[serde(default)]
+    pub inputs: Vec<usize>,
+
+    /// Slot indices the operation wrote to.
+    #[serde(default)]
+    pub outputs: Vec<usize>,
+}
+
+/// The machine-readable part of a proof step, reported by a strand.
+///
+/// # Example
+///
+/// ```
+/// use volt_hard::proof_constructor::ProofOperation;
+///
+/// let op = ProofOperation::new("math_engine.mul", vec![6], vec![8]);
+/// assert_eq!(op.op_code, "math_engine.mul");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofOperation {
+    /// Stable operation code, `"<strand>.<operation>"`.
+    pub op_code: String,
+    /// Slot indices read.
+    pub inputs: Vec<usize>,
+    /// Slot indices written.
+    pub outputs: Vec<usize>,
+}
+
+impl ProofOperation {
+    /// Creates an operation record.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::proof_constructor::ProofOperation;
+    ///
+    /// let op = ProofOperation::new("hdc_algebra.bind", vec![6, 0, 2], vec![8]);
+    /// assert_eq!(op.inputs, vec![6, 0, 2]);
+    /// ```
+    pub fn new(op_code: impl Into<String>, inputs: Vec<usize>, outputs: Vec<usize>) -> Self {
+        Self {
+            op_code: op_code.into(),
+            inputs,
+            outputs,
+        }
+    }
 }
 
 /// A complete proof chain recording all Hard Strand processing for a frame.
@@ -122,6 +182,63 @@ impl ProofChain {
     pub fn is_empty(&self) -> bool {
         self.steps.is_empty()
     }
+
+    /// Renders the chain as a Graphviz DOT digraph.
+    ///
+    /// Each step becomes a node `stepN` labelled with its strand name,
+    /// routing similarity, gamma after the step and activation flag;
+    /// activated steps are filled. Edges follow execution order. The
+    /// output can be pasted into any DOT renderer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::proof_constructor::ProofConstructor;
+    ///
+    /// let mut proof = ProofConstructor::new();
+    /// proof.record_step("math_engine", "2 + 2 = 4", 0.95, 1.0, true);
+    /// proof.record_certainty_propagation(0.8);
+    ///
+    /// let dot = proof.build(0.8).to_dot();
+    /// assert!(dot.starts_with("digraph proof {"));
+    /// assert!(dot.contains("step0 -> step1;"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph proof {\n    rankdir=LR;\n    node [shape=box];\n");
+        for (i, step) in self.steps.iter().enumerate() {
+            let style = if step.activated {
+                ", style=filled, fillcolor=lightblue"
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    step{i} [label=\"{}\\nsim={:.3} gamma={:.3}\\nactivated={}\"{style}];\n",
+                escape_dot(&step.strand_name),
+                step.similarity,
+                step.gamma_after,
+                step.activated,
+            ));
+        }
+        for i in 1..self.steps.len() {
+            dot.push_str(&format!("    step{} -> step{i};\n", i - 1));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Escapes a string for use inside a double-quoted DOT label.
+fn escape_dot(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
 }
 
 /// Constructs proof chains by recording Hard Strand processing steps.
@@ -199,6 +316,45 @@ impl ProofConstructor {
         similarity: f32,
         gamma_after: f32,
         activated: bool,
+    ) {
+        let op_code = if activated {
+            format!("{strand_name}.process")
+        } else {
+            format!("{strand_name}.skip")
+        };
+        self.record_operation(
+            strand_name,
+            description,
+            similarity,
+            gamma_after,
+            activated,
+            ProofOperation::new(op_code, Vec::new(), Vec::new()),
+        );
+    }
+
+    /// Record a proof step with its machine-readable operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::proof_constructor::{ProofConstructor, ProofOperation};
+    ///
+    /// let mut proof = ProofConstructor::new();
+    /// let op = ProofOperation::new("math_engine.mul", vec![6], vec![8]);
+    /// proof.record_operation("math_engine", "6 * 7 = 42", 0.9, 1.0, true, op);
+    ///
+    /// let chain = proof.build(1.0);
+    /// assert_eq!(chain.steps[0].op_code, "math_engine.mul");
+    /// assert_eq!(chain.steps[0].outputs, vec![8]);
+    /// ```
+    pub fn record_operation(
+        &mut self,
+        strand_name: &str,
+        description: &str,
+        similarity: f32,
+        gamma_after: f32,
+        activated: bool,
+        operation: ProofOperation,
     ) {
         self.steps.push(ProofStep {
             strand_name: strand_name.to_string(),
@@ -206,11 +362,16 @@ impl ProofConstructor {
             similarity,
             gamma_after,
             activated,
+            op_code: operation.op_code,
+            inputs: operation.inputs,
+            outputs: operation.outputs,
         });
     }
 
     /// Record a step from a [`RoutingDecision`] and strand result description.
     ///
+    /// Uses the decision's [`ProofOperation`] when the strand reported one.
+    ///
     /// # Example
     ///
     /// ```
@@ -223,6 +384,7 @@ impl ProofConstructor {
     ///     slot_index: 1,
     ///     similarity: 0.95,
     ///     activated: true,
+    ///     operation: None,
     /// };
     /// proof.record_from_decision(&decision, "math_engine: 1 + 1 = 2", 1.0);
     ///
@@ -235,13 +397,23 @@ impl ProofConstructor {
         description: &str,
         gamma_after: f32,
     ) {
-        self.record_step(
-            &decision.strand_name,
-            description,
-            decision.similarity,
-            gamma_after,
-            decision.activated,
-        );
+        match &decision.operation {
+            Some(operation) => self.record_operation(
+                &decision.strand_name,
+                description,
+                decision.similarity,
+                gamma_after,
+                decision.activated,
+                operation.clone(),
+            ),
+            None => self.record_step(
+                &decision.strand_name,
+                description,
+                decision.similarity,
+                gamma_after,
+                decision.activated,
+            ),
+        }
     }
 
     /// Record a CertaintyEngine propagation step.
@@ -259,12 +431,42 @@ impl ProofConstructor {
     /// assert!(chain.steps[0].activated);
     /// ```
     pub fn record_certainty_propagation(&mut self, global_gamma: f32) {
+        self.record_certainty_rule(global_gamma, PropagationRule::Min);
+    }
+
+    /// Record a CertaintyEngine propagation step that used `rule`.
+    ///
+    /// The op code is `"certainty_engine.<rule>"`, e.g.
+    /// `"certainty_engine.product"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::certainty_engine::PropagationRule;
+    /// use volt_hard::proof_constructor::ProofConstructor;
+    ///
+    /// let mut proof = ProofConstructor::new();
+    /// proof.record_certainty_rule(1.0, PropagationRule::Override(1.0));
+    ///
+    /// let chain = proof.build(1.0);
+    /// assert_eq!(chain.steps[0].op_code, "certainty_engine.override");
+    /// ```
+    pub fn record_certainty_rule(&mut self, global_gamma: f32, rule: PropagationRule) {
+        let label = match rule {
+            PropagationRule::Min => "min-rule",
+            PropagationRule::Product => "product-rule",
+            PropagationRule::Override(_) => "override",
+            PropagationRule::WeightedMean => "weighted-mean",
+        };
         self.steps.push(ProofStep {
             strand_name: "certainty_engine".to_string(),
-            description: format!("min-rule propagation: global_gamma = {global_gamma:.4}"),
+            description: format!("{label} propagation: global_gamma = {global_gamma:.4}"),
             similarity: 1.0,
             gamma_after: global_gamma,
             activated: true,
+            op_code: format!("certainty_engine.{}", rule.op_name()),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
         });
     }
 
@@ -366,6 +568,7 @@ mod tests {
             slot_index: 1,
             similarity: 0.95,
             activated: true,
+            operation: None,
         };
         proof.record_from_decision(&decision, "847 * 392 = 332024", 1.0);
 
@@ -419,6 +622,36 @@ mod tests {
         assert!((chain.final_gamma - 0.42).abs() < 0.01);
     }
 
+    #[test]
+    fn proof_chain_to_dot_has_node_per_step() {
+        let mut proof = ProofConstructor::new();
+        proof.record_step("math_engine", "2 + 2 = 4", 0.92, 1.0, true);
+        proof.record_step("hdc_algebra", "skipped", 0.1, 1.0, false);
+        proof.record_certainty_propagation(0.8);
+
+        let dot = proof.build(0.8).to_dot();
+        assert!(dot.starts_with("digraph proof {"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+        assert!(dot.trim_end().ends_with('}'));
+        for i in 0..3 {
+            assert!(dot.contains(&format!("    step{i} [label=")));
+        }
+        assert!(dot.contains("step0 -> step1;"));
+        assert!(dot.contains("step1 -> step2;"));
+        assert!(dot.contains("activated=false"));
+        assert!(dot.contains("sim=0.920 gamma=1.000"));
+    }
+
+    #[test]
+    fn proof_chain_to_dot_escapes_labels() {
+        let mut proof = ProofConstructor::new();
+        proof.record_step("odd\"name\\", "x", 0.5, 0.5, true);
+
+        let dot = proof.build(0.5).to_dot();
+        assert!(dot.contains("label=\"odd\\\"name\\\\\\n"));
+        assert!(!dot.contains("->"));
+    }
+
     #[test]
     fn proof_constructor_default_trait() {
         let proof = ProofConstructor::default();
@@ -21,6 +21,8 @@ use std::panic::{catch_unwind, AssertUnwindSafe};
 use volt_bus::similarity;
 use volt_core::{TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
 
+use crate::certainty_engine::PropagationRule;
+use crate::proof_constructor::ProofOperation;
 use crate::strand::HardStrand;
 
 /// A routing decision made by the Intent Router.
@@ -38,6 +40,7 @@ use crate::strand::HardStrand;
 ///     slot_index: 6,
 ///     similarity: 0.85,
 ///     activated: true,
+///     operation: None,
 /// };
 /// assert!(decision.activated);
 /// ```
@@ -54,6 +57,9 @@ pub struct RoutingDecision {
 
     /// Whether the strand was actually activated (similarity >= threshold).
     pub activated: bool,
+
+    /// The operation the strand reported, if it activated and reported one.
+    pub operation: Option<ProofOperation>,
 }
 
 /// The result of the full Hard Core pipeline (router + strand execution).
@@ -136,6 +142,19 @@ impl IntentRouter {
         self.strands.push(strand);
     }
 
+    /// Register an async (I/O-bound) strand, driven on the runtime
+    /// behind `handle` via [`AsyncStrandAdapter`](crate::async_strand::AsyncStrandAdapter).
+    #[cfg(feature = "async")]
+    pub fn register_async(
+        &mut self,
+        strand: Box<dyn crate::async_strand::AsyncHardStrand>,
+        handle: tokio::runtime::Handle,
+    ) {
+        self.register(Box::new(crate::async_strand::AsyncStrandAdapter::new(
+            strand, handle,
+        )));
+    }
+
     /// Returns the number of registered strands.
     ///
     /// # Example
@@ -191,6 +210,91 @@ impl IntentRouter {
         self.strands.iter().map(|s| s.name()).collect()
     }
 
+    /// The [`PropagationRule`] declared by the strand named `name`, or
+    /// `None` if no such strand is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::certainty_engine::PropagationRule;
+    /// use volt_hard::router::IntentRouter;
+    /// use volt_hard::math_engine::MathEngine;
+    ///
+    /// let mut router = IntentRouter::new();
+    /// router.register(Box::new(MathEngine::new()));
+    /// assert_eq!(
+    ///     router.propagation_rule("math_engine"),
+    ///     Some(PropagationRule::Override(1.0))
+    /// );
+    /// assert_eq!(router.propagation_rule("nonexistent"), None);
+    /// ```
+    pub fn propagation_rule(&self, name: &str) -> Option<PropagationRule> {
+        self.strands
+            .iter()
+            .find(|s| s.name() == name)
+            .map(|s| s.propagation_rule())
+    }
+
+    /// The capability vector of the strand named `name`, or `None` if no
+    /// such strand is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::router::IntentRouter;
+    /// use volt_hard::math_engine::MathEngine;
+    /// use volt_hard::strand::HardStrand;
+    ///
+    /// let mut router = IntentRouter::new();
+    /// let engine = MathEngine::new();
+    /// let cap = *engine.capability_vector();
+    /// router.register(Box::new(engine));
+    /// assert_eq!(router.capability_vector("math_engine"), Some(&cap));
+    /// assert!(router.capability_vector("nonexistent").is_none());
+    /// ```
+    pub fn capability_vector(&self, name: &str) -> Option<&[f32; SLOT_DIM]> {
+        self.strands
+            .iter()
+            .find(|s| s.name() == name)
+            .map(|s| s.capability_vector())
+    }
+
+    /// Rank registered strands by cosine similarity to `vector`.
+    ///
+    /// Returns up to `k` `(strand name, similarity)` pairs, highest first.
+    /// Thresholds are not applied; ties keep registration order. Used to
+    /// find runner-up strands for ambiguous queries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::router::IntentRouter;
+    /// use volt_hard::math_engine::MathEngine;
+    /// use volt_hard::hdc_algebra::HDCAlgebra;
+    /// use volt_hard::strand::HardStrand;
+    ///
+    /// let mut router = IntentRouter::new();
+    /// let engine = MathEngine::new();
+    /// let cap = *engine.capability_vector();
+    /// router.register(Box::new(HDCAlgebra::new()));
+    /// router.register(Box::new(engine));
+    ///
+    /// let ranked = router.route_top_k(&cap, 2);
+    /// assert_eq!(ranked[0].0, "math_engine");
+    /// assert!((ranked[0].1 - 1.0).abs() < 1e-5);
+    /// assert_eq!(ranked.len(), 2);
+    /// ```
+    pub fn route_top_k(&self, vector: &[f32; SLOT_DIM], k: usize) -> Vec<(String, f32)> {
+        let mut ranked: Vec<(String, f32)> = self
+            .strands
+            .iter()
+            .map(|s| (s.name().to_string(), similarity(s.capability_vector(), vector)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked.truncate(k);
+        ranked
+    }
+
     /// Route a frame through the Hard Core pipeline.
     ///
     /// Computes cosine similarity between each strand's capability vector
@@ -237,6 +341,48 @@ impl IntentRouter {
     /// }).unwrap().join().unwrap();
     /// ```
     pub fn route(&self, frame: &TensorFrame) -> Result<RouterResult, VoltError> {
+        self.route_excluding(frame, &[])
+    }
+
+    /// Route a frame as [`route`](Self::route) does, skipping any strand
+    /// whose name appears in `excluded`.
+    ///
+    /// Names that match no registered strand are ignored. If every strand
+    /// is excluded, the frame passes through unchanged with no decisions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(VoltError)` if the chosen strand's `process()` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::router::IntentRouter;
+    /// use volt_hard::math_engine::MathEngine;
+    /// use volt_hard::strand::HardStrand;
+    /// use volt_core::{TensorFrame, SlotData, SlotRole};
+    ///
+    /// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+    ///     let mut router = IntentRouter::new();
+    ///     let engine = MathEngine::new();
+    ///     let cap = *engine.capability_vector();
+    ///     router.register(Box::new(engine));
+    ///
+    ///     let mut frame = TensorFrame::new();
+    ///     let mut pred = SlotData::new(SlotRole::Predicate);
+    ///     pred.write_resolution(0, cap);
+    ///     frame.write_slot(1, pred).unwrap();
+    ///
+    ///     let excluded = vec!["math_engine".to_string()];
+    ///     let result = router.route_excluding(&frame, &excluded).unwrap();
+    ///     assert!(result.decisions.is_empty());
+    /// }).unwrap().join().unwrap();
+    /// ```
+    pub fn route_excluding(
+        &self,
+        frame: &TensorFrame,
+        excluded: &[String],
+    ) -> Result<RouterResult, VoltError> {
         if self.strands.is_empty() {
             return Ok(RouterResult {
                 frame: frame.clone(),
@@ -266,6 +412,9 @@ impl IntentRouter {
         let mut best_sim: f32 = f32::NEG_INFINITY;
 
         for (strand_idx, strand) in self.strands.iter().enumerate() {
+            if excluded.iter().any(|name| name == strand.name()) {
+                continue;
+            }
             let cap = strand.capability_vector();
             for &(slot_idx, slot_vec) in &slot_vectors {
                 let sim = similarity(cap, slot_vec);
@@ -292,50 +441,10 @@ impl IntentRouter {
             let threshold = strand.threshold();
 
             if best_sim >= threshold {
-                // Activate the strand with panic safety.
-                // If a buggy module panics, we catch it, log an error,
-                // and pass the frame through unchanged rather than
-                // crashing the entire server.
-                let strand_name = strand.name().to_string();
-                let catch_result = catch_unwind(AssertUnwindSafe(|| strand.process(frame)));
-
-                match catch_result {
-                    Ok(Ok(strand_result)) => {
-                        decisions.push(RoutingDecision {
-                            strand_name,
-                            slot_index: best_slot_idx,
-                            similarity: best_sim,
-                            activated: strand_result.activated,
-                        });
-                        result_frame = strand_result.frame;
-                    }
-                    Ok(Err(e)) => {
-                        // Strand returned an error — propagate it.
-                        return Err(e);
-                    }
-                    Err(panic_payload) => {
-                        // Strand panicked — log and continue with frame unchanged.
-                        let panic_msg = if let Some(s) = panic_payload.downcast_ref::<&str>() {
-                            (*s).to_string()
-                        } else if let Some(s) = panic_payload.downcast_ref::<String>() {
-                            s.clone()
-                        } else {
-                            "unknown panic".to_string()
-                        };
-                        tracing::error!(
-                            strand = %strand_name,
-                            panic = %panic_msg,
-                            "Hard strand panicked during processing — skipping"
-                        );
-                        decisions.push(RoutingDecision {
-                            strand_name,
-                            slot_index: best_slot_idx,
-                            similarity: best_sim,
-                            activated: false,
-                        });
-                        result_frame = frame.clone();
-                    }
-                }
+                let (decision, strand_frame) =
+                    self.run_strand(strand_idx, frame, best_slot_idx, best_sim)?;
+                decisions.push(decision);
+                result_frame = strand_frame;
             } else {
                 // Below threshold — pass through
                 decisions.push(RoutingDecision {
@@ -343,6 +452,7 @@ impl IntentRouter {
                     slot_index: best_slot_idx,
                     similarity: best_sim,
                     activated: false,
+                    operation: None,
                 });
 
                 result_frame = frame.clone();
@@ -356,6 +466,250 @@ impl IntentRouter {
             decisions,
         })
     }
+
+    /// Route each active slot independently and run every strand that
+    /// wins at least one slot, concurrently, merging their outputs.
+    ///
+    /// Each active slot's R₀ vector picks its own best strand (ties go to
+    /// registration order); slots below that strand's threshold are
+    /// dropped. Slots that pick the same strand form one group, and each
+    /// group's strand processes the **input** frame on its own scoped
+    /// thread. Outputs are merged in slot order: every slot a strand
+    /// changed is copied into the result, a later group overwriting an
+    /// earlier one on conflict, and `frame_meta.verified` is set if any
+    /// strand set it. Decisions are ordered by slot index, so the result
+    /// is identical to running the groups one after another on the same
+    /// input.
+    ///
+    /// If no slot clears a threshold, this falls back to
+    /// [`route_excluding`](Self::route_excluding), so pass-through frames
+    /// record the same below-threshold decision.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first group (in slot order) whose strand
+    /// fails. Panicking strands are skipped as in `route_excluding`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::router::IntentRouter;
+    /// use volt_hard::math_engine::MathEngine;
+    /// use volt_core::TensorFrame;
+    ///
+    /// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+    ///     let mut router = IntentRouter::new();
+    ///     router.register(Box::new(MathEngine::new()));
+    ///     let result = router.route_parallel_excluding(&TensorFrame::new(), &[]).unwrap();
+    ///     assert!(result.decisions.is_empty());
+    /// }).unwrap().join().unwrap();
+    /// ```
+    pub fn route_parallel_excluding(
+        &self,
+        frame: &TensorFrame,
+        excluded: &[String],
+    ) -> Result<RouterResult, VoltError> {
+        let groups = self.slot_groups(frame, excluded);
+        if groups.is_empty() {
+            return self.route_excluding(frame, excluded);
+        }
+        self.execute_groups(frame, &groups, true)
+    }
+
+    /// Group active slots by the strand each one routes to, keeping only
+    /// slots at or above that strand's threshold. Groups are ordered by
+    /// their best-matching slot index.
+    fn slot_groups(&self, frame: &TensorFrame, excluded: &[String]) -> Vec<SlotGroup> {
+        let mut groups: Vec<SlotGroup> = Vec::new();
+        for (slot_idx, slot) in frame.slots.iter().enumerate() {
+            let Some(slot_vec) = slot.as_ref().and_then(|s| s.resolutions[0].as_ref()) else {
+                continue;
+            };
+            let mut best: Option<(usize, f32)> = None;
+            for (strand_idx, strand) in self.strands.iter().enumerate() {
+                if excluded.iter().any(|name| name == strand.name()) {
+                    continue;
+                }
+                let sim = similarity(strand.capability_vector(), slot_vec);
+                if best.is_none_or(|(_, best_sim)| sim > best_sim) {
+                    best = Some((strand_idx, sim));
+                }
+            }
+            let Some((strand_idx, sim)) = best else {
+                continue;
+            };
+            if sim < self.strands[strand_idx].threshold() {
+                continue;
+            }
+            match groups.iter_mut().find(|g| g.strand_idx == strand_idx) {
+                Some(group) if sim > group.similarity => {
+                    group.slot_index = slot_idx;
+                    group.similarity = sim;
+                }
+                Some(_) => {}
+                None => groups.push(SlotGroup {
+                    strand_idx,
+                    slot_index: slot_idx,
+                    similarity: sim,
+                }),
+            }
+        }
+        groups.sort_by_key(|g| g.slot_index);
+        groups
+    }
+
+    /// Run each group's strand on `frame` and merge the outputs in group
+    /// order. With `parallel`, groups run on scoped threads; the merged
+    /// result does not depend on it.
+    fn execute_groups(
+        &self,
+        frame: &TensorFrame,
+        groups: &[SlotGroup],
+        parallel: bool,
+    ) -> Result<RouterResult, VoltError> {
+        let outputs: Vec<Result<(RoutingDecision, TensorFrame), VoltError>> =
+            if parallel && groups.len() > 1 {
+                std::thread::scope(|scope| {
+                    let handles: Vec<_> = groups
+                        .iter()
+                        .map(|g| {
+                            std::thread::Builder::new()
+                                .stack_size(STRAND_THREAD_STACK)
+                                .spawn_scoped(scope, move || {
+                                    self.run_strand(g.strand_idx, frame, g.slot_index, g.similarity)
+                                })
+                        })
+                        .collect();
+                    handles
+                        .into_iter()
+                        .map(|handle| {
+                            handle
+                                .map_err(|e| VoltError::Internal {
+                                    message: format!("failed to spawn strand thread: {e}"),
+                                })?
+                                .join()
+                                .map_err(|_| VoltError::Internal {
+                                    message: "strand thread panicked".to_string(),
+                                })?
+                        })
+                        .collect()
+                })
+            } else {
+                groups
+                    .iter()
+                    .map(|g| self.run_strand(g.strand_idx, frame, g.slot_index, g.similarity))
+                    .collect()
+            };
+
+        let mut merged = frame.clone();
+        let mut decisions = Vec::with_capacity(outputs.len());
+        for output in outputs {
+            let (decision, strand_frame) = output?;
+            for i in 0..MAX_SLOTS {
+                if slot_changed(frame, &strand_frame, i) {
+                    if slot_changed(frame, &merged, i) {
+                        tracing::warn!(
+                            strand = %decision.strand_name,
+                            slot = i,
+                            "parallel strands wrote the same slot — keeping the later one"
+                        );
+                    }
+                    merged.slots[i] = strand_frame.slots[i].clone();
+                    merged.meta[i] = strand_frame.meta[i].clone();
+                }
+            }
+            merged.frame_meta.verified |= strand_frame.frame_meta.verified;
+            decisions.push(decision);
+        }
+
+        Ok(RouterResult {
+            frame: merged,
+            decisions,
+        })
+    }
+
+    /// Run one strand with panic safety, producing its routing decision
+    /// and output frame.
+    ///
+    /// If a buggy module panics, the panic is caught and logged, and the
+    /// frame passes through unchanged rather than crashing the server.
+    fn run_strand(
+        &self,
+        strand_idx: usize,
+        frame: &TensorFrame,
+        slot_index: usize,
+        similarity: f32,
+    ) -> Result<(RoutingDecision, TensorFrame), VoltError> {
+        let strand = &self.strands[strand_idx];
+        let strand_name = strand.name().to_string();
+        match catch_unwind(AssertUnwindSafe(|| strand.process(frame))) {
+            Ok(Ok(strand_result)) => Ok((
+                RoutingDecision {
+                    strand_name,
+                    slot_index,
+                    similarity,
+                    activated: strand_result.activated,
+                    operation: strand_result.operation,
+                },
+                strand_result.frame,
+            )),
+            // Strand returned an error — propagate it.
+            Ok(Err(e)) => Err(e),
+            Err(panic_payload) => {
+                let panic_msg = if let Some(s) = panic_payload.downcast_ref::<&str>() {
+                    (*s).to_string()
+                } else if let Some(s) = panic_payload.downcast_ref::<String>() {
+                    s.clone()
+                } else {
+                    "unknown panic".to_string()
+                };
+                tracing::error!(
+                    strand = %strand_name,
+                    panic = %panic_msg,
+                    "Hard strand panicked during processing — skipping"
+                );
+                Ok((
+                    RoutingDecision {
+                        strand_name,
+                        slot_index,
+                        similarity,
+                        activated: false,
+                        operation: None,
+                    },
+                    frame.clone(),
+                ))
+            }
+        }
+    }
+}
+
+/// Stack size for threads running strands in parallel; frames are large.
+const STRAND_THREAD_STACK: usize = 4 * 1024 * 1024;
+
+/// Slots routed to one strand by [`IntentRouter::route_parallel_excluding`].
+#[derive(Debug, Clone, Copy)]
+struct SlotGroup {
+    strand_idx: usize,
+    /// The group's best-matching slot, reported in its decision.
+    slot_index: usize,
+    similarity: f32,
+}
+
+/// Whether slot `i` (data or metadata) differs between two frames.
+fn slot_changed(before: &TensorFrame, after: &TensorFrame, i: usize) -> bool {
+    let data_changed = match (&before.slots[i], &after.slots[i]) {
+        (None, None) => false,
+        (Some(a), Some(b)) => {
+            a.role != b.role || a.codebook_id != b.codebook_id || a.resolutions != b.resolutions
+        }
+        _ => true,
+    };
+    let (a, b) = (&before.meta[i], &after.meta[i]);
+    data_changed
+        || a.certainty != b.certainty
+        || a.source != b.source
+        || a.updated_at != b.updated_at
+        || a.needs_verify != b.needs_verify
 }
 
 impl Default for IntentRouter {
@@ -368,7 +722,7 @@ impl Default for IntentRouter {
 mod tests {
     use super::*;
     use crate::math_engine::MathEngine;
-    use crate::strand::HardStrand;
+    use crate::strand::{HardStrand, StrandResult};
     use volt_core::{SlotData, SlotRole};
 
     #[test]
@@ -490,6 +844,208 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn router_skips_excluded_strands() {
+        std::thread::Builder::new()
+            .stack_size(4 * 1024 * 1024)
+            .spawn(|| {
+                let mut router = IntentRouter::new();
+                let engine = MathEngine::new();
+                let cap = *engine.capability_vector();
+                router.register(Box::new(engine));
+
+                let mut frame = TensorFrame::new();
+                let mut pred = SlotData::new(SlotRole::Predicate);
+                pred.write_resolution(0, cap);
+                frame.write_slot(1, pred).unwrap();
+                let mut instrument = SlotData::new(SlotRole::Instrument);
+                let mut data = [0.0_f32; SLOT_DIM];
+                data[0] = 1.0; // ADD
+                data[1] = 2.0;
+                data[2] = 3.0;
+                instrument.write_resolution(0, data);
+                frame.write_slot(6, instrument).unwrap();
+
+                // Unknown names are ignored: math still activates.
+                let unknown = vec!["no_such_strand".to_string()];
+                let result = router.route_excluding(&frame, &unknown).unwrap();
+                assert!(result.decisions[0].activated);
+
+                let excluded = vec!["math_engine".to_string()];
+                let result = router.route_excluding(&frame, &excluded).unwrap();
+                assert!(result.decisions.is_empty());
+                assert!(result.frame.read_slot(8).is_err());
+                assert_eq!(result.frame.active_slot_count(), frame.active_slot_count());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    /// Strand with a fixed capability vector that never activates.
+    struct FixedStrand {
+        name: &'static str,
+        capability: [f32; SLOT_DIM],
+    }
+
+    impl HardStrand for FixedStrand {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+            &self.capability
+        }
+
+        fn threshold(&self) -> f32 {
+            0.5
+        }
+
+        fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+            Ok(StrandResult {
+                frame: frame.clone(),
+                activated: false,
+                operation: None,
+                description: String::new(),
+            })
+        }
+    }
+
+    fn unit_vector(weights: &[(usize, f32)]) -> [f32; SLOT_DIM] {
+        let mut v = [0.0_f32; SLOT_DIM];
+        for &(i, w) in weights {
+            v[i] = w;
+        }
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        v.map(|x| x / norm)
+    }
+
+    /// Strand that activates and overwrites `out_slot` with its capability.
+    struct WriterStrand {
+        name: &'static str,
+        capability: [f32; SLOT_DIM],
+        out_slot: usize,
+    }
+
+    impl HardStrand for WriterStrand {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+            &self.capability
+        }
+
+        fn threshold(&self) -> f32 {
+            0.5
+        }
+
+        fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+            let mut result = frame.clone();
+            let mut slot = SlotData::new(SlotRole::Result);
+            slot.write_resolution(0, self.capability);
+            result.write_slot(self.out_slot, slot)?;
+            result.meta[self.out_slot].certainty = 1.0;
+            Ok(StrandResult {
+                frame: result,
+                activated: true,
+                operation: None,
+                description: String::new(),
+            })
+        }
+    }
+
+    #[test]
+    fn route_parallel_groups_slots_and_merges_in_slot_order() {
+        std::thread::Builder::new()
+            .stack_size(4 * 1024 * 1024)
+            .spawn(|| {
+                let cap_a = unit_vector(&[(0, 1.0)]);
+                let cap_b = unit_vector(&[(1, 1.0)]);
+                let mut router = IntentRouter::new();
+                router.register(Box::new(WriterStrand {
+                    name: "b",
+                    capability: cap_b,
+                    out_slot: 9,
+                }));
+                router.register(Box::new(WriterStrand {
+                    name: "a",
+                    capability: cap_a,
+                    out_slot: 8,
+                }));
+
+                let mut frame = TensorFrame::new();
+                for (slot, vector) in [
+                    (0, unit_vector(&[(1, 1.0), (0, 0.2)])),
+                    (1, cap_a),
+                    (3, cap_b),
+                    (4, unit_vector(&[(5, 1.0)])), // below both thresholds
+                ] {
+                    let mut data = SlotData::new(SlotRole::Predicate);
+                    data.write_resolution(0, vector);
+                    frame.write_slot(slot, data).unwrap();
+                }
+
+                let groups = router.slot_groups(&frame, &[]);
+                let picked: Vec<(&str, usize)> = groups
+                    .iter()
+                    .map(|g| (router.strands[g.strand_idx].name(), g.slot_index))
+                    .collect();
+                // "b" wins slots 0 and 3 (best: 3); "a" wins slot 1.
+                assert_eq!(picked, vec![("a", 1), ("b", 3)]);
+
+                let parallel = router.route_parallel_excluding(&frame, &[]).unwrap();
+                let serial = router.execute_groups(&frame, &groups, false).unwrap();
+                let names: Vec<&str> =
+                    parallel.decisions.iter().map(|d| d.strand_name.as_str()).collect();
+                assert_eq!(names, vec!["a", "b"]);
+                assert!(parallel.decisions.iter().all(|d| d.activated));
+                for i in 0..MAX_SLOTS {
+                    assert!(!slot_changed(&serial.frame, &parallel.frame, i), "slot {i}");
+                }
+                assert_eq!(parallel.frame.slots[8].as_ref().unwrap().resolutions[0], Some(cap_a));
+                assert_eq!(parallel.frame.slots[9].as_ref().unwrap().resolutions[0], Some(cap_b));
+
+                // Excluding both falls back to the serial router's pass-through.
+                let excluded = vec!["a".to_string(), "b".to_string()];
+                let none = router.route_parallel_excluding(&frame, &excluded).unwrap();
+                assert!(none.decisions.is_empty());
+                assert!(none.frame.slots[8].is_none());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn route_top_k_orders_strands_by_similarity() {
+        let mut router = IntentRouter::new();
+        for (name, weights) in [
+            ("x_axis", vec![(0, 1.0)]),
+            ("y_axis", vec![(1, 1.0)]),
+            ("diagonal", vec![(0, 1.0), (1, 1.0)]),
+        ] {
+            router.register(Box::new(FixedStrand {
+                name,
+                capability: unit_vector(&weights),
+            }));
+        }
+
+        // Query leans towards x: cos(x)=0.8, cos(diag)=(0.8+0.6)/√2, cos(y)=0.6
+        let query = unit_vector(&[(0, 0.8), (1, 0.6)]);
+        let ranked = router.route_top_k(&query, 3);
+        let names: Vec<&str> = ranked.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["diagonal", "x_axis", "y_axis"]);
+        let expected = [1.4 / 2.0_f32.sqrt(), 0.8, 0.6];
+        for ((_, score), want) in ranked.iter().zip(expected) {
+            assert!((score - want).abs() < 1e-5, "{score} vs {want}");
+        }
+
+        assert_eq!(router.route_top_k(&query, 2).len(), 2);
+        assert_eq!(router.route_top_k(&query, 10).len(), 3);
+        assert!(router.route_top_k(&query, 0).is_empty());
+    }
+
     #[test]
     fn router_preserves_frame_on_no_activation() {
         let mut router = IntentRouter::new();
@@ -15,6 +15,9 @@
 
 use volt_core::{ModuleInfo, TensorFrame, VoltError, SLOT_DIM};
 
+use crate::certainty_engine::PropagationRule;
+use crate::proof_constructor::ProofOperation;
+
 /// A pluggable deterministic computation module for the CPU Hard Core.
 ///
 /// Each Hard Strand:
@@ -42,6 +45,7 @@ use volt_core::{ModuleInfo, TensorFrame, VoltError, SLOT_DIM};
 ///         Ok(StrandResult {
 ///             frame: frame.clone(),
 ///             activated: false,
+///             operation: None,
 ///             description: "echo: no-op".to_string(),
 ///         })
 ///     }
@@ -87,6 +91,27 @@ pub trait HardStrand: Send + Sync {
     fn info(&self) -> Option<ModuleInfo> {
         None
     }
+
+    /// How the pipeline combines slot gammas into global certainty after
+    /// this strand activates.
+    ///
+    /// Defaults to [`PropagationRule::Min`]. Strands producing exact
+    /// answers can return [`PropagationRule::Override`] so their result
+    /// is not capped by less certain input slots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::certainty_engine::PropagationRule;
+    /// use volt_hard::hdc_algebra::HDCAlgebra;
+    /// use volt_hard::strand::HardStrand;
+    ///
+    /// let algebra = HDCAlgebra::new();
+    /// assert_eq!(algebra.propagation_rule(), PropagationRule::Min);
+    /// ```
+    fn propagation_rule(&self) -> PropagationRule {
+        PropagationRule::Min
+    }
 }
 
 /// The result of a [`HardStrand`] processing a frame.
@@ -100,6 +125,7 @@ pub trait HardStrand: Send + Sync {
 /// let result = StrandResult {
 ///     frame: TensorFrame::new(),
 ///     activated: false,
+///     operation: None,
 ///     description: "no-op".to_string(),
 /// };
 /// assert!(!result.activated);
@@ -119,4 +145,10 @@ pub struct StrandResult {
     ///
     /// Used for proof chain construction and logging.
     pub description: String,
+
+    /// Machine-readable record of the operation performed, if any.
+    ///
+    /// Activated strands should set this so proof consumers get a stable
+    /// op code and the slots touched without parsing `description`.
+    pub operation: Option<ProofOperation>,
 }
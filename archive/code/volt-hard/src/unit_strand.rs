@@ -0,0 +1,454 @@
+//! UnitConvertStrand Hard Strand — exact unit conversion.
+//!
+//! Converts a value between units of the same physical family (length,
+//! mass, temperature) and writes the result with gamma = 1.0. Converting
+//! across families (e.g. length → mass) is an error rather than a guess.
+//!
+//! ## Slot Convention
+//!
+//! | Slot | Resolution | Dim | Meaning |
+//! |------|-----------|-----|---------|
+//! | S6 (Instrument) | R0 | dim\[0\] = 40.0 | CONVERT operation code |
+//! | S6 (Instrument) | R0 | dim\[1\] | Source unit id |
+//! | S6 (Instrument) | R0 | dim\[2\] | Target unit id |
+//! | S6 (Instrument) | R0 | dim\[3\] | Value in the source unit |
+//! | S8 (Result) | R0 | dim\[0\] | Value in the target unit |
+//! | S8 (Result) | R0 | dim\[1\] | 1.0 = valid result |
+//!
+//! ## Unit Ids
+//!
+//! | Family | Ids |
+//! |--------|-----|
+//! | Length | 1 = m, 2 = km, 3 = cm, 4 = mi, 5 = ft, 6 = in |
+//! | Mass | 11 = kg, 12 = g, 13 = lb, 14 = oz |
+//! | Temperature | 21 = °C, 22 = °F, 23 = K |
+//!
+//! ## Feature Gate
+//!
+//! This module is behind `#[cfg(feature = "units")]`.
+
+use volt_core::{
+    module_info::{ModuleInfo, ModuleType},
+    slot::{SlotMeta, SlotSource},
+    SlotData, SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM,
+};
+
+use crate::proof_constructor::ProofOperation;
+use crate::strand::{HardStrand, StrandResult};
+
+/// Operation code for unit conversion (stored in S6 R0 dim[0]).
+const OP_CONVERT: f32 = 40.0;
+
+/// Slot index for operation input (Instrument = S6).
+const INSTRUMENT_SLOT: usize = 6;
+/// Slot index for result output (Result = S8).
+const RESULT_SLOT: usize = 8;
+
+/// Physical quantity a unit measures; only same-family conversions are valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Family {
+    Length,
+    Mass,
+    Temperature,
+}
+
+impl Family {
+    fn name(self) -> &'static str {
+        match self {
+            Family::Length => "length",
+            Family::Mass => "mass",
+            Family::Temperature => "temperature",
+        }
+    }
+}
+
+/// A unit as an affine map to its family's base unit:
+/// `base = value * scale + offset` (base: metre, kilogram, kelvin).
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    id: u32,
+    symbol: &'static str,
+    family: Family,
+    scale: f64,
+    offset: f64,
+}
+
+const fn unit(id: u32, symbol: &'static str, family: Family, scale: f64, offset: f64) -> Unit {
+    Unit {
+        id,
+        symbol,
+        family,
+        scale,
+        offset,
+    }
+}
+
+const UNITS: [Unit; 13] = [
+    unit(1, "m", Family::Length, 1.0, 0.0),
+    unit(2, "km", Family::Length, 1000.0, 0.0),
+    unit(3, "cm", Family::Length, 0.01, 0.0),
+    unit(4, "mi", Family::Length, 1609.344, 0.0),
+    unit(5, "ft", Family::Length, 0.3048, 0.0),
+    unit(6, "in", Family::Length, 0.0254, 0.0),
+    unit(11, "kg", Family::Mass, 1.0, 0.0),
+    unit(12, "g", Family::Mass, 0.001, 0.0),
+    unit(13, "lb", Family::Mass, 0.453_592_37, 0.0),
+    unit(14, "oz", Family::Mass, 0.028_349_523_125, 0.0),
+    unit(21, "°C", Family::Temperature, 1.0, 273.15),
+    unit(
+        22,
+        "°F",
+        Family::Temperature,
+        5.0 / 9.0,
+        273.15 - 32.0 * 5.0 / 9.0,
+    ),
+    unit(23, "K", Family::Temperature, 1.0, 0.0),
+];
+
+fn strand_error(message: String) -> VoltError {
+    VoltError::StrandError {
+        strand_id: 0,
+        message: format!("unit_convert: {message}"),
+    }
+}
+
+/// Look up a unit by its encoded id.
+fn lookup_unit(id: f32) -> Result<Unit, VoltError> {
+    UNITS
+        .iter()
+        .find(|u| (id - u.id as f32).abs() < 0.1)
+        .copied()
+        .ok_or_else(|| strand_error(format!("unknown unit id {id}")))
+}
+
+/// Convert `value` from `from` to `to`, rejecting cross-family conversions.
+fn convert(value: f64, from: Unit, to: Unit) -> Result<f64, VoltError> {
+    if from.family != to.family {
+        return Err(strand_error(format!(
+            "cannot convert {} ({}) to {} ({})",
+            from.symbol,
+            from.family.name(),
+            to.symbol,
+            to.family.name()
+        )));
+    }
+    Ok((value * from.scale + from.offset - to.offset) / to.scale)
+}
+
+/// The UnitConvertStrand Hard Strand — converts between units of
+/// length, mass, and temperature.
+///
+/// # Example
+///
+/// ```
+/// use volt_hard::unit_strand::UnitConvertStrand;
+/// use volt_hard::strand::HardStrand;
+/// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+///
+/// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+///     let strand = UnitConvertStrand::new();
+///     let mut frame = TensorFrame::new();
+///     let mut inst = SlotData::new(SlotRole::Instrument);
+///     let mut data = [0.0_f32; SLOT_DIM];
+///     data[0] = 40.0; // OP_CONVERT
+///     data[1] = 21.0; // °C
+///     data[2] = 22.0; // °F
+///     data[3] = 100.0;
+///     inst.write_resolution(0, data);
+///     frame.write_slot(6, inst).unwrap();
+///     frame.meta[6].certainty = 0.9;
+///
+///     let result = strand.process(&frame).unwrap();
+///     let r0 = result.frame.read_slot(8).unwrap().resolutions[0].unwrap();
+///     assert!((r0[0] - 212.0).abs() < 1e-3);
+/// }).unwrap().join().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnitConvertStrand {
+    /// Pre-computed capability vector for routing.
+    capability: [f32; SLOT_DIM],
+}
+
+impl UnitConvertStrand {
+    /// Create a new `UnitConvertStrand` with a deterministic capability vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_hard::unit_strand::UnitConvertStrand;
+    /// use volt_hard::strand::HardStrand;
+    ///
+    /// let strand = UnitConvertStrand::new();
+    /// assert_eq!(strand.name(), "unit_convert");
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            capability: Self::build_capability_vector(),
+        }
+    }
+
+    /// Build the deterministic capability vector for unit conversion queries.
+    fn build_capability_vector() -> [f32; SLOT_DIM] {
+        const UNITS_SEED: u64 = 0x554e_4954_434e_5631; // "UNITCNV1"
+        let mut v = [0.0_f32; SLOT_DIM];
+        for (i, val) in v.iter_mut().enumerate() {
+            let mut h = UNITS_SEED.wrapping_mul(0xd2b7_4407_b1ce_6e93);
+            h = h.wrapping_add(i as u64);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+            h ^= h >> 33;
+            h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+            h ^= h >> 33;
+            *val = ((h as f64 / u64::MAX as f64) * 2.0 - 1.0) as f32;
+        }
+        // L2 normalize
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 1e-10 {
+            for x in &mut v {
+                *x /= norm;
+            }
+        }
+        v
+    }
+}
+
+impl Default for UnitConvertStrand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HardStrand for UnitConvertStrand {
+    fn name(&self) -> &str {
+        "unit_convert"
+    }
+
+    fn capability_vector(&self) -> &[f32; SLOT_DIM] {
+        &self.capability
+    }
+
+    fn threshold(&self) -> f32 {
+        0.3
+    }
+
+    fn process(&self, frame: &TensorFrame) -> Result<StrandResult, VoltError> {
+        let inst_data = frame.slots[INSTRUMENT_SLOT]
+            .as_ref()
+            .and_then(|slot| slot.resolutions[0].as_ref());
+
+        let Some(r0) = inst_data else {
+            return Ok(StrandResult {
+                frame: frame.clone(),
+                activated: false,
+                operation: None,
+                description: "unit_convert: no instrument data".to_string(),
+            });
+        };
+
+        let op_code = r0[0];
+        if (op_code - OP_CONVERT).abs() >= 0.5 {
+            return Ok(StrandResult {
+                frame: frame.clone(),
+                activated: false,
+                operation: None,
+                description: format!("unit_convert: unrecognized op code {op_code}"),
+            });
+        }
+
+        let from = lookup_unit(r0[1])?;
+        let to = lookup_unit(r0[2])?;
+        let value = r0[3];
+        if !value.is_finite() {
+            return Err(strand_error(format!("value is not finite: {value}")));
+        }
+        let converted = convert(f64::from(value), from, to)? as f32;
+
+        let mut result = frame.clone();
+
+        let mut result_data = [0.0_f32; SLOT_DIM];
+        result_data[0] = converted;
+        result_data[1] = 1.0; // valid flag
+
+        let mut result_slot = SlotData::new(SlotRole::Result);
+        result_slot.write_resolution(0, result_data);
+        result.write_slot(RESULT_SLOT, result_slot)?;
+
+        // Conversion factors are exact definitions: gamma = 1.0
+        result.meta[RESULT_SLOT] = SlotMeta {
+            certainty: 1.0,
+            source: SlotSource::HardCore,
+            updated_at: 0,
+            needs_verify: false,
+        };
+
+        result.frame_meta.verified = true;
+        result.frame_meta.proof_length += 1;
+
+        // Update global certainty (min-rule over active slots)
+        let min_gamma = (0..MAX_SLOTS)
+            .filter(|&i| result.slots[i].is_some())
+            .map(|i| result.meta[i].certainty)
+            .fold(f32::INFINITY, f32::min);
+        if min_gamma.is_finite() {
+            result.frame_meta.global_certainty = min_gamma;
+        }
+
+        let op_name = match from.family {
+            Family::Length => "unit_convert.length",
+            Family::Mass => "unit_convert.mass",
+            Family::Temperature => "unit_convert.temperature",
+        };
+
+        Ok(StrandResult {
+            frame: result,
+            activated: true,
+            description: format!(
+                "unit_convert: {value} {} = {converted} {}",
+                from.symbol, to.symbol
+            ),
+            operation: Some(ProofOperation::new(
+                op_name,
+                vec![INSTRUMENT_SLOT],
+                vec![RESULT_SLOT],
+            )),
+        })
+    }
+
+    fn info(&self) -> Option<ModuleInfo> {
+        Some(ModuleInfo {
+            id: "volt-strand-units".to_string(),
+            display_name: "Unit Conversion Strand".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            author: "Volt X Team".to_string(),
+            description: "Exact length, mass, and temperature conversion.".to_string(),
+            module_type: ModuleType::HardStrand,
+            min_core_version: "0.1.0".to_string(),
+            requires: vec![],
+            deterministic: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_STACK: usize = 4 * 1024 * 1024;
+
+    fn make_convert_frame(from: f32, to: f32, value: f32) -> TensorFrame {
+        let strand = UnitConvertStrand::new();
+        let mut frame = TensorFrame::new();
+
+        let mut pred = SlotData::new(SlotRole::Predicate);
+        pred.write_resolution(0, *strand.capability_vector());
+        frame.write_slot(1, pred).unwrap();
+        frame.meta[1].certainty = 0.8;
+
+        let mut inst = SlotData::new(SlotRole::Instrument);
+        let mut data = [0.0_f32; SLOT_DIM];
+        data[0] = OP_CONVERT;
+        data[1] = from;
+        data[2] = to;
+        data[3] = value;
+        inst.write_resolution(0, data);
+        frame.write_slot(INSTRUMENT_SLOT, inst).unwrap();
+        frame.meta[INSTRUMENT_SLOT].certainty = 0.9;
+        frame
+    }
+
+    fn converted(from: f32, to: f32, value: f32) -> Result<f32, VoltError> {
+        let frame = make_convert_frame(from, to, value);
+        let result = UnitConvertStrand::new().process(&frame)?;
+        Ok(result.frame.read_slot(RESULT_SLOT).unwrap().resolutions[0].unwrap()[0])
+    }
+
+    fn run<F: FnOnce() + Send + 'static>(f: F) {
+        std::thread::Builder::new()
+            .stack_size(TEST_STACK)
+            .spawn(f)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn unit_ids_are_unique() {
+        for (i, a) in UNITS.iter().enumerate() {
+            assert!(UNITS[i + 1..].iter().all(|b| b.id != a.id), "{}", a.symbol);
+        }
+    }
+
+    #[test]
+    fn km_to_miles() {
+        run(|| {
+            let miles = converted(2.0, 4.0, 100.0).unwrap();
+            assert!((miles - 62.137).abs() < 1e-3, "got {miles}");
+        });
+    }
+
+    #[test]
+    fn mass_and_temperature_conversions() {
+        run(|| {
+            assert!((converted(11.0, 13.0, 1.0).unwrap() - 2.204_623).abs() < 1e-4);
+            assert!((converted(22.0, 21.0, 98.6).unwrap() - 37.0).abs() < 1e-3);
+            assert!((converted(21.0, 23.0, -273.15).unwrap()).abs() < 1e-3);
+        });
+    }
+
+    #[test]
+    fn length_to_mass_is_an_error() {
+        run(|| {
+            let err = converted(2.0, 11.0, 5.0).unwrap_err();
+            assert!(matches!(err, VoltError::StrandError { .. }));
+            assert!(err.to_string().contains("length"), "{err}");
+        });
+    }
+
+    #[test]
+    fn unknown_unit_is_an_error() {
+        run(|| {
+            assert!(converted(2.0, 99.0, 5.0).is_err());
+        });
+    }
+
+    #[test]
+    fn result_has_full_certainty_and_proof_op() {
+        run(|| {
+            let frame = make_convert_frame(5.0, 6.0, 1.0);
+            let result = UnitConvertStrand::new().process(&frame).unwrap();
+            assert!(result.activated);
+            assert_eq!(result.frame.meta[RESULT_SLOT].certainty, 1.0);
+            assert_eq!(
+                result.operation.unwrap().op_code,
+                "unit_convert.length".to_string()
+            );
+        });
+    }
+
+    #[test]
+    fn non_convert_op_passes_through() {
+        run(|| {
+            let mut frame = make_convert_frame(2.0, 4.0, 1.0);
+            frame.slots[INSTRUMENT_SLOT].as_mut().unwrap().resolutions[0]
+                .as_mut()
+                .unwrap()[0] = 3.0;
+            let result = UnitConvertStrand::new().process(&frame).unwrap();
+            assert!(!result.activated);
+        });
+    }
+
+    #[test]
+    fn conversion_routes_through_default_pipeline() {
+        run(|| {
+            let frame = make_convert_frame(2.0, 4.0, 100.0);
+            let result = crate::default_pipeline().process(&frame).unwrap();
+            let r0 = result.frame.read_slot(RESULT_SLOT).unwrap().resolutions[0].unwrap();
+            assert!((r0[0] - 62.137).abs() < 1e-3);
+            assert!(result
+                .proof
+                .steps
+                .iter()
+                .any(|step| step.strand_name == "unit_convert"));
+        });
+    }
+}
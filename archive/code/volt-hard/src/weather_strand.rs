@@ -34,6 +34,7 @@ use volt_core::{
     SlotData, SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM,
 };
 
+use crate::proof_constructor::ProofOperation;
 use crate::strand::{HardStrand, StrandResult};
 
 /// Operation code for weather queries (stored in S6 R0 dim[0]).
@@ -186,6 +187,7 @@ impl HardStrand for WeatherStrand {
             return Ok(StrandResult {
                 frame: frame.clone(),
                 activated: false,
+                operation: None,
                 description: "weather: no instrument data".to_string(),
             });
         };
@@ -195,6 +197,7 @@ impl HardStrand for WeatherStrand {
             return Ok(StrandResult {
                 frame: frame.clone(),
                 activated: false,
+                operation: None,
                 description: format!("weather: unrecognized op code {op_code}"),
             });
         }
@@ -242,6 +245,11 @@ impl HardStrand for WeatherStrand {
             frame: result,
             activated: true,
             description,
+            operation: Some(ProofOperation::new(
+                "weather.lookup",
+                vec![INSTRUMENT_SLOT],
+                vec![RESULT_SLOT],
+            )),
         })
     }
 
@@ -253,6 +261,9 @@ impl HardStrand for WeatherStrand {
             author: "Volt X Team".to_string(),
             description: "Example community module providing mock weather data.".to_string(),
             module_type: ModuleType::HardStrand,
+            min_core_version: "0.1.0".to_string(),
+            requires: vec![],
+            deterministic: true,
         })
     }
 }
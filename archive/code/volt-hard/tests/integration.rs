@@ -429,6 +429,7 @@ fn custom_strand_implementation() {
                     Ok(volt_hard::strand::StrandResult {
                         frame: frame.clone(),
                         activated: false,
+                        operation: None,
                         description: "null: no-op".to_string(),
                     })
                 }
@@ -733,15 +734,16 @@ fn pipeline_end_to_end_math_with_proof() {
                 "Pipeline: at least 1 strand should activate"
             );
 
-            // Verify certainty propagation
+            // Verify certainty propagation: MathEngine's exact answer
+            // overrides the min-rule
             assert!(
-                (result.frame.frame_meta.global_certainty - 0.8).abs() < 0.01,
-                "Pipeline: global certainty should be 0.8 (min), got {}",
+                (result.frame.frame_meta.global_certainty - 1.0).abs() < 0.01,
+                "Pipeline: global certainty should be 1.0 (override), got {}",
                 result.frame.frame_meta.global_certainty
             );
             assert!(
-                (result.proof.final_gamma - 0.8).abs() < 0.01,
-                "Pipeline: proof final_gamma should be 0.8, got {}",
+                (result.proof.final_gamma - 1.0).abs() < 0.01,
+                "Pipeline: proof final_gamma should be 1.0, got {}",
                 result.proof.final_gamma
             );
         })
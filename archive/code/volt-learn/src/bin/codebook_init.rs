@@ -129,6 +129,7 @@ fn main() {
         },
         output_path,
         log_interval: 10_000,
+        calibration_holdout: 10_000,
     };
 
     // Run on a thread with large stack (TensorFrame is ~64KB, Windows default is 1MB)
@@ -150,6 +151,10 @@ fn main() {
             eprintln!("  Vectors used (k-means): {}", r.vectors_used_for_kmeans);
             eprintln!("  K-means iterations:     {}", r.kmeans_iterations);
             eprintln!("  Mean quant. error:      {:.6}", r.mean_quantization_error);
+            match r.calibration {
+                Some(c) => eprintln!("  Calibration:            scale={:.4}, bias={:.4}", c.scale, c.bias),
+                None => eprintln!("  Calibration:            none (raw similarity)"),
+            }
             eprintln!("  Saved to:               {}", r.codebook_path.display());
         }
         Err(e) => {
@@ -10,7 +10,8 @@
 //! ```text
 //! The Stack JSONL → StackCorpusReader → Translator.encode()
 //!     → extract R0 slot vectors → subsample → mini-batch k-means
-//!     → Codebook::from_entries(centroids) → save()
+//!     → Codebook::from_entries(centroids)
+//!     → fit decode calibration on held-out vectors → save()
 //! ```
 //!
 //! # Example
@@ -28,6 +29,7 @@
 //!     kmeans_config: KMeansConfig { k: 65_536, ..Default::default() },
 //!     output_path: PathBuf::from("checkpoints/codebook_code.bin"),
 //!     log_interval: 10_000,
+//!     calibration_holdout: 10_000,
 //! };
 //! let translator = StubTranslator::new();
 //! let result = init_codebook_from_corpus(&config, &translator).unwrap();
@@ -38,7 +40,7 @@ use std::path::{Path, PathBuf};
 
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
-use volt_bus::codebook::Codebook;
+use volt_bus::codebook::{Calibration, Codebook};
 use volt_core::{TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
 use volt_translate::Translator;
 
@@ -60,6 +62,9 @@ pub struct CodebookInitConfig {
     pub output_path: PathBuf,
     /// Print progress every N files (0 = no progress output).
     pub log_interval: usize,
+    /// Collected vectors held out of k-means and used to fit the
+    /// codebook's decode-confidence [`Calibration`] (0 = no calibration).
+    pub calibration_holdout: usize,
 }
 
 impl Default for CodebookInitConfig {
@@ -71,6 +76,7 @@ impl Default for CodebookInitConfig {
             kmeans_config: KMeansConfig::default(),
             output_path: PathBuf::from("checkpoints/codebook_code.bin"),
             log_interval: 10_000,
+            calibration_holdout: 10_000,
         }
     }
 }
@@ -92,8 +98,15 @@ pub struct CodebookInitResult {
     pub mean_quantization_error: f32,
     /// Path where the codebook was saved.
     pub codebook_path: PathBuf,
+    /// Decode-confidence calibration saved with the codebook, or `None`
+    /// if no vectors were held out or the fit failed.
+    pub calibration: Option<Calibration>,
 }
 
+/// Perturbation norms (relative to the unit-norm held-out vector) used
+/// when fitting the decode calibration, from barely noisy to mostly noise.
+const CALIBRATION_NOISE_LEVELS: [f32; 7] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0];
+
 /// Extract non-zero R0 slot vectors from a TensorFrame.
 ///
 /// Pulls the resolution-0 (discourse level) embedding from each
@@ -143,6 +156,67 @@ fn subsample(
         .collect()
 }
 
+/// Fit a decode-confidence [`Calibration`] for `codebook` on held-out vectors.
+///
+/// Each held-out vector is perturbed at several noise levels. A noisy
+/// decode counts as correct when it quantizes to the same entry as the
+/// clean vector; its similarity is the cosine between the noisy vector
+/// and the entry it decoded to. Platt scaling is then fitted to those
+/// `(similarity, correct)` pairs.
+///
+/// # Errors
+///
+/// Returns [`VoltError::BusError`] if quantization fails or the decodes
+/// are all correct or all wrong (nothing to calibrate against).
+///
+/// # Example
+///
+/// ```
+/// use volt_bus::codebook::Codebook;
+/// use volt_core::SLOT_DIM;
+/// use volt_learn::codebook_init::fit_calibration;
+///
+/// let entries: Vec<[f32; SLOT_DIM]> = (0..16)
+///     .map(|i| {
+///         let mut v = [0.0f32; SLOT_DIM];
+///         v[i] = 1.0;
+///         v
+///     })
+///     .collect();
+/// let codebook = Codebook::from_entries(entries.clone()).unwrap();
+///
+/// let calibration = fit_calibration(&codebook, &entries, 42).unwrap();
+/// assert!(calibration.apply(0.9) > calibration.apply(0.3));
+/// ```
+pub fn fit_calibration(
+    codebook: &Codebook,
+    held_out: &[[f32; SLOT_DIM]],
+    seed: u64,
+) -> Result<Calibration, VoltError> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // Uniform noise in [-1, 1] has per-dimension variance 1/3.
+    let unit_noise_norm = (SLOT_DIM as f32 / 3.0).sqrt();
+    let mut samples = Vec::with_capacity(held_out.len() * CALIBRATION_NOISE_LEVELS.len());
+
+    for v in held_out {
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm < 1e-10 {
+            continue;
+        }
+        let (clean_id, _) = codebook.quantize(v)?;
+        for level in CALIBRATION_NOISE_LEVELS {
+            let mut noisy = [0.0f32; SLOT_DIM];
+            for (n, x) in noisy.iter_mut().zip(v.iter()) {
+                *n = x / norm + level * rng.random_range(-1.0f32..=1.0) / unit_noise_norm;
+            }
+            let (id, decoded) = codebook.quantize(&noisy)?;
+            samples.push((volt_bus::similarity(&noisy, &decoded), id == clean_id));
+        }
+    }
+
+    Calibration::fit(&samples)
+}
+
 /// Open a corpus reader from a path (file or directory).
 fn open_corpus(path: &Path) -> Result<StackCorpusReader, VoltError> {
     if path.is_dir() {
@@ -253,6 +327,12 @@ pub fn init_codebook_from_corpus(
         });
     }
 
+    // Hold out vectors for calibration, keeping at least k for k-means
+    let holdout = config
+        .calibration_holdout
+        .min(vectors_collected - config.kmeans_config.k);
+    let held_out = all_vectors.split_off(vectors_collected - holdout);
+
     // Step 3: Subsample for k-means
     let kmeans_vectors = if config.kmeans_sample_size > 0 {
         let sample = subsample(&all_vectors, config.kmeans_sample_size, config.kmeans_config.seed);
@@ -262,7 +342,10 @@ pub fn init_codebook_from_corpus(
         );
         sample
     } else {
-        eprintln!("[codebook-init] Using all {vectors_collected} vectors for k-means");
+        eprintln!(
+            "[codebook-init] Using all {} vectors for k-means",
+            all_vectors.len()
+        );
         all_vectors.clone()
     };
     let vectors_used_for_kmeans = kmeans_vectors.len();
@@ -283,7 +366,29 @@ pub fn init_codebook_from_corpus(
         "[codebook-init] Building codebook ({} entries)",
         kmeans_result.centroids.len()
     );
-    let codebook = Codebook::from_entries(kmeans_result.centroids)?;
+    let mut codebook = Codebook::from_entries(kmeans_result.centroids)?;
+
+    // Step 5b: Fit decode-confidence calibration on the held-out vectors
+    let calibration = if held_out.is_empty() {
+        None
+    } else {
+        match fit_calibration(&codebook, &held_out, config.kmeans_config.seed) {
+            Ok(calibration) => {
+                eprintln!(
+                    "[codebook-init] Fitted calibration on {} held-out vectors (scale={:.4}, bias={:.4})",
+                    held_out.len(),
+                    calibration.scale,
+                    calibration.bias
+                );
+                Some(calibration)
+            }
+            Err(e) => {
+                eprintln!("[codebook-init] Skipping calibration: {e}");
+                None
+            }
+        }
+    };
+    codebook.set_calibration(calibration);
 
     // Step 6: Save codebook
     if let Some(parent) = config.output_path.parent()
@@ -302,9 +407,10 @@ pub fn init_codebook_from_corpus(
         config.output_path.display()
     );
 
-    // Step 7: Validate quantization error on all collected vectors
+    // Step 7: Validate quantization error on the k-means vectors
     eprintln!(
-        "[codebook-init] Validating quantization error on {vectors_collected} vectors"
+        "[codebook-init] Validating quantization error on {} vectors",
+        all_vectors.len()
     );
     // Reload codebook to verify save/load roundtrip, then use lookup for validation.
     // We use the k-means centroids directly since Codebook.entries is private.
@@ -335,6 +441,7 @@ pub fn init_codebook_from_corpus(
         kmeans_iterations: kmeans_result.iterations,
         mean_quantization_error: mean_error,
         codebook_path: config.output_path.clone(),
+        calibration,
     })
 }
 
@@ -374,6 +481,40 @@ mod tests {
         }
     }
 
+    fn basis_codebook(n: usize) -> (Codebook, Vec<[f32; SLOT_DIM]>) {
+        let entries: Vec<[f32; SLOT_DIM]> = (0..n)
+            .map(|i| {
+                let mut v = [0.0f32; SLOT_DIM];
+                v[i] = 1.0;
+                v
+            })
+            .collect();
+        (Codebook::from_entries(entries.clone()).unwrap(), entries)
+    }
+
+    #[test]
+    fn fit_calibration_is_monotonic_and_bounded() {
+        let (codebook, held_out) = basis_codebook(32);
+        let calibration = fit_calibration(&codebook, &held_out, 7).unwrap();
+        assert!(calibration.scale > 0.0);
+
+        let mut last = 0.0f32;
+        for i in 0..=20 {
+            let c = calibration.apply(i as f32 / 20.0);
+            assert!((0.0..=1.0).contains(&c));
+            assert!(c >= last);
+            last = c;
+        }
+    }
+
+    #[test]
+    fn fit_calibration_is_deterministic() {
+        let (codebook, held_out) = basis_codebook(16);
+        let a = fit_calibration(&codebook, &held_out, 3).unwrap();
+        let b = fit_calibration(&codebook, &held_out, 3).unwrap();
+        assert_eq!(a, b);
+    }
+
     #[test]
     fn subsample_smaller_than_target() {
         let vectors: Vec<[f32; SLOT_DIM]> = vec![[1.0; SLOT_DIM]; 10];
@@ -11,6 +11,10 @@
 //! 2. Each cluster's R₀ vectors are averaged into a wisdom frame
 //! 3. Wisdom frames get high gamma (0.95) and are stored back
 //! 4. Source frames are recorded as superseded
+//!
+//! [`distill_strand_from`] does the same in budgeted, resumable steps:
+//! it examines only frames past a per-strand cursor, so a pass cut short
+//! by the sleep scheduler's budget continues where it stopped.
 
 use volt_core::VoltError;
 use volt_db::VoltStore;
@@ -54,6 +58,7 @@ impl Default for DistillationConfig {
 ///     clusters_found: 2,
 ///     wisdom_frames_created: 2,
 ///     frames_superseded: 12,
+///     frames_remaining: 0,
 /// };
 /// assert_eq!(result.clusters_found, 2);
 /// ```
@@ -67,6 +72,32 @@ pub struct DistillationResult {
     pub wisdom_frames_created: usize,
     /// Number of source frames that were superseded.
     pub frames_superseded: usize,
+    /// Frames a budgeted pass left for a later step. Always 0 for
+    /// [`distill_strand`], which examines the whole strand.
+    pub frames_remaining: usize,
+}
+
+/// Outcome of one budgeted [`distill_strand_from`] step.
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::distillation::distill_strand_from;
+/// use volt_db::VoltStore;
+///
+/// let mut store = VoltStore::new();
+/// let step = distill_strand_from(&mut store, 0, 0, 100).unwrap();
+/// assert_eq!(step.cursor, 0);
+/// assert_eq!(step.frames_examined, 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DistillationStep {
+    /// Clusters and wisdom frames produced by this step.
+    pub result: DistillationResult,
+    /// Cursor to resume from: the highest frame ID examined so far.
+    pub cursor: u64,
+    /// Frames examined by this step, charged against the budget.
+    pub frames_examined: usize,
 }
 
 /// Runs distillation on a single strand.
@@ -99,6 +130,50 @@ pub fn distill_strand(
         clusters_found: result.clusters_found,
         wisdom_frames_created: result.wisdom_frames.len(),
         frames_superseded: result.superseded_frame_ids.len(),
+        frames_remaining: 0,
+    })
+}
+
+/// Runs one budgeted distillation step on a strand, resuming after
+/// `cursor`.
+///
+/// Delegates to [`VoltStore::consolidate_strand_from`]: at most
+/// `max_frames` frames past `cursor` are clustered, and frames already
+/// behind the cursor are never clustered again. Start with a cursor of 0
+/// and pass the returned [`DistillationStep::cursor`] to the next step.
+///
+/// # Errors
+///
+/// Returns [`VoltError::StorageError`] if consolidation fails.
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::distillation::distill_strand_from;
+/// use volt_db::VoltStore;
+///
+/// let mut store = VoltStore::new();
+/// let step = distill_strand_from(&mut store, 0, 0, 10).unwrap();
+/// assert_eq!(step.result.frames_remaining, 0);
+/// ```
+pub fn distill_strand_from(
+    store: &mut VoltStore,
+    strand_id: u64,
+    cursor: u64,
+    max_frames: usize,
+) -> Result<DistillationStep, VoltError> {
+    let progress = store.consolidate_strand_from(strand_id, cursor, max_frames)?;
+
+    Ok(DistillationStep {
+        result: DistillationResult {
+            strand_id,
+            clusters_found: progress.result.clusters_found,
+            wisdom_frames_created: progress.result.wisdom_frames.len(),
+            frames_superseded: progress.result.superseded_frame_ids.len(),
+            frames_remaining: progress.frames_remaining,
+        },
+        cursor: progress.cursor,
+        frames_examined: progress.frames_examined,
     })
 }
 
@@ -137,6 +212,7 @@ pub fn distill_all_strands(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use volt_core::slot::SlotSource;
     use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
 
     fn make_frame_with_r0(value: f32) -> TensorFrame {
@@ -149,6 +225,7 @@ mod tests {
         }
         slot.write_resolution(0, vec);
         frame.write_slot(0, slot).unwrap();
+        frame.meta[0].source = SlotSource::Translator;
         frame
     }
 
@@ -11,6 +11,12 @@
 //! - **Logic** (250 pairs): deductive reasoning patterns
 //! - **Factual** (250 pairs): entity-attribute recall patterns
 //! - **Creative** (250 pairs): word-association patterns
+//!
+//! [`generate_eval_dataset_filtered`] narrows the dataset to chosen
+//! categories and, optionally, a seeded per-category subsample.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// Category of an evaluation pair.
 ///
@@ -34,6 +40,16 @@ pub enum EvalCategory {
     Creative,
 }
 
+impl EvalCategory {
+    /// Every category, in dataset order.
+    pub const ALL: [EvalCategory; 4] = [
+        EvalCategory::Math,
+        EvalCategory::Logic,
+        EvalCategory::Factual,
+        EvalCategory::Creative,
+    ];
+}
+
 /// A single evaluation pair: question text mapped to verified answer text.
 ///
 /// # Example
@@ -58,6 +74,46 @@ pub struct EvalPair {
     pub category: EvalCategory,
 }
 
+/// Selects a subset of the evaluation dataset.
+///
+/// The default keeps every category in full, matching
+/// [`generate_eval_dataset`].
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::eval_dataset::{EvalCategory, EvalFilter};
+///
+/// let filter = EvalFilter {
+///     categories: vec![EvalCategory::Logic],
+///     per_category: Some(50),
+///     ..EvalFilter::default()
+/// };
+/// assert_eq!(filter.seed, 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalFilter {
+    /// Categories to keep. Categories not listed are dropped; an empty
+    /// list yields an empty dataset.
+    pub categories: Vec<EvalCategory>,
+    /// Maximum pairs per category. `None` keeps every pair; larger
+    /// values than a category holds are clamped to its size.
+    pub per_category: Option<usize>,
+    /// Seed for the per-category subsample. The same seed always picks
+    /// the same pairs.
+    pub seed: u64,
+}
+
+impl Default for EvalFilter {
+    fn default() -> Self {
+        Self {
+            categories: EvalCategory::ALL.to_vec(),
+            per_category: None,
+            seed: 0,
+        }
+    }
+}
+
 /// Number words used by the math generator.
 const NUMBERS: &[&str] = &[
     "zero", "one", "two", "three", "four", "five", "six", "seven",
@@ -161,6 +217,71 @@ pub fn generate_eval_dataset() -> Vec<EvalPair> {
     pairs
 }
 
+/// Generates the evaluation dataset restricted by `filter`.
+///
+/// Pairs keep their [`generate_eval_dataset`] order. With
+/// `per_category: Some(n)`, each kept category contributes
+/// `min(n, available)` pairs chosen by a seeded partial Fisher-Yates
+/// shuffle; each category is sampled independently, so adding or
+/// removing a category does not change another category's sample.
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::eval_dataset::{generate_eval_dataset_filtered, EvalCategory, EvalFilter};
+///
+/// // Balanced 50-per-category eval set
+/// let filter = EvalFilter { per_category: Some(50), seed: 7, ..EvalFilter::default() };
+/// let dataset = generate_eval_dataset_filtered(&filter);
+/// assert_eq!(dataset.len(), 200);
+/// assert_eq!(
+///     dataset.iter().filter(|p| p.category == EvalCategory::Math).count(),
+///     50,
+/// );
+/// ```
+pub fn generate_eval_dataset_filtered(filter: &EvalFilter) -> Vec<EvalPair> {
+    let mut pairs = Vec::new();
+    for (i, category) in EvalCategory::ALL.into_iter().enumerate() {
+        if !filter.categories.contains(&category) {
+            continue;
+        }
+        let all = match category {
+            EvalCategory::Math => generate_math_pairs(),
+            EvalCategory::Logic => generate_logic_pairs(),
+            EvalCategory::Factual => generate_factual_pairs(),
+            EvalCategory::Creative => generate_creative_pairs(),
+        };
+        match filter.per_category {
+            Some(n) if n < all.len() => {
+                let seed = filter.seed ^ (i as u64).wrapping_mul(0x9e3779b97f4a7c15);
+                pairs.extend(sample_in_order(all, n, seed));
+            }
+            _ => pairs.extend(all),
+        }
+    }
+    pairs
+}
+
+/// Picks `n < pairs.len()` pairs with a seeded partial Fisher-Yates
+/// shuffle, returned in their original order.
+fn sample_in_order(pairs: Vec<EvalPair>, n: usize, seed: u64) -> Vec<EvalPair> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut indices: Vec<usize> = (0..pairs.len()).collect();
+    for i in 0..n {
+        let j = rng.random_range(i..indices.len());
+        indices.swap(i, j);
+    }
+    let mut keep = vec![false; pairs.len()];
+    for &i in &indices[..n] {
+        keep[i] = true;
+    }
+    pairs
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(pair, keep)| keep.then_some(pair))
+        .collect()
+}
+
 /// Generates 250 math evaluation pairs.
 #[allow(clippy::needless_range_loop)]
 fn generate_math_pairs() -> Vec<EvalPair> {
@@ -454,6 +575,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn default_filter_returns_full_dataset() {
+        let full = generate_eval_dataset();
+        let filtered = generate_eval_dataset_filtered(&EvalFilter::default());
+        assert_eq!(full.len(), filtered.len());
+        for (a, b) in full.iter().zip(filtered.iter()) {
+            assert_eq!(a.question, b.question);
+            assert_eq!(a.category, b.category);
+        }
+    }
+
+    #[test]
+    fn filter_keeps_only_requested_categories() {
+        let filter = EvalFilter {
+            categories: vec![EvalCategory::Creative, EvalCategory::Logic],
+            ..EvalFilter::default()
+        };
+        let dataset = generate_eval_dataset_filtered(&filter);
+        assert_eq!(dataset.len(), 500);
+        assert!(dataset
+            .iter()
+            .all(|p| matches!(p.category, EvalCategory::Logic | EvalCategory::Creative)));
+        // Dataset order is kept regardless of filter order
+        assert_eq!(dataset[0].category, EvalCategory::Logic);
+
+        let none = EvalFilter { categories: Vec::new(), ..EvalFilter::default() };
+        assert!(generate_eval_dataset_filtered(&none).is_empty());
+    }
+
+    #[test]
+    fn per_category_sampling_is_balanced_and_clamped() {
+        let filter = EvalFilter { per_category: Some(50), ..EvalFilter::default() };
+        let dataset = generate_eval_dataset_filtered(&filter);
+        for category in EvalCategory::ALL {
+            let count = dataset.iter().filter(|p| p.category == category).count();
+            assert_eq!(count, 50, "{category:?}");
+        }
+
+        let filter = EvalFilter {
+            categories: vec![EvalCategory::Math],
+            per_category: Some(10_000),
+            seed: 3,
+        };
+        assert_eq!(generate_eval_dataset_filtered(&filter).len(), 250);
+    }
+
+    #[test]
+    fn per_category_sampling_is_seed_reproducible() {
+        let questions = |seed| {
+            let filter = EvalFilter { per_category: Some(20), seed, ..EvalFilter::default() };
+            generate_eval_dataset_filtered(&filter)
+                .into_iter()
+                .map(|p| p.question)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(questions(11), questions(11));
+        assert_ne!(questions(11), questions(12));
+
+        // A category's sample does not depend on which others are kept
+        let math_only = EvalFilter {
+            categories: vec![EvalCategory::Math],
+            per_category: Some(20),
+            seed: 11,
+        };
+        let math: Vec<_> = generate_eval_dataset_filtered(&math_only)
+            .into_iter()
+            .map(|p| p.question)
+            .collect();
+        assert_eq!(math, questions(11)[..20]);
+    }
+
     #[test]
     fn eval_category_debug() {
         assert_eq!(format!("{:?}", EvalCategory::Math), "Math");
@@ -21,7 +21,7 @@
 
 use volt_core::{VoltError, MAX_SLOTS, SLOT_DIM};
 use volt_db::VoltStore;
-use volt_soft::vfn::Vfn;
+use volt_soft::vfn::{Vfn, VfnConfig};
 
 use crate::event::LearningEvent;
 
@@ -47,6 +47,37 @@ pub struct FfSample {
     pub is_positive: bool,
 }
 
+/// Restricts which events [`collect_ff_samples_filtered`] draws from.
+///
+/// Every criterion is optional and they compose: an event must pass all
+/// of the set criteria to contribute samples.
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::forward_forward::FfSampleFilter;
+///
+/// let filter = FfSampleFilter {
+///     strand_id: Some(3),
+///     since: Some(1_000_000),
+///     max_samples: Some(256),
+/// };
+/// assert_eq!(FfSampleFilter::default().strand_id, None);
+/// # let _ = filter;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FfSampleFilter {
+    /// Only use events from this strand. `None` = all strands.
+    pub strand_id: Option<u64>,
+    /// Only use events with `timestamp >= since` (microseconds).
+    /// `None` = no lower bound.
+    pub since: Option<u64>,
+    /// Cap on the number of returned samples. When more are collected,
+    /// a uniform random subset is kept rather than the first N.
+    /// `None` = no cap.
+    pub max_samples: Option<usize>,
+}
+
 /// Configuration for Forward-Forward training.
 ///
 /// # Example
@@ -63,7 +94,8 @@ pub struct FfConfig {
     /// Learning rate for weight updates. Default: 0.001.
     pub learning_rate: f32,
     /// Goodness threshold — positive samples should exceed this,
-    /// negative samples should stay below. Default: 2.0.
+    /// negative samples should stay below. A sample already on its side
+    /// of the threshold produces no update. Default: 2.0.
     pub goodness_threshold: f32,
     /// Number of training epochs per layer. Default: 5.
     pub num_epochs: usize,
@@ -76,7 +108,8 @@ pub struct FfConfig {
     /// Gamma threshold below which a frame is considered negative.
     /// Default: 0.3.
     pub negative_gamma_threshold: f32,
-    /// Random seed for corruption noise generation. Default: 42.
+    /// Random seed for corruption noise generation and the dropout masks
+    /// drawn during [`train_ff`]. Default: 42.
     pub seed: u64,
 }
 
@@ -124,6 +157,74 @@ pub struct FfResult {
     pub negative_goodness_after: Vec<f32>,
 }
 
+impl FfResult {
+    /// Per-layer separation margin after training: mean positive
+    /// goodness minus mean negative goodness.
+    ///
+    /// A layer that separates the classes has a clearly positive margin;
+    /// one near zero (or negative) is not learning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_learn::forward_forward::FfResult;
+    ///
+    /// let result = FfResult {
+    ///     layers_updated: 2,
+    ///     positive_goodness_before: vec![1.0, 1.0],
+    ///     positive_goodness_after: vec![3.0, 1.1],
+    ///     negative_goodness_before: vec![1.0, 1.0],
+    ///     negative_goodness_after: vec![0.5, 1.0],
+    /// };
+    /// let margins = result.separation_margins();
+    /// assert!((margins[0] - 2.5).abs() < 1e-6);
+    /// assert!((margins[1] - 0.1).abs() < 1e-6);
+    /// ```
+    pub fn separation_margins(&self) -> Vec<f32> {
+        self.positive_goodness_after
+            .iter()
+            .zip(&self.negative_goodness_after)
+            .map(|(pos, neg)| pos - neg)
+            .collect()
+    }
+
+    /// Per-layer separation margin before training, for comparison with
+    /// [`FfResult::separation_margins`].
+    pub fn separation_margins_before(&self) -> Vec<f32> {
+        self.positive_goodness_before
+            .iter()
+            .zip(&self.negative_goodness_before)
+            .map(|(pos, neg)| pos - neg)
+            .collect()
+    }
+
+    /// Indices of layers whose margin after training is below
+    /// `min_margin`, i.e. layers that are not separating the classes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_learn::forward_forward::FfResult;
+    ///
+    /// let result = FfResult {
+    ///     layers_updated: 2,
+    ///     positive_goodness_before: vec![1.0, 1.0],
+    ///     positive_goodness_after: vec![3.0, 1.1],
+    ///     negative_goodness_before: vec![1.0, 1.0],
+    ///     negative_goodness_after: vec![0.5, 1.0],
+    /// };
+    /// assert_eq!(result.stalled_layers(0.5), vec![1]);
+    /// ```
+    pub fn stalled_layers(&self, min_margin: f32) -> Vec<usize> {
+        self.separation_margins()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, margin)| margin < min_margin)
+            .map(|(layer, _)| layer)
+            .collect()
+    }
+}
+
 /// Simple PRNG for deterministic noise generation (splitmix64).
 struct Rng(u64);
 
@@ -140,6 +241,11 @@ impl Rng {
         z ^ (z >> 31)
     }
 
+    /// Returns a uniform index in `0..n`. `n` must be non-zero.
+    fn next_below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
     /// Returns approximate Gaussian noise via Box-Muller using
     /// two uniform samples.
     fn next_gaussian(&mut self, stddev: f32) -> f32 {
@@ -185,8 +291,26 @@ pub fn collect_ff_samples(
         });
     }
 
-    let mut samples = Vec::new();
     let mut rng = Rng::new(config.seed);
+    let samples = extract_samples(events.iter(), store, config, &mut rng);
+
+    if samples.is_empty() {
+        return Err(VoltError::LearnError {
+            message: "collect_ff_samples: no valid samples extracted".to_string(),
+        });
+    }
+
+    Ok(samples)
+}
+
+/// Extracts labeled R₀ samples from the frames referenced by `events`.
+fn extract_samples<'a>(
+    events: impl Iterator<Item = &'a LearningEvent>,
+    store: &VoltStore,
+    config: &FfConfig,
+    rng: &mut Rng,
+) -> Vec<FfSample> {
+    let mut samples = Vec::new();
 
     for event in events {
         // Compute average gamma for this event (non-zero slots only)
@@ -251,12 +375,72 @@ pub fn collect_ff_samples(
         }
     }
 
+    samples
+}
+
+/// Collects Forward-Forward training samples from a filtered subset of events.
+///
+/// Like [`collect_ff_samples`], but only events matching `filter`
+/// (strand and/or recency) contribute. This lets sleep consolidation
+/// focus training on one strand's recent frames. If more samples are
+/// extracted than `filter.max_samples`, a uniform random subset is
+/// kept (seeded by `config.seed`), so the cap does not bias toward
+/// the earliest events.
+///
+/// # Errors
+///
+/// Returns [`VoltError::LearnError`] if no events match the filter or
+/// no samples could be extracted from the matching events.
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::forward_forward::{collect_ff_samples_filtered, FfConfig, FfSampleFilter};
+///
+/// let store = volt_db::VoltStore::new();
+/// let filter = FfSampleFilter { strand_id: Some(1), ..FfSampleFilter::default() };
+/// let result = collect_ff_samples_filtered(&[], &store, &FfConfig::default(), &filter);
+/// assert!(result.is_err());
+/// ```
+pub fn collect_ff_samples_filtered(
+    events: &[LearningEvent],
+    store: &VoltStore,
+    config: &FfConfig,
+    filter: &FfSampleFilter,
+) -> Result<Vec<FfSample>, VoltError> {
+    let mut matching = events
+        .iter()
+        .filter(|e| filter.strand_id.is_none_or(|id| e.strand_id == id))
+        .filter(|e| filter.since.is_none_or(|since| e.timestamp >= since))
+        .peekable();
+
+    if matching.peek().is_none() {
+        return Err(VoltError::LearnError {
+            message: format!("collect_ff_samples_filtered: no events match {filter:?}"),
+        });
+    }
+
+    let mut rng = Rng::new(config.seed);
+    let mut samples = extract_samples(matching, store, config, &mut rng);
+
     if samples.is_empty() {
         return Err(VoltError::LearnError {
-            message: "collect_ff_samples: no valid samples extracted".to_string(),
+            message: "collect_ff_samples_filtered: no valid samples extracted".to_string(),
         });
     }
 
+    if let Some(max) = filter.max_samples
+        && samples.len() > max
+    {
+        // Partial Fisher-Yates: the first `max` positions end up a
+        // uniform random subset of all samples.
+        for i in 0..max {
+            let j = i + rng.next_below(samples.len() - i);
+            samples.swap(i, j);
+        }
+        samples.truncate(max);
+    }
+
     Ok(samples)
 }
 
@@ -265,6 +449,17 @@ fn goodness(activations: &[f32]) -> f32 {
     activations.iter().map(|a| a * a).sum()
 }
 
+/// Computes a layer's activations before layer normalization, whose
+/// output would make goodness constant.
+fn layer_activations(vfn: &Vfn, layer_idx: usize, input: &[f32]) -> Result<Vec<f32>, VoltError> {
+    let pre = vfn.forward_layer_linear(layer_idx, input)?;
+    if layer_idx + 1 == vfn.layer_count() {
+        return Ok(pre);
+    }
+    let activation = vfn.config().activation;
+    Ok(pre.into_iter().map(|z| activation.apply(z)).collect())
+}
+
 /// Computes average goodness for a set of samples through a VFN layer.
 fn avg_goodness_for_samples(
     vfn: &Vfn,
@@ -282,7 +477,7 @@ fn avg_goodness_for_samples(
         for &prev in prev_layers {
             input = vfn.forward_layer(prev, &input)?;
         }
-        let activations = vfn.forward_layer(layer_idx, &input)?;
+        let activations = layer_activations(vfn, layer_idx, &input)?;
         total += goodness(&activations);
     }
     Ok(total / samples.len() as f32)
@@ -297,6 +492,12 @@ fn avg_goodness_for_samples(
 /// No backpropagation — gradients never flow between layers.
 /// VRAM usage is approximately 1x inference (one layer active at a time).
 ///
+/// Inputs to the layer being trained come from
+/// [`Vfn::forward_layer_train`], so the VFN's [`VfnConfig::dropout`]
+/// applies to the earlier layers, with a fresh mask per sample derived
+/// from [`FfConfig::seed`]. Goodness is measured before the layer's own
+/// normalization, if any.
+///
 /// # Errors
 ///
 /// Returns [`VoltError::LearnError`] if no samples are provided or
@@ -333,6 +534,8 @@ pub fn train_ff(
         samples.iter().filter(|s| !s.is_positive).collect();
 
     let n_layers = vfn.layer_count();
+    let activation = vfn.config().activation;
+    let mut step = 0u64;
     let mut pos_goodness_before = Vec::with_capacity(n_layers);
     let mut pos_goodness_after = Vec::with_capacity(n_layers);
     let mut neg_goodness_before = Vec::with_capacity(n_layers);
@@ -358,18 +561,29 @@ pub fn train_ff(
         neg_goodness_before.push(neg_before);
 
         let (in_dim, out_dim) = vfn.layer_shape(layer_idx)?;
+        let is_output = layer_idx + 1 == n_layers;
 
         // Train this layer for num_epochs
         for _epoch in 0..config.num_epochs {
             for sample in samples {
-                // Forward through previous layers (detached)
+                // Forward through previous layers (detached), with dropout
+                let train_config = VfnConfig {
+                    dropout_seed: config.seed.wrapping_add(step),
+                    ..*vfn.config()
+                };
+                step += 1;
                 let mut input: Vec<f32> = sample.embedding.to_vec();
                 for &prev in &prev_layers {
-                    input = vfn.forward_layer(prev, &input)?;
+                    input = vfn.forward_layer_train(prev, &input, &train_config)?;
                 }
 
-                // Forward through current layer
-                let activations = vfn.forward_layer(layer_idx, &input)?;
+                // Forward through current layer, keeping pre-activations z
+                let pre = vfn.forward_layer_linear(layer_idx, &input)?;
+                let activations: Vec<f32> = if is_output {
+                    pre.clone()
+                } else {
+                    pre.iter().map(|&z| activation.apply(z)).collect()
+                };
                 let g = goodness(&activations);
 
                 let needs_update = if sample.is_positive {
@@ -386,13 +600,14 @@ pub fn train_ff(
                 // goodness = Σ a_i²
                 // d(goodness)/d(w_ij) = 2 * a_i * d(a_i)/d(w_ij)
                 //
-                // For a linear layer (pre-ReLU): a_i = Σ_j w_ij * x_j + b_i
-                // d(a_i)/d(w_ij) = x_j (if ReLU active, i.e. a_i > 0)
+                // With z_i = Σ_j w_ij * x_j + b_i and a_i = f(z_i) for the
+                // configured activation f:
+                // d(a_i)/d(w_ij) = f'(z_i) * x_j
                 //
-                // So d(goodness)/d(w_ij) = 2 * a_i * x_j * relu_mask_i
-                // And d(goodness)/d(b_i) = 2 * a_i * relu_mask_i
+                // So d(goodness)/d(w_ij) = 2 * a_i * f'(z_i) * x_j
+                // And d(goodness)/d(b_i) = 2 * a_i * f'(z_i)
                 //
-                // For the output layer (no ReLU): relu_mask is always 1.
+                // For the output layer (no activation): f' is always 1.
 
                 let mut weight_deltas = vec![0.0f32; in_dim * out_dim];
                 let mut bias_deltas = vec![0.0f32; out_dim];
@@ -400,14 +615,12 @@ pub fn train_ff(
                 let sign = if sample.is_positive { 1.0 } else { -1.0 };
 
                 for i in 0..out_dim {
-                    // ReLU mask: for hidden layers, only update if activation > 0
-                    let relu_active =
-                        layer_idx >= 2 || activations[i] > 0.0;
-                    if !relu_active {
+                    let slope = if is_output { 1.0 } else { activation.derivative(pre[i]) };
+                    if slope == 0.0 {
                         continue;
                     }
 
-                    let grad_factor = sign * 2.0 * activations[i];
+                    let grad_factor = sign * 2.0 * activations[i] * slope;
 
                     // Weight gradient
                     let row_start = i * in_dim;
@@ -543,6 +756,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn train_ff_separates_clearly_separable_samples() {
+        let mut vfn = Vfn::new_random(42);
+        let mut samples = Vec::new();
+        for i in 0..20 {
+            let mut positive = [0.0; SLOT_DIM];
+            positive[i] = 1.0;
+            samples.push(FfSample { embedding: positive, is_positive: true });
+            let mut negative = [0.0; SLOT_DIM];
+            negative[128 + i] = 1.0;
+            samples.push(FfSample { embedding: negative, is_positive: false });
+        }
+        let config = FfConfig {
+            num_epochs: 10,
+            learning_rate: 0.01,
+            ..FfConfig::default()
+        };
+        let result = train_ff(&mut vfn, &samples, &config).unwrap();
+
+        let margins = result.separation_margins();
+        assert_eq!(margins.len(), result.layers_updated);
+        assert!(
+            margins[0] > 0.1,
+            "layer 0 should separate the classes, margin {} (before {:?})",
+            margins[0],
+            result.separation_margins_before()
+        );
+        assert!(margins[0] > result.separation_margins_before()[0]);
+        assert!(!result.stalled_layers(0.1).contains(&0));
+    }
+
+    #[test]
+    fn train_ff_updates_negative_outputs_of_shallow_vfn() {
+        // 256 → 64 → 256: layer 1 is the linear output layer
+        let mut vfn = Vfn::new_random_with_shape(42, &[64]).unwrap();
+        let sample = make_positive_sample(0.1);
+        let config = FfConfig {
+            num_epochs: 1,
+            learning_rate: 0.01,
+            goodness_threshold: f32::MAX,
+            ..FfConfig::default()
+        };
+        let before = vfn.clone();
+        train_ff(&mut vfn, std::slice::from_ref(&sample), &config).unwrap();
+
+        // Layer 1 trains on the already-trained layer 0's output
+        let hidden = vfn.forward_layer(0, &sample.embedding).unwrap();
+        let old_out = before.forward_layer(1, &hidden).unwrap();
+        let new_out = vfn.forward_layer(1, &hidden).unwrap();
+        let negative: Vec<usize> = (0..old_out.len()).filter(|&i| old_out[i] < 0.0).collect();
+        assert!(!negative.is_empty());
+        for i in negative {
+            assert!(new_out[i] < old_out[i], "output unit {i} was masked");
+        }
+    }
+
+    #[test]
+    fn train_ff_applies_seeded_dropout_to_earlier_layers() {
+        let sample = make_positive_sample(0.1);
+        let trained = |dropout: f32, seed: u64| {
+            let mut vfn =
+                Vfn::with_config(42, VfnConfig { dropout, ..VfnConfig::default() });
+            let config = FfConfig {
+                num_epochs: 1,
+                learning_rate: 0.01,
+                goodness_threshold: f32::MAX,
+                seed,
+                ..FfConfig::default()
+            };
+            train_ff(&mut vfn, std::slice::from_ref(&sample), &config).unwrap();
+            vfn.forward(&sample.embedding).unwrap()
+        };
+
+        assert_eq!(trained(0.0, 1), trained(0.0, 2));
+        assert_eq!(trained(0.5, 1), trained(0.5, 1));
+        assert_ne!(trained(0.5, 1), trained(0.5, 2));
+    }
+
+    #[test]
+    fn train_ff_follows_leaky_relu_gradient_on_negative_units() {
+        use volt_soft::vfn::{Activation, VfnConfig};
+
+        let activation = VfnConfig { activation: Activation::LeakyRelu(0.1), ..VfnConfig::default() };
+        let mut vfn = Vfn::with_config(42, activation);
+        let sample = make_positive_sample(0.1);
+        let config = FfConfig {
+            num_epochs: 1,
+            learning_rate: 0.01,
+            goodness_threshold: f32::MAX,
+            ..FfConfig::default()
+        };
+        let before = vfn.clone();
+        train_ff(&mut vfn, std::slice::from_ref(&sample), &config).unwrap();
+
+        let old_z = before.forward_layer_linear(0, &sample.embedding).unwrap();
+        let new_z = vfn.forward_layer_linear(0, &sample.embedding).unwrap();
+        let negative: Vec<usize> = (0..old_z.len()).filter(|&i| old_z[i] < 0.0).collect();
+        assert!(!negative.is_empty());
+        for i in negative {
+            assert!(new_z[i] < old_z[i], "hidden unit {i} was treated as dead");
+        }
+    }
+
     #[test]
     fn collect_ff_samples_empty_events_errors() {
         let store = VoltStore::new();
@@ -566,6 +882,131 @@ mod tests {
         assert!(collect_ff_samples(&events, &store, &config).is_err());
     }
 
+    /// Stores one single-slot frame per `(strand, timestamp)` pair and
+    /// returns matching high-gamma events plus each frame's R₀ vector.
+    fn store_events(
+        store: &mut VoltStore,
+        specs: &[(u64, u64)],
+    ) -> (Vec<LearningEvent>, Vec<[f32; SLOT_DIM]>) {
+        use volt_core::slot::SlotSource;
+        use volt_core::{SlotData, SlotRole, TensorFrame};
+
+        let mut events = Vec::new();
+        let mut vectors = Vec::new();
+        for (i, &(strand_id, timestamp)) in specs.iter().enumerate() {
+            let mut r0 = [0.0; SLOT_DIM];
+            r0[i % SLOT_DIM] = 1.0;
+            let mut frame = TensorFrame::new();
+            let mut slot = SlotData::new(SlotRole::Agent);
+            slot.write_resolution(0, r0);
+            frame.write_slot(0, slot).unwrap();
+            frame.meta[0].source = SlotSource::Translator;
+
+            store.switch_strand(strand_id).unwrap();
+            let frame_id = store.store(frame).unwrap();
+            events.push(LearningEvent {
+                frame_id,
+                strand_id,
+                query_type: DiscourseType::Query,
+                gamma_scores: [0.9; MAX_SLOTS],
+                convergence_iterations: 5,
+                ghost_activations: 0,
+                timestamp,
+            });
+            vectors.push(r0);
+        }
+        (events, vectors)
+    }
+
+    #[test]
+    fn collect_ff_samples_filtered_restricts_strand_and_window() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let mut store = VoltStore::new();
+                let specs = [(1, 100), (1, 200), (2, 200), (1, 300), (2, 50)];
+                let (events, vectors) = store_events(&mut store, &specs);
+
+                let filter = FfSampleFilter {
+                    strand_id: Some(1),
+                    since: Some(150),
+                    max_samples: None,
+                };
+                let samples =
+                    collect_ff_samples_filtered(&events, &store, &FfConfig::default(), &filter)
+                        .unwrap();
+
+                // Frames 1 and 3 match: one positive + one corrupted negative each.
+                assert_eq!(samples.len(), 4);
+                let positives: Vec<_> = samples.iter().filter(|s| s.is_positive).collect();
+                assert_eq!(positives.len(), 2);
+                for sample in positives {
+                    assert!(
+                        sample.embedding == vectors[1] || sample.embedding == vectors[3],
+                        "positive sample came from outside the filter"
+                    );
+                }
+
+                // Each criterion alone is looser than both combined.
+                let strand_only = FfSampleFilter { strand_id: Some(1), ..FfSampleFilter::default() };
+                let since_only = FfSampleFilter { since: Some(150), ..FfSampleFilter::default() };
+                let config = FfConfig::default();
+                assert_eq!(
+                    collect_ff_samples_filtered(&events, &store, &config, &strand_only).unwrap().len(),
+                    6
+                );
+                assert_eq!(
+                    collect_ff_samples_filtered(&events, &store, &config, &since_only).unwrap().len(),
+                    6
+                );
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn collect_ff_samples_filtered_no_match_errors() {
+        let store = VoltStore::new();
+        let events = vec![LearningEvent {
+            frame_id: 1,
+            strand_id: 0,
+            query_type: DiscourseType::Query,
+            gamma_scores: [0.9; MAX_SLOTS],
+            convergence_iterations: 5,
+            ghost_activations: 0,
+            timestamp: 10,
+        }];
+        let filter = FfSampleFilter { since: Some(20), ..FfSampleFilter::default() };
+        assert!(collect_ff_samples_filtered(&events, &store, &FfConfig::default(), &filter).is_err());
+    }
+
+    #[test]
+    fn collect_ff_samples_filtered_samples_rather_than_truncates() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let mut store = VoltStore::new();
+                let specs: Vec<(u64, u64)> = (0..40).map(|t| (1, t)).collect();
+                let (events, vectors) = store_events(&mut store, &specs);
+
+                let filter = FfSampleFilter { max_samples: Some(10), ..FfSampleFilter::default() };
+                let samples =
+                    collect_ff_samples_filtered(&events, &store, &FfConfig::default(), &filter)
+                        .unwrap();
+                assert_eq!(samples.len(), 10);
+
+                // Truncation would keep only the first five frames' samples.
+                let late_positive = samples.iter().any(|s| {
+                    s.is_positive && vectors[5..].contains(&s.embedding)
+                });
+                assert!(late_positive, "sampling should reach beyond the earliest events");
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn goodness_computation_correct() {
         let activations = vec![1.0, 2.0, 3.0];
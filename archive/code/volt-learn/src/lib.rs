@@ -79,7 +79,10 @@ pub use logger::{EventLogger, LoggerConfig};
 pub use stats::{StrandStatistics, TopicDistribution};
 
 // 5.2 re-exports
-pub use forward_forward::{FfSample, FfConfig, FfResult, collect_ff_samples, train_ff};
+pub use forward_forward::{
+    FfSample, FfSampleFilter, FfConfig, FfResult, collect_ff_samples,
+    collect_ff_samples_filtered, train_ff,
+};
 pub use distillation::{DistillationConfig, DistillationResult, distill_all_strands, distill_strand};
 pub use graduation::{GraduationConfig, GraduationResult, check_graduation};
 pub use sleep::{SleepConfig, SleepScheduler, SleepHandle, SleepCycleResult};
@@ -94,8 +97,10 @@ pub mod self_play;
 pub mod rlvf;
 
 // 5.3 re-exports
-pub use eval_dataset::{EvalCategory, EvalPair, generate_eval_dataset};
-pub use reward::{RewardConfig, RewardOutcome, compute_reward};
+pub use eval_dataset::{
+    EvalCategory, EvalFilter, EvalPair, generate_eval_dataset, generate_eval_dataset_filtered,
+};
+pub use reward::{DefaultReward, RewardConfig, RewardFn, RewardOutcome, compute_reward};
 pub use calibration::{CalibrationBin, CalibrationResult, compute_calibration};
 pub use self_play::{PuzzleType, LogicPuzzle, PuzzleResult, generate_puzzles, grade_puzzle};
 pub use rlvf::{RlvfConfig, RlvfResult, train_rlvf};
@@ -13,8 +13,17 @@
 //! | Wrong       | Low      | +0.2   | Honest uncertainty           |
 //! | Wrong       | High     | -2.0   | Overconfident error           |
 //! | Wrong       | Mid      | -0.5   | Moderate error               |
+//!
+//! ## Pluggable Rewards
+//!
+//! RLVF scores answers through the [`RewardFn`] trait. [`DefaultReward`]
+//! implements the shaping above; task-specific signals (e.g. running
+//! generated code) can be plugged in by implementing the trait.
 
 use volt_core::{TensorFrame, SLOT_DIM};
+use volt_translate::{StubTranslator, Translator};
+
+use crate::eval_dataset::EvalPair;
 
 /// Configuration for reward computation.
 ///
@@ -129,6 +138,110 @@ pub fn compute_reward(correctness: f32, gamma: f32, config: &RewardConfig) -> Re
     }
 }
 
+/// A reward signal for RLVF training.
+///
+/// Called once per eval pair per evaluation pass, with the VFN's output
+/// frame for that pair's question. Implementations should be cheap:
+/// training passes `&dyn RewardFn`, so there is no per-step boxing, but
+/// any work done here runs for every pair in every epoch.
+///
+/// # Example
+///
+/// ```
+/// use volt_core::TensorFrame;
+/// use volt_learn::eval_dataset::{EvalCategory, EvalPair};
+/// use volt_learn::reward::{RewardFn, RewardOutcome};
+///
+/// /// Rewards any output that has at least one active slot.
+/// struct NonEmpty;
+///
+/// impl RewardFn for NonEmpty {
+///     fn score(&self, _question: &EvalPair, answer: &TensorFrame) -> RewardOutcome {
+///         let ok = answer.active_slot_count() > 0;
+///         RewardOutcome {
+///             reward: if ok { 1.0 } else { -1.0 },
+///             is_correct: ok,
+///             correctness: if ok { 1.0 } else { 0.0 },
+///             gamma: answer.frame_meta.global_certainty,
+///         }
+///     }
+/// }
+///
+/// let pair = EvalPair {
+///     question: "two plus three".to_string(),
+///     answer: "five".to_string(),
+///     category: EvalCategory::Math,
+/// };
+/// assert!(!NonEmpty.score(&pair, &TensorFrame::new()).is_correct);
+/// ```
+pub trait RewardFn {
+    /// Scores `answer`, the VFN output frame for `question`.
+    fn score(&self, question: &EvalPair, answer: &TensorFrame) -> RewardOutcome;
+}
+
+/// The standard RLVF reward: R₀ similarity to the encoded reference
+/// answer, shaped by [`compute_reward`].
+///
+/// Correctness is [`slot_cosine_similarity`] between the output frame
+/// and the reference answer encoded with `translator`. Gamma is the mean
+/// of the output frame's positive slot certainties (0.5 if none).
+///
+/// # Example
+///
+/// ```
+/// use volt_learn::eval_dataset::{EvalCategory, EvalPair};
+/// use volt_learn::reward::{DefaultReward, RewardConfig, RewardFn};
+/// use volt_translate::{StubTranslator, Translator};
+///
+/// let translator = StubTranslator::new();
+/// let reward = DefaultReward::new(&translator, RewardConfig::default());
+/// let pair = EvalPair {
+///     question: "sky is".to_string(),
+///     answer: "sky is".to_string(),
+///     category: EvalCategory::Factual,
+/// };
+/// let frame = translator.encode(&pair.answer).unwrap().frame;
+/// let outcome = reward.score(&pair, &frame);
+/// assert!(outcome.is_correct);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DefaultReward<'a> {
+    translator: &'a StubTranslator,
+    config: RewardConfig,
+}
+
+impl<'a> DefaultReward<'a> {
+    /// Creates the default reward, encoding reference answers with `translator`.
+    pub fn new(translator: &'a StubTranslator, config: RewardConfig) -> Self {
+        Self { translator, config }
+    }
+}
+
+impl RewardFn for DefaultReward<'_> {
+    fn score(&self, question: &EvalPair, answer: &TensorFrame) -> RewardOutcome {
+        // An answer that cannot be encoded has no reference: zero similarity.
+        let correctness = self
+            .translator
+            .encode(&question.answer)
+            .map(|reference| slot_cosine_similarity(answer, &reference.frame))
+            .unwrap_or(0.0);
+
+        let active_gammas: Vec<f32> = answer
+            .meta
+            .iter()
+            .map(|m| m.certainty)
+            .filter(|&g| g > 0.0)
+            .collect();
+        let gamma = if active_gammas.is_empty() {
+            0.5 // Default gamma for frames without certainty
+        } else {
+            active_gammas.iter().sum::<f32>() / active_gammas.len() as f32
+        };
+
+        compute_reward(correctness, gamma, &self.config)
+    }
+}
+
 /// Computes average cosine similarity between matching active R₀ slots
 /// of two frames.
 ///
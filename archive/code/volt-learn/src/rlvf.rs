@@ -10,10 +10,10 @@
 //! 1. Encode question → TensorFrame via translator
 //! 2. Run VFN forward on each active R₀ slot → drift-modified output
 //! 3. Encode answer → reference TensorFrame
-//! 4. Compute cosine similarity → correctness
-//! 5. Compute reward from correctness + gamma calibration
-//! 6. REINFORCE: advantage = reward - baseline
-//! 7. Layer-local weight updates (no backprop)
+//! 4. Score the output with a [`RewardFn`] (by default: cosine
+//!    similarity → correctness, shaped by gamma calibration)
+//! 5. REINFORCE: advantage = reward - baseline
+//! 6. Layer-local weight updates (no backprop)
 //!
 //! ## Relation to Forward-Forward
 //!
@@ -29,7 +29,7 @@ use volt_translate::Translator;
 
 use crate::calibration::{self, CalibrationResult};
 use crate::eval_dataset::EvalPair;
-use crate::reward::{self, RewardConfig, RewardOutcome};
+use crate::reward::{RewardConfig, RewardFn, RewardOutcome};
 use crate::self_play;
 
 /// Configuration for RLVF training.
@@ -51,7 +51,9 @@ pub struct RlvfConfig {
     pub num_epochs: usize,
     /// Exponential moving average decay for baseline. Default: 0.9.
     pub baseline_decay: f32,
-    /// Reward computation configuration.
+    /// Reward shaping for [`DefaultReward`](crate::reward::DefaultReward)
+    /// when the caller builds one from this config (as the sleep
+    /// scheduler does). Ignored by custom reward functions.
     pub reward_config: RewardConfig,
     /// Random seed for reproducibility. Default: 42.
     pub seed: u64,
@@ -125,9 +127,11 @@ struct RlvfSample {
 
 /// Trains the VFN using RLVF (REINFORCE with baseline).
 ///
-/// Evaluates the VFN on the provided evaluation pairs, computes
-/// rewards from correctness + gamma calibration, and updates VFN
-/// weights layer-by-layer using the advantage signal.
+/// Evaluates the VFN on the provided evaluation pairs, scores each
+/// output with `reward_fn`, and updates VFN weights layer-by-layer
+/// using the advantage signal. Pass a
+/// [`DefaultReward`](crate::reward::DefaultReward) for the standard
+/// correctness + gamma calibration reward.
 ///
 /// Also evaluates self-play logic puzzles before and after training
 /// to measure reasoning improvement.
@@ -142,6 +146,7 @@ struct RlvfSample {
 /// ```no_run
 /// use volt_learn::rlvf::{train_rlvf, RlvfConfig};
 /// use volt_learn::eval_dataset::generate_eval_dataset;
+/// use volt_learn::reward::DefaultReward;
 /// use volt_soft::vfn::Vfn;
 /// use volt_translate::StubTranslator;
 ///
@@ -149,13 +154,15 @@ struct RlvfSample {
 /// let translator = StubTranslator::new();
 /// let dataset = generate_eval_dataset();
 /// let config = RlvfConfig { num_epochs: 1, ..RlvfConfig::default() };
-/// let result = train_rlvf(&mut vfn, &dataset[..10], &translator, &config).unwrap();
+/// let reward = DefaultReward::new(&translator, config.reward_config.clone());
+/// let result = train_rlvf(&mut vfn, &dataset[..10], &translator, &reward, &config).unwrap();
 /// assert_eq!(result.epochs_completed, 1);
 /// ```
 pub fn train_rlvf(
     vfn: &mut Vfn,
     eval_pairs: &[EvalPair],
     translator: &StubTranslator,
+    reward_fn: &dyn RewardFn,
     config: &RlvfConfig,
 ) -> Result<RlvfResult, VoltError> {
     if eval_pairs.is_empty() {
@@ -165,7 +172,7 @@ pub fn train_rlvf(
     }
 
     // Measure before-training metrics
-    let outcomes_before = evaluate_all(vfn, eval_pairs, translator, &config.reward_config)?;
+    let outcomes_before = evaluate_all(vfn, eval_pairs, translator, reward_fn)?;
     let calibration_before = calibration::compute_calibration(&outcomes_before);
     let mean_reward_before = mean_reward(&outcomes_before);
 
@@ -181,7 +188,7 @@ pub fn train_rlvf(
     let mut baseline = mean_reward_before;
 
     for _epoch in 0..config.num_epochs {
-        let outcomes = evaluate_all(vfn, eval_pairs, translator, &config.reward_config)?;
+        let outcomes = evaluate_all(vfn, eval_pairs, translator, reward_fn)?;
 
         // Collect samples with advantages
         let samples = collect_rlvf_samples(
@@ -205,7 +212,7 @@ pub fn train_rlvf(
     }
 
     // Measure after-training metrics
-    let outcomes_after = evaluate_all(vfn, eval_pairs, translator, &config.reward_config)?;
+    let outcomes_after = evaluate_all(vfn, eval_pairs, translator, reward_fn)?;
     let calibration_after = calibration::compute_calibration(&outcomes_after);
     let mean_reward_after = mean_reward(&outcomes_after);
 
@@ -233,13 +240,12 @@ fn evaluate_all(
     vfn: &Vfn,
     eval_pairs: &[EvalPair],
     translator: &StubTranslator,
-    reward_config: &RewardConfig,
+    reward_fn: &dyn RewardFn,
 ) -> Result<Vec<RewardOutcome>, VoltError> {
     let mut outcomes = Vec::with_capacity(eval_pairs.len());
 
     for pair in eval_pairs {
         let question_frame = translator.encode(&pair.question)?;
-        let answer_frame = translator.encode(&pair.answer)?;
 
         // Run VFN forward on each active R₀ slot of the question
         let mut output_frame = question_frame.frame.clone();
@@ -264,28 +270,7 @@ fn evaluate_all(
             }
         }
 
-        // Compute correctness as cosine similarity between output and answer
-        let correctness = reward::slot_cosine_similarity(
-            &output_frame,
-            &answer_frame.frame,
-        );
-
-        // Use average gamma from the question frame's meta
-        let active_gammas: Vec<f32> = question_frame
-            .frame
-            .meta
-            .iter()
-            .map(|m| m.certainty)
-            .filter(|&g| g > 0.0)
-            .collect();
-        let gamma = if active_gammas.is_empty() {
-            0.5 // Default gamma for frames without certainty
-        } else {
-            active_gammas.iter().sum::<f32>() / active_gammas.len() as f32
-        };
-
-        let outcome = reward::compute_reward(correctness, gamma, reward_config);
-        outcomes.push(outcome);
+        outcomes.push(reward_fn.score(pair, &output_frame));
     }
 
     Ok(outcomes)
@@ -334,10 +319,12 @@ fn apply_reinforce_updates(
     learning_rate: f32,
 ) -> Result<(), VoltError> {
     let n_layers = vfn.layer_count();
+    let activation = vfn.config().activation;
 
     for layer_idx in 0..n_layers {
         let prev_layers: Vec<usize> = (0..layer_idx).collect();
         let (in_dim, out_dim) = vfn.layer_shape(layer_idx)?;
+        let is_output = layer_idx + 1 == n_layers;
 
         for sample in samples {
             // Forward through previous layers (detached)
@@ -346,12 +333,18 @@ fn apply_reinforce_updates(
                 input = vfn.forward_layer(prev, &input)?;
             }
 
-            // Forward through current layer
-            let activations = vfn.forward_layer(layer_idx, &input)?;
+            // Forward through current layer, keeping pre-activations z
+            let pre = vfn.forward_layer_linear(layer_idx, &input)?;
+            let activations: Vec<f32> = if is_output {
+                pre.clone()
+            } else {
+                pre.iter().map(|&z| activation.apply(z)).collect()
+            };
 
             // Compute gradients of goodness w.r.t. weights
             // goodness = Σ a_i²
-            // d(goodness)/d(w_ij) = 2 * a_i * x_j * relu_mask_i
+            // d(goodness)/d(w_ij) = 2 * a_i * f'(z_i) * x_j
+            // (f' = 1 on the linear output layer)
             let mut weight_deltas = vec![0.0f32; in_dim * out_dim];
             let mut bias_deltas = vec![0.0f32; out_dim];
 
@@ -361,13 +354,12 @@ fn apply_reinforce_updates(
             let magnitude = sample.advantage.abs().min(2.0); // Clamp magnitude
 
             for i in 0..out_dim {
-                // ReLU mask: hidden layers require activation > 0
-                let relu_active = layer_idx >= 2 || activations[i] > 0.0;
-                if !relu_active {
+                let slope = if is_output { 1.0 } else { activation.derivative(pre[i]) };
+                if slope == 0.0 {
                     continue;
                 }
 
-                let grad_factor = sign * magnitude * 2.0 * activations[i];
+                let grad_factor = sign * magnitude * 2.0 * activations[i] * slope;
 
                 let row_start = i * in_dim;
                 for j in 0..in_dim {
@@ -448,6 +440,31 @@ fn count_puzzle_correct(
 mod tests {
     use super::*;
     use crate::eval_dataset::generate_eval_dataset;
+    use crate::reward::{compute_reward, slot_cosine_similarity, DefaultReward};
+    use std::cell::Cell;
+    use volt_core::TensorFrame;
+
+    fn default_reward(translator: &StubTranslator) -> DefaultReward<'_> {
+        DefaultReward::new(translator, RewardConfig::default())
+    }
+
+    /// Constant reward that counts how often it is consulted.
+    struct CountingReward {
+        reward: f32,
+        calls: Cell<usize>,
+    }
+
+    impl RewardFn for CountingReward {
+        fn score(&self, _question: &EvalPair, answer: &TensorFrame) -> RewardOutcome {
+            self.calls.set(self.calls.get() + 1);
+            RewardOutcome {
+                reward: self.reward,
+                is_correct: true,
+                correctness: 1.0,
+                gamma: answer.frame_meta.global_certainty,
+            }
+        }
+    }
 
     #[test]
     fn default_config_sensible() {
@@ -464,7 +481,8 @@ mod tests {
         let mut vfn = Vfn::new_random(42);
         let translator = StubTranslator::new();
         let config = RlvfConfig::default();
-        assert!(train_rlvf(&mut vfn, &[], &translator, &config).is_err());
+        let reward = default_reward(&translator);
+        assert!(train_rlvf(&mut vfn, &[], &translator, &reward, &config).is_err());
     }
 
     #[test]
@@ -477,7 +495,9 @@ mod tests {
             puzzle_count: 10,
             ..RlvfConfig::default()
         };
-        let result = train_rlvf(&mut vfn, &dataset[..20], &translator, &config).unwrap();
+        let reward = default_reward(&translator);
+        let result =
+            train_rlvf(&mut vfn, &dataset[..20], &translator, &reward, &config).unwrap();
         assert_eq!(result.epochs_completed, 1);
         assert_eq!(result.total_puzzles, 10);
     }
@@ -492,7 +512,9 @@ mod tests {
             puzzle_count: 5,
             ..RlvfConfig::default()
         };
-        let result = train_rlvf(&mut vfn, &dataset[..10], &translator, &config).unwrap();
+        let reward = default_reward(&translator);
+        let result =
+            train_rlvf(&mut vfn, &dataset[..10], &translator, &reward, &config).unwrap();
         assert!(result.calibration_before.ece >= 0.0);
         assert!(result.calibration_after.ece >= 0.0);
         assert_eq!(result.calibration_before.total_samples, 10);
@@ -504,11 +526,65 @@ mod tests {
         let vfn = Vfn::new_random(42);
         let translator = StubTranslator::new();
         let dataset = generate_eval_dataset();
-        let config = RewardConfig::default();
-        let outcomes = evaluate_all(&vfn, &dataset[..5], &translator, &config).unwrap();
+        let reward = default_reward(&translator);
+        let outcomes = evaluate_all(&vfn, &dataset[..5], &translator, &reward).unwrap();
         assert_eq!(outcomes.len(), 5);
     }
 
+    #[test]
+    fn default_reward_matches_shaped_similarity() {
+        let translator = StubTranslator::new();
+        let reward = default_reward(&translator);
+        let config = RewardConfig::default();
+
+        for pair in &generate_eval_dataset()[..5] {
+            let output = translator.encode(&pair.question).unwrap().frame;
+            let reference = translator.encode(&pair.answer).unwrap().frame;
+            let gammas: Vec<f32> = output
+                .meta
+                .iter()
+                .map(|m| m.certainty)
+                .filter(|&g| g > 0.0)
+                .collect();
+            let gamma = if gammas.is_empty() {
+                0.5
+            } else {
+                gammas.iter().sum::<f32>() / gammas.len() as f32
+            };
+            let expected =
+                compute_reward(slot_cosine_similarity(&output, &reference), gamma, &config);
+
+            let got = reward.score(pair, &output);
+            assert_eq!(got.reward, expected.reward);
+            assert_eq!(got.correctness, expected.correctness);
+            assert_eq!(got.gamma, expected.gamma);
+        }
+    }
+
+    #[test]
+    fn train_rlvf_uses_custom_reward() {
+        let mut vfn = Vfn::new_random(42);
+        let translator = StubTranslator::new();
+        let dataset = generate_eval_dataset();
+        let config = RlvfConfig {
+            num_epochs: 2,
+            puzzle_count: 5,
+            ..RlvfConfig::default()
+        };
+        let reward = CountingReward {
+            reward: 0.75,
+            calls: Cell::new(0),
+        };
+
+        let result =
+            train_rlvf(&mut vfn, &dataset[..8], &translator, &reward, &config).unwrap();
+
+        assert!((result.mean_reward_before - 0.75).abs() < f32::EPSILON);
+        assert!((result.mean_reward_after - 0.75).abs() < f32::EPSILON);
+        // One pass before, one per epoch, one after
+        assert_eq!(reward.calls.get(), 8 * (config.num_epochs + 2));
+    }
+
     #[test]
     fn mean_reward_empty() {
         assert!((mean_reward(&[]) - 0.0).abs() < f32::EPSILON);
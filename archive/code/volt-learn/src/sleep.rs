@@ -11,7 +11,9 @@
 //! ## Sleep Cycle Phases
 //!
 //! 1. Snapshot learning events from the logger
-//! 2. Distill all strands (cluster → wisdom frames)
+//! 2. Distill all strands (cluster → wisdom frames), at most
+//!    [`SleepConfig::consolidation_budget`] frames per cycle; a per-strand
+//!    cursor lets the next cycle resume where this one stopped
 //! 3. Collect Forward-Forward samples from events
 //! 4. Train VFN layer-by-layer (no backprop)
 //! 5. Check strand graduation (novel topic → new strand)
@@ -24,6 +26,8 @@
 //! remains responsive during consolidation because locks are held
 //! only for the duration of each phase.
 
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 use std::thread::JoinHandle;
@@ -39,6 +43,7 @@ use crate::eval_dataset;
 use crate::forward_forward::{self, FfConfig, FfResult};
 use crate::graduation::{self, GraduationConfig, GraduationResult};
 use crate::logger::EventLogger;
+use crate::reward::DefaultReward;
 use crate::rlvf::{self, RlvfConfig, RlvfResult};
 
 /// Configuration for the sleep scheduler.
@@ -70,6 +75,14 @@ pub struct SleepConfig {
     /// Minimum accumulated learning events before RLVF triggers.
     /// Default: 100.
     pub rlvf_min_events: usize,
+    /// Maximum frames examined by distillation per sleep cycle, across
+    /// all strands. Strands left unfinished resume from their cursor in
+    /// the next cycle. Default: 1024.
+    pub consolidation_budget: usize,
+    /// File the per-strand consolidation cursors are saved to after each
+    /// cycle and restored from by [`SleepScheduler::restore`]. `None`
+    /// keeps them in memory only. Default: `None`.
+    pub cursor_path: Option<PathBuf>,
 }
 
 impl Default for SleepConfig {
@@ -82,6 +95,8 @@ impl Default for SleepConfig {
             graduation_config: GraduationConfig::default(),
             rlvf_config: None,
             rlvf_min_events: 100,
+            consolidation_budget: 1024,
+            cursor_path: None,
         }
     }
 }
@@ -143,6 +158,8 @@ pub struct SleepScheduler {
     config: SleepConfig,
     last_activity: Instant,
     is_sleeping: bool,
+    /// Highest frame ID distilled so far, per strand.
+    consolidation_cursors: BTreeMap<u64, u64>,
 }
 
 impl SleepScheduler {
@@ -160,9 +177,39 @@ impl SleepScheduler {
             config,
             last_activity: Instant::now(),
             is_sleeping: false,
+            consolidation_cursors: BTreeMap::new(),
         }
     }
 
+    /// Creates a sleep scheduler, restoring consolidation cursors from
+    /// [`SleepConfig::cursor_path`] if that file exists.
+    ///
+    /// Use this after a restart so distillation resumes instead of
+    /// re-clustering frames it already consolidated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::LearnError`] if the cursor file exists but
+    /// cannot be read or parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_learn::sleep::{SleepConfig, SleepScheduler};
+    ///
+    /// let scheduler = SleepScheduler::restore(SleepConfig::default()).unwrap();
+    /// assert_eq!(scheduler.consolidation_cursor(0), 0);
+    /// ```
+    pub fn restore(config: SleepConfig) -> Result<Self, VoltError> {
+        let mut scheduler = Self::new(config);
+        if let Some(path) = &scheduler.config.cursor_path
+            && path.exists()
+        {
+            scheduler.consolidation_cursors = load_cursors(path)?;
+        }
+        Ok(scheduler)
+    }
+
     /// Creates a sleep scheduler with default configuration.
     ///
     /// # Example
@@ -226,6 +273,21 @@ impl SleepScheduler {
         &self.config
     }
 
+    /// Returns the highest frame ID of `strand_id` already examined by
+    /// distillation, or 0 if the strand has not been distilled yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_learn::sleep::SleepScheduler;
+    ///
+    /// let scheduler = SleepScheduler::with_defaults();
+    /// assert_eq!(scheduler.consolidation_cursor(7), 0);
+    /// ```
+    pub fn consolidation_cursor(&self, strand_id: u64) -> u64 {
+        self.consolidation_cursors.get(&strand_id).copied().unwrap_or(0)
+    }
+
     /// Runs a full sleep consolidation cycle.
     ///
     /// Executes all phases in order: distillation → FF training →
@@ -309,7 +371,7 @@ impl SleepScheduler {
 
     /// Internal implementation of the sleep cycle phases.
     fn run_sleep_cycle_inner(
-        &self,
+        &mut self,
         store: &mut VoltStore,
         vfn: &mut Vfn,
         logger: &EventLogger,
@@ -317,8 +379,8 @@ impl SleepScheduler {
         // Phase 1: Snapshot events
         let events: Vec<_> = logger.events().to_vec();
 
-        // Phase 2: Distill all strands
-        let distillation_results = distillation::distill_all_strands(store)?;
+        // Phase 2: Distill all strands, resuming from each strand's cursor
+        let distillation_results = self.distill_within_budget(store)?;
 
         // Phase 3+4: Collect FF samples and train VFN
         let ff_result = if !events.is_empty() {
@@ -346,7 +408,8 @@ impl SleepScheduler {
         {
             let translator = StubTranslator::new();
             let dataset = eval_dataset::generate_eval_dataset();
-            rlvf::train_rlvf(vfn, &dataset, &translator, rlvf_config).ok()
+            let reward = DefaultReward::new(&translator, rlvf_config.reward_config.clone());
+            rlvf::train_rlvf(vfn, &dataset, &translator, &reward, rlvf_config).ok()
         } else {
             None
         };
@@ -373,15 +436,38 @@ impl SleepScheduler {
         })
     }
 
+    /// Distills every strand from its cursor until the cycle's
+    /// consolidation budget is spent, then persists the cursors.
+    fn distill_within_budget(
+        &mut self,
+        store: &mut VoltStore,
+    ) -> Result<Vec<DistillationResult>, VoltError> {
+        let mut budget = self.config.consolidation_budget;
+        let mut results = Vec::new();
+        for strand_id in store.list_strands() {
+            let cursor = self.consolidation_cursor(strand_id);
+            let step = distillation::distill_strand_from(store, strand_id, cursor, budget)?;
+            budget -= step.frames_examined;
+            self.consolidation_cursors.insert(strand_id, step.cursor);
+            results.push(step.result);
+        }
+        if let Some(path) = &self.config.cursor_path {
+            save_cursors(path, &self.consolidation_cursors)?;
+        }
+        Ok(results)
+    }
+
     /// Spawns a background thread that polls for idle and runs sleep cycles.
     ///
     /// The thread acquires locks in a fixed order (logger → store → vfn)
     /// to prevent deadlocks. Returns a [`SleepHandle`] that can be used
-    /// to stop the thread.
+    /// to stop the thread. Consolidation cursors are restored as by
+    /// [`restore`](Self::restore).
     ///
     /// # Errors
     ///
-    /// Returns [`VoltError::LearnError`] if the thread fails to spawn.
+    /// Returns [`VoltError::LearnError`] if the thread fails to spawn or
+    /// the cursor file cannot be read.
     ///
     /// # Example
     ///
@@ -419,13 +505,12 @@ impl SleepScheduler {
         let activity_flag = Arc::new(AtomicBool::new(false));
         let activity_clone = Arc::clone(&activity_flag);
         let poll_interval = config.poll_interval;
+        let mut scheduler = SleepScheduler::restore(config)?;
 
         let thread = std::thread::Builder::new()
             .name("sleep-scheduler".into())
             .stack_size(4 * 1024 * 1024)
             .spawn(move || {
-                let mut scheduler = SleepScheduler::new(config);
-
                 while !stop_clone.load(Ordering::Relaxed) {
                     std::thread::sleep(poll_interval);
 
@@ -477,6 +562,26 @@ impl SleepScheduler {
     }
 }
 
+/// Reads consolidation cursors saved by [`save_cursors`].
+fn load_cursors(path: &Path) -> Result<BTreeMap<u64, u64>, VoltError> {
+    let json = std::fs::read_to_string(path).map_err(|e| VoltError::LearnError {
+        message: format!("failed to read consolidation cursors {}: {e}", path.display()),
+    })?;
+    serde_json::from_str(&json).map_err(|e| VoltError::LearnError {
+        message: format!("failed to parse consolidation cursors {}: {e}", path.display()),
+    })
+}
+
+/// Writes consolidation cursors as a JSON object keyed by strand ID.
+fn save_cursors(path: &Path, cursors: &BTreeMap<u64, u64>) -> Result<(), VoltError> {
+    let json = serde_json::to_string(cursors).map_err(|e| VoltError::LearnError {
+        message: format!("failed to serialize consolidation cursors: {e}"),
+    })?;
+    std::fs::write(path, json).map_err(|e| VoltError::LearnError {
+        message: format!("failed to write consolidation cursors {}: {e}", path.display()),
+    })
+}
+
 /// Handle to a running background sleep scheduler.
 ///
 /// # Example
@@ -637,6 +742,85 @@ mod tests {
         assert!(!scheduler.is_sleeping());
     }
 
+    fn make_similar_frame() -> volt_core::TensorFrame {
+        use volt_core::slot::SlotSource;
+        use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
+
+        let mut frame = TensorFrame::new();
+        let mut slot = SlotData::new(SlotRole::Agent);
+        slot.write_resolution(0, [1.0 / (SLOT_DIM as f32).sqrt(); SLOT_DIM]);
+        frame.write_slot(0, slot).unwrap();
+        frame.meta[0].source = SlotSource::Translator;
+        frame
+    }
+
+    #[test]
+    fn budgeted_sleep_resumes_across_restart_without_duplicates() {
+        let dir = std::env::temp_dir()
+            .join(format!("volt_sleep_cursor_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config = SleepConfig {
+            consolidation_budget: 6,
+            cursor_path: Some(dir.join("cursors.json")),
+            ..SleepConfig::default()
+        };
+        let mut scheduler = SleepScheduler::restore(config.clone()).unwrap();
+        let mut store = VoltStore::new();
+        let mut vfn = Vfn::new_random(42);
+        let logger = EventLogger::new();
+
+        fn drain(
+            scheduler: &mut SleepScheduler,
+            store: &mut VoltStore,
+            vfn: &mut Vfn,
+            logger: &EventLogger,
+        ) {
+            for _ in 0..32 {
+                let result = scheduler.force_sleep(store, vfn, logger).unwrap();
+                if result.distillation.iter().all(|d| d.frames_remaining == 0) {
+                    return;
+                }
+            }
+            panic!("consolidation never caught up");
+        }
+
+        for _ in 0..volt_db::tier0::T0_CAPACITY + 20 {
+            store.store(make_similar_frame()).unwrap();
+        }
+        // The first cycle stops at the budget with frames left over.
+        let first = scheduler.force_sleep(&mut store, &mut vfn, &logger).unwrap();
+        assert!(first.distillation[0].frames_remaining > 0);
+        drain(&mut scheduler, &mut store, &mut vfn, &logger);
+
+        // Frames evicted between cycles are picked up on resume.
+        let cursor = scheduler.consolidation_cursor(0);
+        for _ in 0..10 {
+            store.store(make_similar_frame()).unwrap();
+        }
+        drain(&mut scheduler, &mut store, &mut vfn, &logger);
+        let cursor_after = scheduler.consolidation_cursor(0);
+        assert!(cursor_after > cursor);
+
+        // A restarted scheduler resumes from the saved cursor.
+        let mut restarted = SleepScheduler::restore(config).unwrap();
+        assert_eq!(restarted.consolidation_cursor(0), cursor_after);
+        let again = restarted.force_sleep(&mut store, &mut vfn, &logger).unwrap();
+        assert_eq!(again.distillation[0].wisdom_frames_created, 0);
+
+        let mut sources: Vec<u64> = store
+            .get_by_strand(0)
+            .iter()
+            .flat_map(|f| f.frame_meta.derived_from.iter().copied())
+            .collect();
+        assert!(!sources.is_empty());
+        let total = sources.len();
+        sources.sort_unstable();
+        sources.dedup();
+        assert_eq!(sources.len(), total, "a frame fed two wisdom frames");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn background_scheduler_starts_and_stops() {
         let config = SleepConfig {
@@ -18,10 +18,13 @@
 //!     println!("{}: {} bytes", entry.path, entry.content.len());
 //! }
 //! ```
+//!
+//! Long runs can checkpoint [`StackCorpusReader::current_offset`] and
+//! later resume from it with [`StackCorpusReader::seek_to`].
 
 use serde::Deserialize;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use volt_core::VoltError;
 
@@ -55,6 +58,8 @@ pub struct StackCorpusReader {
     current_path: PathBuf,
     /// Line number within current file (for error messages).
     line_number: usize,
+    /// Bytes consumed from the current file.
+    offset: u64,
 }
 
 impl std::fmt::Debug for StackCorpusReader {
@@ -62,6 +67,7 @@ impl std::fmt::Debug for StackCorpusReader {
         f.debug_struct("StackCorpusReader")
             .field("current_path", &self.current_path)
             .field("line_number", &self.line_number)
+            .field("offset", &self.offset)
             .field("remaining_files", &self.remaining_files.len())
             .finish()
     }
@@ -91,6 +97,7 @@ impl StackCorpusReader {
             line_buf: String::new(),
             current_path: path.to_path_buf(),
             line_number: 0,
+            offset: 0,
         })
     }
 
@@ -148,6 +155,7 @@ impl StackCorpusReader {
             line_buf: String::new(),
             current_path: first_path,
             line_number: 0,
+            offset: 0,
         })
     }
 
@@ -164,8 +172,74 @@ impl StackCorpusReader {
         self.reader = BufReader::new(file);
         self.current_path = next_path;
         self.line_number = 0;
+        self.offset = 0;
         Ok(true)
     }
+
+    /// Byte offset within the current file of the next unread line.
+    ///
+    /// After the iterator yields a record, this points just past that
+    /// record's trailing newline, so it can be checkpointed and passed
+    /// to [`seek_to`](Self::seek_to) on a fresh reader to resume.
+    ///
+    /// In directory mode the offset is relative to
+    /// [`current_path`](Self::current_path).
+    pub fn current_offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Path of the file currently being read.
+    pub fn current_path(&self) -> &Path {
+        &self.current_path
+    }
+
+    /// Move the read position in the current file to `byte_offset`.
+    ///
+    /// If `byte_offset` falls inside a line, the rest of that line is
+    /// skipped so reading resumes at the next record boundary. Offsets
+    /// past the end of the file leave the file exhausted. Line numbers
+    /// in later error messages count from the seek point.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoltError::LearnError` if the file cannot be seeked or read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use volt_learn::stack_corpus::StackCorpusReader;
+    ///
+    /// let mut reader = StackCorpusReader::from_file("corpus.jsonl").unwrap();
+    /// let _first = reader.next();
+    /// let checkpoint = reader.current_offset();
+    ///
+    /// let mut resumed = StackCorpusReader::from_file("corpus.jsonl").unwrap();
+    /// resumed.seek_to(checkpoint).unwrap();
+    /// ```
+    pub fn seek_to(&mut self, byte_offset: u64) -> Result<(), VoltError> {
+        let seek_error = |e: std::io::Error| VoltError::LearnError {
+            message: format!(
+                "failed to seek {} to byte {byte_offset}: {e}",
+                self.current_path.display()
+            ),
+        };
+        if byte_offset == 0 {
+            self.reader.seek(SeekFrom::Start(0)).map_err(seek_error)?;
+            self.offset = 0;
+        } else {
+            // Start one byte early: if that byte is the previous line's
+            // newline we consume only it, otherwise we skip to the end of
+            // the partial line.
+            self.reader
+                .seek(SeekFrom::Start(byte_offset - 1))
+                .map_err(seek_error)?;
+            let mut skipped = Vec::new();
+            let n = self.reader.read_until(b'\n', &mut skipped).map_err(seek_error)?;
+            self.offset = byte_offset - 1 + n as u64;
+        }
+        self.line_number = 0;
+        Ok(())
+    }
 }
 
 impl Iterator for StackCorpusReader {
@@ -183,7 +257,8 @@ impl Iterator for StackCorpusReader {
                         Err(e) => return Some(Err(e)),
                     }
                 }
-                Ok(_) => {
+                Ok(n) => {
+                    self.offset += n as u64;
                     self.line_number += 1;
                     let trimmed = self.line_buf.trim();
                     if trimmed.is_empty() {
@@ -290,6 +365,52 @@ NOT VALID JSON
         assert!(results[2].is_ok()); // iterator continues past errors
     }
 
+    #[test]
+    fn seek_to_checkpoint_resumes_after_last_record() {
+        let data = r#"{"content":"a","language":"python"}
+{"content":"b","language":"python"}
+
+{"content":"c","language":"python"}
+{"content":"d","language":"python"}
+"#;
+        let f = temp_jsonl(data);
+        let mut reader = StackCorpusReader::from_file(f.path()).unwrap();
+        assert_eq!(reader.current_offset(), 0);
+        assert_eq!(reader.next().unwrap().unwrap().content, "a");
+        assert_eq!(reader.next().unwrap().unwrap().content, "b");
+
+        let checkpoint = reader.current_offset();
+        let second_end = data.match_indices('\n').nth(1).unwrap().0 as u64 + 1;
+        assert_eq!(checkpoint, second_end);
+
+        let mut resumed = StackCorpusReader::from_file(f.path()).unwrap();
+        resumed.seek_to(checkpoint).unwrap();
+        assert_eq!(resumed.current_offset(), checkpoint);
+        let rest: Vec<_> = resumed.collect::<Result<Vec<_>, _>>().unwrap();
+        let contents: Vec<_> = rest.iter().map(|e| e.content.as_str()).collect();
+        assert_eq!(contents, ["c", "d"]);
+        assert_eq!(reader.current_offset(), checkpoint);
+    }
+
+    #[test]
+    fn seek_to_mid_record_skips_to_next_boundary() {
+        let data = r#"{"content":"a","language":"python"}
+{"content":"b","language":"python"}
+"#;
+        let f = temp_jsonl(data);
+        let mut reader = StackCorpusReader::from_file(f.path()).unwrap();
+        reader.seek_to(5).unwrap();
+        let first_end = data.find('\n').unwrap() as u64 + 1;
+        assert_eq!(reader.current_offset(), first_end);
+        assert_eq!(reader.next().unwrap().unwrap().content, "b");
+
+        // Past EOF leaves the reader exhausted; seeking back restarts it.
+        reader.seek_to(10_000).unwrap();
+        assert!(reader.next().is_none());
+        reader.seek_to(0).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap().content, "a");
+    }
+
     #[test]
     fn from_file_nonexistent_errors() {
         let result = StackCorpusReader::from_file("/nonexistent/path.jsonl");
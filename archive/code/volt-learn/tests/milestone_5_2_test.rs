@@ -10,6 +10,7 @@ use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use volt_core::meta::DiscourseType;
+use volt_core::slot::SlotSource;
 use volt_core::{SlotData, SlotRole, TensorFrame, MAX_SLOTS, SLOT_DIM};
 use volt_db::VoltStore;
 use volt_learn::forward_forward::{collect_ff_samples, train_ff, FfConfig, FfSample};
@@ -25,6 +26,7 @@ fn make_frame_with_r0(slot_role: SlotRole, values: &[f32; SLOT_DIM]) -> TensorFr
     let mut slot = SlotData::new(slot_role);
     slot.write_resolution(0, *values);
     frame.write_slot(0, slot).unwrap();
+    frame.meta[0].source = SlotSource::Translator;
     frame.normalize_slot(0, 0).unwrap();
     frame
 }
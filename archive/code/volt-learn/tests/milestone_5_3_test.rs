@@ -9,7 +9,7 @@
 
 use volt_learn::calibration::compute_calibration;
 use volt_learn::eval_dataset::{generate_eval_dataset, EvalCategory};
-use volt_learn::reward::{compute_reward, RewardConfig};
+use volt_learn::reward::{compute_reward, DefaultReward, RewardConfig};
 use volt_learn::rlvf::{train_rlvf, RlvfConfig};
 use volt_learn::self_play::{generate_puzzles, grade_puzzle, PuzzleType};
 use volt_soft::vfn::Vfn;
@@ -26,8 +26,9 @@ fn run_rlvf_small() -> volt_learn::rlvf::RlvfResult {
         puzzle_threshold: 0.3,
         ..RlvfConfig::default()
     };
+    let reward = DefaultReward::new(&translator, config.reward_config.clone());
     // Use a subset for speed
-    train_rlvf(&mut vfn, &dataset[..50], &translator, &config).unwrap()
+    train_rlvf(&mut vfn, &dataset[..50], &translator, &reward, &config).unwrap()
 }
 
 /// Test 1: Certainty calibration is computed and has valid ECE.
@@ -133,7 +134,8 @@ fn no_safety_regression_after_rlvf() {
     };
 
     // Train
-    let _result = train_rlvf(&mut vfn, &dataset[..30], &translator, &config).unwrap();
+    let reward = DefaultReward::new(&translator, config.reward_config.clone());
+    let _result = train_rlvf(&mut vfn, &dataset[..30], &translator, &reward, &config).unwrap();
 
     // Verify VFN still produces valid outputs
     for pair in &dataset[..50] {
@@ -191,6 +191,7 @@ fn phase_0_3_full_pipeline_synthetic() {
             },
             output_path: output_path.clone(),
             log_interval: 50,
+            calibration_holdout: 20,
         };
 
         let translator = volt_translate::StubTranslator::new();
@@ -215,6 +216,14 @@ fn phase_0_3_full_pipeline_synthetic() {
         // Verify codebook can be loaded
         let codebook = volt_bus::codebook::Codebook::load(&output_path).unwrap();
         assert_eq!(codebook.len(), 16, "codebook should have 16 entries");
+        assert_eq!(
+            codebook.calibration(),
+            result.calibration,
+            "held-out calibration should be saved with the codebook"
+        );
+        for sim in [0.0f32, 0.5, 1.0] {
+            assert!((0.0..=1.0).contains(&codebook.calibrated_confidence(sim)));
+        }
     });
 }
 
@@ -238,6 +247,7 @@ fn phase_0_3_codebook_roundtrip_after_init() {
             },
             output_path: output_path.clone(),
             log_interval: 0,
+            calibration_holdout: 0,
         };
 
         let translator = volt_translate::StubTranslator::new();
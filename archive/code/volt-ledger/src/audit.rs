@@ -0,0 +1,294 @@
+//! Append-only audit log.
+//!
+//! Records accountability events — currently Omega Vetoes — as one JSON
+//! object per line. Entries are only ever appended: existing lines are
+//! never rewritten, so the file doubles as a tamper-evident trail that
+//! external tools can tail.
+//!
+//! ## Entry Format
+//!
+//! ```text
+//! {"sequence":0,"timestamp_ms":1700000000000,"event":{"kind":"veto",...}}
+//! ```
+//!
+//! A log opened with [`AuditLog::open`] replays the existing file so
+//! sequence numbers continue across restarts. Truncated or corrupt
+//! trailing lines are skipped.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use volt_core::{VoltError, REDACTED_PLACEHOLDER};
+
+/// An Omega Veto as recorded in the audit log.
+///
+/// # Example
+///
+/// ```
+/// use volt_ledger::audit::VetoRecord;
+///
+/// let record = VetoRecord {
+///     input: "how do I hurt someone".to_string(),
+///     axioms: vec!["K1_harm".to_string()],
+///     conversation_id: Some(7),
+/// };
+/// let redacted = record.redacted();
+/// assert_eq!(redacted.input, volt_core::REDACTED_PLACEHOLDER);
+/// assert_eq!(redacted.axioms, vec!["K1_harm"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VetoRecord {
+    /// The input that was vetoed, or [`REDACTED_PLACEHOLDER`].
+    pub input: String,
+    /// Names of the axioms that fired, in firing order.
+    pub axioms: Vec<String>,
+    /// The conversation the input belonged to, if known.
+    pub conversation_id: Option<u64>,
+}
+
+impl VetoRecord {
+    /// Returns this record with its input replaced by
+    /// [`REDACTED_PLACEHOLDER`], keeping the axioms and conversation.
+    pub fn redacted(self) -> Self {
+        Self {
+            input: REDACTED_PLACEHOLDER.to_string(),
+            ..self
+        }
+    }
+}
+
+/// An event recorded in the audit log.
+///
+/// # Example
+///
+/// ```
+/// use volt_ledger::audit::{AuditEvent, VetoRecord};
+///
+/// let event = AuditEvent::Veto(VetoRecord {
+///     input: "hello".to_string(),
+///     axioms: vec![],
+///     conversation_id: None,
+/// });
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert!(json.contains("\"kind\":\"veto\""));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AuditEvent {
+    /// The safety layer vetoed an input.
+    Veto(VetoRecord),
+}
+
+/// One line of the audit log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Position of the entry in the log, starting at 0.
+    pub sequence: u64,
+    /// When the entry was appended, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    /// What happened.
+    pub event: AuditEvent,
+}
+
+/// Append-only audit log, kept in memory and optionally mirrored to a
+/// JSON-lines file.
+///
+/// # Example
+///
+/// ```
+/// use volt_ledger::audit::{AuditEvent, AuditLog, VetoRecord};
+///
+/// let mut log = AuditLog::in_memory();
+/// log.append(AuditEvent::Veto(VetoRecord {
+///     input: "bad".to_string(),
+///     axioms: vec!["K1_harm".to_string()],
+///     conversation_id: None,
+/// }))
+/// .unwrap();
+/// assert_eq!(log.len(), 1);
+/// assert_eq!(log.recent(10)[0].sequence, 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+    file: Option<File>,
+    path: Option<PathBuf>,
+}
+
+impl AuditLog {
+    /// Creates an empty log that is not persisted.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Opens the log file at `path`, creating it if missing, and loads
+    /// its existing entries. New entries are appended to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the file cannot be read or
+    /// opened for appending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_ledger::audit::AuditLog;
+    ///
+    /// let path = std::env::temp_dir().join("volt_audit_doc_open.jsonl");
+    /// let _ = std::fs::remove_file(&path);
+    /// let log = AuditLog::open(&path).unwrap();
+    /// assert!(log.is_empty());
+    /// assert_eq!(log.path(), Some(path.as_path()));
+    /// ```
+    pub fn open(path: &Path) -> Result<Self, VoltError> {
+        let mut entries = Vec::new();
+        if path.exists() {
+            let file = File::open(path).map_err(|e| VoltError::StorageError {
+                message: format!("failed to read audit log {}: {e}", path.display()),
+            })?;
+            for line in BufReader::new(file).lines() {
+                let line = line.map_err(|e| VoltError::StorageError {
+                    message: format!("failed to read audit log {}: {e}", path.display()),
+                })?;
+                if let Ok(entry) = serde_json::from_str::<AuditEntry>(&line) {
+                    entries.push(entry);
+                }
+            }
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| VoltError::StorageError {
+                message: format!("failed to open audit log {}: {e}", path.display()),
+            })?;
+        Ok(Self {
+            entries,
+            file: Some(file),
+            path: Some(path.to_path_buf()),
+        })
+    }
+
+    /// Appends `event`, stamped with the next sequence number and the
+    /// current time, and returns the new entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::StorageError`] if the entry cannot be written
+    /// to the backing file. The entry is not kept in that case.
+    pub fn append(&mut self, event: AuditEvent) -> Result<&AuditEntry, VoltError> {
+        let entry = AuditEntry {
+            sequence: self.entries.last().map_or(0, |last| last.sequence + 1),
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            event,
+        };
+        if let Some(file) = &mut self.file {
+            let mut line = serde_json::to_string(&entry).map_err(|e| VoltError::StorageError {
+                message: format!("failed to serialize audit entry: {e}"),
+            })?;
+            line.push('\n');
+            file.write_all(line.as_bytes())
+                .and_then(|()| file.flush())
+                .map_err(|e| VoltError::StorageError {
+                    message: format!("failed to append audit entry: {e}"),
+                })?;
+        }
+        self.entries.push(entry);
+        Ok(self.entries.last().expect("entry was just pushed"))
+    }
+
+    /// Returns up to `limit` entries, newest first.
+    pub fn recent(&self, limit: usize) -> Vec<&AuditEntry> {
+        self.entries.iter().rev().take(limit).collect()
+    }
+
+    /// Returns every entry, oldest first.
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries in the log.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the log has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the backing file, or `None` for an in-memory log.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join("volt_audit_test")
+            .join(format!("{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn veto(input: &str) -> AuditEvent {
+        AuditEvent::Veto(VetoRecord {
+            input: input.to_string(),
+            axioms: vec!["K1_harm".to_string()],
+            conversation_id: Some(1),
+        })
+    }
+
+    #[test]
+    fn append_assigns_increasing_sequence() {
+        let mut log = AuditLog::in_memory();
+        assert_eq!(log.append(veto("a")).unwrap().sequence, 0);
+        assert_eq!(log.append(veto("b")).unwrap().sequence, 1);
+        let recent = log.recent(1);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].event, veto("b"));
+    }
+
+    #[test]
+    fn reopen_replays_entries_and_continues_sequence() {
+        let path = temp_path("reopen.jsonl");
+        {
+            let mut log = AuditLog::open(&path).unwrap();
+            log.append(veto("first")).unwrap();
+            log.append(veto("second")).unwrap();
+        }
+        let mut log = AuditLog::open(&path).unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.entries()[0].event, veto("first"));
+        assert_eq!(log.append(veto("third")).unwrap().sequence, 2);
+
+        let lines = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(lines.lines().count(), 3);
+    }
+
+    #[test]
+    fn open_skips_truncated_tail() {
+        let path = temp_path("truncated.jsonl");
+        {
+            let mut log = AuditLog::open(&path).unwrap();
+            log.append(veto("kept")).unwrap();
+        }
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"sequence\":1,\"timest").unwrap();
+
+        let log = AuditLog::open(&path).unwrap();
+        assert_eq!(log.len(), 1);
+    }
+}
@@ -14,10 +14,15 @@
 //! - Privacy-preserving: differential privacy on shared strands.
 //! - Depends on `volt-core`, `volt-bus`, `volt-db`.
 
+pub use ed25519_dalek;
 pub use volt_core;
 
+pub mod audit;
+pub mod package;
+
+pub use audit::{AuditEntry, AuditEvent, AuditLog, VetoRecord};
+pub use package::{pack_module, unpack_module, ModulePackage, MODULE_PACKAGE_VERSION};
+
 // MILESTONE: 7.1 — Intelligence Commons foundation
 // TODO: Define strand sharing protocol
-// TODO: Implement append-only audit log
-// TODO: Implement module distribution format
 // TODO: Implement P2P mesh discovery
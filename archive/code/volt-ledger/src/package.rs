@@ -0,0 +1,408 @@
+//! Signed module packages for community module distribution.
+//!
+//! A package bundles everything needed to share a module: its
+//! [`ModuleInfo`] manifest, the capability vector the router matches
+//! against, and an opaque payload (a WASM binary or a config blob). The
+//! whole container is signed with Ed25519 so a downloaded package can be
+//! checked for tampering before it is installed.
+//!
+//! ## Package Format
+//!
+//! ```text
+//! [magic: 4B "VXMP"][version: u32][manifest_len: u32][manifest: JSON]
+//! [capability_vector: SLOT_DIM × f32][payload_len: u64][payload]
+//! [signer: 32B Ed25519 public key][signature: 64B]
+//! ```
+//!
+//! All integers are little-endian. The signature covers every byte
+//! before it, including the signer's key. [`unpack_module`] accepts a
+//! package only if that key is one of the caller's trusted keys, so a
+//! package re-signed by anyone else is rejected.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use volt_core::module_info::{ModuleInfo, ModuleType};
+use volt_core::{VoltError, SLOT_DIM};
+
+/// Magic bytes identifying a module package.
+const PACKAGE_MAGIC: [u8; 4] = *b"VXMP";
+
+/// Current module package format version.
+pub const MODULE_PACKAGE_VERSION: u32 = 1;
+
+/// Length of the trailing signer key and signature.
+const TRAILER_LEN: usize = 32 + 64;
+
+/// A verified module package.
+///
+/// # Example
+///
+/// ```
+/// use volt_core::module_info::{ModuleInfo, ModuleType};
+/// use volt_core::SLOT_DIM;
+/// use volt_ledger::ed25519_dalek::SigningKey;
+/// use volt_ledger::package::{pack_module, unpack_module};
+///
+/// let info = ModuleInfo {
+///     id: "volt-strand-weather".to_string(),
+///     display_name: "Weather Strand".to_string(),
+///     version: "0.1.0".to_string(),
+///     author: "Volt X Team".to_string(),
+///     description: "Mock weather data.".to_string(),
+///     module_type: ModuleType::HardStrand,
+///     min_core_version: "0.1.0".to_string(),
+///     requires: vec![],
+///     deterministic: true,
+/// };
+/// let key = SigningKey::from_bytes(&[7; 32]);
+/// let bytes = pack_module(info, [0.1; SLOT_DIM], b"(module)".to_vec(), &key).unwrap();
+///
+/// let package = unpack_module(&bytes, &[key.verifying_key()]).unwrap();
+/// assert_eq!(package.info.id, "volt-strand-weather");
+/// assert_eq!(package.signer, key.verifying_key());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModulePackage {
+    /// The module's manifest.
+    pub info: ModuleInfo,
+    /// The capability vector the router matches the module against.
+    pub capability_vector: [f32; SLOT_DIM],
+    /// The module's WASM binary or config blob.
+    pub payload: Vec<u8>,
+    /// Public key the package was signed with.
+    pub signer: VerifyingKey,
+}
+
+/// Serialized form of [`ModuleInfo`], which has no serde support.
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    id: String,
+    display_name: String,
+    version: String,
+    author: String,
+    description: String,
+    module_type: String,
+    min_core_version: String,
+    requires: Vec<String>,
+    deterministic: bool,
+}
+
+impl From<ModuleInfo> for Manifest {
+    fn from(info: ModuleInfo) -> Self {
+        Self {
+            id: info.id,
+            display_name: info.display_name,
+            version: info.version,
+            author: info.author,
+            description: info.description,
+            module_type: info.module_type.to_string(),
+            min_core_version: info.min_core_version,
+            requires: info.requires,
+            deterministic: info.deterministic,
+        }
+    }
+}
+
+impl TryFrom<Manifest> for ModuleInfo {
+    type Error = VoltError;
+
+    fn try_from(manifest: Manifest) -> Result<Self, VoltError> {
+        let module_type = match manifest.module_type.as_str() {
+            "Translator" => ModuleType::Translator,
+            "HardStrand" => ModuleType::HardStrand,
+            "ActionCore" => ModuleType::ActionCore,
+            other => {
+                return Err(VoltError::ModuleError {
+                    name: manifest.id,
+                    message: format!("unknown module type {other:?}"),
+                });
+            }
+        };
+        Ok(Self {
+            id: manifest.id,
+            display_name: manifest.display_name,
+            version: manifest.version,
+            author: manifest.author,
+            description: manifest.description,
+            module_type,
+            min_core_version: manifest.min_core_version,
+            requires: manifest.requires,
+            deterministic: manifest.deterministic,
+        })
+    }
+}
+
+/// Pack a module into a signed package.
+///
+/// `payload` is stored verbatim; `signing_key` signs the whole container.
+///
+/// # Errors
+///
+/// Returns [`VoltError::ModuleError`] if the manifest cannot be
+/// serialized or is larger than 4 GiB.
+///
+/// # Example
+///
+/// ```
+/// use volt_core::module_info::{ModuleInfo, ModuleType};
+/// use volt_core::SLOT_DIM;
+/// use volt_ledger::ed25519_dalek::SigningKey;
+/// use volt_ledger::package::pack_module;
+///
+/// let info = ModuleInfo {
+///     id: "echo".to_string(),
+///     display_name: "Echo".to_string(),
+///     version: "1.0.0".to_string(),
+///     author: "Someone".to_string(),
+///     description: "Echoes its input.".to_string(),
+///     module_type: ModuleType::HardStrand,
+///     min_core_version: String::new(),
+///     requires: vec![],
+///     deterministic: true,
+/// };
+/// let bytes = pack_module(info, [0.0; SLOT_DIM], vec![], &SigningKey::from_bytes(&[1; 32]))
+///     .unwrap();
+/// assert!(bytes.starts_with(b"VXMP"));
+/// ```
+pub fn pack_module(
+    info: ModuleInfo,
+    capability_vector: [f32; SLOT_DIM],
+    payload: Vec<u8>,
+    signing_key: &SigningKey,
+) -> Result<Vec<u8>, VoltError> {
+    let id = info.id.clone();
+    let manifest = serde_json::to_vec(&Manifest::from(info)).map_err(|e| {
+        VoltError::ModuleError {
+            name: id.clone(),
+            message: format!("failed to serialize manifest: {e}"),
+        }
+    })?;
+    let manifest_len = u32::try_from(manifest.len()).map_err(|_| VoltError::ModuleError {
+        name: id,
+        message: format!("manifest too large: {} bytes", manifest.len()),
+    })?;
+
+    let mut bytes = Vec::with_capacity(
+        4 + 4 + 4 + manifest.len() + SLOT_DIM * 4 + 8 + payload.len() + TRAILER_LEN,
+    );
+    bytes.extend_from_slice(&PACKAGE_MAGIC);
+    bytes.extend_from_slice(&MODULE_PACKAGE_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&manifest_len.to_le_bytes());
+    bytes.extend_from_slice(&manifest);
+    for x in &capability_vector {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    bytes.extend_from_slice(signing_key.verifying_key().as_bytes());
+
+    let signature = signing_key.sign(&bytes);
+    bytes.extend_from_slice(&signature.to_bytes());
+    Ok(bytes)
+}
+
+/// Unpack a module package, verifying its format version and that it
+/// was signed by one of the `trusted` keys.
+///
+/// Signatures are checked with Ed25519 strict verification, which also
+/// rejects weak keys and malleable signatures.
+///
+/// # Errors
+///
+/// Returns [`VoltError::ModuleError`] if the bytes are not a package,
+/// were written by another format version, are truncated or malformed,
+/// were signed by a key not in `trusted`, or fail signature verification.
+///
+/// # Example
+///
+/// ```
+/// use volt_ledger::ed25519_dalek::SigningKey;
+/// use volt_ledger::package::unpack_module;
+///
+/// let trusted = [SigningKey::from_bytes(&[7; 32]).verifying_key()];
+/// assert!(unpack_module(b"not a package", &trusted).is_err());
+/// ```
+pub fn unpack_module(bytes: &[u8], trusted: &[VerifyingKey]) -> Result<ModulePackage, VoltError> {
+    let invalid = |message: String| VoltError::ModuleError {
+        name: "package".to_string(),
+        message,
+    };
+
+    let mut reader = PackageReader { bytes, pos: 0 };
+    if reader.take(4)? != PACKAGE_MAGIC.as_slice() {
+        return Err(invalid("invalid magic bytes".to_string()));
+    }
+    let version = reader.u32()?;
+    if version != MODULE_PACKAGE_VERSION {
+        return Err(invalid(format!(
+            "unsupported package version {version} (expected {MODULE_PACKAGE_VERSION})"
+        )));
+    }
+
+    // Verify before parsing anything else out of the signed region
+    if bytes.len() < reader.pos + TRAILER_LEN {
+        return Err(invalid("package truncated".to_string()));
+    }
+    let signed_len = bytes.len() - 64;
+    let signer_bytes: [u8; 32] = bytes[signed_len - 32..signed_len].try_into().unwrap();
+    let signature_bytes: [u8; 64] = bytes[signed_len..].try_into().unwrap();
+    let signer = VerifyingKey::from_bytes(&signer_bytes)
+        .map_err(|e| invalid(format!("invalid signer key: {e}")))?;
+    if !trusted.contains(&signer) {
+        return Err(invalid("package signed by an untrusted key".to_string()));
+    }
+    signer
+        .verify_strict(&bytes[..signed_len], &Signature::from_bytes(&signature_bytes))
+        .map_err(|_| invalid("signature verification failed".to_string()))?;
+
+    let manifest_len = reader.u32()? as usize;
+    let manifest: Manifest = serde_json::from_slice(reader.take(manifest_len)?)
+        .map_err(|e| invalid(format!("malformed manifest: {e}")))?;
+    let mut capability_vector = [0.0f32; SLOT_DIM];
+    for x in &mut capability_vector {
+        *x = f32::from_le_bytes(reader.take(4)?.try_into().unwrap());
+    }
+    let payload_len = usize::try_from(reader.u64()?)
+        .map_err(|_| invalid("payload length overflows usize".to_string()))?;
+    let payload = reader.take(payload_len)?.to_vec();
+    if reader.pos != signed_len - 32 {
+        return Err(invalid(format!(
+            "{} unexpected bytes before signature",
+            (signed_len - 32).saturating_sub(reader.pos)
+        )));
+    }
+
+    Ok(ModulePackage {
+        info: manifest.try_into()?,
+        capability_vector,
+        payload,
+        signer,
+    })
+}
+
+/// Bounds-checked cursor over package bytes.
+struct PackageReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PackageReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], VoltError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| VoltError::ModuleError {
+                name: "package".to_string(),
+                message: "package truncated".to_string(),
+            })?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> Result<u32, VoltError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, VoltError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_info() -> ModuleInfo {
+        ModuleInfo {
+            id: "volt-strand-weather".to_string(),
+            display_name: "Weather Strand".to_string(),
+            version: "0.2.1".to_string(),
+            author: "Volt X Team".to_string(),
+            description: "Provides mock weather data.".to_string(),
+            module_type: ModuleType::ActionCore,
+            min_core_version: "0.1.0".to_string(),
+            requires: vec!["volt-strand-clock".to_string()],
+            deterministic: false,
+        }
+    }
+
+    fn test_vector() -> [f32; SLOT_DIM] {
+        std::array::from_fn(|i| (i as f32 * 0.37).sin())
+    }
+
+    fn key() -> SigningKey {
+        SigningKey::from_bytes(&[42; 32])
+    }
+
+    fn trusted() -> [VerifyingKey; 1] {
+        [key().verifying_key()]
+    }
+
+    #[test]
+    fn pack_unpack_roundtrip() {
+        let payload = b"\0asm\x01\0\0\0".to_vec();
+        let bytes = pack_module(test_info(), test_vector(), payload.clone(), &key()).unwrap();
+        let package = unpack_module(&bytes, &trusted()).unwrap();
+
+        let info = test_info();
+        assert_eq!(package.info.id, info.id);
+        assert_eq!(package.info.display_name, info.display_name);
+        assert_eq!(package.info.version, info.version);
+        assert_eq!(package.info.author, info.author);
+        assert_eq!(package.info.description, info.description);
+        assert_eq!(package.info.module_type, info.module_type);
+        assert_eq!(package.info.min_core_version, info.min_core_version);
+        assert_eq!(package.info.requires, info.requires);
+        assert_eq!(package.info.deterministic, info.deterministic);
+        assert_eq!(package.capability_vector, test_vector());
+        assert_eq!(package.payload, payload);
+        assert_eq!(package.signer, key().verifying_key());
+    }
+
+    #[test]
+    fn tampered_payload_rejected() {
+        let mut bytes = pack_module(test_info(), test_vector(), vec![1, 2, 3, 4], &key()).unwrap();
+        let payload_pos = bytes.len() - TRAILER_LEN - 2;
+        bytes[payload_pos] ^= 0xFF;
+        let err = unpack_module(&bytes, &trusted()).unwrap_err();
+        assert!(err.to_string().contains("signature"), "{err}");
+    }
+
+    #[test]
+    fn resigned_by_another_key_rejected_unless_trusted() {
+        let bytes = pack_module(test_info(), test_vector(), vec![9], &key()).unwrap();
+        let other = SigningKey::from_bytes(&[3; 32]);
+        let mut forged = bytes[..bytes.len() - TRAILER_LEN].to_vec();
+        forged.extend_from_slice(other.verifying_key().as_bytes());
+        let signature = other.sign(&forged);
+        forged.extend_from_slice(&signature.to_bytes());
+
+        // Intact, but not from a trusted author
+        let err = unpack_module(&forged, &trusted()).unwrap_err();
+        assert!(err.to_string().contains("untrusted"), "{err}");
+        assert!(unpack_module(&forged, &[]).is_err());
+
+        let both = [key().verifying_key(), other.verifying_key()];
+        let package = unpack_module(&forged, &both).unwrap();
+        assert_eq!(package.signer, other.verifying_key());
+    }
+
+    #[test]
+    fn version_mismatch_rejected() {
+        let mut bytes = pack_module(test_info(), test_vector(), vec![], &key()).unwrap();
+        bytes[4..8].copy_from_slice(&(MODULE_PACKAGE_VERSION + 1).to_le_bytes());
+        let err = unpack_module(&bytes, &trusted()).unwrap_err();
+        assert!(err.to_string().contains("version"), "{err}");
+    }
+
+    #[test]
+    fn truncated_and_garbage_rejected() {
+        let bytes = pack_module(test_info(), test_vector(), vec![5; 16], &key()).unwrap();
+        for len in [0, 3, 8, 20, bytes.len() - 1] {
+            assert!(unpack_module(&bytes[..len], &trusted()).is_err(), "len {len}");
+        }
+        assert!(unpack_module(b"VXMP\x01\0\0\0garbage", &trusted()).is_err());
+    }
+}
@@ -120,6 +120,24 @@ fn build_axiom_vector(seed: u64) -> [f32; SLOT_DIM] {
     v
 }
 
+/// Highest threshold a [`Severity::Halt`] axiom may be configured with.
+///
+/// Deployments may relax Halt axioms somewhat (e.g. K3 for internal tools),
+/// but never far enough to effectively disable the Omega Veto.
+///
+/// # Example
+///
+/// ```
+/// use volt_safety::axiom::{default_axioms, Severity, MAX_HALT_THRESHOLD};
+///
+/// for axiom in default_axioms() {
+///     if axiom.severity == Severity::Halt {
+///         assert!(axiom.threshold <= MAX_HALT_THRESHOLD);
+///     }
+/// }
+/// ```
+pub const MAX_HALT_THRESHOLD: f32 = 0.85;
+
 // Deterministic seeds for each axiom (ASCII-encoded mnemonics).
 const K1_SEED: u64 = 0x4b31_4841_524d_5f5f; // "K1HARM__"
 const K2_SEED: u64 = 0x4b32_4445_4345_5054; // "K2DECEPT"
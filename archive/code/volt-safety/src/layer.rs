@@ -177,6 +177,43 @@ impl SafetyLayer {
         self.monitor.axiom_count()
     }
 
+    /// Overrides the violation threshold of a single axiom.
+    ///
+    /// See [`TransitionMonitor::set_threshold`] for the rules; in
+    /// particular Halt-severity axioms cannot be relaxed past
+    /// [`MAX_HALT_THRESHOLD`](crate::axiom::MAX_HALT_THRESHOLD).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SafetyViolation`] if the override is rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_safety::layer::SafetyLayer;
+    /// use volt_hard::default_pipeline;
+    ///
+    /// let mut layer = SafetyLayer::new(default_pipeline());
+    /// layer.set_threshold("K4_autonomy", 0.5).unwrap();
+    /// assert!(layer.set_threshold("K1_harm", 1.0).is_err());
+    /// ```
+    pub fn set_threshold(&mut self, axiom_name: &str, threshold: f32) -> Result<(), VoltError> {
+        self.monitor.set_threshold(axiom_name, threshold)
+    }
+
+    /// Applies a map of per-axiom threshold overrides, keyed by axiom name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SafetyViolation`] if any override is rejected;
+    /// in that case no threshold is changed.
+    pub fn apply_thresholds(
+        &mut self,
+        overrides: &std::collections::HashMap<String, f32>,
+    ) -> Result<(), VoltError> {
+        self.monitor.apply_thresholds(overrides)
+    }
+
     /// Returns the number of veto events that have occurred.
     ///
     /// # Example
@@ -252,6 +289,22 @@ impl SafetyLayer {
     /// }).unwrap().join().unwrap();
     /// ```
     pub fn process(&mut self, frame: &TensorFrame) -> Result<SafetyResult, VoltError> {
+        self.process_excluding(frame, &[])
+    }
+
+    /// Process a frame as [`process`](Self::process) does, with the named
+    /// hard strands left out of routing for this call only.
+    ///
+    /// Unknown strand names are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`process`](Self::process).
+    pub fn process_excluding(
+        &mut self,
+        frame: &TensorFrame,
+        excluded: &[String],
+    ) -> Result<SafetyResult, VoltError> {
         // Step 1: Pre-check
         let pre_monitor = self.monitor.check_frame(frame);
         let pre_scoring = self.scorer.score(&pre_monitor);
@@ -271,7 +324,7 @@ impl SafetyLayer {
         }
 
         // Step 3: Process through pipeline
-        let pipeline_result = self.pipeline.process(frame)?;
+        let pipeline_result = self.pipeline.process_excluding(frame, excluded)?;
 
         // Step 4: Post-check
         let post_monitor = self.monitor.check_frame(&pipeline_result.frame);
@@ -449,6 +502,7 @@ mod tests {
                 let log = result.veto_log.unwrap();
                 assert!(log.aggregate_score > 0.7);
                 assert!(!log.violation_details.is_empty());
+                assert_eq!(log.axioms.first(), Some(&"K1_harm"));
             })
             .unwrap()
             .join()
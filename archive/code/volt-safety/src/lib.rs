@@ -12,6 +12,7 @@
 //! - **[`scorer`]**: Violation Scorer computing aggregate safety scores
 //! - **[`veto`]**: Omega Veto — hardware-level halt, cannot be overridden
 //! - **[`layer`]**: Safety Layer wrapping the entire Soft Core → Hard Core pipeline
+//! - **[`runner`]**: Reusable runner with an optional content-hash verdict cache
 //!
 //! ## Architecture Rules
 //!
@@ -57,10 +58,12 @@ pub use volt_core;
 pub mod axiom;
 pub mod layer;
 pub mod monitor;
+pub mod runner;
 pub mod scorer;
 pub mod veto;
 
 pub use layer::SafetyResult;
+pub use runner::SafetyRunner;
 
 use volt_core::{TensorFrame, VoltError};
 
@@ -99,9 +102,7 @@ pub fn safe_process(frame: &TensorFrame) -> Result<TensorFrame, VoltError> {
             let mut safety_layer = layer::SafetyLayer::new(pipeline);
             let result = safety_layer.process(&frame)?;
             if result.vetoed {
-                return Err(VoltError::SafetyViolation {
-                    message: "omega veto triggered: frame violated safety axioms".to_string(),
-                });
+                return Err(veto_error(&result));
             }
             Ok(result.frame)
         })
@@ -141,17 +142,49 @@ pub fn safe_process(frame: &TensorFrame) -> Result<TensorFrame, VoltError> {
 /// }).unwrap().join().unwrap();
 /// ```
 pub fn safe_process_full(frame: &TensorFrame) -> Result<SafetyResult, VoltError> {
+    safe_process_full_excluding(frame, Vec::new())
+}
+
+/// Like [`safe_process_full`], but with the named hard strands (e.g.
+/// `"math_engine"`) left out of routing for this call.
+///
+/// Unknown names are ignored. With every strand excluded the frame still
+/// passes through the safety checks and certainty propagation unrouted.
+///
+/// # Errors
+///
+/// Same as [`safe_process_full`].
+///
+/// # Example
+///
+/// ```
+/// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+/// use volt_safety::safe_process_full_excluding;
+///
+/// std::thread::Builder::new().stack_size(4 * 1024 * 1024).spawn(|| {
+///     let mut frame = TensorFrame::new();
+///     let mut slot = SlotData::new(SlotRole::Agent);
+///     slot.write_resolution(0, [0.1; SLOT_DIM]);
+///     frame.write_slot(0, slot).unwrap();
+///
+///     let excluded = vec!["math_engine".to_string(), "no_such_strand".to_string()];
+///     let result = safe_process_full_excluding(&frame, excluded).unwrap();
+///     assert!(!result.vetoed);
+/// }).unwrap().join().unwrap();
+/// ```
+pub fn safe_process_full_excluding(
+    frame: &TensorFrame,
+    excluded: Vec<String>,
+) -> Result<SafetyResult, VoltError> {
     let frame = Box::new(frame.clone());
     std::thread::Builder::new()
         .stack_size(8 * 1024 * 1024)
         .spawn(move || {
             let pipeline = volt_hard::default_pipeline();
             let mut safety_layer = layer::SafetyLayer::new(pipeline);
-            let result = safety_layer.process(&frame)?;
+            let result = safety_layer.process_excluding(&frame, &excluded)?;
             if result.vetoed {
-                return Err(VoltError::SafetyViolation {
-                    message: "omega veto triggered: frame violated safety axioms".to_string(),
-                });
+                return Err(veto_error(&result));
             }
             Ok(result)
         })
@@ -164,6 +197,58 @@ pub fn safe_process_full(frame: &TensorFrame) -> Result<SafetyResult, VoltError>
         })?
 }
 
+/// Prefix of the [`VoltError::SafetyViolation`] message for an Omega Veto.
+const VETO_MESSAGE: &str = "omega veto triggered: frame violated safety axioms";
+
+/// Build the error for a vetoed `result`, naming the axioms that fired
+/// so [`vetoed_axioms`] can recover them.
+fn veto_error(result: &SafetyResult) -> VoltError {
+    let axioms = result
+        .veto_log
+        .as_ref()
+        .map(|log| log.axioms.join(", "))
+        .unwrap_or_default();
+    VoltError::SafetyViolation {
+        message: format!("{VETO_MESSAGE} [{axioms}]"),
+    }
+}
+
+/// Names of the axioms that fired, recovered from an Omega Veto error
+/// message produced by [`safe_process`] or [`safe_process_full_excluding`].
+///
+/// The message may be wrapped in further context. Returns an empty list
+/// if `message` does not describe an Omega Veto.
+///
+/// # Example
+///
+/// ```
+/// use volt_safety::vetoed_axioms;
+///
+/// let message = "safety violation: omega veto triggered: \
+///                frame violated safety axioms [K1_harm, K3_privacy]";
+/// assert_eq!(vetoed_axioms(message), vec!["K1_harm", "K3_privacy"]);
+/// assert!(vetoed_axioms("hard core pipeline failed").is_empty());
+/// ```
+pub fn vetoed_axioms(message: &str) -> Vec<String> {
+    let Some(start) = message.find(VETO_MESSAGE) else {
+        return Vec::new();
+    };
+    let rest = &message[start + VETO_MESSAGE.len()..];
+    let Some(list) = rest
+        .trim_start()
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(list, _)| list)
+    else {
+        return Vec::new();
+    };
+    list.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,6 +308,7 @@ mod tests {
                 match result.unwrap_err() {
                     VoltError::SafetyViolation { message } => {
                         assert!(message.contains("omega veto"));
+                        assert_eq!(vetoed_axioms(&message), vec!["K1_harm"]);
                     }
                     other => panic!("expected SafetyViolation, got {:?}", other),
                 }
@@ -19,10 +19,12 @@
 //! assert!(result.violations.is_empty());
 //! ```
 
+use std::collections::HashMap;
+
 use volt_bus::similarity;
-use volt_core::{TensorFrame, MAX_SLOTS, SLOT_DIM};
+use volt_core::{TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
 
-use crate::axiom::{Axiom, Severity};
+use crate::axiom::{Axiom, Severity, MAX_HALT_THRESHOLD};
 
 /// A single violation detected by the monitor.
 ///
@@ -186,6 +188,122 @@ impl TransitionMonitor {
         &self.axioms
     }
 
+    /// Overrides the violation threshold of a single axiom.
+    ///
+    /// Lowering a threshold makes the axiom stricter (it fires on less
+    /// similar content); raising it relaxes the axiom. Halt-severity
+    /// axioms cannot be raised above [`MAX_HALT_THRESHOLD`], so the
+    /// Omega Veto can never be effectively disabled by configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SafetyViolation`] if no axiom is named
+    /// `axiom_name`, if `threshold` is not in `(0.0, 1.0]`, or if it
+    /// exceeds [`MAX_HALT_THRESHOLD`] for a Halt-severity axiom.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_safety::monitor::TransitionMonitor;
+    /// use volt_safety::axiom::default_axioms;
+    ///
+    /// let mut monitor = TransitionMonitor::new(default_axioms());
+    /// monitor.set_threshold("K4_autonomy", 0.5).unwrap();
+    /// assert_eq!(monitor.threshold("K4_autonomy"), Some(0.5));
+    ///
+    /// // K1 cannot be relaxed past the hard ceiling
+    /// assert!(monitor.set_threshold("K1_harm", 0.99).is_err());
+    /// ```
+    pub fn set_threshold(&mut self, axiom_name: &str, threshold: f32) -> Result<(), VoltError> {
+        let index = self.validate_threshold(axiom_name, threshold)?;
+        self.axioms[index].threshold = threshold;
+        Ok(())
+    }
+
+    /// Applies a map of per-axiom threshold overrides, keyed by axiom name.
+    ///
+    /// All entries are validated before any is applied, so an invalid
+    /// entry leaves the monitor unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::SafetyViolation`] under the same conditions
+    /// as [`TransitionMonitor::set_threshold`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use volt_safety::monitor::TransitionMonitor;
+    /// use volt_safety::axiom::default_axioms;
+    ///
+    /// let mut monitor = TransitionMonitor::new(default_axioms());
+    /// let overrides = HashMap::from([
+    ///     ("K3_privacy".to_string(), 0.8),
+    ///     ("K4_autonomy".to_string(), 0.55),
+    /// ]);
+    /// monitor.apply_thresholds(&overrides).unwrap();
+    /// assert_eq!(monitor.threshold("K3_privacy"), Some(0.8));
+    /// ```
+    pub fn apply_thresholds(&mut self, overrides: &HashMap<String, f32>) -> Result<(), VoltError> {
+        let mut updates = Vec::with_capacity(overrides.len());
+        for (name, &threshold) in overrides {
+            updates.push((self.validate_threshold(name, threshold)?, threshold));
+        }
+        for (index, threshold) in updates {
+            self.axioms[index].threshold = threshold;
+        }
+        Ok(())
+    }
+
+    /// Returns the current threshold of the named axiom, if it exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_safety::monitor::TransitionMonitor;
+    /// use volt_safety::axiom::default_axioms;
+    ///
+    /// let monitor = TransitionMonitor::new(default_axioms());
+    /// assert_eq!(monitor.threshold("K1_harm"), Some(0.7));
+    /// assert_eq!(monitor.threshold("K9_unknown"), None);
+    /// ```
+    pub fn threshold(&self, axiom_name: &str) -> Option<f32> {
+        self.axioms
+            .iter()
+            .find(|a| a.name == axiom_name)
+            .map(|a| a.threshold)
+    }
+
+    /// Validates a threshold override and returns the index of its axiom.
+    fn validate_threshold(&self, axiom_name: &str, threshold: f32) -> Result<usize, VoltError> {
+        let index = self
+            .axioms
+            .iter()
+            .position(|a| a.name == axiom_name)
+            .ok_or_else(|| VoltError::SafetyViolation {
+                message: format!("cannot set threshold: unknown axiom '{axiom_name}'"),
+            })?;
+
+        if !threshold.is_finite() || threshold <= 0.0 || threshold > 1.0 {
+            return Err(VoltError::SafetyViolation {
+                message: format!(
+                    "threshold {threshold} for axiom '{axiom_name}' must be in (0.0, 1.0]"
+                ),
+            });
+        }
+
+        if self.axioms[index].severity == Severity::Halt && threshold > MAX_HALT_THRESHOLD {
+            return Err(VoltError::SafetyViolation {
+                message: format!(
+                    "threshold {threshold} for Halt axiom '{axiom_name}' exceeds the hard ceiling {MAX_HALT_THRESHOLD}"
+                ),
+            });
+        }
+
+        Ok(index)
+    }
+
     /// Check a single frame against all axioms.
     ///
     /// Computes cosine similarity between each active slot's R0 embedding
@@ -374,6 +492,101 @@ mod tests {
         assert_eq!(monitor.axiom_count(), 5);
     }
 
+    /// Builds a unit vector with the given cosine similarity to `axis`.
+    fn vector_at_similarity(axis: &[f32; SLOT_DIM], sim: f32) -> [f32; SLOT_DIM] {
+        // Orthogonal component: a fixed pattern with its projection on `axis` removed.
+        let mut ortho = [0.0_f32; SLOT_DIM];
+        for (i, v) in ortho.iter_mut().enumerate() {
+            *v = if i % 2 == 0 { 1.0 } else { -0.5 };
+        }
+        let dot: f32 = ortho.iter().zip(axis.iter()).map(|(a, b)| a * b).sum();
+        for (o, a) in ortho.iter_mut().zip(axis.iter()) {
+            *o -= dot * a;
+        }
+        let norm: f32 = ortho.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let cross = (1.0 - sim * sim).sqrt();
+        let mut v = [0.0_f32; SLOT_DIM];
+        for ((out, a), o) in v.iter_mut().zip(axis.iter()).zip(ortho.iter()) {
+            *out = sim * a + cross * o / norm;
+        }
+        v
+    }
+
+    fn frame_with_r0(vector: [f32; SLOT_DIM]) -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        let mut slot = SlotData::new(SlotRole::Predicate);
+        slot.write_resolution(0, vector);
+        frame.write_slot(1, slot).unwrap();
+        frame
+    }
+
+    #[test]
+    fn lowering_warning_threshold_triggers_borderline_frame() {
+        let axioms = default_axioms();
+        let k4_vector = axioms[3].vector;
+        let mut monitor = TransitionMonitor::new(axioms);
+
+        // Similarity 0.55 is below K4's default threshold of 0.65
+        let frame = frame_with_r0(vector_at_similarity(&k4_vector, 0.55));
+        assert!(monitor.check_frame(&frame).is_safe());
+
+        monitor.set_threshold("K4_autonomy", 0.5).unwrap();
+        let result = monitor.check_frame(&frame);
+        assert!(!result.is_safe());
+        assert_eq!(result.violations[0].axiom_name, "K4_autonomy");
+        assert_eq!(result.max_severity, Some(Severity::Warning));
+    }
+
+    #[test]
+    fn raising_warning_threshold_silences_borderline_frame() {
+        let axioms = default_axioms();
+        let k4_vector = axioms[3].vector;
+        let mut monitor = TransitionMonitor::new(axioms);
+
+        let frame = frame_with_r0(vector_at_similarity(&k4_vector, 0.75));
+        assert!(!monitor.check_frame(&frame).is_safe());
+
+        monitor.set_threshold("K4_autonomy", 0.8).unwrap();
+        assert!(monitor.check_frame(&frame).is_safe());
+    }
+
+    #[test]
+    fn halt_threshold_cannot_exceed_ceiling() {
+        let axioms = default_axioms();
+        let k1_vector = axioms[0].vector;
+        let mut monitor = TransitionMonitor::new(axioms);
+
+        assert!(monitor.set_threshold("K1_harm", 1.0).is_err());
+        assert!(monitor.set_threshold("K1_harm", MAX_HALT_THRESHOLD + 0.01).is_err());
+        assert_eq!(monitor.threshold("K1_harm"), Some(0.7));
+
+        // Relaxing up to the ceiling is allowed, and K1 still halts
+        monitor.set_threshold("K1_harm", MAX_HALT_THRESHOLD).unwrap();
+        let result = monitor.check_frame(&frame_with_r0(k1_vector));
+        assert!(result.requires_halt());
+    }
+
+    #[test]
+    fn set_threshold_rejects_unknown_axiom_and_bad_values() {
+        let mut monitor = TransitionMonitor::new(default_axioms());
+        assert!(monitor.set_threshold("K9_unknown", 0.5).is_err());
+        assert!(monitor.set_threshold("K4_autonomy", 0.0).is_err());
+        assert!(monitor.set_threshold("K4_autonomy", f32::NAN).is_err());
+        assert!(monitor.set_threshold("K4_autonomy", 1.5).is_err());
+    }
+
+    #[test]
+    fn apply_thresholds_is_all_or_nothing() {
+        let mut monitor = TransitionMonitor::new(default_axioms());
+        let overrides = HashMap::from([
+            ("K4_autonomy".to_string(), 0.5),
+            ("K1_harm".to_string(), 0.95),
+        ]);
+        assert!(monitor.apply_thresholds(&overrides).is_err());
+        assert_eq!(monitor.threshold("K4_autonomy"), Some(0.65));
+        assert_eq!(monitor.threshold("K1_harm"), Some(0.7));
+    }
+
     #[test]
     fn monitor_slot_without_r0_is_skipped() {
         let monitor = TransitionMonitor::new(default_axioms());
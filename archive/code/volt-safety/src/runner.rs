@@ -0,0 +1,340 @@
+//! Reusable safety runner with an optional verdict cache.
+//!
+//! [`SafetyLayer::process`] is deterministic: frames with the same
+//! content always receive the same verdict. A [`SafetyRunner`] can
+//! therefore keep a bounded LRU cache keyed by
+//! [`TensorFrame::content_hash`] plus the slot and global certainties,
+//! skipping the pipeline for repeats.
+//!
+//! The cache is off by default, so a plain runner behaves exactly like
+//! calling the layer directly. Because the key ignores `frame_id`,
+//! `strand_id` and timestamps, a cache hit returns the first matching
+//! frame's result with the caller's identity fields (`frame_id`,
+//! `strand_id`, `created_at`) restored.
+//! Each cached entry holds a full [`TensorFrame`] (~65 KB), so keep
+//! the capacity modest.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use volt_core::{TensorFrame, VoltError};
+
+use crate::layer::{SafetyLayer, SafetyResult};
+
+/// Cache key for a frame: its content hash and every certainty.
+///
+/// The pipeline reads and rewrites certainties, so frames with the same
+/// content but different γ must not share a result.
+fn cache_key(frame: &TensorFrame) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    frame.content_hash().hash(&mut hasher);
+    frame.frame_meta.global_certainty.to_bits().hash(&mut hasher);
+    for meta in &frame.meta {
+        meta.certainty.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Bounded least-recently-used map from cache key to result.
+#[derive(Debug)]
+struct ResultCache {
+    capacity: usize,
+    entries: HashMap<u64, SafetyResult>,
+    /// Keys from least to most recently used.
+    order: VecDeque<u64>,
+}
+
+impl ResultCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn get(&mut self, key: u64) -> Option<&SafetyResult> {
+        if self.entries.contains_key(&key) {
+            self.touch(key);
+        }
+        self.entries.get(&key)
+    }
+
+    fn insert(&mut self, key: u64, result: SafetyResult) {
+        if self.entries.insert(key, result).is_some() {
+            self.touch(key);
+            return;
+        }
+        self.order.push_back(key);
+        while self.entries.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn touch(&mut self, key: u64) {
+        if let Some(pos) = self.order.iter().position(|&k| k == key) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(key);
+    }
+}
+
+/// Runs frames through a [`SafetyLayer`], optionally caching verdicts.
+///
+/// # Example
+///
+/// ```
+/// use volt_safety::runner::SafetyRunner;
+/// use volt_core::{TensorFrame, SlotData, SlotRole, SLOT_DIM};
+///
+/// std::thread::Builder::new().stack_size(8 * 1024 * 1024).spawn(|| {
+///     let mut runner = SafetyRunner::default().with_cache(16);
+///
+///     let mut frame = TensorFrame::new();
+///     let mut slot = SlotData::new(SlotRole::Agent);
+///     slot.write_resolution(0, [0.1; SLOT_DIM]);
+///     frame.write_slot(0, slot).unwrap();
+///
+///     let first = runner.process(&frame).unwrap();
+///     let second = runner.process(&frame).unwrap();
+///     assert_eq!(first.vetoed, second.vetoed);
+///     assert_eq!(runner.pipeline_runs(), 1);
+///     assert_eq!(runner.cache_hits(), 1);
+/// }).unwrap().join().unwrap();
+/// ```
+pub struct SafetyRunner {
+    layer: SafetyLayer,
+    cache: Option<ResultCache>,
+    pipeline_runs: u64,
+    cache_hits: u64,
+}
+
+impl std::fmt::Debug for SafetyRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SafetyRunner(cache={:?}, cached={}, runs={}, hits={})",
+            self.cache_capacity(),
+            self.cache_len(),
+            self.pipeline_runs,
+            self.cache_hits
+        )
+    }
+}
+
+impl SafetyRunner {
+    /// Creates a runner around `layer` with caching disabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_safety::runner::SafetyRunner;
+    /// use volt_safety::layer::SafetyLayer;
+    /// use volt_hard::default_pipeline;
+    ///
+    /// let runner = SafetyRunner::new(SafetyLayer::new(default_pipeline()));
+    /// assert_eq!(runner.cache_capacity(), None);
+    /// ```
+    pub fn new(layer: SafetyLayer) -> Self {
+        Self {
+            layer,
+            cache: None,
+            pipeline_runs: 0,
+            cache_hits: 0,
+        }
+    }
+
+    /// Enables an LRU verdict cache holding up to `capacity` results.
+    ///
+    /// A capacity of 0 disables caching.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_safety::runner::SafetyRunner;
+    ///
+    /// let runner = SafetyRunner::default().with_cache(64);
+    /// assert_eq!(runner.cache_capacity(), Some(64));
+    /// assert_eq!(SafetyRunner::default().with_cache(0).cache_capacity(), None);
+    /// ```
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.cache = (capacity > 0).then(|| ResultCache::new(capacity));
+        self
+    }
+
+    /// Process a frame as [`SafetyLayer::process`] does, serving repeats
+    /// of previously seen content from the cache when enabled.
+    ///
+    /// Errors are never cached.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`SafetyLayer::process`].
+    pub fn process(&mut self, frame: &TensorFrame) -> Result<SafetyResult, VoltError> {
+        let Some(cache) = self.cache.as_mut() else {
+            self.pipeline_runs += 1;
+            return self.layer.process(frame);
+        };
+
+        let key = cache_key(frame);
+        if let Some(cached) = cache.get(key) {
+            let mut result = cached.clone();
+            result.frame.frame_meta.frame_id = frame.frame_meta.frame_id;
+            result.frame.frame_meta.strand_id = frame.frame_meta.strand_id;
+            result.frame.frame_meta.created_at = frame.frame_meta.created_at;
+            self.cache_hits += 1;
+            return Ok(result);
+        }
+
+        self.pipeline_runs += 1;
+        let result = self.layer.process(frame)?;
+        cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    /// Number of times the underlying pipeline has run.
+    pub fn pipeline_runs(&self) -> u64 {
+        self.pipeline_runs
+    }
+
+    /// Number of calls answered from the cache.
+    pub fn cache_hits(&self) -> u64 {
+        self.cache_hits
+    }
+
+    /// Maximum cached results, or `None` if caching is disabled.
+    pub fn cache_capacity(&self) -> Option<usize> {
+        self.cache.as_ref().map(|c| c.capacity)
+    }
+
+    /// Number of results currently cached.
+    pub fn cache_len(&self) -> usize {
+        self.cache.as_ref().map_or(0, |c| c.entries.len())
+    }
+
+    /// Drops all cached results, keeping the cache enabled.
+    pub fn clear_cache(&mut self) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.entries.clear();
+            cache.order.clear();
+        }
+    }
+}
+
+impl Default for SafetyRunner {
+    /// A runner over the default pipeline and axioms, with caching disabled.
+    fn default() -> Self {
+        Self::new(SafetyLayer::new(volt_hard::default_pipeline()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use volt_core::{SlotData, SlotRole, SLOT_DIM};
+
+    fn frame_with(value: f32) -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        let mut slot = SlotData::new(SlotRole::Agent);
+        slot.write_resolution(0, [value; SLOT_DIM]);
+        frame.write_slot(0, slot).unwrap();
+        frame.meta[0].certainty = 0.8;
+        frame
+    }
+
+    fn on_big_stack(f: impl FnOnce() + Send + 'static) {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(f)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn cache_hit_skips_pipeline_and_keeps_verdict() {
+        on_big_stack(|| {
+            let mut runner = SafetyRunner::default().with_cache(4);
+            let mut frame = frame_with(0.1);
+            frame.frame_meta.frame_id = 1;
+            let first = runner.process(&frame).unwrap();
+
+            let mut repeat = frame.clone();
+            repeat.frame_meta.frame_id = 2;
+            repeat.frame_meta.created_at = 99;
+            let second = runner.process(&repeat).unwrap();
+
+            assert_eq!(runner.pipeline_runs(), 1);
+            assert_eq!(runner.cache_hits(), 1);
+            assert_eq!(second.vetoed, first.vetoed);
+            assert_eq!(second.pre_check_score, first.pre_check_score);
+            assert_eq!(second.post_check_score, first.post_check_score);
+            assert_eq!(second.frame.content_hash(), first.frame.content_hash());
+            assert_eq!(second.frame.frame_meta.frame_id, 2);
+            assert_eq!(second.frame.frame_meta.created_at, 99);
+        });
+    }
+
+    #[test]
+    fn frames_differing_only_in_certainty_are_not_shared() {
+        on_big_stack(|| {
+            let mut runner = SafetyRunner::default().with_cache(4);
+            let sure = frame_with(0.1);
+            let mut unsure = sure.clone();
+            unsure.meta[0].certainty = 0.2;
+            assert_eq!(sure.content_hash(), unsure.content_hash());
+
+            let first = runner.process(&sure).unwrap();
+            let second = runner.process(&unsure).unwrap();
+            assert_eq!(runner.pipeline_runs(), 2);
+            assert_eq!(runner.cache_hits(), 0);
+            assert_ne!(first.frame.meta[0].certainty, second.frame.meta[0].certainty);
+
+            let again = runner.process(&unsure).unwrap();
+            assert_eq!(runner.cache_hits(), 1);
+            assert_eq!(again.frame.meta[0].certainty, second.frame.meta[0].certainty);
+        });
+    }
+
+    #[test]
+    fn cache_is_disabled_by_default() {
+        on_big_stack(|| {
+            let mut runner = SafetyRunner::default();
+            let frame = frame_with(0.1);
+            runner.process(&frame).unwrap();
+            runner.process(&frame).unwrap();
+            assert_eq!(runner.pipeline_runs(), 2);
+            assert_eq!(runner.cache_hits(), 0);
+            assert_eq!(runner.cache_len(), 0);
+        });
+    }
+
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        on_big_stack(|| {
+            let mut runner = SafetyRunner::default().with_cache(2);
+            let (a, b, c) = (frame_with(0.1), frame_with(0.2), frame_with(0.3));
+
+            runner.process(&a).unwrap();
+            runner.process(&b).unwrap();
+            runner.process(&a).unwrap(); // hit; b is now least recent
+            runner.process(&c).unwrap(); // evicts b
+            assert_eq!(runner.cache_len(), 2);
+            assert_eq!(runner.pipeline_runs(), 3);
+
+            runner.process(&a).unwrap();
+            assert_eq!(runner.pipeline_runs(), 3);
+            runner.process(&b).unwrap();
+            assert_eq!(runner.pipeline_runs(), 4);
+
+            runner.clear_cache();
+            assert_eq!(runner.cache_len(), 0);
+            assert_eq!(runner.cache_capacity(), Some(2));
+        });
+    }
+}
@@ -49,6 +49,7 @@ use crate::scorer::{ScoredViolation, ScoringResult};
 /// let log = VetoLog {
 ///     trigger_frame: TensorFrame::new(),
 ///     violation_details: vec![],
+///     axioms: vec!["K1_harm"],
 ///     aggregate_score: 0.9,
 ///     safe_frame: TensorFrame::new(),
 /// };
@@ -62,6 +63,9 @@ pub struct VetoLog {
     /// Human-readable details of each violation that led to the veto.
     pub violation_details: Vec<String>,
 
+    /// Names of the axioms that fired, deduplicated in firing order.
+    pub axioms: Vec<&'static str>,
+
     /// The aggregate violation score.
     pub aggregate_score: f32,
 
@@ -194,10 +198,17 @@ impl OmegaVeto {
             .iter()
             .map(Self::format_violation)
             .collect();
+        let mut axioms: Vec<&'static str> = Vec::new();
+        for v in &scoring.violations {
+            if !axioms.contains(&v.axiom_name) {
+                axioms.push(v.axiom_name);
+            }
+        }
 
         let log = VetoLog {
             trigger_frame: trigger_frame.clone(),
             violation_details,
+            axioms,
             aggregate_score: scoring.aggregate_score,
             safe_frame: safe_frame.clone(),
         };
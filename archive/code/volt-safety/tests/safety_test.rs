@@ -370,6 +370,34 @@ fn k4_warning_does_not_trigger_veto() {
         .unwrap();
 }
 
+/// Threshold overrides cannot switch off the Omega Veto for K1.
+#[test]
+fn threshold_overrides_preserve_omega_veto() {
+    std::thread::Builder::new()
+        .stack_size(TEST_STACK)
+        .spawn(|| {
+            let axioms = default_axioms();
+            let k1_vector = axioms[0].vector;
+            let mut layer = SafetyLayer::new(default_pipeline());
+
+            // Relax K3 for an internal tool, tighten K4, try to disable K1
+            layer.set_threshold("K3_privacy", 0.8).unwrap();
+            layer.set_threshold("K4_autonomy", 0.5).unwrap();
+            assert!(layer.set_threshold("K1_harm", 1.0).is_err());
+
+            let mut frame = TensorFrame::new();
+            let mut slot = SlotData::new(SlotRole::Predicate);
+            slot.write_resolution(0, k1_vector);
+            frame.write_slot(1, slot).unwrap();
+
+            let result = layer.process(&frame).unwrap();
+            assert!(result.vetoed, "K1 must still trigger the Omega Veto");
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
 /// The `check()` method works without processing.
 #[test]
 fn check_without_processing() {
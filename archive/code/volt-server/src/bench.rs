@@ -0,0 +1,306 @@
+//! In-process benchmark harness behind `volt-server bench`.
+//!
+//! Drives canned inputs through the real [`routes::think`] handler on a
+//! real [`AppState`] — no HTTP, no mocks — and reports latency
+//! percentiles, throughput, and a per-phase timing breakdown. A warm-up
+//! pass runs first and is excluded from the measurements.
+//!
+//! Requests are issued by `concurrency` tokio tasks, each on its own
+//! conversation. The handler is CPU-bound, so effective parallelism is
+//! capped by the runtime's worker threads.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::State;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use volt_core::VoltError;
+
+use crate::models::{ThinkRequest, TimingMs};
+use crate::routes;
+use crate::state::AppState;
+
+/// Inputs cycled through by the benchmark, mixing plain sentences with
+/// one the math strand answers.
+pub const BENCH_INPUTS: &[&str] = &[
+    "The cat sat on the mat",
+    "dog ran home",
+    "rockets launch into orbit",
+    "10 + 5",
+    "quantum physics energy",
+    "hello world",
+];
+
+/// Settings for one benchmark run.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::bench::BenchConfig;
+///
+/// let config = BenchConfig::default();
+/// assert_eq!(config.concurrency, 4);
+/// assert!(config.max_p99_ms.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchConfig {
+    /// Measured requests.
+    pub requests: usize,
+    /// Concurrent request tasks.
+    pub concurrency: usize,
+    /// Unmeasured requests issued before timing starts.
+    pub warmup: usize,
+    /// p99 latency budget; [`BenchReport::within_budget`] checks it.
+    pub max_p99_ms: Option<f64>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            requests: 100,
+            concurrency: 4,
+            warmup: 10,
+            max_p99_ms: None,
+        }
+    }
+}
+
+/// End-to-end request latency summary, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    /// Median latency.
+    pub p50: f64,
+    /// 95th percentile latency.
+    pub p95: f64,
+    /// 99th percentile latency.
+    pub p99: f64,
+    /// Mean latency.
+    pub mean: f64,
+    /// Slowest request.
+    pub max: f64,
+}
+
+/// Mean time per request spent in each pipeline phase, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PhaseBreakdown {
+    /// Text → TensorFrame.
+    pub encode_ms: f64,
+    /// Everything between encode and decode: priming, routing, RAR,
+    /// verification, safety, and storage.
+    pub pipeline_ms: f64,
+    /// TensorFrame → text.
+    pub decode_ms: f64,
+}
+
+/// Results of a benchmark run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    /// Measured requests.
+    pub requests: usize,
+    /// Concurrent request tasks.
+    pub concurrency: usize,
+    /// Warm-up requests excluded from the measurements.
+    pub warmup: usize,
+    /// Wall-clock time for the measured requests.
+    pub wall_ms: f64,
+    /// Measured requests per second of wall-clock time.
+    pub throughput_rps: f64,
+    /// End-to-end latency as seen by the caller.
+    pub latency_ms: LatencySummary,
+    /// Per-phase means from each response's `timing_ms`.
+    pub phases_ms: PhaseBreakdown,
+}
+
+impl BenchReport {
+    /// Whether p99 latency is at most `max_p99_ms`.
+    pub fn within_budget(&self, max_p99_ms: f64) -> bool {
+        self.latency_ms.p99 <= max_p99_ms
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let l = &self.latency_ms;
+        let p = &self.phases_ms;
+        writeln!(
+            f,
+            "requests: {} (concurrency {}, warm-up {})",
+            self.requests, self.concurrency, self.warmup
+        )?;
+        writeln!(
+            f,
+            "throughput: {:.1} req/s over {:.1} ms",
+            self.throughput_rps, self.wall_ms
+        )?;
+        writeln!(
+            f,
+            "latency ms: p50 {:.3}  p95 {:.3}  p99 {:.3}  mean {:.3}  max {:.3}",
+            l.p50, l.p95, l.p99, l.mean, l.max
+        )?;
+        write!(
+            f,
+            "phases ms (mean): encode {:.3}  pipeline {:.3}  decode {:.3}",
+            p.encode_ms, p.pipeline_ms, p.decode_ms
+        )
+    }
+}
+
+/// One measured request.
+struct Sample {
+    latency_ms: f64,
+    timing: TimingMs,
+}
+
+/// Run the benchmark described by `config` against `state`.
+///
+/// Requests run on the current tokio runtime. Every request must
+/// succeed; the canned inputs are all valid, so a failure means the
+/// pipeline itself is broken.
+///
+/// # Errors
+///
+/// Returns [`VoltError::Internal`] if `config.requests` is zero, if a
+/// conversation cannot be created, or if any request fails.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::bench::{run_bench, BenchConfig};
+/// use volt_server::state::AppState;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let config = BenchConfig { requests: 3, concurrency: 1, warmup: 1, max_p99_ms: None };
+/// let report = run_bench(AppState::new(), &config).await.unwrap();
+/// assert_eq!(report.requests, 3);
+/// assert!(report.latency_ms.p50 <= report.latency_ms.p99);
+/// # });
+/// ```
+pub async fn run_bench(state: Arc<AppState>, config: &BenchConfig) -> Result<BenchReport, VoltError> {
+    if config.requests == 0 {
+        return Err(VoltError::Internal {
+            message: "benchmark needs at least one request".to_string(),
+        });
+    }
+    let concurrency = config.concurrency.clamp(1, config.requests);
+
+    // Plan the bind/unbind FFTs up front, as the server does at startup.
+    volt_bus::warm_up();
+
+    let conversations = (0..concurrency)
+        .map(|_| state.get_or_create_conversation(None))
+        .collect::<Result<Vec<u64>, VoltError>>()?;
+
+    for i in 0..config.warmup {
+        think_once(&state, conversations[i % concurrency], i).await?;
+    }
+
+    let next = Arc::new(AtomicUsize::new(0));
+    let requests = config.requests;
+    let start = Instant::now();
+    let workers: Vec<_> = conversations
+        .into_iter()
+        .map(|conversation_id| {
+            let state = Arc::clone(&state);
+            let next = Arc::clone(&next);
+            tokio::spawn(async move {
+                let mut samples = Vec::new();
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= requests {
+                        return Ok::<_, VoltError>(samples);
+                    }
+                    samples.push(think_once(&state, conversation_id, i).await?);
+                }
+            })
+        })
+        .collect();
+
+    let mut samples = Vec::with_capacity(requests);
+    for worker in workers {
+        let worker_samples = worker.await.map_err(|e| VoltError::Internal {
+            message: format!("benchmark worker panicked: {e}"),
+        })??;
+        samples.extend(worker_samples);
+    }
+    let wall_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok(summarize(config, concurrency, wall_ms, &samples))
+}
+
+/// Send `BENCH_INPUTS[i]` (cycled) through `/api/think` in-process.
+async fn think_once(state: &Arc<AppState>, conversation_id: u64, i: usize) -> Result<Sample, VoltError> {
+    let request = ThinkRequest {
+        text: BENCH_INPUTS[i % BENCH_INPUTS.len()].to_string(),
+        conversation_id: Some(conversation_id),
+        disabled_strands: None,
+        num_alternatives: None,
+        max_iterations: None,
+        include_proof_dot: false,
+        echo_encoded: None,
+        slot_alternatives: None,
+    };
+    let start = Instant::now();
+    let Json(response) = routes::think(State(Arc::clone(state)), Json(request))
+        .await
+        .map_err(|(status, Json(body))| VoltError::Internal {
+            message: format!("benchmark request {i} failed ({status}): {}", body.error),
+        })?;
+    Ok(Sample {
+        latency_ms: start.elapsed().as_secs_f64() * 1000.0,
+        timing: response.timing_ms,
+    })
+}
+
+fn summarize(config: &BenchConfig, concurrency: usize, wall_ms: f64, samples: &[Sample]) -> BenchReport {
+    let n = samples.len() as f64;
+    let mut latencies: Vec<f64> = samples.iter().map(|s| s.latency_ms).collect();
+    latencies.sort_by(f64::total_cmp);
+    let mean_of = |f: fn(&TimingMs) -> f64| samples.iter().map(|s| f(&s.timing)).sum::<f64>() / n;
+
+    BenchReport {
+        requests: samples.len(),
+        concurrency,
+        warmup: config.warmup,
+        wall_ms,
+        throughput_rps: if wall_ms > 0.0 { n * 1000.0 / wall_ms } else { 0.0 },
+        latency_ms: LatencySummary {
+            p50: percentile(&latencies, 50.0),
+            p95: percentile(&latencies, 95.0),
+            p99: percentile(&latencies, 99.0),
+            mean: latencies.iter().sum::<f64>() / n,
+            max: latencies.last().copied().unwrap_or(0.0),
+        },
+        phases_ms: PhaseBreakdown {
+            encode_ms: mean_of(|t| t.encode_ms),
+            pipeline_ms: mean_of(|t| (t.total_ms - t.encode_ms - t.decode_ms).max(0.0)),
+            decode_ms: mean_of(|t| t.decode_ms),
+        },
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted`; 0.0 when empty.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let sorted: Vec<f64> = (1..=100).map(f64::from).collect();
+        assert_eq!(percentile(&sorted, 50.0), 50.0);
+        assert_eq!(percentile(&sorted, 95.0), 95.0);
+        assert_eq!(percentile(&sorted, 99.0), 99.0);
+        assert_eq!(percentile(&[7.0], 99.0), 7.0);
+        assert_eq!(percentile(&[], 50.0), 0.0);
+    }
+}
@@ -50,6 +50,12 @@ impl ChatClient {
         let request = ThinkRequest {
             text: text.to_string(),
             conversation_id: self.conversation_id,
+            disabled_strands: None,
+            num_alternatives: None,
+            max_iterations: None,
+            include_proof_dot: false,
+            echo_encoded: None,
+            slot_alternatives: None,
         };
 
         let response = self.client.post(&url).json(&request).send()?;
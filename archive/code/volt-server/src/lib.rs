@@ -10,6 +10,7 @@
 //! - `GET /health` — health check
 //! - `POST /api/think` — process text through the translation pipeline
 //! - `GET /api/modules` — list installed modules
+//! - `GET /api/stats` — memory, conversation, and SSE queue statistics
 //!
 //! ## Architecture Rules
 //!
@@ -17,10 +18,12 @@
 //! - No other `volt-*` crate may depend on `volt-server`.
 //! - Network code also lives in `volt-ledger`.
 
+pub mod bench;
 pub mod models;
 pub mod registry;
 pub mod routes;
 pub mod state;
+pub mod stream;
 
 pub use volt_core;
 
@@ -81,15 +84,33 @@ pub fn build_app_with_state(state: Arc<AppState>) -> Router {
         .route("/health", get(routes::health))
         .route("/api/think", post(routes::think))
         .route("/api/think/stream", post(routes::think_stream))
+        .route("/api/think/replay/{frame_id}", post(routes::replay_frame))
         .route("/api/modules", get(routes::list_modules))
+        .route("/api/stats", get(routes::stats))
         .route(
             "/api/conversations",
             post(routes::create_conversation).get(routes::list_conversations),
         )
+        .route("/api/conversations/search", post(routes::search_conversations))
         .route(
             "/api/conversations/{id}/history",
             get(routes::get_conversation_history),
         )
+        .route(
+            "/api/conversations/{id}/context",
+            post(routes::set_conversation_context).delete(routes::clear_conversation_context),
+        )
+        .route(
+            "/api/conversations/{id}/regenerate",
+            post(routes::regenerate),
+        )
+        .route("/api/admin/maintenance", post(routes::set_maintenance))
+        .route("/api/admin/gc", post(routes::admin_gc))
+        .route("/api/admin/vetoes", get(routes::admin_vetoes))
+        .route(
+            "/api/admin/consolidate/{strand_id}",
+            post(routes::admin_consolidate),
+        )
         .nest_service("/static", ServeDir::new("crates/volt-server/static"))
         .route("/", get(|| async { Redirect::permanent("/static/index.html") }))
         .layer(CorsLayer::permissive())
@@ -11,12 +11,15 @@
 //! volt-server modules list       List installed modules
 //! volt-server modules install X  Show instructions to enable module X
 //! volt-server modules uninstall X Show instructions to disable module X
+//! volt-server bench [options]    Benchmark the in-process pipeline
 //! ```
 
 use std::sync::Arc;
 
 use volt_learn::rlvf::RlvfConfig;
 use volt_learn::sleep::{SleepConfig, SleepScheduler};
+use volt_ledger::AuditLog;
+use volt_server::bench::{run_bench, BenchConfig};
 use volt_server::registry::ModuleRegistry;
 use volt_server::state::AppState;
 
@@ -27,6 +30,7 @@ async fn main() {
     match args.get(1).map(|s| s.as_str()) {
         Some("modules") => handle_modules(&args[2..]),
         Some("serve") => start_server().await,
+        Some("bench") => handle_bench(&args[2..]).await,
         Some(other) => {
             eprintln!("Unknown command: {other}");
             eprintln!();
@@ -47,6 +51,71 @@ fn print_usage() {
     eprintln!("  volt-server modules list           List installed modules");
     eprintln!("  volt-server modules install <name> Show install instructions");
     eprintln!("  volt-server modules uninstall <name> Show uninstall instructions");
+    eprintln!("  volt-server bench [options]        Benchmark the in-process pipeline");
+    eprintln!("      --requests N      measured requests (default 100)");
+    eprintln!("      --concurrency C   concurrent request tasks (default 4)");
+    eprintln!("      --warmup W        unmeasured warm-up requests (default 10)");
+    eprintln!("      --max-p99-ms MS   exit with status 2 if p99 latency exceeds MS");
+}
+
+/// Handle `volt-server bench ...`: run the benchmark and print its report.
+///
+/// Exits with status 1 on bad arguments or a failed request, and 2 if
+/// `--max-p99-ms` is exceeded.
+async fn handle_bench(args: &[String]) {
+    let config = match parse_bench_args(args) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!();
+            print_usage();
+            std::process::exit(1);
+        }
+    };
+
+    let report = match run_bench(AppState::new(), &config).await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Benchmark failed: {e}");
+            std::process::exit(1);
+        }
+    };
+    println!("{report}");
+
+    if let Some(max) = config.max_p99_ms
+        && !report.within_budget(max)
+    {
+        eprintln!(
+            "p99 latency {:.3} ms exceeds the {max} ms budget",
+            report.latency_ms.p99
+        );
+        std::process::exit(2);
+    }
+}
+
+/// Parse `--flag value` pairs for `volt-server bench`.
+fn parse_bench_args(args: &[String]) -> Result<BenchConfig, String> {
+    fn value<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+        value
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(|| format!("{flag} needs a numeric value"))
+    }
+
+    let mut config = BenchConfig::default();
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--requests" => config.requests = value(flag, iter.next())?,
+            "--concurrency" => config.concurrency = value(flag, iter.next())?,
+            "--warmup" => config.warmup = value(flag, iter.next())?,
+            "--max-p99-ms" => config.max_p99_ms = Some(value(flag, iter.next())?),
+            other => return Err(format!("Unknown bench option: {other}")),
+        }
+    }
+    if config.requests == 0 || config.concurrency == 0 {
+        return Err("--requests and --concurrency must be at least 1".to_string());
+    }
+    Ok(config)
 }
 
 /// Handle `volt-server modules ...` subcommands.
@@ -64,6 +133,15 @@ fn handle_modules(args: &[String]) {
                 );
                 println!("    {}", m.description);
             }
+            let rejected = registry.rejected();
+            if !rejected.is_empty() {
+                println!();
+                println!("Rejected modules ({}):", rejected.len());
+                println!();
+                for r in rejected {
+                    println!("  {} v{}: {}", r.info.id, r.info.version, r.reason);
+                }
+            }
         }
         Some("install") => {
             if let Some(name) = args.get(1) {
@@ -120,8 +198,41 @@ async fn start_server() {
 
     tracing::info!("Starting Volt X server on 0.0.0.0:8080");
 
+    // Plan the bind/unbind FFTs before the first request needs them.
+    volt_bus::warm_up();
+
     // Create shared state so we can pass references to the sleep scheduler.
-    let state: Arc<AppState> = AppState::new();
+    let mut state: Arc<AppState> = AppState::new();
+
+    // Admin endpoints stay disabled unless a key is configured.
+    if let Ok(key) = std::env::var("VOLT_ADMIN_API_KEY")
+        && !key.is_empty()
+    {
+        Arc::get_mut(&mut state)
+            .expect("state is not shared yet")
+            .admin_api_key = Some(key);
+        tracing::info!("Admin API enabled");
+    }
+
+    // Vetoes are audited in memory unless a log file is configured.
+    if let Ok(path) = std::env::var("VOLT_VETO_AUDIT_LOG")
+        && !path.is_empty()
+    {
+        match AuditLog::open(std::path::Path::new(&path)) {
+            Ok(log) => {
+                tracing::info!("Veto audit log: {path} ({} entries)", log.len());
+                let state = Arc::get_mut(&mut state).expect("state is not shared yet");
+                state.veto_audit = Arc::new(std::sync::Mutex::new(log));
+            }
+            Err(e) => tracing::error!("Failed to open veto audit log, keeping it in memory: {e}"),
+        }
+    }
+    if std::env::var("VOLT_REDACT_VETO_INPUTS").is_ok_and(|v| v == "1" || v == "true") {
+        Arc::get_mut(&mut state)
+            .expect("state is not shared yet")
+            .redact_veto_inputs = true;
+        tracing::info!("Vetoed inputs are redacted in the audit log");
+    }
 
     tracing::info!(
         "Module registry: {} modules discovered",
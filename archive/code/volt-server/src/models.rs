@@ -20,8 +20,46 @@ pub struct ThinkRequest {
     /// Optional conversation ID. If None, a new conversation will be created.
     #[serde(default)]
     pub conversation_id: Option<u64>,
+    /// Hard strand names (e.g. `"math_engine"`) to leave out of routing
+    /// for this request only. Unknown names are ignored.
+    #[serde(default)]
+    pub disabled_strands: Option<Vec<String>>,
+    /// Number of alternative outputs to return (1 to [`MAX_ALTERNATIVES`]).
+    /// Values above 1 run extra noise-seeded RAR passes and fill
+    /// [`ThinkResponse::alternatives`]. Ignored by `/api/think/stream`.
+    #[serde(default)]
+    pub num_alternatives: Option<usize>,
+    /// Caps RAR iterations for this request, trading convergence for
+    /// latency. Clamped to `1..=`[`MAX_RAR_ITERATIONS`]; omitted uses the
+    /// default RAR budget.
+    #[serde(default)]
+    pub max_iterations: Option<u32>,
+    /// When `true`, the response carries the proof chain rendered as a
+    /// Graphviz DOT digraph in [`ThinkResponse::proof_dot`].
+    #[serde(default)]
+    pub include_proof_dot: bool,
+    /// When `Some(true)`, the response echoes the frame the translator
+    /// encoded, before priming, Hard Core, or RAR touched it, in
+    /// [`ThinkResponse::encoded_slots`]. Off by default.
+    #[serde(default)]
+    pub echo_encoded: Option<bool>,
+    /// When set, the response lists up to this many candidate words per
+    /// slot in [`ThinkResponse::slot_alternatives`]. Clamped to
+    /// `1..=`[`MAX_SLOT_ALTERNATIVES`]. Off by default, since ranking the
+    /// whole vocabulary per slot is expensive.
+    #[serde(default)]
+    pub slot_alternatives: Option<usize>,
 }
 
+/// Largest accepted [`ThinkRequest::num_alternatives`].
+pub const MAX_ALTERNATIVES: usize = 8;
+
+/// Upper bound applied to [`ThinkRequest::max_iterations`].
+pub const MAX_RAR_ITERATIONS: u32 = 200;
+
+/// Upper bound applied to [`ThinkRequest::slot_alternatives`].
+pub const MAX_SLOT_ALTERNATIVES: usize = 10;
+
 /// Response body for `POST /api/think`.
 ///
 /// # Example
@@ -49,10 +87,19 @@ pub struct ThinkRequest {
 ///         similarity: 1.0,
 ///         gamma_after: 0.8,
 ///         activated: true,
+///         op_code: "certainty_engine.min_rule".into(),
+///         inputs: vec![],
+///         outputs: vec![],
 ///     }],
 ///     safety_score: 0.0,
 ///     memory_frame_count: 1,
 ///     ghost_count: 0,
+///     ghost_influence: 0.0,
+///     alternatives: vec![],
+///     regenerated: None,
+///     proof_dot: None,
+///     encoded_slots: None,
+///     slot_alternatives: vec![],
 ///     timing_ms: TimingMs { encode_ms: 0.1, decode_ms: 0.05, total_ms: 0.15 },
 /// };
 /// let json = serde_json::to_string(&resp).unwrap();
@@ -80,10 +127,68 @@ pub struct ThinkResponse {
     pub memory_frame_count: usize,
     /// Number of ghost gists that influenced this RAR pass.
     pub ghost_count: usize,
+    /// How much the ghosts shaped the answer: their share of the RAR
+    /// attention messages, averaged over slots and iterations. In
+    /// `[0, 1]`; 0.0 when a Hard Strand answered without RAR.
+    #[serde(default)]
+    pub ghost_influence: f32,
+    /// Distinct candidate outputs, highest certainty first. Empty unless
+    /// the request asked for more than one alternative.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<AlternativeResponse>,
+    /// Set only on responses from `/api/conversations/:id/regenerate`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regenerated: Option<Box<RegenerationInfo>>,
+    /// The proof chain as a Graphviz DOT digraph. Set only when the
+    /// request asked for it with `include_proof_dot`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_dot: Option<String>,
+    /// Per-slot summary of the translator's encoded frame, before the
+    /// pipeline ran. Set only when the request asked for it with
+    /// `echo_encoded`; compare with `slot_states` to separate encoder
+    /// bugs from pipeline effects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoded_slots: Option<Vec<EncodedSlot>>,
+    /// Candidate decodes per slot, parallel to `slot_states`: up to `m`
+    /// `(word, similarity)` pairs from vocabulary cleanup, most similar
+    /// first, so the first is the slot's `word`. Slots not decoded by
+    /// cleanup (redacted, numeric results, no match) have none. Empty
+    /// unless the request set `slot_alternatives`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slot_alternatives: Vec<Vec<(String, f32)>>,
     /// Timing breakdown in milliseconds.
     pub timing_ms: TimingMs,
 }
 
+/// One candidate output from a `num_alternatives` request.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::AlternativeResponse;
+///
+/// let alt = AlternativeResponse {
+///     text: "cat sat mat.".into(),
+///     certainty: 0.8,
+///     seed: 0,
+///     iterations: 12,
+/// };
+/// let json = serde_json::to_string(&alt).unwrap();
+/// assert!(json.contains("\"seed\":0"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlternativeResponse {
+    /// The decoded output text.
+    pub text: String,
+    /// Global certainty (gamma) of the frame this output was decoded from.
+    pub certainty: f32,
+    /// Diffusion noise seed of the RAR pass; 0 is the noise-free pass
+    /// that also produced [`ThinkResponse::text`].
+    pub seed: u64,
+    /// RAR iterations used by this pass.
+    pub iterations: u32,
+}
+
 /// A single step from the Hard Core proof chain.
 ///
 /// # Example
@@ -97,6 +202,9 @@ pub struct ThinkResponse {
 ///     similarity: 0.95,
 ///     gamma_after: 0.8,
 ///     activated: true,
+///     op_code: "math_engine.add".into(),
+///     inputs: vec![6],
+///     outputs: vec![8],
 /// };
 /// let json = serde_json::to_string(&step).unwrap();
 /// assert!(json.contains("math_engine"));
@@ -113,6 +221,15 @@ pub struct ProofStepResponse {
     pub gamma_after: f32,
     /// Whether the strand actually activated and performed computation.
     pub activated: bool,
+    /// Machine-readable operation code (e.g. `"math_engine.mul"`).
+    #[serde(default)]
+    pub op_code: String,
+    /// Slot indices the operation read.
+    #[serde(default)]
+    pub inputs: Vec<usize>,
+    /// Slot indices the operation wrote.
+    #[serde(default)]
+    pub outputs: Vec<usize>,
 }
 
 /// Debug information for a single active slot in the TensorFrame.
@@ -149,6 +266,37 @@ pub struct SlotState {
     pub resolution_count: u32,
 }
 
+/// Summary of one slot of the encoder's output frame.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::EncodedSlot;
+///
+/// let slot = EncodedSlot {
+///     index: 0,
+///     role: "Agent".into(),
+///     word: "cat".into(),
+///     certainty: 0.8,
+///     r0_norm: 1.0,
+/// };
+/// let json = serde_json::to_string(&slot).unwrap();
+/// assert!(json.contains("r0_norm"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncodedSlot {
+    /// Slot index (0-15).
+    pub index: usize,
+    /// Semantic role the encoder assigned (e.g., "Agent", "Predicate").
+    pub role: String,
+    /// The word the slot's embedding decodes to.
+    pub word: String,
+    /// Per-slot certainty (gamma) set by the encoder.
+    pub certainty: f32,
+    /// L2 norm of the slot's R₀ vector; 0.0 if R₀ is empty.
+    pub r0_norm: f32,
+}
+
 /// Timing breakdown for a single think operation, in milliseconds.
 ///
 /// # Example
@@ -220,6 +368,10 @@ pub struct HealthResponse {
 ///     author: "Volt X Team".into(),
 ///     description: "Exact arithmetic.".into(),
 ///     module_type: "HardStrand".into(),
+///     min_core_version: "0.1.0".into(),
+///     requires: vec![],
+///     active: true,
+///     error: None,
 /// };
 /// let json = serde_json::to_string(&m).unwrap();
 /// assert!(json.contains("math_engine"));
@@ -238,6 +390,18 @@ pub struct ModuleResponse {
     pub description: String,
     /// Module type: "Translator", "HardStrand", or "ActionCore".
     pub module_type: String,
+    /// Oldest compatible `volt-core` version (empty if unconstrained).
+    #[serde(default)]
+    pub min_core_version: String,
+    /// IDs of modules this one depends on.
+    #[serde(default)]
+    pub requires: Vec<String>,
+    /// Whether the module passed its compatibility checks and is in use.
+    #[serde(default)]
+    pub active: bool,
+    /// Why the module was refused, if it is not active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 /// Metadata about a conversation.
@@ -309,6 +473,63 @@ pub struct ConversationListResponse {
     pub conversations: Vec<ConversationMeta>,
 }
 
+/// Request body for `POST /api/conversations/search`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::ConversationSearchRequest;
+///
+/// let req: ConversationSearchRequest =
+///     serde_json::from_str(r#"{"text": "cats", "k": 3}"#).unwrap();
+/// assert_eq!(req.k, Some(3));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationSearchRequest {
+    /// Topic to search for; encoded the same way as a think request.
+    pub text: String,
+    /// Maximum number of conversations to return (1 to
+    /// [`MAX_SEARCH_RESULTS`]). Defaults to [`DEFAULT_SEARCH_RESULTS`].
+    #[serde(default)]
+    pub k: Option<usize>,
+}
+
+/// Conversations returned by a search when `k` is omitted.
+pub const DEFAULT_SEARCH_RESULTS: usize = 10;
+
+/// Largest accepted [`ConversationSearchRequest::k`].
+pub const MAX_SEARCH_RESULTS: usize = 100;
+
+/// One conversation matched by `POST /api/conversations/search`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationSearchHit {
+    /// The matching conversation (VoltDB strand) ID.
+    pub conversation_id: u64,
+    /// Cosine distance of the conversation's closest frame to the query.
+    pub distance: f32,
+    /// Number of the conversation's frames among the search hits.
+    pub frame_count: usize,
+}
+
+/// Response body for `POST /api/conversations/search`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::{ConversationSearchHit, ConversationSearchResponse};
+///
+/// let resp = ConversationSearchResponse {
+///     results: vec![ConversationSearchHit { conversation_id: 7, distance: 0.1, frame_count: 2 }],
+/// };
+/// let json = serde_json::to_string(&resp).unwrap();
+/// assert!(json.contains("\"conversation_id\":7"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationSearchResponse {
+    /// Matching conversations, closest first, one row per conversation.
+    pub results: Vec<ConversationSearchHit>,
+}
+
 /// A single message in a conversation history.
 ///
 /// # Example
@@ -352,6 +573,7 @@ pub struct HistoryMessage {
 ///         gamma: vec![0.8],
 ///         timestamp: 1000,
 ///     }],
+///     context: None,
 /// };
 /// let json = serde_json::to_string(&resp).unwrap();
 /// assert!(json.contains("hello"));
@@ -362,6 +584,327 @@ pub struct ConversationHistoryResponse {
     pub conversation_id: u64,
     /// All messages in chronological order (oldest first).
     pub messages: Vec<HistoryMessage>,
+    /// The priming context, if one is set. Kept apart from `messages`
+    /// because it is not a turn in the conversation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<HistoryMessage>,
+}
+
+/// Request body for `POST /api/conversations/:id/context`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::SetContextRequest;
+///
+/// let json = r#"{"text": "we are talking about cats"}"#;
+/// let req: SetContextRequest = serde_json::from_str(json).unwrap();
+/// assert_eq!(req.text, "we are talking about cats");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetContextRequest {
+    /// The priming text to encode and attach to the conversation.
+    pub text: String,
+}
+
+/// Response body for `POST /api/conversations/:id/context`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::ContextResponse;
+///
+/// let resp = ContextResponse {
+///     conversation_id: 1,
+///     text: "we are talking about cats".into(),
+///     active_slots: 3,
+/// };
+/// let json = serde_json::to_string(&resp).unwrap();
+/// assert!(json.contains("active_slots"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextResponse {
+    /// The conversation the context is attached to.
+    pub conversation_id: u64,
+    /// The priming text as submitted.
+    pub text: String,
+    /// Number of active slots in the encoded priming frame.
+    pub active_slots: usize,
+}
+
+/// RAR effort preset for a regenerated turn.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::RarQuality;
+///
+/// let q: RarQuality = serde_json::from_str("\"thorough\"").unwrap();
+/// assert_eq!(q, RarQuality::Thorough);
+/// assert_eq!(RarQuality::default(), RarQuality::Standard);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RarQuality {
+    /// Fewer iterations and a looser convergence threshold.
+    Fast,
+    /// The settings `/api/think` uses.
+    #[default]
+    Standard,
+    /// More iterations and a tighter convergence threshold.
+    Thorough,
+}
+
+/// Request body for `POST /api/conversations/:id/regenerate`.
+///
+/// Both fields are optional; an empty body (`{}`) re-runs the last turn
+/// with the `/api/think` defaults.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::{RarQuality, RegenerateRequest};
+///
+/// let json = r#"{"seed": 3, "quality": "fast"}"#;
+/// let req: RegenerateRequest = serde_json::from_str(json).unwrap();
+/// assert_eq!(req.seed, Some(3));
+/// assert_eq!(req.quality, Some(RarQuality::Fast));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegenerateRequest {
+    /// Diffusion noise seed for RAR. 0 (the default) is the noise-free
+    /// pass `/api/think` runs; other values add seeded noise.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// RAR effort preset. Defaults to [`RarQuality::Standard`].
+    #[serde(default)]
+    pub quality: Option<RarQuality>,
+}
+
+/// Regeneration details attached to a [`ThinkResponse`].
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::RegenerationInfo;
+///
+/// let info = RegenerationInfo {
+///     input_text: "cat sat mat.".into(),
+///     frame_id: 12,
+///     supersedes: Some(11),
+///     seed: 0,
+/// };
+/// let json = serde_json::to_string(&info).unwrap();
+/// assert!(json.contains("\"supersedes\":11"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegenerationInfo {
+    /// The original input, recovered by decoding the stored input frame.
+    pub input_text: String,
+    /// Frame ID of the new response in VoltDB (0 if it was not stored).
+    pub frame_id: u64,
+    /// Frame ID of the response this one replaced, if it was stored.
+    pub supersedes: Option<u64>,
+    /// Diffusion seed used for the re-run.
+    pub seed: u64,
+}
+
+/// Request body for `POST /api/think/replay/:frame_id`.
+///
+/// Both fields are optional; an empty body (`{}`) replays the frame
+/// without storing the result.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::ReplayRequest;
+///
+/// let req: ReplayRequest = serde_json::from_str(r#"{"store": true}"#).unwrap();
+/// assert!(req.store);
+/// assert!(req.disabled_strands.is_none());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReplayRequest {
+    /// Store the replayed frame in the original frame's strand.
+    #[serde(default)]
+    pub store: bool,
+    /// Hard strand names to leave out of routing for this replay.
+    #[serde(default)]
+    pub disabled_strands: Option<Vec<String>>,
+}
+
+/// A decoded frame in a [`ReplayResponse`].
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::ReplayFrame;
+///
+/// let frame = ReplayFrame {
+///     text: "cat sat mat.".into(),
+///     gamma: vec![0.8, 0.8, 0.8],
+///     global_certainty: 0.8,
+/// };
+/// assert_eq!(frame.gamma.len(), 3);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayFrame {
+    /// Decoded text.
+    pub text: String,
+    /// Certainty of each active slot.
+    pub gamma: Vec<f32>,
+    /// Frame-level certainty.
+    pub global_certainty: f32,
+}
+
+/// Response body for `POST /api/think/replay/:frame_id`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::{ReplayFrame, ReplayResponse};
+///
+/// let frame = ReplayFrame { text: "cat sat mat.".into(), gamma: vec![0.8], global_certainty: 0.8 };
+/// let resp = ReplayResponse {
+///     frame_id: 7,
+///     original: frame.clone(),
+///     replay: frame,
+///     iterations: 12,
+///     proof_steps: vec![],
+///     safety_score: 0.0,
+///     stored_frame_id: None,
+///     total_ms: 4.2,
+/// };
+/// let json = serde_json::to_string(&resp).unwrap();
+/// assert!(json.contains("\"stored_frame_id\":null"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayResponse {
+    /// ID of the stored frame that was replayed.
+    pub frame_id: u64,
+    /// The stored frame as it was.
+    pub original: ReplayFrame,
+    /// The frame after re-running the pipeline on it.
+    pub replay: ReplayFrame,
+    /// RAR iterations used by the replay (0 if a Hard Strand handled it).
+    pub iterations: u32,
+    /// Proof chain of the replay.
+    pub proof_steps: Vec<ProofStepResponse>,
+    /// Pre-check safety score of the replay.
+    pub safety_score: f32,
+    /// Frame ID of the stored replay, if [`ReplayRequest::store`] was set.
+    pub stored_frame_id: Option<u64>,
+    /// Wall-clock time for the replay in milliseconds.
+    pub total_ms: f64,
+}
+
+/// Request body for `POST /api/admin/maintenance`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::MaintenanceRequest;
+///
+/// let req: MaintenanceRequest = serde_json::from_str(r#"{"enabled": true}"#).unwrap();
+/// assert!(req.enabled);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceRequest {
+    /// `true` to start rejecting writes, `false` to resume.
+    pub enabled: bool,
+}
+
+/// Response body for `POST /api/admin/maintenance`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::MaintenanceResponse;
+///
+/// let resp = MaintenanceResponse { maintenance: true, previous: false };
+/// let json = serde_json::to_string(&resp).unwrap();
+/// assert!(json.contains("\"maintenance\":true"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceResponse {
+    /// Maintenance mode after the request.
+    pub maintenance: bool,
+    /// Maintenance mode before the request.
+    pub previous: bool,
+}
+
+/// Query string for `GET /api/admin/vetoes`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::VetoListQuery;
+///
+/// let query: VetoListQuery = serde_json::from_str(r#"{"limit": 5}"#).unwrap();
+/// assert_eq!(query.limit, Some(5));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VetoListQuery {
+    /// Maximum number of vetoes to return (1 to [`MAX_VETO_RESULTS`]).
+    /// Defaults to [`DEFAULT_VETO_RESULTS`].
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Vetoes returned by `GET /api/admin/vetoes` when `limit` is omitted.
+pub const DEFAULT_VETO_RESULTS: usize = 50;
+
+/// Largest accepted [`VetoListQuery::limit`].
+pub const MAX_VETO_RESULTS: usize = 1000;
+
+/// One audited Omega Veto.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::VetoAuditEntry;
+///
+/// let entry = VetoAuditEntry {
+///     sequence: 0,
+///     timestamp_ms: 1_700_000_000_000,
+///     input: "[redacted]".to_string(),
+///     axioms: vec!["K1_harm".to_string()],
+///     conversation_id: Some(1),
+/// };
+/// let json = serde_json::to_string(&entry).unwrap();
+/// assert!(json.contains("K1_harm"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetoAuditEntry {
+    /// Position of the entry in the audit log.
+    pub sequence: u64,
+    /// When the veto was recorded, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    /// The vetoed input, or `"[redacted]"` if inputs are redacted.
+    pub input: String,
+    /// Names of the axioms that fired.
+    pub axioms: Vec<String>,
+    /// The conversation the input belonged to, if known.
+    pub conversation_id: Option<u64>,
+}
+
+/// Response body for `GET /api/admin/vetoes`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::VetoListResponse;
+///
+/// let resp = VetoListResponse { vetoes: vec![], total: 0 };
+/// let json = serde_json::to_string(&resp).unwrap();
+/// assert!(json.contains("\"total\":0"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetoListResponse {
+    /// The most recent vetoes, newest first.
+    pub vetoes: Vec<VetoAuditEntry>,
+    /// Number of vetoes in the audit log.
+    pub total: usize,
 }
 
 /// Server-Sent Event for streaming inference progress.
@@ -383,7 +926,64 @@ pub enum StreamEvent {
     /// RAR inference started
     Thinking,
     /// Processing completed
-    Complete(ThinkResponse),
+    Complete(Box<ThinkResponse>),
     /// Error occurred
     Error(String),
+    /// The client is reading slowly; this many progress events were dropped
+    Backpressure(usize),
+}
+
+/// Response body for `GET /api/stats`.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::models::StatsResponse;
+///
+/// let stats = StatsResponse {
+///     memory_frame_count: 12,
+///     conversation_count: 2,
+///     active_streams: 1,
+///     stream_queue_depth: 3,
+///     stream_peak_queue_depth: 7,
+///     stream_dropped_events: 0,
+///     stream_disconnects: 0,
+///     maintenance_mode: false,
+///     t0_evictions: 4,
+///     t1_to_t2_overflows: 0,
+///     store_operations: 12,
+/// };
+/// let json = serde_json::to_string(&stats).unwrap();
+/// assert!(json.contains("stream_queue_depth"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsResponse {
+    /// Total frames across all memory tiers.
+    pub memory_frame_count: usize,
+    /// Number of known conversations.
+    pub conversation_count: usize,
+    /// SSE streams whose client is still attached.
+    pub active_streams: usize,
+    /// Events buffered across all SSE streams, not yet read by clients.
+    pub stream_queue_depth: usize,
+    /// Highest total SSE queue depth observed since startup.
+    pub stream_peak_queue_depth: usize,
+    /// Progress events dropped because a client read too slowly.
+    pub stream_dropped_events: u64,
+    /// SSE streams aborted because the client disconnected.
+    pub stream_disconnects: u64,
+    /// Whether write endpoints are currently rejected for maintenance.
+    #[serde(default)]
+    pub maintenance_mode: bool,
+    /// Frames evicted from T0 working memory into T1 since startup.
+    ///
+    /// A count close to `store_operations` means T0 is undersized.
+    #[serde(default)]
+    pub t0_evictions: u64,
+    /// Frames overflowed from T1 into the T2 archive since startup.
+    #[serde(default)]
+    pub t1_to_t2_overflows: u64,
+    /// Frames stored into memory since startup.
+    #[serde(default)]
+    pub store_operations: u64,
 }
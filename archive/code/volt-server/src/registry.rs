@@ -12,8 +12,39 @@
 //!
 //! Built-in modules (MathEngine, HDCAlgebra, StubTranslator, TextAction)
 //! are always registered.
+//!
+//! ## Compatibility Checks
+//!
+//! A module activates only if [`CORE_VERSION`] meets its
+//! `min_core_version` and every module in its `requires` list is active.
+//! Modules that fail either check are kept as [`RejectedModule`]s with
+//! the reason, so `/api/modules` can show why they are missing.
+//!
+//! ## Capability Discovery
+//!
+//! Hard Strands carry the capability vector the Intent Router matches
+//! against. [`discover()`](ModuleRegistry::discover) copies those vectors
+//! from the default router, so
+//! [`find_by_capability`](ModuleRegistry::find_by_capability) can answer
+//! "which module handles this?" without knowing module names.
+
+use std::collections::HashMap;
+
+use volt_bus::similarity;
+use volt_core::module_info::{ModuleInfo, ModuleType, CORE_VERSION};
+use volt_core::{VoltError, SLOT_DIM};
+
+/// `min_core_version` declared by the built-in modules.
+const BUILTIN_MIN_CORE_VERSION: &str = "0.1.0";
 
-use volt_core::module_info::{ModuleInfo, ModuleType};
+/// A module that failed its compatibility checks and was not activated.
+#[derive(Debug, Clone)]
+pub struct RejectedModule {
+    /// The module's declared metadata.
+    pub info: ModuleInfo,
+    /// Why the module was refused.
+    pub reason: String,
+}
 
 /// Registry of all installed Volt modules.
 ///
@@ -31,6 +62,8 @@ use volt_core::module_info::{ModuleInfo, ModuleType};
 #[derive(Debug, Clone)]
 pub struct ModuleRegistry {
     modules: Vec<ModuleInfo>,
+    rejected: Vec<RejectedModule>,
+    capabilities: HashMap<String, Box<[f32; SLOT_DIM]>>,
 }
 
 impl ModuleRegistry {
@@ -61,6 +94,9 @@ impl ModuleRegistry {
                 description: "Exact arithmetic: add, sub, mul, div, pow, sqrt, abs, neg."
                     .to_string(),
                 module_type: ModuleType::HardStrand,
+                min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+                requires: vec![],
+                deterministic: true,
             },
             ModuleInfo {
                 id: "hdc_algebra".to_string(),
@@ -70,6 +106,9 @@ impl ModuleRegistry {
                 description: "Compositional reasoning via HDC bind/unbind/superpose/permute."
                     .to_string(),
                 module_type: ModuleType::HardStrand,
+                min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+                requires: vec![],
+                deterministic: true,
             },
             // Built-in Translator (always present)
             ModuleInfo {
@@ -80,6 +119,9 @@ impl ModuleRegistry {
                 description: "Heuristic word-to-slot mapping with deterministic hash vectors."
                     .to_string(),
                 module_type: ModuleType::Translator,
+                min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+                requires: vec![],
+                deterministic: true,
             },
             // Built-in ActionCore (always present)
             ModuleInfo {
@@ -89,6 +131,9 @@ impl ModuleRegistry {
                 author: "Volt X Team".to_string(),
                 description: "Default text output: decode TensorFrame slots to UTF-8.".to_string(),
                 module_type: ModuleType::ActionCore,
+                min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+                requires: vec![],
+                deterministic: true,
             },
         ];
 
@@ -101,6 +146,9 @@ impl ModuleRegistry {
             author: "Volt X Team".to_string(),
             description: "WASM-sandboxed code execution with fuel limits.".to_string(),
             module_type: ModuleType::HardStrand,
+            min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+            requires: vec![],
+            deterministic: true,
         });
 
         // WeatherStrand (behind weather feature)
@@ -112,6 +160,9 @@ impl ModuleRegistry {
             author: "Volt X Team".to_string(),
             description: "Example community module providing mock weather data.".to_string(),
             module_type: ModuleType::HardStrand,
+            min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+            requires: vec![],
+            deterministic: true,
         });
 
         // LLM Translator (behind volt-translate/llm feature)
@@ -124,9 +175,155 @@ impl ModuleRegistry {
             description: "Qwen3-0.6B backbone with projection head + VQ-VAE codebook."
                 .to_string(),
             module_type: ModuleType::Translator,
+            min_core_version: BUILTIN_MIN_CORE_VERSION.to_string(),
+            requires: vec![],
+            deterministic: true,
         });
 
-        Self { modules }
+        let mut registry = Self::from_modules(modules);
+        let router = volt_hard::default_router();
+        for name in router.strand_names() {
+            if let Some(vector) = router.capability_vector(name) {
+                registry.set_capability_vector(name, *vector);
+            }
+        }
+        registry
+    }
+
+    /// Build a registry from candidate modules, activating each one whose
+    /// core version and dependencies check out.
+    ///
+    /// Candidates may be listed in any order: a module whose dependency
+    /// appears later is activated once the dependency is. Modules left
+    /// over are recorded in [`rejected`](ModuleRegistry::rejected).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::registry::ModuleRegistry;
+    ///
+    /// let registry = ModuleRegistry::from_modules(Vec::new());
+    /// assert_eq!(registry.module_count(), 0);
+    /// ```
+    pub fn from_modules(candidates: Vec<ModuleInfo>) -> Self {
+        let mut registry = Self::empty();
+        let mut pending = candidates;
+        loop {
+            let before = pending.len();
+            pending.retain(|info| {
+                if registry.check(info).is_ok() {
+                    registry.modules.push(info.clone());
+                    false
+                } else {
+                    true
+                }
+            });
+            if pending.is_empty() || pending.len() == before {
+                break;
+            }
+        }
+        for info in pending {
+            // Records the rejection; the error is kept in `rejected`.
+            let _ = registry.register(info);
+        }
+        registry
+    }
+
+    /// A registry with no modules.
+    fn empty() -> Self {
+        Self {
+            modules: Vec::new(),
+            rejected: Vec::new(),
+            capabilities: HashMap::new(),
+        }
+    }
+
+    /// Activate one module after checking its core version and that
+    /// every module it requires is already active.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::ModuleError`] if the module is already
+    /// installed, needs a newer core, or requires a module that is not
+    /// active. The module is then recorded in
+    /// [`rejected`](ModuleRegistry::rejected).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_core::module_info::{ModuleInfo, ModuleType};
+    /// use volt_server::registry::ModuleRegistry;
+    ///
+    /// let mut registry = ModuleRegistry::discover();
+    /// let err = registry
+    ///     .register(ModuleInfo {
+    ///         id: "chart_action".to_string(),
+    ///         display_name: "Chart Action".to_string(),
+    ///         version: "0.1.0".to_string(),
+    ///         author: "Someone".to_string(),
+    ///         description: "Renders charts.".to_string(),
+    ///         module_type: ModuleType::ActionCore,
+    ///         min_core_version: "0.1.0".to_string(),
+    ///         requires: vec!["plot_strand".to_string()],
+    ///         deterministic: true,
+    ///     })
+    ///     .unwrap_err();
+    /// assert!(err.to_string().contains("plot_strand"));
+    /// assert!(!registry.is_installed("chart_action"));
+    /// ```
+    pub fn register(&mut self, info: ModuleInfo) -> Result<(), VoltError> {
+        match self.check(&info) {
+            Ok(()) => {
+                self.modules.push(info);
+                Ok(())
+            }
+            Err(e) => {
+                let reason = match &e {
+                    VoltError::ModuleError { message, .. } => message.clone(),
+                    other => other.to_string(),
+                };
+                self.rejected.push(RejectedModule { info, reason });
+                Err(e)
+            }
+        }
+    }
+
+    /// Check `info` against the core version and the active modules.
+    fn check(&self, info: &ModuleInfo) -> Result<(), VoltError> {
+        if self.is_installed(&info.id) {
+            return Err(VoltError::ModuleError {
+                name: info.id.clone(),
+                message: "a module with this ID is already installed".to_string(),
+            });
+        }
+        info.check_core_version(CORE_VERSION)?;
+        let missing: Vec<&str> = info
+            .requires
+            .iter()
+            .filter(|dep| !self.is_installed(dep))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            return Err(VoltError::ModuleError {
+                name: info.id.clone(),
+                message: format!("missing required module(s): {}", missing.join(", ")),
+            });
+        }
+        Ok(())
+    }
+
+    /// Modules that failed their compatibility checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::registry::ModuleRegistry;
+    ///
+    /// let registry = ModuleRegistry::discover();
+    /// assert!(registry.rejected().is_empty()); // built-ins are compatible
+    /// ```
+    pub fn rejected(&self) -> &[RejectedModule] {
+        &self.rejected
     }
 
     /// Total number of registered modules.
@@ -191,6 +388,77 @@ impl ModuleRegistry {
             .filter(|m| m.module_type == module_type)
             .collect()
     }
+
+    /// Record the capability vector of the installed module `module_id`.
+    ///
+    /// Ignored if no such module is installed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::registry::ModuleRegistry;
+    ///
+    /// let mut registry = ModuleRegistry::discover();
+    /// registry.set_capability_vector("text_action", [0.1; 256]);
+    /// assert!(registry.capability_vector("text_action").is_some());
+    /// registry.set_capability_vector("nonexistent", [0.1; 256]);
+    /// assert!(registry.capability_vector("nonexistent").is_none());
+    /// ```
+    pub fn set_capability_vector(&mut self, module_id: &str, vector: [f32; SLOT_DIM]) {
+        if self.is_installed(module_id) {
+            self.capabilities
+                .insert(module_id.to_string(), Box::new(vector));
+        }
+    }
+
+    /// The capability vector of `module_id`, if it has one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::registry::ModuleRegistry;
+    ///
+    /// let registry = ModuleRegistry::discover();
+    /// assert!(registry.capability_vector("math_engine").is_some());
+    /// assert!(registry.capability_vector("stub_translator").is_none());
+    /// ```
+    pub fn capability_vector(&self, module_id: &str) -> Option<&[f32; SLOT_DIM]> {
+        self.capabilities.get(module_id).map(|v| &**v)
+    }
+
+    /// Find modules whose capability vector matches `query_gist`.
+    ///
+    /// Returns `(module, cosine similarity)` pairs at or above
+    /// `threshold`, most similar first. Modules without a capability
+    /// vector are never returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::registry::ModuleRegistry;
+    ///
+    /// let registry = ModuleRegistry::discover();
+    /// let query = *registry.capability_vector("math_engine").unwrap();
+    /// let found = registry.find_by_capability(&query, 0.5);
+    /// assert_eq!(found[0].0.id, "math_engine");
+    /// ```
+    pub fn find_by_capability(
+        &self,
+        query_gist: &[f32; SLOT_DIM],
+        threshold: f32,
+    ) -> Vec<(ModuleInfo, f32)> {
+        let mut found: Vec<(ModuleInfo, f32)> = self
+            .modules
+            .iter()
+            .filter_map(|m| {
+                let vector = self.capabilities.get(&m.id)?;
+                let sim = similarity(vector, query_gist);
+                (sim >= threshold).then(|| (m.clone(), sim))
+            })
+            .collect();
+        found.sort_by(|a, b| b.1.total_cmp(&a.1));
+        found
+    }
 }
 
 impl Default for ModuleRegistry {
@@ -243,6 +511,125 @@ mod tests {
         assert!(translators.iter().any(|m| m.id == "stub_translator"));
     }
 
+    fn module(id: &str, min_core_version: &str, requires: &[&str]) -> ModuleInfo {
+        ModuleInfo {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            version: "0.1.0".to_string(),
+            author: "Test".to_string(),
+            description: "Test module.".to_string(),
+            module_type: ModuleType::HardStrand,
+            min_core_version: min_core_version.to_string(),
+            requires: requires.iter().map(|d| d.to_string()).collect(),
+            deterministic: true,
+        }
+    }
+
+    #[test]
+    fn built_in_modules_are_compatible() {
+        let registry = ModuleRegistry::discover();
+        assert!(registry.rejected().is_empty(), "{:?}", registry.rejected());
+        for m in registry.list_modules() {
+            assert!(m.check_core_version(CORE_VERSION).is_ok(), "{}", m.id);
+        }
+    }
+
+    #[test]
+    fn register_with_satisfied_dependency() {
+        let mut registry = ModuleRegistry::discover();
+        registry
+            .register(module("geometry", "0.1.0", &["math_engine"]))
+            .unwrap();
+        assert!(registry.is_installed("geometry"));
+        assert!(registry.rejected().is_empty());
+    }
+
+    #[test]
+    fn register_with_unsatisfied_dependency() {
+        let mut registry = ModuleRegistry::discover();
+        let err = registry
+            .register(module("chart", "0.1.0", &["math_engine", "plotter"]))
+            .unwrap_err();
+        assert!(err.to_string().contains("missing required module(s): plotter"), "{err}");
+        assert!(!registry.is_installed("chart"));
+        assert_eq!(registry.rejected().len(), 1);
+        assert_eq!(registry.rejected()[0].info.id, "chart");
+    }
+
+    #[test]
+    fn register_rejects_newer_core_requirement() {
+        let mut registry = ModuleRegistry::discover();
+        assert!(registry.register(module("future", "999.0.0", &[])).is_err());
+        assert!(registry.rejected()[0].reason.contains("requires volt-core >= 999.0.0"));
+    }
+
+    #[test]
+    fn from_modules_resolves_dependencies_in_any_order() {
+        let registry = ModuleRegistry::from_modules(vec![
+            module("c", "", &["b"]),
+            module("b", "", &["a"]),
+            module("a", "", &[]),
+            module("orphan", "", &["nowhere"]),
+            module("needs_orphan", "", &["orphan"]),
+        ]);
+        assert!(registry.is_installed("a"));
+        assert!(registry.is_installed("b"));
+        assert!(registry.is_installed("c"));
+        let rejected: Vec<&str> = registry.rejected().iter().map(|r| r.info.id.as_str()).collect();
+        assert_eq!(rejected, vec!["orphan", "needs_orphan"]);
+    }
+
+    /// Deterministic unit vector standing in for an encoded query.
+    fn query_vector(seed: u64) -> [f32; SLOT_DIM] {
+        let mut v = [0.0_f32; SLOT_DIM];
+        let mut h = seed;
+        for x in &mut v {
+            h = h.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            *x = ((h >> 40) as f32 / (1u64 << 24) as f32) * 2.0 - 1.0;
+        }
+        let norm: f32 = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        v.map(|x| x / norm)
+    }
+
+    #[test]
+    fn find_by_capability_ranks_math_engine_for_math_query() {
+        let registry = ModuleRegistry::discover();
+        let math = registry.capability_vector("math_engine").unwrap();
+        let greeting = query_vector(0x0048_454c_4c4f);
+        let mut math_query = [0.0_f32; SLOT_DIM];
+        for i in 0..SLOT_DIM {
+            math_query[i] = 0.9 * math[i] + 0.1 * greeting[i];
+        }
+
+        let found = registry.find_by_capability(&math_query, 0.5);
+        assert_eq!(found[0].0.id, "math_engine");
+        let math_sim = found[0].1;
+
+        let greeting_found = registry.find_by_capability(&greeting, -1.0);
+        let greeting_sim = greeting_found
+            .iter()
+            .find(|(m, _)| m.id == "math_engine")
+            .unwrap()
+            .1;
+        assert!(math_sim > greeting_sim, "{math_sim} vs {greeting_sim}");
+        assert!(
+            !registry
+                .find_by_capability(&greeting, 0.5)
+                .iter()
+                .any(|(m, _)| m.id == "math_engine")
+        );
+    }
+
+    #[test]
+    fn find_by_capability_skips_modules_without_vector_and_sorts() {
+        let registry = ModuleRegistry::discover();
+        let found = registry.find_by_capability(&query_vector(7), -1.0);
+        assert!(!found.is_empty());
+        assert!(found.iter().all(|(m, _)| registry.capability_vector(&m.id).is_some()));
+        assert!(!found.iter().any(|(m, _)| m.id == "stub_translator"));
+        assert!(found.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
     #[test]
     fn list_by_type_action_core() {
         let registry = ModuleRegistry::discover();
@@ -1,31 +1,44 @@
 //! Axum route handlers for the HTTP API.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Instant;
 
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::body::{Body, Bytes};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::sse::{Event, Sse};
-use axum::response::IntoResponse;
+use axum::response::{IntoResponse, Response};
 use axum::Json;
-use futures::stream::Stream;
-use tokio::sync::mpsc;
-use tokio_stream::wrappers::ReceiverStream;
+use futures::stream::{self, Stream, StreamExt};
 
 use volt_bus::similarity_frames;
+use volt_core::meta::DiscourseType;
 use volt_core::slot::SlotSource;
 use volt_core::{SlotRole, VoltError, SLOT_DIM, MAX_SLOTS};
+use volt_db::compressed::FrameEntry;
+use volt_db::{extract_gist, ConsolidationResult, GcResult};
+use volt_hard::proof_constructor::{ProofChain, ProofStep};
+use volt_ledger::AuditEvent;
 use volt_soft::attention::SlotAttention;
+use volt_soft::diffusion::DiffusionConfig;
 use volt_soft::rar::{rar_loop_with_ghosts, GhostConfig, RarConfig};
+use volt_soft::vfn::Vfn;
 use volt_translate::decode::format_output;
 use volt_translate::Translator;
 
 use crate::models::{
-    ConversationHistoryResponse, ConversationListResponse, CreateConversationResponse,
-    ErrorResponse, HealthResponse, HistoryMessage, ModuleResponse, ProofStepResponse, SlotState,
-    StreamEvent, ThinkRequest, ThinkResponse, TimingMs,
+    AlternativeResponse, ContextResponse, ConversationListResponse,
+    ConversationSearchHit, ConversationSearchRequest, ConversationSearchResponse,
+    CreateConversationResponse, EncodedSlot, ErrorResponse, HealthResponse, HistoryMessage,
+    MaintenanceRequest, MaintenanceResponse, ModuleResponse, ProofStepResponse, RarQuality, RegenerateRequest, RegenerationInfo, ReplayFrame, ReplayRequest, ReplayResponse, SetContextRequest,
+    SlotState, StatsResponse, StreamEvent, ThinkRequest, ThinkResponse, TimingMs,
+    VetoAuditEntry, VetoListQuery, VetoListResponse, DEFAULT_VETO_RESULTS, MAX_VETO_RESULTS,
+    DEFAULT_SEARCH_RESULTS, MAX_ALTERNATIVES, MAX_RAR_ITERATIONS, MAX_SEARCH_RESULTS,
+    MAX_SLOT_ALTERNATIVES,
 };
 use crate::state::AppState;
+use crate::stream::stream_channel;
 
 /// `GET /health` — health check endpoint.
 ///
@@ -56,6 +69,86 @@ struct PipelineOutput {
     safety_score: f32,
     /// Number of ghost gists that influenced RAR.
     ghost_count: usize,
+    /// Ghost share of the RAR attention messages (0.0 without RAR).
+    ghost_influence: f32,
+    /// Extra noise-seeded RAR results for `num_alternatives` requests.
+    alternatives: Vec<AlternativeFrame>,
+}
+
+/// A verified frame from one noise-seeded RAR pass.
+struct AlternativeFrame {
+    frame: Box<volt_core::TensorFrame>,
+    seed: u64,
+    iterations: u32,
+}
+
+/// Diffusion sigma for the noise-seeded passes behind `num_alternatives`.
+const ALTERNATIVE_NOISE_SIGMA: f32 = 0.05;
+
+/// Clear every resolution of `frame` except `resolution`, the one RAR
+/// refined, so the frame decodes to where that pass converged instead of
+/// falling back to the translator's own words at other resolutions.
+fn keep_refined_resolution(frame: &mut volt_core::TensorFrame, resolution: usize) {
+    for slot in frame.slots.iter_mut().flatten() {
+        for (r, data) in slot.resolutions.iter_mut().enumerate() {
+            if r != resolution {
+                *data = None;
+            }
+        }
+    }
+}
+
+/// Run one RAR pass per seed in `seeds`, in parallel, each with
+/// `base_config` plus diffusion noise from that seed, and verify each result through the
+/// safety-wrapped Hard Core.
+///
+/// Each returned frame keeps only the resolution RAR refined (see
+/// [`keep_refined_resolution`]).
+///
+/// Passes that fail or are vetoed are dropped rather than failing the
+/// request: the noise-free answer is already in hand.
+fn rar_alternatives(
+    frame: &volt_core::TensorFrame,
+    vfn: &Vfn,
+    attention: &SlotAttention,
+    ghost_config: &GhostConfig,
+    disabled_strands: &[String],
+    base_config: &RarConfig,
+    seeds: std::ops::Range<u64>,
+) -> Vec<AlternativeFrame> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = seeds
+            .filter_map(|seed| {
+                std::thread::Builder::new()
+                    .stack_size(8 * 1024 * 1024)
+                    .spawn_scoped(scope, move || {
+                        let config = RarConfig {
+                            diffusion: Some(DiffusionConfig::uniform(ALTERNATIVE_NOISE_SIGMA, seed)),
+                            ..base_config.clone()
+                        };
+                        let rar_result =
+                            rar_loop_with_ghosts(frame, vfn, attention, &config, ghost_config).ok()?;
+                        let verified = volt_safety::safe_process_full_excluding(
+                            &rar_result.frame,
+                            disabled_strands.to_vec(),
+                        )
+                        .ok()?;
+                        let mut frame = Box::new(verified.frame);
+                        keep_refined_resolution(&mut frame, config.resolution);
+                        Some(AlternativeFrame {
+                            frame,
+                            seed,
+                            iterations: rar_result.iterations,
+                        })
+                    })
+                    .ok()
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().ok().flatten())
+            .collect()
+    })
 }
 
 /// `POST /api/think` — process text through the full pipeline.
@@ -67,16 +160,47 @@ struct PipelineOutput {
 /// safety-wrapped Hard Core pipeline, verifies frame integrity via
 /// the Bus, then decodes back to text.
 ///
+/// With `num_alternatives > 1` and no Hard Strand match, extra RAR
+/// passes with distinct diffusion seeds run concurrently with the main
+/// pass; their distinct decodes are returned in `alternatives`.
+///
+/// `max_iterations` caps the RAR budget for this request (including any
+/// alternative passes), clamped to `1..=MAX_RAR_ITERATIONS`.
+///
+/// `echo_encoded: true` adds `encoded_slots`, a summary of the frame
+/// exactly as the translator produced it, before conversation priming.
+///
+/// `slot_alternatives: m` adds up to `m` ranked candidate words per slot
+/// in `slot_alternatives`, with `m` clamped to
+/// `1..=MAX_SLOT_ALTERNATIVES`.
+///
 /// # Errors
 ///
-/// - 400 Bad Request: empty text, input too large
+/// - 400 Bad Request: empty text, input too large, `num_alternatives`
+///   outside `1..=MAX_ALTERNATIVES`, encoded frame fails
+///   [`TensorFrame::validate`](volt_core::TensorFrame::validate)
 /// - 422 Unprocessable Entity: invalid JSON (handled by Axum)
-/// - 403 Forbidden: safety violation (Omega Veto triggered)
+/// - 403 Forbidden: safety violation (Omega Veto triggered); the veto is
+///   recorded in [`AppState::veto_audit`]
+/// - 503 Service Unavailable: the server is in maintenance mode
 pub async fn think(
     State(state): State<Arc<AppState>>,
     Json(request): Json<ThinkRequest>,
 ) -> Result<Json<ThinkResponse>, (StatusCode, Json<ErrorResponse>)> {
     let total_start = Instant::now();
+    ensure_writable(&state)?;
+
+    let num_alternatives = request.num_alternatives.unwrap_or(1);
+    if num_alternatives == 0 || num_alternatives > MAX_ALTERNATIVES {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: format!(
+                    "num_alternatives must be between 1 and {MAX_ALTERNATIVES}, got {num_alternatives}"
+                ),
+            }),
+        ));
+    }
 
     // Get or create conversation
     let conversation_id = state
@@ -107,171 +231,162 @@ pub async fn think(
         )
     })?;
 
-    // Encode: text -> TensorFrame
+    // Encode: text -> TensorFrame, rejecting malformed frames before RAR
     let encode_start = Instant::now();
-    let output = state.translator.encode(&request.text).map_err(|e| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: e.to_string(),
-            }),
-        )
-    })?;
+    let output = state
+        .translator
+        .encode(&request.text)
+        .and_then(|output| output.frame.validate().map(|()| output))
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+        })?;
     let encode_ms = encode_start.elapsed().as_secs_f64() * 1000.0;
+    let encoded_slots = if request.echo_encoded.unwrap_or(false) {
+        Some(encoded_slot_summaries(&state, &output.frame)?)
+    } else {
+        None
+    };
 
-    // Fetch ghost gists from memory before entering the pipeline thread.
-    // Read lock is cheap — many concurrent readers allowed.
-    let ghost_gists: Vec<[f32; SLOT_DIM]> = state
-        .memory
-        .read()
-        .map(|guard| guard.ghost_gists())
-        .unwrap_or_default();
-    let ghost_count = ghost_gists.len();
+    let options = TurnOptions {
+        num_alternatives,
+        disabled_strands: request.disabled_strands.unwrap_or_default(),
+        rar_config: rar_config_capped(request.max_iterations),
+        slot_alternatives: request
+            .slot_alternatives
+            .map_or(0, |m| m.clamp(1, MAX_SLOT_ALTERNATIVES)),
+    };
+    let (mut response, _) = run_turn(
+        &state,
+        conversation_id,
+        output.frame,
+        None,
+        encode_ms,
+        total_start,
+        options,
+    )
+    .inspect_err(|error| audit_if_vetoed(&state, error, &request.text, Some(conversation_id)))?;
+    if request.include_proof_dot {
+        response.proof_dot = Some(proof_steps_dot(&response.proof_steps));
+    }
+    response.encoded_slots = encoded_slots;
+    Ok(Json(response))
+}
 
-    // Snapshot the shared VFN for this request. Clone is ~6 MB (three
-    // Linear layers) but avoids holding the read lock during the entire
-    // RAR loop, so the sleep scheduler can still write-lock for training.
-    let vfn_snapshot = state.vfn.read().map_err(|e| {
+/// Whether a Hard Strand handled `result`, so RAR can be skipped.
+///
+/// Certainty propagation is recorded as an activated step on every
+/// frame, so it never counts as a Hard Strand answer.
+fn hard_strand_activated(result: &volt_safety::SafetyResult) -> bool {
+    result.proof.as_ref().is_some_and(|chain| {
+        chain
+            .steps
+            .iter()
+            .any(|step| step.activated && step.strand_name != "certainty_engine")
+    })
+}
+
+/// Record `error` in [`AppState::veto_audit`] if it is an Omega Veto
+/// (403 from the pipeline) of `input`.
+fn audit_if_vetoed(
+    state: &AppState,
+    error: &(StatusCode, Json<ErrorResponse>),
+    input: &str,
+    conversation_id: Option<u64>,
+) {
+    let (status, body) = error;
+    if *status == StatusCode::FORBIDDEN {
+        let axioms = volt_safety::vetoed_axioms(&body.error);
+        state.record_veto(input, axioms, conversation_id);
+    }
+}
+
+/// Summarize each active slot of the translator's output `frame`.
+fn encoded_slot_summaries(
+    state: &AppState,
+    frame: &volt_core::TensorFrame,
+) -> Result<Vec<EncodedSlot>, (StatusCode, Json<ErrorResponse>)> {
+    let slot_words = state.translator.decode_slots(frame).map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
-                error: format!("VFN read lock failed: {e}"),
+                error: format!("decode failed: {e}"),
             }),
         )
-    })?.clone();
-
-    // Run the full CPU-heavy pipeline on a thread with adequate stack.
-    // TensorFrame is ~65KB and the pipeline creates multiple copies,
-    // so we need more than the default async executor thread stack.
-    let pipeline_frame = Box::new(output.frame.clone());
-    let pipeline_output = std::thread::Builder::new()
-        .stack_size(8 * 1024 * 1024)
-        .spawn(move || -> Result<PipelineOutput, (StatusCode, String)> {
-            // Soft Core: RAR inference loop with ghost frame cross-attention.
-            // Uses the shared VFN (snapshot) so trained weights from sleep
-            // consolidation carry through to inference.
-            let attention = SlotAttention::new_random(43);
-            // CRITICAL: Route on the ORIGINAL encoded frame BEFORE RAR!
-            // RAR modifies all slots, which destroys capability tags used for routing.
-            // If a Hard Strand activates, use its result directly.
-            // Otherwise, fall back to RAR refinement.
-            let safety_result_original =
-                volt_safety::safe_process_full(&pipeline_frame).map_err(|e| match &e {
-                    VoltError::SafetyViolation { .. } => {
-                        (StatusCode::FORBIDDEN, format!("safety violation: {e}"))
-                    }
-                    _ => (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        format!("hard core pipeline failed: {e}"),
-                    ),
-                })?;
-
-            // Check if any Hard Strand activated
-            let hard_strand_activated = safety_result_original
-                .proof
+    })?;
+    Ok(slot_words
+        .into_iter()
+        .map(|(index, role, word)| EncodedSlot {
+            index,
+            role: format_role(&role),
+            word,
+            certainty: frame.meta[index].certainty,
+            r0_norm: frame.slots[index]
                 .as_ref()
-                .map(|chain| chain.steps.iter().any(|step| step.activated))
-                .unwrap_or(false);
-
-            let (safety_result, iterations) = if hard_strand_activated {
-                // Hard Strand handled it — use that result directly (no RAR needed)
-                (safety_result_original, 0)
-            } else {
-                // No Hard Strand match — run through Soft Core RAR for refinement
-                let config = RarConfig::default();
-                let ghost_config = GhostConfig { gists: ghost_gists, alpha: 0.1 };
-                let rar_result = rar_loop_with_ghosts(
-                    &pipeline_frame,
-                    &vfn_snapshot,
-                    &attention,
-                    &config,
-                    &ghost_config,
-                )
-                .map_err(|e| {
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        format!("soft core RAR failed: {e}"),
-                    )
-                })?;
-                let iterations = rar_result.iterations;
-
-                // Route the refined frame through Hard Core again
-                let safety_result_refined =
-                    volt_safety::safe_process_full(&rar_result.frame).map_err(|e| match &e {
-                        VoltError::SafetyViolation { .. } => {
-                            (StatusCode::FORBIDDEN, format!("safety violation: {e}"))
-                        }
-                        _ => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            format!("hard core pipeline failed: {e}"),
-                        ),
-                    })?;
-                (safety_result_refined, iterations)
-            };
+                .and_then(|slot| slot.resolutions[0].as_ref())
+                .map_or(0.0, |r0| r0.iter().map(|x| x * x).sum::<f32>().sqrt()),
+        })
+        .collect())
+}
 
-            // Bus integrity check
-            let _bus_similarity = similarity_frames(&pipeline_frame, &safety_result.frame);
+/// Pipeline settings for one turn, shared by `/api/think` and
+/// `/api/conversations/:id/regenerate`.
+struct TurnOptions {
+    num_alternatives: usize,
+    disabled_strands: Vec<String>,
+    rar_config: RarConfig,
+    /// Candidate words to rank per slot; 0 skips the ranking.
+    slot_alternatives: usize,
+}
 
-            // Extract proof steps
-            let proof_steps: Vec<ProofStepResponse> = safety_result
-                .proof
-                .map(|chain| {
-                    chain
-                        .steps
-                        .into_iter()
-                        .map(|step| ProofStepResponse {
-                            strand_name: step.strand_name,
-                            description: step.description,
-                            similarity: step.similarity,
-                            gamma_after: step.gamma_after,
-                            activated: step.activated,
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
+/// Run one turn of conversation `conversation_id` on an encoded input:
+/// prime, route, refine, verify, store, and decode.
+///
+/// The active strand must already be switched to the conversation. The
+/// unprimed `input` is stored alongside the response (see
+/// [`AppState::store_turn`]) unless `input_id` names an input stored by
+/// an earlier turn. Returns the response and the stored response's frame
+/// ID (0 if the store dropped it).
+fn run_turn(
+    state: &AppState,
+    conversation_id: u64,
+    input: volt_core::TensorFrame,
+    input_id: Option<u64>,
+    encode_ms: f64,
+    total_start: Instant,
+    options: TurnOptions,
+) -> Result<(ThinkResponse, u64), (StatusCode, Json<ErrorResponse>)> {
+    // Superpose the conversation's priming context, if any, into the
+    // encoded frame. Its gist also leads the ghosts for RAR attention.
+    let mut encoded_frame = input.clone();
+    let context_gist = state.prime_frame(conversation_id, &mut encoded_frame);
 
-            Ok(PipelineOutput {
-                frame: Box::new(safety_result.frame),
-                iterations,
-                proof_steps,
-                safety_score: safety_result.pre_check_score,
-                ghost_count,
-            })
-        })
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to spawn pipeline thread: {e}"),
-                }),
-            )
-        })?
-        .join()
-        .map_err(|_| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: "pipeline thread panicked".to_string(),
-                }),
-            )
-        })?
-        .map_err(|(status, msg)| (status, Json(ErrorResponse { error: msg })))?;
+    // Fetch ghost gists from memory before entering the pipeline thread.
+    // Read lock is cheap — many concurrent readers allowed.
+    let mut ghost_gists: Vec<[f32; SLOT_DIM]> = state
+        .memory
+        .read()
+        .map(|guard| guard.ghost_gists())
+        .unwrap_or_default();
+    if let Some(gist) = context_gist {
+        ghost_gists.insert(0, gist);
+    }
+    let num_alternatives = options.num_alternatives;
+    let slot_alternatives = options.slot_alternatives;
+    let pipeline_output = run_pipeline(state, encoded_frame, ghost_gists, options)?;
 
     let verified_frame = pipeline_output.frame;
 
-    // Store verified frame to memory (T0 working memory, auto-evicts to T1).
+    // Store the turn to memory (T0 working memory, auto-evicts to T1).
     // This feeds the HNSW index and refreshes the Ghost Bleed Buffer
     // so future requests benefit from memory of past conversations.
-    let memory_frame_count = {
-        let mut guard = state.memory.write().map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("memory store lock failed: {e}"),
-                }),
-            )
-        })?;
-        guard.store(*verified_frame.clone()).map_err(|e| {
+    let (frame_id, memory_frame_count) = state
+        .store_turn(&input, input_id, *verified_frame.clone())
+        .map_err(|e| {
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
@@ -279,8 +394,6 @@ pub async fn think(
                 }),
             )
         })?;
-        guard.total_frame_count()
-    };
 
     // Log learning event (best-effort — never fail the request).
     {
@@ -331,6 +444,52 @@ pub async fn think(
             )
         })?;
     let decoded_text = format_output(&slot_words);
+    let slot_alternatives = if slot_alternatives > 0 {
+        state
+            .translator
+            .decode_slot_candidates(&verified_frame, slot_alternatives)
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("decode failed: {e}"),
+                    }),
+                )
+            })?
+            .into_iter()
+            .map(|(_, candidates)| candidates)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Rank the noise-free answer and any alternatives by certainty,
+    // collapsing identical decodes onto their most certain pass.
+    let alternatives = if num_alternatives > 1 {
+        let mut ranked = vec![AlternativeResponse {
+            text: decoded_text.clone(),
+            certainty: verified_frame.frame_meta.global_certainty,
+            seed: 0,
+            iterations: pipeline_output.iterations,
+        }];
+        for alternative in &pipeline_output.alternatives {
+            if let Ok(words) = state.translator.decode_slots(&alternative.frame) {
+                ranked.push(AlternativeResponse {
+                    text: format_output(&words),
+                    certainty: alternative.frame.frame_meta.global_certainty,
+                    seed: alternative.seed,
+                    iterations: alternative.iterations,
+                });
+            }
+        }
+        ranked.sort_by(|a, b| b.certainty.total_cmp(&a.certainty).then(a.seed.cmp(&b.seed)));
+        let mut seen = std::collections::HashSet::new();
+        ranked.retain(|alt| seen.insert(alt.text.clone()));
+        ranked.truncate(num_alternatives);
+        ranked
+    } else {
+        Vec::new()
+    };
     let decode_ms = decode_start.elapsed().as_secs_f64() * 1000.0;
 
     let total_ms = total_start.elapsed().as_secs_f64() * 1000.0;
@@ -357,7 +516,7 @@ pub async fn think(
     // Update conversation metadata
     state.update_conversation_metadata(conversation_id);
 
-    Ok(Json(ThinkResponse {
+    let response = ThinkResponse {
         text: decoded_text,
         gamma,
         conversation_id,
@@ -368,65 +527,251 @@ pub async fn think(
         safety_score: pipeline_output.safety_score,
         memory_frame_count,
         ghost_count: pipeline_output.ghost_count,
+        ghost_influence: pipeline_output.ghost_influence,
+        alternatives,
+        regenerated: None,
+        proof_dot: None,
+        encoded_slots: None,
+        slot_alternatives,
         timing_ms: TimingMs {
             encode_ms,
             decode_ms,
             total_ms,
         },
-    }))
+    };
+    Ok((response, frame_id))
 }
 
-/// `POST /api/think/stream` — process text with SSE streaming.
-///
-/// Same as `/api/think` but streams progress updates via Server-Sent Events.
-/// Sends real-time updates during encoding, RAR inference, and completion.
-///
-/// # Event Types
-///
-/// - `status` - Status message (e.g., "Encoding...")
-/// - `encoding` - Encoding phase started
-/// - `thinking` - RAR inference started
-/// - `complete` - Processing completed (includes full ThinkResponse)
-/// - `error` - Error occurred
-pub async fn think_stream(
-    State(state): State<Arc<AppState>>,
-    Json(request): Json<ThinkRequest>,
-) -> Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>> {
-    let (tx, rx) = mpsc::channel(100);
-
-    // Clone state components for the async task
-    let state_clone = state.clone();
-    let request_clone = request.clone();
+/// Run the CPU-heavy part of a turn on `frame`: Hard Core routing, Soft
+/// Core RAR refinement with `ghost_gists` when no Hard Strand activates,
+/// and safety verification. Nothing is stored.
+fn run_pipeline(
+    state: &AppState,
+    frame: volt_core::TensorFrame,
+    ghost_gists: Vec<[f32; SLOT_DIM]>,
+    options: TurnOptions,
+) -> Result<PipelineOutput, (StatusCode, Json<ErrorResponse>)> {
+    let ghost_count = ghost_gists.len();
 
-    tokio::spawn(async move {
-        tracing::info!("Starting streaming request");
+    // Snapshot the shared VFN for this request. Clone is ~6 MB (three
+    // Linear layers) but avoids holding the read lock during the entire
+    // RAR loop, so the sleep scheduler can still write-lock for training.
+    let vfn_snapshot = state.vfn.read().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("VFN read lock failed: {e}"),
+            }),
+        )
+    })?.clone();
 
-        // Helper to send events (ignore errors if client disconnected)
-        let send = |event: StreamEvent| {
-            let tx = tx.clone();
-            async move {
-                let event_str = serde_json::to_string(&event).unwrap_or_default();
-                if tx.send(Ok(Event::default().data(event_str))).await.is_err() {
-                    tracing::warn!("Client disconnected during streaming");
-                }
-            }
-        };
+    // Run the full CPU-heavy pipeline on a thread with adequate stack.
+    // TensorFrame is ~65KB and the pipeline creates multiple copies,
+    // so we need more than the default async executor thread stack.
+    let pipeline_frame = Box::new(frame);
+    let TurnOptions {
+        num_alternatives,
+        disabled_strands,
+        rar_config,
+        ..
+    } = options;
+    std::thread::Builder::new()
+        .stack_size(8 * 1024 * 1024)
+        .spawn(move || -> Result<PipelineOutput, (StatusCode, String)> {
+            // Soft Core: RAR inference loop with ghost frame cross-attention.
+            // Uses the shared VFN (snapshot) so trained weights from sleep
+            // consolidation carry through to inference.
+            let attention = SlotAttention::new_random(43);
+            // CRITICAL: Route on the ORIGINAL encoded frame BEFORE RAR!
+            // RAR modifies all slots, which destroys capability tags used for routing.
+            // If a Hard Strand activates, use its result directly.
+            // Otherwise, fall back to RAR refinement.
+            let safety_result_original =
+                volt_safety::safe_process_full_excluding(&pipeline_frame, disabled_strands.clone())
+                    .map_err(|e| match &e {
+                        VoltError::SafetyViolation { .. } => {
+                            (StatusCode::FORBIDDEN, format!("safety violation: {e}"))
+                        }
+                        _ => (
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            format!("hard core pipeline failed: {e}"),
+                        ),
+                    })?;
 
-        let total_start = Instant::now();
+            let hard_strand_activated = hard_strand_activated(&safety_result_original);
 
-        // Get or create conversation
-        send(StreamEvent::Status("Preparing conversation...".to_string())).await;
-        tracing::info!("Creating/getting conversation");
-        let conversation_id = match state_clone
-            .get_or_create_conversation(request_clone.conversation_id)
-        {
-            Ok(id) => {
-                tracing::info!("Conversation ID: {}", id);
-                id
+            let (safety_result, iterations, ghost_influence, alternatives) = if hard_strand_activated {
+                // Hard Strand handled it — use that result directly (no RAR needed)
+                (safety_result_original, 0, 0.0, Vec::new())
+            } else {
+                // No Hard Strand match — run through Soft Core RAR for refinement
+                let ghost_config = GhostConfig { gists: ghost_gists, alpha: 0.1 };
+                // Seed 0 is the noise-free pass on this thread; the
+                // remaining seeds run alongside it as alternatives.
+                let (rar_result, alternatives) = std::thread::scope(|scope| {
+                    let alternatives = scope.spawn(|| {
+                        rar_alternatives(
+                            &pipeline_frame,
+                            &vfn_snapshot,
+                            &attention,
+                            &ghost_config,
+                            &disabled_strands,
+                            &rar_config,
+                            1..num_alternatives as u64,
+                        )
+                    });
+                    let rar_result = rar_loop_with_ghosts(
+                        &pipeline_frame,
+                        &vfn_snapshot,
+                        &attention,
+                        &rar_config,
+                        &ghost_config,
+                    );
+                    (rar_result, alternatives.join().unwrap_or_default())
+                });
+                let rar_result = rar_result.map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("soft core RAR failed: {e}"),
+                    )
+                })?;
+                let iterations = rar_result.iterations;
+                let ghost_influence = rar_result.ghost_influence;
+
+                // Route the refined frame through Hard Core again
+                let mut safety_result_refined =
+                    volt_safety::safe_process_full_excluding(&rar_result.frame, disabled_strands)
+                        .map_err(|e| match &e {
+                            VoltError::SafetyViolation { .. } => {
+                                (StatusCode::FORBIDDEN, format!("safety violation: {e}"))
+                            }
+                            _ => (
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                                format!("hard core pipeline failed: {e}"),
+                            ),
+                        })?;
+                // A seeded pass answers from where its noise led, like
+                // the alternatives do.
+                if rar_config.diffusion.is_some() {
+                    keep_refined_resolution(&mut safety_result_refined.frame, rar_config.resolution);
+                }
+                (safety_result_refined, iterations, ghost_influence, alternatives)
+            };
+
+            // Bus integrity check
+            let _bus_similarity = similarity_frames(&pipeline_frame, &safety_result.frame);
+
+            // Extract proof steps
+            let proof_steps: Vec<ProofStepResponse> = safety_result
+                .proof
+                .map(|chain| {
+                    chain
+                        .steps
+                        .into_iter()
+                        .map(|step| ProofStepResponse {
+                            strand_name: step.strand_name,
+                            description: step.description,
+                            similarity: step.similarity,
+                            gamma_after: step.gamma_after,
+                            activated: step.activated,
+                            op_code: step.op_code,
+                            inputs: step.inputs,
+                            outputs: step.outputs,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Ok(PipelineOutput {
+                frame: Box::new(safety_result.frame),
+                iterations,
+                proof_steps,
+                safety_score: safety_result.pre_check_score,
+                ghost_count,
+                ghost_influence,
+                alternatives,
+            })
+        })
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("failed to spawn pipeline thread: {e}"),
+                }),
+            )
+        })?
+        .join()
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "pipeline thread panicked".to_string(),
+                }),
+            )
+        })?
+        .map_err(|(status, msg)| (status, Json(ErrorResponse { error: msg })))
+}
+
+/// `POST /api/think/stream` — process text with SSE streaming.
+///
+/// Same as `/api/think` but streams progress updates via Server-Sent Events.
+/// Sends real-time updates during encoding, RAR inference, and completion.
+///
+/// # Event Types
+///
+/// - `status` - Status message (e.g., "Encoding...")
+/// - `encoding` - Encoding phase started
+/// - `thinking` - RAR inference started
+/// - `complete` - Processing completed (includes full ThinkResponse)
+/// - `error` - Error occurred
+/// - `backpressure` - The client is reading slowly; carries the number of
+///   progress events dropped since the last hint
+///
+/// Progress events (`status`, `encoding`, `thinking`) are dropped rather
+/// than queued when the client falls behind; `complete` and `error`
+/// always wait for room. If the client disconnects, the stream is
+/// aborted and the frame is not stored.
+///
+/// # Errors
+///
+/// - 503 Service Unavailable: the server is in maintenance mode (returned
+///   before the stream opens)
+pub async fn think_stream(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<ThinkRequest>,
+) -> Result<
+    Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>>,
+    (StatusCode, Json<ErrorResponse>),
+> {
+    ensure_writable(&state)?;
+
+    let (mut sender, receiver) =
+        stream_channel(state.stream_capacity, state.stream_stats.clone());
+
+    // Clone state components for the async task
+    let state_clone = state.clone();
+    let request_clone = request.clone();
+
+    tokio::spawn(async move {
+        tracing::info!("Starting streaming request");
+
+        let total_start = Instant::now();
+
+        // Get or create conversation
+        if !sender.progress(StreamEvent::Status("Preparing conversation...".to_string())) {
+            return;
+        }
+        tracing::info!("Creating/getting conversation");
+        let conversation_id = match state_clone
+            .get_or_create_conversation(request_clone.conversation_id)
+        {
+            Ok(id) => {
+                tracing::info!("Conversation ID: {}", id);
+                id
             }
             Err(e) => {
                 tracing::error!("Conversation creation failed: {}", e);
-                send(StreamEvent::Error(format!("conversation creation failed: {e}"))).await;
+                sender.finish(StreamEvent::Error(format!("conversation creation failed: {e}"))).await;
                 return;
             }
         };
@@ -437,12 +782,14 @@ pub async fn think_stream(
             .write()
             .and_then(|mut guard| guard.switch_strand(conversation_id))
         {
-            send(StreamEvent::Error(format!("strand switch failed: {e}"))).await;
+            sender.finish(StreamEvent::Error(format!("strand switch failed: {e}"))).await;
             return;
         }
 
         // Encode
-        send(StreamEvent::Encoding).await;
+        if !sender.progress(StreamEvent::Encoding) {
+            return;
+        }
         tracing::info!("Starting encoding: {:?}", request_clone.text);
         let encode_start = Instant::now();
         let output = match state_clone.translator.encode(&request_clone.text) {
@@ -452,18 +799,26 @@ pub async fn think_stream(
             }
             Err(e) => {
                 tracing::error!("Encoding failed: {}", e);
-                send(StreamEvent::Error(format!("encode failed: {e}"))).await;
+                sender.finish(StreamEvent::Error(format!("encode failed: {e}"))).await;
                 return;
             }
         };
         let encode_ms = encode_start.elapsed().as_secs_f64() * 1000.0;
 
-        // Fetch ghost gists
-        let ghost_gists: Vec<[f32; SLOT_DIM]> = state_clone
+        // Superpose the conversation's priming context, if any
+        let input_frame = output.frame;
+        let mut encoded_frame = input_frame.clone();
+        let context_gist = state_clone.prime_frame(conversation_id, &mut encoded_frame);
+
+        // Fetch ghost gists, led by the conversation's priming gist
+        let mut ghost_gists: Vec<[f32; SLOT_DIM]> = state_clone
             .memory
             .read()
             .map(|guard| guard.ghost_gists())
             .unwrap_or_default();
+        if let Some(gist) = context_gist {
+            ghost_gists.insert(0, gist);
+        }
         let ghost_count = ghost_gists.len();
 
         // Snapshot VFN (must extract before any await)
@@ -473,37 +828,37 @@ pub async fn think_stream(
         let vfn_snapshot = match vfn_result {
             Ok(vfn) => vfn,
             Err(e) => {
-                send(StreamEvent::Error(e)).await;
+                sender.finish(StreamEvent::Error(e)).await;
                 return;
             }
         };
 
         // Run pipeline
-        send(StreamEvent::Thinking).await;
+        if !sender.progress(StreamEvent::Thinking) {
+            return;
+        }
         tracing::info!("Starting RAR pipeline");
-        let pipeline_frame = Box::new(output.frame.clone());
-        let pipeline_output = match std::thread::Builder::new()
+        let pipeline_frame = Box::new(encoded_frame);
+        let disabled_strands = request_clone.disabled_strands.clone().unwrap_or_default();
+        let rar_config = rar_config_capped(request_clone.max_iterations);
+        let pipeline_thread = match std::thread::Builder::new()
             .stack_size(8 * 1024 * 1024)
             .spawn(move || -> Result<PipelineOutput, String> {
                 // CRITICAL: Route on the ORIGINAL encoded frame BEFORE RAR!
                 // RAR modifies all slots, which destroys capability tags used for routing.
-                let safety_result_original = volt_safety::safe_process_full(&pipeline_frame)
-                    .map_err(|e| format!("hard core pipeline failed: {e}"))?;
+                let safety_result_original =
+                    volt_safety::safe_process_full_excluding(&pipeline_frame, disabled_strands.clone())
+                        .map_err(|e| format!("hard core pipeline failed: {e}"))?;
 
-                // Check if any Hard Strand activated
-                let hard_strand_activated = safety_result_original
-                    .proof
-                    .as_ref()
-                    .map(|chain| chain.steps.iter().any(|step| step.activated))
-                    .unwrap_or(false);
+                let hard_strand_activated = hard_strand_activated(&safety_result_original);
 
-                let (safety_result, iterations) = if hard_strand_activated {
+                let (safety_result, iterations, ghost_influence) = if hard_strand_activated {
                     // Hard Strand handled it — use result directly (no RAR needed)
-                    (safety_result_original, 0)
+                    (safety_result_original, 0, 0.0)
                 } else {
                     // No Hard Strand match — run through Soft Core RAR for refinement
                     let attention = SlotAttention::new_random(43);
-                    let config = RarConfig::default();
+                    let config = rar_config;
                     let ghost_config = GhostConfig {
                         gists: ghost_gists,
                         alpha: 0.1,
@@ -512,10 +867,12 @@ pub async fn think_stream(
                         rar_loop_with_ghosts(&pipeline_frame, &vfn_snapshot, &attention, &config, &ghost_config)
                             .map_err(|e| format!("soft core RAR failed: {e}"))?;
                     let iterations = rar_result.iterations;
+                    let ghost_influence = rar_result.ghost_influence;
 
-                    let safety_result_refined = volt_safety::safe_process_full(&rar_result.frame)
-                        .map_err(|e| format!("hard core pipeline failed: {e}"))?;
-                    (safety_result_refined, iterations)
+                    let safety_result_refined =
+                        volt_safety::safe_process_full_excluding(&rar_result.frame, disabled_strands)
+                            .map_err(|e| format!("hard core pipeline failed: {e}"))?;
+                    (safety_result_refined, iterations, ghost_influence)
                 };
 
                 let _bus_similarity =
@@ -533,6 +890,9 @@ pub async fn think_stream(
                                 similarity: step.similarity,
                                 gamma_after: step.gamma_after,
                                 activated: step.activated,
+                                op_code: step.op_code,
+                                inputs: step.inputs,
+                                outputs: step.outputs,
                             })
                             .collect()
                     })
@@ -544,17 +904,40 @@ pub async fn think_stream(
                     proof_steps,
                     safety_score: safety_result.pre_check_score,
                     ghost_count,
+                ghost_influence,
+                    alternatives: Vec::new(),
                 })
             })
-            .and_then(|handle| handle.join().map_err(|_| std::io::Error::other("thread panicked")))
         {
-            Ok(Ok(output)) => output,
-            Ok(Err(e)) => {
-                send(StreamEvent::Error(format!("pipeline failed: {e}"))).await;
+            Ok(handle) => handle,
+            Err(e) => {
+                sender.finish(StreamEvent::Error(format!("thread spawn failed: {e}"))).await;
                 return;
             }
-            Err(e) => {
-                send(StreamEvent::Error(format!("thread spawn failed: {e}"))).await;
+        };
+
+        // Wait for the pipeline off the executor, but stop as soon as the
+        // client disconnects. The pipeline thread then runs to completion
+        // on its own and its result is dropped; nothing is stored.
+        let joined = tokio::select! {
+            joined = tokio::task::spawn_blocking(move || pipeline_thread.join()) => joined,
+            () = sender.closed() => {
+                sender.disconnected();
+                return;
+            }
+        };
+        let pipeline_output = match joined {
+            Ok(Ok(Ok(output))) => output,
+            Ok(Ok(Err(e))) => {
+                let axioms = volt_safety::vetoed_axioms(&e);
+                if !axioms.is_empty() {
+                    state_clone.record_veto(&request_clone.text, axioms, Some(conversation_id));
+                }
+                sender.finish(StreamEvent::Error(format!("pipeline failed: {e}"))).await;
+                return;
+            }
+            Ok(Err(_)) | Err(_) => {
+                sender.finish(StreamEvent::Error("pipeline thread panicked".to_string())).await;
                 return;
             }
         };
@@ -562,20 +945,14 @@ pub async fn think_stream(
         let verified_frame = pipeline_output.frame;
 
         // Store to memory
-        let memory_frame_count = match state_clone
-            .memory
-            .write()
-            .and_then(|mut guard| {
-                guard.store(*verified_frame.clone())?;
-                Ok(guard.total_frame_count())
-            })
-        {
-            Ok(count) => count,
-            Err(e) => {
-                send(StreamEvent::Error(format!("memory store failed: {e}"))).await;
-                return;
-            }
-        };
+        let memory_frame_count =
+            match state_clone.store_turn(&input_frame, None, *verified_frame.clone()) {
+                Ok((_, count)) => count,
+                Err(e) => {
+                    sender.finish(StreamEvent::Error(format!("memory store failed: {e}"))).await;
+                    return;
+                }
+            };
 
         // Log learning event
         {
@@ -617,7 +994,7 @@ pub async fn think_stream(
         let slot_words = match state_clone.translator.decode_slots(&verified_frame) {
             Ok(words) => words,
             Err(e) => {
-                send(StreamEvent::Error(format!("decode failed: {e}"))).await;
+                sender.finish(StreamEvent::Error(format!("decode failed: {e}"))).await;
                 return;
             }
         };
@@ -650,7 +1027,7 @@ pub async fn think_stream(
 
         // Send completion event
         tracing::info!("Sending completion event");
-        send(StreamEvent::Complete(ThinkResponse {
+        sender.finish(StreamEvent::Complete(Box::new(ThinkResponse {
             text: decoded_text,
             gamma,
             conversation_id,
@@ -661,47 +1038,110 @@ pub async fn think_stream(
             safety_score: pipeline_output.safety_score,
             memory_frame_count,
             ghost_count: pipeline_output.ghost_count,
+            ghost_influence: pipeline_output.ghost_influence,
+            alternatives: Vec::new(),
+            regenerated: None,
+            proof_dot: None,
+            encoded_slots: None,
+            slot_alternatives: Vec::new(),
             timing_ms: TimingMs {
                 encode_ms,
                 decode_ms,
                 total_ms,
             },
-        }))
+        })))
         .await;
 
         tracing::info!("Streaming request completed successfully");
     });
 
-    Sse::new(ReceiverStream::new(rx))
+    Ok(Sse::new(receiver))
+}
+
+/// `GET /api/stats` — server statistics.
+///
+/// Reports memory and conversation counts alongside SSE stream health:
+/// how many streams are attached, how many events are buffered waiting
+/// for slow clients, and how many were dropped or aborted. The memory
+/// tier counters come from [`volt_db::VoltStore::store_metrics`].
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///   "memory_frame_count": 12, "conversation_count": 2,
+///   "active_streams": 1, "stream_queue_depth": 3, "stream_peak_queue_depth": 7,
+///   "stream_dropped_events": 0, "stream_disconnects": 0,
+///   "maintenance_mode": false,
+///   "t0_evictions": 4, "t1_to_t2_overflows": 0, "store_operations": 12
+/// }
+/// ```
+pub async fn stats(State(state): State<Arc<AppState>>) -> Json<StatsResponse> {
+    let (memory_frame_count, metrics) = state
+        .memory
+        .read()
+        .map(|guard| (guard.total_frame_count(), guard.store_metrics()))
+        .unwrap_or_default();
+    let conversation_count = state
+        .conversations
+        .read()
+        .map(|convs| convs.len())
+        .unwrap_or(0);
+    let streams = &state.stream_stats;
+
+    Json(StatsResponse {
+        memory_frame_count,
+        conversation_count,
+        active_streams: streams.active_streams(),
+        stream_queue_depth: streams.queue_depth(),
+        stream_peak_queue_depth: streams.peak_queue_depth(),
+        stream_dropped_events: streams.dropped_events(),
+        stream_disconnects: streams.disconnects(),
+        maintenance_mode: state.maintenance_mode.load(Ordering::SeqCst),
+        t0_evictions: metrics.t0_evictions,
+        t1_to_t2_overflows: metrics.t1_to_t2_overflows,
+        store_operations: metrics.store_operations,
+    })
 }
 
 /// `GET /api/modules` — list all installed modules.
 ///
 /// Returns a JSON array of module metadata, including built-in modules
 /// and any feature-gated community modules that were compiled in.
+/// Modules refused by the registry's compatibility checks are listed
+/// after the active ones with `active: false` and an `error`.
 ///
 /// # Example Response
 ///
 /// ```json
 /// [
-///   {"id": "math_engine", "display_name": "Math Engine", ...},
-///   {"id": "hdc_algebra", "display_name": "HDC Algebra", ...}
+///   {"id": "math_engine", "display_name": "Math Engine", "active": true, ...},
+///   {"id": "chart_action", "active": false,
+///    "error": "missing required module(s): plot_strand", ...}
 /// ]
 /// ```
 pub async fn list_modules(
     State(state): State<Arc<AppState>>,
 ) -> impl IntoResponse {
-    let modules: Vec<ModuleResponse> = state
-        .registry
-        .list_modules()
+    let registry = &state.registry;
+    let active = registry.list_modules().iter().map(|m| (m, None));
+    let rejected = registry
+        .rejected()
         .iter()
-        .map(|m| ModuleResponse {
+        .map(|r| (&r.info, Some(r.reason.clone())));
+    let modules: Vec<ModuleResponse> = active
+        .chain(rejected)
+        .map(|(m, error)| ModuleResponse {
             id: m.id.clone(),
             display_name: m.display_name.clone(),
             version: m.version.clone(),
             author: m.author.clone(),
             description: m.description.clone(),
             module_type: m.module_type.to_string(),
+            min_core_version: m.min_core_version.clone(),
+            requires: m.requires.clone(),
+            active: error.is_none(),
+            error,
         })
         .collect();
     Json(modules)
@@ -718,9 +1158,14 @@ pub async fn list_modules(
 /// ```json
 /// {"conversation_id": 1234567890}
 /// ```
+///
+/// # Errors
+///
+/// - 503 Service Unavailable: the server is in maintenance mode
 pub async fn create_conversation(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<CreateConversationResponse>, (StatusCode, Json<ErrorResponse>)> {
+    ensure_writable(&state)?;
     let conversation_id = state.get_or_create_conversation(None).map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -767,11 +1212,85 @@ pub async fn list_conversations(
     }))
 }
 
+/// `POST /api/conversations/search` — find conversations about a topic.
+///
+/// Encodes `text`, queries the memory index across all strands, and
+/// returns one row per conversation ranked by its closest frame.
+/// Available in maintenance mode.
+///
+/// # Errors
+///
+/// - 400 Bad Request: empty text, text with no content to search on, or
+///   `k` outside `1..=MAX_SEARCH_RESULTS`
+///
+/// # Example Response
+///
+/// ```json
+/// {"results": [{"conversation_id": 7, "distance": 0.08, "frame_count": 3}]}
+/// ```
+pub async fn search_conversations(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<ConversationSearchRequest>,
+) -> Result<Json<ConversationSearchResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let k = request.k.unwrap_or(DEFAULT_SEARCH_RESULTS);
+    if k == 0 || k > MAX_SEARCH_RESULTS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: format!("k must be between 1 and {MAX_SEARCH_RESULTS}, got {k}"),
+            }),
+        ));
+    }
+
+    let bad_request = |error: String| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error }));
+    let frame = state
+        .translator
+        .encode(&request.text)
+        .map_err(|e| bad_request(e.to_string()))?
+        .frame;
+    let query = extract_gist(&frame)
+        .map_err(|e| bad_request(e.to_string()))?
+        .ok_or_else(|| bad_request("search text has no content to search on".to_string()))?
+        .vector;
+
+    let rows = state
+        .memory
+        .read()
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("memory lock failed: {e}"),
+                }),
+            )
+        })?
+        .search_conversations(&query, k);
+
+    Ok(Json(ConversationSearchResponse {
+        results: rows
+            .into_iter()
+            .map(|(conversation_id, distance, frame_count)| ConversationSearchHit {
+                conversation_id,
+                distance,
+                frame_count,
+            })
+            .collect(),
+    }))
+}
+
 /// `GET /api/conversations/:id/history` — retrieve conversation history.
 ///
 /// Returns all messages in a conversation in chronological order.
 /// Each message includes the decoded text, gamma scores, and timestamp.
 ///
+/// The body is streamed: frames are fetched and decoded one at a time
+/// and each message is written as soon as it is ready, so memory use does
+/// not grow with the conversation. The bytes are identical to serializing
+/// a [`ConversationHistoryResponse`](crate::models::ConversationHistoryResponse). Frames evicted from T0/T1 while the
+/// response is streaming are skipped. If a frame fails to decode after
+/// streaming has begun, the response body ends with an error and the
+/// client sees a truncated (invalid) JSON document.
+///
 /// # Errors
 ///
 /// - 404 Not Found: conversation ID does not exist
@@ -790,78 +1309,838 @@ pub async fn list_conversations(
 pub async fn get_conversation_history(
     State(state): State<Arc<AppState>>,
     Path(id): Path<u64>,
-) -> Result<Json<ConversationHistoryResponse>, (StatusCode, Json<ErrorResponse>)> {
-    // Check if conversation exists
-    let conv_exists = state
-        .conversations
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    ensure_conversation_exists(&state, id)?;
+
+    // Only the IDs are collected up front, in chronological order; frames
+    // are cloned one at a time while streaming. Stored user inputs are
+    // kept for regeneration, not listed as messages.
+    let frame_ids: Vec<u64> = state
+        .memory
         .read()
         .map_err(|e| {
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: format!("conversations lock poisoned: {e}"),
+                    error: format!("memory lock failed: {e}"),
                 }),
             )
         })?
-        .contains_key(&id);
+        .iter_strand_chronological(id)
+        .iter()
+        .filter(|frame| frame.frame_meta.discourse_type != DiscourseType::Query)
+        .map(|frame| frame.frame_meta.frame_id)
+        .collect();
 
-    if !conv_exists {
-        return Err((
-            StatusCode::NOT_FOUND,
+    let decode_err = |e: VoltError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
-                error: format!("conversation {id} not found"),
+                error: format!("decode failed: {e}"),
             }),
-        ));
-    }
+        )
+    };
 
-    // Retrieve all frames for this conversation from VoltDB and clone them
-    // We need to clone because get_by_strand returns references
-    let frames: Vec<volt_core::TensorFrame> = {
-        let guard = state.memory.read().map_err(|e| {
-            (
+    // The priming context is reported on its own, not as a turn.
+    let context = match state.contexts.read() {
+        Ok(contexts) => contexts
+            .get(&id)
+            .map(|ctx| {
+                history_message(&state, &ctx.frame, ctx.set_at).map(|mut msg| {
+                    msg.text = ctx.text.clone();
+                    msg
+                })
+            })
+            .transpose()
+            .map_err(decode_err)?,
+        Err(e) => {
+            return Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: format!("memory lock failed: {e}"),
+                    error: format!("contexts lock poisoned: {e}"),
                 }),
-            )
-        })?;
-        guard.get_by_strand(id).into_iter().cloned().collect()
+            ));
+        }
     };
 
-    // Decode each frame to build history messages
-    let mut messages = Vec::new();
-    for frame in &frames {
-        let slot_words = state.translator.decode_slots(frame).map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("decode failed: {e}"),
-                }),
-            )
-        })?;
+    let body = history_body(state, id, frame_ids, context);
+    Ok(([(header::CONTENT_TYPE, "application/json")], body).into_response())
+}
 
-        let text = format_output(&slot_words);
+/// Stream a [`ConversationHistoryResponse`](crate::models::ConversationHistoryResponse) as JSON, decoding one frame
+/// per chunk.
+fn history_body(
+    state: Arc<AppState>,
+    conversation_id: u64,
+    frame_ids: Vec<u64>,
+    context: Option<HistoryMessage>,
+) -> Body {
+    let head = stream::once(async move {
+        Ok(Bytes::from(format!(
+            "{{\"conversation_id\":{conversation_id},\"messages\":["
+        )))
+    });
 
-        let gamma: Vec<f32> = (0..MAX_SLOTS)
-            .filter(|&i| frame.slots[i].is_some())
-            .map(|i| frame.meta[i].certainty)
-            .collect();
+    let mut first = true;
+    let messages = stream::iter(frame_ids).filter_map(move |frame_id| {
+        let item = history_chunk(&state, frame_id).map(|json| {
+            json.map(|json| {
+                let mut chunk = Vec::with_capacity(json.len() + 1);
+                if !std::mem::take(&mut first) {
+                    chunk.push(b',');
+                }
+                chunk.extend_from_slice(&json);
+                Bytes::from(chunk)
+            })
+        });
+        async move { item }
+    });
 
-        // Extract timestamp from frame metadata (use frame_id as fallback)
-        let timestamp = frame.frame_meta.frame_id;
+    let tail = stream::once(async move {
+        let mut tail = b"]".to_vec();
+        if let Some(context) = context {
+            tail.extend_from_slice(b",\"context\":");
+            serde_json::to_writer(&mut tail, &context).map_err(std::io::Error::other)?;
+        }
+        tail.push(b'}');
+        Ok(Bytes::from(tail))
+    });
 
-        messages.push(HistoryMessage {
-            frame_id: frame.frame_meta.frame_id,
-            text,
-            gamma,
-            timestamp,
-        });
+    Body::from_stream(head.chain(messages).chain(tail))
+}
+
+/// Decode and serialize one history frame.
+///
+/// Returns `None` if the frame is no longer in T0/T1.
+fn history_chunk(state: &AppState, frame_id: u64) -> Option<std::io::Result<Vec<u8>>> {
+    let frame = match state.memory.read() {
+        Ok(guard) => guard.get_by_id(frame_id).cloned()?,
+        Err(e) => return Some(Err(std::io::Error::other(e.to_string()))),
+    };
+    // Frames stored without a creation time report their ID instead.
+    let timestamp = match frame.frame_meta.created_at {
+        0 => frame.frame_meta.frame_id,
+        created_at => created_at,
+    };
+    let result = history_message(state, &frame, timestamp)
+        .map_err(|e| std::io::Error::other(format!("decode failed: {e}")))
+        .and_then(|msg| serde_json::to_vec(&msg).map_err(std::io::Error::other));
+    if let Err(e) = &result {
+        tracing::error!("history stream for frame {frame_id} aborted: {e}");
     }
+    Some(result)
+}
 
-    Ok(Json(ConversationHistoryResponse {
-        conversation_id: id,
-        messages,
-    }))
+/// `POST /api/conversations/:id/context` — set a conversation's priming context.
+///
+/// Encodes the text once and attaches it to the conversation. Every
+/// subsequent turn superposes the priming gist into RAR alongside the
+/// ghost gists, until the context is cleared or replaced.
+///
+/// # Errors
+///
+/// - 400 Bad Request: empty text, input too large, or no encodable content
+/// - 404 Not Found: conversation ID does not exist
+///
+/// # Example Response
+///
+/// ```json
+/// {"conversation_id": 1, "text": "we are talking about cats", "active_slots": 3}
+/// ```
+pub async fn set_conversation_context(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<u64>,
+    Json(request): Json<SetContextRequest>,
+) -> Result<Json<ContextResponse>, (StatusCode, Json<ErrorResponse>)> {
+    ensure_conversation_exists(&state, id)?;
+
+    let output = state.translator.encode(&request.text).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+    let active_slots = output.frame.active_slot_count();
+
+    state
+        .set_conversation_context(id, &request.text, output.frame)
+        .map_err(|e| {
+            let status = match e {
+                VoltError::FrameError { .. } => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (
+                status,
+                Json(ErrorResponse {
+                    error: format!("context priming failed: {e}"),
+                }),
+            )
+        })?;
+
+    Ok(Json(ContextResponse {
+        conversation_id: id,
+        text: request.text,
+        active_slots,
+    }))
+}
+
+/// `DELETE /api/conversations/:id/context` — clear a conversation's priming context.
+///
+/// Returns 204 No Content whether or not a context was set.
+///
+/// # Errors
+///
+/// - 404 Not Found: conversation ID does not exist
+pub async fn clear_conversation_context(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<u64>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    ensure_conversation_exists(&state, id)?;
+    state.clear_conversation_context(id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /api/conversations/:id/regenerate` — re-run a conversation's last turn.
+///
+/// Re-runs the last input through the pipeline, optionally with a
+/// different diffusion `seed` or RAR `quality`, and stores the new
+/// response. A non-zero seed answers from the noisy pass's refined
+/// resolution, as `num_alternatives` passes do. The previous response is replaced in VoltDB by a tombstone
+/// whose `superseded_by` links to the new one, so it drops out of the
+/// history. The last turn and the link are both read from and written to
+/// VoltDB, so they survive a restart of a disk-backed store. The original
+/// input text is recovered by decoding the stored input frame and
+/// returned in `regenerated.input_text`.
+///
+/// # Errors
+///
+/// - 400 Bad Request: the conversation has no turn to regenerate
+/// - 403 Forbidden: safety violation (Omega Veto triggered); the veto is
+///   recorded in [`AppState::veto_audit`]
+/// - 404 Not Found: conversation ID does not exist
+/// - 503 Service Unavailable: the server is in maintenance mode
+///
+/// # Example Request
+///
+/// ```json
+/// {"seed": 7, "quality": "thorough"}
+/// ```
+pub async fn regenerate(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<u64>,
+    Json(request): Json<RegenerateRequest>,
+) -> Result<Json<ThinkResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let total_start = Instant::now();
+    ensure_writable(&state)?;
+    ensure_conversation_exists(&state, id)?;
+
+    let turn = state.last_turn(id).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: format!("conversation {id} has no turn to regenerate"),
+            }),
+        )
+    })?;
+
+    // Recover the original input text from its stored frame.
+    let recover_start = Instant::now();
+    let input_text = state
+        .translator
+        .decode_slots(&turn.input)
+        .map(|words| format_output(&words))
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("decode failed: {e}"),
+                }),
+            )
+        })?;
+    let recover_ms = recover_start.elapsed().as_secs_f64() * 1000.0;
+
+    state.memory.write().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("memory lock failed: {e}"),
+            }),
+        )
+    })?.switch_strand(id).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("strand switch failed: {e}"),
+            }),
+        )
+    })?;
+
+    let seed = request.seed.unwrap_or(0);
+    let mut rar_config = rar_config_for(request.quality.unwrap_or_default());
+    if seed != 0 {
+        rar_config.diffusion = Some(DiffusionConfig::uniform(ALTERNATIVE_NOISE_SIGMA, seed));
+    }
+    let options = TurnOptions {
+        num_alternatives: 1,
+        disabled_strands: Vec::new(),
+        rar_config,
+        slot_alternatives: 0,
+    };
+    let input_id = turn.input.frame_meta.frame_id;
+    let (mut response, frame_id) = run_turn(
+        &state,
+        id,
+        *turn.input,
+        Some(input_id),
+        recover_ms,
+        total_start,
+        options,
+    )
+    .inspect_err(|error| audit_if_vetoed(&state, error, &input_text, Some(id)))?;
+
+    // If the new response was not stored, the old one stays current.
+    let supersedes = match turn.response_frame_id {
+        Some(old_id) if frame_id != 0 => {
+            let superseded = state
+                .memory
+                .write()
+                .map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: format!("memory lock failed: {e}"),
+                        }),
+                    )
+                })?
+                .supersede_frame(old_id, frame_id)
+                .map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: format!("supersede failed: {e}"),
+                        }),
+                    )
+                })?;
+            superseded.then_some(old_id)
+        }
+        _ => None,
+    };
+
+    response.regenerated = Some(Box::new(RegenerationInfo {
+        input_text,
+        frame_id,
+        supersedes,
+        seed,
+    }));
+    Ok(Json(response))
+}
+
+/// Default RAR settings with a request's `max_iterations` cap applied,
+/// clamped to `1..=MAX_RAR_ITERATIONS`.
+fn rar_config_capped(max_iterations: Option<u32>) -> RarConfig {
+    let mut config = RarConfig::default();
+    if let Some(cap) = max_iterations {
+        config.max_iterations = cap.clamp(1, MAX_RAR_ITERATIONS);
+    }
+    config
+}
+
+/// Render response proof steps as a Graphviz DOT digraph via
+/// [`ProofChain::to_dot`].
+fn proof_steps_dot(steps: &[ProofStepResponse]) -> String {
+    let steps: Vec<ProofStep> = steps
+        .iter()
+        .map(|step| ProofStep {
+            strand_name: step.strand_name.clone(),
+            description: step.description.clone(),
+            similarity: step.similarity,
+            gamma_after: step.gamma_after,
+            activated: step.activated,
+            op_code: step.op_code.clone(),
+            inputs: step.inputs.clone(),
+            outputs: step.outputs.clone(),
+        })
+        .collect();
+    let chain = ProofChain {
+        final_gamma: steps.last().map_or(0.0, |step| step.gamma_after),
+        activated_count: steps.iter().filter(|step| step.activated).count(),
+        steps,
+    };
+    chain.to_dot()
+}
+
+/// RAR settings for a [`RarQuality`] preset.
+///
+/// `Standard` is [`RarConfig::default`], the settings `/api/think` uses.
+fn rar_config_for(quality: RarQuality) -> RarConfig {
+    match quality {
+        RarQuality::Fast => RarConfig {
+            epsilon: 0.01,
+            max_iterations: 10,
+            ..RarConfig::default()
+        },
+        RarQuality::Standard => RarConfig::default(),
+        RarQuality::Thorough => RarConfig {
+            epsilon: 0.0001,
+            max_iterations: 200,
+            ..RarConfig::default()
+        },
+    }
+}
+
+/// `POST /api/think/replay/:frame_id` — re-run the pipeline on a stored frame.
+///
+/// Loads the frame from memory as stored (no re-encoding) and runs it
+/// through Hard Core routing, RAR, and safety, returning the decoded
+/// original alongside the replay. The replay uses no ghost gists or
+/// conversation priming, so replaying a frame repeatedly gives the same
+/// result. Nothing is stored unless `store` is set, in which case the
+/// replay is stored in the original frame's strand.
+///
+/// # Errors
+///
+/// - 403 Forbidden: safety violation (Omega Veto triggered); the veto is
+///   recorded in [`AppState::veto_audit`]
+/// - 404 Not Found: no frame with this ID
+/// - 422 Unprocessable Entity: the frame has decayed below full resolution
+/// - 503 Service Unavailable: `store` was set in maintenance mode
+///
+/// # Example Request
+///
+/// ```json
+/// {"store": false}
+/// ```
+pub async fn replay_frame(
+    State(state): State<Arc<AppState>>,
+    Path(frame_id): Path<u64>,
+    Json(request): Json<ReplayRequest>,
+) -> Result<Json<ReplayResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let total_start = Instant::now();
+    if request.store {
+        ensure_writable(&state)?;
+    }
+
+    let entry = state
+        .memory
+        .read()
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("memory lock failed: {e}"),
+                }),
+            )
+        })?
+        .get_entry_by_id(frame_id);
+    let original = match entry {
+        Some(FrameEntry::Full(frame)) => frame,
+        Some(other) => {
+            return Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse {
+                    error: format!(
+                        "frame {frame_id} has decayed to {:?}; only full frames can be replayed",
+                        other.decay_level()
+                    ),
+                }),
+            ));
+        }
+        None => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: format!("frame {frame_id} not found"),
+                }),
+            ));
+        }
+    };
+
+    let options = TurnOptions {
+        num_alternatives: 1,
+        disabled_strands: request.disabled_strands.unwrap_or_default(),
+        rar_config: RarConfig::default(),
+        slot_alternatives: 0,
+    };
+    let output = run_pipeline(&state, (*original).clone(), Vec::new(), options)
+        .inspect_err(|error| {
+            let input = state
+                .translator
+                .decode_slots(&original)
+                .map(|words| format_output(&words))
+                .unwrap_or_default();
+            audit_if_vetoed(&state, error, &input, Some(original.frame_meta.strand_id));
+        })?;
+
+    let stored_frame_id = if request.store {
+        let mut guard = state.memory.write().map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("memory store lock failed: {e}"),
+                }),
+            )
+        })?;
+        // Store into the original strand, then switch back so the replay
+        // does not redirect later writes of the active conversation.
+        let active_strand = guard.active_strand();
+        let stored = guard
+            .switch_strand(original.frame_meta.strand_id)
+            .and_then(|()| guard.store(*output.frame.clone()));
+        let stored = guard
+            .switch_strand(active_strand)
+            .and(stored)
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("memory store failed: {e}"),
+                    }),
+                )
+            })?;
+        Some(stored)
+    } else {
+        None
+    };
+
+    Ok(Json(ReplayResponse {
+        frame_id,
+        original: replay_frame_summary(&state, &original)?,
+        replay: replay_frame_summary(&state, &output.frame)?,
+        iterations: output.iterations,
+        proof_steps: output.proof_steps,
+        safety_score: output.safety_score,
+        stored_frame_id,
+        total_ms: total_start.elapsed().as_secs_f64() * 1000.0,
+    }))
+}
+
+/// Decode `frame` into the text and certainties reported by a replay.
+fn replay_frame_summary(
+    state: &AppState,
+    frame: &volt_core::TensorFrame,
+) -> Result<ReplayFrame, (StatusCode, Json<ErrorResponse>)> {
+    let slot_words = state.translator.decode_slots(frame).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("decode failed: {e}"),
+            }),
+        )
+    })?;
+    Ok(ReplayFrame {
+        text: format_output(&slot_words),
+        gamma: (0..MAX_SLOTS)
+            .filter(|&i| frame.slots[i].is_some())
+            .map(|i| frame.meta[i].certainty)
+            .collect(),
+        global_certainty: frame.frame_meta.global_certainty,
+    })
+}
+
+/// `POST /api/admin/maintenance` — turn maintenance mode on or off.
+///
+/// While maintenance mode is on, `/api/think`, `/api/think/stream`,
+/// regenerate, storing replays, and conversation creation return 503; search, history,
+/// context, stats, and module listings keep working. Requests already past the
+/// check run to completion.
+///
+/// Requires the `x-api-key` header to match
+/// [`AppState::admin_api_key`].
+///
+/// # Errors
+///
+/// - 401 Unauthorized: missing or wrong `x-api-key`
+/// - 403 Forbidden: no admin API key is configured
+///
+/// # Example Request
+///
+/// ```json
+/// {"enabled": true}
+/// ```
+pub async fn set_maintenance(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<MaintenanceRequest>,
+) -> Result<Json<MaintenanceResponse>, (StatusCode, Json<ErrorResponse>)> {
+    require_admin(&state, &headers)?;
+
+    let previous = state
+        .maintenance_mode
+        .swap(request.enabled, Ordering::SeqCst);
+    if request.enabled {
+        tracing::warn!("Maintenance mode enabled (was {previous}); rejecting writes");
+    } else {
+        tracing::info!("Maintenance mode disabled (was {previous}); accepting writes");
+    }
+
+    Ok(Json(MaintenanceResponse {
+        maintenance: request.enabled,
+        previous,
+    }))
+}
+
+/// `POST /api/admin/gc` — run a garbage-collection pass over memory.
+///
+/// Holds the memory write lock only for the pass itself. Admin GC and
+/// consolidation runs are serialized: a second request waits for the
+/// first to finish. Works in maintenance mode. Requires the `x-api-key`
+/// header to match [`AppState::admin_api_key`].
+///
+/// # Errors
+///
+/// - 401 Unauthorized: missing or wrong `x-api-key`
+/// - 403 Forbidden: no admin API key is configured
+///
+/// # Example Response
+///
+/// ```json
+/// {"frames_compressed": 3, "frames_gisted": 0, "frames_tombstoned": 0, "frames_preserved": 61}
+/// ```
+pub async fn admin_gc(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<GcResult>, (StatusCode, Json<ErrorResponse>)> {
+    require_admin(&state, &headers)?;
+    let _admin = lock_admin_ops(&state)?;
+
+    let result = state.memory.write().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("memory lock failed: {e}"),
+            }),
+        )
+    })?.run_gc().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("gc failed: {e}"),
+            }),
+        )
+    })?;
+    tracing::info!(
+        "Admin GC: {} compressed, {} gisted, {} tombstoned, {} preserved",
+        result.frames_compressed,
+        result.frames_gisted,
+        result.frames_tombstoned,
+        result.frames_preserved
+    );
+    Ok(Json(result))
+}
+
+/// `POST /api/admin/consolidate/:strand_id` — consolidate one strand.
+///
+/// Runs [`VoltStore::consolidate_strand`](volt_db::VoltStore::consolidate_strand)
+/// and returns its result, including the wisdom frames created. A strand
+/// with no frames (or that does not exist) yields zero clusters. Locking
+/// and authentication are as for [`admin_gc`].
+///
+/// # Errors
+///
+/// - 401 Unauthorized: missing or wrong `x-api-key`
+/// - 403 Forbidden: no admin API key is configured
+pub async fn admin_consolidate(
+    State(state): State<Arc<AppState>>,
+    Path(strand_id): Path<u64>,
+    headers: HeaderMap,
+) -> Result<Json<ConsolidationResult>, (StatusCode, Json<ErrorResponse>)> {
+    require_admin(&state, &headers)?;
+    let _admin = lock_admin_ops(&state)?;
+
+    let result = state.memory.write().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("memory lock failed: {e}"),
+            }),
+        )
+    })?
+        .consolidate_strand(strand_id)
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("consolidation failed: {e}"),
+                }),
+            )
+        })?;
+    tracing::info!(
+        "Admin consolidation of strand {strand_id}: {} clusters, {} wisdom frames",
+        result.clusters_found,
+        result.wisdom_frames.len()
+    );
+    Ok(Json(result))
+}
+
+/// `GET /api/admin/vetoes` — review recent Omega Vetoes, newest first.
+///
+/// Reads [`AppState::veto_audit`]. Vetoes are appended off the request
+/// path, so one may appear a moment after its 403 response. Inputs are
+/// shown as logged: redacted if [`AppState::redact_veto_inputs`] was set.
+/// Requires the `x-api-key` header to match [`AppState::admin_api_key`].
+///
+/// # Errors
+///
+/// - 400 Bad Request: `limit` outside `1..=MAX_VETO_RESULTS`
+/// - 401 Unauthorized: missing or wrong `x-api-key`
+/// - 403 Forbidden: no admin API key is configured
+///
+/// # Example Response
+///
+/// ```json
+/// {"vetoes": [{"sequence": 0, "timestamp_ms": 1700000000000, "input": "...",
+///   "axioms": ["K1_harm"], "conversation_id": 42}], "total": 1}
+/// ```
+pub async fn admin_vetoes(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<VetoListQuery>,
+) -> Result<Json<VetoListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    require_admin(&state, &headers)?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_VETO_RESULTS);
+    if limit == 0 || limit > MAX_VETO_RESULTS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: format!("limit must be between 1 and {MAX_VETO_RESULTS}, got {limit}"),
+            }),
+        ));
+    }
+
+    let audit = state.veto_audit.lock().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("audit log lock failed: {e}"),
+            }),
+        )
+    })?;
+    let vetoes = audit
+        .recent(limit)
+        .into_iter()
+        .map(|entry| match &entry.event {
+            AuditEvent::Veto(record) => VetoAuditEntry {
+                sequence: entry.sequence,
+                timestamp_ms: entry.timestamp_ms,
+                input: record.input.clone(),
+                axioms: record.axioms.clone(),
+                conversation_id: record.conversation_id,
+            },
+        })
+        .collect();
+    Ok(Json(VetoListResponse {
+        vetoes,
+        total: audit.len(),
+    }))
+}
+
+/// Return 403 if admin endpoints are disabled and 401 unless the
+/// `x-api-key` header matches [`AppState::admin_api_key`].
+fn require_admin(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let Some(expected) = state.admin_api_key.as_deref() else {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: "admin endpoints are disabled: no API key configured".to_string(),
+            }),
+        ));
+    };
+    let provided = headers.get("x-api-key").and_then(|v| v.to_str().ok());
+    if provided != Some(expected) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse {
+                error: "missing or invalid x-api-key".to_string(),
+            }),
+        ));
+    }
+    Ok(())
+}
+
+/// Take [`AppState::admin_ops`] for the duration of an admin operation.
+fn lock_admin_ops(
+    state: &AppState,
+) -> Result<std::sync::MutexGuard<'_, ()>, (StatusCode, Json<ErrorResponse>)> {
+    state.admin_ops.lock().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("admin lock failed: {e}"),
+            }),
+        )
+    })
+}
+
+/// Return 503 while the server is in maintenance mode.
+fn ensure_writable(state: &AppState) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if state.maintenance_mode.load(Ordering::SeqCst) {
+        Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ErrorResponse {
+                error: "maintenance".to_string(),
+            }),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Return 404 unless `id` names a known conversation.
+fn ensure_conversation_exists(
+    state: &AppState,
+    id: u64,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let exists = state
+        .conversations
+        .read()
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("conversations lock poisoned: {e}"),
+                }),
+            )
+        })?
+        .contains_key(&id);
+
+    if exists {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("conversation {id} not found"),
+            }),
+        ))
+    }
+}
+
+/// Decode a stored frame into a [`HistoryMessage`].
+fn history_message(
+    state: &AppState,
+    frame: &volt_core::TensorFrame,
+    timestamp: u64,
+) -> Result<HistoryMessage, VoltError> {
+    let slot_words = state.translator.decode_slots(frame)?;
+    let text = format_output(&slot_words);
+
+    let gamma: Vec<f32> = (0..MAX_SLOTS)
+        .filter(|&i| frame.slots[i].is_some())
+        .map(|i| frame.meta[i].certainty)
+        .collect();
+
+    Ok(HistoryMessage {
+        frame_id: frame.frame_meta.frame_id,
+        text,
+        gamma,
+        timestamp,
+    })
 }
 
 /// Format a [`SlotRole`] to a human-readable string.
@@ -889,5 +2168,6 @@ fn format_source(source: &SlotSource) -> String {
         SlotSource::HardCore => "HardCore".to_string(),
         SlotSource::Memory => "Memory".to_string(),
         SlotSource::Personal => "Personal".to_string(),
+        SlotSource::Redacted => "Redacted".to_string(),
     }
 }
@@ -1,16 +1,20 @@
 //! Shared application state for the Axum server.
 
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::SystemTime;
-use volt_core::VoltError;
-use volt_db::{ConcurrentVoltStore, VoltStore};
+use volt_core::meta::DiscourseType;
+use volt_core::{TensorFrame, VoltError, SLOT_DIM};
+use volt_db::{extract_gist, ConcurrentVoltStore, VoltStore};
 use volt_learn::EventLogger;
+use volt_ledger::{AuditEvent, AuditLog, VetoRecord};
 use volt_soft::vfn::Vfn;
 use volt_translate::StubTranslator;
 
 use crate::models::ConversationMeta;
 use crate::registry::ModuleRegistry;
+use crate::stream::{StreamStats, STREAM_CHANNEL_CAPACITY};
 
 /// Thread-safe event logger shared across handlers.
 pub type ConcurrentEventLogger = Arc<RwLock<EventLogger>>;
@@ -18,6 +22,43 @@ pub type ConcurrentEventLogger = Arc<RwLock<EventLogger>>;
 /// Thread-safe VFN shared between inference and the sleep scheduler.
 pub type SharedVfn = Arc<RwLock<Vfn>>;
 
+/// Append-only audit log shared between handlers and its writer tasks.
+pub type SharedAuditLog = Arc<Mutex<AuditLog>>;
+
+/// Weight of the priming gist when superposed into a turn's R₀ slots.
+pub const CONTEXT_ALPHA: f32 = 0.1;
+
+/// A priming frame attached to a conversation.
+///
+/// Encoded once when the context is set. Its R₀ gist is superposed into
+/// every subsequent turn's frame and fed to RAR alongside the ghost
+/// gists, so the priming persists until it is cleared or replaced.
+#[derive(Debug, Clone)]
+pub struct ConversationContext {
+    /// The priming text as submitted.
+    pub text: String,
+    /// The encoded priming frame (boxed, ~65KB).
+    pub frame: Box<TensorFrame>,
+    /// The R₀ gist of `frame`, superposed into RAR as a ghost.
+    pub gist: [f32; SLOT_DIM],
+    /// Unix timestamp (microseconds) when the context was set.
+    pub set_at: u64,
+}
+
+/// The most recent turn of a conversation, read back for regeneration.
+///
+/// Each turn stores the encoded user input (before context priming) as
+/// a [`DiscourseType::Query`] frame and the verified response as a
+/// [`DiscourseType::Response`] frame derived from it, so the last turn
+/// survives a restart of a disk-backed store.
+#[derive(Debug, Clone)]
+pub struct ConversationTurn {
+    /// The stored user input, before priming (boxed, ~65KB).
+    pub input: Box<TensorFrame>,
+    /// Frame ID of the live response to `input`, if one is stored.
+    pub response_frame_id: Option<u64>,
+}
+
 /// Shared application state, passed to all route handlers via Axum `State`.
 ///
 /// The [`StubTranslator`] uses internal `RwLock` for thread safety.
@@ -29,6 +70,10 @@ pub type SharedVfn = Arc<RwLock<Vfn>>;
 /// sleep consolidation (Forward-Forward + RLVF training).
 /// The `conversations` map tracks conversation metadata (created_at,
 /// last_message_at, message_count) for all active conversations.
+/// The `contexts` map holds the optional priming frame per conversation.
+/// The [`StreamStats`] track queue depth and backpressure across all
+/// SSE streams.
+/// The [`SharedAuditLog`] records every Omega Veto for later review.
 ///
 /// # Example
 ///
@@ -50,6 +95,26 @@ pub struct AppState {
     pub registry: ModuleRegistry,
     /// Conversation metadata indexed by conversation ID.
     pub conversations: Arc<RwLock<HashMap<u64, ConversationMeta>>>,
+    /// Priming contexts indexed by conversation ID.
+    pub contexts: Arc<RwLock<HashMap<u64, ConversationContext>>>,
+    /// Queue-depth and backpressure counters for SSE streams.
+    pub stream_stats: Arc<StreamStats>,
+    /// Event channel capacity for each SSE stream.
+    pub stream_capacity: usize,
+    /// When set, write endpoints (think, stream, regenerate, new
+    /// conversations) reject requests with 503 while reads keep working.
+    pub maintenance_mode: AtomicBool,
+    /// Key required in the `x-api-key` header by admin endpoints.
+    /// Admin endpoints are disabled while this is `None`.
+    pub admin_api_key: Option<String>,
+    /// Held for the whole of an admin GC or consolidation run so that
+    /// concurrent admin operations run one at a time.
+    pub admin_ops: Mutex<()>,
+    /// Audit trail of Omega Vetoes, reviewed via `GET /api/admin/vetoes`.
+    pub veto_audit: SharedAuditLog,
+    /// When set, vetoed inputs are logged as
+    /// [`REDACTED_PLACEHOLDER`](volt_core::REDACTED_PLACEHOLDER).
+    pub redact_veto_inputs: bool,
 }
 
 impl AppState {
@@ -74,6 +139,14 @@ impl AppState {
             vfn: Arc::new(RwLock::new(Vfn::new_random(42))),
             registry: ModuleRegistry::discover(),
             conversations: Arc::new(RwLock::new(HashMap::new())),
+            contexts: Arc::new(RwLock::new(HashMap::new())),
+            stream_stats: Arc::new(StreamStats::default()),
+            stream_capacity: STREAM_CHANNEL_CAPACITY,
+            maintenance_mode: AtomicBool::new(false),
+            admin_api_key: None,
+            admin_ops: Mutex::new(()),
+            veto_audit: Arc::new(Mutex::new(AuditLog::in_memory())),
+            redact_veto_inputs: false,
         })
     }
 
@@ -180,4 +253,236 @@ impl AppState {
             meta.message_count += 1;
         }
     }
+
+    /// Attach a priming frame to a conversation, replacing any existing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::FrameError`] if the frame has no R₀ data to
+    /// derive a gist from, or [`VoltError::Internal`] on lock poisoning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::state::AppState;
+    /// use volt_translate::Translator;
+    ///
+    /// let state = AppState::new();
+    /// let conv_id = state.get_or_create_conversation(None).unwrap();
+    /// let frame = state.translator.encode("cats are animals").unwrap().frame;
+    /// state.set_conversation_context(conv_id, "cats are animals", frame).unwrap();
+    ///
+    /// let mut turn = state.translator.encode("dogs chase balls").unwrap().frame;
+    /// assert!(state.prime_frame(conv_id, &mut turn).is_some());
+    /// ```
+    pub fn set_conversation_context(
+        &self,
+        id: u64,
+        text: &str,
+        frame: TensorFrame,
+    ) -> Result<(), VoltError> {
+        let gist = extract_gist(&frame)?
+            .ok_or_else(|| VoltError::FrameError {
+                message: "context frame has no R0 data to prime with".to_string(),
+            })?
+            .vector;
+
+        let set_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+
+        let mut contexts = self.contexts.write().map_err(|e| VoltError::Internal {
+            message: format!("contexts lock poisoned: {e}"),
+        })?;
+        contexts.insert(
+            id,
+            ConversationContext {
+                text: text.to_string(),
+                frame: Box::new(frame),
+                gist,
+                set_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove a conversation's priming frame.
+    ///
+    /// Returns `true` if a context was present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::state::AppState;
+    ///
+    /// let state = AppState::new();
+    /// assert!(!state.clear_conversation_context(1));
+    /// ```
+    pub fn clear_conversation_context(&self, id: u64) -> bool {
+        self.contexts
+            .write()
+            .map(|mut contexts| contexts.remove(&id).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Superpose a conversation's priming gist into `frame`, if a context is set.
+    ///
+    /// Each active slot's R₀ vector is blended with the gist at weight
+    /// [`CONTEXT_ALPHA`] via [`volt_bus::superpose`]. Returns the gist so
+    /// the caller can also hand it to RAR as a ghost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::state::AppState;
+    /// use volt_translate::Translator;
+    ///
+    /// let state = AppState::new();
+    /// let conv_id = state.get_or_create_conversation(None).unwrap();
+    /// let mut frame = state.translator.encode("dogs chase balls").unwrap().frame;
+    /// assert!(state.prime_frame(conv_id, &mut frame).is_none());
+    /// ```
+    pub fn prime_frame(&self, id: u64, frame: &mut TensorFrame) -> Option<[f32; SLOT_DIM]> {
+        let gist = self
+            .contexts
+            .read()
+            .ok()
+            .and_then(|contexts| contexts.get(&id).map(|ctx| ctx.gist))?;
+
+        let weighted_gist = gist.map(|x| x * CONTEXT_ALPHA);
+        for slot in frame.slots.iter_mut().flatten() {
+            if let Some(r0) = slot.resolutions[0].as_mut() {
+                let norm: f32 = r0.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if norm < 1e-10 {
+                    continue;
+                }
+                let weighted_r0 = r0.map(|x| x / norm * (1.0 - CONTEXT_ALPHA));
+                if let Ok(blended) = volt_bus::superpose(&[&weighted_r0, &weighted_gist]) {
+                    *r0 = blended;
+                }
+            }
+        }
+        Some(gist)
+    }
+
+    /// Store one turn in the active strand: `input` as a
+    /// [`DiscourseType::Query`] frame, then `response` as a
+    /// [`DiscourseType::Response`] frame derived from it.
+    ///
+    /// When `input_id` names an already stored input (a regenerated
+    /// turn), only the response is stored. Returns the response's frame
+    /// ID (0 if the store dropped it) and the store's total frame count.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::Internal`] if the memory lock is poisoned, or
+    /// the store's error if a frame cannot be stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::state::AppState;
+    /// use volt_core::TensorFrame;
+    ///
+    /// let state = AppState::new();
+    /// let (response_id, count) = state
+    ///     .store_turn(&TensorFrame::new(), None, TensorFrame::new())
+    ///     .unwrap();
+    /// assert_eq!(count, 2);
+    /// assert_eq!(state.last_turn(0).unwrap().response_frame_id, Some(response_id));
+    /// assert!(state.last_turn(1).is_none());
+    /// ```
+    pub fn store_turn(
+        &self,
+        input: &TensorFrame,
+        input_id: Option<u64>,
+        mut response: TensorFrame,
+    ) -> Result<(u64, usize), VoltError> {
+        let mut memory = self.memory.write().map_err(|e| VoltError::Internal {
+            message: format!("memory lock poisoned: {e}"),
+        })?;
+        let input_id = match input_id {
+            Some(id) => id,
+            None => {
+                let mut query = input.clone();
+                query.frame_meta.discourse_type = DiscourseType::Query;
+                memory.store(query)?
+            }
+        };
+        response.frame_meta.discourse_type = DiscourseType::Response;
+        response.frame_meta.derived_from.push(input_id);
+        let response_id = memory.store(response)?;
+        Ok((response_id, memory.total_frame_count()))
+    }
+
+    /// The last stored turn of conversation `id`, if any.
+    ///
+    /// Reads VoltDB without counting frame accesses.
+    pub fn last_turn(&self, id: u64) -> Option<ConversationTurn> {
+        let memory = self.memory.read().ok()?;
+        let frames = memory.iter_strand_chronological(id);
+        let input = frames
+            .iter()
+            .rev()
+            .find(|f| f.frame_meta.discourse_type == DiscourseType::Query)?;
+        let input_id = input.frame_meta.frame_id;
+        let response_frame_id = frames
+            .iter()
+            .rev()
+            .find(|f| {
+                f.frame_meta.discourse_type == DiscourseType::Response
+                    && f.frame_meta.derived_from.contains(&input_id)
+            })
+            .map(|f| f.frame_meta.frame_id);
+        Some(ConversationTurn {
+            input: Box::new((*input).clone()),
+            response_frame_id,
+        })
+    }
+
+    /// Record an Omega Veto of `input` in [`AppState::veto_audit`],
+    /// redacting the input if [`AppState::redact_veto_inputs`] is set.
+    ///
+    /// The append runs on Tokio's blocking pool when called inside a
+    /// runtime, so a slow audit file never delays the 403 response.
+    /// Failures are logged and otherwise ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_server::state::AppState;
+    ///
+    /// let state = AppState::new();
+    /// state.record_veto("bad input", vec!["K1_harm".to_string()], Some(3));
+    /// assert_eq!(state.veto_audit.lock().unwrap().len(), 1);
+    /// ```
+    pub fn record_veto(&self, input: &str, axioms: Vec<String>, conversation_id: Option<u64>) {
+        let mut record = VetoRecord {
+            input: input.to_string(),
+            axioms,
+            conversation_id,
+        };
+        if self.redact_veto_inputs {
+            record = record.redacted();
+        }
+        let audit = self.veto_audit.clone();
+        let append = move || {
+            let result = match audit.lock() {
+                Ok(mut log) => log.append(AuditEvent::Veto(record)).map(|_| ()),
+                Err(e) => Err(VoltError::Internal {
+                    message: format!("audit log lock poisoned: {e}"),
+                }),
+            };
+            if let Err(e) = result {
+                tracing::warn!("failed to record veto: {e}");
+            }
+        };
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn_blocking(append);
+            }
+            Err(_) => append(),
+        }
+    }
 }
@@ -0,0 +1,228 @@
+//! Backpressure-aware plumbing for the SSE `think_stream` endpoint.
+//!
+//! The streaming handler pushes [`StreamEvent`]s into a bounded channel
+//! that the SSE response drains. Two failure modes look alike from the
+//! sender's side and are handled differently here:
+//!
+//! - **Slow client** (channel full): intermediate progress events are
+//!   dropped and later summarised with a [`StreamEvent::Backpressure`]
+//!   hint. Terminal events (`Complete`/`Error`) always wait for room.
+//! - **Disconnected client** (channel closed): the sender reports it
+//!   so the handler can abort instead of finishing work nobody reads.
+//!
+//! Both are counted in the shared [`StreamStats`], which backs the
+//! streaming fields of `GET /api/stats`.
+
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::response::sse::Event;
+use futures::stream::Stream;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use crate::models::StreamEvent;
+
+/// Default capacity of each SSE stream's event channel.
+pub const STREAM_CHANNEL_CAPACITY: usize = 100;
+
+type SseItem = Result<Event, Infallible>;
+
+/// Server-wide counters for SSE streams.
+///
+/// # Example
+///
+/// ```
+/// use volt_server::stream::StreamStats;
+///
+/// let stats = StreamStats::default();
+/// assert_eq!(stats.queue_depth(), 0);
+/// assert_eq!(stats.active_streams(), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct StreamStats {
+    active_streams: AtomicUsize,
+    queue_depth: AtomicUsize,
+    peak_queue_depth: AtomicUsize,
+    dropped_events: AtomicU64,
+    disconnects: AtomicU64,
+}
+
+impl StreamStats {
+    /// Number of SSE streams whose client is still attached.
+    pub fn active_streams(&self) -> usize {
+        self.active_streams.load(Ordering::Relaxed)
+    }
+
+    /// Events currently buffered across all streams, not yet read by clients.
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth.load(Ordering::Relaxed)
+    }
+
+    /// Highest total queue depth observed since startup.
+    pub fn peak_queue_depth(&self) -> usize {
+        self.peak_queue_depth.load(Ordering::Relaxed)
+    }
+
+    /// Progress events dropped because a client was reading too slowly.
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped_events.load(Ordering::Relaxed)
+    }
+
+    /// Streams aborted because the client disconnected.
+    pub fn disconnects(&self) -> u64 {
+        self.disconnects.load(Ordering::Relaxed)
+    }
+
+    fn enqueued(&self) {
+        let depth = self.queue_depth.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak_queue_depth.fetch_max(depth, Ordering::Relaxed);
+    }
+
+    fn dequeued(&self, count: usize) {
+        self.queue_depth.fetch_sub(count, Ordering::Relaxed);
+    }
+}
+
+/// Create a stream channel whose depth is tracked in `stats`.
+///
+/// Returns the handler-side [`StreamSender`] and the response-side
+/// [`StreamReceiver`], which is passed to `Sse::new`.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use volt_server::stream::{stream_channel, StreamStats};
+///
+/// let stats = Arc::new(StreamStats::default());
+/// let (_tx, _rx) = stream_channel(8, stats.clone());
+/// assert_eq!(stats.active_streams(), 1);
+/// ```
+pub fn stream_channel(capacity: usize, stats: Arc<StreamStats>) -> (StreamSender, StreamReceiver) {
+    let (tx, rx) = mpsc::channel(capacity);
+    stats.active_streams.fetch_add(1, Ordering::Relaxed);
+    (
+        StreamSender {
+            tx,
+            stats: stats.clone(),
+            pending_dropped: 0,
+        },
+        StreamReceiver { rx, stats },
+    )
+}
+
+/// Handler side of an SSE stream.
+#[derive(Debug)]
+pub struct StreamSender {
+    tx: mpsc::Sender<SseItem>,
+    stats: Arc<StreamStats>,
+    /// Progress events dropped since the last delivered backpressure hint.
+    pending_dropped: usize,
+}
+
+impl StreamSender {
+    /// Send an intermediate progress event without waiting.
+    ///
+    /// If the channel is full the event is dropped and counted; the
+    /// client learns how many were skipped from the next
+    /// [`StreamEvent::Backpressure`] hint that fits.
+    ///
+    /// Returns `false` if the client has disconnected.
+    pub fn progress(&mut self, event: StreamEvent) -> bool {
+        if self.pending_dropped > 0 {
+            match self.try_send(&StreamEvent::Backpressure(self.pending_dropped)) {
+                Ok(()) => self.pending_dropped = 0,
+                Err(TrySendError::Full(_)) => {}
+                Err(TrySendError::Closed(_)) => return self.disconnected(),
+            }
+        }
+        match self.try_send(&event) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                self.pending_dropped += 1;
+                self.stats.dropped_events.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(TrySendError::Closed(_)) => self.disconnected(),
+        }
+    }
+
+    /// Send a terminal event (`Complete` or `Error`), waiting for room.
+    ///
+    /// Any outstanding backpressure hint is delivered first, so a slow
+    /// client still sees every terminal event.
+    ///
+    /// Returns `false` if the client has disconnected.
+    pub async fn finish(&mut self, event: StreamEvent) -> bool {
+        if self.pending_dropped > 0 {
+            if !self.send(&StreamEvent::Backpressure(self.pending_dropped)).await {
+                return self.disconnected();
+            }
+            self.pending_dropped = 0;
+        }
+        self.send(&event).await || self.disconnected()
+    }
+
+    /// Resolves once the client has disconnected.
+    pub async fn closed(&self) {
+        self.tx.closed().await
+    }
+
+    /// Record that the stream is being abandoned because the client left.
+    ///
+    /// Always returns `false`, so callers can use it as a send result.
+    pub fn disconnected(&self) -> bool {
+        tracing::warn!("Client disconnected during streaming; aborting");
+        self.stats.disconnects.fetch_add(1, Ordering::Relaxed);
+        false
+    }
+
+    fn try_send(&self, event: &StreamEvent) -> Result<(), TrySendError<SseItem>> {
+        self.stats.enqueued();
+        self.tx.try_send(Ok(to_sse(event))).inspect_err(|_| self.stats.dequeued(1))
+    }
+
+    async fn send(&self, event: &StreamEvent) -> bool {
+        self.stats.enqueued();
+        let sent = self.tx.send(Ok(to_sse(event))).await.is_ok();
+        if !sent {
+            self.stats.dequeued(1);
+        }
+        sent
+    }
+}
+
+/// Response side of an SSE stream; keeps [`StreamStats`] in step with
+/// what the client has actually read.
+#[derive(Debug)]
+pub struct StreamReceiver {
+    rx: mpsc::Receiver<SseItem>,
+    stats: Arc<StreamStats>,
+}
+
+impl Stream for StreamReceiver {
+    type Item = SseItem;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let polled = self.rx.poll_recv(cx);
+        if let Poll::Ready(Some(_)) = polled {
+            self.stats.dequeued(1);
+        }
+        polled
+    }
+}
+
+impl Drop for StreamReceiver {
+    fn drop(&mut self) {
+        // Events still buffered are discarded with the channel.
+        self.stats.dequeued(self.rx.len());
+        self.stats.active_streams.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn to_sse(event: &StreamEvent) -> Event {
+    Event::default().data(serde_json::to_string(event).unwrap_or_default())
+}
@@ -0,0 +1,41 @@
+//! Smoke test for the in-process benchmark harness.
+
+use volt_server::bench::{run_bench, BenchConfig};
+use volt_server::state::AppState;
+
+#[tokio::test]
+async fn tiny_bench_reports_metrics() {
+    let config = BenchConfig {
+        requests: 5,
+        concurrency: 2,
+        warmup: 2,
+        max_p99_ms: None,
+    };
+    let report = run_bench(AppState::new(), &config).await.unwrap();
+
+    assert_eq!(report.requests, 5);
+    assert_eq!(report.concurrency, 2);
+    assert!(report.wall_ms > 0.0);
+    assert!(report.throughput_rps > 0.0);
+
+    let latency = report.latency_ms;
+    assert!(latency.p50 > 0.0);
+    assert!(latency.p50 <= latency.p95 && latency.p95 <= latency.p99);
+    assert!(latency.p99 <= latency.max);
+    assert!(report.within_budget(latency.max));
+    assert!(!report.within_budget(latency.p99 / 2.0));
+
+    let phases = report.phases_ms;
+    assert!(phases.encode_ms > 0.0 && phases.decode_ms > 0.0);
+    assert!(phases.pipeline_ms >= 0.0);
+    assert!(report.to_string().contains("p99"));
+}
+
+#[tokio::test]
+async fn bench_rejects_zero_requests() {
+    let config = BenchConfig {
+        requests: 0,
+        ..BenchConfig::default()
+    };
+    assert!(run_bench(AppState::new(), &config).await.is_err());
+}
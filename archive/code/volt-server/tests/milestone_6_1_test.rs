@@ -140,3 +140,52 @@ async fn api_modules_returns_json() {
         "all modules should have a valid module_type"
     );
 }
+
+// --------------------------------------------------------------------------
+// Test: Rejected modules are surfaced with their error
+// --------------------------------------------------------------------------
+
+#[tokio::test]
+async fn api_modules_reports_missing_dependency() {
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+    use volt_core::module_info::ModuleInfo;
+
+    let mut state = volt_server::state::AppState::new();
+    let registry = &mut std::sync::Arc::get_mut(&mut state).unwrap().registry;
+    let module = |id: &str, requires: &str| ModuleInfo {
+        id: id.to_string(),
+        display_name: id.to_string(),
+        version: "0.1.0".to_string(),
+        author: "Community".to_string(),
+        description: "Test module.".to_string(),
+        module_type: ModuleType::HardStrand,
+        min_core_version: "0.1.0".to_string(),
+        requires: vec![requires.to_string()],
+        deterministic: true,
+    };
+    registry.register(module("geometry", "math_engine")).unwrap();
+    assert!(registry.register(module("chart", "plotter")).is_err());
+
+    let app = volt_server::build_app_with_state(state);
+    let response = app
+        .oneshot(Request::builder().uri("/api/modules").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
+        .await
+        .unwrap();
+    let modules: Vec<volt_server::models::ModuleResponse> =
+        serde_json::from_slice(&body).unwrap();
+
+    let geometry = modules.iter().find(|m| m.id == "geometry").unwrap();
+    assert!(geometry.active);
+    assert_eq!(geometry.requires, vec!["math_engine"]);
+    assert!(geometry.error.is_none());
+
+    let chart = modules.iter().find(|m| m.id == "chart").unwrap();
+    assert!(!chart.active);
+    let error = chart.error.as_deref().unwrap();
+    assert!(error.contains("missing required module(s): plotter"), "{error}");
+}
@@ -293,6 +293,287 @@ async fn think_response_has_proof_chain() {
     assert_eq!(last.strand_name, "certainty_engine");
 }
 
+/// Helper: POST a raw JSON body to `/api/think` and parse the response.
+async fn think_json(app: axum::Router, body: &str) -> ThinkResponse {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/think")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_owned()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn think_disabled_strands_falls_through_to_rar() {
+    let routed = think_json(build_app(), r#"{"text": "10 + 5"}"#).await;
+    assert!(
+        routed
+            .proof_steps
+            .iter()
+            .any(|s| s.strand_name == "math_engine" && s.activated),
+        "math query should route to math_engine by default"
+    );
+    assert_eq!(routed.iterations, 0, "hard strand result skips RAR");
+
+    let disabled = think_json(
+        build_app(),
+        r#"{"text": "10 + 5", "disabled_strands": ["math_engine", "no_such_strand"]}"#,
+    )
+    .await;
+    assert!(
+        !disabled
+            .proof_steps
+            .iter()
+            .any(|s| s.strand_name == "math_engine" && s.activated),
+        "disabled math_engine must not activate"
+    );
+    assert!(disabled.iterations > 0, "query should fall through to RAR");
+
+}
+
+#[tokio::test]
+async fn think_unknown_disabled_strands_route_like_none() {
+    let steps = |think: &ThinkResponse| -> Vec<(String, bool)> {
+        think
+            .proof_steps
+            .iter()
+            .map(|s| (s.strand_name.clone(), s.activated))
+            .collect()
+    };
+
+    let plain = think_json(build_app(), r#"{"text": "10 + 5"}"#).await;
+    let unknown = think_json(
+        build_app(),
+        r#"{"text": "10 + 5", "disabled_strands": ["no_such_strand"]}"#,
+    )
+    .await;
+    assert_eq!(steps(&unknown), steps(&plain));
+    assert_eq!(unknown.iterations, plain.iterations);
+    assert_eq!(unknown.iterations, 0, "math query is still hard-handled");
+}
+
+#[tokio::test]
+async fn think_non_math_prompt_reaches_rar() {
+    let think = think_json(build_app(), r#"{"text": "The cat sat on the mat"}"#).await;
+    assert!(
+        !think
+            .proof_steps
+            .iter()
+            .any(|s| s.activated && s.strand_name != "certainty_engine"),
+        "no hard strand should handle a plain sentence"
+    );
+    assert!(think.iterations > 0, "plain sentence should fall through to RAR");
+}
+
+#[tokio::test]
+async fn think_max_iterations_caps_rar() {
+    for cap in [1, 3] {
+        let body = format!(r#"{{"text": "The cat sat on the mat", "max_iterations": {cap}}}"#);
+        let think = think_json(build_app(), &body).await;
+        assert!(think.iterations >= 1, "query should reach RAR");
+        assert!(
+            think.iterations <= cap,
+            "{} iterations exceeds cap {cap}",
+            think.iterations
+        );
+        assert!(!think.text.is_empty());
+        assert!(think.gamma.iter().all(|g| (0.0..=1.0).contains(g)));
+    }
+
+    // Zero is clamped up to one iteration.
+    let think = think_json(
+        build_app(),
+        r#"{"text": "The cat sat on the mat", "max_iterations": 0}"#,
+    )
+    .await;
+    assert_eq!(think.iterations, 1);
+}
+
+#[tokio::test]
+async fn think_include_proof_dot_renders_digraph() {
+    let think = think_json(
+        build_app(),
+        r#"{"text": "The cat sat on the mat", "include_proof_dot": true}"#,
+    )
+    .await;
+    let dot = think.proof_dot.expect("proof_dot requested");
+    assert!(dot.starts_with("digraph proof {"));
+    assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+    assert!(!think.proof_steps.is_empty());
+    for (i, step) in think.proof_steps.iter().enumerate() {
+        assert!(dot.contains(&format!("step{i} [label=\"{}", step.strand_name)));
+    }
+
+    // Omitted by default.
+    let think = think_json(build_app(), r#"{"text": "The cat sat on the mat"}"#).await;
+    assert!(think.proof_dot.is_none());
+}
+
+#[tokio::test]
+async fn think_echo_encoded_reports_translator_output() {
+    use volt_server::models::CreateConversationResponse;
+    use volt_server::state::AppState;
+    use volt_translate::{StubTranslator, Translator};
+
+    let text = "The cat sat on the mat";
+    let state = AppState::new();
+    let app = volt_server::build_app_with_state(state.clone());
+    let (_, body) = send_json(app.clone(), "POST", "/api/conversations", None).await;
+    let id = serde_json::from_slice::<CreateConversationResponse>(&body)
+        .unwrap()
+        .conversation_id;
+    // Priming changes the frame the pipeline sees, but not the echo.
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        &format!("/api/conversations/{id}/context"),
+        Some(r#"{"text": "quantum physics energy"}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let body = format!(r#"{{"text": "{text}", "conversation_id": {id}, "echo_encoded": true}}"#);
+    let think = think_json(app, &body).await;
+    let echo = think.encoded_slots.expect("encoded_slots requested");
+
+    let translator = StubTranslator::new();
+    let encoded = translator.encode(text).unwrap().frame;
+    let expected = translator.decode_slots(&encoded).unwrap();
+    assert_eq!(echo.len(), expected.len());
+    for (slot, (index, role, word)) in echo.iter().zip(&expected) {
+        assert_eq!(slot.index, *index);
+        assert_eq!(slot.role, format!("{role:?}"));
+        assert_eq!(&slot.word, word);
+        assert_eq!(slot.certainty, encoded.meta[*index].certainty);
+        let r0 = encoded.slots[*index].as_ref().unwrap().resolutions[0].unwrap();
+        let norm = r0.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((slot.r0_norm - norm).abs() < 1e-5);
+    }
+
+    {
+        let guard = state.memory.read().unwrap();
+        let stored = guard
+            .get_by_strand(id)
+            .into_iter()
+            .find(|f| f.frame_meta.discourse_type == volt_core::meta::DiscourseType::Response)
+            .unwrap();
+        let index = echo[0].index;
+        assert_ne!(
+            stored.slots[index].as_ref().unwrap().resolutions[0],
+            encoded.slots[index].as_ref().unwrap().resolutions[0],
+            "the final frame was primed, the echo was not"
+        );
+    }
+
+    // Omitted by default.
+    let think = think_json(build_app(), r#"{"text": "The cat sat on the mat"}"#).await;
+    assert!(think.encoded_slots.is_none());
+}
+
+#[tokio::test]
+async fn think_slot_alternatives_lead_with_decoded_word() {
+    use volt_server::models::MAX_SLOT_ALTERNATIVES;
+
+    let app = build_app();
+    // Grow the vocabulary so each slot has several candidates to rank.
+    think_json(app.clone(), r#"{"text": "dog ran home"}"#).await;
+    let think = think_json(
+        app.clone(),
+        r#"{"text": "The cat sat on the mat", "slot_alternatives": 3}"#,
+    )
+    .await;
+    assert_eq!(think.slot_alternatives.len(), think.slot_states.len());
+    let mut ranked = 0;
+    for (slot, candidates) in think.slot_states.iter().zip(&think.slot_alternatives) {
+        assert!(candidates.len() <= 3);
+        if let Some((top, _)) = candidates.first() {
+            assert_eq!(top, &slot.word, "slot {} top candidate", slot.index);
+            ranked += 1;
+        }
+        assert!(candidates.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+    assert!(ranked > 0, "no slot was decoded by cleanup");
+
+    // `m` is capped.
+    let think = think_json(
+        app,
+        r#"{"text": "The cat sat on the mat", "slot_alternatives": 1000}"#,
+    )
+    .await;
+    assert!(think.slot_alternatives.iter().all(|c| c.len() <= MAX_SLOT_ALTERNATIVES));
+
+    // Omitted by default.
+    let think = think_json(build_app(), r#"{"text": "The cat sat on the mat"}"#).await;
+    assert!(think.slot_alternatives.is_empty());
+}
+
+#[tokio::test]
+async fn think_returns_distinct_alternatives() {
+    let think = think_json(
+        build_app(),
+        r#"{"text": "The cat sat on the mat", "num_alternatives": 3}"#,
+    )
+    .await;
+
+    assert!(think.iterations > 0, "query should reach RAR");
+    assert!(
+        think.alternatives.len() > 1,
+        "noise-seeded passes should add distinct alternatives, got {}",
+        think.alternatives.len()
+    );
+    assert!(think.alternatives.len() <= 3);
+    let texts: std::collections::HashSet<&str> =
+        think.alternatives.iter().map(|a| a.text.as_str()).collect();
+    assert_eq!(texts.len(), think.alternatives.len(), "alternatives must be deduplicated");
+    assert!(texts.contains(think.text.as_str()), "main answer is one of the alternatives");
+    for alt in &think.alternatives {
+        assert!((0.0..=1.0).contains(&alt.certainty));
+    }
+    assert!(
+        think.alternatives.windows(2).all(|w| w[0].certainty >= w[1].certainty),
+        "alternatives ranked by certainty"
+    );
+
+    // A single alternative keeps the plain response shape.
+    let single = think_json(
+        build_app(),
+        r#"{"text": "The cat sat on the mat", "num_alternatives": 1}"#,
+    )
+    .await;
+    assert!(single.alternatives.is_empty());
+    assert_eq!(single.text, think.text);
+}
+
+#[tokio::test]
+async fn think_rejects_out_of_range_alternatives() {
+    for n in [0, 9] {
+        let response = build_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/think")
+                    .header("content-type", "application/json")
+                    .body(Body::from(format!(
+                        r#"{{"text": "hello", "num_alternatives": {n}}}"#
+                    )))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
 #[tokio::test]
 async fn think_response_has_safety_score() {
     let app = build_app();
@@ -392,10 +673,10 @@ async fn think_stores_frame_to_memory() {
 
     let resp = think_once(app, "cat sat mat").await;
 
-    // After one request, exactly one frame should be stored
+    // After one request, the input and its response should be stored
     assert_eq!(
-        resp.memory_frame_count, 1,
-        "first request should store 1 frame"
+        resp.memory_frame_count, 2,
+        "first request should store 2 frames"
     );
 }
 
@@ -422,9 +703,10 @@ async fn think_memory_accumulates_across_requests() {
     let resp2 = think_once(app.clone(), "on the mat").await;
     let resp3 = think_once(app, "in the hat").await;
 
-    assert_eq!(resp1.memory_frame_count, 1);
-    assert_eq!(resp2.memory_frame_count, 2);
-    assert_eq!(resp3.memory_frame_count, 3);
+    // Each turn stores its input and its response
+    assert_eq!(resp1.memory_frame_count, 2);
+    assert_eq!(resp2.memory_frame_count, 4);
+    assert_eq!(resp3.memory_frame_count, 6);
 }
 
 /// Verifies that frames produced by the full pipeline
@@ -548,3 +830,770 @@ async fn think_response_includes_memory_fields() {
     // by accessing it (deserialization would have failed if missing)
     let _ = resp.ghost_count;
 }
+
+// --------------------------------------------------------------------------
+// Conversation context priming
+// --------------------------------------------------------------------------
+
+/// Helper: send a request with an optional JSON body and return status + body.
+async fn send_json(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<String>,
+) -> (StatusCode, Vec<u8>) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(body.map(Body::from).unwrap_or_else(Body::empty))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, bytes.to_vec())
+}
+
+/// Helper: create a conversation on `state`, optionally prime it, send
+/// one turn, and return the R₀ vectors of the stored frame.
+async fn primed_turn(
+    state: std::sync::Arc<volt_server::state::AppState>,
+    context: Option<&str>,
+    text: &str,
+) -> (ThinkResponse, Vec<[f32; volt_core::SLOT_DIM]>) {
+    use volt_server::models::CreateConversationResponse;
+
+    let app = volt_server::build_app_with_state(state.clone());
+    let (status, body) = send_json(app.clone(), "POST", "/api/conversations", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let conv: CreateConversationResponse = serde_json::from_slice(&body).unwrap();
+    let id = conv.conversation_id;
+
+    if let Some(context) = context {
+        let (status, _) = send_json(
+            app.clone(),
+            "POST",
+            &format!("/api/conversations/{id}/context"),
+            Some(format!(r#"{{"text": "{context}"}}"#)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, body) = send_json(
+        app,
+        "POST",
+        "/api/think",
+        Some(format!(r#"{{"text": "{text}", "conversation_id": {id}}}"#)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let resp: ThinkResponse = serde_json::from_slice(&body).unwrap();
+
+    let guard = state.memory.read().unwrap();
+    let frames = guard.get_by_strand(id);
+    assert_eq!(frames.len(), 2, "one turn should store its input and response");
+    let response = frames
+        .iter()
+        .find(|f| f.frame_meta.discourse_type == volt_core::meta::DiscourseType::Response)
+        .unwrap();
+    let r0: Vec<[f32; volt_core::SLOT_DIM]> = response
+        .slots
+        .iter()
+        .flatten()
+        .filter_map(|slot| slot.resolutions[0])
+        .collect();
+    (resp, r0)
+}
+
+#[tokio::test]
+async fn context_priming_influences_stored_frame() {
+    use volt_server::state::AppState;
+
+    let text = "the dog chased the ball";
+    let (plain, plain_r0) = primed_turn(AppState::new(), None, text).await;
+    let (primed, primed_r0) =
+        primed_turn(AppState::new(), Some("cats sleep on warm mats"), text).await;
+
+    assert_eq!(plain.ghost_count, 0);
+    assert_eq!(primed.ghost_count, 1, "priming gist should join the ghosts");
+    assert_eq!(plain.ghost_influence, 0.0, "no ghosts, no influence");
+    assert!(
+        primed.ghost_influence > 0.0 && primed.ghost_influence <= 1.0,
+        "priming gist should shape attention, got {}",
+        primed.ghost_influence
+    );
+    assert_eq!(plain_r0.len(), primed_r0.len());
+
+    let max_diff = plain_r0
+        .iter()
+        .zip(&primed_r0)
+        .flat_map(|(a, b)| a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()))
+        .fold(0.0f32, f32::max);
+    assert!(
+        max_diff > 1e-6,
+        "priming should change the stored frame, max diff {max_diff}"
+    );
+}
+
+#[tokio::test]
+async fn context_is_listed_in_history_and_can_be_cleared() {
+    use volt_server::models::{ContextResponse, ConversationHistoryResponse};
+    use volt_server::state::AppState;
+
+    let state = AppState::new();
+    let id = state.get_or_create_conversation(None).unwrap();
+    let app = volt_server::build_app_with_state(state);
+
+    let (status, body) = send_json(
+        app.clone(),
+        "POST",
+        &format!("/api/conversations/{id}/context"),
+        Some(r#"{"text": "cats sleep on warm mats"}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let ctx: ContextResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(ctx.conversation_id, id);
+    assert!(ctx.active_slots > 0);
+
+    let history_uri = format!("/api/conversations/{id}/history");
+    let (_, body) = send_json(app.clone(), "GET", &history_uri, None).await;
+    let history: ConversationHistoryResponse = serde_json::from_slice(&body).unwrap();
+    assert!(history.messages.is_empty(), "context is not a turn");
+    assert_eq!(
+        history.context.map(|c| c.text).as_deref(),
+        Some("cats sleep on warm mats")
+    );
+
+    let context_uri = format!("/api/conversations/{id}/context");
+    let (status, _) = send_json(app.clone(), "DELETE", &context_uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = send_json(app, "GET", &history_uri, None).await;
+    let history: ConversationHistoryResponse = serde_json::from_slice(&body).unwrap();
+    assert!(history.context.is_none());
+}
+
+#[tokio::test]
+async fn context_on_unknown_conversation_returns_404() {
+    let app = build_app();
+
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        "/api/conversations/999/context",
+        Some(r#"{"text": "hello"}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send_json(app, "DELETE", "/api/conversations/999/context", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn regenerate_supersedes_last_response() {
+    use volt_server::models::ConversationHistoryResponse;
+    use volt_server::state::AppState;
+
+    let state = AppState::new();
+    let id = state.get_or_create_conversation(None).unwrap();
+    let app = volt_server::build_app_with_state(state.clone());
+
+    let (status, body) = send_json(
+        app.clone(),
+        "POST",
+        "/api/think",
+        Some(format!(r#"{{"text": "the cat sat on the mat", "conversation_id": {id}}}"#)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let original: ThinkResponse = serde_json::from_slice(&body).unwrap();
+    assert!(original.iterations > 0, "query should reach RAR");
+
+    let history_uri = format!("/api/conversations/{id}/history");
+    let (_, body) = send_json(app.clone(), "GET", &history_uri, None).await;
+    let history: ConversationHistoryResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(history.messages.len(), 1);
+    let original_id = history.messages[0].frame_id;
+
+    let (status, body) = send_json(
+        app.clone(),
+        "POST",
+        &format!("/api/conversations/{id}/regenerate"),
+        Some(r#"{"seed": 3, "quality": "fast"}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let resp: ThinkResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(resp.conversation_id, id);
+    assert!(resp.iterations > 0, "regeneration should reach RAR");
+    assert_ne!(resp.text, original.text, "a different seed should change the output");
+    let info = resp.regenerated.expect("regenerate should report its details");
+    assert!(info.input_text.contains("cat"), "input text: {}", info.input_text);
+    assert_eq!(info.seed, 3);
+    assert_eq!(info.supersedes, Some(original_id));
+    assert_ne!(info.frame_id, 0);
+    assert_ne!(info.frame_id, original_id);
+
+    // The old response is now a tombstone linking to the new one.
+    let (_, body) = send_json(app, "GET", &history_uri, None).await;
+    let history: ConversationHistoryResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(history.messages.len(), 1);
+    assert_eq!(history.messages[0].frame_id, info.frame_id);
+    let memory = state.memory.read().unwrap();
+    assert!(memory.get_by_id(original_id).is_none());
+    assert_eq!(memory.superseded_by(original_id), Some(info.frame_id));
+}
+
+#[tokio::test]
+async fn regenerate_survives_restart_of_disk_backed_store() {
+    use volt_db::{VoltStore, VoltStoreConfig};
+    use volt_server::state::AppState;
+
+    let dir = std::env::temp_dir()
+        .join("volt_server_regenerate_restart")
+        .join(format!("{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let config = VoltStoreConfig {
+        data_dir: dir.clone(),
+        ..VoltStoreConfig::default()
+    };
+    let start = |config: VoltStoreConfig| {
+        let state = AppState::new();
+        *state.memory.write().unwrap() = VoltStore::open(config).unwrap();
+        state
+    };
+    let regenerate = |app: axum::Router, id: u64| async move {
+        let (status, body) = send_json(
+            app,
+            "POST",
+            &format!("/api/conversations/{id}/regenerate"),
+            Some("{}".to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let resp: ThinkResponse = serde_json::from_slice(&body).unwrap();
+        resp.regenerated.expect("regenerate should report its details")
+    };
+
+    let state = start(config.clone());
+    let id = state.get_or_create_conversation(None).unwrap();
+    let app = volt_server::build_app_with_state(state);
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        "/api/think",
+        Some(format!(r#"{{"text": "the cat sat on the mat", "conversation_id": {id}}}"#)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let first = regenerate(app, id).await;
+    let original_id = first.supersedes.expect("first response superseded");
+
+    // Restart without a clean shutdown: only VoltDB's WAL remembers. The
+    // fresh translator has no vocabulary, so only the turn is checked.
+    let state = start(config);
+    state.get_or_create_conversation(Some(id)).unwrap();
+    let app = volt_server::build_app_with_state(state.clone());
+    let second = regenerate(app, id).await;
+    assert_eq!(second.supersedes, Some(first.frame_id));
+    let memory = state.memory.read().unwrap();
+    assert_eq!(memory.superseded_by(original_id), Some(first.frame_id));
+    assert_eq!(memory.superseded_by(first.frame_id), Some(second.frame_id));
+    drop(memory);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn regenerate_empty_conversation_returns_400() {
+    use volt_server::state::AppState;
+
+    let state = AppState::new();
+    let id = state.get_or_create_conversation(None).unwrap();
+    let app = volt_server::build_app_with_state(state);
+
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        &format!("/api/conversations/{id}/regenerate"),
+        Some("{}".to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send_json(
+        app,
+        "POST",
+        "/api/conversations/999/regenerate",
+        Some("{}".to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+// --------------------------------------------------------------------------
+// Maintenance mode
+// --------------------------------------------------------------------------
+
+/// Helper: toggle maintenance mode with the given `x-api-key`.
+async fn set_maintenance(app: axum::Router, key: &str, enabled: bool) -> StatusCode {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/admin/maintenance")
+        .header("content-type", "application/json")
+        .header("x-api-key", key)
+        .body(Body::from(format!(r#"{{"enabled": {enabled}}}"#)))
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn maintenance_mode_rejects_writes_but_serves_reads() {
+    use volt_server::models::StatsResponse;
+    use volt_server::state::AppState;
+
+    let mut state = AppState::new();
+    std::sync::Arc::get_mut(&mut state).unwrap().admin_api_key = Some("secret".into());
+    let id = state.get_or_create_conversation(None).unwrap();
+    let app = volt_server::build_app_with_state(state);
+
+    assert_eq!(set_maintenance(app.clone(), "wrong", true).await, StatusCode::UNAUTHORIZED);
+    assert_eq!(set_maintenance(app.clone(), "secret", true).await, StatusCode::OK);
+
+    let think_body = Some(r#"{"text": "the cat sat"}"#.to_string());
+    let (status, body) = send_json(app.clone(), "POST", "/api/think", think_body.clone()).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert!(String::from_utf8_lossy(&body).contains("maintenance"));
+    let (status, _) = send_json(app.clone(), "POST", "/api/think/stream", think_body.clone()).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    let (status, _) = send_json(app.clone(), "POST", "/api/conversations", None).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+
+    let (status, _) = send_json(
+        app.clone(),
+        "GET",
+        &format!("/api/conversations/{id}/history"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send_json(app.clone(), "GET", "/api/stats", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let stats: StatsResponse = serde_json::from_slice(&body).unwrap();
+    assert!(stats.maintenance_mode);
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        "/api/conversations/search",
+        Some(r#"{"text": "the cat sat"}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(set_maintenance(app.clone(), "secret", false).await, StatusCode::OK);
+    let (status, _) = send_json(app, "POST", "/api/think", think_body).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn stats_reports_memory_store_metrics() {
+    use volt_server::models::StatsResponse;
+
+    let app = build_app();
+    let body = Some(r#"{"text": "the cat sat"}"#.to_string());
+    let (status, _) = send_json(app.clone(), "POST", "/api/think", body).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send_json(app, "GET", "/api/stats", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let stats: StatsResponse = serde_json::from_slice(&body).unwrap();
+    assert!(stats.store_operations >= 1);
+    assert_eq!(stats.store_operations, stats.memory_frame_count as u64);
+    assert_eq!(stats.t0_evictions, 0);
+    assert_eq!(stats.t1_to_t2_overflows, 0);
+}
+
+#[tokio::test]
+async fn maintenance_endpoint_disabled_without_api_key() {
+    let app = build_app();
+    assert_eq!(set_maintenance(app, "", true).await, StatusCode::FORBIDDEN);
+}
+
+// --------------------------------------------------------------------------
+// Admin GC and consolidation
+// --------------------------------------------------------------------------
+
+/// Helper: POST an admin endpoint with the given `x-api-key`.
+async fn admin_post(app: axum::Router, uri: &str, key: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("x-api-key", key)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn admin_state() -> std::sync::Arc<volt_server::state::AppState> {
+    let mut state = volt_server::state::AppState::new();
+    std::sync::Arc::get_mut(&mut state).unwrap().admin_api_key = Some("secret".into());
+    state
+}
+
+#[tokio::test]
+async fn admin_gc_returns_result_counts() {
+    let state = admin_state();
+    think_once(volt_server::build_app_with_state(state.clone()), "the cat sat").await;
+    let app = volt_server::build_app_with_state(state);
+
+    let (status, _) = admin_post(app.clone(), "/api/admin/gc", "wrong").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, body) = admin_post(app, "/api/admin/gc", "secret").await;
+    assert_eq!(status, StatusCode::OK);
+    for key in [
+        "frames_compressed",
+        "frames_gisted",
+        "frames_tombstoned",
+        "frames_preserved",
+    ] {
+        assert!(body[key].is_u64(), "missing {key} in {body}");
+    }
+}
+
+#[tokio::test]
+async fn admin_consolidate_reports_clusters_and_unknown_strand_is_empty() {
+    use volt_core::slot::SlotSource;
+    use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
+
+    let state = admin_state();
+    {
+        let mut memory = state.memory.write().unwrap();
+        // Enough identical frames to overflow T0 into T1, where
+        // consolidation looks for clusters.
+        for _ in 0..80 {
+            let mut frame = TensorFrame::new();
+            let mut slot = SlotData::new(SlotRole::Agent);
+            slot.write_resolution(0, [0.5; SLOT_DIM]);
+            frame.write_slot(0, slot).unwrap();
+            frame.meta[0].source = SlotSource::Translator;
+            memory.store(frame).unwrap();
+        }
+    }
+    let app = volt_server::build_app_with_state(state);
+
+    let (status, body) = admin_post(app.clone(), "/api/admin/consolidate/0", "secret").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["clusters_found"].as_u64().unwrap() > 0, "{body}");
+    let wisdom = body["wisdom_frames"].as_array().unwrap();
+    assert!(!wisdom.is_empty());
+    assert!(wisdom[0]["frame_meta"]["frame_id"].is_u64());
+    assert!(body["superseded_frame_ids"].is_array());
+
+    let (status, body) = admin_post(app, "/api/admin/consolidate/987654", "secret").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["clusters_found"], 0);
+    assert_eq!(body["wisdom_frames"].as_array().unwrap().len(), 0);
+    assert_eq!(body["superseded_frame_ids"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_admin_ops_all_complete() {
+    let state = admin_state();
+    let app = volt_server::build_app_with_state(state);
+
+    let mut handles = Vec::new();
+    for i in 0..6 {
+        let app = app.clone();
+        let uri = if i % 2 == 0 {
+            "/api/admin/gc".to_string()
+        } else {
+            format!("/api/admin/consolidate/{i}")
+        };
+        handles.push(tokio::spawn(async move { admin_post(app, &uri, "secret").await.0 }));
+    }
+    for handle in handles {
+        assert_eq!(handle.await.unwrap(), StatusCode::OK);
+    }
+}
+
+// --------------------------------------------------------------------------
+// Conversation search
+// --------------------------------------------------------------------------
+
+#[tokio::test]
+async fn search_conversations_ranks_matching_conversation_first() {
+    use volt_server::models::{ConversationSearchResponse, CreateConversationResponse};
+
+    let app = build_app();
+    let search = |app: axum::Router, body: &'static str| async move {
+        let (status, body) =
+            send_json(app, "POST", "/api/conversations/search", Some(body.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        serde_json::from_slice::<ConversationSearchResponse>(&body).unwrap()
+    };
+    assert!(search(app.clone(), r#"{"text": "cats"}"#).await.results.is_empty());
+
+    let mut ids = Vec::new();
+    for text in ["the cat sat on the mat", "rockets launch into orbit", "bread needs yeast"] {
+        let (_, body) = send_json(app.clone(), "POST", "/api/conversations", None).await;
+        let id = serde_json::from_slice::<CreateConversationResponse>(&body)
+            .unwrap()
+            .conversation_id;
+        for _ in 0..2 {
+            let (status, _) = send_json(
+                app.clone(),
+                "POST",
+                "/api/think",
+                Some(format!(r#"{{"text": "{text}", "conversation_id": {id}}}"#)),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+        }
+        ids.push(id);
+    }
+
+    let resp = search(app.clone(), r#"{"text": "rockets launch into orbit", "k": 3}"#).await;
+    assert_eq!(resp.results.len(), 3);
+    assert_eq!(resp.results[0].conversation_id, ids[1]);
+    // Two turns, each storing an input and a response
+    assert_eq!(resp.results[0].frame_count, 4);
+    let mut seen: Vec<u64> = resp.results.iter().map(|r| r.conversation_id).collect();
+    seen.sort_unstable();
+    seen.dedup();
+    assert_eq!(seen.len(), 3);
+    for pair in resp.results.windows(2) {
+        assert!(pair[0].distance <= pair[1].distance);
+    }
+
+    let (status, _) = send_json(
+        app,
+        "POST",
+        "/api/conversations/search",
+        Some(r#"{"text": "cats", "k": 0}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// --------------------------------------------------------------------------
+// Streamed history
+// --------------------------------------------------------------------------
+
+#[tokio::test]
+async fn history_streams_valid_json_matching_serialized_response() {
+    use volt_server::models::{ConversationHistoryResponse, CreateConversationResponse};
+
+    let app = build_app();
+    let (_, body) = send_json(app.clone(), "POST", "/api/conversations", None).await;
+    let id = serde_json::from_slice::<CreateConversationResponse>(&body)
+        .unwrap()
+        .conversation_id;
+    let history_uri = format!("/api/conversations/{id}/history");
+
+    // Empty conversation: still a complete document.
+    let (status, body) = send_json(app.clone(), "GET", &history_uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        String::from_utf8(body).unwrap(),
+        format!(r#"{{"conversation_id":{id},"messages":[]}}"#)
+    );
+
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        &format!("/api/conversations/{id}/context"),
+        Some(r#"{"text": "pets and animals"}"#.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let turns = ["the cat sat", "dogs chase balls", "birds sing loudly", "fish swim", "cows eat grass"];
+    for text in turns {
+        let (status, _) = send_json(
+            app.clone(),
+            "POST",
+            "/api/think",
+            Some(format!(r#"{{"text": "{text}", "conversation_id": {id}}}"#)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let request = Request::builder()
+        .uri(&history_uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/json");
+    let raw = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    let history: ConversationHistoryResponse = serde_json::from_slice(&raw).unwrap();
+    assert_eq!(history.conversation_id, id);
+    assert_eq!(history.messages.len(), turns.len());
+    for pair in history.messages.windows(2) {
+        assert!(pair[0].frame_id < pair[1].frame_id);
+    }
+    assert_eq!(history.context.as_ref().unwrap().text, "pets and animals");
+
+    // Same bytes as serializing the response in one piece.
+    assert_eq!(serde_json::to_vec(&history).unwrap(), raw.to_vec());
+}
+
+#[tokio::test]
+async fn replay_reruns_stored_frame_without_storing() {
+    use volt_server::models::ReplayResponse;
+    use volt_translate::Translator;
+    use volt_server::state::AppState;
+
+    let state = AppState::new();
+    let frame = state.translator.encode("the cat sat on the mat").unwrap().frame;
+    let frame_id = {
+        let mut memory = state.memory.write().unwrap();
+        memory.switch_strand(7).unwrap();
+        let frame_id = memory.store(frame).unwrap();
+        memory.switch_strand(3).unwrap();
+        frame_id
+    };
+    let app = volt_server::build_app_with_state(state.clone());
+    let uri = format!("/api/think/replay/{frame_id}");
+
+    let (status, body) = send_json(app.clone(), "POST", &uri, Some("{}".to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+    let first: ReplayResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(first.frame_id, frame_id);
+    assert!(!first.original.text.is_empty());
+    assert!(!first.replay.gamma.is_empty());
+    assert_eq!(first.stored_frame_id, None);
+    assert_eq!(state.memory.read().unwrap().total_frame_count(), 1);
+
+    // Same stored input, same result
+    let (_, body) = send_json(app.clone(), "POST", &uri, Some("{}".to_string())).await;
+    let second: ReplayResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(second.replay.text, first.replay.text);
+    assert_eq!(second.replay.gamma, first.replay.gamma);
+
+    let (status, body) =
+        send_json(app.clone(), "POST", &uri, Some(r#"{"store": true}"#.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+    let stored: ReplayResponse = serde_json::from_slice(&body).unwrap();
+    let stored_id = stored.stored_frame_id.expect("replay should be stored");
+    assert_ne!(stored_id, frame_id);
+    {
+        let memory = state.memory.read().unwrap();
+        assert_eq!(memory.total_frame_count(), 2);
+        let replayed = memory.get_by_id(stored_id).unwrap();
+        assert_eq!(replayed.frame_meta.strand_id, 7, "stored in the original strand");
+        assert_eq!(memory.active_strand(), 3, "active strand is left unchanged");
+    }
+
+    let (status, _) = send_json(
+        app,
+        "POST",
+        "/api/think/replay/999999",
+        Some("{}".to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+// --------------------------------------------------------------------------
+// Veto audit
+// --------------------------------------------------------------------------
+
+/// Helper: store a frame that trips the K1 axiom and replay it.
+async fn replay_vetoed_frame(state: &std::sync::Arc<volt_server::state::AppState>) -> StatusCode {
+    use volt_core::slot::SlotSource;
+    use volt_core::{SlotData, SlotRole, TensorFrame};
+
+    let mut frame = TensorFrame::new();
+    let mut slot = SlotData::new(SlotRole::Predicate);
+    slot.write_resolution(0, volt_safety::axiom::default_axioms()[0].vector);
+    frame.write_slot(1, slot).unwrap();
+    frame.meta[1].certainty = 0.9;
+    frame.meta[1].source = SlotSource::Translator;
+    let frame_id = state.memory.write().unwrap().store(frame).unwrap();
+
+    let app = volt_server::build_app_with_state(state.clone());
+    let uri = format!("/api/think/replay/{frame_id}");
+    send_json(app, "POST", &uri, Some("{}".to_string())).await.0
+}
+
+/// Helper: GET `/api/admin/vetoes`, retrying until at least `min`
+/// entries are visible (audit appends run off the request path).
+async fn list_vetoes(
+    state: &std::sync::Arc<volt_server::state::AppState>,
+    min: usize,
+) -> volt_server::models::VetoListResponse {
+    for _ in 0..100 {
+        let app = volt_server::build_app_with_state(state.clone());
+        let request = Request::builder()
+            .uri("/api/admin/vetoes")
+            .header("x-api-key", "secret")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let list: volt_server::models::VetoListResponse = serde_json::from_slice(&bytes).unwrap();
+        if list.vetoes.len() >= min {
+            return list;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    panic!("expected at least {min} audited vetoes");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn veto_is_recorded_in_audit_log() {
+    let state = admin_state();
+    assert!(list_vetoes(&state, 0).await.vetoes.is_empty());
+
+    assert_eq!(replay_vetoed_frame(&state).await, StatusCode::FORBIDDEN);
+    let list = list_vetoes(&state, 1).await;
+    assert_eq!(list.total, 1);
+    let entry = &list.vetoes[0];
+    assert_eq!(entry.axioms.first().map(String::as_str), Some("K1_harm"));
+    assert!(!entry.input.is_empty());
+    assert_ne!(entry.input, volt_core::REDACTED_PLACEHOLDER);
+    assert!(entry.timestamp_ms > 0);
+
+    // The endpoint sits behind the admin key and bounds `limit`.
+    for (uri, key, expected) in [
+        ("/api/admin/vetoes", "wrong", StatusCode::UNAUTHORIZED),
+        ("/api/admin/vetoes?limit=0", "secret", StatusCode::BAD_REQUEST),
+    ] {
+        let request = Request::builder()
+            .uri(uri)
+            .header("x-api-key", key)
+            .body(Body::empty())
+            .unwrap();
+        let app = volt_server::build_app_with_state(state.clone());
+        assert_eq!(app.oneshot(request).await.unwrap().status(), expected);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn redacted_veto_keeps_axiom_but_not_input() {
+    let mut state = admin_state();
+    std::sync::Arc::get_mut(&mut state).unwrap().redact_veto_inputs = true;
+
+    assert_eq!(replay_vetoed_frame(&state).await, StatusCode::FORBIDDEN);
+    let list = list_vetoes(&state, 1).await;
+    assert_eq!(list.vetoes[0].input, volt_core::REDACTED_PLACEHOLDER);
+    assert_eq!(list.vetoes[0].axioms.first().map(String::as_str), Some("K1_harm"));
+}
@@ -95,3 +95,97 @@ async fn streaming_creates_conversation_when_none_provided() {
         "Expected at least one conversation to be created"
     );
 }
+
+/// Helper: open a stream on `state` without reading the body yet.
+async fn open_stream(
+    state: std::sync::Arc<AppState>,
+    text: &str,
+) -> axum::response::Response {
+    let app = build_app_with_state(state);
+    tower::ServiceExt::oneshot(
+        app,
+        axum::http::Request::builder()
+            .method("POST")
+            .uri("/api/think/stream")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(format!(r#"{{"text":"{text}"}}"#)))
+            .unwrap(),
+    )
+    .await
+    .unwrap()
+}
+
+/// A client that reads nothing until the pipeline is done still gets the
+/// Complete event, preceded by a hint for the progress events it missed.
+#[tokio::test]
+async fn slow_consumer_still_receives_complete() {
+    let mut state = AppState::new();
+    std::sync::Arc::get_mut(&mut state).unwrap().stream_capacity = 1;
+    let stats = state.stream_stats.clone();
+
+    let response = open_stream(state, "the cat sat on the mat").await;
+
+    // Let the pipeline run to completion while the client reads nothing.
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+    assert!(stats.dropped_events() > 0, "full channel should drop progress events");
+    assert!(stats.queue_depth() >= 1);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+
+    assert!(body.contains(r#""type":"Backpressure""#), "body: {body}");
+    assert!(body.contains(r#""type":"Complete""#), "body: {body}");
+    assert!(
+        body.find("Backpressure") < body.find("Complete"),
+        "hint should precede completion"
+    );
+    assert_eq!(stats.queue_depth(), 0);
+    assert_eq!(stats.active_streams(), 0);
+    assert_eq!(stats.disconnects(), 0);
+}
+
+/// Dropping the response aborts the stream task before anything is stored.
+#[tokio::test]
+async fn disconnected_client_aborts_stream() {
+    let state = AppState::new();
+    let stats = state.stream_stats.clone();
+    let memory = state.memory.clone();
+
+    let response = open_stream(state, "the cat sat on the mat").await;
+    drop(response);
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+    assert_eq!(stats.disconnects(), 1);
+    assert_eq!(stats.active_streams(), 0);
+    assert_eq!(memory.read().unwrap().total_frame_count(), 0);
+}
+
+/// `/api/stats` reports the SSE queue counters.
+#[tokio::test]
+async fn stats_endpoint_reports_stream_queue() {
+    use volt_server::models::StatsResponse;
+
+    let state = AppState::new();
+    let app = build_app_with_state(state);
+
+    let response = tower::ServiceExt::oneshot(
+        app,
+        axum::http::Request::builder()
+            .uri("/api/stats")
+            .body(axum::body::Body::empty())
+            .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let stats: StatsResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(stats.active_streams, 0);
+    assert_eq!(stats.stream_queue_depth, 0);
+    assert_eq!(stats.memory_frame_count, 0);
+}
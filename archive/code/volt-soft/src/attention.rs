@@ -5,8 +5,17 @@
 //! - Softmax attention weights: `softmax(Q·Kᵀ / √d)`
 //! - Weighted value aggregation: `msg_i = Σⱼ αᵢⱼ · Vⱼ`
 //!
+//! Empty slots are masked out: they are never keys, so they take no
+//! share of the softmax and a frame with two active slots attends
+//! exactly as if the other fourteen did not exist.
+//!
 //! Attention is O(S² × D) where S=16 slots and D=256 dims,
 //! far cheaper than token-level O(n²) in transformers.
+//!
+//! With [`SlotAttention::new_random_multihead`] the 256 dims are split
+//! into equal heads, each with its own softmax over the active slots;
+//! the per-head messages are concatenated back into 256 dims. The
+//! default is a single head.
 
 use crate::nn::{Linear, Rng};
 use volt_core::{VoltError, MAX_SLOTS, SLOT_DIM};
@@ -42,6 +51,9 @@ pub struct SlotAttention {
     wq: Linear,
     wk: Linear,
     wv: Linear,
+    /// Number of attention heads; `SLOT_DIM` is split evenly across them.
+    num_heads: usize,
+    /// Per-head scale `1/√(SLOT_DIM / num_heads)`.
     scale: f32,
     /// Optional additive attention bias indexed by slot position.
     /// `attention_bias[i][j]` is added to the pre-softmax logit
@@ -51,7 +63,11 @@ pub struct SlotAttention {
 
 impl std::fmt::Debug for SlotAttention {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "SlotAttention(dim={}, scale={:.4})", SLOT_DIM, self.scale)
+        write!(
+            f,
+            "SlotAttention(dim={}, heads={}, scale={:.4})",
+            SLOT_DIM, self.num_heads, self.scale
+        )
     }
 }
 
@@ -74,11 +90,49 @@ impl SlotAttention {
             wq: Linear::new_xavier(&mut rng, SLOT_DIM, SLOT_DIM),
             wk: Linear::new_xavier(&mut rng, SLOT_DIM, SLOT_DIM),
             wv: Linear::new_xavier(&mut rng, SLOT_DIM, SLOT_DIM),
+            num_heads: 1,
             scale: 1.0 / (SLOT_DIM as f32).sqrt(),
             attention_bias: None,
         }
     }
 
+    /// Creates a multi-head attention module with Xavier/Glorot random
+    /// initialization.
+    ///
+    /// The Q/K/V projections are the same as [`new_random`](Self::new_random)
+    /// for the same seed; the projected vectors are split into `num_heads`
+    /// contiguous chunks of `SLOT_DIM / num_heads` dims, each attending
+    /// independently. With `num_heads == 1` the output is identical to
+    /// [`new_random`](Self::new_random).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::Internal`] if `num_heads` is zero or does not
+    /// divide `SLOT_DIM`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::attention::SlotAttention;
+    ///
+    /// let attn = SlotAttention::new_random_multihead(42, 4).unwrap();
+    /// assert_eq!(attn.num_heads(), 4);
+    /// assert!(SlotAttention::new_random_multihead(42, 3).is_err());
+    /// ```
+    pub fn new_random_multihead(seed: u64, num_heads: usize) -> Result<Self, VoltError> {
+        if num_heads == 0 || !SLOT_DIM.is_multiple_of(num_heads) {
+            return Err(VoltError::Internal {
+                message: format!(
+                    "SlotAttention: {num_heads} heads do not evenly split {SLOT_DIM} dims"
+                ),
+            });
+        }
+        let mut attn = Self::new_random(seed);
+        attn.num_heads = num_heads;
+        attn.scale = 1.0 / ((SLOT_DIM / num_heads) as f32).sqrt();
+        Ok(attn)
+    }
+
     /// Creates a new attention module with Xavier/Glorot random initialization
     /// and an additive attention bias.
     ///
@@ -106,6 +160,7 @@ impl SlotAttention {
             wq: Linear::new_xavier(&mut rng, SLOT_DIM, SLOT_DIM),
             wk: Linear::new_xavier(&mut rng, SLOT_DIM, SLOT_DIM),
             wv: Linear::new_xavier(&mut rng, SLOT_DIM, SLOT_DIM),
+            num_heads: 1,
             scale: 1.0 / (SLOT_DIM as f32).sqrt(),
             attention_bias: Some(bias),
         }
@@ -115,7 +170,12 @@ impl SlotAttention {
     ///
     /// For each active slot, computes scaled dot-product attention over
     /// all active slots and returns the aggregated value vectors as messages.
-    /// Inactive slots (None) receive zero messages.
+    /// Each head attends over its own slice of dims; the attention bias,
+    /// if set, is added to every head's logits. Inactive slots (None)
+    /// are masked out of every softmax and receive zero messages. A query
+    /// whose logits are all `-inf` (every key masked, e.g. by an
+    /// attention bias of `f32::NEG_INFINITY`) also receives a zero
+    /// message rather than NaN.
     ///
     /// # Errors
     ///
@@ -141,7 +201,8 @@ impl SlotAttention {
     ) -> Result<[[f32; SLOT_DIM]; MAX_SLOTS], VoltError> {
         let mut messages = [[0.0f32; SLOT_DIM]; MAX_SLOTS];
 
-        // Collect active slot indices and their states
+        // Mask: only occupied slots act as queries and keys, so empty
+        // slots never enter the softmax denominator
         let active: Vec<(usize, &[f32; SLOT_DIM])> = states
             .iter()
             .enumerate()
@@ -157,40 +218,53 @@ impl SlotAttention {
         let ks: Vec<Vec<f32>> = active.iter().map(|(_, s)| self.wk.forward(*s)).collect();
         let vs: Vec<Vec<f32>> = active.iter().map(|(_, s)| self.wv.forward(*s)).collect();
 
-        // For each query slot, compute attention weights and aggregate values
+        // For each query slot and head, compute attention weights and
+        // aggregate that head's slice of the values
+        let head_dim = SLOT_DIM / self.num_heads;
         for (qi, &(slot_i, _)) in active.iter().enumerate() {
-            // Compute attention scores: Q_i · K_j / sqrt(d) + bias[i][j]
-            let mut scores = vec![0.0f32; active.len()];
-            for (kj, &(slot_j, _)) in active.iter().enumerate() {
-                let dot: f32 = qs[qi].iter().zip(ks[kj].iter()).map(|(a, b)| a * b).sum();
-                scores[kj] = dot * self.scale;
-                if let Some(ref bias) = self.attention_bias {
-                    scores[kj] += bias[slot_i][slot_j];
+            for head in 0..self.num_heads {
+                let dims = head * head_dim..(head + 1) * head_dim;
+                // Compute attention scores: Q_i · K_j / sqrt(d_head) + bias[i][j]
+                let mut scores = vec![0.0f32; active.len()];
+                for (kj, &(slot_j, _)) in active.iter().enumerate() {
+                    let dot: f32 = qs[qi][dims.clone()]
+                        .iter()
+                        .zip(ks[kj][dims.clone()].iter())
+                        .map(|(a, b)| a * b)
+                        .sum();
+                    scores[kj] = dot * self.scale;
+                    if let Some(ref bias) = self.attention_bias {
+                        scores[kj] += bias[slot_i][slot_j];
+                    }
                 }
-            }
 
-            // Softmax with numerical stability (subtract max)
-            let max_score = scores
-                .iter()
-                .cloned()
-                .fold(f32::NEG_INFINITY, f32::max);
-            let mut exp_sum = 0.0f32;
-            for s in &mut scores {
-                *s = (*s - max_score).exp();
-                exp_sum += *s;
-            }
-            if exp_sum < 1e-10 {
-                // All scores are -inf or zero; skip this slot
-                continue;
-            }
-            for s in &mut scores {
-                *s /= exp_sum;
-            }
+                // Softmax with numerical stability (subtract max)
+                let max_score = scores
+                    .iter()
+                    .cloned()
+                    .fold(f32::NEG_INFINITY, f32::max);
+                if max_score == f32::NEG_INFINITY {
+                    // Every key masked; -inf - -inf would be NaN
+                    continue;
+                }
+                let mut exp_sum = 0.0f32;
+                for s in &mut scores {
+                    *s = (*s - max_score).exp();
+                    exp_sum += *s;
+                }
+                if exp_sum < 1e-10 {
+                    // All scores are -inf or zero; skip this head
+                    continue;
+                }
+                for s in &mut scores {
+                    *s /= exp_sum;
+                }
 
-            // Weighted sum of values
-            for (vj, &weight) in scores.iter().enumerate() {
-                for d in 0..SLOT_DIM {
-                    messages[slot_i][d] += weight * vs[vj][d];
+                // Weighted sum of values
+                for (vj, &weight) in scores.iter().enumerate() {
+                    for d in dims.clone() {
+                        messages[slot_i][d] += weight * vs[vj][d];
+                    }
                 }
             }
         }
@@ -219,6 +293,20 @@ impl SlotAttention {
         (&self.wq, &self.wk, &self.wv)
     }
 
+    /// Returns the number of attention heads (1 unless built with
+    /// [`new_random_multihead`](Self::new_random_multihead)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::attention::SlotAttention;
+    ///
+    /// assert_eq!(SlotAttention::new_random(42).num_heads(), 1);
+    /// ```
+    pub fn num_heads(&self) -> usize {
+        self.num_heads
+    }
+
     /// Returns the attention bias matrix, if set.
     ///
     /// Used to inspect or verify the structural prior.
@@ -342,6 +430,84 @@ mod tests {
         assert!(debug.contains("dim=256"));
     }
 
+    #[test]
+    fn multihead_output_is_finite_and_shape_preserving() {
+        let attn = SlotAttention::new_random_multihead(42, 4).unwrap();
+        let mut states = [const { None }; MAX_SLOTS];
+        for (i, state) in states.iter_mut().take(3).enumerate() {
+            *state = Some(random_vector(i as u64 + 100));
+        }
+        let messages = attn.forward(&states).unwrap();
+        assert_eq!(messages.len(), MAX_SLOTS);
+        for (i, msg) in messages.iter().enumerate() {
+            assert!(msg.iter().all(|x| x.is_finite()));
+            assert_eq!(msg.iter().any(|&x| x != 0.0), i < 3, "slot {i}");
+        }
+        // Four independent softmaxes differ from one over all dims
+        let single = SlotAttention::new_random(42).forward(&states).unwrap();
+        assert_ne!(messages[0], single[0]);
+    }
+
+    #[test]
+    fn single_head_multihead_matches_single_head() {
+        let single = SlotAttention::new_random(42);
+        let multi = SlotAttention::new_random_multihead(42, 1).unwrap();
+        let mut states = [const { None }; MAX_SLOTS];
+        states[0] = Some(random_vector(100));
+        states[1] = Some(random_vector(200));
+        states[2] = Some(random_vector(300));
+        assert_eq!(single.forward(&states).unwrap(), multi.forward(&states).unwrap());
+    }
+
+    #[test]
+    fn multihead_rejects_uneven_split() {
+        assert!(SlotAttention::new_random_multihead(42, 0).is_err());
+        assert!(SlotAttention::new_random_multihead(42, 3).is_err());
+        assert!(SlotAttention::new_random_multihead(42, SLOT_DIM).is_ok());
+    }
+
+    #[test]
+    fn padded_frame_matches_dense_frame() {
+        let attn = SlotAttention::new_random(42);
+        let a = random_vector(100);
+        let b = random_vector(200);
+
+        let mut dense = [const { None }; MAX_SLOTS];
+        dense[0] = Some(a);
+        dense[1] = Some(b);
+        // Same two slots surrounded by empties
+        let mut padded = [const { None }; MAX_SLOTS];
+        padded[5] = Some(a);
+        padded[12] = Some(b);
+
+        let dense_msg = attn.forward(&dense).unwrap();
+        let padded_msg = attn.forward(&padded).unwrap();
+        for d in 0..SLOT_DIM {
+            assert!((dense_msg[0][d] - padded_msg[5][d]).abs() < 1e-6);
+            assert!((dense_msg[1][d] - padded_msg[12][d]).abs() < 1e-6);
+        }
+        for (i, msg) in padded_msg.iter().enumerate() {
+            if i != 5 && i != 12 {
+                assert!(msg.iter().all(|&x| x == 0.0), "slot {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn fully_masked_query_yields_zero_message() {
+        let mut bias = [[0.0f32; MAX_SLOTS]; MAX_SLOTS];
+        bias[0] = [f32::NEG_INFINITY; MAX_SLOTS];
+        let attn = SlotAttention::new_with_bias(42, bias);
+
+        let mut states = [const { None }; MAX_SLOTS];
+        states[0] = Some(random_vector(100));
+        states[1] = Some(random_vector(200));
+        let messages = attn.forward(&states).unwrap();
+        assert!(messages[0].iter().all(|&x| x == 0.0));
+        assert!(messages[1].iter().all(|x| x.is_finite()));
+        assert!(messages[1].iter().any(|&x| x != 0.0));
+    }
+
     #[test]
     fn new_random_has_no_bias() {
         let attn = SlotAttention::new_random(42);
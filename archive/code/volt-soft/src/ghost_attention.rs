@@ -89,12 +89,51 @@ pub fn forward_with_ghosts(
     ghost_gists: &[[f32; SLOT_DIM]],
     config: &GhostAttentionConfig,
 ) -> Result<[[f32; SLOT_DIM]; MAX_SLOTS], VoltError> {
+    forward_with_ghosts_influence(attention, states, ghost_gists, config)
+        .map(|(messages, _)| messages)
+}
+
+/// Like [`forward_with_ghosts`], but also reports how much the ghosts
+/// shaped the blended messages.
+///
+/// The influence is the ghost share of each active slot's blended
+/// message, `‖α·ghost_msg‖ / (‖(1-α)·slot_msg‖ + ‖α·ghost_msg‖)`,
+/// averaged over active slots. It lies in `[0, 1]` and is exactly 0.0
+/// when there are no ghosts, no active slots, or `alpha == 0.0`.
+///
+/// # Errors
+///
+/// Returns [`VoltError::Internal`] if any output message contains NaN or Inf.
+///
+/// # Example
+///
+/// ```
+/// use volt_soft::attention::SlotAttention;
+/// use volt_soft::ghost_attention::{forward_with_ghosts_influence, GhostAttentionConfig};
+/// use volt_core::{MAX_SLOTS, SLOT_DIM};
+///
+/// let attn = SlotAttention::new_random(42);
+/// let mut states = [const { None }; MAX_SLOTS];
+/// states[0] = Some([0.1_f32; SLOT_DIM]);
+/// let mut ghost = [0.0_f32; SLOT_DIM];
+/// ghost[0] = 1.0;
+///
+/// let config = GhostAttentionConfig { alpha: 0.5 };
+/// let (_, influence) = forward_with_ghosts_influence(&attn, &states, &[ghost], &config).unwrap();
+/// assert!(influence > 0.0 && influence <= 1.0);
+/// ```
+pub fn forward_with_ghosts_influence(
+    attention: &SlotAttention,
+    states: &[Option<[f32; SLOT_DIM]>; MAX_SLOTS],
+    ghost_gists: &[[f32; SLOT_DIM]],
+    config: &GhostAttentionConfig,
+) -> Result<([[f32; SLOT_DIM]; MAX_SLOTS], f32), VoltError> {
     // Step 1: Compute normal slot-to-slot attention
     let slot_messages = attention.forward(states)?;
 
     // Early exit if no ghosts or alpha is effectively zero
     if ghost_gists.is_empty() || config.alpha <= 0.0 {
-        return Ok(slot_messages);
+        return Ok((slot_messages, 0.0));
     }
 
     // Step 2: Compute ghost attention
@@ -109,7 +148,7 @@ pub fn forward_with_ghosts(
         .collect();
 
     if active.is_empty() {
-        return Ok(slot_messages);
+        return Ok((slot_messages, 0.0));
     }
 
     // Project slot queries
@@ -166,6 +205,18 @@ pub fn forward_with_ghosts(
         }
     }
 
+    // Ghost share of each active slot's blended message
+    let mut influence_sum = 0.0f32;
+    for &(slot_i, _) in &active {
+        let slot_norm = one_minus_alpha * l2_norm(&slot_messages[slot_i]);
+        let ghost_norm = alpha * l2_norm(&ghost_messages[slot_i]);
+        let total = slot_norm + ghost_norm;
+        if total > 1e-10 {
+            influence_sum += ghost_norm / total;
+        }
+    }
+    let influence = (influence_sum / active.len() as f32).clamp(0.0, 1.0);
+
     // Validate output
     for (i, msg) in blended.iter().enumerate() {
         if msg.iter().any(|x| !x.is_finite()) {
@@ -178,7 +229,11 @@ pub fn forward_with_ghosts(
         }
     }
 
-    Ok(blended)
+    Ok((blended, influence))
+}
+
+fn l2_norm(v: &[f32; SLOT_DIM]) -> f32 {
+    v.iter().map(|x| x * x).sum::<f32>().sqrt()
 }
 
 #[cfg(test)]
@@ -282,6 +337,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn influence_zero_without_ghosts_or_alpha() {
+        let attn = SlotAttention::new_random(42);
+        let states = make_states(2, 0.1);
+        let ghosts = [make_ghost_gist(0)];
+
+        let (_, none) =
+            forward_with_ghosts_influence(&attn, &states, &[], &GhostAttentionConfig { alpha: 0.5 })
+                .unwrap();
+        let (_, zero_alpha) =
+            forward_with_ghosts_influence(&attn, &states, &ghosts, &GhostAttentionConfig { alpha: 0.0 })
+                .unwrap();
+        assert_eq!(none, 0.0);
+        assert_eq!(zero_alpha, 0.0);
+    }
+
+    #[test]
+    fn influence_grows_with_alpha_and_stays_in_unit_range() {
+        let attn = SlotAttention::new_random(42);
+        let states = make_states(3, 0.1);
+        let ghosts = [make_ghost_gist(0), make_ghost_gist(128)];
+
+        let mut last = 0.0;
+        for alpha in [0.1, 0.5, 0.9, 1.0] {
+            let (_, influence) =
+                forward_with_ghosts_influence(&attn, &states, &ghosts, &GhostAttentionConfig { alpha })
+                    .unwrap();
+            assert!((0.0..=1.0).contains(&influence));
+            assert!(influence > last, "alpha {alpha}: {influence} <= {last}");
+            last = influence;
+        }
+        assert!((last - 1.0).abs() < 1e-6, "alpha 1.0 is ghost-only");
+    }
+
     #[test]
     fn alpha_one_gives_only_ghost_messages() {
         let attn = SlotAttention::new_random(42);
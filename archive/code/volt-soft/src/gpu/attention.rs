@@ -63,13 +63,25 @@ impl GpuSlotAttention {
 
     /// Creates a GPU attention module by loading weights from a CPU one.
     ///
+    /// The GPU module is single-head, so only single-head CPU modules
+    /// can be transferred.
+    ///
     /// # Errors
     ///
-    /// Returns [`VoltError::Internal`] if weight transfer fails.
+    /// Returns [`VoltError::Internal`] if `cpu_attn` has more than one
+    /// head or weight transfer fails.
     pub fn from_cpu_attention(
         cpu_attn: &crate::attention::SlotAttention,
         device: &Device,
     ) -> Result<Self, VoltError> {
+        if cpu_attn.num_heads() > 1 {
+            return Err(VoltError::Internal {
+                message: format!(
+                    "GpuSlotAttention is single-head; cannot load a {}-head CPU module",
+                    cpu_attn.num_heads()
+                ),
+            });
+        }
         let (q, k, v) = cpu_attn.projections();
 
         let wq = Self::cpu_to_candle_linear(q, device).map_err(|e| VoltError::Internal {
@@ -213,6 +225,13 @@ mod tests {
         assert!(flat.iter().all(|x| x.is_finite()));
     }
 
+    #[test]
+    fn from_cpu_attention_rejects_multihead() {
+        let cpu_attn = crate::attention::SlotAttention::new_random_multihead(43, 4).unwrap();
+        let err = GpuSlotAttention::from_cpu_attention(&cpu_attn, &Device::Cpu).unwrap_err();
+        assert!(matches!(err, VoltError::Internal { .. }), "{err}");
+    }
+
     #[test]
     fn from_cpu_attention_matches() {
         let cpu_attn = crate::attention::SlotAttention::new_random(43);
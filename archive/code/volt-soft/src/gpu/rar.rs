@@ -15,7 +15,7 @@ use volt_core::{TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
 use super::attention::GpuSlotAttention;
 use super::vfn::GpuVfn;
 use crate::diffusion;
-use crate::rar::{RarConfig, RarResult};
+use crate::rar::{sanitize_state, NonFinitePolicy, RarConfig, RarResult};
 
 /// Runs the GPU-accelerated RAR inference loop.
 ///
@@ -24,7 +24,8 @@ use crate::rar::{RarConfig, RarResult};
 ///
 /// # Errors
 ///
-/// Returns [`VoltError::FrameError`] if the configured resolution is out of range.
+/// Returns [`VoltError::FrameError`] if the configured resolution is out
+/// of range or [`RarConfig::dt_per_resolution`] holds an invalid step size.
 /// Returns [`VoltError::Internal`] if tensor operations fail.
 ///
 /// # Example
@@ -58,15 +59,7 @@ pub fn gpu_rar_loop(
     let device = vfn.device();
 
     // Validate config
-    if config.resolution >= volt_core::NUM_RESOLUTIONS {
-        return Err(VoltError::FrameError {
-            message: format!(
-                "RAR resolution {} out of range (max {})",
-                config.resolution,
-                volt_core::NUM_RESOLUTIONS,
-            ),
-        });
-    }
+    let dt = config.step_size()?;
 
     let mut frame = input.clone();
     let mut converged = [false; MAX_SLOTS];
@@ -87,12 +80,7 @@ pub fn gpu_rar_loop(
 
     // Early exit: no active slots
     if active_indices.is_empty() {
-        return Ok(RarResult {
-            frame,
-            iterations: 0,
-            converged,
-            final_deltas: deltas,
-        });
+        return Ok(RarResult::new(frame, 0, converged, deltas));
     }
 
     let map_err = |e: candle_core::Error| VoltError::Internal {
@@ -197,7 +185,7 @@ pub fn gpu_rar_loop(
                 let s = all_state_data[state_start + d];
                 let drift = drift_data[state_start + d];
                 let msg = msg_flat[state_start + d];
-                new_state[d] = s + config.dt * (drift + config.beta * msg);
+                new_state[d] = s + dt * (drift + config.beta * msg);
             }
 
             // Add diffusion noise if present
@@ -207,6 +195,12 @@ pub fn gpu_rar_loop(
                 }
             }
 
+            if !sanitize_state(&mut new_state, global_idx, iteration, config.on_nonfinite)? {
+                converged[global_idx] = true;
+                deltas[global_idx] = 0.0;
+                continue;
+            }
+
             // L2 normalize
             let norm: f32 = new_state.iter().map(|x| x * x).sum::<f32>().sqrt();
             if norm < 1e-10 {
@@ -244,12 +238,7 @@ pub fn gpu_rar_loop(
 
     frame.frame_meta.rar_iterations = iteration;
 
-    Ok(RarResult {
-        frame,
-        iterations: iteration,
-        converged,
-        final_deltas: deltas,
-    })
+    Ok(RarResult::new(frame, iteration, converged, deltas))
 }
 
 #[cfg(test)]
@@ -338,9 +327,12 @@ mod tests {
             epsilon: 0.01,
             max_iterations: 5,
             dt: 0.1,
+            dt_per_resolution: None,
             beta: 0.5,
             resolution: 0,
             diffusion: None,
+            on_nonfinite: NonFinitePolicy::Error,
+            adaptive_dt: false,
         };
 
         let mut frame = TensorFrame::new();
@@ -85,7 +85,10 @@ impl GpuVfn {
     ///
     /// # Errors
     ///
-    /// Returns [`VoltError::Internal`] if weight transfer fails.
+    /// Returns [`VoltError::Internal`] if weight transfer fails, or if the
+    /// CPU VFN uses an activation other than ReLU, layer normalization,
+    /// or a shape other than 256→512→512→256 (the GPU path supports only
+    /// the default VFN).
     ///
     /// # Example
     ///
@@ -101,7 +104,33 @@ impl GpuVfn {
         cpu_vfn: &crate::vfn::Vfn,
         device: &Device,
     ) -> Result<Self, VoltError> {
-        let (l1, l2, l3) = cpu_vfn.layers();
+        let activation = cpu_vfn.config().activation;
+        if activation != crate::vfn::Activation::Relu {
+            return Err(VoltError::Internal {
+                message: format!(
+                    "GpuVfn from_cpu_vfn: unsupported activation {activation:?} (GPU path is ReLU-only)"
+                ),
+            });
+        }
+
+        if cpu_vfn.config().layer_norm {
+            return Err(VoltError::Internal {
+                message: "GpuVfn from_cpu_vfn: layer normalization is not supported on the GPU path"
+                    .to_string(),
+            });
+        }
+
+        let unsupported_shape = || VoltError::Internal {
+            message: format!(
+                "GpuVfn from_cpu_vfn: unsupported shape {cpu_vfn:?} (GPU path is {SLOT_DIM}→{HIDDEN_DIM}→{HIDDEN_DIM}→{SLOT_DIM} only)"
+            ),
+        };
+        let [l1, l2, l3] = cpu_vfn.layers() else {
+            return Err(unsupported_shape());
+        };
+        if l2.in_dim() != HIDDEN_DIM || l2.out_dim() != HIDDEN_DIM {
+            return Err(unsupported_shape());
+        }
 
         let layer1 = Self::cpu_linear_to_candle(l1, device)?;
         let layer2 = Self::cpu_linear_to_candle(l2, device)?;
@@ -14,6 +14,7 @@
 //! - [`rar::rar_loop`]: The RAR inference loop orchestrator
 //! - [`rar::RarConfig`] — Configuration (epsilon, dt, beta, budget)
 //! - [`rar::RarResult`] — Output frame + convergence diagnostics
+//! - [`rar::NonFinitePolicy`] — Handling for NaN/Inf slot states
 //!
 //! ## Architecture Rules
 //!
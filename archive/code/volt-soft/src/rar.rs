@@ -11,12 +11,16 @@
 //!    then L2-normalize. Check per-slot convergence: `‖ΔS‖ < ε`.
 //!
 //! The loop terminates when all slots converge OR the iteration budget
-//! is exhausted.
+//! is exhausted. With [`RarConfig::adaptive_dt`] set, `dt` is halved
+//! whenever the per-iteration delta grows; the deltas are reported in
+//! [`RarResult::delta_history`]. [`RarConfig::dt_per_resolution`] gives
+//! each resolution its own starting `dt`.
 
 use crate::attention::SlotAttention;
 use crate::diffusion::{self, DiffusionConfig};
 use crate::ghost_attention::{self, GhostAttentionConfig};
 use crate::vfn::Vfn;
+use volt_bus::codebook::Codebook;
 use volt_core::{TensorFrame, VoltError, MAX_SLOTS, NUM_RESOLUTIONS, SLOT_DIM};
 
 /// Configuration for the RAR inference loop.
@@ -41,6 +45,12 @@ pub struct RarConfig {
     /// Step size for state updates: `S += dt × (drift + β·msg)`.
     pub dt: f32,
 
+    /// Step size for each resolution, overriding `dt` for the resolution
+    /// the loop runs on (e.g. fast coarse settling at R₀, slow fine
+    /// refinement at R₃). Every entry must be in `(0, 1]`. `None` uses
+    /// `dt` at every resolution.
+    pub dt_per_resolution: Option<[f32; NUM_RESOLUTIONS]>,
+
     /// Weight for attention messages: `β` in the update rule.
     pub beta: f32,
 
@@ -50,6 +60,38 @@ pub struct RarConfig {
     /// Optional diffusion noise injection configuration.
     /// `None` disables noise (backward compatible with Milestone 2.3).
     pub diffusion: Option<DiffusionConfig>,
+
+    /// What to do when a slot's updated state contains NaN or Inf.
+    pub on_nonfinite: NonFinitePolicy,
+
+    /// Halve `dt` whenever an iteration's convergence delta is larger
+    /// than the previous one (the loop is oscillating). The reduced step
+    /// size is kept for the rest of the loop. `false` keeps `dt` fixed.
+    pub adaptive_dt: bool,
+}
+
+/// Handling for non-finite values in a slot's updated RAR state.
+///
+/// Checked every iteration on `S + dt × (drift + β·msg) + noise`, before
+/// normalization, so a bad drift, attention message, or noise sample is
+/// caught in the iteration that produced it.
+///
+/// # Example
+///
+/// ```
+/// use volt_soft::rar::{NonFinitePolicy, RarConfig};
+///
+/// assert_eq!(RarConfig::default().on_nonfinite, NonFinitePolicy::Error);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFinitePolicy {
+    /// Abort with [`VoltError::Internal`] naming the slot and iteration.
+    #[default]
+    Error,
+    /// Replace NaN with 0 and ±Inf with ±1, then normalize as usual.
+    Clamp,
+    /// Keep the slot's previous state and freeze it as converged.
+    Revert,
 }
 
 impl Default for RarConfig {
@@ -58,13 +100,63 @@ impl Default for RarConfig {
             epsilon: 0.001,
             max_iterations: 50,
             dt: 0.1,
+            dt_per_resolution: None,
             beta: 0.5,
             resolution: 0,
             diffusion: None,
+            on_nonfinite: NonFinitePolicy::Error,
+            adaptive_dt: false,
         }
     }
 }
 
+impl RarConfig {
+    /// The starting step size for [`RarConfig::resolution`]: its entry in
+    /// [`RarConfig::dt_per_resolution`] if set, otherwise `dt`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::FrameError`] if the resolution is out of range
+    /// or any per-resolution step size is outside `(0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::rar::RarConfig;
+    ///
+    /// let config = RarConfig {
+    ///     dt_per_resolution: Some([0.2, 0.1, 0.05, 0.02]),
+    ///     resolution: 2,
+    ///     ..RarConfig::default()
+    /// };
+    /// assert_eq!(config.step_size().unwrap(), 0.05);
+    /// assert_eq!(RarConfig::default().step_size().unwrap(), 0.1);
+    /// ```
+    pub fn step_size(&self) -> Result<f32, VoltError> {
+        if self.resolution >= NUM_RESOLUTIONS {
+            return Err(VoltError::FrameError {
+                message: format!(
+                    "RAR resolution {} out of range (max {})",
+                    self.resolution, NUM_RESOLUTIONS
+                ),
+            });
+        }
+        let Some(steps) = &self.dt_per_resolution else {
+            return Ok(self.dt);
+        };
+        if let Some((r, dt)) = steps
+            .iter()
+            .enumerate()
+            .find(|(_, dt)| !(**dt > 0.0 && **dt <= 1.0))
+        {
+            return Err(VoltError::FrameError {
+                message: format!("RAR step size for R{r} must be in (0, 1], got {dt}"),
+            });
+        }
+        Ok(steps[self.resolution])
+    }
+}
+
 /// Result of a RAR inference loop execution.
 ///
 /// Contains the evolved frame plus diagnostic information about
@@ -93,6 +185,128 @@ pub struct RarResult {
     /// Per-slot final delta (‖S(t) - S(t-1)‖) at the last iteration.
     /// 0.0 for empty/inactive slots.
     pub final_deltas: [f32; MAX_SLOTS],
+
+    /// The semantic attractor the frame settled into: the superposed,
+    /// L2-normalized R₀ gist of `frame` (see [`attractor_gist`]).
+    /// Computed whether or not the loop converged; all zeros if the
+    /// frame has no usable R₀ data.
+    pub attractor_gist: [f32; SLOT_DIM],
+
+    /// How much ghost gists shaped the Attend phase: the ghost share of
+    /// the blended attention messages, averaged over active slots and
+    /// iterations (see
+    /// [`forward_with_ghosts_influence`](ghost_attention::forward_with_ghosts_influence)).
+    /// In `[0, 1]`; 0.0 without ghosts, with `alpha == 0.0`, and for
+    /// the ghost-free loops.
+    pub ghost_influence: f32,
+
+    /// Convergence delta of each iteration, in order: the largest
+    /// ‖S(t) - S(t-1)‖ among the slots refined in that iteration.
+    /// One entry per iteration performed (empty if the loop exited
+    /// before the first iteration).
+    pub delta_history: Vec<f32>,
+}
+
+impl RarResult {
+    /// Builds a result, deriving `attractor_gist` from the final frame.
+    pub(crate) fn new(
+        frame: TensorFrame,
+        iterations: u32,
+        converged: [bool; MAX_SLOTS],
+        final_deltas: [f32; MAX_SLOTS],
+    ) -> Self {
+        let attractor_gist = attractor_gist(&frame);
+        Self {
+            frame,
+            iterations,
+            converged,
+            final_deltas,
+            attractor_gist,
+            ghost_influence: 0.0,
+            delta_history: Vec::new(),
+        }
+    }
+
+    /// Classifies the attractor by its nearest codebook entry.
+    ///
+    /// Returns the entry ID and its cosine similarity to
+    /// [`Self::attractor_gist`], or `None` if the gist is all zeros or
+    /// the lookup fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_bus::codebook::Codebook;
+    /// use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
+    /// use volt_soft::attention::SlotAttention;
+    /// use volt_soft::rar::{rar_loop, RarConfig};
+    /// use volt_soft::vfn::Vfn;
+    ///
+    /// std::thread::Builder::new().stack_size(8 * 1024 * 1024).spawn(|| {
+    ///     let mut frame = TensorFrame::new();
+    ///     let mut slot = SlotData::new(SlotRole::Agent);
+    ///     slot.write_resolution(0, [0.1; SLOT_DIM]);
+    ///     frame.write_slot(0, slot).unwrap();
+    ///
+    ///     let config = RarConfig { max_iterations: 3, ..RarConfig::default() };
+    ///     let result = rar_loop(&frame, &Vfn::new_random(1), &SlotAttention::new_random(2), &config).unwrap();
+    ///
+    ///     let mut a = [0.0; SLOT_DIM];
+    ///     a[0] = 1.0;
+    ///     let codebook = Codebook::from_entries(vec![a, result.attractor_gist]).unwrap();
+    ///     let (id, sim) = result.classify_attractor(&codebook).unwrap();
+    ///     assert_eq!(id, 1);
+    ///     assert!(sim > 0.99);
+    /// }).unwrap().join().unwrap();
+    /// ```
+    pub fn classify_attractor(&self, codebook: &Codebook) -> Option<(u16, f32)> {
+        let (id, entry) = codebook.quantize(&self.attractor_gist).ok()?;
+        Some((id, volt_bus::similarity(&self.attractor_gist, &entry)))
+    }
+}
+
+/// Computes the R₀ gist of a frame: the superposition of every active
+/// slot's R₀ vector, L2-normalized.
+///
+/// Matches `volt_db::gist::extract_gist`, except that frames with no
+/// usable R₀ data yield all zeros instead of `None`.
+///
+/// # Example
+///
+/// ```
+/// use volt_core::{SlotData, SlotRole, TensorFrame, SLOT_DIM};
+/// use volt_soft::rar::attractor_gist;
+///
+/// assert_eq!(attractor_gist(&TensorFrame::new()), [0.0; SLOT_DIM]);
+///
+/// let mut frame = TensorFrame::new();
+/// let mut slot = SlotData::new(SlotRole::Agent);
+/// slot.write_resolution(0, [0.5; SLOT_DIM]);
+/// frame.write_slot(0, slot).unwrap();
+/// let gist = attractor_gist(&frame);
+/// let norm: f32 = gist.iter().map(|x| x * x).sum::<f32>().sqrt();
+/// assert!((norm - 1.0).abs() < 1e-5);
+/// ```
+pub fn attractor_gist(frame: &TensorFrame) -> [f32; SLOT_DIM] {
+    let r0_vecs: Vec<&[f32; SLOT_DIM]> = frame
+        .slots
+        .iter()
+        .filter_map(|s| s.as_ref())
+        .filter_map(|slot| slot.resolutions[0].as_ref())
+        .collect();
+
+    match r0_vecs.as_slice() {
+        [] => [0.0; SLOT_DIM],
+        [single] => {
+            let norm: f32 = single.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm < 1e-10 {
+                [0.0; SLOT_DIM]
+            } else {
+                single.map(|x| x / norm)
+            }
+        }
+        _ => volt_bus::superpose(&r0_vecs).unwrap_or([0.0; SLOT_DIM]),
+    }
 }
 
 /// Runs the Root-Attend-Refine inference loop on a TensorFrame.
@@ -104,8 +318,10 @@ pub struct RarResult {
 ///
 /// # Errors
 ///
-/// Returns [`VoltError::FrameError`] if the configured resolution is out of range.
-/// Returns [`VoltError::Internal`] if numerical issues occur during inference.
+/// Returns [`VoltError::FrameError`] if the configured resolution is out
+/// of range or [`RarConfig::dt_per_resolution`] holds an invalid step size.
+/// Returns [`VoltError::Internal`] if numerical issues occur during inference,
+/// including a non-finite slot state under [`NonFinitePolicy::Error`].
 ///
 /// # Example
 ///
@@ -133,14 +349,7 @@ pub fn rar_loop(
     config: &RarConfig,
 ) -> Result<RarResult, VoltError> {
     // Validate config
-    if config.resolution >= NUM_RESOLUTIONS {
-        return Err(VoltError::FrameError {
-            message: format!(
-                "RAR resolution {} out of range (max {})",
-                config.resolution, NUM_RESOLUTIONS
-            ),
-        });
-    }
+    let initial_dt = config.step_size()?;
 
     let mut frame = input.clone();
     let mut converged = [false; MAX_SLOTS];
@@ -159,15 +368,12 @@ pub fn rar_loop(
 
     // Early exit: no active slots
     if converged.iter().all(|&c| c) {
-        return Ok(RarResult {
-            frame,
-            iterations: 0,
-            converged,
-            final_deltas: deltas,
-        });
+        return Ok(RarResult::new(frame, 0, converged, deltas));
     }
 
     let mut iteration = 0;
+    let mut dt = initial_dt;
+    let mut delta_history = Vec::new();
 
     while iteration < config.max_iterations {
         // Check if all slots converged
@@ -223,6 +429,7 @@ pub fn rar_loop(
         };
 
         // === REFINE PHASE ===
+        let mut iteration_delta = 0.0f32;
         for i in 0..MAX_SLOTS {
             if converged[i] {
                 continue;
@@ -233,7 +440,7 @@ pub fn rar_loop(
                 // State update: S_i(t+1) = S_i(t) + dt × (drift + β·msg) + noise
                 let mut new_state = [0.0f32; SLOT_DIM];
                 for d in 0..SLOT_DIM {
-                    new_state[d] = state[d] + config.dt * (drift[d] + config.beta * msg[d]);
+                    new_state[d] = state[d] + dt * (drift[d] + config.beta * msg[d]);
                 }
 
                 // Add diffusion noise if present
@@ -245,6 +452,13 @@ pub fn rar_loop(
                     }
                 }
 
+                if !sanitize_state(&mut new_state, i, iteration, config.on_nonfinite)? {
+                    // Reverted: the frame still holds the previous state
+                    converged[i] = true;
+                    deltas[i] = 0.0;
+                    continue;
+                }
+
                 // L2 normalize to unit hypersphere
                 let norm: f32 = new_state.iter().map(|x| x * x).sum::<f32>().sqrt();
                 if norm < 1e-10 {
@@ -264,6 +478,7 @@ pub fn rar_loop(
                     .sum::<f32>()
                     .sqrt();
                 deltas[i] = delta;
+                iteration_delta = iteration_delta.max(delta);
 
                 if delta < config.epsilon {
                     converged[i] = true;
@@ -275,17 +490,62 @@ pub fn rar_loop(
                 }
             }
         }
+
+        dt = next_dt(config, dt, delta_history.last().copied(), iteration_delta);
+        delta_history.push(iteration_delta);
     }
 
     // Update frame metadata with iteration count
     frame.frame_meta.rar_iterations = iteration;
 
-    Ok(RarResult {
-        frame,
-        iterations: iteration,
-        converged,
-        final_deltas: deltas,
-    })
+    let mut result = RarResult::new(frame, iteration, converged, deltas);
+    result.delta_history = delta_history;
+    Ok(result)
+}
+
+/// Step size for the next iteration under [`RarConfig::adaptive_dt`]:
+/// halved when `delta` grew since the `previous` iteration, unchanged
+/// otherwise or when adaptation is off.
+fn next_dt(config: &RarConfig, dt: f32, previous: Option<f32>, delta: f32) -> f32 {
+    if config.adaptive_dt && previous.is_some_and(|prev| delta > prev) {
+        dt * 0.5
+    } else {
+        dt
+    }
+}
+
+/// Applies `policy` to a slot's un-normalized updated state.
+///
+/// Returns `Ok(true)` if `state` may be used (possibly after clamping)
+/// and `Ok(false)` if the slot should keep its previous state.
+pub(crate) fn sanitize_state(
+    state: &mut [f32; SLOT_DIM],
+    slot: usize,
+    iteration: u32,
+    policy: NonFinitePolicy,
+) -> Result<bool, VoltError> {
+    let Some(dim) = state.iter().position(|x| !x.is_finite()) else {
+        return Ok(true);
+    };
+    match policy {
+        NonFinitePolicy::Error => Err(VoltError::Internal {
+            message: format!(
+                "RAR iteration {iteration}: slot {slot} state is non-finite ({} at dim {dim})",
+                state[dim]
+            ),
+        }),
+        NonFinitePolicy::Clamp => {
+            for x in state.iter_mut() {
+                if x.is_nan() {
+                    *x = 0.0;
+                } else if x.is_infinite() {
+                    *x = x.signum();
+                }
+            }
+            Ok(true)
+        }
+        NonFinitePolicy::Revert => Ok(false),
+    }
 }
 
 /// Configuration for ghost frame cross-attention in the RAR loop.
@@ -326,8 +586,10 @@ pub struct GhostConfig {
 ///
 /// # Errors
 ///
-/// Returns [`VoltError::FrameError`] if the configured resolution is out of range.
-/// Returns [`VoltError::Internal`] if numerical issues occur during inference.
+/// Returns [`VoltError::FrameError`] if the configured resolution is out
+/// of range or [`RarConfig::dt_per_resolution`] holds an invalid step size.
+/// Returns [`VoltError::Internal`] if numerical issues occur during inference,
+/// including a non-finite slot state under [`NonFinitePolicy::Error`].
 ///
 /// # Example
 ///
@@ -357,14 +619,7 @@ pub fn rar_loop_with_ghosts(
     ghost_config: &GhostConfig,
 ) -> Result<RarResult, VoltError> {
     // Validate config
-    if config.resolution >= NUM_RESOLUTIONS {
-        return Err(VoltError::FrameError {
-            message: format!(
-                "RAR resolution {} out of range (max {})",
-                config.resolution, NUM_RESOLUTIONS
-            ),
-        });
-    }
+    let initial_dt = config.step_size()?;
 
     let ghost_attn_config = GhostAttentionConfig {
         alpha: ghost_config.alpha,
@@ -386,15 +641,13 @@ pub fn rar_loop_with_ghosts(
 
     // Early exit: no active slots
     if converged.iter().all(|&c| c) {
-        return Ok(RarResult {
-            frame,
-            iterations: 0,
-            converged,
-            final_deltas: deltas,
-        });
+        return Ok(RarResult::new(frame, 0, converged, deltas));
     }
 
     let mut iteration = 0;
+    let mut influence_sum = 0.0f32;
+    let mut dt = initial_dt;
+    let mut delta_history = Vec::new();
 
     while iteration < config.max_iterations {
         // Check if all slots converged
@@ -423,8 +676,13 @@ pub fn rar_loop_with_ghosts(
         }
 
         // === ATTEND PHASE (with ghost frames) ===
-        let messages =
-            ghost_attention::forward_with_ghosts(attention, &states, &ghost_config.gists, &ghost_attn_config)?;
+        let (messages, influence) = ghost_attention::forward_with_ghosts_influence(
+            attention,
+            &states,
+            &ghost_config.gists,
+            &ghost_attn_config,
+        )?;
+        influence_sum += influence;
 
         // === DIFFUSION NOISE ===
         let noise_vectors = if let Some(ref diff_config) = config.diffusion {
@@ -447,6 +705,7 @@ pub fn rar_loop_with_ghosts(
         };
 
         // === REFINE PHASE ===
+        let mut iteration_delta = 0.0f32;
         for i in 0..MAX_SLOTS {
             if converged[i] {
                 continue;
@@ -456,7 +715,7 @@ pub fn rar_loop_with_ghosts(
 
                 let mut new_state = [0.0f32; SLOT_DIM];
                 for d in 0..SLOT_DIM {
-                    new_state[d] = state[d] + config.dt * (drift[d] + config.beta * msg[d]);
+                    new_state[d] = state[d] + dt * (drift[d] + config.beta * msg[d]);
                 }
 
                 if let Some(ref noise_arr) = noise_vectors
@@ -467,6 +726,12 @@ pub fn rar_loop_with_ghosts(
                     }
                 }
 
+                if !sanitize_state(&mut new_state, i, iteration, config.on_nonfinite)? {
+                    converged[i] = true;
+                    deltas[i] = 0.0;
+                    continue;
+                }
+
                 let norm: f32 = new_state.iter().map(|x| x * x).sum::<f32>().sqrt();
                 if norm < 1e-10 {
                     converged[i] = true;
@@ -483,6 +748,7 @@ pub fn rar_loop_with_ghosts(
                     .sum::<f32>()
                     .sqrt();
                 deltas[i] = delta;
+                iteration_delta = iteration_delta.max(delta);
 
                 if delta < config.epsilon {
                     converged[i] = true;
@@ -493,16 +759,19 @@ pub fn rar_loop_with_ghosts(
                 }
             }
         }
+
+        dt = next_dt(config, dt, delta_history.last().copied(), iteration_delta);
+        delta_history.push(iteration_delta);
     }
 
     frame.frame_meta.rar_iterations = iteration;
 
-    Ok(RarResult {
-        frame,
-        iterations: iteration,
-        converged,
-        final_deltas: deltas,
-    })
+    let mut result = RarResult::new(frame, iteration, converged, deltas);
+    result.delta_history = delta_history;
+    if iteration > 0 {
+        result.ghost_influence = (influence_sum / iteration as f32).clamp(0.0, 1.0);
+    }
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -532,6 +801,156 @@ mod tests {
         v
     }
 
+    fn make_multi_slot_frame() -> TensorFrame {
+        let mut frame = TensorFrame::new();
+        for (i, role) in [SlotRole::Agent, SlotRole::Predicate, SlotRole::Patient]
+            .into_iter()
+            .enumerate()
+        {
+            let mut slot = volt_core::SlotData::new(role);
+            slot.write_resolution(0, normalized_vector(100 + i as u64));
+            frame.write_slot(i, slot).unwrap();
+        }
+        frame
+    }
+
+    #[test]
+    fn delta_history_records_each_iteration() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let vfn = make_vfn();
+                let attn = make_attention();
+                let frame = make_multi_slot_frame();
+                let fixed = RarConfig {
+                    epsilon: 1e-10,
+                    max_iterations: 30,
+                    dt: 4.0,
+                    ..RarConfig::default()
+                };
+                let adaptive = RarConfig {
+                    adaptive_dt: true,
+                    ..fixed.clone()
+                };
+
+                let fixed_result = rar_loop(&frame, &vfn, &attn, &fixed).unwrap();
+                let adaptive_result = rar_loop(&frame, &vfn, &attn, &adaptive).unwrap();
+                let fixed_history = &fixed_result.delta_history;
+                let adaptive_history = &adaptive_result.delta_history;
+                assert_eq!(fixed_history.len(), fixed_result.iterations as usize);
+                assert_eq!(adaptive_history.len(), adaptive_result.iterations as usize);
+                let max_final = fixed_result.final_deltas.iter().copied().fold(0.0, f32::max);
+                assert_eq!(*fixed_history.last().unwrap(), max_final);
+
+                // Identical until the first increase, after which dt is halved.
+                let first_rise = fixed_history
+                    .windows(2)
+                    .position(|w| w[1] > w[0])
+                    .expect("dt = 4.0 should oscillate at least once")
+                    + 1;
+                assert_eq!(
+                    &adaptive_history[..=first_rise],
+                    &fixed_history[..=first_rise]
+                );
+                assert!(adaptive_history[first_rise + 1] < fixed_history[first_rise + 1]);
+
+                let rises = |h: &[f32]| h.windows(2).filter(|w| w[1] > w[0]).count();
+                assert!(rises(adaptive_history) <= rises(fixed_history));
+                assert!(adaptive_history.last().unwrap() < fixed_history.last().unwrap());
+
+                // The ghost loop adapts the same way.
+                let ghosts = GhostConfig { gists: Vec::new(), alpha: 0.1 };
+                let ghost_result =
+                    rar_loop_with_ghosts(&frame, &vfn, &attn, &adaptive, &ghosts).unwrap();
+                assert_eq!(&ghost_result.delta_history, adaptive_history);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn per_resolution_step_sizes_set_each_resolution_pace() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let vfn = make_vfn();
+                let attn = make_attention();
+                let mut frame = make_multi_slot_frame();
+                for i in 0..3 {
+                    frame.slots[i].as_mut().unwrap().resolutions[1] =
+                        Some(normalized_vector(200 + i as u64));
+                }
+                let run = |resolution: usize, dt: f32, per_res: Option<[f32; NUM_RESOLUTIONS]>| {
+                    let config = RarConfig {
+                        epsilon: 1e-4,
+                        max_iterations: 40,
+                        dt,
+                        dt_per_resolution: per_res,
+                        resolution,
+                        ..RarConfig::default()
+                    };
+                    rar_loop(&frame, &vfn, &attn, &config).unwrap()
+                };
+
+                // A uniform array behaves exactly like the scalar dt.
+                let uniform = [0.1; NUM_RESOLUTIONS];
+                for resolution in [0, 1] {
+                    assert_eq!(
+                        run(resolution, 0.1, Some(uniform)).delta_history,
+                        run(resolution, 0.1, None).delta_history
+                    );
+                }
+
+                // Fast coarse settling, slow fine refinement.
+                let differentiated = [0.4, 0.02, 0.1, 0.1];
+                let coarse = run(0, 0.1, Some(differentiated));
+                let fine = run(1, 0.1, Some(differentiated));
+                assert_eq!(coarse.delta_history, run(0, 0.4, None).delta_history);
+                assert_eq!(fine.delta_history, run(1, 0.02, None).delta_history);
+                assert!(coarse.delta_history[0] > run(0, 0.1, None).delta_history[0]);
+                assert!(fine.delta_history[0] < run(1, 0.1, None).delta_history[0]);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn invalid_per_resolution_step_sizes_rejected() {
+        let vfn = make_vfn();
+        let attn = make_attention();
+        let frame = make_multi_slot_frame();
+        for steps in [[0.1, 0.0, 0.1, 0.1], [0.1, 0.1, 1.5, 0.1], [f32::NAN, 0.1, 0.1, 0.1]] {
+            let config = RarConfig {
+                dt_per_resolution: Some(steps),
+                ..RarConfig::default()
+            };
+            assert!(config.step_size().is_err());
+            assert!(matches!(
+                rar_loop(&frame, &vfn, &attn, &config),
+                Err(VoltError::FrameError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn multihead_attention_drives_rar_loop() {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                let vfn = make_vfn();
+                let attn = SlotAttention::new_random_multihead(43, 4).unwrap();
+                let frame = make_multi_slot_frame();
+                let result = rar_loop(&frame, &vfn, &attn, &RarConfig::default()).unwrap();
+                assert!(result.iterations > 0);
+                assert!(result.final_deltas.iter().all(|d| d.is_finite()));
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn empty_frame_converges_immediately() {
         let vfn = make_vfn();
@@ -542,6 +961,7 @@ mod tests {
         let result = rar_loop(&frame, &vfn, &attn, &config).unwrap();
         assert_eq!(result.iterations, 0);
         assert!(result.converged.iter().all(|&c| c));
+        assert!(result.delta_history.is_empty());
     }
 
     #[test]
@@ -785,4 +1205,50 @@ mod tests {
             norm
         );
     }
+
+    #[test]
+    fn ghost_influence_tracks_alpha() {
+        let vfn = make_vfn();
+        let attn = make_attention();
+        let config = RarConfig {
+            max_iterations: 5,
+            ..RarConfig::default()
+        };
+
+        let mut frame = TensorFrame::new();
+        frame
+            .write_at(0, 0, SlotRole::Agent, normalized_vector(100))
+            .unwrap();
+        frame
+            .write_at(1, 0, SlotRole::Predicate, normalized_vector(200))
+            .unwrap();
+        let gists = vec![normalized_vector(300), normalized_vector(400)];
+
+        let run = |alpha: f32| {
+            let ghost_config = GhostConfig {
+                gists: gists.clone(),
+                alpha,
+            };
+            rar_loop_with_ghosts(&frame, &vfn, &attn, &config, &ghost_config)
+                .unwrap()
+                .ghost_influence
+        };
+        let zero = run(0.0);
+        let strong = run(0.9);
+
+        assert_eq!(zero, 0.0);
+        assert!(strong > 0.5, "strong alpha influence {strong}");
+        assert!(strong <= 1.0);
+
+        let no_ghosts = rar_loop_with_ghosts(
+            &frame,
+            &vfn,
+            &attn,
+            &config,
+            &GhostConfig { gists: vec![], alpha: 0.9 },
+        )
+        .unwrap();
+        assert_eq!(no_ghosts.ghost_influence, 0.0);
+        assert_eq!(rar_loop(&frame, &vfn, &attn, &config).unwrap().ghost_influence, 0.0);
+    }
 }
@@ -12,15 +12,183 @@
 //! - Linear(512 → 512) + ReLU
 //! - Linear(512 → 256), no activation
 //!
+//! [`Vfn::new_random_with_shape`] builds other depths and widths (still
+//! 256 in and out) for experimentation; checkpoints record the layer
+//! count, so [`Vfn::load`] restores whatever shape was saved.
+//!
+//! The hidden activation is configurable through [`VfnConfig`]; ReLU is
+//! the default. [`Activation::LeakyRelu`] and [`Activation::Gelu`] keep a
+//! non-zero gradient for negative pre-activations, avoiding dead units.
+//!
+//! [`VfnConfig`] also carries optional layer normalization of hidden
+//! layers, applied in every forward pass, and seeded dropout, which only
+//! [`Vfn::forward_layer_train`] applies. Inference never drops units.
+//!
 //! Weights are randomly initialized (Xavier/Glorot). Training comes in
 //! Milestone 2.4 (Flow Matching on GPU).
 
 use crate::nn::{Linear, Rng};
 use volt_core::{VoltError, SLOT_DIM};
 
-/// Hidden dimension for the VFN's intermediate layers.
+/// Hidden dimension for the default VFN's intermediate layers.
 const HIDDEN_DIM: usize = 512;
 
+/// Current checkpoint format version. Version 1 had no activation
+/// field and always loads as [`Activation::Relu`]; versions before 3
+/// had no layer count and always hold the default three layers;
+/// versions before 4 had no layer-norm flag and load without it.
+const CHECKPOINT_VERSION: u32 = 4;
+
+/// Layer count of checkpoints written before version 3.
+const LEGACY_LAYER_COUNT: usize = 3;
+
+/// Variance floor for layer normalization of hidden layers.
+const LAYER_NORM_EPSILON: f32 = 1e-5;
+
+/// Normalizes `values` in place to zero mean and unit variance.
+fn layer_normalize(values: &mut [f32]) {
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    let var = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f32>() / n;
+    let inv_std = 1.0 / (var + LAYER_NORM_EPSILON).sqrt();
+    for x in values {
+        *x = (*x - mean) * inv_std;
+    }
+}
+
+/// `sqrt(2 / π)` and the cubic coefficient of the GELU tanh approximation.
+const GELU_SQRT_2_OVER_PI: f32 = 0.797_884_6;
+const GELU_CUBIC: f32 = 0.044_715;
+
+/// Activation applied after each hidden layer of the VFN.
+///
+/// # Example
+///
+/// ```
+/// use volt_soft::vfn::Activation;
+///
+/// assert_eq!(Activation::Relu.apply(-1.0), 0.0);
+/// assert_eq!(Activation::LeakyRelu(0.1).apply(-1.0), -0.1);
+/// assert!(Activation::Gelu.apply(-1.0) < 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Activation {
+    /// `max(0, x)`. Zero input gives zero output.
+    #[default]
+    Relu,
+    /// `x` for positive inputs, `slope * x` otherwise.
+    LeakyRelu(f32),
+    /// Gaussian Error Linear Unit (tanh approximation).
+    Gelu,
+}
+
+impl Activation {
+    /// Applies the activation to a single value.
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            Activation::Relu => x.max(0.0),
+            Activation::LeakyRelu(slope) => {
+                if x > 0.0 {
+                    x
+                } else {
+                    slope * x
+                }
+            }
+            Activation::Gelu => {
+                0.5 * x * (1.0 + (GELU_SQRT_2_OVER_PI * (x + GELU_CUBIC * x * x * x)).tanh())
+            }
+        }
+    }
+
+    /// Derivative of the activation at pre-activation `x`, for backward
+    /// passes. ReLU's derivative at 0 is taken as 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::vfn::Activation;
+    ///
+    /// assert_eq!(Activation::Relu.derivative(-1.0), 0.0);
+    /// assert_eq!(Activation::LeakyRelu(0.1).derivative(-1.0), 0.1);
+    /// assert!((Activation::Gelu.derivative(0.0) - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn derivative(self, x: f32) -> f32 {
+        match self {
+            Activation::Relu => {
+                if x > 0.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Activation::LeakyRelu(slope) => {
+                if x > 0.0 {
+                    1.0
+                } else {
+                    slope
+                }
+            }
+            Activation::Gelu => {
+                let t = (GELU_SQRT_2_OVER_PI * (x + GELU_CUBIC * x * x * x)).tanh();
+                let du = GELU_SQRT_2_OVER_PI * (1.0 + 3.0 * GELU_CUBIC * x * x);
+                0.5 * (1.0 + t) + 0.5 * x * (1.0 - t * t) * du
+            }
+        }
+    }
+
+    /// Encodes the activation as a `(tag, parameter)` pair for checkpoints.
+    fn to_checkpoint(self) -> (u32, f32) {
+        match self {
+            Activation::Relu => (0, 0.0),
+            Activation::LeakyRelu(slope) => (1, slope),
+            Activation::Gelu => (2, 0.0),
+        }
+    }
+
+    /// Decodes a `(tag, parameter)` pair written by [`Self::to_checkpoint`].
+    fn from_checkpoint(tag: u32, param: f32) -> Result<Self, VoltError> {
+        match tag {
+            0 => Ok(Activation::Relu),
+            1 if param.is_finite() => Ok(Activation::LeakyRelu(param)),
+            1 => Err(VoltError::LearnError {
+                message: format!("Invalid LeakyReLU slope in checkpoint: {param}"),
+            }),
+            2 => Ok(Activation::Gelu),
+            _ => Err(VoltError::LearnError {
+                message: format!("Unknown activation tag in checkpoint: {tag}"),
+            }),
+        }
+    }
+}
+
+/// Configuration for a [`Vfn`].
+///
+/// # Example
+///
+/// ```
+/// use volt_soft::vfn::{Activation, Vfn, VfnConfig};
+///
+/// let config = VfnConfig { activation: Activation::Gelu, ..VfnConfig::default() };
+/// let vfn = Vfn::with_config(42, config);
+/// assert_eq!(vfn.config().activation, Activation::Gelu);
+/// assert_eq!(VfnConfig::default().activation, Activation::Relu);
+/// assert_eq!(VfnConfig::default().dropout, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VfnConfig {
+    /// Activation applied after each hidden layer.
+    pub activation: Activation,
+    /// Normalize each hidden layer's activations to zero mean and unit
+    /// variance, in inference and training alike. Default: `false`.
+    pub layer_norm: bool,
+    /// Probability of zeroing each hidden unit during
+    /// [`Vfn::forward_layer_train`], in `[0, 1)`. Survivors are scaled by
+    /// `1 / (1 - dropout)`. Default: 0.0.
+    pub dropout: f32,
+    /// Seed for the dropout mask; vary it per training step. Default: 0.
+    pub dropout_seed: u64,
+}
+
 /// A Vector Field Network: slot-local MLP for RAR inference.
 ///
 /// Takes a 256-dim slot embedding and produces a 256-dim drift vector.
@@ -40,21 +208,19 @@ const HIDDEN_DIM: usize = 512;
 /// ```
 #[derive(Clone)]
 pub struct Vfn {
-    layer1: Linear,
-    layer2: Linear,
-    layer3: Linear,
+    /// Linear layers in order; the activation follows every layer but
+    /// the last. Always at least one, 256 in and 256 out.
+    layers: Vec<Linear>,
+    config: VfnConfig,
 }
 
 impl std::fmt::Debug for Vfn {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Vfn({}→{}→{}→{})",
-            self.layer1.in_dim(),
-            self.layer1.out_dim(),
-            self.layer2.out_dim(),
-            self.layer3.out_dim()
-        )
+        write!(f, "Vfn({}", self.layers[0].in_dim())?;
+        for layer in &self.layers {
+            write!(f, "→{}", layer.out_dim())?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -72,18 +238,83 @@ impl Vfn {
     /// let vfn = Vfn::new_random(42);
     /// ```
     pub fn new_random(seed: u64) -> Self {
-        let mut rng = Rng::new(seed);
-        Self {
-            layer1: Linear::new_xavier(&mut rng, SLOT_DIM, HIDDEN_DIM),
-            layer2: Linear::new_xavier(&mut rng, HIDDEN_DIM, HIDDEN_DIM),
-            layer3: Linear::new_xavier(&mut rng, HIDDEN_DIM, SLOT_DIM),
+        Self::with_config(seed, VfnConfig::default())
+    }
+
+    /// Creates a new randomly initialized VFN with the given configuration.
+    ///
+    /// Weights are identical to [`Vfn::new_random`] for the same seed;
+    /// only the hidden activation differs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::vfn::{Activation, Vfn, VfnConfig};
+    ///
+    /// let config = VfnConfig { activation: Activation::LeakyRelu(0.01), ..VfnConfig::default() };
+    /// let vfn = Vfn::with_config(42, config);
+    /// assert_eq!(vfn.config().activation, Activation::LeakyRelu(0.01));
+    /// ```
+    pub fn with_config(seed: u64, config: VfnConfig) -> Self {
+        Self::build(seed, &[HIDDEN_DIM, HIDDEN_DIM], config)
+    }
+
+    /// Creates a randomly initialized VFN with the given hidden layer
+    /// widths: `256 → hidden_dims[0] → … → hidden_dims[n-1] → 256`, with
+    /// the configured activation (ReLU by default) after each hidden layer.
+    ///
+    /// [`Vfn::new_random`] is this with `[512, 512]`, and produces the
+    /// same weights for the same seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::LearnError`] if `hidden_dims` is empty or
+    /// contains a zero width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::vfn::Vfn;
+    ///
+    /// let vfn = Vfn::new_random_with_shape(42, &[256, 256, 256]).unwrap();
+    /// assert_eq!(vfn.layer_count(), 4);
+    /// assert_eq!(format!("{vfn:?}"), "Vfn(256→256→256→256→256)");
+    /// assert!(Vfn::new_random_with_shape(42, &[]).is_err());
+    /// ```
+    pub fn new_random_with_shape(seed: u64, hidden_dims: &[usize]) -> Result<Self, VoltError> {
+        if hidden_dims.is_empty() || hidden_dims.contains(&0) {
+            return Err(VoltError::LearnError {
+                message: format!("VFN shape needs at least one non-zero hidden width, got {hidden_dims:?}"),
+            });
         }
+        Ok(Self::build(seed, hidden_dims, VfnConfig::default()))
+    }
+
+    /// Builds `SLOT_DIM → hidden_dims… → SLOT_DIM` with Xavier weights
+    /// drawn in layer order from one seeded RNG.
+    fn build(seed: u64, hidden_dims: &[usize], config: VfnConfig) -> Self {
+        let mut rng = Rng::new(seed);
+        let dims: Vec<usize> = std::iter::once(SLOT_DIM)
+            .chain(hidden_dims.iter().copied())
+            .chain(std::iter::once(SLOT_DIM))
+            .collect();
+        let layers = dims
+            .windows(2)
+            .map(|w| Linear::new_xavier(&mut rng, w[0], w[1]))
+            .collect();
+        Self { layers, config }
+    }
+
+    /// Returns the VFN's configuration.
+    pub fn config(&self) -> &VfnConfig {
+        &self.config
     }
 
     /// Computes the drift vector for a single slot embedding.
     ///
-    /// Passes the input through 3 linear layers with ReLU activations
-    /// on the first two. Returns a 256-dim drift vector.
+    /// Passes the input through the linear layers with the configured
+    /// activation (and layer normalization, if enabled) on all but the
+    /// last. Returns a 256-dim drift vector.
     ///
     /// # Errors
     ///
@@ -108,16 +339,21 @@ impl Vfn {
             });
         }
 
-        // Layer 1: 256 → 512, ReLU
-        let h1 = self.layer1.forward(input);
-        let h1: Vec<f32> = h1.into_iter().map(|x| x.max(0.0)).collect();
-
-        // Layer 2: 512 → 512, ReLU
-        let h2 = self.layer2.forward(&h1);
-        let h2: Vec<f32> = h2.into_iter().map(|x| x.max(0.0)).collect();
-
-        // Layer 3: 512 → 256, no activation
-        let out = self.layer3.forward(&h2);
+        let act = self.config.activation;
+
+        // Hidden layers with activation, then the output layer without
+        let (output_layer, hidden_layers) = self
+            .layers
+            .split_last()
+            .expect("VFN always has an output layer");
+        let mut h = input.to_vec();
+        for layer in hidden_layers {
+            h = layer.forward(&h).into_iter().map(|x| act.apply(x)).collect();
+            if self.config.layer_norm {
+                layer_normalize(&mut h);
+            }
+        }
+        let out = output_layer.forward(&h);
 
         // Convert to fixed-size array
         let mut result = [0.0f32; SLOT_DIM];
@@ -133,18 +369,19 @@ impl Vfn {
         Ok(result)
     }
 
-    /// Returns references to the three internal linear layers.
+    /// Returns the internal linear layers in order.
     ///
     /// Used by [`crate::gpu::vfn::GpuVfn::from_cpu_vfn`] to transfer
     /// weights to candle tensors.
     #[cfg_attr(not(feature = "gpu"), allow(dead_code))]
-    pub(crate) fn layers(&self) -> (&Linear, &Linear, &Linear) {
-        (&self.layer1, &self.layer2, &self.layer3)
+    pub(crate) fn layers(&self) -> &[Linear] {
+        &self.layers
     }
 
     // --- Forward-Forward Training API (Milestone 5.2) ---
 
-    /// Returns the number of layers in the VFN (always 3).
+    /// Returns the number of linear layers in the VFN (3 for
+    /// [`Vfn::new_random`]).
     ///
     /// # Example
     ///
@@ -155,14 +392,14 @@ impl Vfn {
     /// assert_eq!(vfn.layer_count(), 3);
     /// ```
     pub fn layer_count(&self) -> usize {
-        3
+        self.layers.len()
     }
 
     /// Returns `(in_dim, out_dim)` for the given layer.
     ///
     /// # Errors
     ///
-    /// Returns [`VoltError::Internal`] if `layer_idx >= 3`.
+    /// Returns [`VoltError::Internal`] if `layer_idx >= layer_count()`.
     ///
     /// # Example
     ///
@@ -181,17 +418,18 @@ impl Vfn {
 
     /// Forward pass through a single layer.
     ///
-    /// For layers 0 and 1, applies ReLU after the linear transform.
-    /// Layer 2 (output) has no activation. Returns the layer's output
-    /// activations.
+    /// For hidden layers, applies the configured activation after the
+    /// linear transform, then layer normalization if
+    /// [`VfnConfig::layer_norm`] is set; the last (output) layer has
+    /// neither. Returns the layer's output activations.
     ///
     /// Used by Forward-Forward training to compute per-layer goodness
     /// without propagating gradients across layers.
     ///
     /// # Errors
     ///
-    /// Returns [`VoltError::Internal`] if `layer_idx >= 3` or input
-    /// contains NaN/Inf.
+    /// Returns [`VoltError::Internal`] if `layer_idx >= layer_count()` or
+    /// input contains NaN/Inf.
     ///
     /// # Example
     ///
@@ -209,6 +447,50 @@ impl Vfn {
         &self,
         layer_idx: usize,
         input: &[f32],
+    ) -> Result<Vec<f32>, VoltError> {
+        let output = self.forward_layer_linear(layer_idx, input)?;
+
+        // Apply activation for hidden layers, not the output layer
+        if layer_idx + 1 < self.layers.len() {
+            let act = self.config.activation;
+            let mut hidden: Vec<f32> = output.into_iter().map(|x| act.apply(x)).collect();
+            if self.config.layer_norm {
+                layer_normalize(&mut hidden);
+            }
+            Ok(hidden)
+        } else {
+            Ok(output)
+        }
+    }
+
+    /// Linear transform of a single layer, before any activation.
+    ///
+    /// Backward passes use these pre-activations with
+    /// [`Activation::derivative`]; [`Vfn::forward_layer`] equals this
+    /// followed by the activation (and layer normalization, if enabled)
+    /// on hidden layers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::Internal`] if `layer_idx >= layer_count()` or
+    /// input contains NaN/Inf.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::vfn::Vfn;
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let vfn = Vfn::new_random(42);
+    /// let input = vec![0.1_f32; SLOT_DIM];
+    /// let z = vfn.forward_layer_linear(0, &input).unwrap();
+    /// let h = vfn.forward_layer(0, &input).unwrap();
+    /// assert!(z.iter().zip(&h).all(|(z, h)| z.max(0.0) == *h));
+    /// ```
+    pub fn forward_layer_linear(
+        &self,
+        layer_idx: usize,
+        input: &[f32],
     ) -> Result<Vec<f32>, VoltError> {
         if input.iter().any(|x| !x.is_finite()) {
             return Err(VoltError::Internal {
@@ -217,16 +499,70 @@ impl Vfn {
                 ),
             });
         }
+        Ok(self.get_layer(layer_idx)?.forward(input))
+    }
 
-        let layer = self.get_layer(layer_idx)?;
-        let output = layer.forward(input);
+    /// Training-time forward pass through a single layer.
+    ///
+    /// Like [`Vfn::forward_layer`], then, for hidden layers only, applies
+    /// inverted dropout at rate [`VfnConfig::dropout`]. The dropout mask
+    /// is drawn from [`VfnConfig::dropout_seed`] and the layer index, so
+    /// the same seed reproduces the same mask. Activation and layer
+    /// normalization are always the VFN's own; `config.activation` and
+    /// `config.layer_norm` are not read. The output layer is returned
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::Internal`] under the same conditions as
+    /// [`Vfn::forward_layer`], or if `config.dropout` is outside `[0, 1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::vfn::{Vfn, VfnConfig};
+    /// use volt_core::SLOT_DIM;
+    ///
+    /// let vfn = Vfn::new_random(42);
+    /// let input = vec![0.1_f32; SLOT_DIM];
+    /// let config = VfnConfig { dropout: 0.5, dropout_seed: 7, ..VfnConfig::default() };
+    /// let h1 = vfn.forward_layer_train(0, &input, &config).unwrap();
+    /// assert_eq!(h1.len(), 512);
+    /// assert_eq!(h1, vfn.forward_layer_train(0, &input, &config).unwrap());
+    /// ```
+    pub fn forward_layer_train(
+        &self,
+        layer_idx: usize,
+        input: &[f32],
+        config: &VfnConfig,
+    ) -> Result<Vec<f32>, VoltError> {
+        if !(0.0..1.0).contains(&config.dropout) {
+            return Err(VoltError::Internal {
+                message: format!(
+                    "VFN forward_layer_train: dropout must be in [0, 1), got {}",
+                    config.dropout
+                ),
+            });
+        }
 
-        // Apply ReLU for layers 0 and 1 (hidden layers), not layer 2 (output)
-        if layer_idx < 2 {
-            Ok(output.into_iter().map(|x| x.max(0.0)).collect())
-        } else {
-            Ok(output)
+        let mut output = self.forward_layer(layer_idx, input)?;
+        if layer_idx + 1 == self.layers.len() {
+            return Ok(output);
         }
+
+        if config.dropout > 0.0 {
+            let mut rng = Rng::new(config.dropout_seed ^ ((layer_idx as u64 + 1) << 32));
+            let keep_scale = 1.0 / (1.0 - config.dropout);
+            for x in &mut output {
+                *x = if rng.next_f32() < config.dropout {
+                    0.0
+                } else {
+                    *x * keep_scale
+                };
+            }
+        }
+
+        Ok(output)
     }
 
     /// Updates weights of a single layer for Forward-Forward training.
@@ -236,8 +572,8 @@ impl Vfn {
     ///
     /// # Errors
     ///
-    /// Returns [`VoltError::Internal`] if `layer_idx >= 3` or delta
-    /// dimensions don't match the layer shape.
+    /// Returns [`VoltError::Internal`] if `layer_idx >= layer_count()` or
+    /// delta dimensions don't match the layer shape.
     ///
     /// # Example
     ///
@@ -292,32 +628,116 @@ impl Vfn {
         Ok(())
     }
 
-    /// Returns an immutable reference to the layer at the given index.
-    fn get_layer(&self, layer_idx: usize) -> Result<&Linear, VoltError> {
-        match layer_idx {
-            0 => Ok(&self.layer1),
-            1 => Ok(&self.layer2),
-            2 => Ok(&self.layer3),
-            _ => Err(VoltError::Internal {
+    /// Blends two VFNs weight by weight: `(1 - weight) * self + weight * other`.
+    ///
+    /// Lets the sleep scheduler keep an exponential moving average of
+    /// trained weights instead of swapping them in wholesale. `weight = 0`
+    /// returns a clone of `self` and `weight = 1` a clone of `other`.
+    ///
+    /// Only the output layer is linear, so the blended network's drift is
+    /// not in general the blend of the two drifts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::LearnError`] if `weight` is not in `[0, 1]`,
+    /// the layer counts, shapes or activations differ, or a blended parameter is
+    /// not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_soft::vfn::Vfn;
+    ///
+    /// let current = Vfn::new_random(1);
+    /// let trained = Vfn::new_random(2);
+    /// let ema = current.interpolate(&trained, 0.1).unwrap();
+    /// assert_eq!(ema.layer_shape(0).unwrap(), current.layer_shape(0).unwrap());
+    /// ```
+    pub fn interpolate(&self, other: &Vfn, weight: f32) -> Result<Vfn, VoltError> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(VoltError::LearnError {
+                message: format!("VFN interpolate: weight must be in [0, 1], got {weight}"),
+            });
+        }
+        if self.config.activation != other.config.activation {
+            return Err(VoltError::LearnError {
                 message: format!(
-                    "VFN layer index {layer_idx} out of range (0..3)"
+                    "VFN interpolate: activation mismatch ({:?} vs {:?})",
+                    self.config.activation, other.config.activation
                 ),
-            }),
+            });
+        }
+        if self.layer_count() != other.layer_count() {
+            return Err(VoltError::LearnError {
+                message: format!(
+                    "VFN interpolate: layer count mismatch ({} vs {})",
+                    self.layer_count(),
+                    other.layer_count()
+                ),
+            });
+        }
+        for idx in 0..self.layer_count() {
+            let (a, b) = (self.layer_shape(idx)?, other.layer_shape(idx)?);
+            if a != b {
+                return Err(VoltError::LearnError {
+                    message: format!(
+                        "VFN interpolate: layer {idx} shape mismatch ({a:?} vs {b:?})"
+                    ),
+                });
+            }
+        }
+
+        if weight == 0.0 {
+            return Ok(self.clone());
         }
+        if weight == 1.0 {
+            return Ok(other.clone());
+        }
+
+        let blend = |a: &Linear, b: &Linear, idx: usize| -> Result<Linear, VoltError> {
+            let mix = |x: &[f32], y: &[f32]| -> Vec<f32> {
+                x.iter()
+                    .zip(y)
+                    .map(|(&x, &y)| (1.0 - weight) * x + weight * y)
+                    .collect()
+            };
+            let weights = mix(a.weights(), b.weights());
+            let bias = mix(a.bias(), b.bias());
+            if weights.iter().chain(&bias).any(|x| !x.is_finite()) {
+                return Err(VoltError::LearnError {
+                    message: format!("VFN interpolate: layer {idx} produced non-finite values"),
+                });
+            }
+            Linear::from_weights_and_bias(weights, bias, a.in_dim(), a.out_dim())
+        };
+
+        let layers = self
+            .layers
+            .iter()
+            .zip(&other.layers)
+            .enumerate()
+            .map(|(idx, (a, b))| blend(a, b, idx))
+            .collect::<Result<_, _>>()?;
+        Ok(Vfn {
+            layers,
+            config: self.config,
+        })
+    }
+
+    /// Returns an immutable reference to the layer at the given index.
+    fn get_layer(&self, layer_idx: usize) -> Result<&Linear, VoltError> {
+        let count = self.layers.len();
+        self.layers.get(layer_idx).ok_or_else(|| VoltError::Internal {
+            message: format!("VFN layer index {layer_idx} out of range (0..{count})"),
+        })
     }
 
     /// Returns a mutable reference to the layer at the given index.
     fn get_layer_mut(&mut self, layer_idx: usize) -> Result<&mut Linear, VoltError> {
-        match layer_idx {
-            0 => Ok(&mut self.layer1),
-            1 => Ok(&mut self.layer2),
-            2 => Ok(&mut self.layer3),
-            _ => Err(VoltError::Internal {
-                message: format!(
-                    "VFN layer index {layer_idx} out of range (0..3)"
-                ),
-            }),
-        }
+        let count = self.layers.len();
+        self.layers.get_mut(layer_idx).ok_or_else(|| VoltError::Internal {
+            message: format!("VFN layer index {layer_idx} out of range (0..{count})"),
+        })
     }
 
     // --- Checkpoint Save/Load (Phase 0.1) ---
@@ -330,7 +750,11 @@ impl Vfn {
     ///
     /// Binary format:
     /// - Magic: "VFNC" (4 bytes)
-    /// - Version: u32 (4 bytes, currently 1)
+    /// - Version: u32 (4 bytes, currently 4)
+    /// - Activation tag: u32 (0 = ReLU, 1 = LeakyReLU, 2 = GELU)
+    /// - Activation parameter: f32 (LeakyReLU slope, otherwise 0)
+    /// - Layer norm: u32 (1 if hidden layers are normalized, otherwise 0)
+    /// - Layer count: u32 (4 bytes)
     /// - Checksum: CRC32 of all weights data (4 bytes)
     /// - For each layer:
     ///   - in_dim: u32 (4 bytes)
@@ -364,12 +788,28 @@ impl Vfn {
                 message: format!("Failed to write magic bytes: {}", e),
             })?;
 
-        // Version: 1
-        file.write_all(&1u32.to_le_bytes())
+        file.write_all(&CHECKPOINT_VERSION.to_le_bytes())
             .map_err(|e| VoltError::LearnError {
                 message: format!("Failed to write version: {}", e),
             })?;
 
+        let (tag, param) = self.config.activation.to_checkpoint();
+        file.write_all(&tag.to_le_bytes())
+            .and_then(|_| file.write_all(&param.to_le_bytes()))
+            .map_err(|e| VoltError::LearnError {
+                message: format!("Failed to write activation: {}", e),
+            })?;
+
+        file.write_all(&u32::from(self.config.layer_norm).to_le_bytes())
+            .map_err(|e| VoltError::LearnError {
+                message: format!("Failed to write layer norm flag: {}", e),
+            })?;
+
+        file.write_all(&(self.layers.len() as u32).to_le_bytes())
+            .map_err(|e| VoltError::LearnError {
+                message: format!("Failed to write layer count: {}", e),
+            })?;
+
         // Compute checksum of all weights
         let checksum = self.compute_checksum();
         file.write_all(&checksum.to_le_bytes())
@@ -378,9 +818,9 @@ impl Vfn {
             })?;
 
         // Save each layer
-        self.save_layer(&mut file, &self.layer1)?;
-        self.save_layer(&mut file, &self.layer2)?;
-        self.save_layer(&mut file, &self.layer3)?;
+        for layer in &self.layers {
+            self.save_layer(&mut file, layer)?;
+        }
 
         Ok(())
     }
@@ -389,7 +829,11 @@ impl Vfn {
     ///
     /// Validates magic bytes, version compatibility, and checksum
     /// before loading weights. Ensures bitwise-identical restoration
-    /// of saved weights.
+    /// of saved weights, the saved activation, layer-norm flag and shape.
+    /// Version 1 checkpoints predate the activation field and load as
+    /// ReLU; versions 1 and 2 predate the layer count and hold three
+    /// layers; versions before 4 predate layer normalization and load
+    /// without it.
     ///
     /// # Errors
     ///
@@ -398,7 +842,7 @@ impl Vfn {
     /// - Magic bytes don't match "VFNC"
     /// - Version is incompatible
     /// - Checksum verification fails
-    /// - Layer dimensions don't match expected VFN architecture
+    /// - Layers don't chain from 256 in to 256 out
     ///
     /// # Example
     ///
@@ -437,14 +881,65 @@ impl Vfn {
                 message: format!("Failed to read version: {}", e),
             })?;
         let version = u32::from_le_bytes(version_bytes);
-        if version != 1 {
+        if version == 0 || version > CHECKPOINT_VERSION {
             return Err(VoltError::LearnError {
                 message: format!(
-                    "Incompatible checkpoint version: expected 1, got {version}"
+                    "Incompatible checkpoint version: expected 1..={CHECKPOINT_VERSION}, got {version}"
                 ),
             });
         }
 
+        let activation = if version >= 2 {
+            let mut tag_bytes = [0u8; 4];
+            let mut param_bytes = [0u8; 4];
+            file.read_exact(&mut tag_bytes)
+                .and_then(|_| file.read_exact(&mut param_bytes))
+                .map_err(|e| VoltError::LearnError {
+                    message: format!("Failed to read activation: {}", e),
+                })?;
+            Activation::from_checkpoint(
+                u32::from_le_bytes(tag_bytes),
+                f32::from_le_bytes(param_bytes),
+            )?
+        } else {
+            Activation::Relu
+        };
+
+        let layer_norm = if version >= 4 {
+            let mut flag_bytes = [0u8; 4];
+            file.read_exact(&mut flag_bytes)
+                .map_err(|e| VoltError::LearnError {
+                    message: format!("Failed to read layer norm flag: {}", e),
+                })?;
+            match u32::from_le_bytes(flag_bytes) {
+                0 => false,
+                1 => true,
+                other => {
+                    return Err(VoltError::LearnError {
+                        message: format!("Invalid layer norm flag in checkpoint: {other}"),
+                    })
+                }
+            }
+        } else {
+            false
+        };
+
+        let layer_count = if version >= 3 {
+            let mut count_bytes = [0u8; 4];
+            file.read_exact(&mut count_bytes)
+                .map_err(|e| VoltError::LearnError {
+                    message: format!("Failed to read layer count: {}", e),
+                })?;
+            u32::from_le_bytes(count_bytes) as usize
+        } else {
+            LEGACY_LAYER_COUNT
+        };
+        if layer_count == 0 {
+            return Err(VoltError::LearnError {
+                message: "Invalid checkpoint: zero layers".to_string(),
+            });
+        }
+
         // Read stored checksum
         let mut checksum_bytes = [0u8; 4];
         file.read_exact(&mut checksum_bytes)
@@ -454,49 +949,41 @@ impl Vfn {
         let stored_checksum = u32::from_le_bytes(checksum_bytes);
 
         // Load layers
-        let layer1 = Self::load_layer(&mut file)?;
-        let layer2 = Self::load_layer(&mut file)?;
-        let layer3 = Self::load_layer(&mut file)?;
-
-        // Validate layer dimensions match VFN architecture
-        if layer1.in_dim() != SLOT_DIM || layer1.out_dim() != HIDDEN_DIM {
-            return Err(VoltError::LearnError {
-                message: format!(
-                    "Layer 1 dimensions mismatch: expected {}→{}, got {}→{}",
-                    SLOT_DIM,
-                    HIDDEN_DIM,
-                    layer1.in_dim(),
-                    layer1.out_dim()
-                ),
-            });
-        }
-        if layer2.in_dim() != HIDDEN_DIM || layer2.out_dim() != HIDDEN_DIM {
-            return Err(VoltError::LearnError {
-                message: format!(
-                    "Layer 2 dimensions mismatch: expected {}→{}, got {}→{}",
-                    HIDDEN_DIM,
-                    HIDDEN_DIM,
-                    layer2.in_dim(),
-                    layer2.out_dim()
-                ),
-            });
+        let layers = (0..layer_count)
+            .map(|_| Self::load_layer(&mut file))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Validate the layers chain SLOT_DIM → … → SLOT_DIM
+        let mut expected_in = SLOT_DIM;
+        for (idx, layer) in layers.iter().enumerate() {
+            if layer.in_dim() != expected_in {
+                return Err(VoltError::LearnError {
+                    message: format!(
+                        "Layer {} dimensions mismatch: expected input {}, got {}→{}",
+                        idx + 1,
+                        expected_in,
+                        layer.in_dim(),
+                        layer.out_dim()
+                    ),
+                });
+            }
+            expected_in = layer.out_dim();
         }
-        if layer3.in_dim() != HIDDEN_DIM || layer3.out_dim() != SLOT_DIM {
+        if expected_in != SLOT_DIM {
             return Err(VoltError::LearnError {
                 message: format!(
-                    "Layer 3 dimensions mismatch: expected {}→{}, got {}→{}",
-                    HIDDEN_DIM,
-                    SLOT_DIM,
-                    layer3.in_dim(),
-                    layer3.out_dim()
+                    "Layer {layer_count} dimensions mismatch: expected output {SLOT_DIM}, got {expected_in}"
                 ),
             });
         }
 
         let vfn = Self {
-            layer1,
-            layer2,
-            layer3,
+            layers,
+            config: VfnConfig {
+                activation,
+                layer_norm,
+                ..VfnConfig::default()
+            },
         };
 
         // Validate checksum matches
@@ -516,28 +1003,13 @@ impl Vfn {
     fn compute_checksum(&self) -> u32 {
         let mut hasher = crc32fast::Hasher::new();
 
-        // Hash layer1 weights and biases
-        for &w in self.layer1.weights() {
-            hasher.update(&w.to_le_bytes());
-        }
-        for &b in self.layer1.bias() {
-            hasher.update(&b.to_le_bytes());
-        }
-
-        // Hash layer2
-        for &w in self.layer2.weights() {
-            hasher.update(&w.to_le_bytes());
-        }
-        for &b in self.layer2.bias() {
-            hasher.update(&b.to_le_bytes());
-        }
-
-        // Hash layer3
-        for &w in self.layer3.weights() {
-            hasher.update(&w.to_le_bytes());
-        }
-        for &b in self.layer3.bias() {
-            hasher.update(&b.to_le_bytes());
+        for layer in &self.layers {
+            for &w in layer.weights() {
+                hasher.update(&w.to_le_bytes());
+            }
+            for &b in layer.bias() {
+                hasher.update(&b.to_le_bytes());
+            }
         }
 
         hasher.finalize()
@@ -704,6 +1176,134 @@ mod tests {
         }
     }
 
+    #[test]
+    fn activation_derivative_matches_finite_difference() {
+        let h = 1e-3;
+        for act in [Activation::Relu, Activation::LeakyRelu(0.05), Activation::Gelu] {
+            for x in [-3.0f32, -0.7, -0.1, 0.2, 1.5, 4.0] {
+                let numeric = (act.apply(x + h) - act.apply(x - h)) / (2.0 * h);
+                assert!(
+                    (act.derivative(x) - numeric).abs() < 1e-2,
+                    "{act:?} at {x}: {} vs {numeric}",
+                    act.derivative(x)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn leaky_relu_keeps_negative_units_alive() {
+        let relu = Vfn::new_random(42);
+        let leaky = Vfn::with_config(
+            42,
+            VfnConfig { activation: Activation::LeakyRelu(0.01), ..VfnConfig::default() },
+        );
+        let input = vec![-1e-3_f32; SLOT_DIM];
+
+        let relu_h1 = relu.forward_layer(0, &input).unwrap();
+        let leaky_h1 = leaky.forward_layer(0, &input).unwrap();
+        assert!(relu_h1.contains(&0.0), "ReLU should zero some units");
+        assert!(leaky_h1.iter().all(|x| *x != 0.0), "LeakyReLU should not zero units");
+
+        let out = leaky.forward(&[-1e-3; SLOT_DIM]).unwrap();
+        assert!(out.iter().any(|x| *x != 0.0));
+    }
+
+    #[test]
+    fn gelu_output_is_finite() {
+        let vfn = Vfn::with_config(42, VfnConfig { activation: Activation::Gelu, ..VfnConfig::default() });
+        for scale in [-100.0_f32, -1.0, 0.0, 1e-3, 1.0, 100.0] {
+            let input = std::array::from_fn(|i| scale * ((i as f32) * 0.37).sin());
+            let out = vfn.forward(&input).unwrap();
+            assert!(out.iter().all(|x| x.is_finite()), "non-finite at scale {scale}");
+        }
+        assert!(Activation::Gelu.apply(50.0).is_finite());
+        assert!(Activation::Gelu.apply(-50.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn activation_changes_output_but_not_weights() {
+        let relu = Vfn::new_random(42);
+        let gelu = Vfn::with_config(42, VfnConfig { activation: Activation::Gelu, ..VfnConfig::default() });
+        assert_eq!(relu.layers()[0].weights(), gelu.layers()[0].weights());
+
+        let input = [0.1_f32; SLOT_DIM];
+        assert_ne!(relu.forward(&input).unwrap(), gelu.forward(&input).unwrap());
+    }
+
+    #[test]
+    fn interpolate_endpoints_return_clones() {
+        let a = Vfn::new_random(1);
+        let b = Vfn::new_random(2);
+        let input = [0.1_f32; SLOT_DIM];
+
+        let at_zero = a.interpolate(&b, 0.0).unwrap();
+        let at_one = a.interpolate(&b, 1.0).unwrap();
+        assert_eq!(at_zero.forward(&input).unwrap(), a.forward(&input).unwrap());
+        assert_eq!(at_one.forward(&input).unwrap(), b.forward(&input).unwrap());
+    }
+
+    #[test]
+    fn interpolate_midpoint_averages_weights() {
+        let a = Vfn::new_random(1);
+        let b = Vfn::new_random(2);
+        let mid = a.interpolate(&b, 0.5).unwrap();
+
+        assert_eq!(mid.layer_count(), 3);
+        for ((la, lb), lm) in a.layers().iter().zip(b.layers()).zip(mid.layers()) {
+            for ((x, y), m) in la.weights().iter().zip(lb.weights()).zip(lm.weights()) {
+                assert!((m - (x + y) / 2.0).abs() < 1e-6);
+            }
+            for ((x, y), m) in la.bias().iter().zip(lb.bias()).zip(lm.bias()) {
+                assert!((m - (x + y) / 2.0).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_midpoint_forward_lies_between() {
+        // Differ only in the linear output layer, so the blended drift
+        // is exactly the blend of the two drifts.
+        let a = Vfn::new_random(1);
+        let mut b = a.clone();
+        let (in_d, out_d) = b.layer_shape(2).unwrap();
+        let w_deltas: Vec<f32> = (0..in_d * out_d).map(|i| ((i % 7) as f32 - 3.0) * 0.01).collect();
+        let b_deltas: Vec<f32> = (0..out_d).map(|i| ((i % 5) as f32 - 2.0) * 0.1).collect();
+        b.update_layer(2, &w_deltas, &b_deltas, 1.0).unwrap();
+
+        let mid = a.interpolate(&b, 0.5).unwrap();
+        let input = [0.1_f32; SLOT_DIM];
+        let (ya, yb, ym) = (
+            a.forward(&input).unwrap(),
+            b.forward(&input).unwrap(),
+            mid.forward(&input).unwrap(),
+        );
+        assert_ne!(ya, yb);
+        for i in 0..SLOT_DIM {
+            let (lo, hi) = (ya[i].min(yb[i]), ya[i].max(yb[i]));
+            assert!(ym[i].is_finite());
+            assert!(ym[i] >= lo - 1e-5 && ym[i] <= hi + 1e-5, "dim {i}: {} not in [{lo}, {hi}]", ym[i]);
+        }
+    }
+
+    #[test]
+    fn interpolate_rejects_mismatch_and_bad_weight() {
+        let a = Vfn::new_random(1);
+        let gelu = Vfn::with_config(2, VfnConfig { activation: Activation::Gelu, ..VfnConfig::default() });
+        assert!(a.interpolate(&gelu, 0.5).is_err());
+
+        let w = vec![0.0; 3 * 512];
+        let b = vec![0.0; 512];
+        let mut small = Vfn::new_random(2);
+        small.layers[0] = Linear::from_weights_and_bias(w, b, 3, 512).unwrap();
+        assert!(a.interpolate(&small, 0.5).is_err());
+
+        let b = Vfn::new_random(2);
+        assert!(a.interpolate(&b, -0.1).is_err());
+        assert!(a.interpolate(&b, 1.5).is_err());
+        assert!(a.interpolate(&b, f32::NAN).is_err());
+    }
+
     #[test]
     fn debug_format_readable() {
         let vfn = Vfn::new_random(42);
@@ -765,6 +1365,104 @@ mod tests {
         assert!(has_negative || out.iter().all(|x| x.is_finite()));
     }
 
+    #[test]
+    fn forward_layer_train_without_regularization_matches_forward_layer() {
+        let vfn = Vfn::new_random(42);
+        let config = VfnConfig { dropout_seed: 9, ..VfnConfig::default() };
+        let mut input = vec![0.3; SLOT_DIM];
+        for layer in 0..vfn.layer_count() {
+            let plain = vfn.forward_layer(layer, &input).unwrap();
+            assert_eq!(vfn.forward_layer_train(layer, &input, &config).unwrap(), plain);
+            input = plain;
+        }
+    }
+
+    #[test]
+    fn forward_layer_train_dropout_is_reproducible() {
+        let vfn = Vfn::with_config(42, VfnConfig { activation: Activation::Gelu, ..VfnConfig::default() });
+        let input = vec![0.2; SLOT_DIM];
+        let plain = vfn.forward_layer(0, &input).unwrap();
+        let config = VfnConfig { dropout: 0.25, dropout_seed: 11, ..VfnConfig::default() };
+
+        let a = vfn.forward_layer_train(0, &input, &config).unwrap();
+        let b = vfn.forward_layer_train(0, &input, &config).unwrap();
+        assert_eq!(a, b);
+
+        let dropped = a.iter().filter(|x| **x == 0.0).count();
+        assert!(dropped > 64 && dropped < 192, "dropped {dropped} of 512");
+        for (kept, orig) in a.iter().zip(&plain).filter(|(x, _)| **x != 0.0) {
+            assert!((kept - orig / 0.75).abs() < 1e-5);
+        }
+
+        let reseeded = VfnConfig { dropout_seed: 12, ..config };
+        assert_ne!(vfn.forward_layer_train(0, &input, &reseeded).unwrap(), a);
+
+        // Inference stays dropout-free.
+        let slot = [0.2; SLOT_DIM];
+        assert_eq!(vfn.forward(&slot).unwrap(), vfn.forward(&slot).unwrap());
+    }
+
+    #[test]
+    fn layer_norm_applies_to_hidden_layers_at_inference() {
+        let config = VfnConfig {
+            activation: Activation::Gelu,
+            layer_norm: true,
+            ..VfnConfig::default()
+        };
+        let vfn = Vfn::with_config(42, config);
+        let input = vec![0.4; SLOT_DIM];
+
+        let h = vfn.forward_layer(0, &input).unwrap();
+        let n = h.len() as f32;
+        let mean = h.iter().sum::<f32>() / n;
+        let var = h.iter().map(|x| (x - mean) * (x - mean)).sum::<f32>() / n;
+        assert!(mean.abs() < 1e-4);
+        assert!((var - 1.0).abs() < 1e-2);
+
+        // forward chains the same normalized layers; the output layer is
+        // left as is.
+        let mut chained = input.clone();
+        for layer in 0..vfn.layer_count() {
+            chained = vfn.forward_layer(layer, &chained).unwrap();
+        }
+        let slot = [0.4; SLOT_DIM];
+        assert_eq!(vfn.forward(&slot).unwrap().to_vec(), chained);
+        let last = vfn.layer_count() - 1;
+        let hidden = vec![0.1; 512];
+        assert_eq!(
+            vfn.forward_layer(last, &hidden).unwrap(),
+            vfn.forward_layer_linear(last, &hidden).unwrap()
+        );
+
+        // Only dropout is train-only.
+        let train = VfnConfig { dropout_seed: 3, ..VfnConfig::default() };
+        assert_eq!(vfn.forward_layer_train(0, &input, &train).unwrap(), h);
+    }
+
+    #[test]
+    fn checkpoint_restores_layer_norm() {
+        let path = std::env::temp_dir().join("vfn_layer_norm_checkpoint.bin");
+        let vfn = Vfn::with_config(5, VfnConfig { layer_norm: true, ..VfnConfig::default() });
+        vfn.save(&path).unwrap();
+
+        let loaded = Vfn::load(&path).unwrap();
+        assert!(loaded.config().layer_norm);
+        let input = [0.25_f32; SLOT_DIM];
+        assert_eq!(vfn.forward(&input).unwrap(), loaded.forward(&input).unwrap());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn forward_layer_train_rejects_bad_dropout() {
+        let vfn = Vfn::new_random(42);
+        let input = vec![0.1; SLOT_DIM];
+        for dropout in [-0.1, 1.0, f32::NAN] {
+            let config = VfnConfig { dropout, ..VfnConfig::default() };
+            assert!(vfn.forward_layer_train(0, &input, &config).is_err());
+        }
+    }
+
     #[test]
     fn forward_layer_invalid_index() {
         let vfn = Vfn::new_random(42);
@@ -861,6 +1559,83 @@ mod tests {
         let _ = std::fs::remove_file(&checkpoint_path);
     }
 
+    #[test]
+    fn checkpoint_restores_activation() {
+        let temp_dir = std::env::temp_dir();
+        for (name, activation) in [
+            ("leaky", Activation::LeakyRelu(0.05)),
+            ("gelu", Activation::Gelu),
+        ] {
+            let path = temp_dir.join(format!("vfn_activation_{name}.bin"));
+            let vfn = Vfn::with_config(7, VfnConfig { activation, ..VfnConfig::default() });
+            vfn.save(&path).unwrap();
+
+            let loaded = Vfn::load(&path).unwrap();
+            assert_eq!(loaded.config().activation, activation);
+            assert!(!loaded.config().layer_norm);
+            let input = [-0.3_f32; SLOT_DIM];
+            assert_eq!(vfn.forward(&input).unwrap(), loaded.forward(&input).unwrap());
+
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+
+    #[test]
+    fn checkpoint_version_1_loads_as_relu() {
+        let temp_dir = std::env::temp_dir();
+        let path = temp_dir.join("vfn_v1_checkpoint.bin");
+
+        // Rewrite a current checkpoint into the v1 layout: drop the
+        // 8-byte activation field, 4-byte layer-norm flag and 4-byte
+        // layer count that follow the version.
+        let vfn = Vfn::new_random(3);
+        vfn.save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let mut v1 = Vec::with_capacity(bytes.len() - 16);
+        v1.extend_from_slice(b"VFNC");
+        v1.extend_from_slice(&1u32.to_le_bytes());
+        v1.extend_from_slice(&bytes[24..]);
+        std::fs::write(&path, v1).unwrap();
+
+        let loaded = Vfn::load(&path).unwrap();
+        assert_eq!(loaded.config().activation, Activation::Relu);
+        let input = [0.42_f32; SLOT_DIM];
+        assert_eq!(vfn.forward(&input).unwrap(), loaded.forward(&input).unwrap());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn custom_shape_checkpoint_roundtrip() {
+        let temp_dir = std::env::temp_dir();
+        let path = temp_dir.join("vfn_custom_shape_checkpoint.bin");
+
+        let vfn = Vfn::new_random_with_shape(11, &[256, 256, 256]).unwrap();
+        assert_eq!(vfn.layer_count(), 4);
+        for idx in 0..4 {
+            assert_eq!(vfn.layer_shape(idx).unwrap(), (256, 256));
+        }
+        let input = [0.3_f32; SLOT_DIM];
+        let drift = vfn.forward(&input).unwrap();
+        assert!(drift.iter().all(|x| x.is_finite()));
+
+        vfn.save(&path).unwrap();
+        let loaded = Vfn::load(&path).unwrap();
+        assert_eq!(loaded.layer_count(), 4);
+        assert_eq!(loaded.compute_checksum(), vfn.compute_checksum());
+        assert_eq!(loaded.forward(&input).unwrap(), drift);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn default_shape_matches_new_random() {
+        let shaped = Vfn::new_random_with_shape(42, &[512, 512]).unwrap();
+        let default = Vfn::new_random(42);
+        assert_eq!(shaped.compute_checksum(), default.compute_checksum());
+        assert!(Vfn::new_random_with_shape(42, &[512, 0]).is_err());
+    }
+
     #[test]
     fn checkpoint_load_invalid_magic_bytes() {
         let temp_dir = std::env::temp_dir();
@@ -909,10 +1684,11 @@ mod tests {
 
         // Corrupt the file (flip a bit in the weights section)
         let mut data = std::fs::read(&checkpoint_path).unwrap();
-        // Magic(4) + Version(4) + Checksum(4) + in_dim(4) + out_dim(4) = 20 bytes
-        // Corrupt first weight byte
-        if data.len() > 24 {
-            data[24] ^= 0xFF; // Flip all bits
+        // Magic(4) + Version(4) + Activation(8) + LayerNorm(4)
+        // + LayerCount(4) + Checksum(4) + in_dim(4) + out_dim(4) = 36 bytes
+        // Corrupt a weight byte
+        if data.len() > 36 {
+            data[36] ^= 0xFF; // Flip all bits
             std::fs::write(&checkpoint_path, &data).unwrap();
         }
 
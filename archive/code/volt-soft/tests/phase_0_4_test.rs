@@ -9,7 +9,7 @@
 use volt_core::{SlotRole, TensorFrame, MAX_SLOTS, SLOT_DIM};
 use volt_soft::attention::SlotAttention;
 use volt_soft::code_attention::{code_attention_bias, new_code_attention};
-use volt_soft::rar::{rar_loop, rar_loop_with_ghosts, GhostConfig, RarConfig};
+use volt_soft::rar::{rar_loop, rar_loop_with_ghosts, GhostConfig, NonFinitePolicy, RarConfig};
 use volt_soft::vfn::Vfn;
 
 /// Run a closure on a thread with 4MB stack (Windows TensorFrame safety).
@@ -151,9 +151,12 @@ fn phase_0_4_code_bias_produces_different_rar_trajectory() {
             epsilon: 0.001,
             max_iterations: 10,
             dt: 0.1,
+            dt_per_resolution: None,
             beta: 0.5,
             resolution: 0,
             diffusion: None,
+            on_nonfinite: NonFinitePolicy::Error,
+            adaptive_dt: false,
         };
 
         // Run RAR with random attention
@@ -6,7 +6,8 @@
 use std::time::Instant;
 use volt_core::{SlotRole, TensorFrame, SLOT_DIM, MAX_SLOTS};
 use volt_soft::attention::SlotAttention;
-use volt_soft::rar::{rar_loop, RarConfig};
+use volt_soft::diffusion::{generate_noise, DiffusionConfig};
+use volt_soft::rar::{rar_loop, NonFinitePolicy, RarConfig};
 use volt_soft::vfn::Vfn;
 
 /// Create a deterministic pseudo-random normalized vector from a seed.
@@ -383,3 +384,88 @@ fn inactive_slots_preserved() {
         );
     }
 }
+
+/// The attractor gist is the result frame's R₀ gist, converged or not.
+#[test]
+fn attractor_gist_matches_result_frame_gist() {
+    let vfn = make_vfn();
+    let attn = make_attention();
+    let frame = make_frame(4);
+
+    for max_iterations in [1, 50] {
+        let config = RarConfig {
+            epsilon: 1e-6,
+            max_iterations,
+            ..RarConfig::default()
+        };
+        let result = rar_loop(&frame, &vfn, &attn, &config).unwrap();
+        if max_iterations == 1 {
+            assert!(result.converged[..4].iter().any(|c| !c), "expected a non-converged run");
+        }
+
+        let expected = volt_db::gist::extract_gist(&result.frame)
+            .unwrap()
+            .expect("result frame has R0 data")
+            .vector;
+        for (a, b) in result.attractor_gist.iter().zip(expected.iter()) {
+            assert!((a - b).abs() < 1e-6, "attractor gist differs: {a} vs {b}");
+        }
+    }
+
+    let empty = rar_loop(&TensorFrame::new(), &vfn, &attn, &RarConfig::default()).unwrap();
+    assert_eq!(empty.attractor_gist, [0.0; SLOT_DIM]);
+}
+
+/// Noise large enough that a sample occasionally overflows to ±Inf
+/// poisons the slot mid-loop; each policy handles it in that iteration.
+#[test]
+fn nonfinite_state_is_caught_at_the_poisoned_iteration() {
+    let vfn = make_vfn();
+    let attn = make_attention();
+    let frame = make_frame(1);
+    let mut mask = [false; MAX_SLOTS];
+    mask[0] = true;
+
+    // Find a seed whose first non-finite noise sample lands after iteration 1.
+    let (diffusion, poisoned_at) = (0..100)
+        .find_map(|seed| {
+            let diff = DiffusionConfig::uniform(1e38, seed);
+            let first = (1..=50u32).find(|&it| {
+                generate_noise(&diff, &mask, it).unwrap()[0]
+                    .unwrap()
+                    .iter()
+                    .any(|x| !x.is_finite())
+            })?;
+            (first > 1).then_some((diff, first))
+        })
+        .expect("some seed should overflow mid-loop");
+
+    // epsilon 0 keeps the slot active until it is poisoned
+    let config = |on_nonfinite, max_iterations| RarConfig {
+        epsilon: 0.0,
+        max_iterations,
+        diffusion: Some(diffusion.clone()),
+        on_nonfinite,
+        ..RarConfig::default()
+    };
+
+    let err = rar_loop(&frame, &vfn, &attn, &config(NonFinitePolicy::Error, 50)).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains(&format!("iteration {poisoned_at}")), "{msg}");
+    assert!(msg.contains("slot 0"), "{msg}");
+
+    let before = rar_loop(&frame, &vfn, &attn, &config(NonFinitePolicy::Error, poisoned_at - 1))
+        .unwrap();
+    let reverted = rar_loop(&frame, &vfn, &attn, &config(NonFinitePolicy::Revert, 50)).unwrap();
+    assert_eq!(reverted.iterations, poisoned_at);
+    assert!(reverted.converged[0]);
+    assert_eq!(
+        reverted.frame.read_slot(0).unwrap().resolutions[0],
+        before.frame.read_slot(0).unwrap().resolutions[0]
+    );
+
+    let clamped = rar_loop(&frame, &vfn, &attn, &config(NonFinitePolicy::Clamp, 50)).unwrap();
+    assert_eq!(clamped.iterations, 50);
+    let state = clamped.frame.read_slot(0).unwrap().resolutions[0].unwrap();
+    assert!(state.iter().all(|x| x.is_finite()));
+}
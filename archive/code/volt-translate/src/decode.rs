@@ -15,6 +15,9 @@ pub struct VocabEntry {
     pub vector: [f32; SLOT_DIM],
 }
 
+/// Ranked `(word, similarity)` cleanup candidates, most similar first.
+pub type WordCandidates = Vec<(String, f32)>;
+
 /// Find the closest word in the vocabulary for a given slot vector.
 ///
 /// Returns the word with the highest cosine similarity above `threshold`,
@@ -53,6 +56,45 @@ pub fn nearest_word(
     best_word.map(|s| s.to_string())
 }
 
+/// Find the `m` closest words in the vocabulary for a given slot vector.
+///
+/// Returns `(word, similarity)` pairs above `threshold`, most similar
+/// first. Ties keep vocabulary order, so the first entry is always the
+/// word [`nearest_word`] picks for the same arguments.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::decode::{VocabEntry, nearest_words};
+/// use volt_translate::encode::word_to_vector;
+///
+/// let vocab = vec![
+///     VocabEntry { word: "cat".into(), vector: word_to_vector("cat") },
+///     VocabEntry { word: "dog".into(), vector: word_to_vector("dog") },
+/// ];
+/// let ranked = nearest_words(&word_to_vector("cat"), &vocab, -1.0, 2);
+/// assert_eq!(ranked[0].0, "cat");
+/// assert_eq!(ranked.len(), 2);
+/// ```
+pub fn nearest_words(
+    vector: &[f32; SLOT_DIM],
+    vocabulary: &[VocabEntry],
+    threshold: f32,
+    m: usize,
+) -> WordCandidates {
+    let mut ranked: Vec<(&str, f32)> = vocabulary
+        .iter()
+        .map(|entry| (entry.word.as_str(), similarity(vector, &entry.vector)))
+        .filter(|&(_, sim)| sim > threshold)
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+        .into_iter()
+        .take(m)
+        .map(|(word, sim)| (word.to_string(), sim))
+        .collect()
+}
+
 /// Format decoded slot words into a human-readable sentence.
 ///
 /// Special handling:
@@ -160,6 +202,30 @@ mod tests {
         assert_eq!(nearest_word(&query, &[], 0.0), None);
     }
 
+    #[test]
+    fn nearest_words_ranks_and_agrees_with_nearest_word() {
+        let vocab: Vec<VocabEntry> = ["cat", "hat", "mat", "dog"]
+            .iter()
+            .map(|w| VocabEntry {
+                word: (*w).into(),
+                vector: word_to_vector(w),
+            })
+            .collect();
+        let mut query = word_to_vector("hat");
+        for (q, c) in query.iter_mut().zip(word_to_vector("cat").iter()) {
+            *q = 0.6 * *q + 0.4 * c;
+        }
+
+        let ranked = nearest_words(&query, &vocab, 0.0, 3);
+        assert!(!ranked.is_empty() && ranked.len() <= 3);
+        assert_eq!(Some(ranked[0].0.clone()), nearest_word(&query, &vocab, 0.0));
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(ranked.iter().all(|(_, sim)| *sim > 0.0));
+
+        assert!(nearest_words(&query, &vocab, 0.0, 0).is_empty());
+        assert!(nearest_words(&query, &[], 0.0, 3).is_empty());
+    }
+
     #[test]
     fn format_output_agent_predicate_patient() {
         let words = vec![
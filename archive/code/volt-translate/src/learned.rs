@@ -23,7 +23,7 @@ use std::path::Path;
 
 use candle_core::{Device, Tensor};
 use tokenizers::Tokenizer;
-use volt_core::{SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
+use volt_core::{SlotRole, TensorFrame, VoltError, MAX_SLOTS, REDACTED_PLACEHOLDER, SLOT_DIM};
 
 use crate::code_decoder::{CodeDecoder, CodeDecoderConfig};
 use crate::code_encoder::{CodeEncoder, CodeEncoderConfig};
@@ -170,7 +170,11 @@ impl Translator for LearnedTranslator {
             {
                 let role = slot.role;
                 let certainty = frame.meta[i].certainty;
-                let desc = format!("[{role:?} γ={certainty:.2}]");
+                let desc = if frame.is_redacted(i) {
+                    REDACTED_PLACEHOLDER.to_string()
+                } else {
+                    format!("[{role:?} γ={certainty:.2}]")
+                };
                 result.push((i, role, desc));
             }
         }
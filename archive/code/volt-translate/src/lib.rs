@@ -21,7 +21,10 @@
 pub mod action_core;
 pub mod decode;
 pub mod encode;
+pub mod multi;
+pub mod postproc;
 pub mod stub;
+pub mod symmetry;
 
 #[cfg(feature = "llm")]
 pub mod llm;
@@ -34,7 +37,10 @@ pub mod code_decoder;
 pub mod learned;
 
 pub use action_core::{ActionCore, ActionOutput, OutputModality, TextAction};
+pub use multi::MultiTranslator;
+pub use postproc::{Capitalize, Chain, PostProcessor, PunctuationJoin};
 pub use stub::StubTranslator;
+pub use symmetry::{verify_symmetry, SymmetryReport};
 pub use volt_core;
 
 #[cfg(feature = "llm")]
@@ -70,6 +76,34 @@ pub struct TranslateOutput {
     pub slots_filled: usize,
 }
 
+/// A natural language, identified by its ISO 639-1 code.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::Lang;
+///
+/// assert_eq!(Lang::EN.code(), "en");
+/// assert_eq!(Lang::new("fr"), Lang::new("fr"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lang(&'static str);
+
+impl Lang {
+    /// English.
+    pub const EN: Lang = Lang("en");
+
+    /// Creates a language from its ISO 639-1 code (e.g. `"de"`).
+    pub const fn new(code: &'static str) -> Self {
+        Self(code)
+    }
+
+    /// Returns the ISO 639-1 code.
+    pub fn code(&self) -> &'static str {
+        self.0
+    }
+}
+
 /// Trait for translating between external modalities and TensorFrames.
 ///
 /// Implementors convert raw input into TensorFrames (encode) and
@@ -97,6 +131,31 @@ pub trait Translator {
     /// Returns a string representation of the frame contents.
     fn decode(&self, frame: &TensorFrame) -> Result<String, VoltError>;
 
+    /// Decode a TensorFrame, then run `postproc` over the text.
+    ///
+    /// [`decode`](Translator::decode) is left untouched; this only
+    /// transforms its output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_translate::{Chain, StubTranslator, Translator};
+    ///
+    /// let t = StubTranslator::new();
+    /// let output = t.encode("cat sat mat").unwrap();
+    /// let raw = t.decode(&output.frame).unwrap();
+    /// let text = t.decode_with_postproc(&output.frame, &Chain::readable()).unwrap();
+    /// assert_eq!(raw, "cat sat mat.");
+    /// assert_eq!(text, "Cat sat mat.");
+    /// ```
+    fn decode_with_postproc(
+        &self,
+        frame: &TensorFrame,
+        postproc: &dyn PostProcessor,
+    ) -> Result<String, VoltError> {
+        self.decode(frame).map(|text| postproc.process(&text))
+    }
+
     /// Decode each active slot individually.
     ///
     /// Returns a vec of `(slot_index, role, decoded_word)` tuples for
@@ -120,6 +179,24 @@ pub trait Translator {
         frame: &TensorFrame,
     ) -> Result<Vec<(usize, SlotRole, String)>, VoltError>;
 
+    /// Detect the language of raw text input.
+    ///
+    /// Returns `None` by default, meaning "no opinion". Language-specific
+    /// translators override this so a [`MultiTranslator`] can route input
+    /// to them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_translate::{StubTranslator, Translator};
+    ///
+    /// let t = StubTranslator::new();
+    /// assert_eq!(t.detect_language("the cat sat"), None);
+    /// ```
+    fn detect_language(&self, _input: &str) -> Option<Lang> {
+        None
+    }
+
     /// Optional metadata about this translator module.
     ///
     /// Returns `None` by default. Community modules should override
@@ -21,7 +21,7 @@ use std::path::PathBuf;
 use volt_bus::codebook::Codebook;
 use volt_core::meta::DiscourseType;
 use volt_core::slot::{SlotMeta, SlotSource};
-use volt_core::{SlotData, SlotRole, TensorFrame, VoltError, MAX_SLOTS};
+use volt_core::{CertaintyRule, SlotData, SlotRole, TensorFrame, VoltError, MAX_SLOTS, REDACTED_PLACEHOLDER};
 
 use super::backbone::LlmBackbone;
 use super::projection::{aggregate_to_slots, FrameProjectionHead, ProjectionConfig};
@@ -235,12 +235,15 @@ impl Translator for LlmTranslator {
             let role = slot_to_role(slot_idx);
             let mut slot = SlotData::new(role);
 
-            // Quantize through codebook if available
+            // Quantize through codebook if available; a poor codebook match
+            // lowers the slot's certainty by its calibrated confidence
+            let mut match_confidence = 1.0;
             if let Some(codebook) = &self.codebook {
-                match codebook.quantize(slot_vec) {
-                    Ok((cb_id, quantized)) => {
+                match codebook.quantize_with_confidence(slot_vec) {
+                    Ok((cb_id, quantized, cb_confidence)) => {
                         slot.write_resolution(0, quantized);
                         slot.codebook_id = Some(cb_id);
+                        match_confidence = cb_confidence;
                     }
                     Err(_) => {
                         // Fall back to raw vector if quantization fails
@@ -255,7 +258,9 @@ impl Translator for LlmTranslator {
 
             // Set slot metadata
             frame.meta[slot_idx] = SlotMeta {
-                certainty: self.base_certainty * (confidence / active_slots.len() as f32).min(1.0),
+                certainty: self.base_certainty
+                    * (confidence / active_slots.len() as f32).min(1.0)
+                    * match_confidence,
                 source: SlotSource::Translator,
                 updated_at: now_micros(),
                 needs_verify: true,
@@ -266,7 +271,7 @@ impl Translator for LlmTranslator {
 
         // Set frame metadata
         frame.frame_meta.discourse_type = classify_discourse(input);
-        frame.frame_meta.global_certainty = frame.min_certainty().unwrap_or(0.0);
+        frame.recompute_global_certainty(CertaintyRule::Min);
         frame.frame_meta.created_at = now_micros();
 
         Ok(TranslateOutput {
@@ -304,7 +309,9 @@ impl Translator for LlmTranslator {
 
         for i in 0..MAX_SLOTS {
             if let Some(slot) = &frame.slots[i] {
-                let desc = if let Some(cb_id) = slot.codebook_id {
+                let desc = if frame.is_redacted(i) {
+                    REDACTED_PLACEHOLDER.to_string()
+                } else if let Some(cb_id) = slot.codebook_id {
                     format!("cb:{cb_id}")
                 } else {
                     "raw".to_string()
@@ -0,0 +1,219 @@
+//! Language routing across per-language translators.
+//!
+//! [`MultiTranslator`] holds a fallback translator plus any number of
+//! language-specific translators. On encode, each registered translator
+//! is asked to [`detect_language`](crate::Translator::detect_language);
+//! the first one that recognises its own language handles the input.
+//! Input no registered translator claims goes to the fallback.
+//!
+//! Frames carry no language tag, so decoding always uses the fallback.
+
+use volt_core::{ModuleInfo, SlotRole, TensorFrame, VoltError};
+
+use crate::{Lang, TranslateOutput, Translator};
+
+/// A translator that can be shared across server threads.
+type SharedTranslator = Box<dyn Translator + Send + Sync>;
+
+/// Routes input to per-language translators, falling back to a default.
+///
+/// With no languages registered it behaves exactly like its fallback.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::{MultiTranslator, StubTranslator, Translator};
+///
+/// let multi = MultiTranslator::new(Box::new(StubTranslator::new()));
+/// let output = multi.encode("the cat sat").unwrap();
+/// assert_eq!(output.slots_filled, 3);
+/// assert_eq!(multi.detect_language("the cat sat"), None);
+/// ```
+pub struct MultiTranslator {
+    /// Handles input no registered language claims, and all decoding.
+    fallback: SharedTranslator,
+    /// Language translators in registration order.
+    languages: Vec<(Lang, SharedTranslator)>,
+}
+
+impl std::fmt::Debug for MultiTranslator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let langs: Vec<&str> = self.languages.iter().map(|(l, _)| l.code()).collect();
+        f.debug_struct("MultiTranslator")
+            .field("languages", &langs)
+            .finish()
+    }
+}
+
+impl MultiTranslator {
+    /// Creates a router with only a fallback translator.
+    pub fn new(fallback: SharedTranslator) -> Self {
+        Self {
+            fallback,
+            languages: Vec::new(),
+        }
+    }
+
+    /// Registers a translator for `lang`.
+    ///
+    /// The translator only receives input for which its own
+    /// `detect_language` returns `lang`. Registering a language again
+    /// replaces the earlier translator.
+    pub fn register(&mut self, lang: Lang, translator: SharedTranslator) {
+        match self.languages.iter_mut().find(|(l, _)| *l == lang) {
+            Some(entry) => entry.1 = translator,
+            None => self.languages.push((lang, translator)),
+        }
+    }
+
+    /// Returns the registered languages in registration order.
+    pub fn languages(&self) -> Vec<Lang> {
+        self.languages.iter().map(|(l, _)| *l).collect()
+    }
+
+    /// Picks the translator for `input`: the first registered one that
+    /// detects its own language, else the fallback.
+    fn route(&self, input: &str) -> &dyn Translator {
+        self.languages
+            .iter()
+            .find(|(lang, t)| t.detect_language(input) == Some(*lang))
+            .map_or(self.fallback.as_ref(), |(_, t)| t.as_ref())
+    }
+}
+
+impl Translator for MultiTranslator {
+    fn encode(&self, input: &str) -> Result<TranslateOutput, VoltError> {
+        self.route(input).encode(input)
+    }
+
+    fn decode(&self, frame: &TensorFrame) -> Result<String, VoltError> {
+        self.fallback.decode(frame)
+    }
+
+    fn decode_slots(
+        &self,
+        frame: &TensorFrame,
+    ) -> Result<Vec<(usize, SlotRole, String)>, VoltError> {
+        self.fallback.decode_slots(frame)
+    }
+
+    fn detect_language(&self, input: &str) -> Option<Lang> {
+        self.languages
+            .iter()
+            .find_map(|(lang, t)| (t.detect_language(input) == Some(*lang)).then_some(*lang))
+    }
+
+    fn info(&self) -> Option<ModuleInfo> {
+        self.fallback.info()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StubTranslator;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Stub-backed translator that claims input containing any of its
+    /// marker words and counts how often it encodes.
+    struct MockLang {
+        lang: Lang,
+        markers: &'static [&'static str],
+        inner: StubTranslator,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockLang {
+        fn new(lang: Lang, markers: &'static [&'static str]) -> (Self, Arc<AtomicUsize>) {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let mock = Self {
+                lang,
+                markers,
+                inner: StubTranslator::new(),
+                calls: Arc::clone(&calls),
+            };
+            (mock, calls)
+        }
+    }
+
+    impl Translator for MockLang {
+        fn encode(&self, input: &str) -> Result<TranslateOutput, VoltError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.encode(input)
+        }
+
+        fn decode(&self, frame: &TensorFrame) -> Result<String, VoltError> {
+            self.inner.decode(frame)
+        }
+
+        fn decode_slots(
+            &self,
+            frame: &TensorFrame,
+        ) -> Result<Vec<(usize, SlotRole, String)>, VoltError> {
+            self.inner.decode_slots(frame)
+        }
+
+        fn detect_language(&self, input: &str) -> Option<Lang> {
+            input
+                .split_whitespace()
+                .any(|w| self.markers.contains(&w.to_lowercase().as_str()))
+                .then_some(self.lang)
+        }
+    }
+
+    #[test]
+    fn routes_by_detected_language() {
+        let (es, es_calls) = MockLang::new(Lang::new("es"), &["el", "los", "gato"]);
+        let (fr, fr_calls) = MockLang::new(Lang::new("fr"), &["le", "les", "chat"]);
+        let mut multi = MultiTranslator::new(Box::new(StubTranslator::new()));
+        multi.register(Lang::new("es"), Box::new(es));
+        multi.register(Lang::new("fr"), Box::new(fr));
+        assert_eq!(multi.languages(), vec![Lang::new("es"), Lang::new("fr")]);
+
+        assert_eq!(multi.detect_language("el gato duerme"), Some(Lang::new("es")));
+        multi.encode("el gato duerme").unwrap();
+        assert_eq!(es_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(fr_calls.load(Ordering::SeqCst), 0);
+
+        assert_eq!(multi.detect_language("Le chat dort"), Some(Lang::new("fr")));
+        multi.encode("Le chat dort").unwrap();
+        assert_eq!(es_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(fr_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn undetected_language_uses_fallback() {
+        let (es, es_calls) = MockLang::new(Lang::new("es"), &["el"]);
+        let mut multi = MultiTranslator::new(Box::new(StubTranslator::new()));
+        multi.register(Lang::new("es"), Box::new(es));
+
+        assert_eq!(multi.detect_language("the cat sat"), None);
+        let output = multi.encode("the cat sat").unwrap();
+        assert_eq!(output.slots_filled, 3);
+        assert_eq!(es_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn detection_for_unregistered_language_is_ignored() {
+        // Claims "fr" but is registered as "es": never routed to.
+        let (mislabeled, calls) = MockLang::new(Lang::new("fr"), &["le"]);
+        let mut multi = MultiTranslator::new(Box::new(StubTranslator::new()));
+        multi.register(Lang::new("es"), Box::new(mislabeled));
+
+        assert_eq!(multi.detect_language("le chat"), None);
+        multi.encode("le chat").unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn without_languages_matches_stub() {
+        let stub = StubTranslator::new();
+        let multi = MultiTranslator::new(Box::new(StubTranslator::new()));
+        let a = stub.encode("dogs chase red balls").unwrap();
+        let b = multi.encode("dogs chase red balls").unwrap();
+        assert_eq!(a.token_count, b.token_count);
+        assert_eq!(a.slots_filled, b.slots_filled);
+        assert_eq!(stub.decode(&a.frame).unwrap(), multi.decode(&b.frame).unwrap());
+    }
+}
@@ -0,0 +1,236 @@
+//! Output post-processing for decoded text.
+//!
+//! [`Translator::decode`](crate::Translator::decode) output is raw:
+//! lower-case words joined by spaces, with punctuation tokens spaced like
+//! words. A [`PostProcessor`] cleans that up for display, and
+//! [`Translator::decode_with_postproc`](crate::Translator::decode_with_postproc)
+//! applies one after decoding. `decode` itself never post-processes.
+//!
+//! ## Built-ins
+//!
+//! - [`Capitalize`]: upper-cases the first letter of each sentence.
+//! - [`PunctuationJoin`]: collapses whitespace and attaches punctuation
+//!   to its neighbouring word.
+//! - [`Chain`]: runs processors in order. An empty chain is identity.
+
+/// Transforms decoded text into its display form.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::postproc::PostProcessor;
+///
+/// struct Shout;
+/// impl PostProcessor for Shout {
+///     fn process(&self, text: &str) -> String {
+///         text.to_uppercase()
+///     }
+/// }
+///
+/// assert_eq!(Shout.process("cat sat."), "CAT SAT.");
+/// ```
+pub trait PostProcessor: Send + Sync {
+    /// Returns the processed form of `text`.
+    fn process(&self, text: &str) -> String;
+}
+
+/// Upper-cases the first letter of each sentence.
+///
+/// A sentence starts at the beginning of the text and after `.`, `!` or
+/// `?` followed by whitespace.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::postproc::{Capitalize, PostProcessor};
+///
+/// assert_eq!(Capitalize.process("cat sat. dog ran!"), "Cat sat. Dog ran!");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Capitalize;
+
+impl PostProcessor for Capitalize {
+    fn process(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut sentence_start = true;
+        let mut after_terminator = false;
+        for c in text.chars() {
+            if sentence_start && c.is_alphabetic() {
+                out.extend(c.to_uppercase());
+                sentence_start = false;
+            } else {
+                out.push(c);
+                if c.is_alphanumeric() {
+                    sentence_start = false;
+                }
+            }
+            if matches!(c, '.' | '!' | '?') {
+                after_terminator = true;
+            } else if c.is_whitespace() && after_terminator {
+                sentence_start = true;
+                after_terminator = false;
+            } else {
+                after_terminator = false;
+            }
+        }
+        out
+    }
+}
+
+/// Collapses whitespace runs to single spaces and attaches punctuation
+/// tokens to their neighbouring word (`"cat , sat ."` → `"cat, sat."`).
+///
+/// Closing punctuation (`. , ! ? ; : ) ]` and `%`) loses the space
+/// before it; opening brackets (`( [`) lose the space after them.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::postproc::{PostProcessor, PunctuationJoin};
+///
+/// assert_eq!(PunctuationJoin.process("cat  sat ( mat ) ."), "cat sat (mat).");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PunctuationJoin;
+
+impl PostProcessor for PunctuationJoin {
+    fn process(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        for token in text.split_whitespace() {
+            let closes = token
+                .chars()
+                .next()
+                .is_some_and(|c| matches!(c, '.' | ',' | '!' | '?' | ';' | ':' | ')' | ']' | '%'));
+            let after_open = out.ends_with(['(', '[']);
+            if !out.is_empty() && !closes && !after_open {
+                out.push(' ');
+            }
+            out.push_str(token);
+        }
+        out
+    }
+}
+
+/// Runs post-processors in order, each on the previous one's output.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::postproc::{Capitalize, Chain, PostProcessor, PunctuationJoin};
+///
+/// let chain = Chain::new().then(PunctuationJoin).then(Capitalize);
+/// assert_eq!(chain.process("cat sat , mat ."), "Cat sat, mat.");
+/// assert_eq!(Chain::new().process("as is ."), "as is .");
+/// ```
+#[derive(Default)]
+pub struct Chain {
+    steps: Vec<Box<dyn PostProcessor>>,
+}
+
+impl Chain {
+    /// Creates an empty chain, which returns its input unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The chain used for readable output:
+    /// [`PunctuationJoin`] then [`Capitalize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_translate::postproc::{Chain, PostProcessor};
+    ///
+    /// assert_eq!(Chain::readable().process("hi , there ."), "Hi, there.");
+    /// ```
+    pub fn readable() -> Self {
+        Self::new().then(PunctuationJoin).then(Capitalize)
+    }
+
+    /// Appends `step` to the end of the chain.
+    pub fn then(mut self, step: impl PostProcessor + 'static) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// Returns the number of steps in the chain.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if the chain has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl std::fmt::Debug for Chain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Chain").field("steps", &self.steps.len()).finish()
+    }
+}
+
+impl PostProcessor for Chain {
+    fn process(&self, text: &str) -> String {
+        self.steps
+            .iter()
+            .fold(text.to_string(), |acc, step| step.process(&acc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::format_output;
+    use volt_core::SlotRole;
+
+    #[test]
+    fn capitalize_starts_each_sentence() {
+        assert_eq!(Capitalize.process("hello. how are you? fine"), "Hello. How are you? Fine");
+        assert_eq!(Capitalize.process("3.5 apples"), "3.5 apples");
+        assert_eq!(Capitalize.process(""), "");
+    }
+
+    #[test]
+    fn punctuation_join_attaches_punctuation_and_collapses_space() {
+        assert_eq!(PunctuationJoin.process("  a ,  b ; c !  "), "a, b; c!");
+        assert_eq!(PunctuationJoin.process("[ x ] 50 %"), "[x] 50%");
+        assert_eq!(PunctuationJoin.process(""), "");
+    }
+
+    #[test]
+    fn empty_chain_is_identity() {
+        let text = "  raw , text .";
+        assert_eq!(Chain::new().process(text), text);
+        assert!(Chain::new().is_empty());
+    }
+
+    struct Suffix(&'static str);
+
+    impl PostProcessor for Suffix {
+        fn process(&self, text: &str) -> String {
+            format!("{text}{}", self.0)
+        }
+    }
+
+    #[test]
+    fn chain_runs_steps_in_order() {
+        let chain = Chain::new().then(Suffix("a")).then(Suffix("b"));
+        assert_eq!(chain.process("x"), "xab");
+        assert_eq!(chain.len(), 2);
+    }
+
+    #[test]
+    fn readable_chain_over_decoded_slots() {
+        let words = vec![
+            (0, SlotRole::Agent, "cat".to_string()),
+            (1, SlotRole::Predicate, "sat".to_string()),
+            (2, SlotRole::Patient, "mat".to_string()),
+            (3, SlotRole::Location, ",".to_string()),
+            (4, SlotRole::Time, "today".to_string()),
+        ];
+        let raw = format_output(&words);
+        assert_eq!(raw, "cat sat mat , today.");
+        assert_eq!(Chain::readable().process(&raw), "Cat sat mat, today.");
+    }
+}
@@ -12,13 +12,16 @@
 use std::sync::RwLock;
 
 use volt_core::meta::DiscourseType;
-use volt_core::slot::SlotSource;
-use volt_core::{SlotRole, TensorFrame, VoltError, MAX_SLOTS, SLOT_DIM};
+use volt_core::slot::{SlotData, SlotSource};
+use volt_core::{SlotRole, TensorFrame, VoltError, MAX_SLOTS, REDACTED_PLACEHOLDER, SLOT_DIM};
 
-use crate::decode::{format_output, nearest_word, VocabEntry};
+use crate::decode::{format_output, nearest_word, nearest_words, VocabEntry, WordCandidates};
 use crate::encode::{tokenize, word_to_vector, MAX_INPUT_BYTES};
 use crate::{TranslateOutput, Translator};
 
+/// Minimum cosine similarity for a vocabulary word to decode a slot.
+const DECODE_THRESHOLD: f32 = 0.5;
+
 /// Stub text translator using heuristic word-to-slot mapping.
 ///
 /// Each word is encoded as a deterministic 256-dim vector via hash.
@@ -88,6 +91,86 @@ impl StubTranslator {
         }
     }
 
+    /// Decode slot 8 as the numeric result a Hard Strand wrote, if it
+    /// holds one.
+    fn numeric_result(index: usize, slot_data: &SlotData) -> Option<String> {
+        if index != 8 || slot_data.role != SlotRole::Result {
+            return None;
+        }
+        // Math engine writes result in dim[0], valid flag in dim[1]
+        let vec = slot_data.resolutions[0].as_ref()?;
+        let result_value = vec[0];
+        let valid_flag = vec[1];
+        if valid_flag <= 0.5 {
+            return None;
+        }
+        Some(if result_value.fract().abs() < 0.0001 {
+            // Integer result
+            format!("{}", result_value as i64)
+        } else {
+            // Floating point result
+            format!("{:.4}", result_value)
+        })
+    }
+
+    /// The vector a slot decodes from: R1 first (proposition level,
+    /// where encode writes), then the other resolutions.
+    fn decode_vector(slot_data: &SlotData) -> Option<&[f32; SLOT_DIM]> {
+        slot_data.resolutions[1]
+            .as_ref()
+            .or(slot_data.resolutions[0].as_ref())
+            .or(slot_data.resolutions[2].as_ref())
+            .or(slot_data.resolutions[3].as_ref())
+    }
+
+    /// Rank up to `m` candidate words for each active slot.
+    ///
+    /// Returns one `(slot_index, candidates)` pair per slot, in the same
+    /// order as [`decode_slots`](Translator::decode_slots). Candidates are
+    /// `(word, similarity)` pairs from the vocabulary cleanup, most
+    /// similar first, so the first candidate is the decoded word. Slots
+    /// not decoded by cleanup (redacted slots, numeric results, and slots
+    /// with no word above the decode threshold) get no candidates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use volt_translate::{StubTranslator, Translator};
+    ///
+    /// let t = StubTranslator::new();
+    /// let frame = t.encode("cat sat mat").unwrap().frame;
+    /// let candidates = t.decode_slot_candidates(&frame, 3).unwrap();
+    /// let words = t.decode_slots(&frame).unwrap();
+    /// assert_eq!(candidates[0].1[0].0, words[0].2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoltError::TranslateError`] if the vocabulary lock is
+    /// poisoned.
+    pub fn decode_slot_candidates(
+        &self,
+        frame: &TensorFrame,
+        m: usize,
+    ) -> Result<Vec<(usize, WordCandidates)>, VoltError> {
+        let vocab = self.vocab.read().map_err(|e| VoltError::TranslateError {
+            message: format!("failed to acquire vocab read lock: {e}"),
+        })?;
+
+        let mut candidates = Vec::new();
+        for i in 0..MAX_SLOTS {
+            let Some(slot_data) = &frame.slots[i] else {
+                continue;
+            };
+            if frame.is_redacted(i) || Self::numeric_result(i, slot_data).is_some() {
+                candidates.push((i, Vec::new()));
+            } else if let Some(vec) = Self::decode_vector(slot_data) {
+                candidates.push((i, nearest_words(vec, &vocab, DECODE_THRESHOLD, m)));
+            }
+        }
+        Ok(candidates)
+    }
+
     /// Add a word to the vocabulary if not already present.
     fn add_to_vocab(&self, word: &str, vector: [f32; SLOT_DIM]) -> Result<(), VoltError> {
         let mut vocab = self.vocab.write().map_err(|e| VoltError::TranslateError {
@@ -305,37 +388,18 @@ impl Translator for StubTranslator {
 
         for i in 0..MAX_SLOTS {
             if let Some(slot_data) = &frame.slots[i] {
-                // Special handling for slot 8 (Result) — decode numeric result
-                if i == 8
-                    && slot_data.role == SlotRole::Result
-                    && let Some(vec) = slot_data.resolutions[0].as_ref()
-                {
-                    // Math engine writes result in dim[0], valid flag in dim[1]
-                    let result_value = vec[0];
-                    let valid_flag = vec[1];
-                    if valid_flag > 0.5 {
-                        let word = if result_value.fract().abs() < 0.0001 {
-                            // Integer result
-                            format!("{}", result_value as i64)
-                        } else {
-                            // Floating point result
-                            format!("{:.4}", result_value)
-                        };
-                        slot_words.push((i, slot_data.role, word));
-                        continue;
-                    }
+                if frame.is_redacted(i) {
+                    slot_words.push((i, slot_data.role, REDACTED_PLACEHOLDER.to_string()));
+                    continue;
                 }
 
-                // Try R1 first (proposition level, where encode writes),
-                // then fall back through other resolutions
-                let vector = slot_data.resolutions[1]
-                    .as_ref()
-                    .or(slot_data.resolutions[0].as_ref())
-                    .or(slot_data.resolutions[2].as_ref())
-                    .or(slot_data.resolutions[3].as_ref());
+                if let Some(word) = Self::numeric_result(i, slot_data) {
+                    slot_words.push((i, slot_data.role, word));
+                    continue;
+                }
 
-                if let Some(vec) = vector {
-                    let word = nearest_word(vec, &vocab, 0.5)
+                if let Some(vec) = Self::decode_vector(slot_data) {
+                    let word = nearest_word(vec, &vocab, DECODE_THRESHOLD)
                         .unwrap_or_else(|| format!("[slot{i}]"));
                     slot_words.push((i, slot_data.role, word));
                 }
@@ -459,6 +523,44 @@ mod tests {
         assert!(slots.is_empty());
     }
 
+    #[test]
+    fn decode_redacted_slot_yields_placeholder() {
+        let t = StubTranslator::new();
+        let mut frame = t.encode("cat sat mat").unwrap().frame;
+        frame.redact_slots(&[0]).unwrap();
+
+        let slot = frame.read_slot(0).unwrap();
+        assert!(slot.resolutions.iter().flatten().all(|v| v.iter().all(|x| *x == 0.0)));
+
+        let slots = t.decode_slots(&frame).unwrap();
+        assert_eq!(slots[0].2, REDACTED_PLACEHOLDER);
+        assert!(!slots[1].2.contains(REDACTED_PLACEHOLDER));
+        let text = t.decode(&frame).unwrap();
+        assert!(text.contains(REDACTED_PLACEHOLDER));
+        assert!(!text.contains("cat"));
+    }
+
+    #[test]
+    fn slot_candidates_align_with_decoded_words() {
+        let t = StubTranslator::new();
+        t.encode("dog ran home").unwrap();
+        let mut frame = t.encode("cat sat mat").unwrap().frame;
+        frame.redact_slots(&[2]).unwrap();
+
+        let words = t.decode_slots(&frame).unwrap();
+        let candidates = t.decode_slot_candidates(&frame, 2).unwrap();
+        assert_eq!(candidates.len(), words.len());
+        for ((index, ranked), (word_index, _, word)) in candidates.iter().zip(&words) {
+            assert_eq!(index, word_index);
+            if *index == 2 {
+                assert!(ranked.is_empty(), "redacted slot leaked candidates");
+            } else {
+                assert!(!ranked.is_empty() && ranked.len() <= 2);
+                assert_eq!(&ranked[0].0, word);
+            }
+        }
+    }
+
     #[test]
     fn index_to_role_mapping() {
         assert_eq!(StubTranslator::index_to_role(0), SlotRole::Agent);
@@ -0,0 +1,309 @@
+//! Encode/decode symmetry checks for translators.
+//!
+//! [`verify_symmetry`] encodes each corpus item, decodes the frame back
+//! to per-slot words, re-encodes those words, and compares the two
+//! frames slot by slot. A translator whose encode and decode share one
+//! vocabulary or codebook should score close to 1.0.
+//!
+//! ## Slot Fidelity
+//!
+//! Active slots are paired in index order. How a pair is scored depends
+//! on how the original slot was encoded:
+//!
+//! - **Codebook slots** (`codebook_id` set): 1.0 if the re-encoded slot
+//!   quantizes to the same codebook entry, else 0.0. The vectors are
+//!   snapped to entries, so only the entry ID is meaningful.
+//! - **Hash-vector slots**: the Bus cosine similarity
+//!   ([`volt_bus::similarity`], the metric decode's nearest-word lookup
+//!   thresholds on), clamped to `[0, 1]`.
+//!
+//! A slot present on only one side scores 0.0, so dropped or spurious
+//! words lower the item's fidelity.
+
+use volt_bus::similarity;
+use volt_core::slot::SlotData;
+use volt_core::{TensorFrame, MAX_SLOTS, SLOT_DIM};
+
+use crate::Translator;
+
+/// Maximum number of entries kept in [`SymmetryReport::worst_case`].
+pub const WORST_CASE_LIMIT: usize = 5;
+
+/// Round-trip result for one corpus item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetryCase {
+    /// The corpus item as given.
+    pub input: String,
+    /// The decoded per-slot words, space-separated in slot order.
+    pub decoded: String,
+    /// Mean slot fidelity in `[0, 1]`.
+    pub fidelity: f32,
+    /// Number of original slots scored as codebook slots.
+    pub codebook_slots: usize,
+    /// Number of original slots scored as hash-vector slots.
+    pub hash_slots: usize,
+}
+
+/// A corpus item that could not be round-tripped at all.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetryFailure {
+    /// The corpus item as given.
+    pub input: String,
+    /// Why the round trip failed.
+    pub error: String,
+}
+
+/// Summary of a [`verify_symmetry`] run.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SymmetryReport {
+    /// Mean fidelity over round-tripped items (0.0 if there were none).
+    pub mean_fidelity: f32,
+    /// Mean fidelity over codebook slots, if any were seen.
+    pub codebook_fidelity: Option<f32>,
+    /// Mean fidelity over hash-vector slots, if any were seen.
+    pub hash_fidelity: Option<f32>,
+    /// Up to [`WORST_CASE_LIMIT`] round-tripped items, lowest fidelity first.
+    pub worst_case: Vec<SymmetryCase>,
+    /// Items whose encode, decode, or re-encode returned an error.
+    pub failures: Vec<SymmetryFailure>,
+    /// Number of items that round-tripped (excludes failures).
+    pub evaluated: usize,
+}
+
+/// Measure how well `translator` recovers each item of `corpus`.
+///
+/// Encoding may update translator state (e.g. the stub's vocabulary),
+/// exactly as a normal [`Translator::encode`] call would.
+///
+/// # Example
+///
+/// ```
+/// use volt_translate::{verify_symmetry, StubTranslator};
+///
+/// let t = StubTranslator::new();
+/// let report = verify_symmetry(&t, &["the cat sat", "dogs chase balls", ""]);
+/// assert_eq!(report.evaluated, 2);
+/// assert_eq!(report.failures.len(), 1);
+/// assert!(report.mean_fidelity > 0.99);
+/// ```
+pub fn verify_symmetry<T: Translator + ?Sized>(translator: &T, corpus: &[&str]) -> SymmetryReport {
+    let mut report = SymmetryReport::default();
+    let mut cases = Vec::with_capacity(corpus.len());
+    let (mut codebook_sum, mut codebook_n) = (0.0f32, 0usize);
+    let (mut hash_sum, mut hash_n) = (0.0f32, 0usize);
+
+    for &input in corpus {
+        match round_trip(translator, input) {
+            Ok((case, scores)) => {
+                for score in scores {
+                    match score {
+                        SlotScore::Codebook(s) => {
+                            codebook_sum += s;
+                            codebook_n += 1;
+                        }
+                        SlotScore::Hash(s) => {
+                            hash_sum += s;
+                            hash_n += 1;
+                        }
+                    }
+                }
+                cases.push(case);
+            }
+            Err(error) => report.failures.push(SymmetryFailure {
+                input: input.to_string(),
+                error,
+            }),
+        }
+    }
+
+    report.evaluated = cases.len();
+    if !cases.is_empty() {
+        report.mean_fidelity = cases.iter().map(|c| c.fidelity).sum::<f32>() / cases.len() as f32;
+    }
+    report.codebook_fidelity = (codebook_n > 0).then(|| codebook_sum / codebook_n as f32);
+    report.hash_fidelity = (hash_n > 0).then(|| hash_sum / hash_n as f32);
+
+    cases.sort_by(|a, b| a.fidelity.total_cmp(&b.fidelity));
+    cases.truncate(WORST_CASE_LIMIT);
+    report.worst_case = cases;
+    report
+}
+
+/// Fidelity of one original slot, tagged by how it was encoded.
+enum SlotScore {
+    Codebook(f32),
+    Hash(f32),
+}
+
+/// Encode, decode, and re-encode one item, scoring each slot pair.
+fn round_trip<T: Translator + ?Sized>(
+    translator: &T,
+    input: &str,
+) -> Result<(SymmetryCase, Vec<SlotScore>), String> {
+    let original = translator
+        .encode(input)
+        .map_err(|e| format!("encode failed: {e}"))?
+        .frame;
+    let words = translator
+        .decode_slots(&original)
+        .map_err(|e| format!("decode failed: {e}"))?;
+    let decoded = words
+        .iter()
+        .map(|(_, _, word)| word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let reencoded = translator
+        .encode(&decoded)
+        .map_err(|e| format!("re-encode of {decoded:?} failed: {e}"))?
+        .frame;
+
+    let original_slots = active_slots(&original);
+    let reencoded_slots = active_slots(&reencoded);
+    if original_slots.is_empty() {
+        return Err("encode produced no active slots".to_string());
+    }
+
+    let mut scores = Vec::with_capacity(original_slots.len());
+    for (k, slot) in original_slots.iter().enumerate() {
+        let other = reencoded_slots.get(k);
+        let score = match slot.codebook_id {
+            Some(id) => SlotScore::Codebook(match other {
+                Some(other) if other.codebook_id == Some(id) => 1.0,
+                _ => 0.0,
+            }),
+            None => SlotScore::Hash(
+                match (slot_vector(slot), other.and_then(|o| slot_vector(o))) {
+                    (Some(a), Some(b)) => similarity(a, b).clamp(0.0, 1.0),
+                    _ => 0.0,
+                },
+            ),
+        };
+        scores.push(score);
+    }
+
+    // Extra re-encoded slots count against fidelity but belong to
+    // neither slot kind.
+    let total: f32 = scores
+        .iter()
+        .map(|s| match s {
+            SlotScore::Codebook(v) | SlotScore::Hash(v) => *v,
+        })
+        .sum();
+    let fidelity = total / original_slots.len().max(reencoded_slots.len()) as f32;
+    let codebook_slots = scores
+        .iter()
+        .filter(|s| matches!(s, SlotScore::Codebook(_)))
+        .count();
+
+    Ok((
+        SymmetryCase {
+            input: input.to_string(),
+            decoded,
+            fidelity,
+            codebook_slots,
+            hash_slots: scores.len() - codebook_slots,
+        },
+        scores,
+    ))
+}
+
+/// Active slots of `frame` in index order.
+fn active_slots(frame: &TensorFrame) -> Vec<&SlotData> {
+    (0..MAX_SLOTS)
+        .filter_map(|i| frame.slots[i].as_ref())
+        .collect()
+}
+
+/// The vector decode reads for a slot: R1 first, then R0, R2, R3.
+fn slot_vector(slot: &SlotData) -> Option<&[f32; SLOT_DIM]> {
+    slot.resolutions[1]
+        .as_ref()
+        .or(slot.resolutions[0].as_ref())
+        .or(slot.resolutions[2].as_ref())
+        .or(slot.resolutions[3].as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StubTranslator, TranslateOutput};
+    use volt_core::{SlotRole, VoltError};
+
+    #[test]
+    fn report_ranks_math_below_plain_text() {
+        let t = StubTranslator::new();
+        let corpus = ["the cat sat", "dogs chase red balls", "10 + 5", "", "   "];
+        let report = verify_symmetry(&t, &corpus);
+
+        assert_eq!(report.evaluated, 3);
+        assert_eq!(report.failures.len(), 2);
+        assert!(report.failures.iter().all(|f| f.error.contains("encode failed")));
+        assert_eq!(report.codebook_fidelity, None);
+        assert!(report.hash_fidelity.is_some());
+
+        assert_eq!(report.worst_case.len(), 3);
+        let worst = &report.worst_case[0];
+        assert_eq!(worst.input, "10 + 5");
+        assert!(worst.fidelity < 0.5, "math frame fidelity {}", worst.fidelity);
+        for pair in report.worst_case.windows(2) {
+            assert!(pair[0].fidelity <= pair[1].fidelity);
+        }
+
+        let best = report.worst_case.last().unwrap();
+        assert!(best.fidelity > 0.99, "{best:?}");
+        assert_eq!(best.hash_slots, best.decoded.split(' ').count());
+        assert!(report.mean_fidelity > worst.fidelity);
+        assert!(report.mean_fidelity < best.fidelity);
+    }
+
+    /// Stub wrapper that tags every slot with a codebook ID derived
+    /// from its decoded word, and decodes "mat" as "rug".
+    struct CodebookStub(StubTranslator);
+
+    impl Translator for CodebookStub {
+        fn encode(&self, input: &str) -> Result<TranslateOutput, VoltError> {
+            let mut output = self.0.encode(input)?;
+            let words = self.0.decode_slots(&output.frame)?;
+            for (i, _, word) in words {
+                if let Some(slot) = output.frame.slots[i].as_mut() {
+                    slot.codebook_id = Some(word.bytes().map(u16::from).sum());
+                }
+            }
+            Ok(output)
+        }
+
+        fn decode(&self, frame: &TensorFrame) -> Result<String, VoltError> {
+            self.0.decode(frame)
+        }
+
+        fn decode_slots(
+            &self,
+            frame: &TensorFrame,
+        ) -> Result<Vec<(usize, SlotRole, String)>, VoltError> {
+            let mut words = self.0.decode_slots(frame)?;
+            for (_, _, word) in &mut words {
+                if word == "mat" {
+                    *word = "rug".to_string();
+                }
+            }
+            Ok(words)
+        }
+    }
+
+    #[test]
+    fn codebook_slots_score_on_entry_id() {
+        let t = CodebookStub(StubTranslator::new());
+        let report = verify_symmetry(&t, &["cat sat mat", "cat sat"]);
+
+        assert_eq!(report.hash_fidelity, None);
+        let codebook = report.codebook_fidelity.unwrap();
+        assert!((codebook - 4.0 / 5.0).abs() < 1e-6, "codebook fidelity {codebook}");
+
+        let worst = &report.worst_case[0];
+        assert_eq!(worst.input, "cat sat mat");
+        assert_eq!(worst.codebook_slots, 3);
+        assert_eq!(worst.hash_slots, 0);
+        assert!((worst.fidelity - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(report.worst_case[1].fidelity, 1.0);
+    }
+}